@@ -0,0 +1,42 @@
+# Arabic resources, looked up by `TargetLanguage::message`
+# Directory and document names are ASCII transliterations: right-to-left
+# characters in file names break links on several wiki hosts.
+
+language-name = العربية
+prompt-instruction = يرجى كتابة الوثائق باللغة العربية بلغة دقيقة واحترافية وسهلة الفهم. احتفظ بأسماء الشيفرة ومسارات الملفات ومعرّفات عقد مخططات Mermaid بصيغتها اللاتينية الأصلية.
+
+## Output directories and documents
+dir-deep_exploration = 4-Istikshaf-Tafsili
+doc-overview = 1-Nazra-Amma-ala-al-Mashru
+doc-architecture = 2-Nazra-Amma-ala-al-Bunya
+doc-workflow = 3-Sayr-al-Amal
+doc-boundary = 5-Wajihat-al-Hudud
+doc-database = 6-Nazra-Amma-ala-Qaidat-al-Bayanat
+doc-dependencies = 7-Al-Tabaiyat
+toc-title = المحتويات
+
+## Console messages
+config-read-error = ⚠️ تحذير: تعذرت قراءة ملف الإعدادات { $path }، سيتم استخدام الإعدادات الافتراضية
+unknown-provider = ⚠️ تحذير: مزود غير معروف: { $provider }، سيتم استخدام المزود الافتراضي
+unknown-language = ⚠️ تحذير: لغة هدف غير معروفة: { $language }، سيتم استخدام اللغة الافتراضية (English)
+cache-hit = { "   " }✅ استخدام نتيجة تحليل الذكاء الاصطناعي المخزنة مؤقتًا: { $tag }
+ai-analyzing = { "   " }🤖 جارٍ تنفيذ تحليل الذكاء الاصطناعي [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ إخفاق في ذاكرة التخزين المؤقت [{ $category }] - يلزم استدلال الذكاء الاصطناعي
+cache-write = { "   " }💾 كتابة في ذاكرة التخزين المؤقت [{ $category }] - تم تخزين النتيجة
+cache-error = { "   " }❌ خطأ في ذاكرة التخزين المؤقت [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 استخدام نتيجة الضغط المخزنة مؤقتًا [{ $content_type }]
+cannot-read-file = تعذرت قراءة الملف: { $path }
+doc-not-found = ⚠️ تحذير: لم يتم العثور على محتوى الوثيقة، المفتاح: { $key }
+mermaid-error = ⚠️ حدث خطأ أثناء إصلاح مخطط mermaid: { $error }
+summary-reasoning-failed = ⚠️  فشل استدلال التلخيص، سيتم إرجاع النتيجة الجزئية الأصلية...{ $error }
+domain-analysis-failed = ⚠️ تحليل وحدات المجال: فشل تحليل { $domain }: { $error }
+no-code-path-for-domain = ⚠️ المجال '{ $domain }' ليس له مسارات شيفرة مرتبطة
+
+## Research report titles
+agent-system_context = تقرير بحث سياق النظام
+agent-domain_modules = تقرير بحث وحدات المجال
+agent-architecture = تقرير بحث بنية النظام
+agent-workflow = تقرير بحث سير العمل
+agent-key_modules = تقرير بحث الوحدات والمكونات الرئيسية
+agent-boundary = تقرير بحث واجهات الحدود
+agent-database = تقرير بحث نظرة عامة على قاعدة البيانات
@@ -0,0 +1,40 @@
+# German resources, looked up by `TargetLanguage::message`
+
+language-name = Deutsch
+prompt-instruction = Bitte schreiben Sie die Dokumentation auf Deutsch und stellen Sie sicher, dass die Sprache präzise, professionell und leicht verständlich ist.
+
+## Output directories and documents
+dir-deep_exploration = 4-Tiefere-Erkundung
+doc-overview = 1-Projektübersicht
+doc-architecture = 2-Architekturübersicht
+doc-workflow = 3-Arbeitsablauf
+doc-boundary = 5-Grenzschnittstellen
+doc-database = 6-Datenbankübersicht
+doc-dependencies = 7-Abhängigkeiten
+toc-title = Inhalt
+
+## Console messages
+config-read-error = ⚠️ Warnung: Konfigurationsdatei { $path } kann nicht gelesen werden, verwende Standardkonfiguration
+unknown-provider = ⚠️ Warnung: Unbekannter Provider: { $provider }, verwende Standard-Provider
+unknown-language = ⚠️ Warnung: Unbekannte Zielsprache: { $language }, verwende Standardsprache (English)
+cache-hit = { "   " }✅ Verwende gecachtes KI-Analyseergebnis: { $tag }
+ai-analyzing = { "   " }🤖 Führe KI-Analyse durch [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ Cache-Miss [{ $category }] - KI-Inferenz erforderlich
+cache-write = { "   " }💾 Cache-Schreiben [{ $category }] - Ergebnis gecacht
+cache-error = { "   " }❌ Cache-Fehler [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 Verwende gecachtes Kompressionsergebnis [{ $content_type }]
+cannot-read-file = Datei kann nicht gelesen werden: { $path }
+doc-not-found = ⚠️ Warnung: Dokumentinhalt nicht gefunden, Schlüssel: { $key }
+mermaid-error = ⚠️ Fehler während der Mermaid-Diagrammreparatur aufgetreten: { $error }
+summary-reasoning-failed = ⚠️  Zusammenfassungs-Reasoning fehlgeschlagen, gebe ursprüngliches Teilergebnis zurück...{ $error }
+domain-analysis-failed = ⚠️ Domain-Modul-Analyse: { $domain } Analyse fehlgeschlagen: { $error }
+no-code-path-for-domain = ⚠️ Domain '{ $domain }' hat keine zugeordneten Code-Pfade
+
+## Research report titles
+agent-system_context = Systemkontext-Forschungsbericht
+agent-domain_modules = Domain-Modul-Forschungsbericht
+agent-architecture = Systemarchitektur-Forschungsbericht
+agent-workflow = Workflow-Forschungsbericht
+agent-key_modules = Schlüsselmodul- und Komponenten-Forschungsbericht
+agent-boundary = Grenzschnittstellenforschungsbericht
+agent-database = Datenbankübersicht-Forschungsbericht
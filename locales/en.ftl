@@ -0,0 +1,40 @@
+# English resources, looked up by `TargetLanguage::message`
+
+language-name = English
+prompt-instruction = Please write the documentation in English, ensuring accurate, professional, and easy-to-understand language.
+
+## Output directories and documents
+dir-deep_exploration = 4.Deep-Exploration
+doc-overview = 1.Overview
+doc-architecture = 2.Architecture
+doc-workflow = 3.Workflow
+doc-boundary = 5.Boundary-Interfaces
+doc-database = 6.Database-Overview
+doc-dependencies = 7.Dependencies
+toc-title = Contents
+
+## Console messages
+config-read-error = ⚠️ Warning: Cannot read config file { $path }, using default config
+unknown-provider = ⚠️ Warning: Unknown provider: { $provider }, using default provider
+unknown-language = ⚠️ Warning: Unknown target language: { $language }, using default language (English)
+cache-hit = { "   " }✅ Using cached AI analysis result: { $tag }
+ai-analyzing = { "   " }🤖 Performing AI analysis [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ Cache miss [{ $category }] - AI inference required
+cache-write = { "   " }💾 Cache write [{ $category }] - Result cached
+cache-error = { "   " }❌ Cache error [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 Using cached compression result [{ $content_type }]
+cannot-read-file = Cannot read file: { $path }
+doc-not-found = ⚠️ Warning: Document content not found, key: { $key }
+mermaid-error = ⚠️ Error occurred during mermaid diagram repair: { $error }
+summary-reasoning-failed = ⚠️  Summary reasoning failed, returning original partial result...{ $error }
+domain-analysis-failed = ⚠️ Domain module analysis: { $domain } analysis failed: { $error }
+no-code-path-for-domain = ⚠️ Domain '{ $domain }' has no associated code paths
+
+## Research report titles
+agent-system_context = System Context Research Report
+agent-domain_modules = Domain Modules Research Report
+agent-architecture = System Architecture Research Report
+agent-workflow = Workflow Research Report
+agent-key_modules = Key Modules and Components Research Report
+agent-boundary = Boundary Interface Research Report
+agent-database = Database Overview Research Report
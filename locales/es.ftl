@@ -0,0 +1,40 @@
+# Spanish resources, looked up by `TargetLanguage::message`
+
+language-name = Español
+prompt-instruction = Por favor, redacta la documentación en español, asegurando un lenguaje preciso, profesional y fácil de entender.
+
+## Output directories and documents
+dir-deep_exploration = 4-Exploración-Detallada
+doc-overview = 1-Visión-General-del-Proyecto
+doc-architecture = 2-Visión-General-de-la-Arquitectura
+doc-workflow = 3-Flujo-de-Trabajo
+doc-boundary = 5-Interfaces-de-Frontera
+doc-database = 6-Visión-General-de-la-Base-de-Datos
+doc-dependencies = 7-Dependencias
+toc-title = Contenido
+
+## Console messages
+config-read-error = ⚠️ Advertencia: No se puede leer el archivo de configuración { $path }, se usa la configuración predeterminada
+unknown-provider = ⚠️ Advertencia: Proveedor desconocido: { $provider }, se usa el proveedor predeterminado
+unknown-language = ⚠️ Advertencia: Idioma de destino desconocido: { $language }, se usa el idioma predeterminado (English)
+cache-hit = { "   " }✅ Usando el resultado de análisis de IA en caché: { $tag }
+ai-analyzing = { "   " }🤖 Realizando análisis de IA [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ Fallo de caché [{ $category }] - se requiere inferencia de IA
+cache-write = { "   " }💾 Escritura en caché [{ $category }] - resultado almacenado
+cache-error = { "   " }❌ Error de caché [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 Usando el resultado de compresión en caché [{ $content_type }]
+cannot-read-file = No se puede leer el archivo: { $path }
+doc-not-found = ⚠️ Advertencia: No se encontró el contenido del documento, clave: { $key }
+mermaid-error = ⚠️ Se produjo un error al reparar el diagrama mermaid: { $error }
+summary-reasoning-failed = ⚠️  Falló el razonamiento de resumen, se devuelve el resultado parcial original...{ $error }
+domain-analysis-failed = ⚠️ Análisis de módulos de dominio: falló el análisis de { $domain }: { $error }
+no-code-path-for-domain = ⚠️ El dominio '{ $domain }' no tiene rutas de código asociadas
+
+## Research report titles
+agent-system_context = Informe de investigación del contexto del sistema
+agent-domain_modules = Informe de investigación de módulos de dominio
+agent-architecture = Informe de investigación de la arquitectura del sistema
+agent-workflow = Informe de investigación del flujo de trabajo
+agent-key_modules = Informe de investigación de módulos y componentes clave
+agent-boundary = Informe de investigación de interfaces de frontera
+agent-database = Informe de investigación de la visión general de la base de datos
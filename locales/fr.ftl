@@ -0,0 +1,40 @@
+# French resources, looked up by `TargetLanguage::message`
+
+language-name = Français
+prompt-instruction = Veuillez rédiger la documentation en français, en vous assurant que le langage soit précis, professionnel et facile à comprendre.
+
+## Output directories and documents
+dir-deep_exploration = 4-Exploration-Approfondie
+doc-overview = 1-Aperçu-du-Projet
+doc-architecture = 2-Aperçu-de-l'Architecture
+doc-workflow = 3-Flux-de-Travail
+doc-boundary = 5-Interfaces-de-Frontière
+doc-database = 6-Aperçu-Base-de-Données
+doc-dependencies = 7-Dépendances
+toc-title = Sommaire
+
+## Console messages
+config-read-error = ⚠️ Avertissement: Impossible de lire le fichier de configuration { $path }, utilisation de la configuration par défaut
+unknown-provider = ⚠️ Avertissement: Fournisseur inconnu: { $provider }, utilisation du fournisseur par défaut
+unknown-language = ⚠️ Avertissement: Langue cible inconnue: { $language }, utilisation de la langue par défaut (English)
+cache-hit = { "   " }✅ Utilisation du résultat d'analyse IA en cache: { $tag }
+ai-analyzing = { "   " }🤖 Analyse IA en cours [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ Cache manqué [{ $category }] - Inférence IA requise
+cache-write = { "   " }💾 Écriture en cache [{ $category }] - Résultat mis en cache
+cache-error = { "   " }❌ Erreur de cache [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 Utilisation du résultat de compression en cache [{ $content_type }]
+cannot-read-file = Impossible de lire le fichier: { $path }
+doc-not-found = ⚠️ Avertissement: Contenu du document introuvable, clé: { $key }
+mermaid-error = ⚠️ Erreur survenue lors de la réparation du diagramme mermaid: { $error }
+summary-reasoning-failed = ⚠️  Échec du raisonnement de résumé, retour du résultat partiel d'origine...{ $error }
+domain-analysis-failed = ⚠️ Analyse du module de domaine: échec de l'analyse de { $domain }: { $error }
+no-code-path-for-domain = ⚠️ Le domaine '{ $domain }' n'a pas de chemins de code associés
+
+## Research report titles
+agent-system_context = Rapport de recherche sur le contexte système
+agent-domain_modules = Rapport de recherche sur les modules de domaine
+agent-architecture = Rapport de recherche sur l'architecture système
+agent-workflow = Rapport de recherche sur le flux de travail
+agent-key_modules = Rapport de recherche sur les modules et composants clés
+agent-boundary = Rapport de recherche sur les interfaces de frontière
+agent-database = Rapport de recherche sur l'aperçu de la base de données
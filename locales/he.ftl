@@ -0,0 +1,42 @@
+# Hebrew resources, looked up by `TargetLanguage::message`
+# Directory and document names are ASCII transliterations: right-to-left
+# characters in file names break links on several wiki hosts.
+
+language-name = עברית
+prompt-instruction = אנא כתוב את התיעוד בעברית, בשפה מדויקת, מקצועית וקלה להבנה. השאר שמות בקוד, נתיבי קבצים ומזהי צמתים בתרשימי Mermaid בכתב הלטיני המקורי.
+
+## Output directories and documents
+dir-deep_exploration = 4-Chakira-Meumeket
+doc-overview = 1-Skira-Klalit-shel-haProyekt
+doc-architecture = 2-Skirat-Architektura
+doc-workflow = 3-Zrimat-Avoda
+doc-boundary = 5-Mimshakey-Gvul
+doc-database = 6-Skirat-Basis-haNetunim
+doc-dependencies = 7-Tlut
+toc-title = תוכן העניינים
+
+## Console messages
+config-read-error = ⚠️ אזהרה: לא ניתן לקרוא את קובץ התצורה { $path }, נעשה שימוש בתצורת ברירת המחדל
+unknown-provider = ⚠️ אזהרה: ספק לא מוכר: { $provider }, נעשה שימוש בספק ברירת המחדל
+unknown-language = ⚠️ אזהרה: שפת יעד לא מוכרת: { $language }, נעשה שימוש בשפת ברירת המחדל (English)
+cache-hit = { "   " }✅ שימוש בתוצאת ניתוח AI מהמטמון: { $tag }
+ai-analyzing = { "   " }🤖 מבצע ניתוח AI [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ החטאת מטמון [{ $category }] - נדרשת הסקת AI
+cache-write = { "   " }💾 כתיבה למטמון [{ $category }] - התוצאה נשמרה
+cache-error = { "   " }❌ שגיאת מטמון [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 שימוש בתוצאת דחיסה מהמטמון [{ $content_type }]
+cannot-read-file = לא ניתן לקרוא את הקובץ: { $path }
+doc-not-found = ⚠️ אזהרה: תוכן המסמך לא נמצא, מפתח: { $key }
+mermaid-error = ⚠️ אירעה שגיאה בתיקון תרשים mermaid: { $error }
+summary-reasoning-failed = ⚠️  הסקת הסיכום נכשלה, מוחזרת התוצאה החלקית המקורית...{ $error }
+domain-analysis-failed = ⚠️ ניתוח מודולי תחום: ניתוח { $domain } נכשל: { $error }
+no-code-path-for-domain = ⚠️ לתחום '{ $domain }' אין נתיבי קוד משויכים
+
+## Research report titles
+agent-system_context = דוח מחקר הקשר המערכת
+agent-domain_modules = דוח מחקר מודולי תחום
+agent-architecture = דוח מחקר ארכיטקטורת המערכת
+agent-workflow = דוח מחקר זרימת עבודה
+agent-key_modules = דוח מחקר מודולים ורכיבים מרכזיים
+agent-boundary = דוח מחקר ממשקי גבול
+agent-database = דוח מחקר סקירת מסד הנתונים
@@ -0,0 +1,40 @@
+# Hindi resources, looked up by `TargetLanguage::message`
+
+language-name = हिन्दी
+prompt-instruction = कृपया दस्तावेज़ हिन्दी में लिखें और सुनिश्चित करें कि भाषा सटीक, पेशेवर और समझने में आसान हो। तकनीकी शब्दों, कोड पहचानकर्ताओं और फ़ाइल पथों को उनके मूल अंग्रेज़ी रूप में रखें।
+
+## Output directories and documents
+dir-deep_exploration = 4-गहन-अन्वेषण
+doc-overview = 1-परियोजना-अवलोकन
+doc-architecture = 2-आर्किटेक्चर-अवलोकन
+doc-workflow = 3-कार्यप्रवाह
+doc-boundary = 5-सीमा-इंटरफ़ेस
+doc-database = 6-डेटाबेस-अवलोकन
+doc-dependencies = 7-निर्भरताएँ
+toc-title = विषय-सूची
+
+## Console messages
+config-read-error = ⚠️ चेतावनी: कॉन्फ़िगरेशन फ़ाइल { $path } पढ़ी नहीं जा सकी, डिफ़ॉल्ट कॉन्फ़िगरेशन का उपयोग किया जा रहा है
+unknown-provider = ⚠️ चेतावनी: अज्ञात प्रदाता: { $provider }, डिफ़ॉल्ट प्रदाता का उपयोग किया जा रहा है
+unknown-language = ⚠️ चेतावनी: अज्ञात लक्ष्य भाषा: { $language }, डिफ़ॉल्ट भाषा (English) का उपयोग किया जा रहा है
+cache-hit = { "   " }✅ कैश किए गए AI विश्लेषण परिणाम का उपयोग: { $tag }
+ai-analyzing = { "   " }🤖 AI विश्लेषण किया जा रहा है [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ कैश मिस [{ $category }] - AI अनुमान आवश्यक
+cache-write = { "   " }💾 कैश लेखन [{ $category }] - परिणाम कैश किया गया
+cache-error = { "   " }❌ कैश त्रुटि [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 कैश किए गए संपीड़न परिणाम का उपयोग [{ $content_type }]
+cannot-read-file = फ़ाइल पढ़ी नहीं जा सकी: { $path }
+doc-not-found = ⚠️ चेतावनी: दस्तावेज़ की सामग्री नहीं मिली, कुंजी: { $key }
+mermaid-error = ⚠️ mermaid आरेख सुधारते समय त्रुटि हुई: { $error }
+summary-reasoning-failed = ⚠️  सारांश तर्क विफल रहा, मूल आंशिक परिणाम लौटाया जा रहा है...{ $error }
+domain-analysis-failed = ⚠️ डोमेन मॉड्यूल विश्लेषण: { $domain } का विश्लेषण विफल रहा: { $error }
+no-code-path-for-domain = ⚠️ डोमेन '{ $domain }' से कोई कोड पथ संबद्ध नहीं है
+
+## Research report titles
+agent-system_context = सिस्टम संदर्भ शोध रिपोर्ट
+agent-domain_modules = डोमेन मॉड्यूल शोध रिपोर्ट
+agent-architecture = सिस्टम आर्किटेक्चर शोध रिपोर्ट
+agent-workflow = कार्यप्रवाह शोध रिपोर्ट
+agent-key_modules = प्रमुख मॉड्यूल और घटक शोध रिपोर्ट
+agent-boundary = सीमा इंटरफ़ेस शोध रिपोर्ट
+agent-database = डेटाबेस अवलोकन शोध रिपोर्ट
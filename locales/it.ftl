@@ -0,0 +1,40 @@
+# Italian resources, looked up by `TargetLanguage::message`
+
+language-name = Italiano
+prompt-instruction = Si prega di redigere la documentazione in italiano, garantendo un linguaggio accurato, professionale e facile da comprendere.
+
+## Output directories and documents
+dir-deep_exploration = 4-Esplorazione-Approfondita
+doc-overview = 1-Panoramica-del-Progetto
+doc-architecture = 2-Panoramica-dell'Architettura
+doc-workflow = 3-Flusso-di-Lavoro
+doc-boundary = 5-Interfacce-di-Confine
+doc-database = 6-Panoramica-del-Database
+doc-dependencies = 7-Dipendenze
+toc-title = Indice
+
+## Console messages
+config-read-error = ⚠️ Avviso: Impossibile leggere il file di configurazione { $path }, uso della configurazione predefinita
+unknown-provider = ⚠️ Avviso: Provider sconosciuto: { $provider }, uso del provider predefinito
+unknown-language = ⚠️ Avviso: Lingua di destinazione sconosciuta: { $language }, uso della lingua predefinita (English)
+cache-hit = { "   " }✅ Uso del risultato dell'analisi IA in cache: { $tag }
+ai-analyzing = { "   " }🤖 Analisi IA in corso [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ Cache mancante [{ $category }] - inferenza IA necessaria
+cache-write = { "   " }💾 Scrittura in cache [{ $category }] - risultato memorizzato
+cache-error = { "   " }❌ Errore di cache [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 Uso del risultato di compressione in cache [{ $content_type }]
+cannot-read-file = Impossibile leggere il file: { $path }
+doc-not-found = ⚠️ Avviso: Contenuto del documento non trovato, chiave: { $key }
+mermaid-error = ⚠️ Errore durante la riparazione del diagramma mermaid: { $error }
+summary-reasoning-failed = ⚠️  Ragionamento di sintesi non riuscito, restituzione del risultato parziale originale...{ $error }
+domain-analysis-failed = ⚠️ Analisi dei moduli di dominio: analisi di { $domain } non riuscita: { $error }
+no-code-path-for-domain = ⚠️ Il dominio '{ $domain }' non ha percorsi di codice associati
+
+## Research report titles
+agent-system_context = Rapporto di ricerca sul contesto di sistema
+agent-domain_modules = Rapporto di ricerca sui moduli di dominio
+agent-architecture = Rapporto di ricerca sull'architettura di sistema
+agent-workflow = Rapporto di ricerca sul flusso di lavoro
+agent-key_modules = Rapporto di ricerca sui moduli e componenti chiave
+agent-boundary = Rapporto di ricerca sulle interfacce di confine
+agent-database = Rapporto di ricerca sulla panoramica del database
@@ -0,0 +1,40 @@
+# Japanese resources, looked up by `TargetLanguage::message`
+
+language-name = 日本語
+prompt-instruction = 日本語でドキュメントを作成してください。正確で専門的で理解しやすい言語表現を心がけてください。
+
+## Output directories and documents
+dir-deep_exploration = 4-詳細探索
+doc-overview = 1-プロジェクト概要
+doc-architecture = 2-アーキテクチャ概要
+doc-workflow = 3-ワークフロー
+doc-boundary = 5-境界インターフェース
+doc-database = 6-データベース概要
+doc-dependencies = 7-依存関係
+toc-title = 目次
+
+## Console messages
+config-read-error = ⚠️ 警告: 設定ファイル { $path } を読み込めません、デフォルト設定を使用します
+unknown-provider = ⚠️ 警告: 不明なプロバイダー: { $provider }、デフォルトプロバイダーを使用します
+unknown-language = ⚠️ 警告: 不明な対象言語: { $language }、デフォルト言語 (English) を使用します
+cache-hit = { "   " }✅ キャッシュされたAI分析結果を使用: { $tag }
+ai-analyzing = { "   " }🤖 AI分析を実行中 [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ キャッシュミス [{ $category }] - AI推論が必要です
+cache-write = { "   " }💾 キャッシュ書き込み [{ $category }] - 結果がキャッシュされました
+cache-error = { "   " }❌ キャッシュエラー [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 キャッシュされた圧縮結果を使用 [{ $content_type }]
+cannot-read-file = ファイルを読み込めません: { $path }
+doc-not-found = ⚠️ 警告: ドキュメントコンテンツが見つかりません、キー: { $key }
+mermaid-error = ⚠️ mermaidダイアグラムの修復中にエラーが発生しました: { $error }
+summary-reasoning-failed = ⚠️  要約推論に失敗しました、元の部分的な結果を返します...{ $error }
+domain-analysis-failed = ⚠️ ドメインモジュール分析：{ $domain } の分析に失敗しました: { $error }
+no-code-path-for-domain = ⚠️ ドメイン'{ $domain }'に関連するコードパスがありません
+
+## Research report titles
+agent-system_context = システムコンテキスト調査レポート
+agent-domain_modules = ドメインモジュール調査レポート
+agent-architecture = システムアーキテクチャ調査レポート
+agent-workflow = ワークフロー調査レポート
+agent-key_modules = 主要モジュールとコンポーネント調査レポート
+agent-boundary = 境界インターフェース調査レポート
+agent-database = データベース概要調査レポート
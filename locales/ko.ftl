@@ -0,0 +1,40 @@
+# Korean resources, looked up by `TargetLanguage::message`
+
+language-name = 한국어
+prompt-instruction = 한국어로 문서를 작성해 주세요. 정확하고 전문적이며 이해하기 쉬운 언어 표현을 사용해 주세요.
+
+## Output directories and documents
+dir-deep_exploration = 4-심층-탐색
+doc-overview = 1-프로젝트-개요
+doc-architecture = 2-아키텍처-개요
+doc-workflow = 3-워크플로우
+doc-boundary = 5-경계-인터페이스
+doc-database = 6-데이터베이스-개요
+doc-dependencies = 7-의존성
+toc-title = 목차
+
+## Console messages
+config-read-error = ⚠️ 경고: 설정 파일 { $path }을(를) 읽을 수 없습니다. 기본 설정을 사용합니다
+unknown-provider = ⚠️ 경고: 알 수 없는 프로바이더: { $provider }, 기본 프로바이더를 사용합니다
+unknown-language = ⚠️ 경고: 알 수 없는 대상 언어: { $language }, 기본 언어(English)를 사용합니다
+cache-hit = { "   " }✅ 캐시된 AI 분석 결과 사용: { $tag }
+ai-analyzing = { "   " }🤖 AI 분석 수행 중 [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ 캐시 미스 [{ $category }] - AI 추론 필요
+cache-write = { "   " }💾 캐시 쓰기 [{ $category }] - 결과 캐시됨
+cache-error = { "   " }❌ 캐시 오류 [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 캐시된 압축 결과 사용 [{ $content_type }]
+cannot-read-file = 파일을 읽을 수 없습니다: { $path }
+doc-not-found = ⚠️ 경고: 문서 내용을 찾을 수 없습니다, 키: { $key }
+mermaid-error = ⚠️ mermaid 다이어그램 복구 중 오류 발생: { $error }
+summary-reasoning-failed = ⚠️  요약 추론 실패, 원래 부분 결과 반환...{ $error }
+domain-analysis-failed = ⚠️ 도메인 모듈 분석: { $domain } 분석 실패: { $error }
+no-code-path-for-domain = ⚠️ 도메인 '{ $domain }'에 연결된 코드 경로가 없습니다
+
+## Research report titles
+agent-system_context = 시스템 컨텍스트 조사 보고서
+agent-domain_modules = 도메인 모듈 조사 보고서
+agent-architecture = 시스템 아키텍처 조사 보고서
+agent-workflow = 워크플로우 조사 보고서
+agent-key_modules = 핵심 모듈 및 구성 요소 조사 보고서
+agent-boundary = 경계 인터페이스 조사 보고서
+agent-database = 데이터베이스 개요 조사 보고서
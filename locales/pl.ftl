@@ -0,0 +1,40 @@
+# Polish resources, looked up by `TargetLanguage::message`
+
+language-name = Polski
+prompt-instruction = Proszę napisać dokumentację w języku polskim, dbając o precyzyjny, profesjonalny i łatwy do zrozumienia język.
+
+## Output directories and documents
+dir-deep_exploration = 4-Szczegółowa-Eksploracja
+doc-overview = 1-Przegląd-Projektu
+doc-architecture = 2-Przegląd-Architektury
+doc-workflow = 3-Przepływ-Pracy
+doc-boundary = 5-Interfejsy-Graniczne
+doc-database = 6-Przegląd-Bazy-Danych
+doc-dependencies = 7-Zależności
+toc-title = Spis treści
+
+## Console messages
+config-read-error = ⚠️ Ostrzeżenie: Nie można odczytać pliku konfiguracyjnego { $path }, używana jest konfiguracja domyślna
+unknown-provider = ⚠️ Ostrzeżenie: Nieznany dostawca: { $provider }, używany jest dostawca domyślny
+unknown-language = ⚠️ Ostrzeżenie: Nieznany język docelowy: { $language }, używany jest język domyślny (English)
+cache-hit = { "   " }✅ Użycie zbuforowanego wyniku analizy AI: { $tag }
+ai-analyzing = { "   " }🤖 Wykonywanie analizy AI [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ Brak w pamięci podręcznej [{ $category }] - wymagane wnioskowanie AI
+cache-write = { "   " }💾 Zapis do pamięci podręcznej [{ $category }] - wynik zbuforowany
+cache-error = { "   " }❌ Błąd pamięci podręcznej [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 Użycie zbuforowanego wyniku kompresji [{ $content_type }]
+cannot-read-file = Nie można odczytać pliku: { $path }
+doc-not-found = ⚠️ Ostrzeżenie: Nie znaleziono treści dokumentu, klucz: { $key }
+mermaid-error = ⚠️ Wystąpił błąd podczas naprawy diagramu mermaid: { $error }
+summary-reasoning-failed = ⚠️  Wnioskowanie podsumowujące nie powiodło się, zwracany jest oryginalny wynik częściowy...{ $error }
+domain-analysis-failed = ⚠️ Analiza modułów domenowych: analiza { $domain } nie powiodła się: { $error }
+no-code-path-for-domain = ⚠️ Domena '{ $domain }' nie ma powiązanych ścieżek kodu
+
+## Research report titles
+agent-system_context = Raport z badania kontekstu systemu
+agent-domain_modules = Raport z badania modułów domenowych
+agent-architecture = Raport z badania architektury systemu
+agent-workflow = Raport z badania przepływu pracy
+agent-key_modules = Raport z badania kluczowych modułów i komponentów
+agent-boundary = Raport z badania interfejsów granicznych
+agent-database = Raport z badania przeglądu bazy danych
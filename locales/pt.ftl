@@ -0,0 +1,40 @@
+# Portuguese (Brazilian) resources, looked up by `TargetLanguage::message`
+
+language-name = Português
+prompt-instruction = Por favor, escreva a documentação em português do Brasil, garantindo uma linguagem precisa, profissional e fácil de entender.
+
+## Output directories and documents
+dir-deep_exploration = 4-Exploração-Detalhada
+doc-overview = 1-Visão-Geral-do-Projeto
+doc-architecture = 2-Visão-Geral-da-Arquitetura
+doc-workflow = 3-Fluxo-de-Trabalho
+doc-boundary = 5-Interfaces-de-Fronteira
+doc-database = 6-Visão-Geral-do-Banco-de-Dados
+doc-dependencies = 7-Dependências
+toc-title = Conteúdo
+
+## Console messages
+config-read-error = ⚠️ Aviso: Não foi possível ler o arquivo de configuração { $path }, usando a configuração padrão
+unknown-provider = ⚠️ Aviso: Provedor desconhecido: { $provider }, usando o provedor padrão
+unknown-language = ⚠️ Aviso: Idioma de destino desconhecido: { $language }, usando o idioma padrão (English)
+cache-hit = { "   " }✅ Usando resultado de análise de IA em cache: { $tag }
+ai-analyzing = { "   " }🤖 Executando análise de IA [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ Cache não encontrado [{ $category }] - inferência de IA necessária
+cache-write = { "   " }💾 Gravação em cache [{ $category }] - resultado armazenado
+cache-error = { "   " }❌ Erro de cache [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 Usando resultado de compressão em cache [{ $content_type }]
+cannot-read-file = Não foi possível ler o arquivo: { $path }
+doc-not-found = ⚠️ Aviso: Conteúdo do documento não encontrado, chave: { $key }
+mermaid-error = ⚠️ Ocorreu um erro ao reparar o diagrama mermaid: { $error }
+summary-reasoning-failed = ⚠️  Falha no raciocínio de resumo, retornando o resultado parcial original...{ $error }
+domain-analysis-failed = ⚠️ Análise de módulos de domínio: falha na análise de { $domain }: { $error }
+no-code-path-for-domain = ⚠️ O domínio '{ $domain }' não possui caminhos de código associados
+
+## Research report titles
+agent-system_context = Relatório de pesquisa do contexto do sistema
+agent-domain_modules = Relatório de pesquisa dos módulos de domínio
+agent-architecture = Relatório de pesquisa da arquitetura do sistema
+agent-workflow = Relatório de pesquisa do fluxo de trabalho
+agent-key_modules = Relatório de pesquisa dos módulos e componentes principais
+agent-boundary = Relatório de pesquisa das interfaces de fronteira
+agent-database = Relatório de pesquisa da visão geral do banco de dados
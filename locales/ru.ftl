@@ -0,0 +1,40 @@
+# Russian resources, looked up by `TargetLanguage::message`
+
+language-name = Русский
+prompt-instruction = Пожалуйста, напишите документацию на русском языке, обеспечив точность, профессионализм и понятность изложения.
+
+## Output directories and documents
+dir-deep_exploration = 4-Глубокое-Исследование
+doc-overview = 1-Обзор-Проекта
+doc-architecture = 2-Обзор-Архитектуры
+doc-workflow = 3-Рабочий-Процесс
+doc-boundary = 5-Граничные-Интерфейсы
+doc-database = 6-Обзор-Базы-Данных
+doc-dependencies = 7-Зависимости
+toc-title = Содержание
+
+## Console messages
+config-read-error = ⚠️ Предупреждение: Не удается прочитать файл конфигурации { $path }, используется конфигурация по умолчанию
+unknown-provider = ⚠️ Предупреждение: Неизвестный провайдер: { $provider }, используется провайдер по умолчанию
+unknown-language = ⚠️ Предупреждение: Неизвестный целевой язык: { $language }, используется язык по умолчанию (English)
+cache-hit = { "   " }✅ Использование кэшированного результата AI-анализа: { $tag }
+ai-analyzing = { "   " }🤖 Выполнение AI-анализа [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ Промах кэша [{ $category }] - требуется AI-вывод
+cache-write = { "   " }💾 Запись в кэш [{ $category }] - Результат кэширован
+cache-error = { "   " }❌ Ошибка кэша [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 Использование кэшированного результата сжатия [{ $content_type }]
+cannot-read-file = Не удается прочитать файл: { $path }
+doc-not-found = ⚠️ Предупреждение: Содержимое документа не найдено, ключ: { $key }
+mermaid-error = ⚠️ Ошибка при восстановлении диаграммы mermaid: { $error }
+summary-reasoning-failed = ⚠️  Сбой суммирования, возврат исходного частичного результата...{ $error }
+domain-analysis-failed = ⚠️ Анализ доменного модуля: анализ { $domain } не удался: { $error }
+no-code-path-for-domain = ⚠️ Домен '{ $domain }' не имеет связанных путей кода
+
+## Research report titles
+agent-system_context = Отчет об исследовании системного контекста
+agent-domain_modules = Отчет об исследовании доменных модулей
+agent-architecture = Отчет об исследовании системной архитектуры
+agent-workflow = Отчет об исследовании рабочего процесса
+agent-key_modules = Отчет об исследовании ключевых модулей и компонентов
+agent-boundary = Отчет об исследовании граничных интерфейсов
+agent-database = Отчет об исследовании обзора базы данных
@@ -0,0 +1,40 @@
+# Turkish resources, looked up by `TargetLanguage::message`
+
+language-name = Türkçe
+prompt-instruction = Lütfen belgeleri Türkçe olarak yazın; dilin doğru, profesyonel ve kolay anlaşılır olmasına dikkat edin.
+
+## Output directories and documents
+dir-deep_exploration = 4-Derinlemesine-İnceleme
+doc-overview = 1-Proje-Genel-Bakış
+doc-architecture = 2-Mimari-Genel-Bakış
+doc-workflow = 3-İş-Akışı
+doc-boundary = 5-Sınır-Arayüzleri
+doc-database = 6-Veritabanı-Genel-Bakış
+doc-dependencies = 7-Bağımlılıklar
+toc-title = İçindekiler
+
+## Console messages
+config-read-error = ⚠️ Uyarı: { $path } yapılandırma dosyası okunamıyor, varsayılan yapılandırma kullanılıyor
+unknown-provider = ⚠️ Uyarı: Bilinmeyen sağlayıcı: { $provider }, varsayılan sağlayıcı kullanılıyor
+unknown-language = ⚠️ Uyarı: Bilinmeyen hedef dil: { $language }, varsayılan dil (English) kullanılıyor
+cache-hit = { "   " }✅ Önbellekteki yapay zeka analiz sonucu kullanılıyor: { $tag }
+ai-analyzing = { "   " }🤖 Yapay zeka analizi yapılıyor [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ Önbellekte bulunamadı [{ $category }] - yapay zeka çıkarımı gerekli
+cache-write = { "   " }💾 Önbelleğe yazma [{ $category }] - sonuç önbelleğe alındı
+cache-error = { "   " }❌ Önbellek hatası [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 Önbellekteki sıkıştırma sonucu kullanılıyor [{ $content_type }]
+cannot-read-file = Dosya okunamıyor: { $path }
+doc-not-found = ⚠️ Uyarı: Belge içeriği bulunamadı, anahtar: { $key }
+mermaid-error = ⚠️ Mermaid diyagramı onarılırken hata oluştu: { $error }
+summary-reasoning-failed = ⚠️  Özet çıkarımı başarısız oldu, orijinal kısmi sonuç döndürülüyor...{ $error }
+domain-analysis-failed = ⚠️ Alan modülü analizi: { $domain } analizi başarısız oldu: { $error }
+no-code-path-for-domain = ⚠️ '{ $domain }' alanının ilişkili kod yolu yok
+
+## Research report titles
+agent-system_context = Sistem Bağlamı Araştırma Raporu
+agent-domain_modules = Alan Modülleri Araştırma Raporu
+agent-architecture = Sistem Mimarisi Araştırma Raporu
+agent-workflow = İş Akışı Araştırma Raporu
+agent-key_modules = Temel Modüller ve Bileşenler Araştırma Raporu
+agent-boundary = Sınır Arayüzleri Araştırma Raporu
+agent-database = Veritabanı Genel Bakış Araştırma Raporu
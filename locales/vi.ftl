@@ -0,0 +1,40 @@
+# Vietnamese resources, looked up by `TargetLanguage::message`
+
+language-name = Tiếng Việt
+prompt-instruction = Hãy viết toàn bộ tài liệu bằng tiếng Việt tự nhiên, chính xác và dễ hiểu, sử dụng đúng thuật ngữ kỹ thuật.
+
+## Output directories and documents
+dir-deep_exploration = 4-Khám-phá-chi-tiết
+doc-overview = 1-Tổng-quan-Dự-án
+doc-architecture = 2-Kiến-trúc
+doc-workflow = 3-Luồng-xử-lý
+doc-boundary = 5-Lớp-giao-tiếp-biên
+doc-database = 6-Tổng-quan-Cơ-sở-Dữ-liệu
+doc-dependencies = 7-Phụ-thuộc
+toc-title = Mục lục
+
+## Console messages
+config-read-error = ⚠️ Cảnh báo: Không thể đọc tệp cấu hình { $path }, sử dụng cấu hình mặc định
+unknown-provider = ⚠️ Cảnh báo: Nhà cung cấp không xác định: { $provider }, sử dụng nhà cung cấp mặc định
+unknown-language = ⚠️ Cảnh báo: Ngôn ngữ đích không xác định: { $language }, sử dụng ngôn ngữ mặc định (English)
+cache-hit = { "   " }✅ Sử dụng kết quả phân tích AI đã lưu: { $tag }
+ai-analyzing = { "   " }🤖 Đang thực hiện phân tích AI [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ Bỏ lỡ bộ nhớ cache [{ $category }] - Cần suy luận AI
+cache-write = { "   " }💾 Ghi bộ nhớ cache [{ $category }] - Kết quả đã được lưu
+cache-error = { "   " }❌ Lỗi bộ nhớ cache [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 Sử dụng kết quả nén đã lưu [{ $content_type }]
+cannot-read-file = Không thể đọc tệp: { $path }
+doc-not-found = ⚠️ Cảnh báo: Không tìm thấy nội dung tài liệu, khóa: { $key }
+mermaid-error = ⚠️ Lỗi xảy ra trong quá trình sửa chữa sơ đồ mermaid: { $error }
+summary-reasoning-failed = ⚠️  Suy luận tóm tắt thất bại, trả về kết quả một phần ban đầu...{ $error }
+domain-analysis-failed = ⚠️ Phân tích mô-đun miền: phân tích { $domain } thất bại: { $error }
+no-code-path-for-domain = ⚠️ Miền '{ $domain }' không có đường dẫn mã liên kết
+
+## Research report titles
+agent-system_context = Báo cáo nghiên cứu ngữ cảnh hệ thống
+agent-domain_modules = Báo cáo nghiên cứu mô-đun miền
+agent-architecture = Báo cáo nghiên cứu kiến trúc hệ thống
+agent-workflow = Báo cáo nghiên cứu quy trình làm việc
+agent-key_modules = Báo cáo nghiên cứu mô-đun và thành phần chính
+agent-boundary = Báo cáo nghiên cứu giao diện biên
+agent-database = Báo cáo nghiên cứu tổng quan cơ sở dữ liệu
@@ -0,0 +1,40 @@
+# Chinese resources, looked up by `TargetLanguage::message`
+
+language-name = 中文
+prompt-instruction = 请使用中文编写文档，确保语言表达准确、专业、易于理解。
+
+## Output directories and documents
+dir-deep_exploration = 4、深入探索
+doc-overview = 1、项目概述
+doc-architecture = 2、架构概览
+doc-workflow = 3、工作流程
+doc-boundary = 5、边界调用
+doc-database = 6、数据库概览
+doc-dependencies = 7、依赖清单
+toc-title = 目录
+
+## Console messages
+config-read-error = ⚠️ 警告: 无法读取配置文件 { $path }，使用默认配置
+unknown-provider = ⚠️ 警告: 未知的provider: { $provider }，使用默认provider
+unknown-language = ⚠️ 警告: 未知的目标语言: { $language }，使用默认语言 (English)
+cache-hit = { "   " }✅ 使用缓存的AI分析结果: { $tag }
+ai-analyzing = { "   " }🤖 正在进行AI分析 [{ $current }/{ $total }]: { $tag }
+cache-miss = { "   " }⌛ 缓存未命中 [{ $category }] - 需要进行AI推理
+cache-write = { "   " }💾 缓存写入 [{ $category }] - 结果已缓存
+cache-error = { "   " }❌ 缓存错误 [{ $category }]: { $error }
+cache-compression-hit = { "   " }💾 使用缓存的压缩结果 [{ $content_type }]
+cannot-read-file = 无法读取文件: { $path }
+doc-not-found = ⚠️ 警告: 未找到文档内容，键: { $key }
+mermaid-error = ⚠️ mermaid图表修复过程中出现错误: { $error }
+summary-reasoning-failed = ⚠️  总结推理失败，返回原始部分结果...{ $error }
+domain-analysis-failed = ⚠️ 领域模块分析：{ $domain } 分析失败: { $error }
+no-code-path-for-domain = ⚠️ 领域'{ $domain }'没有关联的代码路径
+
+## Research report titles
+agent-system_context = 项目概览调研报告
+agent-domain_modules = 领域模块调研报告
+agent-architecture = 系统架构调研报告
+agent-workflow = 工作流调研报告
+agent-key_modules = 核心模块与组件调研报告
+agent-boundary = 边界接口调研报告
+agent-database = 数据库概览调研报告
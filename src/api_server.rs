@@ -0,0 +1,497 @@
+//! REST API server turning Litho into a documentation service
+//!
+//! Jobs are submitted as JSON (a local project path or a git repository URL, plus config
+//! overrides), run one at a time in submission order, and their output is downloaded as a
+//! zip bundle:
+//!
+//! - `POST /jobs` - submit a job, answers `202` with its id
+//! - `GET /jobs` - all jobs with their status
+//! - `GET /jobs/{id}` - status of a job
+//! - `GET /jobs/{id}/bundle` - the generated documentation as a zip archive
+//! - `GET /metrics` - LLM calls, latencies, tokens, cache hit rate, failures and jobs in the
+//!   Prometheus text format
+//! - `GET /health` - liveness probe, never requires the token
+//!
+//! Jobs may only override the settings in `ALLOWED_OVERRIDES`, so a caller can neither read
+//! the LLM credentials nor turn off redaction, and local projects must sit under one of the
+//! configured `project_roots`.
+
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{Sender, channel};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, info, warn};
+use walkdir::WalkDir;
+
+use crate::config::Config;
+use crate::generator::outlet::RunStatus;
+use crate::generator::workflow::launch;
+use crate::http::{Request, Response, read_request};
+
+/// Settings a job request may override, as dotted paths into the configuration
+const ALLOWED_OVERRIDES: [&str; 5] = [
+    "target_language",
+    "llm.model_efficient",
+    "llm.model_powerful",
+    "llm.temperature",
+    "llm.max_tokens",
+];
+
+/// Lifecycle of a generation job
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobState {
+    Queued,
+    Running,
+    Succeeded,
+    /// Documents were generated, but some steps failed (see the bundled run-summary.json)
+    PartialFailure,
+    Failed,
+}
+
+/// Generation job as reported by the API
+#[derive(Debug, Clone, Serialize)]
+pub struct Job {
+    pub id: String,
+    pub state: JobState,
+    /// Project path or repository URL
+    pub source: String,
+    pub submitted_at: String,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+    pub error: Option<String>,
+    #[serde(skip)]
+    output_path: PathBuf,
+}
+
+/// Body of `POST /jobs`
+#[derive(Debug, Deserialize)]
+struct JobRequest {
+    /// Project directory on the server
+    project_path: Option<PathBuf>,
+    /// Git repository cloned for the job
+    repository: Option<String>,
+    /// Overrides of the server configuration, with the structure of litho.toml
+    #[serde(default)]
+    config: Value,
+}
+
+struct QueuedJob {
+    id: String,
+    config: Config,
+    repository: Option<String>,
+}
+
+struct Shared {
+    config: Config,
+    jobs: Mutex<BTreeMap<String, Job>>,
+    queue: Mutex<Sender<QueuedJob>>,
+    token: Option<String>,
+}
+
+/// HTTP server accepting documentation generation jobs
+pub struct ApiServer {
+    shared: Arc<Shared>,
+}
+
+impl ApiServer {
+    /// Server for the base configuration; jobs run on a dedicated worker thread
+    pub fn new(config: Config) -> Self {
+        let token = config
+            .api
+            .token_env
+            .as_ref()
+            .and_then(|name| std::env::var(name).ok())
+            .filter(|token| !token.is_empty());
+        let (sender, receiver) = channel::<QueuedJob>();
+        let shared = Arc::new(Shared {
+            config,
+            jobs: Mutex::new(BTreeMap::new()),
+            queue: Mutex::new(sender),
+            token,
+        });
+
+        let worker = shared.clone();
+        std::thread::spawn(move || {
+            let runtime = match tokio::runtime::Runtime::new() {
+                Ok(runtime) => runtime,
+                Err(e) => {
+                    warn!("⚠️ Cannot start the job runtime: {}", e);
+                    return;
+                }
+            };
+            for job in receiver {
+                worker.run_job(&runtime, job);
+            }
+        });
+
+        Self { shared }
+    }
+
+    /// Accept connections until the process is stopped
+    pub async fn serve(&self, bind: Option<String>) -> Result<()> {
+        let bind = bind.unwrap_or_else(|| self.shared.config.api.bind.clone());
+        let listener = TcpListener::bind(&bind)
+            .await
+            .with_context(|| format!("Cannot listen on {}", bind))?;
+        if self.shared.token.is_none() && !listener.local_addr()?.ip().is_loopback() {
+            return Err(anyhow!(
+                "Refusing to serve on {} without an API token, set [api] token_env or bind to a loopback address",
+                bind
+            ));
+        }
+        info!(
+            "🌐 Litho API server listening on http://{} (jobs: {}, token: {})",
+            bind,
+            self.shared.config.api.work_dir.display(),
+            if self.shared.token.is_some() { "required" } else { "none" }
+        );
+        if self.shared.token.is_none() {
+            warn!("⚠️ No API token configured, any local process reaching {} can submit jobs", bind);
+        }
+
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            let shared = self.shared.clone();
+            tokio::spawn(async move {
+                if let Err(e) = shared.handle_connection(stream).await {
+                    debug!("API connection from {} failed: {:#}", peer, e);
+                }
+            });
+        }
+    }
+}
+
+impl Shared {
+    async fn handle_connection(&self, mut stream: TcpStream) -> Result<()> {
+        let (reader, mut writer) = stream.split();
+        let response = match read_request(&mut BufReader::new(reader)).await {
+            Ok(request) => self.route(request),
+            Err(e) => Response::error(400, &format!("{:#}", e)),
+        };
+        writer.write_all(&response.into_bytes()).await?;
+        writer.flush().await?;
+        Ok(())
+    }
+
+    fn route(&self, request: Request) -> Response {
+        debug!("API request: {} {}", request.method, request.path);
+        let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+        if segments == ["health"] {
+            return Response::json(200, json!({ "status": "ok" }));
+        }
+        if let Some(token) = &self.token {
+            let authorized = request
+                .headers
+                .get("authorization")
+                .and_then(|value| value.strip_prefix("Bearer "))
+                .is_some_and(|given| constant_time_eq(given.as_bytes(), token.as_bytes()));
+            if !authorized {
+                return Response::error(401, "Missing or invalid bearer token");
+            }
+        }
+
+        match (request.method.as_str(), segments.as_slice()) {
+            ("POST", ["jobs"]) => match self.submit(&request.body) {
+                Ok(job) => Response::json(202, json!(job)),
+                Err(e) => Response::error(400, &format!("{:#}", e)),
+            },
+            ("GET", ["jobs"]) => {
+                let jobs: Vec<Job> = self.jobs.lock().unwrap().values().cloned().collect();
+                Response::json(200, json!({ "jobs": jobs }))
+            }
+            ("GET", ["jobs", id]) => match self.jobs.lock().unwrap().get(*id) {
+                Some(job) => Response::json(200, json!(job)),
+                None => Response::error(404, "Job not found"),
+            },
+            ("GET", ["jobs", id, "bundle"]) => self.bundle(id),
+            ("GET", ["metrics"]) => Response {
+                status: 200,
+                content_type: "text/plain; version=0.0.4; charset=utf-8",
+                headers: Vec::new(),
+                body: self.metrics().into_bytes(),
+            },
+            _ => Response::error(404, "Not found"),
+        }
+    }
+
+    fn submit(&self, body: &[u8]) -> Result<Job> {
+        let request: JobRequest = serde_json::from_slice(body).context("Invalid job request")?;
+        let id = uuid::Uuid::new_v4().to_string();
+        let job_dir = self.config.api.work_dir.join(&id);
+        let (source, project_path) = match (&request.project_path, &request.repository) {
+            (Some(path), None) => {
+                let path = project_path(&self.config.api.project_roots, path)?;
+                (path.display().to_string(), Some(path))
+            }
+            (None, Some(repository)) if is_remote_repository(repository) => (repository.clone(), None),
+            (None, Some(repository)) => return Err(anyhow!("Repository {} is not an https or ssh URL", repository)),
+            _ => return Err(anyhow!("Give either project_path or repository")),
+        };
+        let config = job_config(&self.config, &request.config, project_path, &job_dir)?;
+
+        let job = Job {
+            id: id.clone(),
+            state: JobState::Queued,
+            source,
+            submitted_at: chrono::Utc::now().to_rfc3339(),
+            started_at: None,
+            finished_at: None,
+            error: None,
+            output_path: config.output_path.clone(),
+        };
+        self.jobs.lock().unwrap().insert(id.clone(), job.clone());
+        self.queue
+            .lock()
+            .unwrap()
+            .send(QueuedJob {
+                id,
+                config,
+                repository: request.repository,
+            })
+            .map_err(|_| anyhow!("The job worker has stopped"))?;
+        info!("📥 Job {} queued for {}", job.id, job.source);
+        Ok(job)
+    }
+
+    fn run_job(&self, runtime: &tokio::runtime::Runtime, job: QueuedJob) {
+        self.update(&job.id, |state| {
+            state.state = JobState::Running;
+            state.started_at = Some(chrono::Utc::now().to_rfc3339());
+        });
+        info!("🚀 Job {} started", job.id);
+
+        let result = match &job.repository {
+            Some(repository) => clone_repository(repository, &job.config.project_path),
+            None => Ok(()),
+        }
+        .and_then(|_| runtime.block_on(launch(&job.config)));
+
+        let (state, error) = match result {
+            Ok(RunStatus::Success) => (JobState::Succeeded, None),
+            Ok(RunStatus::PartialFailure) => (JobState::PartialFailure, None),
+            Ok(RunStatus::Failure) => (JobState::Failed, None),
+            Err(e) => (JobState::Failed, Some(format!("{:#}", e))),
+        };
+        info!("🏁 Job {} finished: {:?}", job.id, state);
+        self.update(&job.id, |job| {
+            job.state = state;
+            job.error = error;
+            job.finished_at = Some(chrono::Utc::now().to_rfc3339());
+        });
+    }
+
+    fn update(&self, id: &str, change: impl FnOnce(&mut Job)) {
+        if let Some(job) = self.jobs.lock().unwrap().get_mut(id) {
+            change(job);
+        }
+    }
+
+    /// Process metrics followed by the number of jobs per state
+    fn metrics(&self) -> String {
+        let mut text = crate::metrics::metrics().render();
+        text.push_str("# HELP litho_jobs Generation jobs submitted to this server, by state\n# TYPE litho_jobs gauge\n");
+        let jobs = self.jobs.lock().unwrap();
+        for state in [
+            JobState::Queued,
+            JobState::Running,
+            JobState::Succeeded,
+            JobState::PartialFailure,
+            JobState::Failed,
+        ] {
+            let count = jobs.values().filter(|job| job.state == state).count();
+            text.push_str(&format!("litho_jobs{{state=\"{}\"}} {}\n", json!(state).as_str().unwrap_or_default(), count));
+        }
+        text
+    }
+
+    fn bundle(&self, id: &str) -> Response {
+        let Some(job) = self.jobs.lock().unwrap().get(id).cloned() else {
+            return Response::error(404, "Job not found");
+        };
+        if !matches!(job.state, JobState::Succeeded | JobState::PartialFailure) {
+            return Response::error(409, &format!("Job is {:?}, no bundle available", job.state));
+        }
+        match zip_directory(&job.output_path) {
+            Ok(bytes) => Response {
+                status: 200,
+                content_type: "application/zip",
+                headers: vec![(
+                    "Content-Disposition".to_string(),
+                    format!("attachment; filename=\"litho-docs-{}.zip\"", job.id),
+                )],
+                body: bytes,
+            },
+            Err(e) => Response::error(500, &format!("{:#}", e)),
+        }
+    }
+}
+
+/// Configuration of a job: the server configuration with the request overrides merged in,
+/// writing into the job directory
+fn job_config(base: &Config, overrides: &Value, project_path: Option<PathBuf>, job_dir: &Path) -> Result<Config> {
+    let mut paths = Vec::new();
+    override_paths(overrides, String::new(), &mut paths);
+    if let Some(path) = paths.iter().find(|path| !ALLOWED_OVERRIDES.contains(&path.as_str())) {
+        return Err(anyhow!(
+            "Config override `{}` is not allowed, jobs may only override {}",
+            path,
+            ALLOWED_OVERRIDES.join(", ")
+        ));
+    }
+    let mut value = serde_json::to_value(base)?;
+    merge(&mut value, overrides.clone());
+    let mut config: Config = serde_json::from_value(value).context("Invalid config overrides")?;
+
+    config.project_path = project_path.unwrap_or_else(|| job_dir.join("repository"));
+    config.output_path = job_dir.join("litho.docs");
+    config.internal_path = job_dir.join(".litho");
+    config.resume = false;
+    Ok(config)
+}
+
+/// Dotted paths of the values set by the overrides
+fn override_paths(overrides: &Value, prefix: String, paths: &mut Vec<String>) {
+    match overrides {
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                let path = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                override_paths(value, path, paths);
+            }
+        }
+        Value::Null => {}
+        _ => paths.push(prefix),
+    }
+}
+
+/// Canonical project directory of a job, which must lie under one of the project roots
+fn project_path(roots: &[PathBuf], path: &Path) -> Result<PathBuf> {
+    let canonical = path
+        .canonicalize()
+        .map_err(|_| anyhow!("Project path {} is not a directory", path.display()))?;
+    if !canonical.is_dir() {
+        return Err(anyhow!("Project path {} is not a directory", path.display()));
+    }
+    let allowed = roots
+        .iter()
+        .filter_map(|root| root.canonicalize().ok())
+        .any(|root| canonical.starts_with(root));
+    if !allowed {
+        return Err(anyhow!("Project path {} is outside the configured [api] project_roots", path.display()));
+    }
+    Ok(canonical)
+}
+
+/// Whether a repository is cloned over the network rather than from the server's disk
+fn is_remote_repository(repository: &str) -> bool {
+    ["https://", "ssh://", "git@"].iter().any(|scheme| repository.starts_with(scheme))
+}
+
+/// Compare two secrets in a time independent of where they differ
+fn constant_time_eq(given: &[u8], expected: &[u8]) -> bool {
+    let mut difference = given.len() ^ expected.len();
+    for (index, byte) in expected.iter().enumerate() {
+        difference |= (given.get(index).copied().unwrap_or(0) ^ byte) as usize;
+    }
+    difference == 0
+}
+
+/// Merge the overrides into the value, object by object
+fn merge(value: &mut Value, overrides: Value) {
+    match (value, overrides) {
+        (Value::Object(target), Value::Object(overrides)) => {
+            for (key, override_value) in overrides {
+                merge(target.entry(key).or_insert(Value::Null), override_value);
+            }
+        }
+        (target, Value::Null) if !target.is_null() => {}
+        (target, override_value) => *target = override_value,
+    }
+}
+
+fn clone_repository(repository: &str, destination: &Path) -> Result<()> {
+    info!("📦 Cloning {}...", repository);
+    let output = Command::new("git")
+        .args(["clone", "--depth", "1", "--", repository])
+        .arg(destination)
+        .output()
+        .context("Cannot run git")?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "git clone {} failed: {}",
+            repository,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+/// Zip archive of the files under the directory, with paths relative to it
+fn zip_directory(dir: &Path) -> Result<Vec<u8>> {
+    let mut archive = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    for entry in WalkDir::new(dir).into_iter().flatten().filter(|entry| entry.file_type().is_file()) {
+        let relative = entry.path().strip_prefix(dir)?;
+        let name = relative.components().map(|c| c.as_os_str().to_string_lossy()).collect::<Vec<_>>().join("/");
+        archive.start_file(name, options)?;
+        archive.write_all(&std::fs::read(entry.path())?)?;
+    }
+    Ok(archive.finish()?.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_job_request_and_config() {
+        let body = r#"{"repository": "https://example.com/shop.git", "config": {"target_language": "zh", "llm": {"model_efficient": "small"}}}"#;
+        let raw = format!(
+            "POST /jobs?wait=false HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nAuthorization: Bearer secret\r\n\r\n{}",
+            body.len(),
+            body
+        );
+
+        let request = read_request(&mut BufReader::new(raw.as_bytes())).await.unwrap();
+        assert_eq!((request.method.as_str(), request.path.as_str()), ("POST", "/jobs"));
+        assert_eq!(request.headers["authorization"], "Bearer secret");
+
+        let job_request: JobRequest = serde_json::from_slice(&request.body).unwrap();
+        let base = Config::default();
+        let config = job_config(&base, &job_request.config, None, Path::new("/jobs/1")).unwrap();
+        assert_eq!(config.target_language, crate::i18n::TargetLanguage::Chinese);
+        assert_eq!(config.llm.model_efficient, "small");
+        assert_eq!(config.llm.model_powerful, base.llm.model_powerful);
+        assert_eq!(config.project_path, PathBuf::from("/jobs/1/repository"));
+        assert_eq!(config.output_path, PathBuf::from("/jobs/1/litho.docs"));
+
+        // Credentials, endpoints and redaction stay as the server configured them
+        for overrides in [
+            json!({"llm": {"api_base_url": "https://attacker.example"}}),
+            json!({"redaction": {"enabled": false}}),
+            json!({"notifications": {"webhooks": []}}),
+            json!({"project_path": "/etc"}),
+        ] {
+            assert!(job_config(&base, &overrides, None, Path::new("/jobs/1")).is_err());
+        }
+
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        std::fs::create_dir_all(root.join("shop")).unwrap();
+        let roots = [root.clone()];
+        assert_eq!(project_path(&roots, &root.join("shop")).unwrap(), root.join("shop").canonicalize().unwrap());
+        assert!(project_path(&roots, &root.join("shop/../..")).is_err());
+        assert!(project_path(&[], &root.join("shop")).is_err());
+
+        assert!(is_remote_repository("https://example.com/shop.git") && !is_remote_repository("file:///srv/shop"));
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secre") && !constant_time_eq(b"secreT", b"secret"));
+    }
+}
@@ -0,0 +1,261 @@
+//! Questions answered from the generated knowledge base
+//!
+//! Retrieves the sections of the generated documents (and, with `[knowledge.retrieval]`, the
+//! code insights of the vector index) most relevant to a question, and has the configured LLM
+//! answer from them only, citing the documents it used.
+
+use anyhow::Result;
+use std::io::Write;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tracing::warn;
+
+use crate::config::Config;
+use crate::generator::outlet::IndexOutlet;
+use crate::integrations::retrieval::EmbeddingClient;
+use crate::integrations::vector_index::{IndexKind, VectorIndex};
+use crate::llm::client::LLMClient;
+
+/// Characters of a passage sent to the model
+const MAX_PASSAGE_CHARS: usize = 3000;
+/// Earlier questions and answers kept for follow-up questions
+const MAX_HISTORY: usize = 3;
+
+/// Section of the knowledge base retrieved for a query
+#[derive(Debug, Clone)]
+pub struct Passage {
+    pub title: String,
+    /// Document or source file the passage comes from
+    pub source: String,
+    pub kind: &'static str,
+    /// Similarity score or keyword matches, for display
+    pub relevance: String,
+    pub text: String,
+}
+
+/// Passages most relevant to the query: semantic search over the vector index when
+/// retrieval is enabled, keyword search over the generated documents otherwise
+pub async fn retrieve(config: &Config, query: &str, limit: usize) -> Vec<Passage> {
+    if let Some(passages) = semantic_search(config, query, limit).await {
+        return passages;
+    }
+
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .map(|term| term.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase())
+        .filter(|term| !term.is_empty())
+        .collect();
+    let mut scored: Vec<(usize, Passage)> = IndexOutlet::document_items(&config.output_path)
+        .into_iter()
+        .map(|item| {
+            let text = item.text.to_lowercase();
+            let score: usize = terms.iter().map(|term| text.matches(term.as_str()).count()).sum();
+            let passage = Passage {
+                title: item.title,
+                source: item.source,
+                kind: kind_label(IndexKind::Document),
+                relevance: format!("{} matches", score),
+                text: item.text,
+            };
+            (score, passage)
+        })
+        .filter(|(score, _)| *score > 0)
+        .collect();
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored.into_iter().take(limit).map(|(_, passage)| passage).collect()
+}
+
+async fn semantic_search(config: &Config, query: &str, limit: usize) -> Option<Vec<Passage>> {
+    let retrieval = config.knowledge.retrieval.as_ref().filter(|r| r.enabled)?;
+    let index = VectorIndex::load(&VectorIndex::default_dir(&config.internal_path))?;
+    let client = EmbeddingClient::new(retrieval, &config.llm);
+    if index.model() != client.model() {
+        warn!("⚠️  Vector index was built with another embedding model, using keyword search");
+        return None;
+    }
+
+    let query_vector = match client.embed(&[query.to_string()]).await {
+        Ok(mut vectors) => vectors.pop()?,
+        Err(e) => {
+            warn!("⚠️  Failed to embed search query, using keyword search: {:#}", e);
+            return None;
+        }
+    };
+
+    let hits = index.search(&query_vector, limit, &[]);
+    if hits.is_empty() {
+        return None;
+    }
+    Some(
+        hits.into_iter()
+            .map(|hit| Passage {
+                title: hit.entry.title.clone(),
+                source: hit.entry.source.clone(),
+                kind: kind_label(hit.entry.kind),
+                relevance: format!("score {:.2}", hit.score),
+                text: hit.entry.text.clone(),
+            })
+            .collect(),
+    )
+}
+
+pub fn kind_label(kind: IndexKind) -> &'static str {
+    match kind {
+        IndexKind::Knowledge => "knowledge",
+        IndexKind::CodeInsight => "code insight",
+        IndexKind::Interface => "interface",
+        IndexKind::Document => "document",
+    }
+}
+
+/// Answer of the model with the passages it was given
+pub struct Answer {
+    pub text: String,
+    pub passages: Vec<Passage>,
+}
+
+/// Conversation over the knowledge base; earlier answers give context to follow-up questions
+pub struct AskSession {
+    config: Config,
+    llm_client: LLMClient,
+    limit: usize,
+    history: Vec<(String, String)>,
+}
+
+impl AskSession {
+    pub fn new(config: Config, limit: usize) -> Result<Self> {
+        let llm_client = LLMClient::new(config.clone(), Default::default())?;
+        Ok(Self {
+            config,
+            llm_client,
+            limit,
+            history: Vec::new(),
+        })
+    }
+
+    /// Answer one question, or read questions from stdin until an empty line or `exit`
+    pub async fn run(&mut self, question: Option<String>) -> Result<()> {
+        if let Some(question) = question {
+            let answer = self.ask(&question).await?;
+            println!("{}", format_answer(&answer));
+            return Ok(());
+        }
+
+        println!("Ask about {} (empty line or `exit` to quit)", self.config.get_project_name());
+        let mut lines = BufReader::new(tokio::io::stdin()).lines();
+        loop {
+            print!("\n> ");
+            std::io::stdout().flush()?;
+            let Some(line) = lines.next_line().await? else {
+                break;
+            };
+            let question = line.trim();
+            if question.is_empty() || matches!(question, "exit" | "quit") {
+                break;
+            }
+            match self.ask(question).await {
+                Ok(answer) => println!("\n{}", format_answer(&answer)),
+                Err(e) => eprintln!("❌ {:#}", e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Answer a question from the passages retrieved for it
+    pub async fn ask(&mut self, question: &str) -> Result<Answer> {
+        let passages = retrieve(&self.config, question, self.limit).await;
+        if passages.is_empty() {
+            return Ok(Answer {
+                text: format!(
+                    "The generated documentation in {} has nothing on this question.",
+                    self.config.output_path.display()
+                ),
+                passages,
+            });
+        }
+
+        let system_prompt = format!(
+            "You answer questions about the software project {} using only the documentation excerpts provided. \
+             Cite the excerpts you rely on as [1], [2], ... after the statements they support. \
+             When the excerpts do not answer the question, say so instead of guessing.\n\n{}",
+            self.config.get_project_name(),
+            self.config.target_language.prompt_instruction()
+        );
+        let user_prompt = build_user_prompt(question, &passages, &self.history);
+        let text = self.llm_client.prompt_without_react(&system_prompt, &user_prompt).await?;
+
+        self.history.push((question.to_string(), text.clone()));
+        if self.history.len() > MAX_HISTORY {
+            self.history.remove(0);
+        }
+        Ok(Answer { text, passages })
+    }
+}
+
+fn build_user_prompt(question: &str, passages: &[Passage], history: &[(String, String)]) -> String {
+    let mut prompt = String::from("## Documentation excerpts\n\n");
+    for (number, passage) in passages.iter().enumerate() {
+        let text = match passage.text.char_indices().nth(MAX_PASSAGE_CHARS) {
+            Some((end, _)) => format!("{}…", &passage.text[..end]),
+            None => passage.text.clone(),
+        };
+        prompt.push_str(&format!(
+            "[{}] {} - {} ({})\n{}\n\n",
+            number + 1,
+            passage.source,
+            passage.title,
+            passage.kind,
+            text.trim()
+        ));
+    }
+    if !history.is_empty() {
+        prompt.push_str("## Earlier questions\n\n");
+        for (earlier, answer) in history {
+            prompt.push_str(&format!("Q: {}\nA: {}\n\n", earlier, answer.trim()));
+        }
+    }
+    prompt.push_str(&format!("## Question\n\n{}", question));
+    prompt
+}
+
+/// The answer followed by the sources it was grounded in
+fn format_answer(answer: &Answer) -> String {
+    let mut text = answer.text.trim().to_string();
+    if !answer.passages.is_empty() {
+        text.push_str("\n\nSources:");
+        for (number, passage) in answer.passages.iter().enumerate() {
+            text.push_str(&format!("\n  [{}] {} - {}", number + 1, passage.source, passage.title));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_retrieve_and_prompt() {
+        let temp = tempfile::tempdir().unwrap();
+        let output_path = temp.path().to_path_buf();
+        std::fs::create_dir_all(&output_path).unwrap();
+        std::fs::write(
+            output_path.join("2.Architecture.md"),
+            "# Architecture\n\n## Payments\nRefunds are issued by the payment gateway.\n\n## Catalog\nProducts and prices.",
+        )
+        .unwrap();
+        let config = Config {
+            output_path: output_path.clone(),
+            ..Default::default()
+        };
+
+        let passages = retrieve(&config, "How are refunds issued?", 5).await;
+        assert_eq!(passages.len(), 1);
+        assert_eq!((passages[0].source.as_str(), passages[0].title.as_str()), ("2.Architecture.md", "Payments"));
+
+        let history = vec![("Who owns payments?".to_string(), "The billing team [1].".to_string())];
+        let prompt = build_user_prompt("How are refunds issued?", &passages, &history);
+        assert!(prompt.contains("[1] 2.Architecture.md - Payments (document)\nPayments\nRefunds are issued"));
+        assert!(prompt.contains("Q: Who owns payments?\nA: The billing team [1]."));
+        assert!(prompt.ends_with("## Question\n\nHow are refunds issued?"));
+    }
+}
@@ -6,6 +6,7 @@ use std::time::Duration;
 
 use crate::i18n::TargetLanguage;
 use crate::llm::client::types::TokenUsage;
+use tracing::{debug, info, warn};
 
 /// Cache performance monitor
 #[derive(Clone)]
@@ -91,6 +92,7 @@ impl CachePerformanceMonitor {
         model_name: &str,
     ) {
         self.metrics.cache_hits.fetch_add(1, Ordering::Relaxed);
+        crate::metrics::metrics().record_cache_lookup(true);
         self.metrics
             .total_inference_time_saved
             .fetch_add(inference_time_saved.as_millis() as u64, Ordering::Relaxed);
@@ -121,30 +123,29 @@ impl CachePerformanceMonitor {
                 category, inference_time_saved.as_secs_f64(), token_usage.input_tokens, token_usage.output_tokens, estimated_cost_saved
             ),
         };
-        println!("{}", msg);
+        info!("{}", msg);
     }
 
     /// Record cache miss
     pub fn record_cache_miss(&self, category: &str) {
         self.metrics.cache_misses.fetch_add(1, Ordering::Relaxed);
-        let msg = self.target_language.msg_cache_miss().replace("{}", category);
-        println!("{}", msg);
+        crate::metrics::metrics().record_cache_lookup(false);
+        let msg = self.target_language.msg_cache_miss(category);
+        debug!("{}", msg);
     }
 
     /// Record cache write
     pub fn record_cache_write(&self, category: &str) {
         self.metrics.cache_writes.fetch_add(1, Ordering::Relaxed);
-        let msg = self.target_language.msg_cache_write().replace("{}", category);
-        println!("{}", msg);
+        let msg = self.target_language.msg_cache_write(category);
+        debug!("{}", msg);
     }
 
     /// Record cache error
     pub fn record_cache_error(&self, category: &str, error: &str) {
         self.metrics.cache_errors.fetch_add(1, Ordering::Relaxed);
-        let msg = self.target_language.msg_cache_error()
-            .replace("{}", category)
-            .replacen("{}", error, 1);
-        eprintln!("{}", msg);
+        let msg = self.target_language.msg_cache_error(category, error);
+        warn!("{}", msg);
     }
 
     /// Generate performance report
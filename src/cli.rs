@@ -1,7 +1,10 @@
 use crate::config::{Config, LLMProvider};
 use crate::i18n::TargetLanguage;
-use clap::{Parser, Subcommand};
+use crate::utils::logging::LogFormat;
+use crate::utils::paths;
+use clap::{Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
+use tracing::warn;
 
 /// DeepWiki-RS - Project knowledge base generation engine powered by Rust and AI
 #[derive(Parser, Debug)]
@@ -15,7 +18,7 @@ pub struct Args {
     #[command(subcommand)]
     pub command: Option<Commands>,
 
-    /// Project path
+    /// Project path: a directory, or a .zip, .tar, .tar.gz or .tgz archive unpacked to a temporary workspace
     #[arg(short, long, default_value = ".")]
     pub project_path: PathBuf,
 
@@ -23,6 +26,18 @@ pub struct Args {
     #[arg(short, long, default_value = "./litho.docs")]
     pub output_path: PathBuf,
 
+    /// Document a branch, tag or commit, checked out to a temporary worktree, instead of the working tree
+    #[arg(long = "ref", value_name = "REF")]
+    pub git_ref: Option<String>,
+
+    /// Write the run to a version directory of the output path, listed in versions.json and index.md
+    #[arg(long)]
+    pub versioned: bool,
+
+    /// Name of the version directory (implies --versioned; default: vYYYYMMDD-COMMIT with the short commit hash)
+    #[arg(long, value_name = "LABEL")]
+    pub version_label: Option<String>,
+
     /// Configuration file path
     #[arg(short, long)]
     pub config: Option<PathBuf>,
@@ -43,9 +58,17 @@ pub struct Args {
     #[arg(long)]
     pub skip_documentation: bool,
 
-    /// Enable verbose logging
-    #[arg(short, long)]
-    pub verbose: bool,
+    /// Increase log verbosity (-v: prompt sizes, cache decisions and retry details, -vv: everything)
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    /// Only print errors, suitable for CI logs
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Log output format (text, json)
+    #[arg(long, value_enum, default_value_t = LogFormat::Text, global = true)]
+    pub log_format: LogFormat,
 
     /// High-efficiency model, prioritized for Litho engine's regular inference tasks
     #[arg(long)]
@@ -83,10 +106,18 @@ pub struct Args {
     #[arg(long)]
     pub llm_provider: Option<String>,
 
-    /// Target language (zh, en, ja, ko, de, fr, ru, vi)
+    /// Only analyze directories matching this glob (relative to the project path), repeatable
+    #[arg(long = "include-dir")]
+    pub included_dirs: Vec<String>,
+
+    /// Target language (zh, en, ja, ko, de, fr, ru, vi, es, pt, it, ar, he, hi, tr, pl)
     #[arg(long)]
     pub target_language: Option<String>,
 
+    /// Language of console messages, if different from the target language
+    #[arg(long)]
+    pub ui_language: Option<String>,
+
     /// Auto use report assistant to view report after generation
     #[arg(long, default_value = "false", action = clap::ArgAction::SetTrue)]
     pub disable_preset_tools: bool,
@@ -95,10 +126,23 @@ pub struct Args {
     #[arg(long)]
     pub no_cache: bool,
 
-    /// Force regeneration (clear cache)
+    /// Force regeneration (summarize every directory again instead of reusing unchanged ones)
     #[arg(long)]
     pub force_regenerate: bool,
 
+    /// Resume an interrupted run from the memory snapshot in .litho/memory
+    #[arg(long)]
+    pub resume: bool,
+
+    /// Run unattended in a pipeline: plain log lines on stderr without progress bars or emoji, temperature 0,
+    /// a failure threshold (--max-failures) and the run summary printed to stdout
+    #[arg(long)]
+    pub ci: bool,
+
+    /// With --ci, failed steps plus model fallbacks tolerated before the run fails (default: `[ci] max_failures`, 0)
+    #[arg(long, requires = "ci")]
+    pub max_failures: Option<usize>,
+
     /// Code insights limit for boundary analysis
     #[arg(long)]
     pub boundary_code_limit: Option<usize>,
@@ -125,21 +169,150 @@ pub enum Commands {
         #[arg(long)]
         force: bool,
     },
+
+    /// Serve the generated documentation as MCP tools over stdio (search, get_document, get_module_insight)
+    McpServe,
+
+    /// Ask questions about the project, answered from the generated documentation with cited sources
+    Ask {
+        /// Question to answer; without it, questions are read interactively
+        question: Option<String>,
+
+        /// Documentation sections retrieved per question
+        #[arg(long, default_value_t = 6)]
+        limit: usize,
+    },
+
+    /// Browse the generated documentation in a local web server, with rendered diagrams and search
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:3000")]
+        bind: String,
+    },
+
+    /// Serve a REST API to submit generation jobs, poll their status and download the output bundle
+    ServeApi {
+        /// Address to listen on (default: `[api] bind`, 127.0.0.1:8080)
+        #[arg(long)]
+        bind: Option<String>,
+    },
+
+    /// Inspect the memory snapshot of the last run: list scopes and keys, print an entry or export everything
+    Memory {
+        /// Entry to pretty-print, as `<scope>:<key>` or a key found in a single scope (e.g. "Domain Modules Research Report")
+        entry: Option<String>,
+
+        /// Write all scopes to this file as one JSON bundle
+        #[arg(long)]
+        export: Option<PathBuf>,
+    },
+
+    /// Compare the research results of two archived runs (new modules, changed boundaries)
+    MemoryDiff {
+        /// Older run, as listed in .litho/runs (default: the run before `to`)
+        from: Option<String>,
+
+        /// Newer run (default: the latest run)
+        to: Option<String>,
+
+        /// Print the differences as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Run research agents under two configurations against the memory snapshot of the last run
+    /// and write a side-by-side comparison to .litho/eval
+    Eval {
+        /// Agents to run, by configuration name (e.g. architecture_researcher), comma separated (default: all)
+        #[arg(long, value_delimiter = ',')]
+        agents: Vec<String>,
+
+        /// Configuration file of variant B; variant A is the regular configuration
+        #[arg(long)]
+        config_b: Option<PathBuf>,
+
+        /// Model of variant B, used as both efficient and powerful model
+        #[arg(long)]
+        model_b: Option<String>,
+
+        /// Score both outputs with the powerful model of variant A as judge
+        #[arg(long)]
+        judge: bool,
+
+        /// Directory of the report (default: .litho/eval)
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Run a subset of research agents once per model against the memory snapshot of the last run
+    /// and report latency, token cost, failure rate and confidence to .litho/eval
+    Bench {
+        /// Models to compare, comma separated
+        #[arg(long, value_delimiter = ',', required = true)]
+        models: Vec<String>,
+
+        /// Agents to run, by configuration name, comma separated
+        /// (default: system_context_researcher, domain_modules_detector, boundary_analyzer)
+        #[arg(long, value_delimiter = ',')]
+        agents: Vec<String>,
+
+        /// Directory of the report (default: .litho/eval)
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Run the full pipeline on the fixture projects with the offline mock LLM provider and compare
+    /// the structure of the results with each fixture's golden.json
+    Golden {
+        /// Fixtures to run (default: all)
+        fixtures: Vec<String>,
+
+        /// Directory of the fixtures, each with a `project` directory and its `golden.json`
+        #[arg(long, default_value = "tests/golden")]
+        dir: PathBuf,
+
+        /// Rewrite golden.json with the current results instead of comparing
+        #[arg(long)]
+        update: bool,
+    },
+
+    /// List supported languages, agents, documents, providers and target languages
+    List {
+        /// Only list one kind of capability
+        #[arg(value_enum)]
+        kind: Option<ListKind>,
+    },
+}
+
+/// Capability kinds shown by the `list` subcommand
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListKind {
+    /// Programming languages and file extensions with dedicated analysis
+    Languages,
+    /// Research agents
+    Agents,
+    /// Generated documents
+    Documents,
+    /// LLM providers
+    Providers,
+    /// Target languages of the generated documentation
+    TargetLanguages,
 }
 
 impl Args {
     /// Convert CLI arguments to configuration
     pub fn to_config(self) -> Config {
-        // Determine target language early for proper message localization
-        let target_lang = if let Some(ref lang_str) = self.target_language {
-            lang_str.parse::<TargetLanguage>().unwrap_or_default()
-        } else {
-            TargetLanguage::default()
-        };
+        // Determine console language early for proper message localization
+        let target_lang = self
+            .ui_language
+            .as_ref()
+            .or(self.target_language.as_ref())
+            .and_then(|lang_str| lang_str.parse::<TargetLanguage>().ok())
+            .unwrap_or_default();
 
         let mut config = if let Some(config_path) = &self.config {
             // If config file path is explicitly specified, load from that path
-            let msg = target_lang.msg_config_read_error().replace("{:?}", &format!("{:?}", config_path));
+            let msg = target_lang.msg_config_read_error(&format!("{:?}", config_path));
             Config::from_file(config_path).expect(&msg)
         } else {
             // If no config file is explicitly specified, try loading from default location
@@ -148,7 +321,7 @@ impl Args {
                 .join("litho.toml");
 
             if default_config_path.exists() {
-                let msg = target_lang.msg_config_read_error().replace("{:?}", &format!("{:?}", default_config_path));
+                let msg = target_lang.msg_config_read_error(&format!("{:?}", default_config_path));
                 Config::from_file(&default_config_path).expect(&msg)
             } else {
                 // Default config file doesn't exist, use default values
@@ -156,10 +329,31 @@ impl Args {
             }
         };
 
+        // Temperature 0 keeps repeated CI runs close to each other; an explicit --temperature still applies
+        if self.ci {
+            config.ci.enabled = true;
+            config.llm.temperature = Some(0.0);
+        }
+        if let Some(max_failures) = self.max_failures {
+            config.ci.max_failures = max_failures;
+        }
+
         // Override settings from config file
-        config.project_path = self.project_path.clone();
-        config.output_path = self.output_path;
-        config.internal_path = self.project_path.join(".litho");
+        // Verbatim Windows paths (`\\?\C:\...`) would not resolve the `/` of joined relative paths
+        let project_path = paths::strip_verbatim(&self.project_path);
+        config.output_path = paths::strip_verbatim(&self.output_path);
+        config.internal_path = project_path.join(".litho");
+        config.project_path = project_path;
+        if let Some(git_ref) = self.git_ref {
+            config.git_ref = Some(git_ref);
+        }
+        if self.versioned {
+            config.versioning.enabled = true;
+        }
+        if let Some(label) = self.version_label {
+            config.versioning.enabled = true;
+            config.versioning.label = Some(label);
+        }
 
         // Project name handling: CLI argument has highest priority, if CLI doesn't specify and config file doesn't have it, get_project_name() will auto-infer
         if let Some(name) = self.name {
@@ -171,8 +365,8 @@ impl Args {
             if let Ok(provider) = provider_str.parse::<LLMProvider>() {
                 config.llm.provider = provider;
             } else {
-                let msg = target_lang.msg_unknown_provider().replace("{}", &provider_str);
-                eprintln!("{}", msg);
+                let msg = target_lang.msg_unknown_provider(&provider_str);
+                warn!("{}", msg);
             }
         }
         if let Some(llm_api_base_url) = self.llm_api_base_url {
@@ -212,15 +406,31 @@ impl Args {
             if let Ok(target_language) = target_language_str.parse::<TargetLanguage>() {
                 config.target_language = target_language;
             } else {
-                let msg = target_lang.msg_unknown_language().replace("{}", &target_language_str);
-                eprintln!("{}", msg);
+                let msg = target_lang.msg_unknown_language(&target_language_str);
+                warn!("{}", msg);
+            }
+        }
+        if let Some(ui_language_str) = self.ui_language {
+            if let Ok(ui_language) = ui_language_str.parse::<TargetLanguage>() {
+                config.ui_language = Some(ui_language);
+            } else {
+                let msg = target_lang.msg_unknown_language(&ui_language_str);
+                warn!("{}", msg);
             }
         }
 
+        if !self.included_dirs.is_empty() {
+            config.included_dirs = self.included_dirs;
+        }
+
         // Cache configuration
         if self.no_cache {
             config.cache.enabled = false;
         }
+        config.resume = self.resume;
+        if self.force_regenerate {
+            config.incremental_preprocessing = false;
+        }
 
         // Boundary analysis configuration overrides
         if let Some(code_limit) = self.boundary_code_limit {
@@ -237,3 +447,56 @@ impl Args {
         config
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity_flags() {
+        let args = Args::parse_from(["deepwiki-rs", "-vv"]);
+        assert_eq!((args.quiet, args.verbose), (false, 2));
+        // The flags are global, so they also follow a subcommand
+        let args = Args::parse_from(["deepwiki-rs", "list", "--quiet"]);
+        assert_eq!((args.quiet, args.verbose), (true, 0));
+        assert!(Args::try_parse_from(["deepwiki-rs", "-q", "-v"]).is_err());
+    }
+
+    #[test]
+    fn test_list_subcommand() {
+        let args = Args::parse_from(["deepwiki-rs", "list", "target-languages"]);
+        assert!(matches!(args.command, Some(Commands::List { kind: Some(ListKind::TargetLanguages) })));
+        let args = Args::parse_from(["deepwiki-rs", "list"]);
+        assert!(matches!(args.command, Some(Commands::List { kind: None })));
+        assert!(Args::try_parse_from(["deepwiki-rs", "list", "plugins"]).is_err());
+    }
+
+    #[test]
+    fn test_ui_language_precedence() {
+        let temp = tempfile::tempdir().unwrap();
+        let with_ui = temp.path().join("with-ui.toml");
+        let without_ui = temp.path().join("without-ui.toml");
+        std::fs::write(&with_ui, "target_language = \"zh\"\nui_language = \"ja\"\n").unwrap();
+        std::fs::write(&without_ui, "target_language = \"zh\"\n").unwrap();
+        let config = |args: &[&str]| Args::parse_from(["deepwiki-rs"].iter().chain(args)).to_config();
+
+        // --ui-language overrides the configured UI language
+        let path = with_ui.to_str().unwrap();
+        let cli = config(&["--config", path, "--ui-language", "de"]);
+        assert_eq!(cli.ui_language(), &TargetLanguage::German);
+        assert_eq!(cli.target_language, TargetLanguage::Chinese);
+
+        // Otherwise the configured UI language applies, even with --target-language
+        let configured = config(&["--config", path, "--target-language", "fr"]);
+        assert_eq!(configured.ui_language(), &TargetLanguage::Japanese);
+        assert_eq!(configured.target_language, TargetLanguage::French);
+
+        // Without either, messages follow the target language
+        let path = without_ui.to_str().unwrap();
+        assert_eq!(config(&["--config", path]).ui_language(), &TargetLanguage::Chinese);
+        assert_eq!(
+            config(&["--config", path, "--target-language", "fr"]).ui_language(),
+            &TargetLanguage::French
+        );
+    }
+}
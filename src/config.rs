@@ -2,7 +2,7 @@ use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::Read;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
 
 use crate::i18n::TargetLanguage;
 
@@ -25,6 +25,9 @@ pub enum LLMProvider {
     Gemini,
     #[serde(rename = "ollama")]
     Ollama,
+    /// Offline placeholder answers, for tests and the golden suite
+    #[serde(rename = "mock")]
+    Mock,
 }
 
 impl Default for LLMProvider {
@@ -33,6 +36,23 @@ impl Default for LLMProvider {
     }
 }
 
+impl LLMProvider {
+    /// All supported LLM providers
+    pub fn all() -> Vec<LLMProvider> {
+        vec![
+            LLMProvider::OpenAI,
+            LLMProvider::Moonshot,
+            LLMProvider::DeepSeek,
+            LLMProvider::Mistral,
+            LLMProvider::OpenRouter,
+            LLMProvider::Anthropic,
+            LLMProvider::Gemini,
+            LLMProvider::Ollama,
+            LLMProvider::Mock,
+        ]
+    }
+}
+
 impl std::fmt::Display for LLMProvider {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -44,6 +64,7 @@ impl std::fmt::Display for LLMProvider {
             LLMProvider::Anthropic => write!(f, "anthropic"),
             LLMProvider::Gemini => write!(f, "gemini"),
             LLMProvider::Ollama => write!(f, "ollama"),
+            LLMProvider::Mock => write!(f, "mock"),
         }
     }
 }
@@ -61,6 +82,7 @@ impl std::str::FromStr for LLMProvider {
             "anthropic" => Ok(LLMProvider::Anthropic),
             "gemini" => Ok(LLMProvider::Gemini),
             "ollama" => Ok(LLMProvider::Ollama),
+            "mock" => Ok(LLMProvider::Mock),
             _ => Err(format!("Unknown provider: {}", s)),
         }
     }
@@ -76,6 +98,9 @@ pub struct Config {
     /// Project path
     pub project_path: PathBuf,
 
+    /// Branch, tag or commit to document instead of the working tree
+    pub git_ref: Option<String>,
+
     /// Output path
     pub output_path: PathBuf,
 
@@ -85,6 +110,12 @@ pub struct Config {
     /// Target language
     pub target_language: TargetLanguage,
 
+    /// Language of console messages; defaults to the target language
+    pub ui_language: Option<TargetLanguage>,
+
+    /// Reload the memory snapshot of the previous run and skip the steps it already completed
+    pub resume: bool,
+
     /// Whether to analyze dependencies
     pub analyze_dependencies: bool,
 
@@ -94,9 +125,13 @@ pub struct Config {
     /// Maximum recursion depth
     pub max_depth: u8,
 
-    /// Maximum file size limit (bytes)
+    /// Size above which files are read partially: head, tail and important lines (bytes)
     pub max_file_size: u64,
 
+    /// Overrides of `max_file_size` by extension and for core components
+    #[serde(default)]
+    pub limits: LimitsConfig,
+
     /// Whether to include test files
     pub include_tests: bool,
 
@@ -107,6 +142,22 @@ pub struct Config {
     #[serde(default)]
     pub git_tracked_only: bool,
 
+    /// Whether to skip files and directories matched by the project's .gitignore
+    #[serde(default = "default_true")]
+    pub respect_gitignore: bool,
+
+    /// Reuse the dossiers of directories whose files are unchanged since the previous run
+    #[serde(default = "default_true")]
+    pub incremental_preprocessing: bool,
+
+    /// Whether symbolic links are followed or skipped while scanning the project
+    #[serde(default)]
+    pub symlink_policy: SymlinkPolicy,
+
+    /// Without a CODEOWNERS file, take the author of most commits as the owner of each file
+    #[serde(default)]
+    pub ownership_from_git: bool,
+
     /// Directories to exclude
     pub excluded_dirs: Vec<String>,
 
@@ -119,6 +170,10 @@ pub struct Config {
     /// Only include specified file extensions
     pub included_extensions: Vec<String>,
 
+    /// Only analyze these directories (glob patterns relative to project_path), empty means all
+    #[serde(default)]
+    pub included_dirs: Vec<String>,
+
     /// LLM model configuration
     pub llm: LLMConfig,
 
@@ -129,12 +184,469 @@ pub struct Config {
     #[serde(default)]
     pub knowledge: KnowledgeConfig,
 
-    /// Architecture meta description file path
+    /// Declared architecture (layers, module owners, forbidden dependencies), see `ArchitectureMeta`
     pub architecture_meta_path: Option<PathBuf>,
 
     /// Boundary analysis configuration
     #[serde(default)]
     pub boundary_analysis: BoundaryAnalysisConfig,
+
+    /// Sub-projects of a monorepo, each documented separately and tied together by a top-level overview
+    #[serde(default)]
+    pub projects: Vec<SubProjectConfig>,
+
+    /// Notifications sent when a run completes or fails
+    #[serde(default)]
+    pub notifications: NotificationConfig,
+
+    /// Output naming overrides
+    #[serde(default)]
+    pub output: OutputConfig,
+
+    /// Generator memory limits
+    #[serde(default)]
+    pub memory: MemoryConfig,
+
+    /// Sampling of the files sent to AI analysis in very large repositories
+    #[serde(default)]
+    pub sampling: SamplingConfig,
+
+    /// Data formatting overrides by agent, e.g. `[formatter.architecture_researcher]`
+    #[serde(default)]
+    pub formatter: std::collections::HashMap<String, FormatterOverrides>,
+
+    /// Signals blended into the file importance scores
+    #[serde(default)]
+    pub importance: ImportanceConfig,
+
+    /// Verification of the links in the generated documents
+    #[serde(default)]
+    pub link_check: LinkCheckConfig,
+
+    /// Verification of the file paths cited in the generated documents
+    #[serde(default)]
+    pub path_check: PathCheckConfig,
+
+    /// Re-runs of the research agents reporting a low confidence score
+    #[serde(default)]
+    pub confidence: ConfidenceConfig,
+
+    /// Re-runs of the agents producing invalid or suspiciously short output
+    #[serde(default)]
+    pub escalation: EscalationConfig,
+
+    /// Each run written to its own version directory of the output path
+    #[serde(default)]
+    pub versioning: VersioningConfig,
+
+    /// REST API server mode (`serve-api`)
+    #[serde(default)]
+    pub api: ApiConfig,
+
+    /// Unattended runs in scheduled pipelines (`--ci`)
+    #[serde(default)]
+    pub ci: CiConfig,
+
+    /// Redaction of secrets in the content sent to the LLM provider
+    #[serde(default)]
+    pub redaction: RedactionConfig,
+
+    /// Paths whose content must not be sent to the LLM provider
+    #[serde(default)]
+    pub privacy: PrivacyConfig,
+}
+
+/// REST API server accepting documentation generation jobs
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ApiConfig {
+    /// Address the server listens on
+    #[serde(default = "default_api_bind")]
+    pub bind: String,
+
+    /// Directory holding the checkouts, outputs and working files of the jobs
+    #[serde(default = "default_api_work_dir")]
+    pub work_dir: PathBuf,
+
+    /// Environment variable holding the bearer token required by the API; required unless
+    /// the server listens on a loopback address
+    #[serde(default)]
+    pub token_env: Option<String>,
+
+    /// Directories whose projects jobs may document by `project_path`; without any, jobs
+    /// can only clone repositories
+    #[serde(default)]
+    pub project_roots: Vec<PathBuf>,
+}
+
+/// Sensitive paths of compliance-constrained codebases, as glob patterns relative to
+/// project_path; a directory pattern covers everything below it
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct PrivacyConfig {
+    /// Names, locations and sizes may be used, the content is never sent to the LLM
+    #[serde(default)]
+    pub metadata_only: Vec<String>,
+
+    /// Ignored entirely, as if the files did not exist
+    #[serde(default)]
+    pub excluded: Vec<String>,
+}
+
+/// Secrets replaced by `[REDACTED:<rule>]` in source files, the README, external knowledge
+/// and files read by agent tools before they are sent to the LLM provider
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RedactionConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Minimum entropy (bits per character) of values assigned to `api_key`, `password`,
+    /// `token`, ... for them to be redacted; lower values are taken for placeholders
+    #[serde(default = "default_redaction_min_entropy")]
+    pub min_entropy: f64,
+
+    /// Additional regular expressions to redact; the first capture group when present,
+    /// the whole match otherwise
+    #[serde(default)]
+    pub extra_patterns: Vec<String>,
+}
+
+/// Unattended runs: plain output, temperature 0 and a failure threshold
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct CiConfig {
+    /// Enabled by `--ci`
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Failed steps plus model fallbacks tolerated before the run fails
+    #[serde(default)]
+    pub max_failures: usize,
+}
+
+/// Minimum confidence of the research reports scoring themselves (0-10)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ConfidenceConfig {
+    /// Reports scoring below re-run with the powerful model and expanded context;
+    /// unset disables the re-runs
+    #[serde(default)]
+    pub min_score: Option<f64>,
+
+    /// Re-runs per report before keeping its best-scoring result
+    #[serde(default = "default_confidence_retries")]
+    pub max_retries: usize,
+}
+
+/// Re-runs of agents whose output looks poor, with the powerful model and expanded context
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EscalationConfig {
+    /// Re-run agents whose output fails schema validation or is suspiciously short
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Reports and documents shorter than this (characters) are suspiciously short
+    #[serde(default = "default_min_output_chars")]
+    pub min_output_chars: usize,
+}
+
+/// Versioned documentation output, listed in `versions.json` and a root `index.md`
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct VersioningConfig {
+    /// Write each run to `<output_path>/<version>/` instead of replacing the whole output
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Version directory name (default: `vYYYYMMDD-<short commit>`, or `vYYYYMMDD-HHMMSS`
+    /// outside a git repository)
+    #[serde(default)]
+    pub label: Option<String>,
+
+    /// Versions kept, the oldest are deleted (default: 0, keep all)
+    #[serde(default)]
+    pub keep: usize,
+}
+
+/// Link verification after the documents are written
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LinkCheckConfig {
+    /// Check anchors, links between documents and links to project files
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Repair broken links in place: retarget them to the matching document, anchor
+    /// or project file, or replace them by their text
+    #[serde(default)]
+    pub fix: bool,
+}
+
+/// Verification of the cited file paths before the documents are written
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PathCheckConfig {
+    /// Check the cited paths against the project structure and correct near misses
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// What happens to cited paths matching no project file
+    #[serde(default)]
+    pub unknown_paths: UnknownPathPolicy,
+}
+
+/// Handling of cited paths matching no project file
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UnknownPathPolicy {
+    /// Keep them and report them in the run summary
+    #[default]
+    Flag,
+    /// Drop list items citing them and reduce other citations to the file name
+    Strip,
+}
+
+/// Generator memory limits
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MemoryConfig {
+    /// Warn once when the generator memory grows beyond this size (MB)
+    #[serde(default = "default_memory_warning_mb")]
+    pub warning_threshold_mb: usize,
+
+    /// Spill the least recently used entries to disk when the memory kept resident
+    /// exceeds this size (MB); 0 disables spilling
+    #[serde(default = "default_memory_spill_mb")]
+    pub spill_threshold_mb: usize,
+
+    /// Number of past runs whose memory snapshot is kept in `.litho/runs/` for diffing
+    #[serde(default = "default_keep_runs")]
+    pub keep_runs: usize,
+}
+
+/// Handling of symbolic links during project traversal
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SymlinkPolicy {
+    /// Ignore symbolic links
+    #[default]
+    Skip,
+    /// Traverse symbolic links; files and directories reached twice are scanned once
+    Follow,
+}
+
+/// Strategy choosing which files get AI analysis
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SamplingStrategy {
+    /// Analyze every file
+    #[default]
+    All,
+    /// The `max_files` most important files
+    TopN,
+    /// The `per_directory` most important files of each directory
+    Stratified,
+    /// The most important files of each language, up to its quota
+    LanguageQuota,
+}
+
+/// Sampling of the files sent to AI analysis
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SamplingConfig {
+    #[serde(default)]
+    pub strategy: SamplingStrategy,
+
+    /// Sampling only applies to projects with more files than this
+    #[serde(default = "default_sampling_min_files")]
+    pub min_files: usize,
+
+    /// Files kept by `top_n`, and the overall cap of the other strategies (0 = no cap)
+    #[serde(default = "default_sampling_max_files")]
+    pub max_files: usize,
+
+    /// Files kept per directory by `stratified`
+    #[serde(default = "default_sampling_per_directory")]
+    pub per_directory: usize,
+
+    /// Files kept per file extension by `language_quota`, e.g. `{ rs = 800, ts = 400 }`
+    #[serde(default)]
+    pub language_quotas: std::collections::HashMap<String, usize>,
+
+    /// Quota of the extensions missing from `language_quotas`
+    #[serde(default = "default_sampling_language_quota")]
+    pub default_language_quota: usize,
+}
+
+/// Signals blended into the path and extension based file importance scores
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ImportanceConfig {
+    /// Weight of the dependency graph centrality (PageRank over internal imports); 0 disables it
+    #[serde(default = "default_centrality_weight")]
+    pub centrality_weight: f64,
+
+    /// Weight of the git activity (commit count and recency) of each file; 0 disables it
+    #[serde(default = "default_churn_weight")]
+    pub churn_weight: f64,
+
+    /// Days of git history read for the activity signal
+    #[serde(default = "default_history_days")]
+    pub history_days: u32,
+
+    /// Days after which the recency of a file's latest commit counts half
+    #[serde(default = "default_recency_half_life_days")]
+    pub recency_half_life_days: u32,
+}
+
+/// Data formatting of one agent's prompts; unset fields keep the agent's own settings
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FormatterOverrides {
+    /// Share of the model context window code insights may fill (0.0-1.0)
+    pub code_insights_share: Option<f64>,
+
+    /// Share of the model context window dependency relationships may fill (0.0-1.0)
+    pub dependency_share: Option<f64>,
+
+    /// Include the source summaries of the code insights
+    pub include_source_code: Option<bool>,
+
+    /// Compress oversized data sources
+    pub enable_compression: Option<bool>,
+
+    /// Characters of the README kept; 0 keeps the whole README
+    pub readme_truncate_length: Option<usize>,
+
+    /// Only list directories in the project structure above this many files; 0 always lists files
+    pub only_directories_when_files_more_than: Option<usize>,
+}
+
+/// File size overrides for large files that must be read in full
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct LimitsConfig {
+    /// Extension (without the dot) to the size above which its files are read partially,
+    /// e.g. `{ sql = 1048576 }`
+    #[serde(default)]
+    pub per_extension: std::collections::HashMap<String, u64>,
+
+    /// Size limit of files marked as core components, used when larger than the other limits
+    #[serde(default)]
+    pub core_file_size: Option<u64>,
+}
+
+/// Output naming configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OutputConfig {
+    /// Document type (overview, architecture, workflow, boundary, database, deep_exploration)
+    /// to file or directory name; takes precedence over the target language's names
+    #[serde(default)]
+    pub filenames: std::collections::HashMap<String, String>,
+
+    /// Document type to the languages it is generated in, e.g. `overview = ["en", "zh"]`.
+    /// Unlisted documents are generated in the target language only.
+    #[serde(default)]
+    pub languages: std::collections::HashMap<String, Vec<TargetLanguage>>,
+
+    /// Normalize the documents before writing them: strip model chatter, fix heading
+    /// levels, tag code fences and lay out tables consistently
+    #[serde(default = "default_true")]
+    pub normalize_markdown: bool,
+
+    /// Documents with at least this many level 2 and 3 headings get a table of contents;
+    /// 0 disables it
+    #[serde(default = "default_toc_min_headings")]
+    pub toc_min_headings: usize,
+
+    /// Link the first mention of each module in the documents to its deep-dive document
+    #[serde(default = "default_true")]
+    pub cross_links: bool,
+
+    /// Copy the diagrams of the repository documentation folders to `assets/` and let the
+    /// writers reference them
+    #[serde(default = "default_true")]
+    pub reuse_diagrams: bool,
+
+    /// Append a footer with the tool version, models, source commit and generation date
+    #[serde(default = "default_true")]
+    pub metadata_footer: bool,
+
+    /// Footer text, with `{version}`, `{models}`, `{commit}` and `{date}` replaced
+    #[serde(default)]
+    pub footer_template: Option<String>,
+}
+
+impl Default for OutputConfig {
+    fn default() -> Self {
+        Self {
+            filenames: Default::default(),
+            languages: Default::default(),
+            normalize_markdown: true,
+            toc_min_headings: default_toc_min_headings(),
+            cross_links: true,
+            reuse_diagrams: true,
+            metadata_footer: true,
+            footer_template: None,
+        }
+    }
+}
+
+/// Run completion notifications
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct NotificationConfig {
+    /// Webhooks called with the run summary
+    #[serde(default)]
+    pub webhooks: Vec<WebhookConfig>,
+}
+
+/// Webhook fired when a run finishes
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct WebhookConfig {
+    /// Webhook URL; may be left empty when `url_env` is set
+    #[serde(default)]
+    pub url: String,
+
+    /// Environment variable holding the URL, for secrets that should not be committed
+    #[serde(default)]
+    pub url_env: Option<String>,
+
+    /// Payload format (slack, teams, generic; default: generic)
+    #[serde(default)]
+    pub kind: WebhookKind,
+
+    /// Run outcomes that trigger the webhook (success, partial_failure, failure); empty means all
+    #[serde(default)]
+    pub events: Vec<crate::generator::outlet::RunStatus>,
+
+    /// Extra HTTP headers, e.g. for authentication of generic endpoints
+    #[serde(default)]
+    pub headers: std::collections::HashMap<String, String>,
+}
+
+impl WebhookConfig {
+    /// Resolve the URL from the configuration or `url_env`
+    pub fn resolve_url(&self) -> Option<String> {
+        Some(self.url.clone())
+            .filter(|url| !url.trim().is_empty())
+            .or_else(|| self.url_env.as_ref().and_then(|var| std::env::var(var).ok()))
+    }
+}
+
+/// Webhook payload format
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookKind {
+    /// Slack incoming webhook (`text` message)
+    Slack,
+    /// Microsoft Teams incoming webhook (MessageCard)
+    Teams,
+    /// Plain JSON POST of the run summary
+    #[default]
+    Generic,
+}
+
+/// Sub-project within a monorepo
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SubProjectConfig {
+    /// Sub-project name, also used as the output sub-directory
+    pub name: String,
+
+    /// Path of the sub-project, relative to `project_path`
+    pub path: PathBuf,
+
+    /// Short description of the sub-project's role in the repository
+    #[serde(default)]
+    pub description: String,
 }
 
 /// LLM model configuration
@@ -165,9 +677,13 @@ pub struct LLMConfig {
     /// Retry attempts
     pub retry_attempts: u32,
 
-    /// Retry interval (milliseconds)
+    /// Delay before the first retry (milliseconds), doubled on each further retry
     pub retry_delay_ms: u64,
 
+    /// Upper bound of the retry delay (milliseconds); a provider's Retry-After takes precedence
+    #[serde(default = "default_retry_max_delay_ms")]
+    pub retry_max_delay_ms: u64,
+
     /// Timeout duration (seconds)
     pub timeout_seconds: u64,
 
@@ -182,6 +698,31 @@ pub struct LLMConfig {
     /// Concurrency level for parallel tool execution
     #[serde(default = "default_tool_concurrency")]
     pub tool_concurrency: usize,
+
+    /// Context window of the models (tokens); inferred from the model names when not set.
+    /// Prompt data sources get token budgets in proportion to it.
+    #[serde(default)]
+    pub context_window: Option<usize>,
+}
+
+impl LLMConfig {
+    /// Context window prompts are sized for: the configured one, else the smaller window of
+    /// the efficient and powerful models. Unknown models count as 32K, and Ollama models
+    /// as at most 8K since local servers run with small contexts by default.
+    pub fn context_window(&self) -> usize {
+        if let Some(window) = self.context_window {
+            return window;
+        }
+        let window = [&self.model_efficient, &self.model_powerful]
+            .iter()
+            .map(|model| crate::utils::token_estimator::context_window_for_model(model).unwrap_or(32_768))
+            .min()
+            .unwrap_or(32_768);
+        match self.provider {
+            LLMProvider::Ollama => window.min(8_192),
+            _ => window,
+        }
+    }
 }
 
 fn default_max_turns() -> usize {
@@ -240,6 +781,25 @@ fn default_files_threshold() -> Option<usize> {
 pub struct KnowledgeConfig {
     /// Local documentation files configuration
     pub local_docs: Option<LocalDocsConfig>,
+
+    /// Confluence pages configuration
+    pub confluence: Option<ConfluenceConfig>,
+
+    /// Live database schemas to introspect
+    #[serde(default)]
+    pub databases: Vec<DatabaseSourceConfig>,
+
+    /// Issue tracker (GitHub Issues / Jira) configuration
+    pub issues: Option<IssueTrackerConfig>,
+
+    /// Token budget for external knowledge in a single prompt; when exceeded,
+    /// documents from lower-priority categories are left out first
+    #[serde(default)]
+    pub max_knowledge_tokens: Option<usize>,
+
+    /// Embedding-based retrieval of the knowledge chunks relevant to each agent's task
+    #[serde(default)]
+    pub retrieval: Option<RetrievalConfig>,
 }
 
 /// Document category for organizing external knowledge
@@ -264,6 +824,9 @@ pub struct DocumentCategory {
     /// Chunking configuration for large documents in this category
     #[serde(default)]
     pub chunking: Option<ChunkingConfig>,
+    /// Inclusion priority when the knowledge budget is tight; higher goes first (default: 0)
+    #[serde(default)]
+    pub priority: i32,
 }
 
 /// Configuration for document chunking
@@ -343,6 +906,374 @@ pub struct LocalDocsConfig {
     pub default_chunking: Option<ChunkingConfig>,
 }
 
+/// Confluence knowledge source configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ConfluenceConfig {
+    /// Whether Confluence integration is enabled
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Confluence base URL (e.g., "https://your-company.atlassian.net/wiki")
+    pub base_url: String,
+
+    /// Account email for Confluence Cloud basic auth; if absent the token is sent as a Bearer token
+    #[serde(default)]
+    pub username: Option<String>,
+
+    /// API token or personal access token; falls back to the CONFLUENCE_API_TOKEN environment variable
+    #[serde(default)]
+    pub api_token: Option<String>,
+
+    /// Categorized page sources
+    #[serde(default)]
+    pub categories: Vec<ConfluenceCategory>,
+
+    /// Maximum number of pages fetched per space (default: 50)
+    #[serde(default = "default_confluence_max_pages")]
+    pub max_pages_per_space: usize,
+
+    /// Re-fetch pages when the cache is older than this many hours (default: 24)
+    #[serde(default = "default_knowledge_max_age_hours")]
+    pub max_age_hours: u64,
+
+    /// Local directory to cache fetched content
+    pub cache_dir: Option<PathBuf>,
+
+    /// Default chunking configuration for all categories
+    #[serde(default)]
+    pub default_chunking: Option<ChunkingConfig>,
+}
+
+/// Confluence pages grouped into a knowledge category
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ConfluenceCategory {
+    /// Category identifier (e.g., "architecture", "database", "api")
+    pub name: String,
+
+    /// Human-readable description of this category
+    #[serde(default)]
+    pub description: String,
+
+    /// Space keys whose pages belong to this category
+    #[serde(default)]
+    pub space_keys: Vec<String>,
+
+    /// Individual page IDs that belong to this category
+    #[serde(default)]
+    pub page_ids: Vec<String>,
+
+    /// Which agents should receive documents from this category
+    /// If empty, documents are available to all agents
+    #[serde(default)]
+    pub target_agents: Vec<String>,
+
+    /// Chunking configuration for large pages in this category
+    #[serde(default)]
+    pub chunking: Option<ChunkingConfig>,
+    /// Inclusion priority when the knowledge budget is tight; higher goes first (default: 0)
+    #[serde(default)]
+    pub priority: i32,
+}
+
+/// Live database schema knowledge source configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DatabaseSourceConfig {
+    /// Source name, used for the cache file and document titles
+    pub name: String,
+
+    /// Whether this database source is enabled
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Connection string (postgres://, mysql:// or sqlite://); use a read-only account
+    #[serde(default)]
+    pub connection_string: Option<String>,
+
+    /// Environment variable holding the connection string, used when connection_string is absent
+    #[serde(default)]
+    pub connection_string_env: Option<String>,
+
+    /// Schemas to include (PostgreSQL/MySQL); all non-system schemas if empty
+    #[serde(default)]
+    pub schemas: Vec<String>,
+
+    /// Whether to include stored procedures and functions (default: true)
+    #[serde(default = "default_true")]
+    pub include_routines: bool,
+
+    /// Knowledge category the schema is published under (default: "database")
+    #[serde(default = "default_database_category")]
+    pub category: String,
+
+    /// Which agents should receive the schema
+    /// If empty, the schema is available to all agents
+    #[serde(default)]
+    pub target_agents: Vec<String>,
+
+    /// Inclusion priority when the knowledge budget is tight; higher goes first (default: 0)
+    #[serde(default)]
+    pub priority: i32,
+
+    /// Re-introspect when the cache is older than this many hours (default: 24)
+    #[serde(default = "default_knowledge_max_age_hours")]
+    pub max_age_hours: u64,
+}
+
+impl DatabaseSourceConfig {
+    /// Resolve the connection string from the configuration or the environment
+    pub fn resolve_connection_string(&self) -> Option<String> {
+        self.connection_string
+            .clone()
+            .filter(|url| !url.is_empty())
+            .or_else(|| {
+                self.connection_string_env
+                    .as_ref()
+                    .and_then(|name| std::env::var(name).ok())
+            })
+    }
+}
+
+/// Supported issue trackers
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueTrackerProvider {
+    Github,
+    Jira,
+}
+
+/// Issue tracker knowledge source configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct IssueTrackerConfig {
+    /// Whether issue tracker integration is enabled
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Issue tracker provider
+    pub provider: IssueTrackerProvider,
+
+    /// GitHub repository in "owner/repo" form
+    #[serde(default)]
+    pub repository: Option<String>,
+
+    /// API base URL (Jira site URL; defaults to https://api.github.com for GitHub)
+    #[serde(default)]
+    pub base_url: Option<String>,
+
+    /// Jira project keys to pull issues from
+    #[serde(default)]
+    pub project_keys: Vec<String>,
+
+    /// Custom JQL query for Jira, overrides project_keys and labels
+    #[serde(default)]
+    pub jql: Option<String>,
+
+    /// Account email for Jira Cloud basic auth
+    #[serde(default)]
+    pub username: Option<String>,
+
+    /// API token; falls back to GITHUB_TOKEN or JIRA_API_TOKEN
+    #[serde(default)]
+    pub api_token: Option<String>,
+
+    /// Only pull issues carrying one of these labels
+    #[serde(default)]
+    pub labels: Vec<String>,
+
+    /// Maximum number of recently updated issues to pull (default: 100)
+    #[serde(default = "default_max_issues")]
+    pub max_issues: usize,
+
+    /// Knowledge category the issues are published under (default: "issues")
+    #[serde(default = "default_issues_category")]
+    pub category: String,
+
+    /// Which agents should receive the issues
+    /// If empty, issues are available to all agents
+    #[serde(default)]
+    pub target_agents: Vec<String>,
+
+    /// Inclusion priority when the knowledge budget is tight; higher goes first (default: 0)
+    #[serde(default)]
+    pub priority: i32,
+
+    /// Re-fetch when the cache is older than this many hours (default: 24)
+    #[serde(default = "default_knowledge_max_age_hours")]
+    pub max_age_hours: u64,
+}
+
+impl IssueTrackerConfig {
+    /// Resolve the API token from the configuration or the provider's environment variable
+    pub fn resolve_api_token(&self) -> Option<String> {
+        let env_var = match self.provider {
+            IssueTrackerProvider::Github => "GITHUB_TOKEN",
+            IssueTrackerProvider::Jira => "JIRA_API_TOKEN",
+        };
+        self.api_token
+            .clone()
+            .filter(|token| !token.is_empty())
+            .or_else(|| std::env::var(env_var).ok())
+    }
+}
+
+/// Embedding-based knowledge retrieval (RAG) configuration
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RetrievalConfig {
+    /// Retrieve the top-k relevant chunks instead of including whole categories
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// OpenAI-compatible embeddings endpoint base URL; falls back to `llm.api_base_url`
+    #[serde(default)]
+    pub api_base_url: Option<String>,
+
+    /// API key for the embeddings endpoint; falls back to LITHO_EMBEDDING_API_KEY, then `llm.api_key`
+    #[serde(default)]
+    pub api_key: Option<String>,
+
+    /// Embedding model name (default: "text-embedding-3-small")
+    #[serde(default = "default_embedding_model")]
+    pub model: String,
+
+    /// Number of chunks retrieved per prompt (default: 8)
+    #[serde(default = "default_retrieval_top_k")]
+    pub top_k: usize,
+
+    /// Number of texts embedded per request (default: 32)
+    #[serde(default = "default_embedding_batch_size")]
+    pub batch_size: usize,
+}
+
+impl RetrievalConfig {
+    /// Resolve the embeddings endpoint and API key, falling back to the LLM settings
+    pub fn resolve_endpoint(&self, llm: &LLMConfig) -> (String, String) {
+        let base_url = self
+            .api_base_url
+            .clone()
+            .unwrap_or_else(|| llm.api_base_url.clone());
+        let api_key = self
+            .api_key
+            .clone()
+            .filter(|key| !key.is_empty())
+            .or_else(|| std::env::var("LITHO_EMBEDDING_API_KEY").ok())
+            .unwrap_or_else(|| llm.api_key.clone());
+        (base_url, api_key)
+    }
+}
+
+fn default_embedding_model() -> String {
+    "text-embedding-3-small".to_string()
+}
+
+fn default_retrieval_top_k() -> usize {
+    8
+}
+
+fn default_embedding_batch_size() -> usize {
+    32
+}
+
+fn default_max_issues() -> usize {
+    100
+}
+
+fn default_issues_category() -> String {
+    "issues".to_string()
+}
+
+fn default_database_category() -> String {
+    "database".to_string()
+}
+
+impl ConfluenceConfig {
+    /// Resolve the API token from the configuration or the environment
+    pub fn resolve_api_token(&self) -> Option<String> {
+        self.api_token
+            .clone()
+            .filter(|token| !token.is_empty())
+            .or_else(|| std::env::var("CONFLUENCE_API_TOKEN").ok())
+    }
+}
+
+fn default_confluence_max_pages() -> usize {
+    50
+}
+
+fn default_knowledge_max_age_hours() -> u64 {
+    24
+}
+
+fn default_memory_warning_mb() -> usize {
+    512
+}
+
+fn default_memory_spill_mb() -> usize {
+    256
+}
+
+fn default_keep_runs() -> usize {
+    10
+}
+
+fn default_confidence_retries() -> usize {
+    1
+}
+
+fn default_min_output_chars() -> usize {
+    500
+}
+
+fn default_toc_min_headings() -> usize {
+    6
+}
+
+fn default_redaction_min_entropy() -> f64 {
+    3.5
+}
+
+fn default_retry_max_delay_ms() -> u64 {
+    60_000
+}
+
+fn default_api_bind() -> String {
+    "127.0.0.1:8080".to_string()
+}
+
+fn default_api_work_dir() -> PathBuf {
+    PathBuf::from("./.litho/api-jobs")
+}
+
+fn default_sampling_min_files() -> usize {
+    5000
+}
+
+fn default_sampling_max_files() -> usize {
+    2000
+}
+
+fn default_sampling_per_directory() -> usize {
+    20
+}
+
+fn default_sampling_language_quota() -> usize {
+    200
+}
+
+fn default_centrality_weight() -> f64 {
+    0.3
+}
+
+fn default_churn_weight() -> f64 {
+    0.2
+}
+
+fn default_history_days() -> u32 {
+    365
+}
+
+fn default_recency_half_life_days() -> u32 {
+    90
+}
+
 fn default_true() -> bool {
     true
 }
@@ -350,6 +1281,16 @@ fn default_false() -> bool {
     false
 }
 
+impl SubProjectConfig {
+    /// Output directory name of the sub-project
+    pub fn output_dir_name(&self) -> String {
+        self.name
+            .chars()
+            .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+            .collect()
+    }
+}
+
 impl Config {
     /// Load configuration from file
     pub fn from_file(path: &PathBuf) -> Result<Self> {
@@ -363,6 +1304,152 @@ impl Config {
         Ok(config)
     }
 
+    /// Configuration documenting the unpacked copy of the archive at `project_path`; memory
+    /// and cache stay next to the archive so they outlive the temporary workspace
+    pub fn for_extracted_archive(&self, project_root: &Path) -> Config {
+        let archive = &self.project_path;
+        let mut config = self.clone();
+        config.project_path = project_root.to_path_buf();
+        if config.internal_path.starts_with(archive) {
+            config.internal_path = archive.parent().unwrap_or(Path::new(".")).join(".litho");
+        }
+        if config.project_name.is_none() {
+            config.project_name = Some(crate::utils::archive::archive_stem(archive));
+        }
+        // An unpacked archive is not a git repository
+        config.git_tracked_only = false;
+        config
+    }
+
+    /// Size above which a file is read partially: its extension override or `max_file_size`,
+    /// raised to `limits.core_file_size` for core components
+    pub fn max_file_size_for(&self, path: &Path, is_core: bool) -> u64 {
+        let limit = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(|extension| {
+                self.limits
+                    .per_extension
+                    .iter()
+                    .find(|(key, _)| key.trim_start_matches('.').eq_ignore_ascii_case(extension))
+            })
+            .map(|(_, limit)| *limit)
+            .unwrap_or(self.max_file_size);
+        match self.limits.core_file_size {
+            Some(core_limit) if is_core => limit.max(core_limit),
+            _ => limit,
+        }
+    }
+
+    /// Configuration documenting the checkout of `git_ref` at `project_root`; memory and
+    /// cache stay in the project directory so they outlive the temporary worktree
+    pub fn for_git_ref(&self, project_root: &Path) -> Config {
+        let mut config = self.clone();
+        config.project_path = project_root.to_path_buf();
+        config.git_ref = None;
+        config
+    }
+
+    /// Derive the configuration of a monorepo sub-project from the root configuration.
+    /// The sub-project path must stay inside `project_path`: no root, `.` or `..` components.
+    pub fn for_sub_project(&self, sub_project: &SubProjectConfig) -> Result<Config> {
+        let path = &sub_project.path;
+        if path.as_os_str().is_empty()
+            || !path.components().all(|component| matches!(component, Component::Normal(_)))
+        {
+            anyhow::bail!(
+                "Sub-project '{}' path must be relative to the project path without '.' or '..': {}",
+                sub_project.name,
+                path.display()
+            );
+        }
+
+        let mut config = self.clone();
+        config.project_name = Some(sub_project.name.clone());
+        config.project_path = self.project_path.join(path);
+        config.output_path = self.output_path.join(sub_project.output_dir_name());
+        config.projects = Vec::new();
+        Ok(config)
+    }
+
+    /// Language of console messages: `ui_language` if set, otherwise the target language
+    pub fn ui_language(&self) -> &TargetLanguage {
+        self.ui_language.as_ref().unwrap_or(&self.target_language)
+    }
+
+    /// File name of a document: the `[output.filenames]` override, otherwise the name in its primary language
+    pub fn doc_filename(&self, doc_type: &str) -> String {
+        self.output
+            .filenames
+            .get(doc_type)
+            .cloned()
+            .unwrap_or_else(|| self.document_languages(doc_type).0.get_doc_filename(doc_type))
+    }
+
+    /// Directory name of a document group: the `[output.filenames]` override, otherwise the name in its primary language
+    pub fn directory_name(&self, dir_type: &str) -> String {
+        self.output
+            .filenames
+            .get(dir_type)
+            .cloned()
+            .unwrap_or_else(|| self.document_languages(dir_type).0.get_directory_name(dir_type))
+    }
+
+    /// Languages a document is generated in: the primary language, written at the usual path,
+    /// and the additional ones, written under a directory named after the language code.
+    /// The primary language is the target language when listed, otherwise the first listed one.
+    pub fn document_languages(&self, doc_type: &str) -> (TargetLanguage, Vec<TargetLanguage>) {
+        let listed = match self.output.languages.get(doc_type) {
+            Some(languages) if !languages.is_empty() => languages,
+            _ => return (self.target_language.clone(), Vec::new()),
+        };
+        let primary = if listed.contains(&self.target_language) {
+            self.target_language.clone()
+        } else {
+            listed[0].clone()
+        };
+        let mut extras: Vec<TargetLanguage> = Vec::new();
+        for language in listed {
+            if *language != primary && !extras.contains(language) {
+                extras.push(language.clone());
+            }
+        }
+        (primary, extras)
+    }
+
+    /// Directory of the memory snapshot; each sub-project of a monorepo gets its own
+    pub fn memory_dir(&self) -> PathBuf {
+        self.project_internal_dir("memory")
+    }
+
+    /// Directory of the memory snapshots archived after each run
+    pub fn runs_dir(&self) -> PathBuf {
+        self.project_internal_dir("runs")
+    }
+
+    /// Directory of the `eval` and `bench` reports
+    pub fn eval_dir(&self) -> PathBuf {
+        self.project_internal_dir("eval")
+    }
+
+    /// Per-file index of the previous preprocessing run
+    pub fn preprocess_index_path(&self) -> PathBuf {
+        self.project_internal_dir("index").join("preprocess.json")
+    }
+
+    /// `<internal_path>/<name>`, followed by the sub-project path for sub-projects of a monorepo
+    fn project_internal_dir(&self, name: &str) -> PathBuf {
+        let dir = self.internal_path.join(name);
+        match self
+            .internal_path
+            .parent()
+            .and_then(|root| self.project_path.strip_prefix(root).ok())
+        {
+            Some(relative) if !relative.as_os_str().is_empty() => dir.join(relative),
+            _ => dir,
+        }
+    }
+
     /// Get project name, prioritize configured project_name, otherwise auto-infer
     pub fn get_project_name(&self) -> String {
         // Prioritize configured project name
@@ -624,16 +1711,24 @@ impl Default for Config {
         Self {
             project_name: None,
             project_path: PathBuf::from("."),
+            git_ref: None,
             output_path: PathBuf::from("./litho.docs"),
             internal_path: PathBuf::from("./.litho"),
             target_language: TargetLanguage::default(),
+            ui_language: None,
+            resume: false,
             analyze_dependencies: true,
             identify_components: true,
             max_depth: 10,
             max_file_size: 512 * 1024, // 512KB
+            limits: LimitsConfig::default(),
             include_tests: false,
             include_hidden: false,
             git_tracked_only: true,
+            respect_gitignore: true,
+            incremental_preprocessing: true,
+            symlink_policy: SymlinkPolicy::default(),
+            ownership_from_git: false,
             excluded_dirs: vec![
                 ".litho".to_string(),
                 "litho.docs".to_string(),
@@ -685,11 +1780,28 @@ impl Default for Config {
                 "archive".to_string(),
             ],
             included_extensions: vec![],
+            included_dirs: vec![],
             architecture_meta_path: None,
             llm: LLMConfig::default(),
             cache: CacheConfig::default(),
             knowledge: KnowledgeConfig::default(),
             boundary_analysis: BoundaryAnalysisConfig::default(),
+            projects: Vec::new(),
+            notifications: NotificationConfig::default(),
+            output: OutputConfig::default(),
+            memory: MemoryConfig::default(),
+            sampling: SamplingConfig::default(),
+            formatter: Default::default(),
+            importance: ImportanceConfig::default(),
+            link_check: LinkCheckConfig::default(),
+            path_check: PathCheckConfig::default(),
+            confidence: ConfidenceConfig::default(),
+            escalation: EscalationConfig::default(),
+            versioning: VersioningConfig::default(),
+            api: ApiConfig::default(),
+            ci: CiConfig::default(),
+            redaction: RedactionConfig::default(),
+            privacy: PrivacyConfig::default(),
         }
     }
 }
@@ -706,11 +1818,104 @@ impl Default for LLMConfig {
             temperature: Some(0.1),
             retry_attempts: 3,
             retry_delay_ms: 5000,
+            retry_max_delay_ms: default_retry_max_delay_ms(),
             timeout_seconds: 300,
             disable_preset_tools: false,
             max_parallels: 3,
             max_turns: 100,
             tool_concurrency: 4,
+            context_window: None,
+        }
+    }
+}
+
+impl Default for MemoryConfig {
+    fn default() -> Self {
+        Self {
+            warning_threshold_mb: default_memory_warning_mb(),
+            spill_threshold_mb: default_memory_spill_mb(),
+            keep_runs: default_keep_runs(),
+        }
+    }
+}
+
+impl Default for LinkCheckConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            fix: false,
+        }
+    }
+}
+
+impl Default for PathCheckConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            unknown_paths: UnknownPathPolicy::default(),
+        }
+    }
+}
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_entropy: default_redaction_min_entropy(),
+            extra_patterns: Vec::new(),
+        }
+    }
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        Self {
+            bind: default_api_bind(),
+            work_dir: default_api_work_dir(),
+            token_env: None,
+            project_roots: Vec::new(),
+        }
+    }
+}
+
+impl Default for ConfidenceConfig {
+    fn default() -> Self {
+        Self {
+            min_score: None,
+            max_retries: default_confidence_retries(),
+        }
+    }
+}
+
+impl Default for EscalationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_output_chars: default_min_output_chars(),
+        }
+    }
+}
+
+impl Default for ImportanceConfig {
+    fn default() -> Self {
+        Self {
+            centrality_weight: default_centrality_weight(),
+            churn_weight: default_churn_weight(),
+            history_days: default_history_days(),
+            recency_half_life_days: default_recency_half_life_days(),
+        }
+    }
+}
+
+impl Default for SamplingConfig {
+    fn default() -> Self {
+        Self {
+            strategy: SamplingStrategy::default(),
+            min_files: default_sampling_min_files(),
+            max_files: default_sampling_max_files(),
+            per_directory: default_sampling_per_directory(),
+            language_quotas: std::collections::HashMap::new(),
+            default_language_quota: default_sampling_language_quota(),
         }
     }
 }
@@ -747,4 +1952,164 @@ mod tests {
         assert_eq!(config.include_source_code, false);
         assert_eq!(config.only_directories_when_files_more_than, Some(100));
     }
+
+    #[test]
+    fn test_provider_names_round_trip() {
+        for provider in LLMProvider::all() {
+            assert_eq!(provider.to_string().parse::<LLMProvider>(), Ok(provider.clone()));
+        }
+        assert!("gpt".parse::<LLMProvider>().is_err());
+    }
+
+    #[test]
+    fn test_sub_project_config_derivation() {
+        let config: Config = toml::from_str(
+            r#"
+            project_path = "/repo"
+            output_path = "/repo/litho.docs"
+
+            [[projects]]
+            name = "payment service"
+            path = "services/payment"
+            "#,
+        )
+        .unwrap();
+
+        let sub_config = config.for_sub_project(&config.projects[0]).unwrap();
+        assert_eq!(sub_config.project_path, PathBuf::from("/repo/services/payment"));
+        assert_eq!(sub_config.output_path, PathBuf::from("/repo/litho.docs/payment-service"));
+        assert_eq!(sub_config.get_project_name(), "payment service");
+        assert!(sub_config.projects.is_empty());
+
+        for path in ["../sibling", "/etc", "services/../../outside", "./services", ""] {
+            let sub_project = SubProjectConfig {
+                name: "escape".to_string(),
+                path: PathBuf::from(path),
+                description: String::new(),
+            };
+            assert!(config.for_sub_project(&sub_project).is_err(), "{path}");
+        }
+    }
+
+    #[test]
+    fn test_output_filename_overrides() {
+        let config: Config = toml::from_str(
+            r#"
+            target_language = "zh"
+
+            [output.filenames]
+            overview = "index.md"
+            deep_exploration = "modules"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.doc_filename("overview"), "index.md");
+        assert_eq!(config.doc_filename("workflow"), "3、工作流程.md");
+        assert_eq!(config.directory_name("deep_exploration"), "modules");
+    }
+
+    #[test]
+    fn test_document_languages() {
+        let config: Config = toml::from_str(
+            r#"
+            target_language = "ja"
+
+            [output.languages]
+            overview = ["en", "ja", "zh", "en"]
+            deep_exploration = ["en"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.document_languages("overview"),
+            (TargetLanguage::Japanese, vec![TargetLanguage::English, TargetLanguage::Chinese])
+        );
+        assert_eq!(config.document_languages("deep_exploration"), (TargetLanguage::English, vec![]));
+        assert_eq!(config.document_languages("workflow"), (TargetLanguage::Japanese, vec![]));
+        assert_eq!(config.directory_name("deep_exploration"), "4.Deep-Exploration");
+    }
+
+    #[test]
+    fn test_confidence_settings() {
+        let config: Config = toml::from_str(
+            r#"
+            [confidence]
+            min_score = 6.5
+
+            [escalation]
+            min_output_chars = 800
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.confidence.min_score, Some(6.5));
+        assert_eq!(config.confidence.max_retries, 1);
+        assert_eq!(Config::default().confidence.min_score, None);
+        assert!(config.escalation.enabled);
+        assert_eq!(config.escalation.min_output_chars, 800);
+    }
+
+    #[test]
+    fn test_file_size_limits() {
+        let config: Config = toml::from_str(
+            r#"
+            max_file_size = 65536
+
+            [limits]
+            per_extension = { sql = 1048576, ".ts" = 32768 }
+            core_file_size = 262144
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.max_file_size_for(Path::new("db/schema.SQL"), false), 1048576);
+        assert_eq!(config.max_file_size_for(Path::new("src/api/client.ts"), false), 32768);
+        assert_eq!(config.max_file_size_for(Path::new("src/api/client.ts"), true), 262144);
+        assert_eq!(config.max_file_size_for(Path::new("db/schema.sql"), true), 1048576);
+        assert_eq!(config.max_file_size_for(Path::new("src/main.rs"), false), 65536);
+        assert_eq!(Config::default().max_file_size_for(Path::new("src/main.rs"), true), 512 * 1024);
+    }
+
+    #[test]
+    fn test_formatter_overrides() {
+        use crate::generator::step_forward_agent::FormatterConfig;
+
+        let config: Config = toml::from_str(
+            r#"
+            [formatter.architecture_researcher]
+            include_source_code = true
+            code_insights_share = 0.4
+
+            [formatter.overview]
+            readme_truncate_length = 0
+            enable_compression = false
+            "#,
+        )
+        .unwrap();
+
+        let architecture = FormatterConfig::default().with_overrides(&config.formatter["architecture_researcher"]);
+        assert!(architecture.include_source_code);
+        assert_eq!(architecture.code_insights_share, 0.4);
+        assert_eq!(architecture.dependency_share, FormatterConfig::default().dependency_share);
+        let overview = FormatterConfig::default().with_overrides(&config.formatter["overview"]);
+        assert_eq!(overview.readme_truncate_length, None);
+        assert!(!overview.enable_compression);
+        assert!(toml::from_str::<Config>("[formatter.overview]\ninclude_source = true\n").is_err());
+    }
+
+    #[test]
+    fn test_context_window() {
+        let mut llm = LLMConfig {
+            model_efficient: "gpt-4o-mini".to_string(),
+            model_powerful: "claude-sonnet-4".to_string(),
+            ..LLMConfig::default()
+        };
+        assert_eq!(llm.context_window(), 128_000);
+        llm.provider = LLMProvider::Ollama;
+        assert_eq!(llm.context_window(), 8_192);
+        llm.context_window = Some(32_000);
+        assert_eq!(llm.context_window(), 32_000);
+    }
 }
@@ -0,0 +1,306 @@
+//! Local documentation browser
+//!
+//! Serves the generated output over HTTP for review without a static-site pipeline:
+//! Markdown documents are rendered to HTML with a navigation of all documents, Mermaid
+//! diagrams are drawn in the browser, and `/search?q=` searches the documents the same way
+//! the `ask` command retrieves them.
+
+use anyhow::{Context, Result};
+use regex::{Captures, Regex};
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+use tokio::io::{AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, info};
+use walkdir::WalkDir;
+
+use crate::ask;
+use crate::config::Config;
+use crate::generator::outlet::navigation::slugify;
+use crate::http::{Request, Response, percent_decode, read_request};
+
+static HEADING_TAG_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<h([1-6])>(.*?)</h[1-6]>").unwrap());
+static MERMAID_BLOCK_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?s)<pre><code class="language-mermaid">(.*?)</code></pre>"#).unwrap());
+static TAG_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+
+const MERMAID_SCRIPT: &str = r#"<script type="module">
+import mermaid from "https://cdn.jsdelivr.net/npm/mermaid@11/dist/mermaid.esm.min.mjs";
+mermaid.initialize({ startOnLoad: true, securityLevel: "strict" });
+</script>"#;
+
+const STYLE: &str = "body{margin:0;display:flex;font-family:-apple-system,'Segoe UI',Helvetica,Arial,sans-serif;color:#1f2328;line-height:1.6}\
+nav{width:280px;flex-shrink:0;height:100vh;overflow-y:auto;position:sticky;top:0;padding:16px;box-sizing:border-box;background:#f6f8fa;border-right:1px solid #d0d7de;font-size:14px}\
+nav a{display:block;padding:2px 0;color:#1f2328;text-decoration:none}nav a:hover{color:#0969da}nav .dir{margin-top:12px;font-weight:600;color:#59636e}\
+nav form input{width:100%;box-sizing:border-box;padding:6px;margin-bottom:12px;border:1px solid #d0d7de;border-radius:6px}\
+main{flex:1;min-width:0;max-width:980px;padding:24px 48px}main a{color:#0969da}\
+pre{background:#f6f8fa;padding:12px;border-radius:6px;overflow-x:auto}code{font-size:85%}\
+pre.mermaid{background:none;text-align:center}table{border-collapse:collapse}th,td{border:1px solid #d0d7de;padding:6px 12px}\
+.result{margin-bottom:20px}.result small{color:#59636e}";
+
+/// HTTP server browsing the generated documentation
+pub struct DocsServer {
+    config: Arc<Config>,
+}
+
+impl DocsServer {
+    pub fn new(config: Config) -> Self {
+        Self {
+            config: Arc::new(config),
+        }
+    }
+
+    /// Accept connections until the process is stopped
+    pub async fn serve(&self, bind: &str) -> Result<()> {
+        if !self.config.output_path.is_dir() {
+            anyhow::bail!(
+                "No generated documentation at {}, run the generation first",
+                self.config.output_path.display()
+            );
+        }
+        let listener = TcpListener::bind(bind)
+            .await
+            .with_context(|| format!("Cannot listen on {}", bind))?;
+        info!(
+            "📖 Browsing {} on http://{}",
+            self.config.output_path.display(),
+            bind
+        );
+
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            let config = self.config.clone();
+            tokio::spawn(async move {
+                if let Err(e) = handle_connection(&config, stream).await {
+                    debug!("Docs connection from {} failed: {:#}", peer, e);
+                }
+            });
+        }
+    }
+}
+
+async fn handle_connection(config: &Config, mut stream: TcpStream) -> Result<()> {
+    let (reader, mut writer) = stream.split();
+    let response = match read_request(&mut BufReader::new(reader)).await {
+        Ok(request) => route(config, request).await,
+        Err(e) => Response::html(400, escape_html(&format!("{:#}", e))),
+    };
+    writer.write_all(&response.into_bytes()).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+async fn route(config: &Config, request: Request) -> Response {
+    debug!("Docs request: {} {}", request.method, request.path);
+    if request.method != "GET" {
+        return Response::html(400, "Only GET requests are served".to_string());
+    }
+    let documents = documents(&config.output_path);
+    let project_name = config.get_project_name();
+
+    if request.path == "/search" {
+        let query = request.query_param("q").unwrap_or_default();
+        let passages = ask::retrieve(config, &query, 20).await;
+        let content = search_results(&config.output_path, &query, &passages);
+        return Response::html(200, page(&project_name, &format!("Search: {}", query), &documents, &content));
+    }
+
+    let relative = match request.path.trim_start_matches('/') {
+        "" => match documents.first() {
+            Some(first) => first.clone(),
+            None => return Response::html(404, "No documents generated".to_string()),
+        },
+        path => percent_decode(path),
+    };
+    let Some(path) = resolve(&config.output_path, &relative) else {
+        return Response::html(404, format!("{} not found", escape_html(&relative)));
+    };
+
+    if path.extension().is_some_and(|ext| ext == "md") {
+        match std::fs::read_to_string(&path) {
+            Ok(markdown) => {
+                let title = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+                let html = render_markdown(&markdown);
+                Response::html(200, page(&project_name, &title, &documents, &html))
+            }
+            Err(e) => Response::html(500, escape_html(&e.to_string())),
+        }
+    } else {
+        match std::fs::read(&path) {
+            Ok(body) => Response {
+                status: 200,
+                content_type: content_type(&path),
+                headers: Vec::new(),
+                body,
+            },
+            Err(e) => Response::html(500, escape_html(&e.to_string())),
+        }
+    }
+}
+
+/// File under the output directory; paths escaping it are rejected
+fn resolve(output_path: &Path, relative: &str) -> Option<PathBuf> {
+    let relative = Path::new(relative);
+    if !relative.components().all(|component| matches!(component, Component::Normal(_))) {
+        return None;
+    }
+    Some(output_path.join(relative)).filter(|path| path.is_file())
+}
+
+/// Markdown documents of the output directory, relative to it with `/` separators
+fn documents(output_path: &Path) -> Vec<String> {
+    let mut documents: Vec<String> = WalkDir::new(output_path)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file() && entry.path().extension().is_some_and(|ext| ext == "md"))
+        .filter_map(|entry| {
+            let relative = entry.path().strip_prefix(output_path).ok()?;
+            Some(relative.components().map(|c| c.as_os_str().to_string_lossy()).collect::<Vec<_>>().join("/"))
+        })
+        .collect();
+    // Top-level documents first, each group in name order
+    documents.sort_by(|a, b| (a.contains('/'), a).cmp(&(b.contains('/'), b)));
+    documents
+}
+
+/// HTML of a document, with GitHub heading anchors and Mermaid blocks left to the browser
+fn render_markdown(markdown: &str) -> String {
+    let html = markdown::to_html_with_options(markdown, &markdown::Options::gfm())
+        .unwrap_or_else(|_| format!("<pre>{}</pre>", escape_html(markdown)));
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let html = HEADING_TAG_REGEX.replace_all(&html, |captures: &Captures| {
+        let text = unescape_html(&TAG_REGEX.replace_all(&captures[2], ""));
+        let slug = slugify(&text);
+        let count = counts.entry(slug.clone()).or_default();
+        let anchor = if *count == 0 { slug.clone() } else { format!("{}-{}", slug, count) };
+        *count += 1;
+        format!("<h{level} id=\"{}\">{}</h{level}>", anchor, &captures[2], level = &captures[1])
+    });
+    MERMAID_BLOCK_REGEX
+        .replace_all(&html, r#"<pre class="mermaid">$1</pre>"#)
+        .into_owned()
+}
+
+fn search_results(output_path: &Path, query: &str, passages: &[ask::Passage]) -> String {
+    let mut html = format!("<h1>Search: {}</h1>\n", escape_html(query));
+    if passages.is_empty() {
+        html.push_str("<p>No matching sections.</p>\n");
+    }
+    for passage in passages {
+        // The section text without its heading line
+        let body = passage.text.split_once('\n').map_or("", |(_, body)| body);
+        let snippet: String = body.trim().chars().take(280).collect();
+        let source = if output_path.join(&passage.source).is_file() {
+            format!(
+                "<a href=\"/{}#{}\">{}</a>",
+                encode_path(&passage.source),
+                slugify(&passage.title),
+                escape_html(&passage.title)
+            )
+        } else {
+            escape_html(&passage.title)
+        };
+        html.push_str(&format!(
+            "<div class=\"result\">{}<br><small>{} · {} · {}</small><p>{}…</p></div>\n",
+            source,
+            escape_html(&passage.source),
+            passage.kind,
+            escape_html(&passage.relevance),
+            escape_html(&snippet)
+        ));
+    }
+    html
+}
+
+/// Page with the navigation of all documents and the content
+fn page(project_name: &str, title: &str, documents: &[String], content: &str) -> String {
+    let mut nav = format!(
+        "<a href=\"/\"><strong>{}</strong></a>\n<form action=\"/search\"><input name=\"q\" placeholder=\"Search\"></form>\n",
+        escape_html(project_name)
+    );
+    let mut directory = "";
+    for document in documents {
+        let (parent, name) = document.rsplit_once('/').unwrap_or(("", document));
+        if parent != directory {
+            nav.push_str(&format!("<div class=\"dir\">{}</div>\n", escape_html(parent)));
+            directory = parent;
+        }
+        nav.push_str(&format!(
+            "<a href=\"/{}\">{}</a>\n",
+            encode_path(document),
+            escape_html(name.trim_end_matches(".md"))
+        ));
+    }
+    let mermaid = if content.contains("<pre class=\"mermaid\">") { MERMAID_SCRIPT } else { "" };
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{} - {}</title>\n<style>{}</style>\n</head>\n<body>\n<nav>\n{}</nav>\n<main>\n{}\n</main>\n{}\n</body>\n</html>\n",
+        escape_html(title),
+        escape_html(project_name),
+        STYLE,
+        nav,
+        content,
+        mermaid
+    )
+}
+
+fn content_type(path: &Path) -> &'static str {
+    match path.extension().and_then(|ext| ext.to_str()).unwrap_or_default() {
+        "html" => "text/html; charset=utf-8",
+        "json" => "application/json",
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "css" => "text/css",
+        "js" => "text/javascript",
+        "txt" | "toml" | "yaml" | "yml" => "text/plain; charset=utf-8",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Percent-encode a relative path for a link, keeping the `/` separators
+fn encode_path(path: &str) -> String {
+    path.bytes()
+        .map(|byte| match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn unescape_html(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_and_navigation() {
+        let html = render_markdown("# Overview\n\n## Data & Flow\n\n```mermaid\ngraph TD\n    A --> B\n```\n\n## Data & Flow\n");
+        assert!(html.contains("<h1 id=\"overview\">Overview</h1>"));
+        assert!(html.contains("<h2 id=\"data--flow\">Data &amp; Flow</h2>"));
+        assert!(html.contains("<h2 id=\"data--flow-1\">"));
+        assert!(html.contains("<pre class=\"mermaid\">graph TD\n    A --&gt; B\n</pre>"));
+
+        let page = page("Shop", "Overview", &["1.Overview.md".to_string(), "4.Deep-Exploration/Order Flow.md".to_string()], &html);
+        assert!(page.contains("<div class=\"dir\">4.Deep-Exploration</div>\n<a href=\"/4.Deep-Exploration/Order%20Flow.md\">Order Flow</a>"));
+        assert!(page.contains("mermaid.esm.min.mjs"));
+
+        assert_eq!(percent_decode("4.Deep-Exploration/Order%20Flow.md"), "4.Deep-Exploration/Order Flow.md");
+        assert!(resolve(Path::new("/tmp"), "../etc/passwd").is_none());
+    }
+}
@@ -3,7 +3,10 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::generator::context::GeneratorContext;
-use crate::llm::client::utils::estimate_token_usage;
+use crate::llm::client::utils::{estimate_token_usage, evaluate_befitting_model};
+use crate::utils::token_estimator::TokenEstimator;
+use crate::utils::progress::progress_bars_enabled;
+use tracing::{debug, info};
 
 pub struct AgentExecuteParams {
     pub prompt_sys: String,
@@ -15,12 +18,24 @@ pub struct AgentExecuteParams {
 }
 
 pub async fn prompt(context: &GeneratorContext, params: AgentExecuteParams) -> Result<String> {
+    prompt_using(context, params, None).await
+}
+
+/// Prompt the given model instead of the efficient one
+pub async fn prompt_with_model(context: &GeneratorContext, params: AgentExecuteParams, model: &str) -> Result<String> {
+    prompt_using(context, params, Some(model)).await
+}
+
+async fn prompt_using(context: &GeneratorContext, params: AgentExecuteParams, model: Option<&str>) -> Result<String> {
     let prompt_sys = &params.prompt_sys;
     let prompt_user = &params.prompt_user;
     let cache_scope = &params.cache_scope;
     let log_tag = &params.log_tag;
 
-    let prompt_key = format!("{}|{}|reply-prompt", prompt_sys, prompt_user);
+    let prompt_key = match model {
+        Some(model) => format!("{}|{}|reply-prompt|{}", prompt_sys, prompt_user, model),
+        None => format!("{}|{}|reply-prompt", prompt_sys, prompt_user),
+    };
     // Try to get from cache - Use prompt directly as key, CacheManager will automatically calculate hash
     if let Some(cached_reply) = context
         .cache_manager
@@ -29,28 +44,30 @@ pub async fn prompt(context: &GeneratorContext, params: AgentExecuteParams) -> R
         .get::<serde_json::Value>(cache_scope, &prompt_key)
         .await?
     {
-        let msg = context.config.target_language.msg_cache_hit().replace("{}", log_tag);
-        println!("{}", msg);
+        let msg = context.config.ui_language().msg_cache_hit(log_tag);
+        info!("{}", msg);
         return Ok(cached_reply.to_string());
     }
 
     let (current, total) = params.progress.unwrap_or((1, 1));
-    let msg = context.config.target_language.msg_ai_analyzing();
-    let msg = msg
-        .replacen("{}", &current.to_string(), 1)
-        .replacen("{}", &total.to_string(), 1)
-        .replacen("{}", log_tag, 1);
-    println!("{}", msg);
+    let msg = context.config.ui_language().msg_ai_analyzing(current, total, log_tag);
+    log_analyzing(&msg);
+
+    let model = model.unwrap_or(&context.config.llm.model_efficient);
+    log_prompt_size(model, log_tag, prompt_sys, prompt_user);
 
     let reply = context
         .llm_client
-        .prompt_without_react(prompt_sys, prompt_user)
+        .prompt_without_react_with_model(prompt_sys, prompt_user, model)
         .await
         .map_err(|e| anyhow::anyhow!("AI analysis failed: {}", e))?;
 
     // Estimate token usage
     let input_text = format!("{} {}", prompt_sys, prompt_user);
-    let token_usage = estimate_token_usage(&input_text, &reply);
+    let token_usage = estimate_token_usage(model, &input_text, &reply);
+    context
+        .llm_client
+        .record_token_usage(&usage_agent(cache_scope), model, &token_usage);
 
     // Cache result - Use method with token information
     context
@@ -81,18 +98,16 @@ pub async fn prompt_with_tools(
         .get::<serde_json::Value>(cache_scope, &prompt_key)
         .await?
     {
-        let msg = context.config.target_language.msg_cache_hit().replace("{}", log_tag);
-        println!("{}", msg);
+        let msg = context.config.ui_language().msg_cache_hit(log_tag);
+        info!("{}", msg);
         return Ok(cached_reply.to_string());
     }
 
     let (current, total) = params.progress.unwrap_or((1, 1));
-    let msg = context.config.target_language.msg_ai_analyzing();
-    let msg = msg
-        .replacen("{}", &current.to_string(), 1)
-        .replacen("{}", &total.to_string(), 1)
-        .replacen("{}", log_tag, 1);
-    println!("{}", msg);
+    let msg = context.config.ui_language().msg_ai_analyzing(current, total, log_tag);
+    log_analyzing(&msg);
+
+    log_prompt_size(&context.config.llm.model_efficient, log_tag, prompt_sys, prompt_user);
 
     let reply = context
         .llm_client
@@ -103,7 +118,10 @@ pub async fn prompt_with_tools(
     // Estimate token usage
     let input_text = format!("{} {}", prompt_sys, prompt_user);
     let output_text = serde_json::to_string(&reply).unwrap_or_default();
-    let token_usage = estimate_token_usage(&input_text, &output_text);
+    let token_usage = estimate_token_usage(&context.config.llm.model_efficient, &input_text, &output_text);
+    context
+        .llm_client
+        .record_token_usage(&usage_agent(cache_scope), &context.config.llm.model_efficient, &token_usage);
 
     // Cache result - Use method with token information
     context
@@ -117,6 +135,21 @@ pub async fn prompt_with_tools(
 }
 
 pub async fn extract<T>(context: &GeneratorContext, params: AgentExecuteParams) -> Result<T>
+where
+    T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
+{
+    extract_using(context, params, None).await
+}
+
+/// Extract with the given model instead of the one selected for the prompt size
+pub async fn extract_with_model<T>(context: &GeneratorContext, params: AgentExecuteParams, model: &str) -> Result<T>
+where
+    T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
+{
+    extract_using(context, params, Some(model)).await
+}
+
+async fn extract_using<T>(context: &GeneratorContext, params: AgentExecuteParams, model: Option<&str>) -> Result<T>
 where
     T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
 {
@@ -125,7 +158,10 @@ where
     let cache_scope = &params.cache_scope;
     let log_tag = &params.log_tag;
 
-    let prompt_key = format!("{}|{}", prompt_sys, prompt_user);
+    let prompt_key = match model {
+        Some(model) => format!("{}|{}|{}", prompt_sys, prompt_user, model),
+        None => format!("{}|{}", prompt_sys, prompt_user),
+    };
     // Try to get from cache - Use prompt directly as key, CacheManager will automatically calculate hash
     if let Some(cached_reply) = context
         .cache_manager
@@ -134,29 +170,35 @@ where
         .get::<T>(cache_scope, &prompt_key)
         .await?
     {
-        let msg = context.config.target_language.msg_cache_hit().replace("{}", log_tag);
-        println!("{}", msg);
+        let msg = context.config.ui_language().msg_cache_hit(log_tag);
+        info!("{}", msg);
         return Ok(cached_reply);
     }
 
     let (current, total) = params.progress.unwrap_or((1, 1));
-    let msg = context.config.target_language.msg_ai_analyzing();
-    let msg = msg
-        .replacen("{}", &current.to_string(), 1)
-        .replacen("{}", &total.to_string(), 1)
-        .replacen("{}", log_tag, 1);
-    println!("{}", msg);
-
-    let reply = context
-        .llm_client
-        .extract::<T>(prompt_sys, prompt_user)
-        .await
-        .map_err(|e| anyhow::anyhow!("AI analysis failed: {}", e))?;
+    let msg = context.config.ui_language().msg_ai_analyzing(current, total, log_tag);
+    log_analyzing(&msg);
+
+    let estimation_model = match model {
+        Some(model) => model.to_string(),
+        None => evaluate_befitting_model(&context.config.llm, prompt_sys, prompt_user).0,
+    };
+    let estimation_model = estimation_model.as_str();
+    log_prompt_size(estimation_model, log_tag, prompt_sys, prompt_user);
+
+    let reply = match model {
+        Some(model) => context.llm_client.extract_with_model::<T>(prompt_sys, prompt_user, model).await,
+        None => context.llm_client.extract::<T>(prompt_sys, prompt_user).await,
+    }
+    .map_err(|e| anyhow::anyhow!("AI analysis failed: {}", e))?;
 
     // Estimate token usage
     let input_text = format!("{} {}", prompt_sys, prompt_user);
     let output_text = serde_json::to_string(&reply).unwrap_or_default();
-    let token_usage = estimate_token_usage(&input_text, &output_text);
+    let token_usage = estimate_token_usage(estimation_model, &input_text, &output_text);
+    context
+        .llm_client
+        .record_token_usage(&usage_agent(cache_scope), estimation_model, &token_usage);
 
     // Cache result - Use method with token information
     context
@@ -168,3 +210,29 @@ where
 
     Ok(reply)
 }
+
+/// Agent a call's token usage is attributed to: the cache scope without the item
+/// (domain, directory, ...) some agents append to it
+fn usage_agent(cache_scope: &str) -> String {
+    cache_scope.splitn(3, '/').take(2).collect::<Vec<_>>().join("/")
+}
+
+/// N-of-M lines are redundant while a progress bar shows the same information
+fn log_analyzing(msg: &str) {
+    if progress_bars_enabled() {
+        debug!("{}", msg);
+    } else {
+        info!("{}", msg);
+    }
+}
+
+/// Prompt sizes are only interesting when tuning limits (-v)
+fn log_prompt_size(model: &str, log_tag: &str, prompt_sys: &str, prompt_user: &str) {
+    debug!(
+        "   📏 [{}] prompt size: system {} chars, user {} chars, ~{} tokens",
+        log_tag,
+        prompt_sys.chars().count(),
+        prompt_user.chars().count(),
+        TokenEstimator::for_model(model).estimate_request_tokens(prompt_sys, prompt_user)
+    );
+}
@@ -0,0 +1,231 @@
+//! Model benchmark: a fixed subset of research agents runs once per model against the memory
+//! snapshot of the last run, reporting latency, token cost, failure rate and confidence
+
+use anyhow::{Result, bail};
+use chrono::Local;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use crate::config::Config;
+use crate::generator::eval::{self, AgentRun};
+use crate::generator::research::orchestrator::ResearchOrchestrator;
+use crate::generator::research::types::AgentType;
+
+/// Agents benchmarked by default: one per research layer, each reporting a confidence score
+pub const DEFAULT_AGENTS: [AgentType; 3] = [
+    AgentType::SystemContextResearcher,
+    AgentType::DomainModulesDetector,
+    AgentType::BoundaryAnalyzer,
+];
+
+/// Result of one agent with one model
+#[derive(Debug, Serialize)]
+pub struct AgentBenchmark {
+    pub agent: String,
+    pub seconds: f64,
+    pub llm_calls: usize,
+    pub tokens: usize,
+    pub estimated_cost: f64,
+    /// `confidence_score` of the output, for agents reporting one
+    pub confidence: Option<f64>,
+    pub error: Option<String>,
+}
+
+/// Results of all agents with one model
+#[derive(Debug, Serialize)]
+pub struct ModelBenchmark {
+    pub model: String,
+    pub seconds: f64,
+    pub tokens: usize,
+    pub estimated_cost: f64,
+    /// Share of the agents that failed, from 0 to 1
+    pub failure_rate: f64,
+    pub mean_confidence: Option<f64>,
+    pub agents: Vec<AgentBenchmark>,
+}
+
+/// Report written by `litho bench`
+#[derive(Debug, Serialize)]
+pub struct BenchReport {
+    pub created_at: String,
+    /// Memory snapshot every model started from
+    pub snapshot: PathBuf,
+    pub models: Vec<ModelBenchmark>,
+}
+
+/// Agents named in `keys` in pipeline order, the default subset when `keys` is empty
+pub fn select_agents(keys: &[String]) -> Result<Vec<AgentType>> {
+    if keys.is_empty() {
+        return Ok(DEFAULT_AGENTS.to_vec());
+    }
+    eval::select_agents(keys)
+}
+
+/// Run the agents once per model, each model used as both efficient and powerful model
+pub async fn benchmark(config: &Config, models: &[String], agents: &[AgentType]) -> Result<BenchReport> {
+    if models.is_empty() {
+        bail!("No models to benchmark");
+    }
+    let snapshot = config.memory_dir();
+    let orchestrator = ResearchOrchestrator;
+
+    let mut benchmarks = Vec::new();
+    for model in models {
+        info!("⏱️  Benchmarking {} on {} agents", model, agents.len());
+        let mut model_config = config.clone();
+        model_config.llm.model_efficient = model.clone();
+        model_config.llm.model_powerful = model.clone();
+        let context = eval::variant_context(&model_config, &snapshot)?;
+
+        let mut runs = Vec::new();
+        for agent in agents {
+            runs.push((agent.clone(), eval::run_agent(&orchestrator, agent, &context).await));
+        }
+        benchmarks.push(ModelBenchmark::from_runs(model, runs));
+    }
+
+    Ok(BenchReport {
+        created_at: Local::now().to_rfc3339(),
+        snapshot,
+        models: benchmarks,
+    })
+}
+
+impl ModelBenchmark {
+    fn from_runs(model: &str, runs: Vec<(AgentType, AgentRun)>) -> Self {
+        let agents: Vec<AgentBenchmark> = runs
+            .into_iter()
+            .map(|(agent, run)| AgentBenchmark {
+                agent: agent.config_key().to_string(),
+                seconds: run.duration_seconds,
+                llm_calls: run.llm_calls,
+                tokens: run.total_tokens,
+                estimated_cost: run.estimated_cost,
+                confidence: run
+                    .output
+                    .as_ref()
+                    .and_then(|output| output.get("confidence_score"))
+                    .and_then(|score| score.as_f64()),
+                error: run.error,
+            })
+            .collect();
+        let failures = agents.iter().filter(|agent| agent.error.is_some()).count();
+        let confidences: Vec<f64> = agents.iter().filter_map(|agent| agent.confidence).collect();
+        Self {
+            model: model.to_string(),
+            seconds: agents.iter().map(|agent| agent.seconds).sum(),
+            tokens: agents.iter().map(|agent| agent.tokens).sum(),
+            estimated_cost: agents.iter().map(|agent| agent.estimated_cost).sum(),
+            failure_rate: failures as f64 / agents.len().max(1) as f64,
+            mean_confidence: (!confidences.is_empty())
+                .then(|| confidences.iter().sum::<f64>() / confidences.len() as f64),
+            agents,
+        }
+    }
+}
+
+impl BenchReport {
+    /// Write the report as markdown and JSON to `dir`, returning the markdown path
+    pub fn save(&self, dir: &Path) -> Result<PathBuf> {
+        std::fs::create_dir_all(dir)?;
+        let name = format!("bench-{}", Local::now().format("%Y%m%d-%H%M%S"));
+        let markdown = dir.join(format!("{}.md", name));
+        std::fs::write(&markdown, self.to_markdown())?;
+        std::fs::write(dir.join(format!("{}.json", name)), serde_json::to_string_pretty(self)?)?;
+        Ok(markdown)
+    }
+
+    /// One row per model
+    pub fn summary_table(&self) -> String {
+        let mut md = String::from("| Model | Seconds | Tokens | Est. cost ($) | Failure rate | Mean confidence |\n");
+        md.push_str("|---|---|---|---|---|---|\n");
+        for model in &self.models {
+            md.push_str(&format!(
+                "| {} | {:.1} | {} | {:.4} | {:.0}% | {} |\n",
+                model.model,
+                model.seconds,
+                model.tokens,
+                model.estimated_cost,
+                model.failure_rate * 100.0,
+                model.mean_confidence.map_or("-".to_string(), |confidence| format!("{:.1}", confidence))
+            ));
+        }
+        md
+    }
+
+    /// Summary table, then the results of each agent per model
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::from("# Model Benchmark\n\n");
+        md.push_str(&format!("- Memory snapshot: {}\n", self.snapshot.display()));
+        md.push_str(&format!("- Created at: {}\n\n", self.created_at));
+        md.push_str(&self.summary_table());
+
+        md.push_str("\n## Agents\n\n");
+        md.push_str("| Model | Agent | Seconds | Calls | Tokens | Est. cost ($) | Confidence | Error |\n");
+        md.push_str("|---|---|---|---|---|---|---|---|\n");
+        for model in &self.models {
+            for agent in &model.agents {
+                md.push_str(&format!(
+                    "| {} | {} | {:.1} | {} | {} | {:.4} | {} | {} |\n",
+                    model.model,
+                    agent.agent,
+                    agent.seconds,
+                    agent.llm_calls,
+                    agent.tokens,
+                    agent.estimated_cost,
+                    agent.confidence.map_or("-".to_string(), |confidence| format!("{:.1}", confidence)),
+                    agent.error.as_deref().unwrap_or("").replace('|', "\\|").replace('\n', " ")
+                ));
+            }
+        }
+        md
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn run(seconds: f64, output: Option<serde_json::Value>, error: Option<&str>) -> AgentRun {
+        AgentRun {
+            duration_seconds: seconds,
+            llm_calls: 1,
+            total_tokens: 1000,
+            estimated_cost: 0.002,
+            output_chars: 0,
+            output,
+            error: error.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_model_benchmark() {
+        assert_eq!(select_agents(&[]).unwrap(), DEFAULT_AGENTS);
+        assert_eq!(select_agents(&["workflow_researcher".to_string()]).unwrap(), [AgentType::WorkflowResearcher]);
+
+        let benchmark = ModelBenchmark::from_runs(
+            "gpt-4o-mini",
+            vec![
+                (AgentType::SystemContextResearcher, run(3.0, Some(json!({"confidence_score": 8.0})), None)),
+                (AgentType::DomainModulesDetector, run(5.0, Some(json!({"confidence_score": 6.0})), None)),
+                (AgentType::BoundaryAnalyzer, run(1.0, None, Some("invalid JSON | retry"))),
+                (AgentType::WorkflowResearcher, run(2.0, Some(json!({"main_workflow": {}})), None)),
+            ],
+        );
+        assert_eq!(benchmark.seconds, 11.0);
+        assert_eq!(benchmark.tokens, 4000);
+        assert_eq!(benchmark.failure_rate, 0.25);
+        assert_eq!(benchmark.mean_confidence, Some(7.0));
+
+        let report = BenchReport {
+            created_at: "2025-01-01T12:00:00+00:00".to_string(),
+            snapshot: PathBuf::from(".litho/memory"),
+            models: vec![benchmark],
+        };
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("| gpt-4o-mini | 11.0 | 4000 | 0.0080 | 25% | 7.0 |"));
+        assert!(markdown.contains("| gpt-4o-mini | boundary_analyzer | 1.0 | 1 | 1000 | 0.0020 | - | invalid JSON \\| retry |"));
+    }
+}
@@ -15,6 +15,10 @@ impl StepForwardAgent for ArchitectureEditor {
         AgentType::Architecture.to_string()
     }
 
+    fn config_key(&self) -> Option<String> {
+        Some(AgentType::Architecture.config_key().to_string())
+    }
+
     fn memory_scope_key(&self) -> String {
         MemoryScope::DOCUMENTATION.to_string()
     }
@@ -32,12 +36,13 @@ impl StepForwardAgent for ArchitectureEditor {
                 DataSource::ResearchResult(ResearchAgentType::WorkflowResearcher.to_string()),
             ],
             // Use architecture, deployment, database and ADR docs
-            optional_sources: vec![DataSource::knowledge_categories(vec![
-                "architecture",
-                "deployment",
-                "database",
-                "adr",
-            ])],
+            optional_sources: vec![
+                DataSource::ARCHITECTURE_META,
+                DataSource::ORPHAN_FILES,
+                DataSource::OWNERSHIP,
+                DataSource::DIAGRAM_ASSETS,
+                DataSource::knowledge_categories(vec!["architecture", "deployment", "database", "adr"]),
+            ],
         }
     }
 
@@ -125,7 +130,7 @@ Please generate a high-quality C4 architecture document, ensuring:
 - System boundary definition
 
 ## 3. Container View
-- Domain module division
+- Domain module division (with owning teams when code ownership is provided)
 - Domain module architecture
 - Storage design
 - Inter-domain module communication
@@ -153,6 +158,9 @@ Please generate a high-quality C4 architecture document, ensuring:
 - Deployment topology structure
 - Scalability design
 - Monitoring and operations
+
+## 8. Technical Debt
+- Dead code candidates: the unreferenced source files, when provided, with their likely purpose
 ```
 
 ### 2. Content Quality Standards
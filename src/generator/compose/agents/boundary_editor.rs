@@ -24,6 +24,10 @@ impl StepForwardAgent for BoundaryEditor {
         AgentType::Boundary.to_string()
     }
 
+    fn config_key(&self) -> Option<String> {
+        Some(AgentType::Boundary.config_key().to_string())
+    }
+
     fn memory_scope_key(&self) -> String {
         MemoryScope::DOCUMENTATION.to_string()
     }
@@ -24,6 +24,10 @@ impl StepForwardAgent for DatabaseEditor {
         AgentType::Database.to_string()
     }
 
+    fn config_key(&self) -> Option<String> {
+        Some(AgentType::Database.config_key().to_string())
+    }
+
     fn memory_scope_key(&self) -> String {
         MemoryScope::DOCUMENTATION.to_string()
     }
@@ -0,0 +1,95 @@
+use crate::generator::compose::memory::MemoryScope;
+use crate::generator::compose::types::AgentType;
+use crate::generator::context::GeneratorContext;
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
+use crate::types::dependency::DependencyInventory;
+use anyhow::Result;
+
+/// Dependencies Editor - Renders the dependency inventory of the preprocessing stage as a table
+/// per ecosystem, without using LLM
+#[derive(Default)]
+pub struct DependenciesEditor;
+
+impl DependenciesEditor {
+    pub async fn execute(&self, context: &GeneratorContext) -> Result<String> {
+        let inventory = context
+            .get_from_memory::<DependencyInventory>(PreprocessScope::PREPROCESS, ScopedKeys::DEPENDENCIES)
+            .await
+            .unwrap_or_default();
+        let content = Self::generate_dependencies_documentation(&inventory);
+
+        context
+            .store_to_memory(MemoryScope::DOCUMENTATION, &AgentType::Dependencies.to_string(), &content)
+            .await?;
+        Ok(content)
+    }
+
+    fn generate_dependencies_documentation(inventory: &DependencyInventory) -> String {
+        let mut content = String::from("# Dependencies\n\n");
+        if inventory.is_empty() {
+            content.push_str("No dependencies were detected in this project.\n");
+            return content;
+        }
+
+        content.push_str(&format!(
+            "{} dependencies ({} external) declared by the manifests or imported by the source files. \
+             Internal dependencies are packages of this project; licenses are read from the installed packages when available.\n\n",
+            inventory.dependencies.len(),
+            inventory.external_count()
+        ));
+        for (ecosystem, dependencies) in inventory.by_ecosystem() {
+            content.push_str(&format!("## {}\n\n", ecosystem.label()));
+            content.push_str("| Dependency | Version | Type | License | Used in files |\n");
+            content.push_str("|------------|---------|------|---------|---------------|\n");
+            for dependency in dependencies {
+                content.push_str(&format!(
+                    "| `{}` | {} | {} | {} | {} |\n",
+                    dependency.name,
+                    dependency.version.as_deref().map(escape_cell).unwrap_or_else(|| "-".to_string()),
+                    if dependency.is_external { "External" } else { "Internal" },
+                    dependency.license.as_deref().map(escape_cell).unwrap_or_else(|| "-".to_string()),
+                    dependency.files
+                ));
+            }
+            content.push('\n');
+        }
+        content
+    }
+}
+
+/// Version requirements such as `>=1.0,<2 || ^3` must not break the table
+fn escape_cell(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::dependency::{DependencyEntry, Ecosystem};
+
+    #[test]
+    fn test_dependencies_documentation() {
+        let entry = |name: &str, ecosystem, version: Option<&str>, is_external, license: Option<&str>| DependencyEntry {
+            name: name.to_string(),
+            ecosystem,
+            version: version.map(str::to_string),
+            is_external,
+            license: license.map(str::to_string),
+            files: 2,
+        };
+        let inventory = DependencyInventory {
+            dependencies: vec![
+                entry("serde", Ecosystem::Cargo, Some("1.0"), true, Some("MIT OR Apache-2.0")),
+                entry("shop-core", Ecosystem::Cargo, None, false, None),
+                entry("react", Ecosystem::Npm, Some("^17 || ^18"), true, None),
+            ],
+        };
+
+        let content = DependenciesEditor::generate_dependencies_documentation(&inventory);
+        assert!(content.starts_with("# Dependencies\n\n3 dependencies (2 external)"));
+        assert!(content.contains("## Cargo (crates.io)\n\n| Dependency |"));
+        assert!(content.contains("| `serde` | 1.0 | External | MIT OR Apache-2.0 | 2 |\n| `shop-core` | - | Internal | - | 2 |\n"));
+        assert!(content.contains("## npm\n\n"));
+        assert!(content.contains("| `react` | ^17 \\|\\| ^18 | External | - | 2 |\n"));
+    }
+}
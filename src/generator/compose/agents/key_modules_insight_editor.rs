@@ -6,8 +6,10 @@ use crate::generator::research::types::{AgentType as ResearchAgentType, KeyModul
 use crate::generator::step_forward_agent::{
     AgentDataConfig, DataSource, FormatterConfig, LLMCallMode, PromptTemplate, StepForwardAgent,
 };
+use crate::utils::progress::PhaseProgress;
 use crate::utils::threads::do_parallel_with_limit;
 use anyhow::Result;
+use tracing::{info, warn};
 
 #[derive(Default)]
 pub struct KeyModulesInsightEditor {}
@@ -21,11 +23,13 @@ impl KeyModulesInsightEditor {
             let insight_reports: Vec<KeyModuleReport> = serde_json::from_value(value)?;
             let max_parallels = context.config.llm.max_parallels;
 
-            println!(
+            info!(
                 "🚀 Starting concurrent analysis of insight reports, max concurrency: {}",
                 max_parallels
             );
 
+            let progress = PhaseProgress::new("📝 Module documents", insight_reports.len());
+
             // Create concurrent tasks
             let analysis_futures: Vec<_> = insight_reports
                 .into_iter()
@@ -38,9 +42,12 @@ impl KeyModulesInsightEditor {
                     let domain_name = insight_report.domain_name.clone();
                     let kmie = KeyModuleInsightEditor::new(insight_key.clone(), insight_report);
                     let context_clone = context.clone();
+                    let progress = progress.clone();
 
                     Box::pin(async move {
                         let result = kmie.execute(&context_clone).await;
+                        progress.set_message(&domain_name);
+                        progress.inc();
                         (insight_key, domain_name, result)
                     })
                 })
@@ -48,14 +55,21 @@ impl KeyModulesInsightEditor {
 
             // Use do_parallel_with_limit for concurrency control
             let analysis_results = do_parallel_with_limit(analysis_futures, max_parallels).await;
+            progress.finish();
 
             // Process results and update doc_tree
+            // A failed module document is recorded, the other modules are still written
             for (insight_key, domain_name, result) in analysis_results {
-                result?; // Check for errors
+                if let Err(e) = result {
+                    warn!("⚠️  Failed to generate the {} module document: {:#}", domain_name, e);
+                    let item = format!("{}/{}", context.config.directory_name("deep_exploration"), domain_name);
+                    context.record_failure("compose", &item, &format!("{:#}", e)).await?;
+                    continue;
+                }
 
                 doc_tree.insert(
                     &insight_key,
-                    format!("{}/{}.md", context.config.target_language.get_directory_name("deep_exploration"), &domain_name).as_str(),
+                    format!("{}/{}.md", context.config.directory_name("deep_exploration"), &domain_name).as_str(),
                 );
             }
         }
@@ -85,6 +99,10 @@ impl StepForwardAgent for KeyModuleInsightEditor {
         self.insight_key.to_string()
     }
 
+    fn config_key(&self) -> Option<String> {
+        Some("key_modules_editor".to_string())
+    }
+
     fn memory_scope_key(&self) -> String {
         MemoryScope::DOCUMENTATION.to_string()
     }
@@ -117,6 +135,7 @@ impl StepForwardAgent for KeyModuleInsightEditor {
             3. **Professionalism**: Use standard architecture terminology and expressions
             4. **Readability**: Clear structure, rich language narrative, and easy to understand
             5. **Practicality**: Provide valuable module knowledge and technical implementation details.
+            6. **Ownership**: If the research report lists `owners`, state them as the owning teams of the module near the top of the document.
             "#,
             &report.domain_name, &report.domain_name
         );
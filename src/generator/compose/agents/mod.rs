@@ -1,6 +1,7 @@
 pub mod architecture_editor;
 pub mod boundary_editor;
 pub mod database_editor;
+pub mod dependencies_editor;
 pub mod key_modules_insight_editor;
 pub mod overview_editor;
 pub mod workflow_editor;
@@ -15,6 +15,10 @@ impl StepForwardAgent for OverviewEditor {
         AgentType::Overview.to_string()
     }
 
+    fn config_key(&self) -> Option<String> {
+        Some(AgentType::Overview.config_key().to_string())
+    }
+
     fn memory_scope_key(&self) -> String {
         MemoryScope::DOCUMENTATION.to_string()
     }
@@ -31,8 +35,11 @@ impl StepForwardAgent for OverviewEditor {
             ],
             optional_sources: vec![
                 DataSource::README_CONTENT,
+                DataSource::TECHNOLOGY_COMPOSITION,
+                DataSource::LICENSE,
+                DataSource::DIAGRAM_ASSETS,
                 // Use architecture and ADR docs for overview
-                DataSource::knowledge_categories(vec!["architecture", "adr"]),
+                DataSource::knowledge_categories(vec!["architecture", "adr", "issues"]),
             ],
         }
     }
@@ -105,6 +112,7 @@ IMPORTANT: Do not use transition phrases like "Now I have gathered comprehensive
 - Project name and description
 - Core functionality and value
 - Technical characteristics overview
+- License (exactly as given by the detected project license data)
 
 ## 2. Target Users
 - User role definitions
@@ -128,6 +136,7 @@ IMPORTANT: Do not use transition phrases like "Now I have gathered comprehensive
 
 ## 6. Technical Architecture Overview
 - Main technology stack
+- Technology composition: table of files and lines per language with a Mermaid `pie` chart of the line shares (only when technology composition data is provided)
 - Architecture patterns
 - Key design decisions
 ```
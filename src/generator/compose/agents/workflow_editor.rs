@@ -15,6 +15,10 @@ impl StepForwardAgent for WorkflowEditor {
         AgentType::Workflow.to_string()
     }
 
+    fn config_key(&self) -> Option<String> {
+        Some(AgentType::Workflow.config_key().to_string())
+    }
+
     fn memory_scope_key(&self) -> String {
         MemoryScope::DOCUMENTATION.to_string()
     }
@@ -35,6 +39,7 @@ impl StepForwardAgent for WorkflowEditor {
             optional_sources: vec![DataSource::knowledge_categories(vec![
                 "workflow",
                 "architecture",
+                "issues",
             ])],
         }
     }
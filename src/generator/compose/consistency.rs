@@ -0,0 +1,335 @@
+use anyhow::Result;
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use tracing::warn;
+
+use crate::generator::compose::memory::MemoryScope;
+use crate::generator::compose::types::AgentType;
+use crate::generator::context::GeneratorContext;
+use crate::generator::outlet::run_summary::{ConsistencyIssue, RunKeys, RunScope};
+use crate::generator::preprocess::extractors::dependency_graph::DependencyGraph;
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
+use crate::generator::research::memory::MemoryRetriever;
+use crate::generator::research::types::{AgentType as ResearchAgentType, DomainModulesReport, KeyModuleReport};
+use crate::types::architecture_meta::ArchitectureMeta;
+use crate::types::project_structure::ProjectStructure;
+
+/// Name of the deep-exploration documents in the issues
+const DEEP_EXPLORATION: &str = "Deep Exploration";
+/// Name of the `architecture_meta_path` file in the issues
+const DECLARED_ARCHITECTURE: &str = "Declared Architecture";
+/// Offending dependencies quoted per broken rule
+const MAX_VIOLATION_EXAMPLES: usize = 3;
+
+/// Domain names in bold text or headings, e.g. `**Order Management Domain**` or `### 2.1 Billing Domain`
+static DOMAIN_MENTION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?m)\*\*([A-Z][\w &/-]{1,60}? Domain)\*\*|^#+\s+(?:[\d.]+\s+)?([A-Z][\w &/-]{1,60}? Domain)\s*$").unwrap()
+});
+/// Domain counts, e.g. `5 core domains` or `six domain modules`
+static DOMAIN_COUNT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(\d+|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve)\s+(?:core\s+|business\s+|main\s+|key\s+)?(?:domain modules|domains)\b").unwrap()
+});
+const NUMBER_WORDS: [&str; 11] = [
+    "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven", "twelve",
+];
+
+/// Compare the domain modules of the research with the overview, architecture and
+/// deep-exploration documents, and the declared architecture with the discovered one, and
+/// record the discrepancies so the documents (or the declaration) can be updated
+pub async fn check(context: &GeneratorContext) -> Result<Vec<ConsistencyIssue>> {
+    let report = context
+        .get_research(&ResearchAgentType::DomainModulesDetector.to_string())
+        .await
+        .and_then(|value| serde_json::from_value::<DomainModulesReport>(value).ok())
+        .unwrap_or_default();
+    let domain_paths: Vec<(String, Vec<String>)> = report
+        .domain_modules
+        .into_iter()
+        .map(|domain| (domain.name, domain.code_paths))
+        .collect();
+    let domains: Vec<String> = domain_paths.iter().map(|(name, _)| name.clone()).collect();
+    let explored: Vec<String> = context
+        .get_research(&ResearchAgentType::KeyModulesInsight.to_string())
+        .await
+        .and_then(|value| serde_json::from_value::<Vec<KeyModuleReport>>(value).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|report| report.domain_name)
+        .collect();
+
+    let mut documents = Vec::new();
+    for agent_type in [AgentType::Overview, AgentType::Architecture] {
+        if let Some(markdown) = context
+            .get_from_memory::<String>(MemoryScope::DOCUMENTATION, &agent_type.to_string())
+            .await
+        {
+            documents.push((agent_type, markdown));
+        }
+    }
+
+    let mut issues = find_issues(&domains, &explored, &documents);
+    if let Some(meta) = context
+        .get_from_memory::<ArchitectureMeta>(PreprocessScope::PREPROCESS, ScopedKeys::ARCHITECTURE_META)
+        .await
+    {
+        let architecture = documents
+            .iter()
+            .find(|(agent_type, _)| *agent_type == AgentType::Architecture)
+            .map(|(_, markdown)| markdown.as_str());
+        let (files, dependencies) = discovered_dependencies(context).await;
+        issues.extend(find_architecture_issues(&meta, &files, &dependencies, &domain_paths, architecture));
+    }
+    for issue in &issues {
+        warn!("⚠️ Inconsistent documentation, {}: {}", issue.document, issue.issue);
+    }
+    context
+        .store_to_memory(RunScope::RUN, RunKeys::CONSISTENCY_ISSUES, &issues)
+        .await?;
+    Ok(issues)
+}
+
+fn find_issues(domains: &[String], explored: &[String], documents: &[(AgentType, String)]) -> Vec<ConsistencyIssue> {
+    let mut issues = Vec::new();
+    let mut issue = |document: &str, issue: String| {
+        issues.push(ConsistencyIssue {
+            document: document.to_string(),
+            issue,
+        })
+    };
+    if domains.is_empty() {
+        return Vec::new();
+    }
+
+    for domain in domains {
+        if !explored.iter().any(|name| same_domain(name, domain)) {
+            issue(DEEP_EXPLORATION, format!("domain `{}` has no deep-exploration document", domain));
+        }
+    }
+
+    for (agent_type, markdown) in documents {
+        let document = agent_type.to_string();
+        let lowercase = markdown.to_lowercase();
+        if *agent_type == AgentType::Architecture {
+            for domain in domains {
+                if !lowercase.contains(&domain_stem(domain)) {
+                    issue(&document, format!("domain `{}` is not mentioned", domain));
+                }
+            }
+        }
+
+        let mut unknown: Vec<&str> = DOMAIN_MENTION_REGEX
+            .captures_iter(markdown)
+            .filter_map(|captures| captures.get(1).or_else(|| captures.get(2)))
+            .map(|mention| mention.as_str())
+            .filter(|mention| !domains.iter().any(|domain| same_domain(mention, domain)))
+            .collect();
+        unknown.sort();
+        unknown.dedup();
+        for mention in unknown {
+            issue(&document, format!("mentions domain `{}`, which is not a detected domain module", mention));
+        }
+
+        for captures in DOMAIN_COUNT_REGEX.captures_iter(markdown) {
+            let count = &captures[1];
+            let count = count
+                .parse::<usize>()
+                .ok()
+                .or_else(|| NUMBER_WORDS.iter().position(|word| count.eq_ignore_ascii_case(word)).map(|i| i + 2));
+            if count.is_some_and(|count| count != domains.len()) {
+                issue(
+                    &document,
+                    format!("states `{}` but {} domain modules were detected", &captures[0], domains.len()),
+                );
+            }
+        }
+    }
+    issues
+}
+
+/// Project files and the dependencies between them, relative to the project root
+async fn discovered_dependencies(context: &GeneratorContext) -> (Vec<PathBuf>, Vec<(PathBuf, PathBuf)>) {
+    let Some(structure) = context
+        .get_from_memory::<ProjectStructure>(PreprocessScope::PREPROCESS, ScopedKeys::PROJECT_STRUCTURE)
+        .await
+    else {
+        return (Vec::new(), Vec::new());
+    };
+    let root = &context.config.project_path;
+    let graph = DependencyGraph::build(&structure.files, root, context.config.max_file_size as usize);
+    let files: Vec<PathBuf> = structure
+        .files
+        .iter()
+        .map(|file| file.path.strip_prefix(root).unwrap_or(&file.path).to_path_buf())
+        .collect();
+    let dependencies = graph.edges().map(|(from, to)| (files[from].clone(), files[to].clone())).collect();
+    (files, dependencies)
+}
+
+/// Divergences between the declared architecture and the files, dependencies, domains and
+/// architecture document discovered by the run
+fn find_architecture_issues(
+    meta: &ArchitectureMeta,
+    files: &[PathBuf],
+    dependencies: &[(PathBuf, PathBuf)],
+    domains: &[(String, Vec<String>)],
+    architecture: Option<&str>,
+) -> Vec<ConsistencyIssue> {
+    let mut issues = Vec::new();
+    let mut issue = |document: &str, issue: String| {
+        issues.push(ConsistencyIssue {
+            document: document.to_string(),
+            issue,
+        })
+    };
+
+    if !files.is_empty() {
+        for layer in &meta.layers {
+            if !files.iter().any(|file| meta.layer_of(file) == Some(layer.name.as_str())) {
+                issue(DECLARED_ARCHITECTURE, format!("layer `{}` matches no project files", layer.name));
+            }
+        }
+        for module in &meta.modules {
+            if !files.iter().any(|file| meta.module_of(file).is_some_and(|m| m.name == module.name)) {
+                issue(DECLARED_ARCHITECTURE, format!("module `{}` matches no project files", module.name));
+            }
+        }
+    }
+
+    let mut violations: BTreeMap<String, Vec<&(PathBuf, PathBuf)>> = BTreeMap::new();
+    for dependency in dependencies {
+        for violation in meta.violations(&dependency.0, &dependency.1) {
+            violations.entry(violation).or_default().push(dependency);
+        }
+    }
+    for (violation, offending) in violations {
+        let examples: Vec<String> = offending
+            .iter()
+            .take(MAX_VIOLATION_EXAMPLES)
+            .map(|(from, to)| format!("`{}` -> `{}`", from.display(), to.display()))
+            .collect();
+        issue(
+            DECLARED_ARCHITECTURE,
+            format!("{}, but {} dependencies do, e.g. {}", violation, offending.len(), examples.join(", ")),
+        );
+    }
+
+    if !meta.modules.is_empty() {
+        for (domain, code_paths) in domains {
+            let covered = code_paths.iter().any(|path| meta.module_of(Path::new(path)).is_some())
+                || meta.modules.iter().any(|module| same_domain(&module.name, domain));
+            if !covered {
+                issue(DECLARED_ARCHITECTURE, format!("detected domain `{}` is not covered by any declared module", domain));
+            }
+        }
+    }
+
+    if let Some(markdown) = architecture {
+        let document = AgentType::Architecture.to_string();
+        let lowercase = markdown.to_lowercase();
+        let declared = meta
+            .layers
+            .iter()
+            .map(|layer| ("layer", &layer.name))
+            .chain(meta.modules.iter().map(|module| ("module", &module.name)));
+        for (kind, name) in declared {
+            if !lowercase.contains(&name.to_lowercase()) {
+                issue(&document, format!("declared {} `{}` is not mentioned", kind, name));
+            }
+        }
+    }
+    issues
+}
+
+/// Domain name without the generic `domain`/`module` suffix, lowercased
+fn domain_stem(name: &str) -> String {
+    let name = name.trim().to_lowercase();
+    let mut stem = name.as_str();
+    for suffix in [" domain", " module", " domain module"] {
+        stem = stem.strip_suffix(suffix).unwrap_or(stem);
+    }
+    stem.trim().to_string()
+}
+
+fn same_domain(a: &str, b: &str) -> bool {
+    domain_stem(a) == domain_stem(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_issues() {
+        let domains = vec!["Order Management Domain".to_string(), "Billing Domain".to_string()];
+        let explored = vec!["Order Management Domain".to_string()];
+        let documents = vec![
+            (
+                AgentType::Overview,
+                "The system is split into three core domains.\n\n**Order Management Domain** handles orders.".to_string(),
+            ),
+            (
+                AgentType::Architecture,
+                "## 3. Container View\n### 3.1 Order Management Domain\n### 3.2 Shipping Domain\nThe 2 domains talk over events.".to_string(),
+            ),
+        ];
+
+        let issues: Vec<String> = find_issues(&domains, &explored, &documents)
+            .into_iter()
+            .map(|i| format!("{}: {}", i.document, i.issue))
+            .collect();
+
+        assert_eq!(
+            issues,
+            vec![
+                "Deep Exploration: domain `Billing Domain` has no deep-exploration document",
+                "Project Overview: states `three core domains` but 2 domain modules were detected",
+                "Architecture Description: domain `Billing Domain` is not mentioned",
+                "Architecture Description: mentions domain `Shipping Domain`, which is not a detected domain module",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_architecture_issues() {
+        let meta: ArchitectureMeta = serde_yaml::from_str(
+            "layers:\n  - name: api\n    paths: [src/api]\n    may_depend_on: [domain]\n  - name: domain\n    paths: [src/domain]\n    may_depend_on: []\n  - name: jobs\n    paths: [src/jobs]\nmodules:\n  - name: Billing\n    paths: [src/domain/billing]\n    layer: domain\n",
+        )
+        .unwrap();
+        let files: Vec<PathBuf> = ["src/api/routes.rs", "src/domain/billing/invoice.rs", "src/domain/order.rs", "src/db.rs"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+        let dependencies = vec![
+            (files[0].clone(), files[2].clone()),
+            (files[1].clone(), files[0].clone()),
+            (files[2].clone(), files[0].clone()),
+        ];
+        let domains = vec![
+            ("Billing Domain".to_string(), vec!["src/domain/billing/invoice.rs".to_string()]),
+            ("Persistence Domain".to_string(), vec!["src/db.rs".to_string()]),
+        ];
+
+        let issues: Vec<String> = find_architecture_issues(
+            &meta,
+            &files,
+            &dependencies,
+            &domains,
+            Some("The API layer calls the domain layer; Billing issues invoices."),
+        )
+        .into_iter()
+        .map(|i| format!("{}: {}", i.document, i.issue))
+        .collect();
+
+        assert_eq!(
+            issues,
+            vec![
+                "Declared Architecture: layer `jobs` matches no project files",
+                "Declared Architecture: layer `domain` may not depend on layer `api`, but 2 dependencies do, e.g. `src/domain/billing/invoice.rs` -> `src/api/routes.rs`, `src/domain/order.rs` -> `src/api/routes.rs`",
+                "Declared Architecture: detected domain `Persistence Domain` is not covered by any declared module",
+                "Architecture Description: declared layer `jobs` is not mentioned",
+            ]
+        );
+    }
+}
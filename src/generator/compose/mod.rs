@@ -1,66 +1,245 @@
 use crate::generator::compose::agents::architecture_editor::ArchitectureEditor;
 use crate::generator::compose::agents::boundary_editor::BoundaryEditor;
 use crate::generator::compose::agents::database_editor::DatabaseEditor;
+use crate::generator::compose::agents::dependencies_editor::DependenciesEditor;
 use crate::generator::compose::agents::key_modules_insight_editor::KeyModulesInsightEditor;
 use crate::generator::compose::agents::overview_editor::OverviewEditor;
 use crate::generator::compose::agents::workflow_editor::WorkflowEditor;
+use crate::generator::compose::memory::MemoryScope as DocumentationScope;
+use crate::generator::compose::types::AgentType;
 use crate::generator::context::GeneratorContext;
 use crate::generator::outlet::DocTree;
-use crate::generator::preprocess::memory::{MemoryScope, ScopedKeys};
+use crate::generator::preprocess::extractors::orm_models::OrmModels;
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
 use crate::generator::step_forward_agent::StepForwardAgent;
+use crate::generator::workflow::TimingKeys;
 use crate::types::code::CodePurpose;
-use crate::types::{CodeAndDirectoryInsights, DirectoryPurpose};
+use crate::types::dependency::DependencyInventory;
+use crate::types::DirectoryPurpose;
+use crate::utils::progress::PhaseProgress;
 use anyhow::Result;
+use std::future::Future;
+use std::time::Instant;
+use tracing::{info, warn};
 
 mod agents;
+pub mod consistency;
 pub mod memory;
+pub mod path_verifier;
 pub mod types;
 
+/// Single-file documents of the composer's editors, in the order they are written. `database` and
+/// `dependencies` are only written for projects that have them; the key modules editor writes a
+/// `deep_exploration` directory with one document per module.
+pub const DOCUMENT_TYPES: [&str; 6] = ["overview", "architecture", "workflow", "boundary", "database", "dependencies"];
+
 /// Documentation composer
 #[derive(Default)]
 pub struct DocumentationComposer;
 
 impl DocumentationComposer {
     pub async fn execute(&self, context: &GeneratorContext, doc_tree: &mut DocTree) -> Result<()> {
-        println!("\n🤖 Executing documentation generation process...");
-        println!("📝 Target language: {}", context.config.target_language.display_name());
+        info!("🤖 Executing documentation generation process...");
+        info!("📝 Target language: {}", context.config.target_language.display_name());
 
-        let overview_editor = OverviewEditor::default();
-        overview_editor.execute(context).await?;
+        let has_database_files = self.has_database_files(context).await;
+        let has_dependencies = context
+            .get_from_memory::<DependencyInventory>(PreprocessScope::PREPROCESS, ScopedKeys::DEPENDENCIES)
+            .await
+            .is_some_and(|inventory| !inventory.is_empty());
+        let total_editors = 5 + has_database_files as usize + has_dependencies as usize;
+        let progress = PhaseProgress::new("📝 Documents", total_editors);
 
-        let architecture_editor = ArchitectureEditor::default();
-        architecture_editor.execute(context).await?;
+        progress.set_message("Overview");
+        self.compose_in_languages(context, doc_tree, "overview", |context| async move {
+            OverviewEditor.execute(&context).await?;
+            Ok(DocTree::for_document(&context.config, "overview"))
+        })
+        .await?;
+        progress.inc();
 
-        let workflow_editor = WorkflowEditor::default();
-        workflow_editor.execute(context).await?;
+        progress.set_message("Architecture");
+        self.compose_in_languages(context, doc_tree, "architecture", |context| async move {
+            ArchitectureEditor.execute(&context).await?;
+            Ok(DocTree::for_document(&context.config, "architecture"))
+        })
+        .await?;
+        progress.inc();
 
-        let key_modules_insight_editor = KeyModulesInsightEditor::default();
-        key_modules_insight_editor
-            .execute(context, doc_tree)
-            .await?;
+        progress.set_message("Workflow");
+        self.compose_in_languages(context, doc_tree, "workflow", |context| async move {
+            WorkflowEditor.execute(&context).await?;
+            Ok(DocTree::for_document(&context.config, "workflow"))
+        })
+        .await?;
+        progress.inc();
 
-        let boundary_editor = BoundaryEditor::default();
-        boundary_editor.execute(context).await?;
+        progress.set_message("Key modules");
+        self.compose_in_languages(context, doc_tree, "deep_exploration", |context| async move {
+            let mut tree = DocTree::empty();
+            KeyModulesInsightEditor::default()
+                .execute(&context, &mut tree)
+                .await?;
+            Ok(tree)
+        })
+        .await?;
+        progress.inc();
+
+        progress.set_message("Boundary");
+        self.compose_in_languages(context, doc_tree, "boundary", |context| async move {
+            BoundaryEditor.execute(&context).await?;
+            Ok(DocTree::for_document(&context.config, "boundary"))
+        })
+        .await?;
+        progress.inc();
 
         // Database documentation (only if database files exist)
-        if self.has_database_files(context).await {
-            let database_editor = DatabaseEditor::default();
-            database_editor.execute(context).await?;
+        if has_database_files {
+            progress.set_message("Database");
+            self.compose_in_languages(context, doc_tree, "database", |context| async move {
+                DatabaseEditor.execute(&context).await?;
+                Ok(DocTree::for_document(&context.config, "database"))
+            })
+            .await?;
+            progress.inc();
+        }
+
+        // Dependency inventory (only if manifests or imports were found)
+        if has_dependencies {
+            progress.set_message("Dependencies");
+            self.compose_in_languages(context, doc_tree, "dependencies", |context| async move {
+                DependenciesEditor.execute(&context).await?;
+                let mut tree = DocTree::empty();
+                tree.insert(&AgentType::Dependencies.to_string(), &context.config.doc_filename("dependencies"));
+                Ok(tree)
+            })
+            .await?;
+            progress.inc();
+        }
+        progress.finish();
+
+        consistency::check(context).await?;
+        path_verifier::verify(context, doc_tree).await?;
+
+        Ok(())
+    }
+
+    /// Run an editor once per language configured for the document type in `[output.languages]`.
+    /// Additional languages are stored under `<key>@<code>` and written to `<code>/<path>`;
+    /// the regular documentation keys are restored to the primary language output afterwards.
+    /// A failing editor is recorded in the run summary and the other documents are still written.
+    async fn compose_in_languages<F, Fut>(
+        &self,
+        context: &GeneratorContext,
+        doc_tree: &mut DocTree,
+        doc_type: &str,
+        compose: F,
+    ) -> Result<()>
+    where
+        F: Fn(GeneratorContext) -> Fut,
+        Fut: Future<Output = Result<DocTree>>,
+    {
+        let start = Instant::now();
+        let result = self
+            .compose_languages(context, doc_tree, doc_type, compose)
+            .await;
+        context
+            .record_timing(TimingKeys::COMPOSE, doc_type, start.elapsed().as_secs_f64())
+            .await?;
+        if let Err(e) = result {
+            warn!("⚠️  Failed to generate the {} document, continuing without it: {:#}", doc_type, e);
+            context
+                .record_failure("compose", doc_type, &format!("{:#}", e))
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn compose_languages<F, Fut>(
+        &self,
+        context: &GeneratorContext,
+        doc_tree: &mut DocTree,
+        doc_type: &str,
+        compose: F,
+    ) -> Result<()>
+    where
+        F: Fn(GeneratorContext) -> Fut,
+        Fut: Future<Output = Result<DocTree>>,
+    {
+        let (primary, extras) = context.config.document_languages(doc_type);
+        let tree = compose(context.with_language(&primary)).await?;
+        if extras.is_empty() {
+            doc_tree.extend(tree);
+            return Ok(());
+        }
+
+        let mut primary_documents = Vec::new();
+        for (key, _) in tree.entries() {
+            if let Some(document) = context
+                .get_from_memory::<String>(DocumentationScope::DOCUMENTATION, key)
+                .await
+            {
+                primary_documents.push((key.clone(), document));
+            }
+        }
+
+        for language in extras {
+            info!("📝 Generating {} in {}", doc_type, language.display_name());
+            // The memory snapshot only holds the primary language, so never resume from it here
+            let mut language_context = context.with_language(&language);
+            language_context.config.resume = false;
+            let language_tree = match compose(language_context).await {
+                Ok(tree) => tree,
+                Err(e) => {
+                    warn!("⚠️  Failed to generate {} in {}: {:#}", doc_type, language.display_name(), e);
+                    let item = format!("{} ({})", doc_type, language.code());
+                    context.record_failure("compose", &item, &format!("{:#}", e)).await?;
+                    continue;
+                }
+            };
+            for (key, path) in language_tree.entries() {
+                let Some(document) = context
+                    .get_from_memory::<String>(DocumentationScope::DOCUMENTATION, key)
+                    .await
+                else {
+                    continue;
+                };
+                let variant_key = format!("{}@{}", key, language.code());
+                context
+                    .store_to_memory(DocumentationScope::DOCUMENTATION, &variant_key, document)
+                    .await?;
+                doc_tree.insert(&variant_key, &format!("{}/{}", language.code(), path));
+            }
         }
 
+        for (key, document) in primary_documents {
+            context
+                .store_to_memory(DocumentationScope::DOCUMENTATION, &key, document)
+                .await?;
+        }
+        doc_tree.extend(tree);
         Ok(())
     }
 
     /// Check if the project has database-related files
     async fn has_database_files(&self, context: &GeneratorContext) -> bool {
+        // ORM entities map tables even when the project has no SQL scripts
+        if context
+            .get_from_memory::<OrmModels>(PreprocessScope::PREPROCESS, ScopedKeys::ORM_MODELS)
+            .await
+            .is_some_and(|models| !models.is_empty())
+        {
+            return true;
+        }
         if let Some(insights) = context
-            .get_from_memory::<CodeAndDirectoryInsights>(MemoryScope::PREPROCESS, ScopedKeys::CODE_INSIGHTS)
+            .code_insights()
             .await
         {
             insights.directory_insights.iter().any(|dossier| {
                 dossier.purpose == DirectoryPurpose::Database
                     || dossier.name.to_lowercase().contains("database")
                     || dossier.name.to_lowercase().contains("db")
+                    || dossier.name.to_lowercase().contains("migration")
             }) || insights.directory_insights.iter().flat_map(|d| d.file_insights.iter()).any(|fi| {
                 fi.code_purpose == CodePurpose::Database
                     || fi.file_path.to_string_lossy().ends_with(".sql")
@@ -71,3 +250,56 @@ impl DocumentationComposer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, LLMProvider};
+    use crate::generator::outlet::{DiskOutlet, Outlet, RunStatus, RunSummary};
+    use crate::generator::workflow::create_context;
+
+    #[tokio::test]
+    async fn test_failed_editor_keeps_other_documents() {
+        let temp = tempfile::tempdir().unwrap();
+        let mut config = Config {
+            project_path: temp.path().join("project"),
+            output_path: temp.path().join("docs"),
+            internal_path: temp.path().join(".litho"),
+            resume: false,
+            ..Default::default()
+        };
+        config.llm.provider = LLMProvider::Mock;
+        config.llm.api_key = "mock".to_string();
+        config.cache.enabled = false;
+        let context = create_context(&config).unwrap();
+
+        let mut doc_tree = DocTree::empty();
+        DocumentationComposer
+            .compose_in_languages(&context, &mut doc_tree, "overview", |context| async move {
+                context
+                    .store_to_memory(DocumentationScope::DOCUMENTATION, &AgentType::Overview.to_string(), "# Overview\n")
+                    .await?;
+                Ok(DocTree::for_document(&context.config, "overview"))
+            })
+            .await
+            .unwrap();
+        DocumentationComposer
+            .compose_in_languages(&context, &mut doc_tree, "architecture", |_| async {
+                Err(anyhow::anyhow!("model unavailable"))
+            })
+            .await
+            .unwrap();
+        DiskOutlet::new(doc_tree).save(&context).await.unwrap();
+
+        let overview = config.doc_filename("overview");
+        assert!(config.output_path.join(&overview).is_file());
+        assert!(!config.output_path.join(config.doc_filename("architecture")).exists());
+
+        let summary = RunSummary::collect(&context, None).await;
+        assert_eq!(summary.status, RunStatus::PartialFailure);
+        assert_eq!(summary.documents, vec![overview]);
+        assert_eq!(summary.failures.len(), 1);
+        assert_eq!((summary.failures[0].stage.as_str(), summary.failures[0].item.as_str()), ("compose", "architecture"));
+        assert!(summary.failures[0].error.contains("model unavailable"));
+    }
+}
@@ -0,0 +1,320 @@
+use anyhow::Result;
+use regex::{Captures, Regex};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::LazyLock;
+use tracing::{info, warn};
+
+use crate::config::UnknownPathPolicy;
+use crate::generator::compose::memory::MemoryScope;
+use crate::generator::context::GeneratorContext;
+use crate::generator::outlet::DocTree;
+use crate::generator::outlet::run_summary::ConsistencyIssue;
+use crate::generator::preprocess::extractors::language_stats::language_of;
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
+use crate::types::project_structure::ProjectStructure;
+use crate::utils::paths;
+
+static CODE_SPAN_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"`([^`\n]+)`").unwrap());
+/// Whole code span naming a file, with an optional `:line` or `:start-end` suffix
+static CODE_PATH_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^((?:\./|/)?(?:[\w@.-]+/)*[\w@-][\w@.-]*\.([A-Za-z0-9]{1,10}))((?::\d+(?:-\d+)?)?)$").unwrap()
+});
+/// Path with at least one directory in plain text
+static TEXT_PATH_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(^|[\s(])((?:\./)?(?:[\w@.-]+/)+[\w@-][\w@.-]*\.([A-Za-z0-9]{1,10}))\b").unwrap()
+});
+
+/// Extensions of the configuration and data files cited next to source files
+const CITED_EXTENSIONS: [&str; 14] = [
+    "toml", "json", "yaml", "yml", "md", "xml", "gradle", "properties", "lock", "cfg", "ini", "env", "csproj", "mod",
+];
+
+/// How a cited path relates to the project files
+#[derive(Debug, PartialEq)]
+enum Resolution {
+    Known,
+    /// Near miss of exactly one project file
+    Corrected(String),
+    Unknown,
+}
+
+/// Project files by path and by file name, for resolving the paths cited in documents
+struct ProjectPaths<'a> {
+    root: &'a Path,
+    files: Vec<String>,
+    directories: HashSet<String>,
+    by_name: HashMap<String, Vec<usize>>,
+}
+
+impl<'a> ProjectPaths<'a> {
+    fn new(root: &'a Path, paths: impl Iterator<Item = String>) -> Self {
+        let files: Vec<String> = paths.map(|path| path.replace('\\', "/")).collect();
+        let mut directories = HashSet::new();
+        let mut by_name: HashMap<String, Vec<usize>> = HashMap::new();
+        for (index, file) in files.iter().enumerate() {
+            let mut parts: Vec<&str> = file.split('/').collect();
+            by_name.entry(parts.pop().unwrap_or_default().to_string()).or_default().push(index);
+            for depth in 1..=parts.len() {
+                directories.insert(parts[..depth].join("/"));
+            }
+        }
+        Self {
+            root,
+            files,
+            directories,
+            by_name,
+        }
+    }
+
+    fn resolve(&self, cited: &str) -> Resolution {
+        let path = cited.trim_start_matches("./").trim_start_matches('/');
+        let name = path.rsplit('/').next().unwrap_or(path);
+        let suffix = format!("/{}", path);
+        if self.directories.contains(path)
+            || self.files.iter().any(|file| file == path || file.ends_with(&suffix))
+            || (!path.contains('/') && self.by_name.contains_key(name))
+            || self.root.join(path).exists()
+        {
+            return Resolution::Known;
+        }
+
+        // The right file in the wrong directory
+        if let Some([index]) = self.by_name.get(name).map(Vec::as_slice) {
+            return Resolution::Corrected(self.files[*index].clone());
+        }
+        // A typo in the path
+        let budget = (path.len() / 8).max(1);
+        let mut closest: Vec<(usize, &String)> = self
+            .files
+            .iter()
+            .filter(|file| file.len().abs_diff(path.len()) <= budget)
+            .map(|file| (edit_distance(file, path), file))
+            .filter(|(distance, _)| *distance <= budget)
+            .collect();
+        closest.sort();
+        match closest.as_slice() {
+            [(_, file)] => Resolution::Corrected((*file).clone()),
+            [(best, file), (second, _), ..] if best < second => Resolution::Corrected((*file).clone()),
+            _ => Resolution::Unknown,
+        }
+    }
+}
+
+/// Outcome of verifying the paths of a document
+#[derive(Debug, Default, PartialEq)]
+struct Verification {
+    content: String,
+    /// `cited -> corrected` near misses
+    corrected: Vec<(String, String)>,
+    unknown: Vec<String>,
+}
+
+/// Check the file paths cited in the generated documents against the project structure:
+/// near misses are corrected, paths matching no project file are reported or stripped
+pub async fn verify(context: &GeneratorContext, doc_tree: &DocTree) -> Result<()> {
+    let settings = &context.config.path_check;
+    if !settings.enabled {
+        return Ok(());
+    }
+    let Some(structure) = context
+        .get_from_memory::<ProjectStructure>(PreprocessScope::PREPROCESS, ScopedKeys::PROJECT_STRUCTURE)
+        .await
+    else {
+        return Ok(());
+    };
+    let paths = ProjectPaths::new(
+        &context.config.project_path,
+        structure.files.iter().map(|file| paths::to_slash(&file.path)),
+    );
+
+    let mut issues = Vec::new();
+    let (mut corrected, mut unknown) = (0, 0);
+    for (key, _) in doc_tree.entries() {
+        let Some(markdown) = context
+            .get_from_memory::<String>(MemoryScope::DOCUMENTATION, key)
+            .await
+        else {
+            continue;
+        };
+        let verification = verify_document(&markdown, &paths, settings.unknown_paths);
+        corrected += verification.corrected.len();
+        unknown += verification.unknown.len();
+        for path in &verification.unknown {
+            warn!("⚠️ {} cites `{}`, which does not exist in the project", key, path);
+            issues.push(ConsistencyIssue {
+                document: key.clone(),
+                issue: format!("cites `{}`, which does not exist in the project", path),
+            });
+        }
+        if verification.content != markdown {
+            context
+                .store_to_memory(MemoryScope::DOCUMENTATION, key, verification.content)
+                .await?;
+        }
+    }
+
+    info!(
+        "🔎 Cited paths verified: {} near misses corrected, {} unknown paths {}",
+        corrected,
+        unknown,
+        match settings.unknown_paths {
+            UnknownPathPolicy::Flag => "reported",
+            UnknownPathPolicy::Strip => "stripped",
+        }
+    );
+    context.record_consistency_issues(issues).await
+}
+
+fn verify_document(markdown: &str, paths: &ProjectPaths, policy: UnknownPathPolicy) -> Verification {
+    let mut verification = Verification::default();
+    let mut lines = Vec::new();
+    let mut in_code_block = false;
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+        if in_code_block || line.trim_start().starts_with("```") {
+            lines.push(line.to_string());
+            continue;
+        }
+        lines.extend(verify_line(line, paths, policy, &mut verification));
+    }
+    let rewritten = !verification.corrected.is_empty()
+        || (policy == UnknownPathPolicy::Strip && !verification.unknown.is_empty());
+    verification.content = if rewritten {
+        let mut content = lines.join("\n");
+        if markdown.ends_with('\n') {
+            content.push('\n');
+        }
+        content
+    } else {
+        markdown.to_string()
+    };
+    verification
+}
+
+/// The line with its cited paths checked; `None` when the line is a list item citing only an
+/// unknown path that is stripped
+fn verify_line(line: &str, paths: &ProjectPaths, policy: UnknownPathPolicy, verification: &mut Verification) -> Option<String> {
+    let mut stripped_only_citation = false;
+    let is_list_item = ["- `", "* `"].iter().any(|marker| line.trim_start().starts_with(marker));
+
+    let mut result = String::new();
+    let mut last = 0;
+    for (index, span) in CODE_SPAN_REGEX.captures_iter(line).enumerate() {
+        let whole = span.get(0).unwrap();
+        result.push_str(&verify_text(&line[last..whole.start()], paths, policy, verification));
+        last = whole.end();
+
+        let Some(cited) = CODE_PATH_REGEX.captures(span[1].trim()).filter(|c| is_cited_extension(&c[2])) else {
+            result.push_str(whole.as_str());
+            continue;
+        };
+        match paths.resolve(&cited[1]) {
+            Resolution::Known => result.push_str(whole.as_str()),
+            Resolution::Corrected(path) => {
+                verification.corrected.push((cited[1].to_string(), path.clone()));
+                result.push_str(&format!("`{}{}`", path, &cited[3]));
+            }
+            Resolution::Unknown => {
+                verification.unknown.push(cited[1].to_string());
+                match policy {
+                    UnknownPathPolicy::Flag => result.push_str(whole.as_str()),
+                    UnknownPathPolicy::Strip => {
+                        stripped_only_citation |= index == 0 && is_list_item;
+                        result.push_str(file_name(&cited[1]));
+                    }
+                }
+            }
+        }
+    }
+    result.push_str(&verify_text(&line[last..], paths, policy, verification));
+    (!stripped_only_citation).then_some(result)
+}
+
+/// Plain text with the paths outside links checked
+fn verify_text(text: &str, paths: &ProjectPaths, policy: UnknownPathPolicy, verification: &mut Verification) -> String {
+    TEXT_PATH_REGEX
+        .replace_all(text, |captures: &Captures| {
+            let whole = captures.get(0).unwrap();
+            let cited = &captures[2];
+            let in_link = text[..whole.start()].ends_with("](") || text[..whole.start()].ends_with("://");
+            if in_link || cited.contains("://") || !is_cited_extension(&captures[3]) {
+                return whole.as_str().to_string();
+            }
+            match paths.resolve(cited) {
+                Resolution::Known => whole.as_str().to_string(),
+                Resolution::Corrected(path) => {
+                    verification.corrected.push((cited.to_string(), path.clone()));
+                    format!("{}{}", &captures[1], path)
+                }
+                Resolution::Unknown => {
+                    verification.unknown.push(cited.to_string());
+                    match policy {
+                        UnknownPathPolicy::Flag => whole.as_str().to_string(),
+                        UnknownPathPolicy::Strip => format!("{}{}", &captures[1], file_name(cited)),
+                    }
+                }
+            }
+        })
+        .into_owned()
+}
+
+fn is_cited_extension(extension: &str) -> bool {
+    language_of(extension).is_some() || CITED_EXTENSIONS.contains(&extension.to_lowercase().as_str())
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_document() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        let paths = ProjectPaths::new(
+            &root,
+            ["src/main.rs", "src/generator/workflow.rs", "src/config.rs", "Cargo.toml"]
+                .into_iter()
+                .map(str::to_string),
+        );
+        let markdown = "# Modules\n\n- `src/generator/workflow.rs`: pipeline\n- `src/workflow.rs:12`: moved\n- `src/confg.rs` holds settings, see src/fake/module.rs\n- `src/fake.rs`: invented\n\nContent-Type is `application/json`, `Cargo.toml` and `generator/workflow.rs` exist.\n```rust\nuse src/nothing.rs;\n```\n";
+
+        let flagged = verify_document(markdown, &paths, UnknownPathPolicy::Flag);
+        assert_eq!(
+            flagged.corrected,
+            vec![
+                ("src/workflow.rs".to_string(), "src/generator/workflow.rs".to_string()),
+                ("src/confg.rs".to_string(), "src/config.rs".to_string()),
+            ]
+        );
+        assert_eq!(flagged.unknown, vec!["src/fake/module.rs", "src/fake.rs"]);
+        assert!(flagged.content.contains("- `src/generator/workflow.rs:12`: moved"));
+        assert!(flagged.content.contains("- `src/fake.rs`: invented"));
+
+        let stripped = verify_document(markdown, &paths, UnknownPathPolicy::Strip);
+        assert!(stripped.content.contains("holds settings, see module.rs\n"));
+        assert!(!stripped.content.contains("invented"));
+        assert!(stripped.content.contains("use src/nothing.rs;"));
+    }
+}
@@ -9,6 +9,7 @@ pub enum AgentType {
     Workflow,
     Boundary,
     Database,
+    Dependencies,
 }
 
 impl Display for AgentType {
@@ -19,7 +20,22 @@ impl Display for AgentType {
             AgentType::Workflow => "Core Workflows",
             AgentType::Boundary => "Boundary Interfaces",
             AgentType::Database => "Database Overview",
+            AgentType::Dependencies => "Dependencies",
         };
         write!(f, "{}", str)
     }
 }
+
+impl AgentType {
+    /// Name of the agent in the configuration, e.g. `[formatter.overview]`
+    pub fn config_key(&self) -> &'static str {
+        match self {
+            AgentType::Overview => "overview",
+            AgentType::Architecture => "architecture",
+            AgentType::Workflow => "workflow",
+            AgentType::Boundary => "boundary",
+            AgentType::Database => "database",
+            AgentType::Dependencies => "dependencies",
+        }
+    }
+}
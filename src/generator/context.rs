@@ -8,9 +8,15 @@ use tokio::sync::RwLock;
 use crate::{
     cache::CacheManager, 
     config::Config, 
+    generator::outlet::run_summary::{ConfidenceRetry, ConsistencyIssue, Escalation},
+    generator::preprocess::memory::{MemoryScope, ScopedKeys},
+    i18n::TargetLanguage,
     llm::client::LLMClient, 
     memory::Memory,
+    types::CodeAndDirectoryInsights,
+    utils::secret_redactor::RedactionLog,
 };
+use tracing::{info, warn};
 
 #[derive(Clone)]
 pub struct GeneratorContext {
@@ -22,16 +28,40 @@ pub struct GeneratorContext {
     pub cache_manager: Arc<RwLock<CacheManager>>,
     /// Generator memory
     pub memory: Arc<RwLock<Memory>>,
+    /// Secrets redacted during the run, for `redaction-report.json`
+    pub redactions: Arc<RedactionLog>,
 }
 
 impl GeneratorContext {
+    /// Context sharing memory and caches whose documents are written in another language
+    pub fn with_language(&self, language: &TargetLanguage) -> Self {
+        let mut context = self.clone();
+        context.config.target_language = language.clone();
+        context
+    }
+
+    /// Update Memory, then write the snapshots of the stored scopes once the lock is released
+    async fn update_memory<R>(&self, update: impl FnOnce(&mut Memory) -> Result<R>) -> Result<R> {
+        let (result, writes) = {
+            let mut memory = self.memory.write().await;
+            let result = update(&mut memory);
+            (result, memory.take_snapshot_writes())
+        };
+        if !writes.is_empty() {
+            let written = tokio::task::spawn_blocking(move || writes.into_iter().try_for_each(|write| write.write())).await;
+            if let Err(e) = written.map_err(anyhow::Error::from).and_then(|written| written) {
+                warn!("⚠️  Failed to write memory snapshot: {}", e);
+            }
+        }
+        result
+    }
+
     /// Store data to Memory
     pub async fn store_to_memory<T>(&self, scope: &str, key: &str, data: T) -> Result<()>
     where
         T: Serialize + Send + Sync,
     {
-        let mut memory = self.memory.write().await;
-        memory.store(scope, key, data)
+        self.update_memory(|memory| memory.store(scope, key, data)).await
     }
 
     /// Get data from Memory
@@ -43,6 +73,29 @@ impl GeneratorContext {
         memory.get(scope, key)
     }
 
+    /// Store a large artifact once, shared by all readers of `get_shared_from_memory`
+    pub async fn store_shared_to_memory<T>(&self, scope: &str, key: &str, data: Arc<T>) -> Result<()>
+    where
+        T: Serialize + Send + Sync + 'static,
+    {
+        self.update_memory(|memory| memory.store_shared(scope, key, data)).await
+    }
+
+    /// Get a large artifact from Memory without deserializing a copy per read
+    pub async fn get_shared_from_memory<T>(&self, scope: &str, key: &str) -> Option<Arc<T>>
+    where
+        T: for<'a> Deserialize<'a> + Send + Sync + 'static,
+    {
+        let mut memory = self.memory.write().await;
+        memory.get_shared(scope, key)
+    }
+
+    /// Code and directory insights of the preprocessing stage
+    pub async fn code_insights(&self) -> Option<Arc<CodeAndDirectoryInsights>> {
+        self.get_shared_from_memory(MemoryScope::PREPROCESS, ScopedKeys::CODE_INSIGHTS)
+            .await
+    }
+
     /// Check if data exists in Memory
     pub async fn has_memory_data(&self, scope: &str, key: &str) -> bool {
         let memory = self.memory.read().await;
@@ -55,44 +108,145 @@ impl GeneratorContext {
         memory.list_keys(scope)
     }
 
+    /// Record a non-fatal failure, reported in the run summary
+    pub async fn record_failure(&self, stage: &str, item: &str, error: &str) -> Result<()> {
+        use crate::generator::outlet::RunFailure;
+        use crate::generator::outlet::run_summary::{RunKeys, RunScope};
+
+        crate::metrics::metrics().record_step_failure();
+        self.update_memory(|memory| {
+            let mut failures: Vec<RunFailure> = memory
+                .get(RunScope::RUN, RunKeys::FAILURES)
+                .unwrap_or_default();
+            failures.push(RunFailure {
+                stage: stage.to_string(),
+                item: item.to_string(),
+                error: error.to_string(),
+            });
+            memory.store(RunScope::RUN, RunKeys::FAILURES, failures)
+        })
+        .await
+    }
+
+    /// Record cross-document issues, reported in the run summary
+    pub async fn record_consistency_issues(&self, issues: Vec<ConsistencyIssue>) -> Result<()> {
+        use crate::generator::outlet::run_summary::{RunKeys, RunScope};
+
+        self.update_memory(|memory| {
+            let mut recorded: Vec<ConsistencyIssue> = memory
+                .get(RunScope::RUN, RunKeys::CONSISTENCY_ISSUES)
+                .unwrap_or_default();
+            recorded.extend(issues);
+            memory.store(RunScope::RUN, RunKeys::CONSISTENCY_ISSUES, recorded)
+        })
+        .await
+    }
+
+    /// Record the confidence re-runs of a research agent, reported in the run summary
+    pub async fn record_confidence_retry(&self, retry: ConfidenceRetry) -> Result<()> {
+        use crate::generator::outlet::run_summary::{RunKeys, RunScope};
+
+        self.update_memory(|memory| {
+            let mut retries: Vec<ConfidenceRetry> = memory
+                .get(RunScope::RUN, RunKeys::CONFIDENCE_RETRIES)
+                .unwrap_or_default();
+            retries.push(retry);
+            memory.store(RunScope::RUN, RunKeys::CONFIDENCE_RETRIES, retries)
+        })
+        .await
+    }
+
+    /// Record the re-run of an agent after a poor output, reported in the run summary
+    pub async fn record_escalation(&self, escalation: Escalation) -> Result<()> {
+        use crate::generator::outlet::run_summary::{RunKeys, RunScope};
+
+        self.update_memory(|memory| {
+            let mut escalations: Vec<Escalation> = memory
+                .get(RunScope::RUN, RunKeys::ESCALATIONS)
+                .unwrap_or_default();
+            escalations.push(escalation);
+            memory.store(RunScope::RUN, RunKeys::ESCALATIONS, escalations)
+        })
+        .await
+    }
+
+    /// Record the duration of one item of a workflow stage, reported in the run profile
+    pub async fn record_timing(&self, phase: &str, item: &str, seconds: f64) -> Result<()> {
+        use crate::generator::outlet::run_profile::ProfileEntry;
+        use crate::generator::workflow::{TimingKeys, TimingScope};
+
+        self.update_memory(|memory| {
+            let mut entries: Vec<ProfileEntry> = memory
+                .get(TimingScope::TIMING, TimingKeys::PROFILE)
+                .unwrap_or_default();
+            entries.push(ProfileEntry {
+                phase: phase.to_string(),
+                item: item.to_string(),
+                seconds,
+            });
+            memory.store(TimingScope::TIMING, TimingKeys::PROFILE, entries)
+        })
+        .await
+    }
+
     /// Get Memory usage statistics
     pub async fn get_memory_stats(&self) -> HashMap<String, usize> {
         let memory = self.memory.read().await;
         memory.get_usage_stats()
     }
     
-    /// Load external knowledge for multiple categories
+    /// Load external knowledge for multiple categories.
+    /// With retrieval enabled, only the chunks most relevant to `task` are included.
     pub async fn load_external_knowledge_by_categories(
         &self,
         categories: &[&str],
         agent_filter: Option<&str>,
+        task: &str,
     ) -> Option<String> {
+        let knowledge = self.load_knowledge(categories, agent_filter, task).await?;
+        Some(crate::utils::secret_redactor::redact(
+            &self.config.redaction,
+            &self.redactions,
+            &format!("knowledge: {}", categories.join(", ")),
+            &knowledge,
+        ))
+    }
+
+    async fn load_knowledge(&self, categories: &[&str], agent_filter: Option<&str>, task: &str) -> Option<String> {
         use crate::integrations::KnowledgeSyncer;
-        
+
         match KnowledgeSyncer::new(self.config.clone()) {
             Ok(syncer) => {
-                let mut combined = String::new();
-                let mut found_any = false;
-                
-                for category in categories {
-                    if let Ok(Some(knowledge)) = syncer.load_cached_knowledge_by_category(category, agent_filter) {
-                        combined.push_str(&knowledge);
-                        combined.push_str("\n\n");
-                        found_any = true;
+                if let Some(retrieval) = self.config.knowledge.retrieval.as_ref().filter(|r| r.enabled) {
+                    match syncer
+                        .retrieve_knowledge_by_categories(retrieval, categories, agent_filter, task)
+                        .await
+                    {
+                        Ok(knowledge) => return knowledge,
+                        Err(e) => warn!("⚠️  Knowledge retrieval failed, including whole categories: {:#}", e),
                     }
                 }
-                
-                if found_any {
-                    println!("📚 Loaded knowledge from categories: {:?}", categories);
-                    Some(combined)
-                } else {
-                    None
-                }
+                self.load_whole_categories(&syncer, categories, agent_filter)
             }
             Err(e) => {
-                eprintln!("⚠️  Failed to create knowledge syncer: {}", e);
+                warn!("⚠️  Failed to create knowledge syncer: {}", e);
                 None
             }
         }
     }
+
+    fn load_whole_categories(
+        &self,
+        syncer: &crate::integrations::KnowledgeSyncer,
+        categories: &[&str],
+        agent_filter: Option<&str>,
+    ) -> Option<String> {
+        match syncer.load_cached_knowledge_by_categories(categories, agent_filter) {
+            Ok(Some(knowledge)) => {
+                info!("📚 Loaded knowledge from categories: {:?}", categories);
+                Some(knowledge)
+            }
+            _ => None,
+        }
+    }
 }
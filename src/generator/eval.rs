@@ -0,0 +1,370 @@
+//! A/B evaluation: selected research agents run under two configurations against the
+//! preprocessed memory of the last run, and their outputs are compared side by side
+
+use anyhow::{Context, Result, bail};
+use chrono::Local;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+use crate::cache::CacheManager;
+use crate::config::Config;
+use crate::generator::context::GeneratorContext;
+use crate::generator::research::orchestrator::ResearchOrchestrator;
+use crate::generator::research::types::AgentType;
+use crate::llm::client::LLMClient;
+use crate::llm::client::types::TokenUsage;
+use crate::memory::Memory;
+use crate::utils::secret_redactor::RedactionLog;
+
+/// Characters per token assumed when sizing the outputs given to the judge
+const CHARS_PER_TOKEN: usize = 4;
+
+const JUDGE_SYSTEM_PROMPT: &str = r#"You review the output of a documentation research agent that analyzed a software project.
+Two variants of the agent produced an output from the same project data. Score each output from 1 to 10 for:
+- accuracy: claims are plausible for the project and consistent with each other
+- completeness: the expected aspects are covered
+- specificity: concrete module, file and concept names instead of generic statements
+- structure: the output is well organized and usable for writing documentation
+Judge the content, not the length; a longer output is not better by itself.
+The winner is "A", "B" or "tie"."#;
+
+/// One side of the comparison
+pub struct Variant {
+    /// Shown in the report, e.g. the configuration file or the model
+    pub label: String,
+    pub config: Config,
+}
+
+/// Result of one agent under one variant
+#[derive(Debug, Serialize)]
+pub struct AgentRun {
+    pub duration_seconds: f64,
+    pub llm_calls: usize,
+    pub total_tokens: usize,
+    pub estimated_cost: f64,
+    pub output_chars: usize,
+    pub output: Option<Value>,
+    pub error: Option<String>,
+}
+
+/// Scores of the LLM judge
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct JudgeVerdict {
+    /// Score of output A, from 1 to 10
+    pub score_a: f64,
+    /// Score of output B, from 1 to 10
+    pub score_b: f64,
+    /// "A", "B" or "tie"
+    pub winner: String,
+    /// Main differences between the outputs, in a few sentences
+    pub rationale: String,
+}
+
+/// Outputs of one agent under both variants
+#[derive(Debug, Serialize)]
+pub struct AgentComparison {
+    /// Name of the agent in the configuration
+    pub agent: String,
+    pub a: AgentRun,
+    pub b: AgentRun,
+    pub verdict: Option<JudgeVerdict>,
+}
+
+/// Side-by-side comparison written by `litho eval`
+#[derive(Debug, Serialize)]
+pub struct EvalReport {
+    pub created_at: String,
+    pub variant_a: String,
+    pub variant_b: String,
+    /// Memory snapshot both variants started from
+    pub snapshot: PathBuf,
+    pub agents: Vec<AgentComparison>,
+}
+
+/// Configuration of variant B: the file `config_b` or a copy of variant A, with `model_b` as
+/// both models. Paths and the project come from variant A.
+pub fn variant_b_config(a: &Config, config_b: Option<&PathBuf>, model_b: Option<&str>) -> Result<Config> {
+    if config_b.is_none() && model_b.is_none() {
+        bail!("Variant B needs --config-b or --model-b");
+    }
+    let mut b = match config_b {
+        Some(path) => Config::from_file(path)?,
+        None => a.clone(),
+    };
+    b.project_path = a.project_path.clone();
+    b.output_path = a.output_path.clone();
+    b.internal_path = a.internal_path.clone();
+    b.project_name = a.project_name.clone();
+    if b.llm.api_key.is_empty() {
+        b.llm.api_key = a.llm.api_key.clone();
+    }
+    if let Some(model) = model_b {
+        b.llm.model_efficient = model.to_string();
+        b.llm.model_powerful = model.to_string();
+    }
+    Ok(b)
+}
+
+/// Research agents named in `keys` in pipeline order, all of them when `keys` is empty
+pub fn select_agents(keys: &[String]) -> Result<Vec<AgentType>> {
+    for key in keys {
+        if AgentType::from_config_key(key).is_none() {
+            let known: Vec<&str> = AgentType::all().iter().map(|agent| agent.config_key()).collect();
+            bail!("Unknown agent `{}`, expected one of: {}", key, known.join(", "));
+        }
+    }
+    Ok(AgentType::all()
+        .into_iter()
+        .filter(|agent| keys.is_empty() || keys.iter().any(|key| key == agent.config_key()))
+        .collect())
+}
+
+/// Run the agents under both variants, each on its own copy of the memory snapshot of
+/// variant A. Agents run in pipeline order, so a later agent reads the results of the earlier
+/// agents of its own variant.
+pub async fn evaluate(a: Variant, b: Variant, agents: &[AgentType], judge: bool) -> Result<EvalReport> {
+    let snapshot = a.config.memory_dir();
+    let context_a = variant_context(&a.config, &snapshot)?;
+    let context_b = variant_context(&b.config, &snapshot)?;
+    let orchestrator = ResearchOrchestrator;
+
+    let mut comparisons = Vec::new();
+    for agent in agents {
+        info!("⚖️  Evaluating {}: A = {}, B = {}", agent.config_key(), a.label, b.label);
+        let run_a = run_agent(&orchestrator, agent, &context_a).await;
+        let run_b = run_agent(&orchestrator, agent, &context_b).await;
+        let verdict = match (&run_a.output, &run_b.output) {
+            (Some(output_a), Some(output_b)) if judge => {
+                match judge_outputs(&context_a, agent, output_a, output_b).await {
+                    Ok(verdict) => Some(verdict),
+                    Err(e) => {
+                        warn!("⚠️  Judge failed for {}: {:#}", agent.config_key(), e);
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+        comparisons.push(AgentComparison {
+            agent: agent.config_key().to_string(),
+            a: run_a,
+            b: run_b,
+            verdict,
+        });
+    }
+
+    Ok(EvalReport {
+        created_at: Local::now().to_rfc3339(),
+        variant_a: a.label,
+        variant_b: b.label,
+        snapshot,
+        agents: comparisons,
+    })
+}
+
+/// Generator context of a variant, on an in-memory copy of the snapshot so the last run's
+/// memory is left untouched
+pub(crate) fn variant_context(config: &Config, snapshot: &Path) -> Result<GeneratorContext> {
+    let mut config = config.clone();
+    // Agents would otherwise reuse the results of the snapshot instead of running, and cached
+    // answers hide the differences between models
+    config.resume = false;
+    config.cache.enabled = false;
+    let memory = Memory::load(snapshot).with_context(|| {
+        format!("No memory snapshot found in {}, run litho on the project first", snapshot.display())
+    })?;
+    let redactions = Arc::new(RedactionLog::default());
+    Ok(GeneratorContext {
+        llm_client: LLMClient::new(config.clone(), redactions.clone())?,
+        cache_manager: Arc::new(RwLock::new(CacheManager::new(
+            config.cache.clone(),
+            config.ui_language().clone(),
+        ))),
+        memory: Arc::new(RwLock::new(memory.with_limits(&config.memory))),
+        redactions,
+        config,
+    })
+}
+
+/// Run one agent, measuring its duration and LLM usage
+pub(crate) async fn run_agent(orchestrator: &ResearchOrchestrator, agent: &AgentType, context: &GeneratorContext) -> AgentRun {
+    let (calls_before, usage_before) = context.llm_client.token_usage();
+    let cost_before = estimated_cost(context);
+    let start = Instant::now();
+    let result = orchestrator.execute_single(agent, context).await;
+    let (calls, usage) = context.llm_client.token_usage();
+
+    let (output, error) = match result {
+        Ok(output) => (Some(output), None),
+        Err(e) => {
+            warn!("⚠️  {} failed: {:#}", agent.config_key(), e);
+            (None, Some(format!("{:#}", e)))
+        }
+    };
+    AgentRun {
+        duration_seconds: start.elapsed().as_secs_f64(),
+        llm_calls: calls - calls_before,
+        total_tokens: usage.total_tokens - usage_before.total_tokens,
+        estimated_cost: estimated_cost(context) - cost_before,
+        output_chars: output.as_ref().map_or(0, |output| output.to_string().len()),
+        output,
+        error,
+    }
+}
+
+/// Estimated cost of all calls of the context so far
+fn estimated_cost(context: &GeneratorContext) -> f64 {
+    context
+        .llm_client
+        .token_usage_records()
+        .iter()
+        .map(|record| TokenUsage::new(record.input_tokens, record.output_tokens).estimate_cost(&record.model))
+        .sum()
+}
+
+/// Score both outputs with the powerful model of variant A
+async fn judge_outputs(context: &GeneratorContext, agent: &AgentType, a: &Value, b: &Value) -> Result<JudgeVerdict> {
+    // Each output gets a third of the context window
+    let max_chars = context.config.llm.context_window() / 3 * CHARS_PER_TOKEN;
+    let truncate = |output: &Value| -> Result<String> {
+        Ok(serde_json::to_string_pretty(output)?.chars().take(max_chars).collect())
+    };
+    let user_prompt = format!(
+        "Agent: {} ({})\n\n## Output A\n{}\n\n## Output B\n{}",
+        agent,
+        agent.config_key(),
+        truncate(a)?,
+        truncate(b)?
+    );
+    context
+        .llm_client
+        .extract_with_model(JUDGE_SYSTEM_PROMPT, &user_prompt, &context.config.llm.model_powerful)
+        .await
+}
+
+impl EvalReport {
+    /// Write the report as markdown and JSON to `dir`, returning the markdown path
+    pub fn save(&self, dir: &Path) -> Result<PathBuf> {
+        std::fs::create_dir_all(dir)?;
+        let name = format!("eval-{}", Local::now().format("%Y%m%d-%H%M%S"));
+        let markdown = dir.join(format!("{}.md", name));
+        std::fs::write(&markdown, self.to_markdown())?;
+        std::fs::write(dir.join(format!("{}.json", name)), serde_json::to_string_pretty(self)?)?;
+        Ok(markdown)
+    }
+
+    /// Summary table, then both outputs of each agent in two columns
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::from("# Agent Evaluation\n\n");
+        md.push_str(&format!("- Variant A: {}\n", self.variant_a));
+        md.push_str(&format!("- Variant B: {}\n", self.variant_b));
+        md.push_str(&format!("- Memory snapshot: {}\n", self.snapshot.display()));
+        md.push_str(&format!("- Created at: {}\n\n", self.created_at));
+
+        md.push_str("| Agent | Tokens A | Tokens B | Seconds A | Seconds B | Chars A | Chars B | Score A | Score B | Winner |\n");
+        md.push_str("|---|---|---|---|---|---|---|---|---|---|\n");
+        for comparison in &self.agents {
+            let (score_a, score_b, winner) = match &comparison.verdict {
+                Some(verdict) => (
+                    format!("{:.1}", verdict.score_a),
+                    format!("{:.1}", verdict.score_b),
+                    verdict.winner.clone(),
+                ),
+                None => ("-".to_string(), "-".to_string(), "-".to_string()),
+            };
+            md.push_str(&format!(
+                "| {} | {} | {} | {:.1} | {:.1} | {} | {} | {} | {} | {} |\n",
+                comparison.agent,
+                comparison.a.total_tokens,
+                comparison.b.total_tokens,
+                comparison.a.duration_seconds,
+                comparison.b.duration_seconds,
+                comparison.a.output_chars,
+                comparison.b.output_chars,
+                score_a,
+                score_b,
+                winner
+            ));
+        }
+
+        for comparison in &self.agents {
+            md.push_str(&format!("\n## {}\n\n", comparison.agent));
+            if let Some(verdict) = &comparison.verdict {
+                md.push_str(&format!("**Judge:** {}\n\n", verdict.rationale));
+            }
+            md.push_str("<table>\n<tr><th>A</th><th>B</th></tr>\n<tr>\n");
+            for run in [&comparison.a, &comparison.b] {
+                let body = match (&run.output, &run.error) {
+                    (Some(output), _) => serde_json::to_string_pretty(output).unwrap_or_default(),
+                    (None, Some(error)) => format!("Failed: {}", error),
+                    (None, None) => String::new(),
+                };
+                md.push_str(&format!("<td valign=\"top\">\n\n```json\n{}\n```\n\n</td>\n", body));
+            }
+            md.push_str("</tr>\n</table>\n");
+        }
+        md
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(output: Option<Value>, error: Option<&str>) -> AgentRun {
+        AgentRun {
+            duration_seconds: 2.0,
+            llm_calls: 1,
+            total_tokens: 1200,
+            estimated_cost: 0.001,
+            output_chars: output.as_ref().map_or(0, |output| output.to_string().len()),
+            output,
+            error: error.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_eval_report() {
+        assert_eq!(
+            select_agents(&["workflow_researcher".to_string(), "system_context_researcher".to_string()]).unwrap(),
+            [AgentType::SystemContextResearcher, AgentType::WorkflowResearcher]
+        );
+        assert_eq!(select_agents(&[]).unwrap().len(), AgentType::all().len());
+        assert!(select_agents(&["architect".to_string()]).is_err());
+
+        let a = Config::default();
+        assert!(variant_b_config(&a, None, None).is_err());
+        let b = variant_b_config(&a, None, Some("gpt-4o")).unwrap();
+        assert_eq!((b.llm.model_efficient.as_str(), b.llm.model_powerful.as_str()), ("gpt-4o", "gpt-4o"));
+        assert_eq!(b.project_path, a.project_path);
+
+        let report = EvalReport {
+            created_at: "2025-01-01T12:00:00+00:00".to_string(),
+            variant_a: "litho.toml".to_string(),
+            variant_b: "model gpt-4o".to_string(),
+            snapshot: PathBuf::from(".litho/memory"),
+            agents: vec![AgentComparison {
+                agent: "workflow_researcher".to_string(),
+                a: run(Some(serde_json::json!({"main_workflow": "checkout"})), None),
+                b: run(None, Some("timeout")),
+                verdict: Some(JudgeVerdict {
+                    score_a: 7.0,
+                    score_b: 1.0,
+                    winner: "A".to_string(),
+                    rationale: "B produced no output".to_string(),
+                }),
+            }],
+        };
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("| workflow_researcher | 1200 | 1200 | 2.0 | 2.0 | 28 | 0 | 7.0 | 1.0 | A |"));
+        assert!(markdown.contains("**Judge:** B produced no output"));
+        assert!(markdown.contains("\"main_workflow\": \"checkout\""));
+        assert!(markdown.contains("Failed: timeout"));
+    }
+}
@@ -0,0 +1,272 @@
+//! Golden-output regression suite: the full pipeline runs on small fixture projects with the
+//! offline `mock` LLM provider, and the structure of the results (documents, headings, memory
+//! keys, shape of the research results) is compared with the fixture's recorded `golden.json`
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+use walkdir::WalkDir;
+
+use crate::config::{Config, LLMProvider};
+use crate::generator::outlet::RunStatus;
+use crate::generator::research::memory::MemoryScope as ResearchScope;
+use crate::generator::workflow;
+use crate::memory::Memory;
+
+/// Fixtures bundled with the repository, relative to its root
+pub const DEFAULT_FIXTURES_DIR: &str = "tests/golden";
+/// Project documented by a fixture
+const PROJECT_DIR: &str = "project";
+/// Optional configuration of a fixture, applied before the suite's own settings
+const CONFIG_FILE: &str = "litho.toml";
+const GOLDEN_FILE: &str = "golden.json";
+
+/// Structure of the results of a run, free of model wording and timings
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GoldenSnapshot {
+    pub status: RunStatus,
+    /// Generated files, relative to the output directory
+    pub documents: Vec<String>,
+    /// Headings of each markdown document
+    pub headings: BTreeMap<String, Vec<String>>,
+    /// Keys stored in each memory scope
+    pub memory: BTreeMap<String, Vec<String>>,
+    /// Research results with their values replaced by their JSON type
+    pub research: BTreeMap<String, Value>,
+}
+
+/// Outcome of one fixture
+pub struct FixtureResult {
+    pub name: String,
+    /// Empty when the results match the golden output
+    pub differences: Vec<String>,
+    /// The golden output was written instead of compared
+    pub updated: bool,
+}
+
+/// Run the fixtures of `dir` (only those named in `only`, all when empty), comparing their
+/// results with `golden.json`, or rewriting it when `update` is set
+pub async fn run_suite(dir: &Path, only: &[String], update: bool) -> Result<Vec<FixtureResult>> {
+    let mut fixtures: Vec<PathBuf> = fs::read_dir(dir)
+        .with_context(|| format!("No golden fixtures in {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.join(PROJECT_DIR).is_dir())
+        .collect();
+    fixtures.sort();
+    for name in only {
+        if !fixtures.iter().any(|fixture| fixture.ends_with(name)) {
+            bail!("No golden fixture {} in {}", name, dir.display());
+        }
+    }
+
+    let mut results = Vec::new();
+    for fixture in fixtures {
+        let name = fixture.file_name().unwrap_or_default().to_string_lossy().to_string();
+        if !only.is_empty() && !only.contains(&name) {
+            continue;
+        }
+        info!("🧪 Running golden fixture {}", name);
+        let actual = run_fixture(&fixture).await.with_context(|| format!("Golden fixture {} failed", name))?;
+        let golden_path = fixture.join(GOLDEN_FILE);
+        let result = if update || !golden_path.exists() {
+            fs::write(&golden_path, serde_json::to_string_pretty(&actual)? + "\n")?;
+            FixtureResult { name, differences: Vec::new(), updated: true }
+        } else {
+            let expected = serde_json::from_str(&fs::read_to_string(&golden_path)?)
+                .with_context(|| format!("Invalid golden output {}", golden_path.display()))?;
+            FixtureResult { name, differences: differences(&expected, &actual), updated: false }
+        };
+        results.push(result);
+    }
+    Ok(results)
+}
+
+/// Document a copy of the fixture project in a temporary workspace and capture the structure
+/// of the results
+async fn run_fixture(fixture: &Path) -> Result<GoldenSnapshot> {
+    let workspace = std::env::temp_dir().join(format!("litho-golden-{}", uuid::Uuid::new_v4()));
+    let result = async {
+        let project = workspace.join(PROJECT_DIR);
+        copy_dir(&fixture.join(PROJECT_DIR), &project)?;
+        let config = fixture_config(fixture, &project, &workspace.join("litho.docs"))?;
+        let status = workflow::launch(&config).await?;
+        snapshot(&config, status)
+    }
+    .await;
+    let _ = fs::remove_dir_all(&workspace);
+    result
+}
+
+/// Configuration of a fixture run: the fixture's `litho.toml` with the mock provider, no
+/// cache and the workspace paths
+fn fixture_config(fixture: &Path, project: &Path, output: &Path) -> Result<Config> {
+    let config_path = fixture.join(CONFIG_FILE);
+    let mut config = if config_path.exists() {
+        Config::from_file(&config_path)?
+    } else {
+        Config::default()
+    };
+    config.project_path = project.to_path_buf();
+    config.output_path = output.to_path_buf();
+    config.internal_path = project.join(".litho");
+    config.llm.provider = LLMProvider::Mock;
+    config.llm.api_key = "mock".to_string();
+    config.llm.model_efficient = "mock".to_string();
+    config.llm.model_powerful = "mock".to_string();
+    config.cache.enabled = false;
+    config.resume = false;
+    Ok(config)
+}
+
+fn snapshot(config: &Config, status: RunStatus) -> Result<GoldenSnapshot> {
+    let mut documents = Vec::new();
+    let mut headings = BTreeMap::new();
+    for entry in WalkDir::new(&config.output_path).sort_by_file_name() {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry
+            .path()
+            .strip_prefix(&config.output_path)?
+            .to_string_lossy()
+            .replace('\\', "/");
+        if relative.ends_with(".md") {
+            headings.insert(relative.clone(), markdown_headings(&fs::read_to_string(entry.path())?));
+        }
+        documents.push(relative);
+    }
+
+    let scopes = Memory::read_snapshot(&config.memory_dir())?;
+    let memory = scopes
+        .iter()
+        .map(|(scope, entries)| (scope.clone(), entries.keys().cloned().collect()))
+        .collect();
+    let research = scopes
+        .get(ResearchScope::STUDIES_RESEARCH)
+        .into_iter()
+        .flatten()
+        .map(|(key, value)| (key.clone(), shape(value)))
+        .collect();
+    Ok(GoldenSnapshot { status, documents, headings, memory, research })
+}
+
+/// ATX headings outside of code blocks
+fn markdown_headings(markdown: &str) -> Vec<String> {
+    let mut in_code = false;
+    let mut headings = Vec::new();
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+        } else if !in_code && line.starts_with('#') {
+            headings.push(line.trim().to_string());
+        }
+    }
+    headings
+}
+
+/// The value with every leaf replaced by its JSON type and arrays reduced to their first item
+pub fn shape(value: &Value) -> Value {
+    match value {
+        Value::Null => Value::from("null"),
+        Value::Bool(_) => Value::from("bool"),
+        Value::Number(_) => Value::from("number"),
+        Value::String(_) => Value::from("string"),
+        Value::Array(items) => Value::Array(items.first().map(shape).into_iter().collect()),
+        Value::Object(object) => Value::Object(
+            object
+                .iter()
+                .map(|(key, value)| (key.clone(), shape(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Differences between the golden and the actual structure, one line each
+pub fn differences(expected: &GoldenSnapshot, actual: &GoldenSnapshot) -> Vec<String> {
+    let mut differences = Vec::new();
+    match (serde_json::to_value(expected), serde_json::to_value(actual)) {
+        (Ok(expected), Ok(actual)) => diff_values("", &expected, &actual, &mut differences),
+        (Err(e), _) | (_, Err(e)) => differences.push(format!("unserializable snapshot: {}", e)),
+    }
+    differences
+}
+
+fn diff_values(path: &str, expected: &Value, actual: &Value, differences: &mut Vec<String>) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, value) in expected {
+                let child = format!("{}/{}", path, key);
+                match actual.get(key) {
+                    Some(actual) => diff_values(&child, value, actual, differences),
+                    None => differences.push(format!("{}: missing", child)),
+                }
+            }
+            for key in actual.keys().filter(|key| !expected.contains_key(*key)) {
+                differences.push(format!("{}/{}: unexpected", path, key));
+            }
+        }
+        (Value::Array(expected_items), Value::Array(actual_items))
+            if expected_items.iter().chain(actual_items).all(Value::is_string) =>
+        {
+            for item in expected_items.iter().filter(|item| !actual_items.contains(item)) {
+                differences.push(format!("{}: missing {}", path, item));
+            }
+            for item in actual_items.iter().filter(|item| !expected_items.contains(item)) {
+                differences.push(format!("{}: unexpected {}", path, item));
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) if expected.len() == actual.len() => {
+            for (index, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                diff_values(&format!("{}/{}", path, index), expected, actual, differences);
+            }
+        }
+        _ if expected != actual => differences.push(format!("{}: expected {}, got {}", path, expected, actual)),
+        _ => {}
+    }
+}
+
+fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    for entry in WalkDir::new(from) {
+        let entry = entry?;
+        let target = to.join(entry.path().strip_prefix(from)?);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target)?;
+        } else if entry.file_type().is_file() {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_golden_fixtures() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_FIXTURES_DIR);
+        let results = run_suite(&dir, &[], false).await.unwrap();
+        assert!(!results.is_empty());
+        for result in results {
+            assert!(!result.updated, "{} had no golden output", result.name);
+            assert!(result.differences.is_empty(), "{}: {:#?}", result.name, result.differences);
+        }
+
+        let golden: GoldenSnapshot =
+            serde_json::from_str(&fs::read_to_string(dir.join("rust-cli").join(GOLDEN_FILE)).unwrap()).unwrap();
+        let mut changed = golden.clone();
+        changed.documents.push("extra.md".to_string());
+        changed.research.insert(
+            "System Context Research Report".to_string(),
+            shape(&serde_json::json!({"project_name": 1, "modules": [{"name": "cli"}]})),
+        );
+        let differences = differences(&golden, &changed);
+        assert!(differences.contains(&"/documents: unexpected \"extra.md\"".to_string()));
+        assert!(differences.contains(&"/research/System Context Research Report/project_name: expected \"string\", got \"number\"".to_string()));
+    }
+}
@@ -4,6 +4,10 @@ pub mod research;
 pub mod compose;
 pub mod types;
 pub mod workflow;
+pub mod monorepo;
+pub mod eval;
+pub mod bench;
+pub mod golden;
 pub mod agent_executor;
 pub mod step_forward_agent;
 pub mod outlet;
@@ -0,0 +1,191 @@
+//! Monorepo support: document each configured sub-project and tie them together with a top-level overview
+
+use anyhow::{Result, anyhow};
+use std::fs;
+
+use crate::config::{Config, SubProjectConfig};
+use crate::generator::agent_executor::{AgentExecuteParams, prompt};
+use crate::generator::context::GeneratorContext;
+use crate::generator::outlet::run_summary::{RunKeys, RunScope};
+use crate::generator::outlet::{RunStatus, RunSummary};
+use crate::generator::research::memory::MemoryRetriever;
+use crate::generator::research::types::AgentType as ResearchAgentType;
+use crate::generator::workflow::{create_context, run_project};
+use crate::utils::paths;
+use tracing::{info, warn};
+
+/// Maximum characters of a sub-project's system context passed to the overview prompt
+const MAX_CONTEXT_CHARS: usize = 6000;
+
+/// Result of documenting a single sub-project
+struct SubProjectRun {
+    sub_project: SubProjectConfig,
+    context: GeneratorContext,
+    status: RunStatus,
+}
+
+/// Document every sub-project, then generate the top-level overview
+pub async fn launch_monorepo(config: &Config) -> Result<RunStatus> {
+    info!("🗂️ Monorepo mode: {} sub-projects configured", config.projects.len());
+
+    let root_context = create_context(config)?;
+    let mut runs = Vec::new();
+
+    for (idx, sub_project) in config.projects.iter().enumerate() {
+        info!(
+            "=== [{}/{}] Documenting sub-project '{}' ({}) ===",
+            idx + 1,
+            config.projects.len(),
+            sub_project.name,
+            sub_project.path.display()
+        );
+
+        let sub_config = match config.for_sub_project(sub_project) {
+            Ok(sub_config) => sub_config,
+            Err(e) => {
+                warn!("⚠️  {:#}, skipping", e);
+                root_context
+                    .record_failure("monorepo", &sub_project.name, &format!("{:#}", e))
+                    .await?;
+                continue;
+            }
+        };
+        if !sub_config.project_path.exists() {
+            warn!(
+                "⚠️  Sub-project path does not exist, skipping: {}",
+                sub_config.project_path.display()
+            );
+            root_context
+                .record_failure("monorepo", &sub_project.name, "sub-project path does not exist")
+                .await?;
+            continue;
+        }
+
+        let context = create_context(&sub_config)?;
+        match run_project(&context).await {
+            Ok(status) => {
+                if status != RunStatus::Success {
+                    root_context
+                        .record_failure(
+                            "monorepo",
+                            &sub_project.name,
+                            "partial failure, see the sub-project's run-summary.json",
+                        )
+                        .await?;
+                }
+                runs.push(SubProjectRun {
+                    sub_project: sub_project.clone(),
+                    context,
+                    status,
+                });
+            }
+            Err(e) => {
+                warn!("⚠️  Sub-project '{}' failed: {:#}", sub_project.name, e);
+                root_context
+                    .record_failure("monorepo", &sub_project.name, &format!("{:#}", e))
+                    .await?;
+            }
+        }
+    }
+
+    if runs.is_empty() {
+        let error = anyhow!("All sub-projects failed");
+        write_root_summary(&root_context, Some(format!("{:#}", error))).await;
+        return Err(error);
+    }
+
+    let result = write_top_level_overview(&root_context, &runs).await;
+    write_root_summary(&root_context, result.as_ref().err().map(|e| format!("{:#}", e))).await;
+    result?;
+
+    let status = if runs.iter().all(|r| r.status == RunStatus::Success)
+        && runs.len() == config.projects.len()
+    {
+        RunStatus::Success
+    } else {
+        RunStatus::PartialFailure
+    };
+    Ok(status)
+}
+
+/// Generate the overview that ties all sub-projects together
+async fn write_top_level_overview(root_context: &GeneratorContext, runs: &[SubProjectRun]) -> Result<()> {
+    info!("🖊️ Generating top-level monorepo overview...");
+    let config = &root_context.config;
+    let overview_filename = config.doc_filename("overview");
+
+    let mut project_sections = Vec::new();
+    for run in runs {
+        let system_context = run
+            .context
+            .get_research(&ResearchAgentType::SystemContextResearcher.to_string())
+            .await
+            .map(|v| v.to_string())
+            .unwrap_or_default();
+        let system_context: String = system_context.chars().take(MAX_CONTEXT_CHARS).collect();
+        project_sections.push(format!(
+            "### {}\n- Path: {}\n- Description: {}\n- System context research:\n{}\n",
+            run.sub_project.name,
+            paths::to_slash(&run.sub_project.path),
+            run.sub_project.description,
+            system_context
+        ));
+    }
+
+    let prompt_sys = format!(
+        "You are a software architect writing the top-level overview of a monorepo that contains several independently documented sub-projects. \
+Explain the purpose of the repository as a whole, the role of each sub-project, how the sub-projects depend on and interact with each other, and shared technologies or conventions. \
+Include a mermaid diagram of the relationships between the sub-projects. Start writing the documentation directly in markdown.\n\n{}",
+        config.target_language.prompt_instruction()
+    );
+    let prompt_user = format!(
+        "Repository: {}\n\n## Sub-projects\n{}",
+        config.get_project_name(),
+        project_sections.join("\n")
+    );
+
+    let params = AgentExecuteParams {
+        prompt_sys,
+        prompt_user,
+        cache_scope: "monorepo/overview".to_string(),
+        log_tag: "Monorepo Overview".to_string(),
+        progress: None,
+    };
+    let mut overview = prompt(root_context, params).await?;
+
+    // Deterministic index of the sub-project documentation
+    overview.push_str("\n\n## Sub-projects\n\n| Sub-project | Path | Documentation |\n|---|---|---|\n");
+    for run in runs {
+        let dir_name = run.sub_project.output_dir_name();
+        overview.push_str(&format!(
+            "| {} | `{}` | [{}/{}]({}/{}) |\n",
+            run.sub_project.name,
+            paths::to_slash(&run.sub_project.path),
+            dir_name,
+            overview_filename,
+            dir_name,
+            overview_filename.replace(' ', "%20")
+        ));
+    }
+
+    fs::create_dir_all(&config.output_path)?;
+    let overview_path = config.output_path.join(&overview_filename);
+    fs::write(&overview_path, overview)?;
+    info!("💾 Document saved: {}", overview_path.display());
+
+    let mut documents = vec![overview_filename];
+    documents.extend(runs.iter().map(|r| format!("{}/", r.sub_project.output_dir_name())));
+    root_context
+        .store_to_memory(RunScope::RUN, RunKeys::DOCUMENTS, &documents)
+        .await?;
+
+    Ok(())
+}
+
+/// Write the repository-level run summary
+async fn write_root_summary(root_context: &GeneratorContext, error: Option<String>) {
+    let summary = RunSummary::collect(root_context, error).await;
+    if let Err(e) = summary.save(root_context) {
+        warn!("⚠️  Failed to save run summary: {}", e);
+    }
+}
@@ -1,109 +1,503 @@
 use crate::generator::context::GeneratorContext;
 use anyhow::Result;
+use regex::Regex;
+use std::collections::HashMap;
 use std::path::Path;
-use std::process::Stdio;
-use tokio::process::Command as TokioCommand;
+use std::sync::LazyLock;
+use tracing::{info, warn};
+
+/// Diagram types a mermaid block may declare in its first statement
+const DIAGRAM_TYPES: &[&str] = &[
+    "graph",
+    "flowchart",
+    "sequenceDiagram",
+    "classDiagram",
+    "classDiagram-v2",
+    "stateDiagram",
+    "stateDiagram-v2",
+    "erDiagram",
+    "gantt",
+    "pie",
+    "journey",
+    "gitGraph",
+    "mindmap",
+    "timeline",
+    "quadrantChart",
+    "requirementDiagram",
+    "C4Context",
+    "C4Container",
+    "C4Component",
+    "C4Dynamic",
+    "C4Deployment",
+    "xychart-beta",
+    "sankey-beta",
+    "block-beta",
+    "packet-beta",
+    "architecture-beta",
+    "kanban",
+];
+
+/// Flowchart statements that declare no nodes
+const FLOWCHART_KEYWORDS: [&str; 7] = ["classDef", "class", "style", "linkStyle", "click", "direction", "subgraph"];
+
+/// Sequence diagram blocks closed by `end`
+const SEQUENCE_BLOCKS: [&str; 8] = ["alt", "opt", "loop", "par", "critical", "break", "rect", "box"];
+
+/// Node shapes by opening delimiter, longest first
+const NODE_SHAPES: [(&str, &str); 12] = [
+    ("(((", ")))"),
+    ("((", "))"),
+    ("([", "])"),
+    ("[[", "]]"),
+    ("[(", ")]"),
+    ("{{", "}}"),
+    ("[/", "/]"),
+    ("[\\", "\\]"),
+    ("[", "]"),
+    ("(", ")"),
+    ("{", "}"),
+    (">", "]"),
+];
+
+/// Characters that end a node label early unless the label is quoted
+const LABEL_SPECIAL_CHARS: [char; 10] = ['(', ')', '[', ']', '{', '}', '<', '>', '|', '"'];
+
+/// Flowchart links at the start of the text: `-->`, `---`, `-.->`, `==>`, `-- text -->`, with an
+/// optional `|label|`; `-->>`, `->>`, `->` and `=>` are invalid but recognized so they can be repaired
+static LINK_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^\s*<?(?:--\s[^>|]*?--+[>xo]|==\s[^>|]*?==+[>xo]|-\.\s[^>|]*?\.-[>xo]|-->>|->>|-{2,}[>xo]?|={2,}[>xo]?|-\.+-[>xo]?|->|=>)(?:\|[^|]*\|)?\s*",
+    )
+    .unwrap()
+});
+static NODE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^([A-Za-z0-9_][\w-]*)\s*(.*?)\s*(:::[\w-]+)?$").unwrap());
+static SEQUENCE_MESSAGE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^([^:]*?[^=\s])\s*(==?>)\s*([^:]+:.*)$").unwrap());
+static PARTICIPANT_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?:participant|actor)\s+(\S+)").unwrap());
+
+/// Outcome of checking a mermaid diagram
+#[derive(Debug, Default, PartialEq)]
+pub struct DiagramCheck {
+    /// Diagram source after the automatic repairs
+    pub source: String,
+    pub fixes: Vec<String>,
+    /// Problems the repairs could not resolve
+    pub errors: Vec<String>,
+}
+
+/// Outcome of checking the mermaid diagrams of a markdown document
+#[derive(Debug, Default)]
+pub struct MarkdownCheck {
+    pub content: String,
+    pub diagrams: usize,
+    pub fixes: Vec<String>,
+    /// Remaining errors with the line of the diagram fence
+    pub errors: Vec<(usize, String)>,
+}
+
+/// Validate a mermaid diagram and repair common LLM mistakes: unquoted labels with special
+/// characters, invalid arrows, nodes defined twice with different labels, `end` used as a node id
+/// and unclosed `subgraph`/`alt`/`loop` blocks
+pub fn check_diagram(source: &str) -> DiagramCheck {
+    let mut check = DiagramCheck {
+        source: source.to_string(),
+        ..Default::default()
+    };
+    let mut lines: Vec<String> = source.lines().map(str::to_string).collect();
+
+    // The header is the first statement after the optional front matter and directives
+    let mut in_front_matter = false;
+    let mut header = None;
+    for (index, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if trimmed == "---" {
+            in_front_matter = !in_front_matter;
+            continue;
+        }
+        if in_front_matter || trimmed.is_empty() || trimmed.starts_with("%%") {
+            continue;
+        }
+        header = Some(index);
+        break;
+    }
+    let Some(header) = header else {
+        check.errors.push("empty diagram".to_string());
+        return check;
+    };
+    let diagram_type = lines[header].split_whitespace().next().unwrap_or_default().to_string();
+    if !DIAGRAM_TYPES.contains(&diagram_type.as_str()) {
+        check.errors.push(format!("unknown diagram type `{}`", diagram_type));
+        return check;
+    }
+
+    let mut body = lines.split_off(header + 1);
+    match diagram_type.as_str() {
+        "graph" | "flowchart" => check_flowchart(&mut body, &mut check),
+        "sequenceDiagram" => check_sequence(&mut body, &mut check),
+        _ => {}
+    }
+    if !check.fixes.is_empty() {
+        lines.extend(body);
+        check.source = lines.join("\n");
+    }
+    check
+}
+
+/// Check the mermaid code blocks of a markdown document, repairing them in place
+pub fn check_markdown(markdown: &str) -> MarkdownCheck {
+    let mut result = MarkdownCheck::default();
+    let mut output: Vec<String> = Vec::new();
+    let mut diagram: Option<(usize, Vec<&str>)> = None;
+
+    for (index, line) in markdown.lines().enumerate() {
+        let trimmed = line.trim();
+        match diagram.as_mut() {
+            None => {
+                if trimmed.starts_with("```mermaid") {
+                    diagram = Some((index + 1, Vec::new()));
+                }
+                output.push(line.to_string());
+            }
+            Some((fence_line, body)) if trimmed == "```" => {
+                let check = check_diagram(&body.join("\n"));
+                result.diagrams += 1;
+                result.fixes.extend(check.fixes);
+                result.errors.extend(check.errors.into_iter().map(|error| (*fence_line, error)));
+                if !body.is_empty() {
+                    output.push(check.source);
+                }
+                output.push(line.to_string());
+                diagram = None;
+            }
+            Some((_, body)) => body.push(line),
+        }
+    }
+    if let Some((fence_line, body)) = diagram {
+        result.errors.push((fence_line, "unclosed mermaid code block".to_string()));
+        output.extend(body.into_iter().map(str::to_string));
+    }
+
+    result.content = if result.fixes.is_empty() {
+        markdown.to_string()
+    } else {
+        let mut content = output.join("\n");
+        if markdown.ends_with('\n') {
+            content.push('\n');
+        }
+        content
+    };
+    result
+}
+
+/// Part of a flowchart statement
+enum Part {
+    Nodes(String),
+    Link(String),
+}
+
+fn check_flowchart(lines: &mut [String], check: &mut DiagramCheck) {
+    let mut labels: HashMap<String, String> = HashMap::new();
+    let mut open_subgraphs = 0usize;
+
+    for line in lines.iter_mut() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with("%%") {
+            continue;
+        }
+        let keyword = trimmed.split_whitespace().next().unwrap_or_default();
+        if keyword == "end" && trimmed.split_whitespace().count() == 1 {
+            if open_subgraphs == 0 {
+                check.errors.push("`end` without an open subgraph".to_string());
+            }
+            open_subgraphs = open_subgraphs.saturating_sub(1);
+            continue;
+        }
+        if FLOWCHART_KEYWORDS.contains(&keyword) {
+            if keyword == "subgraph" {
+                open_subgraphs += 1;
+            }
+            continue;
+        }
+
+        let indent = &line[..line.len() - line.trim_start().len()];
+        let mut changed = false;
+        let mut statement = Vec::new();
+        for part in split_statement(trimmed) {
+            match part {
+                Part::Link(link) => {
+                    let fixed = fix_link(&link);
+                    if fixed != link {
+                        check.fixes.push(format!("replaced invalid arrow `{}` with `{}`", link, fixed));
+                        changed = true;
+                    }
+                    statement.push(fixed);
+                }
+                Part::Nodes(nodes) => {
+                    let fixed: Vec<String> = nodes
+                        .split(" & ")
+                        .map(|node| fix_node(node.trim(), &mut labels, check))
+                        .collect();
+                    let fixed = fixed.join(" & ");
+                    changed |= fixed != nodes;
+                    statement.push(fixed);
+                }
+            }
+        }
+        if changed {
+            *line = format!("{}{}", indent, statement.join(" "));
+        }
+    }
+
+    if open_subgraphs > 0 {
+        check.fixes.push(format!("closed {} unclosed subgraph(s)", open_subgraphs));
+        // The body is a slice, the missing `end`s are appended to its last line
+        if let Some(last) = lines.last_mut() {
+            for _ in 0..open_subgraphs {
+                last.push_str("\n    end");
+            }
+        }
+    }
+}
+
+/// Split a statement into node groups and the links between them, ignoring link-like text in labels
+fn split_statement(statement: &str) -> Vec<Part> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut in_quotes = false;
+    let mut start = 0;
+    let mut position = 0;
+    while position < statement.len() {
+        let rest = &statement[position..];
+        let c = rest.chars().next().unwrap_or_default();
+        if !in_quotes && depth == 0 && position > start && let Some(link) = LINK_REGEX.find(rest) {
+            parts.push(Part::Nodes(statement[start..position].trim().to_string()));
+            parts.push(Part::Link(link.as_str().trim().to_string()));
+            position += link.end();
+            start = position;
+            continue;
+        }
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '[' | '(' | '{' if !in_quotes => depth += 1,
+            ']' | ')' | '}' if !in_quotes => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        position += c.len_utf8();
+    }
+    if start < statement.len() {
+        parts.push(Part::Nodes(statement[start..].trim().to_string()));
+    }
+    parts
+}
+
+fn fix_link(link: &str) -> String {
+    let (arrow, label) = match link.find('|') {
+        Some(index) => (&link[..index], &link[index..]),
+        None => (link, ""),
+    };
+    let arrow = match arrow.trim() {
+        "-->>" | "->>" | "->" => "-->",
+        "=>" => "==>",
+        other => other,
+    };
+    format!("{}{}", arrow, label)
+}
+
+fn fix_node(node: &str, labels: &mut HashMap<String, String>, check: &mut DiagramCheck) -> String {
+    let Some(captures) = NODE_REGEX.captures(node) else {
+        return node.to_string();
+    };
+    let mut id = captures[1].to_string();
+    let shape = captures.get(2).map_or("", |m| m.as_str());
+    let class = captures.get(3).map_or("", |m| m.as_str());
+    if id == "end" {
+        check.fixes.push("renamed reserved node id `end` to `end_node`".to_string());
+        id = "end_node".to_string();
+    }
+    // Bare references and the extended `@{ ... }` syntax are kept as they are
+    if shape.is_empty() || shape.starts_with('@') {
+        return format!("{}{}{}", id, shape, class);
+    }
+
+    let Some((open, close)) = NODE_SHAPES.iter().find(|(open, _)| shape.starts_with(open)) else {
+        check.errors.push(format!("cannot parse the shape of node `{}`: {}", id, shape));
+        return node.to_string();
+    };
+    let Some(label) = shape.strip_prefix(open).and_then(|rest| rest.strip_suffix(close)) else {
+        check.errors.push(format!("unbalanced delimiters in node `{}`: {}", id, shape));
+        return node.to_string();
+    };
+    let label = quote_label(label.trim());
+    if label != shape[open.len()..shape.len() - close.len()].trim() {
+        check.fixes.push(format!("quoted the label of node `{}`", id));
+    }
+
+    match labels.get(&id) {
+        Some(first) if *first != label => {
+            check.fixes.push(format!(
+                "node `{}` was defined again with a different label, kept {}",
+                id, first
+            ));
+            format!("{}{}", id, class)
+        }
+        _ => {
+            labels.insert(id.clone(), label.clone());
+            format!("{}{}{}{}{}", id, open, label, close, class)
+        }
+    }
+}
+
+/// Quote a label containing characters mermaid would read as delimiters
+fn quote_label(label: &str) -> String {
+    if label.starts_with('`') {
+        return label.to_string();
+    }
+    let inner = match label.strip_prefix('"').and_then(|l| l.strip_suffix('"')) {
+        Some(quoted) if !quoted.contains('"') => return label.to_string(),
+        Some(quoted) => quoted,
+        None if !label.contains(LABEL_SPECIAL_CHARS) => return label.to_string(),
+        None => label,
+    };
+    format!("\"{}\"", inner.replace('"', "#quot;"))
+}
+
+fn check_sequence(lines: &mut [String], check: &mut DiagramCheck) {
+    let mut participants: Vec<String> = Vec::new();
+    let mut open_blocks = 0usize;
+
+    for line in lines.iter_mut() {
+        let trimmed = line.trim().to_string();
+        let keyword = trimmed.split_whitespace().next().unwrap_or_default();
+        if let Some(captures) = PARTICIPANT_REGEX.captures(&trimmed) {
+            let name = captures[1].to_string();
+            if participants.contains(&name) {
+                check.fixes.push(format!("removed duplicate participant `{}`", name));
+                line.clear();
+            } else {
+                participants.push(name);
+            }
+        } else if SEQUENCE_BLOCKS.contains(&keyword) {
+            open_blocks += 1;
+        } else if trimmed == "end" {
+            if open_blocks == 0 {
+                check.errors.push("`end` without an open block".to_string());
+            }
+            open_blocks = open_blocks.saturating_sub(1);
+        } else if let Some(captures) = SEQUENCE_MESSAGE_REGEX.captures(&trimmed) {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            check
+                .fixes
+                .push(format!("replaced invalid message arrow `{}` with `->>`", &captures[2]));
+            *line = format!("{}{}->>{}", indent, &captures[1], &captures[3]);
+        }
+    }
+
+    if open_blocks > 0 {
+        check.fixes.push(format!("closed {} unclosed block(s)", open_blocks));
+        if let Some(last) = lines.last_mut() {
+            for _ in 0..open_blocks {
+                last.push_str("\n    end");
+            }
+        }
+    }
+}
 
 /// Mermaid diagram fixer
 ///
-/// Uses the mermaid-fixer program to fix syntax errors in mermaid diagrams generated by LLMs.
-/// This is a required dependency — the application will fail to start if mermaid-fixer is not installed.
+/// Validates the mermaid diagrams of the generated documents with a built-in checker
+/// and repairs the syntax errors LLMs commonly make, so diagrams render without extra tools.
 pub struct MermaidFixer;
 
 impl MermaidFixer {
-    /// Check if mermaid-fixer is available
-    pub async fn is_available() -> bool {
-        match TokioCommand::new("mermaid-fixer")
-            .arg("--version")
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .status()
-            .await
-        {
-            Ok(status) => status.success(),
-            Err(_) => false,
-        }
-    }
-
-    /// Fix mermaid charts in specified directory
-    /// 
-    /// # Arguments
-    /// - `context`: Generator context containing configuration information
-    /// - `target_dir`: Directory path to fix
-    /// 
+    /// Check and repair the mermaid diagrams of the markdown files in the directory
+    ///
     /// # Returns
-    /// - `Ok(())`: Fix succeeded or skipped
+    /// - `Ok(())`: Diagrams checked; unrepairable ones are reported as warnings
     /// - `Err(anyhow::Error)`: Error occurred during fixing process
-    pub async fn fix_mermaid_charts(
-        context: &GeneratorContext,
-        target_dir: &Path,
-    ) -> Result<()> {
-        println!("🔧 Starting mermaid chart fixing...");
-
-        // Build mermaid-fixer command
-        let mut cmd = TokioCommand::new("mermaid-fixer");
-        
-        // Set target directory
-        cmd.arg("--directory").arg(target_dir);
-        
-        // Get LLM parameters from configuration
-        let llm_config = &context.config.llm;
-        
-        // Set model parameters
-        cmd.arg("--llm-model").arg(&llm_config.model_powerful);
-        
-        // Set API key
-        if !llm_config.api_key.is_empty() {
-            cmd.arg("--llm-api-key").arg(&llm_config.api_key);
-        }
-        
-        // Set API base URL
-        if !llm_config.api_base_url.is_empty() {
-            cmd.arg("--llm-base-url").arg(&llm_config.api_base_url);
-        }
-        
-        // Enable verbose output
-        cmd.arg("--verbose");
-        
-        // Set stdout and stderr to inherit so we can see output in main program
-        cmd.stdout(Stdio::inherit());
-        cmd.stderr(Stdio::inherit());
-
-        println!("🚀 Executing command (showing partial info): mermaid-fixer --directory {} --llm-model {} --verbose", 
-                 target_dir.display(), 
-                 llm_config.model_powerful);
-
-        // Execute command
-        match cmd.status().await {
-            Ok(status) => {
-                if status.success() {
-                    println!("✅ Mermaid chart fixing completed");
-                } else {
-                    println!("⚠️ mermaid-fixer execution completed but returned non-zero status code: {}", 
-                             status.code().unwrap_or(-1));
-                    println!("💡 This may indicate some charts cannot be fixed, but won't affect subsequent processes");
+    pub async fn fix_mermaid_charts(context: &GeneratorContext, target_dir: &Path) -> Result<()> {
+        info!("🔧 Checking mermaid diagrams...");
+
+        let (mut diagrams, mut fixes, mut errors) = (0, 0, 0);
+        let documents = walkdir::WalkDir::new(target_dir)
+            .into_iter()
+            .flatten()
+            .filter(|entry| entry.file_type().is_file() && entry.path().extension().is_some_and(|e| e == "md"));
+        for document in documents {
+            let path = document.path();
+            let content = match tokio::fs::read_to_string(path).await {
+                Ok(content) => content,
+                Err(e) => {
+                    warn!("{}", context.config.ui_language().msg_mermaid_error(&e.to_string()));
+                    continue;
                 }
+            };
+            let check = check_markdown(&content);
+            diagrams += check.diagrams;
+            fixes += check.fixes.len();
+            errors += check.errors.len();
+            for (line, error) in &check.errors {
+                warn!("⚠️ Mermaid diagram at {}:{}: {}", path.display(), line, error);
             }
-            Err(e) => {
-                let msg = context.config.target_language.msg_mermaid_error();
-                println!("{}", msg.replace("{}", &e.to_string()));
+            if !check.fixes.is_empty()
+                && let Err(e) = tokio::fs::write(path, check.content).await
+            {
+                warn!("{}", context.config.ui_language().msg_mermaid_error(&e.to_string()));
             }
         }
 
+        info!(
+            "✅ Mermaid check completed: {} diagrams, {} repairs, {} unresolved errors",
+            diagrams, fixes, errors
+        );
         Ok(())
     }
 
     /// Automatically fix mermaid charts after document output
-    /// 
+    ///
     /// This is a convenience method that automatically uses the output directory as the fixing target
     pub async fn auto_fix_after_output(context: &GeneratorContext) -> Result<()> {
         let output_dir = &context.config.output_path;
-        
-        if !output_dir.exists() {
-            println!("⚠️ Output directory does not exist, skipping mermaid chart fixing");
+
+        if !tokio::fs::try_exists(output_dir).await.unwrap_or(false) {
+            info!("⚠️ Output directory does not exist, skipping mermaid chart fixing");
             return Ok(());
         }
 
         Self::fix_mermaid_charts(context, output_dir).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repair_flowchart() {
+        let source = "flowchart TD\n    A[Load config (toml)] -> B{Valid?}\n    B -->|yes| end\n    subgraph Core\n        A[Other label] --> C[\"Run \"fast\" path\"]\n    C -.-> D[a -> b]";
+
+        let check = check_diagram(source);
+
+        assert_eq!(
+            check.source,
+            "flowchart TD\n    A[\"Load config (toml)\"] --> B{Valid?}\n    B -->|yes| end_node\n    subgraph Core\n        A --> C[\"Run #quot;fast#quot; path\"]\n    C -.-> D[\"a -> b\"]\n    end"
+        );
+        assert_eq!(check.fixes.len(), 7);
+        assert!(check.errors.is_empty());
+    }
+
+    #[test]
+    fn test_check_markdown_sequence_and_errors() {
+        let markdown = "# Flow\n\n```mermaid\nsequenceDiagram\n    participant A\n    participant A\n    A => B: call\n    alt ok\n        B-->>A: done\n```\n\n```mermaid\nflowchat LR\n```\n";
+
+        let check = check_markdown(markdown);
+
+        assert_eq!(check.diagrams, 2);
+        assert_eq!(
+            check.content,
+            "# Flow\n\n```mermaid\nsequenceDiagram\n    participant A\n\n    A->>B: call\n    alt ok\n        B-->>A: done\n    end\n```\n\n```mermaid\nflowchat LR\n```\n"
+        );
+        assert_eq!(check.errors, vec![(12, "unknown diagram type `flowchat`".to_string())]);
+    }
+}
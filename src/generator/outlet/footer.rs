@@ -0,0 +1,96 @@
+//! Generation metadata appended to every written document: tool version, models, source
+//! commit and date, so readers can judge how fresh each page is
+
+use chrono::Local;
+
+use crate::generator::context::GeneratorContext;
+use crate::utils::git_worktree;
+
+/// Footer used when `output.footer_template` is not set
+const DEFAULT_TEMPLATE: &str = "Generated by Litho {version} with {models} from commit {commit} on {date}.";
+
+/// Metadata of the run shown in the footer
+pub struct FooterInfo {
+    pub version: String,
+    /// Models that answered during the run, else the configured ones
+    pub models: Vec<String>,
+    pub commit: Option<String>,
+    pub date: String,
+}
+
+impl FooterInfo {
+    pub fn collect(context: &GeneratorContext) -> Self {
+        let config = &context.config;
+        let mut models: Vec<String> = context
+            .llm_client
+            .token_usage_records()
+            .into_iter()
+            .map(|record| record.model)
+            .collect();
+        if models.is_empty() {
+            models = vec![config.llm.model_efficient.clone(), config.llm.model_powerful.clone()];
+        }
+        models.sort();
+        models.dedup();
+        Self {
+            version: format!("v{}", env!("CARGO_PKG_VERSION")),
+            models,
+            commit: git_worktree::short_commit(&config.project_path, "HEAD"),
+            date: Local::now().format("%Y-%m-%d").to_string(),
+        }
+    }
+
+    /// Footer text of the template, with `{version}`, `{models}`, `{commit}` and `{date}`
+    /// replaced; separated from the document by a rule
+    pub fn render(&self, template: Option<&str>) -> String {
+        let text = template
+            .unwrap_or(DEFAULT_TEMPLATE)
+            .replace("{version}", &self.version)
+            .replace("{models}", &self.models.join(", "))
+            .replace("{commit}", self.commit.as_deref().unwrap_or("unknown"))
+            .replace("{date}", &self.date);
+        format!("\n---\n\n*{}*\n", text.trim())
+    }
+}
+
+/// The document with the footer appended
+pub fn append_footer(markdown: &str, footer: &str) -> String {
+    format!("{}\n{}", markdown.trim_end(), footer)
+}
+
+/// The document without its footer, for comparing documents of different runs
+pub fn strip_footer(markdown: &str) -> &str {
+    if let Some(start) = markdown.rfind("\n\n---\n\n*") {
+        let footer = &markdown[start + 7..];
+        if footer.trim_end().ends_with('*') && !footer.trim_end().contains('\n') {
+            return &markdown[..start];
+        }
+    }
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_footer() {
+        let info = FooterInfo {
+            version: "v1.2.0".to_string(),
+            models: vec!["gpt-4o".to_string(), "gpt-4o-mini".to_string()],
+            commit: Some("3f2c1ab".to_string()),
+            date: "2025-01-01".to_string(),
+        };
+        let markdown = append_footer("# Overview\n\nText.\n\n", &info.render(None));
+        assert_eq!(
+            markdown,
+            "# Overview\n\nText.\n\n---\n\n*Generated by Litho v1.2.0 with gpt-4o, gpt-4o-mini from commit 3f2c1ab on 2025-01-01.*\n"
+        );
+
+        assert_eq!(strip_footer(&markdown), "# Overview\n\nText.");
+        assert_eq!(strip_footer("# Overview\n\n---\n\n*Note*\n\nMore text.\n"), "# Overview\n\n---\n\n*Note*\n\nMore text.\n");
+
+        let info = FooterInfo { commit: None, ..info };
+        assert_eq!(info.render(Some("Built {date} at {commit}")), "\n---\n\n*Built 2025-01-01 at unknown*\n");
+    }
+}
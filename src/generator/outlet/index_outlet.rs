@@ -0,0 +1,179 @@
+use anyhow::Result;
+use std::fs;
+use std::path::Path;
+use tracing::info;
+use walkdir::WalkDir;
+
+use super::Outlet;
+use crate::generator::context::GeneratorContext;
+use crate::integrations::retrieval::EmbeddingClient;
+use crate::integrations::vector_index::{IndexItem, IndexKind, VectorIndex};
+use crate::types::CodeAndDirectoryInsights;
+use crate::types::code::InterfaceInfo;
+use crate::utils::paths;
+
+/// Index outlet - embeds code insights, interfaces and generated documents into `.litho/index`
+#[derive(Default)]
+pub struct IndexOutlet;
+
+impl IndexOutlet {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn insight_items(insights: &CodeAndDirectoryInsights) -> (Vec<IndexItem>, Vec<IndexItem>) {
+        let mut insight_items = Vec::new();
+        let mut interface_items = Vec::new();
+
+        for insight in &insights.file_insights {
+            let dossier = &insight.code_dossier;
+            let path = paths::to_slash(&dossier.file_path);
+            insight_items.push(IndexItem {
+                id: path.clone(),
+                source: path.clone(),
+                title: dossier.name.clone(),
+                text: format!(
+                    "{} ({})\n{}\n{}",
+                    dossier.name,
+                    path,
+                    insight.detailed_description,
+                    insight.responsibilities.join("\n")
+                ),
+            });
+            interface_items.extend(Self::interface_items(&path, &insight.interfaces));
+        }
+
+        for directory in &insights.directory_insights {
+            let path = paths::to_slash(&directory.path);
+            insight_items.push(IndexItem {
+                id: path.clone(),
+                source: path.clone(),
+                title: directory.name.clone(),
+                text: format!("{} ({})\n{}", directory.name, path, directory.summary),
+            });
+            for file in &directory.file_insights {
+                let file_path = paths::to_slash(&file.file_path);
+                insight_items.push(IndexItem {
+                    id: file_path.clone(),
+                    source: file_path.clone(),
+                    title: file.name.clone(),
+                    text: format!(
+                        "{} ({})\n{}\n{}\n{}",
+                        file.name,
+                        file_path,
+                        file.summary,
+                        file.detailed_description,
+                        file.responsibilities.join("\n")
+                    ),
+                });
+                interface_items.extend(Self::interface_items(&file_path, &file.interfaces));
+            }
+        }
+
+        (insight_items, interface_items)
+    }
+
+    fn interface_items(file_path: &str, interfaces: &[InterfaceInfo]) -> Vec<IndexItem> {
+        interfaces
+            .iter()
+            .map(|interface| {
+                let params: Vec<String> = interface
+                    .parameters
+                    .iter()
+                    .map(|p| format!("{}: {}", p.name, p.param_type))
+                    .collect();
+                let signature = format!(
+                    "{} {}({}){}",
+                    interface.interface_type,
+                    interface.name,
+                    params.join(", "),
+                    interface
+                        .return_type
+                        .as_ref()
+                        .map(|r| format!(" -> {}", r))
+                        .unwrap_or_default()
+                );
+                IndexItem {
+                    id: format!("{}#{}", file_path, interface.name),
+                    source: file_path.to_string(),
+                    title: interface.name.clone(),
+                    text: format!(
+                        "{}\n{}",
+                        signature,
+                        interface.description.clone().unwrap_or_default()
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    /// Split generated Markdown documents into one item per `##` section
+    pub fn document_items(output_dir: &Path) -> Vec<IndexItem> {
+        let mut items = Vec::new();
+
+        for entry in WalkDir::new(output_dir).into_iter().filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path.extension().is_none_or(|ext| ext != "md") {
+                continue;
+            }
+            let Ok(content) = fs::read_to_string(path) else {
+                continue;
+            };
+            let relative = paths::to_slash(path.strip_prefix(output_dir).unwrap_or(path));
+
+            for (i, section) in content.split("\n## ").enumerate() {
+                if section.trim().is_empty() {
+                    continue;
+                }
+                let title = section.lines().next().unwrap_or_default().trim_start_matches('#').trim();
+                items.push(IndexItem {
+                    id: format!("{}#{}", relative, i),
+                    source: relative.clone(),
+                    title: title.to_string(),
+                    text: section.to_string(),
+                });
+            }
+        }
+
+        items
+    }
+}
+
+impl Outlet for IndexOutlet {
+    async fn save(&self, context: &GeneratorContext) -> Result<()> {
+        let Some(retrieval) = context
+            .config
+            .knowledge
+            .retrieval
+            .as_ref()
+            .filter(|r| r.enabled)
+        else {
+            return Ok(());
+        };
+
+        info!("🗂️  Updating vector index...");
+        let client = EmbeddingClient::new(retrieval, &context.config.llm);
+        let mut index = VectorIndex::open(
+            &VectorIndex::default_dir(&context.config.internal_path),
+            client.model(),
+        );
+
+        let mut embedded = 0;
+        if let Some(insights) = context
+            .code_insights()
+            .await
+        {
+            let (insight_items, interface_items) = Self::insight_items(&insights);
+            embedded += index.replace_kind(&client, IndexKind::CodeInsight, insight_items).await?;
+            embedded += index.replace_kind(&client, IndexKind::Interface, interface_items).await?;
+        }
+
+        let document_items = Self::document_items(&context.config.output_path);
+        embedded += index.replace_kind(&client, IndexKind::Document, document_items).await?;
+
+        index.save()?;
+        info!("💾 Vector index updated ({} entries embedded)", embedded);
+        index.log_stats();
+        Ok(())
+    }
+}
@@ -0,0 +1,324 @@
+use anyhow::Result;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+use std::sync::LazyLock;
+use tracing::{info, warn};
+use walkdir::WalkDir;
+
+use super::Outlet;
+use super::navigation::{headings, slugify};
+use crate::generator::context::GeneratorContext;
+
+/// Markdown links and images: `[text](target)` and `![alt](target "title")`
+static LINK_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(!?)\[([^\]]*)\]\(\s*<?([^)\s>]+)>?(?:\s+"[^"]*")?\s*\)"#).unwrap());
+static INLINE_CODE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"`[^`]*`").unwrap());
+
+/// Link that does not resolve, with the repair applied when fixing is enabled
+#[derive(Debug, PartialEq)]
+pub struct BrokenLink {
+    /// Document relative to the output directory
+    pub document: PathBuf,
+    pub line: usize,
+    pub target: String,
+    /// New target, or `None` when the link was replaced by its text
+    pub replacement: Option<String>,
+}
+
+/// Link checker outlet - verifies that anchors, links between documents and links to project
+/// files resolve within the output tree or the repository, and optionally repairs them
+#[derive(Default)]
+pub struct LinkCheckOutlet;
+
+impl LinkCheckOutlet {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Outlet for LinkCheckOutlet {
+    async fn save(&self, context: &GeneratorContext) -> Result<()> {
+        let settings = &context.config.link_check;
+        if !settings.enabled || !context.config.output_path.exists() {
+            return Ok(());
+        }
+        info!("🔗 Checking links in the generated documents...");
+
+        let broken = check_links(&context.config.output_path, &context.config.project_path, settings.fix)?;
+        for link in &broken {
+            match &link.replacement {
+                Some(replacement) if settings.fix => info!(
+                    "🔗 Fixed link {}:{}: {} -> {}",
+                    link.document.display(),
+                    link.line,
+                    link.target,
+                    replacement
+                ),
+                None if settings.fix => info!(
+                    "🔗 Unlinked {}:{}: {} does not resolve",
+                    link.document.display(),
+                    link.line,
+                    link.target
+                ),
+                _ => warn!(
+                    "⚠️ Broken link {}:{}: {}",
+                    link.document.display(),
+                    link.line,
+                    link.target
+                ),
+            }
+        }
+        info!("✅ Link check completed: {} broken links", broken.len());
+        Ok(())
+    }
+}
+
+/// Check the links of all markdown documents under `output_dir`; with `fix`, links to project
+/// files are made relative to the document, anchors and documents are matched by name,
+/// and links that cannot be repaired are replaced by their text
+pub fn check_links(output_dir: &Path, project_dir: &Path, fix: bool) -> Result<Vec<BrokenLink>> {
+    let documents: Vec<PathBuf> = WalkDir::new(output_dir)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file() && entry.path().extension().is_some_and(|e| e == "md"))
+        .filter_map(|entry| entry.path().strip_prefix(output_dir).ok().map(Path::to_path_buf))
+        .collect();
+    let mut contents = HashMap::new();
+    for document in &documents {
+        contents.insert(document.clone(), std::fs::read_to_string(output_dir.join(document))?);
+    }
+    let anchors: HashMap<&PathBuf, HashSet<String>> =
+        contents.iter().map(|(document, content)| (document, heading_anchors(content))).collect();
+
+    let mut broken = Vec::new();
+    for document in &documents {
+        let content = &contents[document];
+        let directory = document.parent().unwrap_or(Path::new(""));
+        let resolver = Resolver {
+            output_dir,
+            project_dir,
+            directory,
+            document,
+            documents: &documents,
+            anchors: &anchors,
+        };
+
+        let mut changed = false;
+        let mut in_code_block = false;
+        let mut lines = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            if line.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+            }
+            if in_code_block || !line.contains("](") {
+                lines.push(line.to_string());
+                continue;
+            }
+            let code_spans: Vec<(usize, usize)> =
+                INLINE_CODE_REGEX.find_iter(line).map(|m| (m.start(), m.end())).collect();
+            let fixed = LINK_REGEX.replace_all(line, |captures: &regex::Captures| {
+                let whole = captures.get(0).unwrap();
+                let target = &captures[3];
+                if code_spans.iter().any(|(start, end)| whole.start() >= *start && whole.start() < *end)
+                    || resolver.resolves(target)
+                {
+                    return whole.as_str().to_string();
+                }
+                let replacement = resolver.repair(target);
+                broken.push(BrokenLink {
+                    document: document.clone(),
+                    line: index + 1,
+                    target: target.to_string(),
+                    replacement: replacement.clone(),
+                });
+                match replacement {
+                    Some(replacement) => format!("{}[{}]({})", &captures[1], &captures[2], replacement),
+                    // An image without its file is dropped, a link keeps its text
+                    None if &captures[1] == "!" => String::new(),
+                    None => captures[2].to_string(),
+                }
+            });
+            changed |= fixed != line;
+            lines.push(fixed.into_owned());
+        }
+
+        if fix && changed {
+            let mut fixed = lines.join("\n");
+            if content.ends_with('\n') {
+                fixed.push('\n');
+            }
+            std::fs::write(output_dir.join(document), fixed)?;
+        }
+    }
+    Ok(broken)
+}
+
+struct Resolver<'a> {
+    output_dir: &'a Path,
+    project_dir: &'a Path,
+    /// Directory of the document, relative to the output directory
+    directory: &'a Path,
+    document: &'a Path,
+    documents: &'a [PathBuf],
+    anchors: &'a HashMap<&'a PathBuf, HashSet<String>>,
+}
+
+impl Resolver<'_> {
+    fn resolves(&self, target: &str) -> bool {
+        if is_external(target) {
+            return true;
+        }
+        let (path, fragment) = split_fragment(target);
+        if path.is_empty() {
+            return self.has_anchor(self.document, fragment);
+        }
+        let Some(resolved) = normalize(&self.directory.join(decode(path))) else {
+            // Escapes the output directory, e.g. `../src/main.rs` from the output root
+            return self.output_dir.join(self.directory).join(decode(path)).exists();
+        };
+        if let Some(document) = self.documents.iter().find(|d| **d == resolved) {
+            return self.has_anchor(document, fragment);
+        }
+        self.output_dir.join(&resolved).exists()
+    }
+
+    /// Target pointing to the intended document, anchor or project file
+    fn repair(&self, target: &str) -> Option<String> {
+        if is_external(target) {
+            return None;
+        }
+        let (path, fragment) = split_fragment(target);
+        let anchor_of = |document: &Path| -> Option<String> {
+            match fragment {
+                None => Some(String::new()),
+                Some(fragment) => {
+                    let slug = slugify(&decode(fragment));
+                    self.has_anchor(document, Some(&slug)).then(|| format!("#{}", slug))
+                }
+            }
+        };
+        if path.is_empty() {
+            return anchor_of(self.document).filter(|anchor| !anchor.is_empty());
+        }
+
+        let file_name = Path::new(path).file_name()?;
+        // A document of the output tree with the same name
+        if let Some(document) = self.documents.iter().find(|d| d.file_name() == Some(file_name)) {
+            return Some(format!("{}{}", relative_to(self.directory, document), anchor_of(document)?));
+        }
+        // A project file, cited relative to the project root
+        let project_path = decode(path.trim_start_matches("./").trim_start_matches('/'));
+        let project_file = self.project_dir.join(&project_path);
+        if project_file.exists() {
+            let output_dir = absolute(self.output_dir);
+            let from = output_dir.join(self.directory);
+            return Some(relative_to(&from, &absolute(&project_file)));
+        }
+        None
+    }
+
+    fn has_anchor(&self, document: &Path, fragment: Option<&str>) -> bool {
+        match fragment {
+            None => true,
+            Some(fragment) => self
+                .anchors
+                .get(&document.to_path_buf())
+                .is_some_and(|anchors| anchors.contains(&decode(fragment).to_lowercase())),
+        }
+    }
+}
+
+fn is_external(target: &str) -> bool {
+    target.contains("://") || target.starts_with("mailto:") || target.starts_with("data:")
+}
+
+fn split_fragment(target: &str) -> (&str, Option<&str>) {
+    match target.split_once('#') {
+        Some((path, fragment)) => (path, Some(fragment)),
+        None => (target, None),
+    }
+}
+
+fn decode(value: &str) -> String {
+    value.replace("%20", " ")
+}
+
+/// Anchors of the headings of a document, as generated by GitHub
+fn heading_anchors(content: &str) -> HashSet<String> {
+    headings(content).into_iter().map(|heading| heading.anchor).collect()
+}
+
+/// Path without `.` and `..` components; `None` when it leaves its root
+fn normalize(path: &Path) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return None;
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+    Some(normalized)
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Relative link from the directory to the path, with forward slashes
+pub(crate) fn relative_to(from_dir: &Path, to: &Path) -> String {
+    let from: Vec<Component> = from_dir.components().collect();
+    let to_components: Vec<Component> = to.components().collect();
+    let common = from.iter().zip(&to_components).take_while(|(a, b)| a == b).count();
+    let mut parts: Vec<String> = vec!["..".to_string(); from.len() - common];
+    parts.extend(to_components[common..].iter().map(|c| c.as_os_str().to_string_lossy().to_string()));
+    parts.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_and_fix_links() {
+        let temp = tempfile::tempdir().unwrap();
+        let project = temp.path().to_path_buf();
+        let output = project.join("litho.docs");
+        std::fs::create_dir_all(output.join("4.Deep-Exploration")).unwrap();
+        std::fs::create_dir_all(project.join("src")).unwrap();
+        std::fs::write(project.join("src/main.rs"), "fn main() {}\n").unwrap();
+        std::fs::write(
+            output.join("1.Overview.md"),
+            "# Overview\n\n## Core Modules\n\nSee [modules](#core-modules), [orders](Orders.md#order-flow), \
+             [entry](src/main.rs), [gone](#missing) and [site](https://example.com).\n\n`[code](nowhere.md)`\n",
+        )
+        .unwrap();
+        std::fs::write(output.join("4.Deep-Exploration/Orders.md"), "# Orders\n\n## Order Flow\n").unwrap();
+
+        let broken = check_links(&output, &project, true).unwrap();
+
+        let summary: Vec<(usize, &str, Option<&str>)> = broken
+            .iter()
+            .map(|b| (b.line, b.target.as_str(), b.replacement.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (5, "Orders.md#order-flow", Some("4.Deep-Exploration/Orders.md#order-flow")),
+                (5, "src/main.rs", Some("../src/main.rs")),
+                (5, "#missing", None),
+            ]
+        );
+        assert_eq!(
+            std::fs::read_to_string(output.join("1.Overview.md")).unwrap(),
+            "# Overview\n\n## Core Modules\n\nSee [modules](#core-modules), [orders](4.Deep-Exploration/Orders.md#order-flow), \
+             [entry](../src/main.rs), gone and [site](https://example.com).\n\n`[code](nowhere.md)`\n"
+        );
+        assert!(check_links(&output, &project, false).unwrap().is_empty());
+    }
+}
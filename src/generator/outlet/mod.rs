@@ -1,63 +1,107 @@
 use crate::generator::compose::types::AgentType;
 use crate::generator::{compose::memory::MemoryScope, context::GeneratorContext};
+use crate::config::Config;
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
 use crate::i18n::TargetLanguage;
+use crate::types::diagram_asset::{ASSETS_DIR, DiagramAsset};
 use anyhow::Result;
-use std::collections::HashMap;
-use std::fs;
+use crate::utils::paths;
+use crate::utils::threads::do_parallel_with_limit;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tracing::{info, warn};
 
 pub mod summary_generator;
 pub mod summary_outlet;
 pub mod fixer;
+pub mod footer;
+pub mod index_outlet;
+pub mod link_checker;
+pub mod navigation;
+pub mod normalizer;
+pub mod run_profile;
+pub mod run_summary;
+pub mod token_report;
+pub mod versions;
 
 pub use summary_outlet::SummaryOutlet;
+pub use index_outlet::IndexOutlet;
+pub use link_checker::LinkCheckOutlet;
 pub use fixer::MermaidFixer;
+pub use run_profile::RunProfile;
+pub use run_summary::{RunFailure, RunStatus, RunSummary};
+pub use token_report::TokenUsageReport;
+pub use versions::OutputVersion;
 
+#[allow(async_fn_in_trait, reason = "outlets are awaited by the crate's own workflow, which needs no `Send` bound")]
 pub trait Outlet {
     async fn save(&self, context: &GeneratorContext) -> Result<()>;
 }
 
+/// Single-file documents and their document type names
+const DOCUMENTS: [(AgentType, &str); 5] = [
+    (AgentType::Overview, "overview"),
+    (AgentType::Architecture, "architecture"),
+    (AgentType::Workflow, "workflow"),
+    (AgentType::Boundary, "boundary"),
+    (AgentType::Database, "database"),
+];
+
 pub struct DocTree {
     /// key is the ScopedKey of Documentation in Memory, value is the relative path for document output
     structure: HashMap<String, String>,
 }
 
 impl DocTree {
-    pub fn new(target_language: &TargetLanguage) -> Self {
-        let structure = HashMap::from([
-            (
-                AgentType::Overview.to_string(),
-                target_language.get_doc_filename("overview"),
-            ),
-            (
-                AgentType::Architecture.to_string(),
-                target_language.get_doc_filename("architecture"),
-            ),
-            (
-                AgentType::Workflow.to_string(),
-                target_language.get_doc_filename("workflow"),
-            ),
-            (
-                AgentType::Boundary.to_string(),
-                target_language.get_doc_filename("boundary"),
-            ),
-            (
-                AgentType::Database.to_string(),
-                target_language.get_doc_filename("database"),
-            ),
-        ]);
+    pub fn new(config: &Config) -> Self {
+        let structure = DOCUMENTS
+            .iter()
+            .map(|(agent_type, doc_type)| (agent_type.to_string(), paths::to_slash(config.doc_filename(doc_type))))
+            .collect();
         Self { structure }
     }
 
+    /// Tree holding only the given single-file document
+    pub fn for_document(config: &Config, doc_type: &str) -> Self {
+        let mut tree = Self::new(config);
+        let keys: Vec<String> = DOCUMENTS
+            .iter()
+            .filter(|(_, name)| *name == doc_type)
+            .map(|(agent_type, _)| agent_type.to_string())
+            .collect();
+        tree.structure.retain(|key, _| keys.contains(key));
+        tree
+    }
+
+    /// Empty tree, filled in by editors that produce several documents
+    pub fn empty() -> Self {
+        Self {
+            structure: HashMap::new(),
+        }
+    }
+
+    /// Keys and paths are stored with forward slashes, whatever the platform or configured names
     pub fn insert(&mut self, scoped_key: &str, relative_path: &str) {
         self.structure
-            .insert(scoped_key.to_string(), relative_path.to_string());
+            .insert(paths::to_slash(scoped_key), paths::to_slash(relative_path));
+    }
+
+    /// Documentation keys and their relative output paths
+    pub fn entries(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.structure.iter()
+    }
+
+    /// Add all entries of another tree, replacing the paths of existing keys
+    pub fn extend(&mut self, other: DocTree) {
+        self.structure.extend(other.structure);
     }
 }
 
 impl Default for DocTree {
     fn default() -> Self {
         // Default to English
-        Self::new(&TargetLanguage::English)
+        Self::new(&Config::default())
     }
 }
 
@@ -71,45 +115,221 @@ impl DiskOutlet {
     }
 }
 
+/// Maximum number of documents written at the same time
+const MAX_CONCURRENT_WRITES: usize = 8;
+
+impl DiskOutlet {
+    /// Write a document through a buffered async writer, creating parent directories as needed
+    async fn write_document(path: PathBuf, content: String) -> Result<PathBuf> {
+        if let Some(parent_dir) = path.parent() {
+            tokio::fs::create_dir_all(parent_dir).await?;
+        }
+        let file = tokio::fs::File::create(&path).await?;
+        let mut writer = BufWriter::new(file);
+        writer.write_all(content.as_bytes()).await?;
+        writer.flush().await?;
+        Ok(path)
+    }
+
+    /// Write a document unless the file already holds the same content, or the content after
+    /// its mermaid diagrams were repaired, apart from the footer; unchanged files keep their
+    /// modification time. Returns whether the file was written.
+    async fn write_if_changed(path: PathBuf, content: String) -> Result<(PathBuf, bool)> {
+        if let Ok(existing) = tokio::fs::read_to_string(&path).await {
+            let existing = footer::strip_footer(&existing);
+            let repaired = fixer::check_markdown(&content).content;
+            if existing == footer::strip_footer(&content) || existing == footer::strip_footer(&repaired) {
+                return Ok((path, false));
+            }
+        }
+        Ok((Self::write_document(path, content).await?, true))
+    }
+
+    /// Delete the files of the output directory that this run does not write, with the
+    /// directories left empty
+    async fn remove_stale_files(output_dir: &Path, keep: &HashSet<PathBuf>) -> Result<()> {
+        let entries: Vec<walkdir::DirEntry> = walkdir::WalkDir::new(output_dir)
+            .min_depth(1)
+            .contents_first(true)
+            .into_iter()
+            .collect::<Result<_, _>>()?;
+        for entry in entries {
+            if entry.file_type().is_dir() {
+                // Fails while the directory still holds kept files
+                let _ = tokio::fs::remove_dir(entry.path()).await;
+            } else if !keep.contains(entry.path()) {
+                tokio::fs::remove_file(entry.path()).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl DiskOutlet {
+    /// Copy the existing diagrams of the repository to the assets directory of the output,
+    /// where the documents reference them; identical copies are left untouched
+    async fn copy_diagrams(context: &GeneratorContext, diagrams: &[DiagramAsset], output_dir: &Path) -> Result<()> {
+        if !context.config.output.reuse_diagrams {
+            return Ok(());
+        }
+        let mut copied = 0;
+        for diagram in diagrams {
+            let source = context.config.project_path.join(&diagram.path);
+            let target = output_dir.join(diagram.output_path());
+            if let (Ok(existing), Ok(new)) = (tokio::fs::read(&target).await, tokio::fs::read(&source).await)
+                && existing == new
+            {
+                copied += 1;
+                continue;
+            }
+            if let Some(parent_dir) = target.parent() {
+                tokio::fs::create_dir_all(parent_dir).await?;
+            }
+            match tokio::fs::copy(&source, &target).await {
+                Ok(_) => copied += 1,
+                Err(e) => {
+                    warn!("⚠️  Failed to copy diagram {}: {}", diagram.path.display(), e);
+                    let item = diagram.path.to_string_lossy();
+                    context.record_failure("output", &item, &e.to_string()).await?;
+                }
+            }
+        }
+        if copied > 0 {
+            info!("🖼️ Copied {} existing diagrams to {}", copied, output_dir.join(ASSETS_DIR).display());
+        }
+        Ok(())
+    }
+
+    /// Normalize the document, link it to related documents and add a table of contents
+    fn prepare_document(
+        &self,
+        config: &Config,
+        scoped_key: &str,
+        relative_path: &str,
+        markdown: String,
+        footer: Option<&str>,
+    ) -> String {
+        let output = &config.output;
+        let mut markdown = if output.normalize_markdown {
+            normalizer::normalize_markdown(&markdown)
+        } else {
+            markdown
+        };
+        if output.cross_links {
+            let related = self.related_documents(config, scoped_key, relative_path);
+            markdown = navigation::link_related_documents(&markdown, &related);
+        }
+        // Documents of additional languages are stored under `<key>@<code>`
+        let language = scoped_key
+            .split_once('@')
+            .and_then(|(_, code)| code.parse::<TargetLanguage>().ok())
+            .unwrap_or_else(|| config.target_language.clone());
+        let markdown = navigation::insert_toc(&markdown, &language.toc_title(), output.toc_min_headings);
+        match footer {
+            Some(footer) => footer::append_footer(&markdown, footer),
+            None => markdown,
+        }
+    }
+
+    /// Module deep-dive documents in the language of the document, by name with their link
+    /// relative to the document
+    fn related_documents(&self, config: &Config, scoped_key: &str, relative_path: &str) -> Vec<(String, String)> {
+        let language_of = |key: &str| key.split_once('@').map(|(_, code)| code.to_string());
+        let deep_exploration = config.directory_name("deep_exploration");
+        let directory = Path::new(relative_path).parent().unwrap_or(Path::new(""));
+        self.doc_tree
+            .structure
+            .iter()
+            .filter(|(key, _)| *key != scoped_key && language_of(key) == language_of(scoped_key))
+            .filter_map(|(_, path)| {
+                let path = Path::new(path);
+                let parent = path.parent()?.file_name()?;
+                (parent.to_string_lossy() == deep_exploration).then(|| {
+                    let name = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+                    (name, link_checker::relative_to(directory, path).replace(' ', "%20"))
+                })
+            })
+            .collect()
+    }
+}
+
 impl Outlet for DiskOutlet {
     async fn save(&self, context: &GeneratorContext) -> Result<()> {
-        println!("\n🖊️ Saving documentation...");
-        // Create output directory
+        info!("🖊️ Saving documentation...");
         let output_dir = &context.config.output_path;
-        if output_dir.exists() {
-            fs::remove_dir_all(output_dir)?;
-        }
-        fs::create_dir_all(output_dir)?;
 
-        // Iterate through document tree structure and save each document
+        let footer = context
+            .config
+            .output
+            .metadata_footer
+            .then(|| footer::FooterInfo::collect(context).render(context.config.output.footer_template.as_deref()));
+
+        // Collect each document of the tree from memory, then write them concurrently
+        let mut saved_documents = Vec::new();
+        let mut documents = Vec::new();
         for (scoped_key, relative_path) in &self.doc_tree.structure {
-            // Get document content from memory
             if let Some(doc_markdown) = context
                 .get_from_memory::<String>(MemoryScope::DOCUMENTATION, scoped_key)
                 .await
             {
-                // Build full output file path
-                let output_file_path = output_dir.join(relative_path);
-
-                // Ensure parent directory exists
-                if let Some(parent_dir) = output_file_path.parent() {
-                    if !parent_dir.exists() {
-                        fs::create_dir_all(parent_dir)?;
-                    }
-                }
-
-                // Write document content to file
-                fs::write(&output_file_path, doc_markdown)?;
-
-                println!("💾 Document saved: {}", output_file_path.display());
+                let doc_markdown = self.prepare_document(
+                    &context.config,
+                    scoped_key,
+                    relative_path,
+                    doc_markdown,
+                    footer.as_deref(),
+                );
+                documents.push((output_dir.join(relative_path), doc_markdown));
+                saved_documents.push(relative_path.clone());
             } else {
                 // If document doesn't exist, log warning but don't interrupt the process
-                let msg = context.config.target_language.msg_doc_not_found();
-                eprintln!("{}", msg.replace("{}", scoped_key));
+                let msg = context.config.ui_language().msg_doc_not_found(scoped_key);
+                warn!("{}", msg);
+                context
+                    .record_failure("output", scoped_key, "document not found in memory")
+                    .await?;
             }
         }
 
-        println!("💾 Document save completed, output directory: {}", output_dir.display());
+        // Files of the previous run are replaced, except unchanged documents and diagrams
+        let diagrams: Vec<DiagramAsset> = context
+            .get_from_memory(PreprocessScope::PREPROCESS, ScopedKeys::DIAGRAM_ASSETS)
+            .await
+            .unwrap_or_default();
+        let mut keep: HashSet<PathBuf> = documents.iter().map(|(path, _)| path.clone()).collect();
+        if context.config.output.reuse_diagrams {
+            keep.extend(diagrams.iter().map(|diagram| output_dir.join(diagram.output_path())));
+        }
+        if tokio::fs::try_exists(output_dir).await? {
+            Self::remove_stale_files(output_dir, &keep).await?;
+        }
+        tokio::fs::create_dir_all(output_dir).await?;
+
+        let writes: Vec<_> = documents
+            .into_iter()
+            .map(|(path, content)| Box::pin(Self::write_if_changed(path, content)))
+            .collect();
+        let mut unchanged = 0;
+        for written in do_parallel_with_limit(writes, MAX_CONCURRENT_WRITES).await {
+            match written? {
+                (path, true) => info!("💾 Document saved: {}", path.display()),
+                (_, false) => unchanged += 1,
+            }
+        }
+        if unchanged > 0 {
+            info!("⏭️  {} unchanged documents kept as they were", unchanged);
+        }
+        saved_documents.sort();
+        Self::copy_diagrams(context, &diagrams, output_dir).await?;
+        context
+            .store_to_memory(
+                run_summary::RunScope::RUN,
+                run_summary::RunKeys::DOCUMENTS,
+                &saved_documents,
+            )
+            .await?;
+
+        info!("💾 Document save completed, output directory: {}", output_dir.display());
 
         // Automatically fix mermaid charts after document save
         MermaidFixer::auto_fix_after_output(context).await?;
@@ -117,3 +337,45 @@ impl Outlet for DiskOutlet {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_write_document_creates_parent_directories() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().to_path_buf();
+        let path = dir.join("4.Deep-Exploration").join("Orders.md");
+
+        let written = DiskOutlet::write_document(path.clone(), "# Orders".to_string()).await.unwrap();
+
+        assert_eq!(written, path);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "# Orders");
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_documents_are_kept() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().to_path_buf();
+        let path = dir.join("overview.md");
+        let stale = dir.join("4.Deep-Exploration").join("Removed.md");
+        let document = |date: &str| format!("# Overview\n\nText.\n\n---\n\n*Generated on {}.*\n", date);
+        std::fs::create_dir_all(stale.parent().unwrap()).unwrap();
+        std::fs::write(&path, document("2025-01-01")).unwrap();
+        std::fs::write(&stale, "# Removed").unwrap();
+
+        DiskOutlet::remove_stale_files(&dir, &HashSet::from([path.clone()])).await.unwrap();
+        assert!(path.exists());
+        assert!(!stale.parent().unwrap().exists());
+
+        let (_, written) = DiskOutlet::write_if_changed(path.clone(), document("2025-02-01")).await.unwrap();
+        assert!(!written);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), document("2025-01-01"));
+        let (_, written) = DiskOutlet::write_if_changed(path.clone(), "# Overview\n\nNew text.\n".to_string())
+            .await
+            .unwrap();
+        assert!(written);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "# Overview\n\nNew text.\n");
+    }
+}
@@ -0,0 +1,179 @@
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Spans of a line that must not receive links: code spans, links, images and autolinks
+static PROTECTED_SPAN_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"`[^`]*`|!?\[[^\]]*\]\([^)]*\)|<[^>\s]+>").unwrap());
+
+/// Heading of a document with its anchor
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heading {
+    pub level: usize,
+    pub title: String,
+    pub anchor: String,
+}
+
+/// ATX headings outside code blocks, with the anchors GitHub generates for them
+pub fn headings(content: &str) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let mut in_code_block = false;
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block || !line.starts_with('#') {
+            continue;
+        }
+        let title = line.trim_start_matches('#');
+        if !title.starts_with(' ') {
+            continue;
+        }
+        let slug = slugify(title);
+        let count = counts.entry(slug.clone()).or_default();
+        let anchor = if *count == 0 { slug.clone() } else { format!("{}-{}", slug, count) };
+        *count += 1;
+        headings.push(Heading {
+            level: line.len() - title.len(),
+            title: title.trim().to_string(),
+            anchor,
+        });
+    }
+    headings
+}
+
+/// GitHub heading anchor: lowercase, punctuation removed, spaces turned into hyphens
+pub fn slugify(heading: &str) -> String {
+    heading
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Insert a table of contents of the level 2 and 3 headings below the title of documents
+/// with at least `min_headings` of them; documents that already link to their own
+/// headings are left as they are
+pub fn insert_toc(markdown: &str, title: &str, min_headings: usize) -> String {
+    let entries: Vec<Heading> = headings(markdown)
+        .into_iter()
+        .filter(|heading| (2..=3).contains(&heading.level))
+        .collect();
+    let has_toc = markdown
+        .lines()
+        .any(|line| line.trim_start().starts_with("- [") && line.contains("](#"));
+    if min_headings == 0 || entries.len() < min_headings || has_toc {
+        return markdown.to_string();
+    }
+
+    let mut toc = format!("**{}**\n\n", title);
+    for heading in &entries {
+        toc.push_str(&format!(
+            "{}- [{}](#{})\n",
+            "  ".repeat(heading.level - 2),
+            heading.title.replace('[', "\\[").replace(']', "\\]"),
+            heading.anchor
+        ));
+    }
+
+    // Below the document title, or at the top of untitled documents
+    let lines: Vec<&str> = markdown.lines().collect();
+    let position = match lines.iter().position(|line| !line.trim().is_empty()) {
+        Some(first) if lines[first].starts_with("# ") => first + 1,
+        _ => 0,
+    };
+    let mut result: Vec<String> = lines[..position].iter().map(|line| line.to_string()).collect();
+    if position > 0 {
+        result.push(String::new());
+    }
+    result.push(toc);
+    let rest = lines[position..].iter().skip_while(|line| line.trim().is_empty());
+    result.extend(rest.map(|line| line.to_string()));
+
+    let mut content = result.join("\n");
+    if markdown.ends_with('\n') {
+        content.push('\n');
+    }
+    content
+}
+
+/// Link the first mention of each related document's name, outside headings, code and
+/// existing links, to the document; `related` holds the names with their relative links
+pub fn link_related_documents(markdown: &str, related: &[(String, String)]) -> String {
+    let mut lines: Vec<String> = markdown.lines().map(str::to_string).collect();
+    // Longer names first, so that "Order Management" wins over "Order"
+    let mut related: Vec<&(String, String)> = related.iter().filter(|(name, _)| !name.is_empty()).collect();
+    related.sort_by_key(|(name, _)| std::cmp::Reverse(name.chars().count()));
+
+    for (name, link) in related {
+        let mut in_code_block = false;
+        for line in lines.iter_mut() {
+            if line.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+                continue;
+            }
+            if in_code_block || line.starts_with('#') {
+                continue;
+            }
+            if let Some(start) = find_mention(line, name) {
+                let end = start + name.len();
+                *line = format!("{}[{}]({}){}", &line[..start], name, link, &line[end..]);
+                break;
+            }
+        }
+    }
+
+    let mut content = lines.join("\n");
+    if markdown.ends_with('\n') {
+        content.push('\n');
+    }
+    content
+}
+
+/// Byte offset of the first mention of the name as a whole word outside protected spans
+fn find_mention(line: &str, name: &str) -> Option<usize> {
+    let protected: Vec<(usize, usize)> =
+        PROTECTED_SPAN_REGEX.find_iter(line).map(|m| (m.start(), m.end())).collect();
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_ascii_alphanumeric() || c == '_');
+    line.match_indices(name).map(|(start, _)| start).find(|&start| {
+        let end = start + name.len();
+        let joins_before = is_word(line[..start].chars().next_back()) && is_word(name.chars().next());
+        let joins_after = is_word(line[end..].chars().next()) && is_word(name.chars().next_back());
+        !joins_before && !joins_after && !protected.iter().any(|(from, to)| start < *to && end > *from)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toc_and_related_links() {
+        let markdown = "# Workflow\n\nIntro\n\n## Checkout\n\nThe `Order Management` step calls Order Management and Payments.\n\n### Retry [v2]\n\n## Checkout\n\n```text\nOrder Management\n```\n";
+
+        let linked = link_related_documents(
+            markdown,
+            &[
+                ("Order Management".to_string(), "4.Deep-Exploration/Order%20Management.md".to_string()),
+                ("Payment".to_string(), "4.Deep-Exploration/Payment.md".to_string()),
+            ],
+        );
+        assert!(linked.contains(
+            "The `Order Management` step calls [Order Management](4.Deep-Exploration/Order%20Management.md) and Payments."
+        ));
+
+        let with_toc = insert_toc(&linked, "Contents", 3);
+        assert!(with_toc.starts_with(
+            "# Workflow\n\n**Contents**\n\n- [Checkout](#checkout)\n  - [Retry \\[v2\\]](#retry-v2)\n- [Checkout](#checkout-1)\n\nIntro\n"
+        ));
+        assert_eq!(insert_toc(&with_toc, "Contents", 3), with_toc);
+        assert_eq!(insert_toc(markdown, "Contents", 4), markdown);
+    }
+}
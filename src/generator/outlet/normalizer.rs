@@ -0,0 +1,246 @@
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Opening lines of models announcing their answer
+static LEADING_CHATTER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(sure|certainly|of course|absolutely|okay|ok|great|here is|here's|here are|below is|below are|the following is|i('ve| have)? (generated|created|written|prepared)|i'll|i will|let me|好的|当然|以下是|下面是)\b").unwrap()
+});
+/// Closing lines of models offering further help; only these sign-offs, a final paragraph
+/// such as "If you need to add a provider, ..." is content
+static TRAILING_CHATTER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r"(?i)^(?:(?:please )?let me know if\b|(?:i )?hope (?:this|that|the above)\b.*\bhelps?\b|",
+        r"feel free to (?:ask|reach out|let me know|contact)\b|",
+        r"if you (?:need|have|want|would like) (?:any )?(?:further|more|additional|other) (?:help|assistance|information|details|questions|changes|clarifications?)\b|",
+        r"is there anything else\b|如果您?还?有(?:任何|其他)?(?:问题|疑问|需要)|如有(?:任何|其他)?(?:问题|疑问)|希望(?:这|以上|本)\S*对您有\S*帮助)"
+    ))
+    .unwrap()
+});
+static FENCE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\s*)(```+|~~~+)\s*([^\s`]*)(.*)$").unwrap());
+static HEADING_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(#{1,6})\s+(.*?)(?:\s+#+)?\s*$").unwrap());
+static TABLE_SEPARATOR_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\|?\s*:?-+:?\s*(\|\s*:?-+:?\s*)*\|?$").unwrap());
+
+/// Longer closing lines are taken for content
+const MAX_SIGN_OFF_CHARS: usize = 200;
+
+const MERMAID_KEYWORDS: [&str; 14] = [
+    "graph", "flowchart", "sequenceDiagram", "classDiagram", "erDiagram", "stateDiagram", "stateDiagram-v2", "gantt",
+    "pie", "journey", "mindmap", "timeline", "gitGraph", "C4Context",
+];
+
+/// Normalize a generated document before it is written: model chatter around the document
+/// and a fence wrapping it are removed, heading levels start at 1 without gaps, code fences
+/// get a language tag and tables a consistent layout
+pub fn normalize_markdown(markdown: &str) -> String {
+    let mut lines: Vec<String> = markdown.lines().map(str::to_string).collect();
+    strip_chatter(&mut lines);
+    unwrap_document_fence(&mut lines);
+
+    let mut normalized = Vec::with_capacity(lines.len());
+    let mut headings: Vec<usize> = Vec::new();
+    let mut fence: Option<String> = None;
+    let mut table: Vec<String> = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        if let Some(marker) = &fence {
+            if line.trim() == marker.as_str() {
+                fence = None;
+            }
+            normalized.push(line.clone());
+            continue;
+        }
+        if line.trim_start().starts_with('|') {
+            table.push(line.clone());
+            continue;
+        }
+        flush_table(&mut table, &mut normalized);
+
+        if let Some(captures) = FENCE_REGEX.captures(line) {
+            fence = Some(captures[2].to_string());
+            let language = match &captures[3] {
+                "" => infer_language(&lines[index + 1..], &captures[2]),
+                tag => canonical_language(tag),
+            };
+            normalized.push(format!("{}{}{}{}", &captures[1], &captures[2], language, &captures[4]));
+        } else if let Some(captures) = HEADING_REGEX.captures(line) {
+            // Levels relative to the enclosing headings, so skipped levels close up
+            let level = captures[1].len();
+            while headings.last().is_some_and(|&enclosing| enclosing >= level) {
+                headings.pop();
+            }
+            headings.push(level);
+            normalized.push(format!("{} {}", "#".repeat(headings.len()), &captures[2]));
+        } else if !(line.trim().is_empty() && normalized.last().is_some_and(|last: &String| last.trim().is_empty())) {
+            normalized.push(line.clone());
+        }
+    }
+    flush_table(&mut table, &mut normalized);
+
+    let mut content = normalized.join("\n");
+    content.push('\n');
+    content
+}
+
+/// Remove the chatter lines before the first heading and after the last line of the document
+fn strip_chatter(lines: &mut Vec<String>) {
+    let leading = lines
+        .iter()
+        .take_while(|line| line.trim().is_empty() || LEADING_CHATTER_REGEX.is_match(line.trim()))
+        .count();
+    // Only chatter introducing the document itself, not its first paragraph
+    let introduces_document = lines
+        .get(leading)
+        .is_some_and(|line| line.starts_with('#') || line.starts_with("```"));
+    if introduces_document {
+        lines.drain(..leading);
+    }
+
+    while let Some(last) = lines.last() {
+        let last = last.trim();
+        if last.is_empty() || (last.chars().count() <= MAX_SIGN_OFF_CHARS && TRAILING_CHATTER_REGEX.is_match(last)) {
+            lines.pop();
+        } else {
+            break;
+        }
+    }
+}
+
+/// Remove a ```markdown fence wrapping the whole document
+fn unwrap_document_fence(lines: &mut Vec<String>) {
+    let wrapped = lines.len() >= 2
+        && matches!(lines[0].trim(), "```markdown" | "```md")
+        && lines.last().is_some_and(|last| last.trim() == "```");
+    if wrapped {
+        lines.pop();
+        lines.remove(0);
+    }
+}
+
+fn flush_table(table: &mut Vec<String>, normalized: &mut Vec<String>) {
+    if table.len() >= 2 && TABLE_SEPARATOR_REGEX.is_match(table[1].trim()) {
+        let columns = split_row(&table[0]).len();
+        for (index, row) in table.iter().enumerate() {
+            let mut cells = split_row(row);
+            if index == 1 {
+                cells = cells.iter().map(|cell| separator_cell(cell)).collect();
+                cells.resize(columns, "---".to_string());
+            } else if cells.len() < columns {
+                cells.resize(columns, String::new());
+            }
+            normalized.push(format!("| {} |", cells.join(" | ")));
+        }
+        table.clear();
+    } else {
+        normalized.append(table);
+    }
+}
+
+/// Cells of a table row; pipes escaped or inside code spans do not split cells
+fn split_row(row: &str) -> Vec<String> {
+    let row = row.trim();
+    let row = row.strip_prefix('|').unwrap_or(row);
+    let row = if row.ends_with('|') && !row.ends_with("\\|") {
+        &row[..row.len() - 1]
+    } else {
+        row
+    };
+
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut in_code = false;
+    let mut escaped = false;
+    for c in row.chars() {
+        match c {
+            '|' if !in_code && !escaped => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            _ => {
+                if c == '`' {
+                    in_code = !in_code;
+                }
+                cell.push(c);
+            }
+        }
+        escaped = c == '\\';
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+fn separator_cell(cell: &str) -> String {
+    match (cell.starts_with(':'), cell.ends_with(':') && cell.len() > 1) {
+        (true, true) => ":---:".to_string(),
+        (true, false) => ":---".to_string(),
+        (false, true) => "---:".to_string(),
+        (false, false) => "---".to_string(),
+    }
+}
+
+/// Language of an untagged code block from its first line
+fn infer_language(following: &[String], marker: &str) -> String {
+    let first = following
+        .iter()
+        .take_while(|line| line.trim() != marker)
+        .map(|line| line.trim())
+        .find(|line| !line.is_empty())
+        .unwrap_or_default();
+    let keyword = first.split_whitespace().next().unwrap_or_default();
+    if MERMAID_KEYWORDS.contains(&keyword) {
+        "mermaid"
+    } else if first.starts_with('{') || first.starts_with('[') {
+        "json"
+    } else {
+        "text"
+    }
+    .to_string()
+}
+
+fn canonical_language(tag: &str) -> String {
+    match tag.to_lowercase().as_str() {
+        "rs" => "rust",
+        "py" => "python",
+        "js" => "javascript",
+        "ts" => "typescript",
+        "sh" | "shell" | "zsh" => "bash",
+        "yml" => "yaml",
+        "md" => "markdown",
+        "mmd" => "mermaid",
+        _ => tag,
+    }
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_markdown() {
+        let markdown = "Sure, here is the architecture document:\n\n```markdown\n## Architecture\n\n#### Layers\n\n\n\nText\n\n|Layer|Role|\n|:--|--:|\n|Web|`a|b`|\n|Core\n\n```\ngraph TD\n    A --> B\n```\n\n~~~rs\nfn main() {}\n~~~\n\n### Notes\n```\n\nLet me know if you need anything else!\n";
+
+        assert_eq!(
+            normalize_markdown(markdown),
+            "# Architecture\n\n## Layers\n\nText\n\n| Layer | Role |\n| :--- | ---: |\n| Web | `a|b` |\n| Core |  |\n\n```mermaid\ngraph TD\n    A --> B\n```\n\n~~~rust\nfn main() {}\n~~~\n\n## Notes\n"
+        );
+    }
+
+    #[test]
+    fn test_keep_content_resembling_chatter() {
+        assert_eq!(normalize_markdown("# Written in C#\n\n## Usage ##\n"), "# Written in C#\n\n## Usage\n");
+
+        let closing = "# Providers\n\nText\n\nIf you need to add a provider, implement the `Provider` trait.\n";
+        assert_eq!(normalize_markdown(closing), closing);
+        let closing = "# Setup\n\nIf you have Docker installed, run `docker compose up`.\n";
+        assert_eq!(normalize_markdown(closing), closing);
+        let closing = "# 部署\n\n希望迁移到新的存储后再启用缓存。\n\n如有需要，可以在 `litho.toml` 中调整并发数。\n";
+        assert_eq!(normalize_markdown(closing), closing);
+
+        for sign_off in [
+            "I hope this helps!",
+            "If you need any further details, just ask.",
+            "Feel free to ask if anything is unclear.",
+            "如有任何问题，请随时告诉我。",
+            "希望这份文档对您有所帮助！",
+        ] {
+            assert_eq!(normalize_markdown(&format!("# Setup\n\nText\n\n{}\n", sign_off)), "# Setup\n\nText\n");
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+
+use crate::generator::context::GeneratorContext;
+use crate::generator::workflow::{TimingKeys, TimingScope};
+use tracing::info;
+
+/// Workflow stages in execution order
+const PHASES: [&str; 4] = [
+    TimingKeys::PREPROCESS,
+    TimingKeys::RESEARCH,
+    TimingKeys::COMPOSE,
+    TimingKeys::OUTPUT,
+];
+
+/// Slowest items of each phase shown in the printed profile
+const PRINTED_ITEMS_PER_PHASE: usize = 5;
+
+/// Duration of one item of a workflow stage (a directory, research agent, editor, ...)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProfileEntry {
+    pub phase: String,
+    pub item: String,
+    pub seconds: f64,
+}
+
+/// Duration of a workflow stage and of its items, slowest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhaseProfile {
+    pub phase: String,
+    /// Wall-clock duration of the stage; items running concurrently may add up to more
+    pub seconds: f64,
+    /// Share of the total execution time
+    pub share: f64,
+    pub items: Vec<ProfileEntry>,
+}
+
+/// Where the time of a run went, written as `run-profile.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunProfile {
+    pub total_seconds: f64,
+    pub phases: Vec<PhaseProfile>,
+}
+
+impl RunProfile {
+    /// Collect the stage durations and item timings recorded in memory
+    pub async fn collect(context: &GeneratorContext) -> Self {
+        let mut stage_durations = BTreeMap::new();
+        for key in PHASES.into_iter().chain([TimingKeys::TOTAL_EXECUTION]) {
+            if let Some(duration) = context.get_from_memory::<f64>(TimingScope::TIMING, key).await {
+                stage_durations.insert(key.to_string(), duration);
+            }
+        }
+        let entries = context
+            .get_from_memory::<Vec<ProfileEntry>>(TimingScope::TIMING, TimingKeys::PROFILE)
+            .await
+            .unwrap_or_default();
+
+        Self::from_timings(&stage_durations, entries)
+    }
+
+    fn from_timings(stage_durations: &BTreeMap<String, f64>, entries: Vec<ProfileEntry>) -> Self {
+        let total_seconds = stage_durations
+            .get(TimingKeys::TOTAL_EXECUTION)
+            .copied()
+            .unwrap_or_else(|| PHASES.iter().filter_map(|phase| stage_durations.get(*phase)).sum());
+
+        let phases = PHASES
+            .iter()
+            .filter_map(|phase| {
+                let mut items: Vec<ProfileEntry> =
+                    entries.iter().filter(|entry| entry.phase == *phase).cloned().collect();
+                items.sort_by(|a, b| b.seconds.total_cmp(&a.seconds));
+                // Stages aborted before their duration was recorded still show their items
+                let seconds = stage_durations
+                    .get(*phase)
+                    .copied()
+                    .or_else(|| (!items.is_empty()).then(|| items.iter().map(|item| item.seconds).sum()))?;
+                Some(PhaseProfile {
+                    phase: phase.to_string(),
+                    seconds,
+                    share: if total_seconds > 0.0 { seconds / total_seconds } else { 0.0 },
+                    items,
+                })
+            })
+            .collect();
+
+        Self { total_seconds, phases }
+    }
+
+    /// Print the profile and write it as `run-profile.json` into the output directory
+    pub fn save(&self, context: &GeneratorContext) -> Result<()> {
+        for line in self.to_string().lines() {
+            info!("{}", line);
+        }
+
+        let output_dir = &context.config.output_path;
+        if !output_dir.exists() {
+            fs::create_dir_all(output_dir)?;
+        }
+        let path = output_dir.join("run-profile.json");
+        fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        info!("💾 Saved run profile: {}", path.display());
+
+        Ok(())
+    }
+}
+
+impl Display for RunProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "⏱️  Run profile (total {:.1}s):", self.total_seconds)?;
+        for phase in &self.phases {
+            writeln!(
+                f,
+                "   {:<12} {:>9.1}s {:>5.1}%",
+                phase.phase,
+                phase.seconds,
+                phase.share * 100.0
+            )?;
+            for item in phase.items.iter().take(PRINTED_ITEMS_PER_PHASE) {
+                writeln!(f, "      {:<40} {:>9.1}s", item.item, item.seconds)?;
+            }
+            if phase.items.len() > PRINTED_ITEMS_PER_PHASE {
+                writeln!(f, "      … {} more", phase.items.len() - PRINTED_ITEMS_PER_PHASE)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(phase: &str, item: &str, seconds: f64) -> ProfileEntry {
+        ProfileEntry {
+            phase: phase.to_string(),
+            item: item.to_string(),
+            seconds,
+        }
+    }
+
+    #[test]
+    fn test_profile_from_timings() {
+        let stage_durations = BTreeMap::from([
+            (TimingKeys::PREPROCESS.to_string(), 60.0),
+            (TimingKeys::RESEARCH.to_string(), 120.0),
+            (TimingKeys::TOTAL_EXECUTION.to_string(), 200.0),
+        ]);
+        let entries = vec![
+            entry(TimingKeys::RESEARCH, "Workflow Research", 40.0),
+            entry(TimingKeys::RESEARCH, "System Context Research", 80.0),
+            entry(TimingKeys::PREPROCESS, "structure extraction", 2.0),
+            entry(TimingKeys::COMPOSE, "overview", 15.0),
+        ];
+
+        let profile = RunProfile::from_timings(&stage_durations, entries);
+
+        let phases: Vec<_> = profile.phases.iter().map(|p| (p.phase.as_str(), p.seconds)).collect();
+        assert_eq!(phases, vec![("preprocess", 60.0), ("research", 120.0), ("compose", 15.0)]);
+        assert_eq!(profile.phases[1].share, 0.6);
+        assert_eq!(profile.phases[1].items[0].item, "System Context Research");
+        assert!(profile.to_string().contains("research"));
+    }
+}
@@ -0,0 +1,295 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::process::ExitCode;
+
+use crate::config::CiConfig;
+use crate::generator::context::GeneratorContext;
+use crate::generator::workflow::{TimingKeys, TimingScope};
+use tracing::info;
+
+/// Memory scope and key definitions for run bookkeeping
+pub struct RunScope;
+
+impl RunScope {
+    /// Memory scope for run bookkeeping
+    pub const RUN: &'static str = "run";
+}
+
+/// Memory key definitions for run bookkeeping
+pub struct RunKeys;
+
+impl RunKeys {
+    /// Relative paths of the documents written to the output directory
+    pub const DOCUMENTS: &'static str = "documents";
+    /// Non-fatal failures encountered during the run
+    pub const FAILURES: &'static str = "failures";
+    /// Discrepancies between the generated documents
+    pub const CONSISTENCY_ISSUES: &'static str = "consistency_issues";
+    /// Research agents re-run for reporting a low confidence score
+    pub const CONFIDENCE_RETRIES: &'static str = "confidence_retries";
+    /// Agents re-run with the powerful model for invalid or suspiciously short output
+    pub const ESCALATIONS: &'static str = "escalations";
+}
+
+/// A non-fatal failure, the run continued with degraded output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunFailure {
+    /// Workflow stage the failure happened in
+    pub stage: String,
+    /// Item that failed (directory, domain, document, ...)
+    pub item: String,
+    /// Error description
+    pub error: String,
+}
+
+/// Discrepancy between the generated documents; regenerating the document usually resolves it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsistencyIssue {
+    /// Document the discrepancy was found in
+    pub document: String,
+    pub issue: String,
+}
+
+/// Re-runs of a research agent whose report scored below `[confidence] min_score`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfidenceRetry {
+    pub agent: String,
+    /// Confidence reported by the first run
+    pub initial_score: f64,
+    /// Confidence of the report kept
+    pub final_score: f64,
+    pub attempts: usize,
+    /// Whether a re-run reached the minimum score
+    pub met_threshold: bool,
+}
+
+/// Re-run of an agent with the powerful model and expanded context after a poor output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Escalation {
+    pub agent: String,
+    /// What was wrong with the first output
+    pub reason: String,
+    /// Whether the re-run produced a usable output
+    pub succeeded: bool,
+}
+
+/// Overall result of a documentation run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStatus {
+    /// All documents were generated without failures
+    Success,
+    /// Documents were generated, but some steps failed and were skipped or degraded
+    PartialFailure,
+    /// The run was aborted
+    Failure,
+}
+
+impl RunStatus {
+    /// Process exit code: 0 success, 1 total failure, 2 partial failure; in CI mode failures
+    /// within `[ci] max_failures` do not fail the pipeline
+    pub fn exit_code(&self, ci: bool) -> ExitCode {
+        match self {
+            RunStatus::Success => ExitCode::SUCCESS,
+            RunStatus::Failure => ExitCode::from(1),
+            RunStatus::PartialFailure if ci => ExitCode::SUCCESS,
+            RunStatus::PartialFailure => ExitCode::from(2),
+        }
+    }
+}
+
+/// Status of a run from its error, failed steps and model fallbacks; in CI mode more failures
+/// and fallbacks than `[ci] max_failures` fail the run
+fn run_status(error: Option<String>, failures: usize, fallbacks: usize, ci: &CiConfig) -> (RunStatus, Option<String>) {
+    let error = match error {
+        None if ci.enabled && failures + fallbacks > ci.max_failures => Some(format!(
+            "{} failed steps and {} model fallbacks exceed the {} allowed by [ci] max_failures",
+            failures, fallbacks, ci.max_failures
+        )),
+        error => error,
+    };
+    let status = if error.is_some() {
+        RunStatus::Failure
+    } else if failures == 0 {
+        RunStatus::Success
+    } else {
+        RunStatus::PartialFailure
+    };
+    (status, error)
+}
+
+/// LLM token totals of the run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RunTokenUsage {
+    pub llm_calls: usize,
+    pub input_tokens: usize,
+    pub output_tokens: usize,
+    pub total_tokens: usize,
+    pub cache_hits: usize,
+    pub cache_misses: usize,
+    pub input_tokens_saved: usize,
+    pub output_tokens_saved: usize,
+}
+
+/// Machine-readable summary written as `run-summary.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunSummary {
+    pub status: RunStatus,
+    pub project_name: String,
+    pub target_language: String,
+    pub finished_at: String,
+    pub documents: Vec<String>,
+    pub failures: Vec<RunFailure>,
+    #[serde(default)]
+    pub consistency_issues: Vec<ConsistencyIssue>,
+    #[serde(default)]
+    pub confidence_retries: Vec<ConfidenceRetry>,
+    #[serde(default)]
+    pub escalations: Vec<Escalation>,
+    /// LLM calls that fell back to another model or to summary reasoning
+    #[serde(default)]
+    pub fallbacks: usize,
+    pub token_usage: RunTokenUsage,
+    /// Stage durations in seconds
+    pub durations: BTreeMap<String, f64>,
+    /// Error that aborted the run
+    pub error: Option<String>,
+}
+
+impl RunSummary {
+    /// Collect the summary from memory, the LLM client and the cache monitor
+    pub async fn collect(context: &GeneratorContext, error: Option<String>) -> Self {
+        let documents = context
+            .get_from_memory::<Vec<String>>(RunScope::RUN, RunKeys::DOCUMENTS)
+            .await
+            .unwrap_or_default();
+        let failures = context
+            .get_from_memory::<Vec<RunFailure>>(RunScope::RUN, RunKeys::FAILURES)
+            .await
+            .unwrap_or_default();
+        let consistency_issues = context
+            .get_from_memory::<Vec<ConsistencyIssue>>(RunScope::RUN, RunKeys::CONSISTENCY_ISSUES)
+            .await
+            .unwrap_or_default();
+        let confidence_retries = context
+            .get_from_memory::<Vec<ConfidenceRetry>>(RunScope::RUN, RunKeys::CONFIDENCE_RETRIES)
+            .await
+            .unwrap_or_default();
+        let escalations = context
+            .get_from_memory::<Vec<Escalation>>(RunScope::RUN, RunKeys::ESCALATIONS)
+            .await
+            .unwrap_or_default();
+
+        let fallbacks = context.llm_client.fallback_count();
+        let (status, error) = run_status(error, failures.len(), fallbacks, &context.config.ci);
+
+        let (llm_calls, usage) = context.llm_client.token_usage();
+        let cache_report = context.cache_manager.read().await.generate_performance_report();
+        let token_usage = RunTokenUsage {
+            llm_calls,
+            input_tokens: usage.input_tokens,
+            output_tokens: usage.output_tokens,
+            total_tokens: usage.total_tokens,
+            cache_hits: cache_report.cache_hits,
+            cache_misses: cache_report.cache_misses,
+            input_tokens_saved: cache_report.input_tokens_saved,
+            output_tokens_saved: cache_report.output_tokens_saved,
+        };
+
+        let mut durations = BTreeMap::new();
+        for key in [
+            TimingKeys::PREPROCESS,
+            TimingKeys::RESEARCH,
+            TimingKeys::COMPOSE,
+            TimingKeys::OUTPUT,
+            TimingKeys::TOTAL_EXECUTION,
+        ] {
+            if let Some(duration) = context
+                .get_from_memory::<f64>(TimingScope::TIMING, key)
+                .await
+            {
+                durations.insert(key.to_string(), duration);
+            }
+        }
+
+        Self {
+            status,
+            project_name: context.config.get_project_name(),
+            target_language: context.config.target_language.to_string(),
+            finished_at: chrono::Utc::now().to_rfc3339(),
+            documents,
+            failures,
+            consistency_issues,
+            confidence_retries,
+            escalations,
+            fallbacks,
+            token_usage,
+            durations,
+            error,
+        }
+    }
+
+    /// Write the summary as `run-summary.json` into the output directory
+    pub fn save(&self, context: &GeneratorContext) -> Result<()> {
+        let output_dir = &context.config.output_path;
+        if !output_dir.exists() {
+            fs::create_dir_all(output_dir)?;
+        }
+
+        let path = output_dir.join("run-summary.json");
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(&path, &json)?;
+        info!("💾 Saved run summary: {}", path.display());
+        // CI pipelines read the summary from stdout, which carries no log lines in CI mode
+        if context.config.ci.enabled {
+            println!("{}", json);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_status_serialization() {
+        assert_eq!(
+            serde_json::to_string(&RunStatus::PartialFailure).unwrap(),
+            "\"partial_failure\""
+        );
+        assert_eq!(RunStatus::Success.exit_code(false), ExitCode::SUCCESS);
+        assert_eq!(RunStatus::PartialFailure.exit_code(false), ExitCode::from(2));
+    }
+
+    #[test]
+    fn test_ci_failure_threshold() {
+        let ci = CiConfig {
+            enabled: true,
+            max_failures: 1,
+        };
+        // Within the threshold: partial failure, which passes the pipeline
+        let (status, error) = run_status(None, 1, 0, &ci);
+        assert_eq!((status, error), (RunStatus::PartialFailure, None));
+        assert_eq!(status.exit_code(true), ExitCode::SUCCESS);
+
+        // Failed steps and fallbacks together exceed it
+        let (status, error) = run_status(None, 1, 1, &ci);
+        assert_eq!(status, RunStatus::Failure);
+        assert!(error.unwrap().contains("1 failed steps and 1 model fallbacks"));
+        assert_eq!(status.exit_code(true), ExitCode::from(1));
+
+        let (status, _) = run_status(None, 0, 2, &ci);
+        assert_eq!(status.exit_code(true), ExitCode::from(1));
+        assert_eq!(run_status(None, 0, 1, &ci).0, RunStatus::Success);
+
+        // Without CI mode, failures only make the run partial
+        let (status, error) = run_status(None, 3, 2, &CiConfig::default());
+        assert_eq!((status, error), (RunStatus::PartialFailure, None));
+        assert_eq!(status.exit_code(false), ExitCode::from(2));
+        assert_eq!(run_status(Some("aborted".to_string()), 0, 0, &ci).0, RunStatus::Failure);
+    }
+}
@@ -4,6 +4,7 @@ use std::fs;
 use super::Outlet;
 use super::summary_generator::{SummaryContentGenerator, SummaryDataCollector, SummaryMode};
 use crate::generator::context::GeneratorContext;
+use tracing::info;
 
 /// Summary outlet - responsible for generating and saving summary reports
 pub struct SummaryOutlet {
@@ -33,7 +34,7 @@ impl Outlet for SummaryOutlet {
             fs::create_dir_all(output_dir)?;
         }
 
-        println!("\n🖊️ Generating project summary report...");
+        info!("🖊️ Generating project summary report...");
 
         // Collect data (only needs to be collected once)
         let summary_data = SummaryDataCollector::collect_data(context).await?;
@@ -43,7 +44,7 @@ impl Outlet for SummaryOutlet {
             SummaryContentGenerator::generate_content(&summary_data, SummaryMode::Full);
         let full_path = output_dir.join(&self.full_file_path);
         fs::write(&full_path, full_content)?;
-        println!("💾 Saved full version summary report: {}", full_path.display());
+        info!("💾 Saved full version summary report: {}", full_path.display());
 
         // If brief version needs to be generated
         if self.generate_both {
@@ -51,7 +52,7 @@ impl Outlet for SummaryOutlet {
                 SummaryContentGenerator::generate_content(&summary_data, SummaryMode::Brief);
             let brief_path = output_dir.join(&self.brief_file_path);
             fs::write(&brief_path, brief_content)?;
-            println!("💾 Saved brief version summary report: {}", brief_path.display());
+            info!("💾 Saved brief version summary report: {}", brief_path.display());
         }
 
         Ok(())
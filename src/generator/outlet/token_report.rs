@@ -0,0 +1,180 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+
+use crate::generator::context::GeneratorContext;
+use crate::llm::client::types::{TokenUsage, TokenUsageRecord};
+use tracing::info;
+
+/// Token usage of a group of calls (an agent, a model or a phase)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TokenUsageTotal {
+    pub name: String,
+    pub calls: usize,
+    pub input_tokens: usize,
+    pub output_tokens: usize,
+    pub total_tokens: usize,
+    pub estimated_cost: f64,
+}
+
+/// LLM spend of a run broken down per agent, model and phase, written as
+/// `token-usage.json` and `token-usage.csv` for chargeback and monitoring
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenUsageReport {
+    pub project_name: String,
+    pub finished_at: String,
+    pub total: TokenUsageTotal,
+    pub by_agent: Vec<TokenUsageTotal>,
+    pub by_model: Vec<TokenUsageTotal>,
+    pub by_phase: Vec<TokenUsageTotal>,
+    /// Usage per (agent, model, phase), the rows of the CSV export
+    pub records: Vec<TokenUsageRecord>,
+}
+
+impl TokenUsageReport {
+    /// Collect the usage recorded by the LLM client
+    pub fn collect(context: &GeneratorContext) -> Self {
+        Self::from_records(
+            context.config.get_project_name(),
+            context.llm_client.token_usage_records(),
+        )
+    }
+
+    fn from_records(project_name: String, records: Vec<TokenUsageRecord>) -> Self {
+        Self {
+            project_name,
+            finished_at: chrono::Utc::now().to_rfc3339(),
+            total: totals(&records, |_| "total")
+                .pop()
+                .unwrap_or_else(|| total("total", 0, &TokenUsage::new(0, 0), 0.0)),
+            by_agent: totals(&records, |record| &record.agent),
+            by_model: totals(&records, |record| &record.model),
+            by_phase: totals(&records, |record| &record.phase),
+            records,
+        }
+    }
+
+    /// One row per agent, model and phase
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("agent,model,phase,calls,input_tokens,output_tokens,total_tokens,estimated_cost\n");
+        for record in &self.records {
+            let cost = TokenUsage::new(record.input_tokens, record.output_tokens).estimate_cost(&record.model);
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{:.6}\n",
+                csv_field(&record.agent),
+                csv_field(&record.model),
+                csv_field(&record.phase),
+                record.calls,
+                record.input_tokens,
+                record.output_tokens,
+                record.total_tokens,
+                cost
+            ));
+        }
+        csv
+    }
+
+    /// Write the report as `token-usage.json` and `token-usage.csv` into the output directory
+    pub fn save(&self, context: &GeneratorContext) -> Result<()> {
+        let output_dir = &context.config.output_path;
+        if !output_dir.exists() {
+            fs::create_dir_all(output_dir)?;
+        }
+
+        fs::write(output_dir.join("token-usage.json"), serde_json::to_string_pretty(self)?)?;
+        fs::write(output_dir.join("token-usage.csv"), self.to_csv())?;
+        info!(
+            "💾 Saved token usage ({} tokens in {} calls): {}",
+            self.total.total_tokens,
+            self.total.calls,
+            output_dir.join("token-usage.{json,csv}").display()
+        );
+
+        Ok(())
+    }
+}
+
+/// Usage summed per group, largest first
+fn totals<'a>(records: &'a [TokenUsageRecord], group: impl Fn(&'a TokenUsageRecord) -> &'a str) -> Vec<TokenUsageTotal> {
+    let mut groups: BTreeMap<&str, (usize, TokenUsage, f64)> = BTreeMap::new();
+    for record in records {
+        let usage = TokenUsage::new(record.input_tokens, record.output_tokens);
+        let (calls, sum, cost) = groups
+            .entry(group(record))
+            .or_insert_with(|| (0, TokenUsage::new(0, 0), 0.0));
+        *calls += record.calls;
+        *cost += usage.estimate_cost(&record.model);
+        *sum = TokenUsage::new(sum.input_tokens + usage.input_tokens, sum.output_tokens + usage.output_tokens);
+    }
+
+    let mut totals: Vec<TokenUsageTotal> = groups
+        .into_iter()
+        .map(|(name, (calls, usage, cost))| total(name, calls, &usage, cost))
+        .collect();
+    totals.sort_by_key(|total| std::cmp::Reverse(total.total_tokens));
+    totals
+}
+
+fn total(name: &str, calls: usize, usage: &TokenUsage, estimated_cost: f64) -> TokenUsageTotal {
+    TokenUsageTotal {
+        name: name.to_string(),
+        calls,
+        input_tokens: usage.input_tokens,
+        output_tokens: usage.output_tokens,
+        total_tokens: usage.total_tokens,
+        estimated_cost,
+    }
+}
+
+/// Quote fields containing separators, quotes or line breaks
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(agent: &str, model: &str, phase: &str, calls: usize, input: usize, output: usize) -> TokenUsageRecord {
+        TokenUsageRecord {
+            agent: agent.to_string(),
+            model: model.to_string(),
+            phase: phase.to_string(),
+            calls,
+            input_tokens: input,
+            output_tokens: output,
+            total_tokens: input + output,
+        }
+    }
+
+    #[test]
+    fn test_report_breakdown_and_csv() {
+        let report = TokenUsageReport::from_records(
+            "shop".to_string(),
+            vec![
+                record("directory_summary", "small", "preprocess", 10, 5000, 1000),
+                record("studies_research/KeyModulesInsight", "large", "research", 3, 20000, 4000),
+                record("studies_research/KeyModulesInsight", "small", "research", 1, 1000, 200),
+                record("documentation/Overview, Editor", "small", "compose", 1, 3000, 900),
+            ],
+        );
+
+        assert_eq!((report.total.calls, report.total.total_tokens), (15, 35100));
+        assert_eq!(report.by_agent[0].name, "studies_research/KeyModulesInsight");
+        assert_eq!((report.by_agent[0].calls, report.by_agent[0].total_tokens), (4, 25200));
+        assert_eq!(
+            report.by_model.iter().map(|total| (total.name.as_str(), total.calls)).collect::<Vec<_>>(),
+            [("large", 3), ("small", 12)]
+        );
+        assert_eq!(report.by_phase.iter().map(|total| total.name.as_str()).collect::<Vec<_>>(), ["research", "preprocess", "compose"]);
+
+        let csv = report.to_csv();
+        assert!(csv.starts_with("agent,model,phase,calls,input_tokens,output_tokens,total_tokens,estimated_cost\n"));
+        assert!(csv.contains("\n\"documentation/Overview, Editor\",small,compose,1,3000,900,3900,0.002550\n"));
+    }
+}
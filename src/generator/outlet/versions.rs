@@ -0,0 +1,248 @@
+//! Versioned output: each run is written to `<output_path>/<version>/`, and `versions.json`
+//! plus a root `index.md` list the versions, newest first
+
+use anyhow::{Result, bail};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use tracing::{info, warn};
+
+use crate::config::Config;
+use crate::generator::outlet::RunStatus;
+use crate::utils::git_worktree;
+
+pub const VERSIONS_FILE: &str = "versions.json";
+const INDEX_FILE: &str = "index.md";
+
+/// One documentation version in `versions.json`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DocVersion {
+    /// Directory of the version, relative to the output root
+    pub label: String,
+    pub created_at: String,
+    /// Short hash of the documented commit
+    pub commit: Option<String>,
+    pub status: RunStatus,
+}
+
+/// Documentation version of this run
+pub struct OutputVersion {
+    pub label: String,
+    pub commit: Option<String>,
+    /// Output directory holding all versions
+    pub root: std::path::PathBuf,
+}
+
+impl OutputVersion {
+    /// Version of the run when versioning is enabled; the commit is that of `git_ref`, or the
+    /// checked-out commit
+    pub fn for_run(config: &Config) -> Result<Option<Self>> {
+        let versioning = &config.versioning;
+        if !versioning.enabled {
+            return Ok(None);
+        }
+        let revision = config.git_ref.as_deref().unwrap_or("HEAD");
+        let commit = git_worktree::short_commit(&config.project_path, revision);
+        let label = match &versioning.label {
+            Some(label) => {
+                if !is_valid_label(label) {
+                    bail!("Invalid version label `{}`: it must be a plain directory name", label);
+                }
+                label.clone()
+            }
+            None => {
+                let now = Local::now();
+                match &commit {
+                    Some(commit) => format!("v{}-{}", now.format("%Y%m%d"), commit),
+                    None => format!("v{}", now.format("%Y%m%d-%H%M%S")),
+                }
+            }
+        };
+        Ok(Some(Self {
+            label,
+            commit,
+            root: config.output_path.clone(),
+        }))
+    }
+
+    /// Configuration writing the documentation to the version directory
+    pub fn apply(&self, config: &Config) -> Config {
+        let mut config = config.clone();
+        config.output_path = self.root.join(&self.label);
+        config
+    }
+
+    /// Add the version to `versions.json`, replacing an earlier run with the same label, delete
+    /// the versions beyond `keep` and rewrite the root index
+    pub fn record(&self, config: &Config, status: RunStatus) -> Result<()> {
+        let mut versions = load_versions(&self.root);
+        versions.retain(|version| version.label != self.label);
+        versions.insert(
+            0,
+            DocVersion {
+                label: self.label.clone(),
+                created_at: Local::now().to_rfc3339(),
+                commit: self.commit.clone(),
+                status,
+            },
+        );
+        let keep = config.versioning.keep;
+        if keep > 0 && versions.len() > keep {
+            for removed in versions.split_off(keep) {
+                if !is_valid_label(&removed.label) {
+                    continue;
+                }
+                let dir = self.root.join(&removed.label);
+                match fs::remove_dir_all(&dir) {
+                    Ok(()) => info!("🗑️  Removed documentation version {}", removed.label),
+                    Err(e) => warn!("⚠️  Failed to remove documentation version {}: {}", dir.display(), e),
+                }
+            }
+        }
+
+        fs::write(self.root.join(VERSIONS_FILE), serde_json::to_string_pretty(&versions)?)?;
+        let overview = config.target_language.get_doc_filename("overview");
+        fs::write(self.root.join(INDEX_FILE), render_index(&self.root, &versions, &overview))?;
+        info!("🔖 Recorded documentation version {} ({} versions)", self.label, versions.len());
+        Ok(())
+    }
+}
+
+/// Versions listed in `versions.json` of the output root, newest first. Entries whose label is
+/// not a plain directory name are dropped, so they are never deleted or linked.
+pub fn load_versions(root: &Path) -> Vec<DocVersion> {
+    let mut versions: Vec<DocVersion> = fs::read_to_string(root.join(VERSIONS_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+    versions.retain(|version| {
+        let valid = is_valid_label(&version.label);
+        if !valid {
+            warn!("⚠️  Ignoring documentation version with invalid label `{}` in {}", version.label, VERSIONS_FILE);
+        }
+        valid
+    });
+    versions
+}
+
+/// Whether a version label is a plain directory name inside the output root that does not
+/// collide with the files written next to the versions
+fn is_valid_label(label: &str) -> bool {
+    !label.is_empty()
+        && !label.starts_with('.')
+        && !label.contains(['/', '\\', ':'])
+        && label != VERSIONS_FILE
+        && label != INDEX_FILE
+}
+
+/// Root index linking the latest version that did not fail, then the others
+fn render_index(root: &Path, versions: &[DocVersion], overview: &str) -> String {
+    let link = |version: &DocVersion| {
+        let target = if root.join(&version.label).join(overview).is_file() {
+            format!("{}/{}", version.label, overview)
+        } else {
+            format!("{}/", version.label)
+        };
+        let created_at = DateTime::parse_from_rfc3339(&version.created_at)
+            .map(|date| date.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|_| version.created_at.clone());
+        let mut details = vec![created_at];
+        if let Some(commit) = &version.commit {
+            details.push(format!("commit `{}`", commit));
+        }
+        match version.status {
+            RunStatus::Success => {}
+            RunStatus::PartialFailure => details.push("partial failure".to_string()),
+            RunStatus::Failure => details.push("failed".to_string()),
+        }
+        format!("[{}]({}) ({})", version.label, target, details.join(", "))
+    };
+
+    let mut md = String::from("# Documentation Versions\n\n");
+    let latest = versions
+        .iter()
+        .position(|version| version.status != RunStatus::Failure)
+        .unwrap_or(0);
+    match versions.get(latest) {
+        Some(version) => md.push_str(&format!("Latest: {}\n", link(version))),
+        None => md.push_str("No versions yet.\n"),
+    }
+    let previous: Vec<&DocVersion> = versions
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index != latest)
+        .map(|(_, version)| version)
+        .collect();
+    if !previous.is_empty() {
+        md.push_str("\n## Other Versions\n\n");
+        for version in previous {
+            md.push_str(&format!("- {}\n", link(version)));
+        }
+    }
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_versions() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().join("docs");
+        let mut config = Config {
+            project_path: root.join("no-such-project"),
+            output_path: root.clone(),
+            ..Default::default()
+        };
+        config.versioning.enabled = true;
+        config.versioning.keep = 2;
+        let overview = config.target_language.get_doc_filename("overview");
+
+        let mut record = |label: &str, status: RunStatus| {
+            config.versioning.label = Some(label.to_string());
+            let version = OutputVersion::for_run(&config).unwrap().unwrap();
+            let versioned = version.apply(&config);
+            assert_eq!(versioned.output_path, root.join(label));
+            fs::create_dir_all(&versioned.output_path).unwrap();
+            fs::write(versioned.output_path.join(&overview), "# Overview\n").unwrap();
+            version.record(&config, status).unwrap();
+        };
+        record("1.0", RunStatus::Success);
+        record("1.1", RunStatus::PartialFailure);
+        record("1.2", RunStatus::Failure);
+
+        let labels: Vec<String> = load_versions(&root).into_iter().map(|version| version.label).collect();
+        assert_eq!(labels, ["1.2", "1.1"]);
+        assert!(!root.join("1.0").exists());
+        let index = fs::read_to_string(root.join(INDEX_FILE)).unwrap();
+        assert!(index.contains(&format!("Latest: [1.1](1.1/{}) (", overview)));
+        assert!(index.contains("partial failure)"));
+        assert!(index.contains(&format!("- [1.2](1.2/{}) (", overview)));
+
+        // Hand-edited entries outside the output root are ignored, never deleted
+        let outside = temp.path().join("outside");
+        fs::create_dir_all(&outside).unwrap();
+        let mut versions = load_versions(&root);
+        for label in ["..", "/abs", outside.to_str().unwrap(), "../1.2"] {
+            versions.push(DocVersion {
+                label: label.to_string(),
+                created_at: String::new(),
+                commit: None,
+                status: RunStatus::Success,
+            });
+        }
+        fs::write(root.join(VERSIONS_FILE), serde_json::to_string(&versions).unwrap()).unwrap();
+        assert_eq!(load_versions(&root).len(), 2);
+        record("1.3", RunStatus::Success);
+        assert!(outside.is_dir());
+
+        for label in ["../outside", "/abs", "", INDEX_FILE, VERSIONS_FILE] {
+            config.versioning.label = Some(label.to_string());
+            assert!(OutputVersion::for_run(&config).is_err(), "{label}");
+        }
+        config.versioning.label = None;
+        assert!(OutputVersion::for_run(&config).unwrap().unwrap().label.starts_with('v'));
+    }
+}
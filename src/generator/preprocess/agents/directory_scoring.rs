@@ -7,6 +7,7 @@ use std::path::PathBuf;
 use crate::generator::agent_executor::{AgentExecuteParams, extract};
 use crate::generator::context::GeneratorContext;
 use crate::types::DirectoryInfo;
+use tracing::warn;
 
 /// LLM directory scoring result — path-keyed to avoid index mismatch
 #[derive(Debug, Serialize, Deserialize, Clone, Default, JsonSchema)]
@@ -126,7 +127,7 @@ impl DirectoryScorer {
             }
         }
         if missing > 0 {
-            eprintln!(
+            warn!(
                 "⚠️  Warning: {} directories had no matching LLM score (will use 0.0)",
                 missing
             );
@@ -183,3 +184,9 @@ IMPORTANT: Output valid JSON only, no markdown fences."#,
         )
     }
 }
+
+impl Default for DirectoryScorer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -6,6 +6,7 @@ use std::path::PathBuf;
 use crate::generator::agent_executor::{AgentExecuteParams, extract};
 use crate::generator::context::GeneratorContext;
 use crate::generator::preprocess::extractors::language_processors::LanguageProcessorManager;
+use crate::types::code::{CodeComplexity, CodePurpose};
 use crate::types::{DirectoryDossier, DirectoryPurpose};
 
 /// Per-file insight from LLM
@@ -171,6 +172,36 @@ impl DirectorySummarizer {
         }
     }
 
+    /// Complexity of a summarized file; files the LLM named but were not sent get empty metrics
+    fn file_complexity(&self, file: Option<&FileContent>) -> CodeComplexity {
+        file.map(|f| self.language_processor.calculate_complexity_metrics(&f.path, &f.content))
+            .unwrap_or_default()
+    }
+
+    /// Purpose of a summarized file; files declaring HTTP routes or message listeners stay API
+    /// boundaries, and routed pages stay pages, whatever purpose the LLM picked, so the boundary
+    /// analysis sees them
+    fn file_purpose(&self, file: Option<&FileContent>, purpose: Option<CodePurpose>) -> CodePurpose {
+        let purpose = purpose.unwrap_or(CodePurpose::Other);
+        if matches!(
+            purpose,
+            CodePurpose::Entry | CodePurpose::Api | CodePurpose::Router | CodePurpose::Controller | CodePurpose::Page
+        ) {
+            return purpose;
+        }
+        let interfaces = file
+            .map(|f| self.language_processor.extract_interfaces(&f.path, &f.content))
+            .unwrap_or_default();
+        let declares = |types: &[&str]| interfaces.iter().any(|interface| types.contains(&interface.interface_type.as_str()));
+        if declares(&["route", "listener"]) {
+            CodePurpose::Api
+        } else if declares(&["page_route"]) {
+            CodePurpose::Page
+        } else {
+            purpose
+        }
+    }
+
     /// Generate a DirectoryDossier for a single directory using LLM.
     /// Files should be batched by caller: if total content exceeds 256KB, summarize
     /// in batches and merge results.
@@ -215,16 +246,14 @@ impl DirectorySummarizer {
                 .into_iter()
                 .map(|fi| {
                     let name = fi.name.clone();
-                    let path = files
-                        .iter()
-                        .find(|f| f.name == name)
-                        .map(|f| f.path.clone())
-                        .unwrap_or_default();
+                    let file = files.iter().find(|f| f.name == name);
+                    let path = file.map(|f| f.path.clone()).unwrap_or_default();
+                    let complexity = self.file_complexity(file);
                     crate::types::FileInsight {
                         name,
                         file_path: path,
                         summary: fi.summary.clone(),
-                        code_purpose: fi.code_purpose.unwrap_or(crate::types::code::CodePurpose::Other),
+                        code_purpose: self.file_purpose(file, fi.code_purpose),
                         source_summary: fi.source_summary.clone(),
                         detailed_description: fi.detailed_description.clone(),
                         responsibilities: fi.responsibilities.clone(),
@@ -250,6 +279,7 @@ impl DirectorySummarizer {
                             version: None,
                         }).collect(),
                         importance_score: fi.importance_score,
+                        complexity,
                     }
                 })
                 .collect(),
@@ -296,16 +326,14 @@ impl DirectorySummarizer {
 
             // Collect per-file insights
             for fi in response.file_insights {
-                let file_path = batch
-                    .iter()
-                    .find(|f| f.name == fi.name)
-                    .map(|f| f.path.clone())
-                    .unwrap_or_default();
+                let file = batch.iter().find(|f| f.name == fi.name);
+                let file_path = file.map(|f| f.path.clone()).unwrap_or_default();
+                let complexity = self.file_complexity(file);
                 all_file_insights.push(crate::types::FileInsight {
                     name: fi.name.clone(),
                     file_path,
                     summary: fi.summary.clone(),
-                    code_purpose: fi.code_purpose.unwrap_or(crate::types::code::CodePurpose::Other),
+                    code_purpose: self.file_purpose(file, fi.code_purpose),
                     source_summary: fi.source_summary.clone(),
                     detailed_description: fi.detailed_description.clone(),
                     responsibilities: fi.responsibilities.clone(),
@@ -331,6 +359,7 @@ impl DirectorySummarizer {
                         version: None,
                     }).collect(),
                     importance_score: fi.importance_score,
+                    complexity,
                 });
             }
         }
@@ -393,15 +422,17 @@ impl DirectorySummarizer {
 
                 // Pre-extract interfaces and dependencies via language processor
                 let path = &f.path;
-                let interfaces = self
+                let mut interfaces = self
                     .language_processor
                     .extract_interfaces(path, &f.content);
+                // Routes and listeners first, so the 20 listed interfaces keep the API surface
+                interfaces.sort_by_key(|i| !matches!(i.interface_type.as_str(), "route" | "listener" | "page_route"));
                 let dependencies = self
                     .language_processor
                     .extract_dependencies(path, &f.content);
                 let complexity = self
                     .language_processor
-                    .calculate_complexity_metrics(&f.content);
+                    .calculate_complexity_metrics(path, &f.content);
 
                 let interfaces_str = if interfaces.is_empty() {
                     String::new()
@@ -421,7 +452,12 @@ impl DirectorySummarizer {
                                 .as_ref()
                                 .map(|r| format!(" -> {}", r))
                                 .unwrap_or_default();
-                            format!("  - {}: {}({}){}", i.name, i.interface_type, params, ret)
+                            let is_boundary = matches!(i.interface_type.as_str(), "route" | "listener" | "page_route");
+                            let handler = match (&i.description, is_boundary) {
+                                (Some(description), true) => format!(" ({})", description),
+                                _ => String::new(),
+                            };
+                            format!("  - {}: {}({}){}{}", i.name, i.interface_type, params, ret, handler)
                         })
                         .collect::<Vec<_>>()
                         .join("\n")
@@ -439,11 +475,12 @@ impl DirectorySummarizer {
                 };
 
                 let metrics_str = format!(
-                    "  lines: {}, functions: {}, classes: {}, complexity: {:.1}",
+                    "  lines: {}, functions: {}, classes: {}, complexity: {:.1}, max nesting: {}",
                     complexity.lines_of_code,
                     complexity.number_of_functions,
                     complexity.number_of_classes,
-                    complexity.cyclomatic_complexity
+                    complexity.cyclomatic_complexity,
+                    complexity.max_nesting_depth
                 );
 
                 let block = if interfaces_str.is_empty() && dependencies_str.is_empty() {
@@ -520,6 +557,12 @@ IMPORTANT: Output valid JSON only, no markdown fences."#,
     }
 }
 
+impl Default for DirectorySummarizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 fn classify_directory_purpose(name: &str) -> DirectoryPurpose {
     let n = name.to_lowercase();
     if n == "src" || n == "lib" || n == "internal" || n == "core" || n == "pkg" {
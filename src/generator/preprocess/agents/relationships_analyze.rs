@@ -2,12 +2,14 @@ use anyhow::Result;
 
 use crate::generator::agent_executor::{AgentExecuteParams, extract};
 use crate::generator::preprocess::memory::ScopedKeys;
-use crate::types::code_releationship::RelationshipAnalysis;
+use crate::types::code_releationship::{CoreDependency, RelationshipAnalysis};
 use crate::{
     generator::context::GeneratorContext,
     types::{DirectoryDossier, DirectorySelection},
     utils::prompt_compressor::{CompressionConfig, PromptCompressor},
 };
+use crate::utils::paths;
+use tracing::info;
 
 pub struct RelationshipsAnalyze {
     prompt_compressor: PromptCompressor,
@@ -24,10 +26,25 @@ impl RelationshipsAnalyze {
     /// Two-phase when index content exceeds max_file_size:
     ///   Phase 1 — selection: LLM picks important directories + files
     ///   Phase 2 — analysis: LLM generates relationship graph from selected subset
+    /// Statically detected `static_calls` are given to the LLM and merged into its result.
     pub async fn execute(
         &self,
         context: &GeneratorContext,
         directory_dossiers: &[DirectoryDossier],
+        static_calls: &[CoreDependency],
+    ) -> Result<RelationshipAnalysis> {
+        let mut analysis = self
+            .analyze(context, directory_dossiers, &self.build_static_calls_content(static_calls))
+            .await?;
+        analysis.merge_dependencies(static_calls);
+        Ok(analysis)
+    }
+
+    async fn analyze(
+        &self,
+        context: &GeneratorContext,
+        directory_dossiers: &[DirectoryDossier],
+        static_calls_content: &str,
     ) -> Result<RelationshipAnalysis> {
         // Build index (metadata only, no per-file details)
         let index_content = self.build_index_content(directory_dossiers);
@@ -38,7 +55,7 @@ impl RelationshipsAnalyze {
         if index_size > index_threshold {
             // Phase 1: LLM selection
             let over_kb = (index_size - index_threshold) / 1024;
-            println!(
+            info!(
                 "   📋 Index too large: {} dirs, {} KB (limit {} KB, exceeded by {} KB) — running Directory Selection...",
                 directory_dossiers.len(),
                 index_size / 1024,
@@ -100,25 +117,53 @@ impl RelationshipsAnalyze {
             let selected_kb = selected_content.len() / 1024;
             let selected_dir_count = selection.selected_directories.len();
             let selected_file_count: usize = selection.selected_files.iter().map(|sf| sf.file_names.len()).sum();
-            println!(
+            info!(
                 "   ✅ Selected {} dirs, {} files — analysis content: {} KB",
                 selected_dir_count, selected_file_count, selected_kb,
             );
 
             // Phase 2: analysis with selected subset
             let agent_params = self
-                .build_analysis_params_with_selection(context, directory_dossiers, &selection)
+                .build_analysis_params_with_selection(
+                    context,
+                    directory_dossiers,
+                    &selection,
+                    static_calls_content,
+                )
                 .await?;
             extract::<RelationshipAnalysis>(context, agent_params).await
         } else {
             // Small enough: single-phase analysis
             let agent_params = self
-                .build_analysis_params(context, directory_dossiers)
+                .build_analysis_params(context, directory_dossiers, static_calls_content)
                 .await?;
             extract::<RelationshipAnalysis>(context, agent_params).await
         }
     }
 
+    /// Prompt section listing the function calls found between files, empty when there are none
+    fn build_static_calls_content(&self, static_calls: &[CoreDependency]) -> String {
+        if static_calls.is_empty() {
+            return String::new();
+        }
+        let calls = static_calls
+            .iter()
+            .map(|call| {
+                format!(
+                    "- {} -> {}: {}",
+                    call.from,
+                    call.to,
+                    call.description.as_deref().unwrap_or_default()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            "\n\n## Statically Detected Function Calls\nThese FunctionCall dependencies were extracted from the source code and are added to your result; use them as facts and do not repeat them:\n{}",
+            calls
+        )
+    }
+
     /// Build lightweight index: directory path, purpose, score, summary, and per-file names + scores.
     fn build_index_content(&self, dossiers: &[DirectoryDossier]) -> String {
         dossiers
@@ -134,7 +179,7 @@ impl RelationshipsAnalyze {
                 format!(
                     "### {} | path: {} | purpose: {:?} | importance: {:.2}\nSummary: {}\nFiles:\n{}",
                     d.name,
-                    d.path.to_string_lossy(),
+                    paths::to_slash(&d.path),
                     d.purpose,
                     d.importance_score,
                     d.summary,
@@ -177,7 +222,7 @@ Rules:
             .await?;
 
         if compression_result.was_compressed {
-            println!(
+            info!(
                 "   ✅ Selection index compressed: {} -> {} tokens",
                 compression_result.original_tokens, compression_result.compressed_tokens
             );
@@ -208,6 +253,7 @@ Output JSON selecting the key directories and per-directory file selection."#,
         &self,
         context: &GeneratorContext,
         directory_dossiers: &[DirectoryDossier],
+        static_calls_content: &str,
     ) -> Result<AgentExecuteParams> {
         let prompt_sys = r#"You are a professional software architecture analyst.
 
@@ -249,7 +295,7 @@ Constraints:
             .await?;
 
         if compression_result.was_compressed {
-            println!(
+            info!(
                 "   ✅ Compression complete: {} -> {} tokens",
                 compression_result.original_tokens, compression_result.compressed_tokens
             );
@@ -267,7 +313,7 @@ Output requirements (strict):
 - If uncertain, use Module as dependency_type.
 
 ## Directory Dossiers
-{}
+{}{}
 
 ## Analysis Requirements:
 Generate a project-level dependency relationship graph, focusing on:
@@ -275,7 +321,7 @@ Generate a project-level dependency relationship graph, focusing on:
 2. Architectural hierarchy (which directories are core, which are peripheral)
 3. Key integration points between directories
 4. Potential architectural issues or circular dependencies"#,
-            compressed_content
+            compressed_content, static_calls_content
         );
 
         Ok(AgentExecuteParams {
@@ -293,6 +339,7 @@ Generate a project-level dependency relationship graph, focusing on:
         context: &GeneratorContext,
         directory_dossiers: &[DirectoryDossier],
         selection: &DirectorySelection,
+        static_calls_content: &str,
     ) -> Result<AgentExecuteParams> {
         let prompt_sys = r#"You are a professional software architecture analyst.
 
@@ -373,7 +420,7 @@ Constraints:
             .await?;
 
         if compression_result.was_compressed {
-            println!(
+            info!(
                 "   ✅ Compression (selected): {} -> {} tokens",
                 compression_result.original_tokens, compression_result.compressed_tokens
             );
@@ -391,7 +438,7 @@ Output requirements (strict):
 - If uncertain, use Module as dependency_type.
 
 ## Selected Directory Dossiers
-{}
+{}{}
 
 ## Analysis Requirements:
 Generate a project-level dependency relationship graph, focusing on:
@@ -399,7 +446,7 @@ Generate a project-level dependency relationship graph, focusing on:
 2. Architectural hierarchy (which directories are core, which are peripheral)
 3. Key integration points between directories
 4. Potential architectural issues or circular dependencies"#,
-            compressed_content
+            compressed_content, static_calls_content
         );
 
         Ok(AgentExecuteParams {
@@ -446,3 +493,9 @@ Generate a project-level dependency relationship graph, focusing on:
             .join("\n\n")
     }
 }
+
+impl Default for RelationshipsAnalyze {
+    fn default() -> Self {
+        Self::new()
+    }
+}
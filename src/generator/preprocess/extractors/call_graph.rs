@@ -0,0 +1,155 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use crate::generator::preprocess::extractors::language_processors::LanguageProcessorManager;
+use crate::types::FileInfo;
+use crate::types::code_releationship::{CoreDependency, DependencyType};
+use crate::utils::threads::par_map;
+
+/// Called functions listed in the description of a dependency
+const DESCRIBED_CALLS: usize = 5;
+
+/// Function-level references between project files, found without the LLM by matching the
+/// calls of each file against the functions defined in the other files
+#[derive(Debug, Default)]
+pub struct CallGraph {
+    /// Called function names by (caller file, defining file), paths relative to the project root
+    calls: BTreeMap<(PathBuf, PathBuf), BTreeSet<String>>,
+}
+
+impl CallGraph {
+    /// Read the source files, only the first `max_bytes` of each, and extract their calls
+    pub fn build(files: &[FileInfo], root: &Path, max_bytes: usize) -> Self {
+        let processors = LanguageProcessorManager::new();
+        let sources: Vec<(PathBuf, String)> = par_map(files, |file| {
+            processors.get_processor(&file.path)?;
+            let bytes = std::fs::read(root.join(&file.path)).ok()?;
+            let content = String::from_utf8_lossy(&bytes[..bytes.len().min(max_bytes)]).to_string();
+            Some((file.path.clone(), content))
+        })
+        .into_iter()
+        .flatten()
+        .collect();
+        Self::from_sources(&processors, &sources)
+    }
+
+    fn from_sources(processors: &LanguageProcessorManager, sources: &[(PathBuf, String)]) -> Self {
+        // Definitions and calls are extracted in parallel, then matched in file order
+        let extracted = par_map(sources, |(path, content)| {
+            (
+                processors.extract_interfaces(path, content),
+                processors.extract_function_calls(path, content),
+            )
+        });
+
+        // Names defined in several files (`new`, `run`, ...) cannot be attributed and are ignored
+        let mut definitions: HashMap<String, Option<&PathBuf>> = HashMap::new();
+        for ((path, _), (interfaces, _)) in sources.iter().zip(&extracted) {
+            for interface in interfaces {
+                if !is_function(&interface.interface_type) {
+                    continue;
+                }
+                definitions
+                    .entry(interface.name.clone())
+                    .and_modify(|defined_in| {
+                        if *defined_in != Some(path) {
+                            *defined_in = None;
+                        }
+                    })
+                    .or_insert(Some(path));
+            }
+        }
+
+        let mut calls: BTreeMap<(PathBuf, PathBuf), BTreeSet<String>> = BTreeMap::new();
+        for ((path, _), (_, names)) in sources.iter().zip(&extracted) {
+            for name in names {
+                if let Some(Some(defined_in)) = definitions.get(name)
+                    && *defined_in != path
+                {
+                    calls
+                        .entry((path.clone(), (*defined_in).clone()))
+                        .or_default()
+                        .insert(name.clone());
+                }
+            }
+        }
+        Self { calls }
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// The `limit` file pairs with the most distinct called functions as `FunctionCall` dependencies
+    pub fn dependencies(&self, limit: usize) -> Vec<CoreDependency> {
+        let mut edges: Vec<_> = self.calls.iter().collect();
+        edges.sort_by_key(|(_, names)| std::cmp::Reverse(names.len()));
+        edges
+            .into_iter()
+            .take(limit)
+            .map(|((from, to), names)| {
+                let described: Vec<&str> = names.iter().take(DESCRIBED_CALLS).map(String::as_str).collect();
+                let more = names.len().saturating_sub(DESCRIBED_CALLS);
+                CoreDependency {
+                    from: from.to_string_lossy().to_string(),
+                    to: to.to_string_lossy().to_string(),
+                    dependency_type: DependencyType::FunctionCall,
+                    importance: names.len().clamp(1, 5) as u8,
+                    description: Some(if more > 0 {
+                        format!("calls {} and {} more", described.join(", "), more)
+                    } else {
+                        format!("calls {}", described.join(", "))
+                    }),
+                }
+            })
+            .collect()
+    }
+}
+
+fn is_function(interface_type: &str) -> bool {
+    ["function", "method", "hook", "procedure"]
+        .iter()
+        .any(|kind| interface_type.contains(kind))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_function_call_edges() {
+        let sources = vec![
+            (
+                PathBuf::from("src/main.rs"),
+                "fn main() {\n    // load_config() is read first\n    let config = load_config();\n    run(&config);\n    if ready(&config) { Server::new(config).serve(); }\n}\n".to_string(),
+            ),
+            (
+                PathBuf::from("src/config.rs"),
+                "pub fn load_config() -> Config {\n    Config::new()\n}\npub fn ready(config: &Config) -> bool {\n    true\n}\nfn new() -> Self {}\n".to_string(),
+            ),
+            (
+                PathBuf::from("src/server.rs"),
+                "pub fn run(config: &Config) {\n    serve(load_config());\n}\npub fn serve() {}\nfn new() -> Self {}\n".to_string(),
+            ),
+        ];
+
+        let graph = CallGraph::from_sources(&LanguageProcessorManager::new(), &sources);
+
+        // `new` is defined twice and the call in the comment is ignored
+        let dependencies = graph.dependencies(10);
+        let edges: Vec<_> = dependencies
+            .iter()
+            .map(|d| (d.from.as_str(), d.to.as_str(), d.description.as_deref().unwrap_or("")))
+            .collect();
+        assert_eq!(
+            edges,
+            vec![
+                ("src/main.rs", "src/config.rs", "calls load_config, ready"),
+                ("src/main.rs", "src/server.rs", "calls run, serve"),
+                ("src/server.rs", "src/config.rs", "calls load_config"),
+            ]
+        );
+        assert_eq!(dependencies[0].importance, 2);
+        assert_eq!(graph.dependencies(1).len(), 1);
+    }
+}
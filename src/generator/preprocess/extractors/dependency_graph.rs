@@ -0,0 +1,335 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::generator::preprocess::extractors::language_processors::LanguageProcessorManager;
+use crate::generator::preprocess::extractors::language_processors::cpp::{HEADER_EXTENSIONS, SOURCE_EXTENSIONS};
+use crate::types::FileInfo;
+use crate::utils::threads::par_map;
+
+/// PageRank damping factor
+const DAMPING: f64 = 0.85;
+const ITERATIONS: usize = 30;
+/// Trailing import segments dropped while resolving, e.g. the item of `crate::utils::threads::spawn`
+const MAX_DROPPED_SEGMENTS: usize = 2;
+/// Import prefixes that carry no path information
+const IGNORED_SEGMENTS: [&str; 5] = ["crate", "self", "super", "@", "~"];
+/// File stems that stand for their directory when imported
+const DIRECTORY_MODULES: [&str; 3] = ["mod", "index", "__init__"];
+
+/// File-level dependency graph of a project, built from the imports the language processors find
+pub struct DependencyGraph {
+    /// Files each file depends on, by index into the project files
+    edges: Vec<Vec<usize>>,
+    /// Whether the imports of each file were read
+    parsed: Vec<bool>,
+}
+
+impl DependencyGraph {
+    /// Read the source files and resolve their internal imports to other project files, and
+    /// link C/C++ headers to their implementation. Only the first `max_bytes` of each file are
+    /// read, imports sit at the top.
+    pub fn build(files: &[FileInfo], root: &Path, max_bytes: usize) -> Self {
+        let processors = LanguageProcessorManager::new();
+        let resolver = ModuleResolver::new(files);
+
+        // Imports are extracted in parallel, then resolved in file order
+        let imports = par_map(files, |file| {
+            let path = root.join(&file.path);
+            processors.get_processor(&path)?;
+            let Ok(bytes) = std::fs::read(&path) else {
+                return Some(Vec::new());
+            };
+            let content = String::from_utf8_lossy(&bytes[..bytes.len().min(max_bytes)]);
+            Some(processors.extract_dependencies(&path, &content))
+        });
+
+        let mut edges = Vec::new();
+        let mut parsed = vec![false; files.len()];
+        for (index, (file, dependencies)) in files.iter().zip(imports).enumerate() {
+            let Some(dependencies) = dependencies else {
+                continue;
+            };
+            parsed[index] = true;
+            for dependency in dependencies {
+                let import = dependency.path.as_deref().unwrap_or(&dependency.name);
+                // `<dir/header.h>` includes may name project headers on the include path
+                let project_include = dependency.dependency_type == "system_include" && import.contains('/');
+                if dependency.is_external && !project_include {
+                    continue;
+                }
+                if let Some(target) = resolver.resolve(import, &file.path)
+                    && target != index
+                {
+                    edges.push((index, target));
+                }
+            }
+        }
+        edges.extend(
+            header_implementations(files)
+                .into_iter()
+                .filter(|(header, source)| parsed[*header] && parsed[*source]),
+        );
+
+        Self {
+            parsed,
+            ..Self::from_edges(files.len(), edges)
+        }
+    }
+
+    pub fn from_edges(node_count: usize, edges: Vec<(usize, usize)>) -> Self {
+        let mut outgoing = vec![Vec::new(); node_count];
+        for (from, to) in edges {
+            if !outgoing[from].contains(&to) {
+                outgoing[from].push(to);
+            }
+        }
+        Self {
+            edges: outgoing,
+            parsed: vec![true; node_count],
+        }
+    }
+
+    /// Dependencies as (importing file, imported file) index pairs
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.edges
+            .iter()
+            .enumerate()
+            .flat_map(|(from, targets)| targets.iter().map(move |to| (from, *to)))
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().map(Vec::len).sum()
+    }
+
+    /// Number of project files importing each file
+    pub fn in_degrees(&self) -> Vec<usize> {
+        let mut degrees = vec![0; self.edges.len()];
+        for target in self.edges.iter().flatten() {
+            degrees[*target] += 1;
+        }
+        degrees
+    }
+
+    pub fn is_parsed(&self, index: usize) -> bool {
+        self.parsed.get(index).copied().unwrap_or(false)
+    }
+
+    /// PageRank of each file, normalized so that the most depended-on file scores 1.0
+    pub fn centrality(&self) -> Vec<f64> {
+        let count = self.edges.len();
+        if count == 0 {
+            return Vec::new();
+        }
+        let base = (1.0 - DAMPING) / count as f64;
+        let mut rank = vec![1.0 / count as f64; count];
+
+        for _ in 0..ITERATIONS {
+            let mut next = vec![base; count];
+            // Files without internal dependencies spread their rank evenly
+            let mut dangling = 0.0;
+            for (from, targets) in self.edges.iter().enumerate() {
+                if targets.is_empty() {
+                    dangling += rank[from];
+                    continue;
+                }
+                let share = DAMPING * rank[from] / targets.len() as f64;
+                for target in targets {
+                    next[*target] += share;
+                }
+            }
+            let dangling_share = DAMPING * dangling / count as f64;
+            next.iter_mut().for_each(|value| *value += dangling_share);
+            rank = next;
+        }
+
+        let max = rank.iter().copied().fold(0.0, f64::max);
+        rank.iter().map(|value| value / max).collect()
+    }
+}
+
+/// Each C/C++ header paired with the implementation file of the same stem, the one closest to
+/// the header when several match, so that including a header depends on its implementation
+fn header_implementations(files: &[FileInfo]) -> Vec<(usize, usize)> {
+    let extension = |file: &FileInfo| {
+        file.path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase())
+            .unwrap_or_default()
+    };
+    let stem = |file: &FileInfo| file.path.file_stem().map(|stem| stem.to_string_lossy().to_lowercase());
+    let mut sources: HashMap<String, Vec<usize>> = HashMap::new();
+    for (index, file) in files.iter().enumerate() {
+        if SOURCE_EXTENSIONS.contains(&extension(file).as_str())
+            && let Some(stem) = stem(file)
+        {
+            sources.entry(stem).or_default().push(index);
+        }
+    }
+
+    files
+        .iter()
+        .enumerate()
+        .filter(|(_, file)| HEADER_EXTENSIONS.contains(&extension(file).as_str()))
+        .filter_map(|(header, file)| {
+            let candidates = sources.get(&stem(file)?)?;
+            let source = candidates.iter().copied().rev().max_by_key(|source| {
+                files[*source]
+                    .path
+                    .iter()
+                    .zip(file.path.iter())
+                    .take_while(|(a, b)| a == b)
+                    .count()
+            })?;
+            Some((header, source))
+        })
+        .collect()
+}
+
+/// Resolves import strings (`crate::utils::threads`, `./utils/helper`, `app.models`, `codec.h`) to
+/// project files by matching their trailing path segments
+struct ModuleResolver {
+    /// Files by the last segment of their module path, with all segments of the module path and
+    /// the file extension
+    by_name: HashMap<String, Vec<(usize, Vec<String>, String)>>,
+}
+
+impl ModuleResolver {
+    fn new(files: &[FileInfo]) -> Self {
+        let mut by_name: HashMap<String, Vec<(usize, Vec<String>, String)>> = HashMap::new();
+        for (index, file) in files.iter().enumerate() {
+            let extension = file
+                .path
+                .extension()
+                .map(|extension| extension.to_string_lossy().to_lowercase())
+                .unwrap_or_default();
+            let mut segments: Vec<String> = file
+                .path
+                .with_extension("")
+                .iter()
+                .map(|segment| segment.to_string_lossy().to_lowercase())
+                .collect();
+            if segments
+                .last()
+                .is_some_and(|stem| DIRECTORY_MODULES.contains(&stem.as_str()))
+            {
+                by_name
+                    .entry(segments.last().cloned().unwrap_or_default())
+                    .or_default()
+                    .push((index, segments.clone(), extension.clone()));
+                segments.pop();
+            }
+            if let Some(name) = segments.last() {
+                by_name.entry(name.clone()).or_default().push((index, segments, extension));
+            }
+        }
+        Self { by_name }
+    }
+
+    fn resolve(&self, import: &str, importer: &Path) -> Option<usize> {
+        let segments: Vec<String> = import
+            .trim_matches(|c| c == '"' || c == '\'' || c == '<' || c == '>' || c == ';')
+            .split(['/', '\\', ':', '.'])
+            .map(|segment| segment.trim().to_lowercase())
+            .filter(|segment| !segment.is_empty() && !IGNORED_SEGMENTS.contains(&segment.as_str()))
+            .collect();
+        let importer: Vec<String> = importer
+            .iter()
+            .map(|segment| segment.to_string_lossy().to_lowercase())
+            .collect();
+
+        for dropped in 0..=MAX_DROPPED_SEGMENTS.min(segments.len().saturating_sub(1)) {
+            let wanted = &segments[..segments.len() - dropped];
+            let Some(candidates) = wanted.last().and_then(|name| self.by_name.get(name)) else {
+                continue;
+            };
+            // Among files matching the import, prefer the one with the imported extension, as
+            // `codec.h` next to `codec.c`, then the one closest to the importing file
+            let extension = segments.get(segments.len() - dropped);
+            let best = candidates
+                .iter()
+                .filter(|(_, module, _)| module.ends_with(wanted))
+                .max_by_key(|(_, module, file_extension)| {
+                    let closeness = module
+                        .iter()
+                        .zip(&importer)
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    (extension == Some(file_extension), closeness)
+                });
+            if let Some((index, _, _)) = best {
+                return Some(*index);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn file(path: &str) -> FileInfo {
+        FileInfo {
+            path: PathBuf::from(path),
+            name: String::new(),
+            size: 0,
+            extension: None,
+            is_core: false,
+            importance_score: 0.0,
+            complexity_score: 0.0,
+            last_modified: None,
+        }
+    }
+
+    #[test]
+    fn test_resolve_imports_and_centrality() {
+        let files = vec![
+            file("src/main.rs"),
+            file("src/utils/threads.rs"),
+            file("src/utils/mod.rs"),
+            file("web/src/api/client.ts"),
+            file("web/src/pages/home.tsx"),
+            file("native/include/codec.h"),
+            file("native/src/codec.c"),
+            file("native/src/main.c"),
+        ];
+        let resolver = ModuleResolver::new(&files);
+        let main = Path::new("src/main.rs");
+        assert_eq!(resolver.resolve("crate::utils::threads::do_parallel", main), Some(1));
+        assert_eq!(resolver.resolve("crate::utils", main), Some(2));
+        assert_eq!(resolver.resolve("../api/client", Path::new("web/src/pages/home.tsx")), Some(3));
+        assert_eq!(resolver.resolve("serde::Serialize", main), None);
+        assert_eq!(resolver.resolve("codec.h", Path::new("native/src/main.c")), Some(5));
+        assert_eq!(resolver.resolve("../include/codec.h", Path::new("native/src/codec.c")), Some(5));
+        assert_eq!(header_implementations(&files), vec![(5, 6)]);
+
+        // Everything depends on the utilities, which depend on nothing
+        let graph = DependencyGraph::from_edges(4, vec![(0, 1), (2, 1), (3, 1), (0, 2), (0, 1)]);
+        assert_eq!(graph.edge_count(), 4);
+        assert_eq!(graph.in_degrees(), vec![0, 3, 1, 0]);
+        let centrality = graph.centrality();
+        assert_eq!(centrality[1], 1.0);
+        assert!(centrality[2] > centrality[0]);
+        assert!(centrality[0] < 0.5);
+    }
+
+    #[test]
+    fn test_build_resolves_rust_use_paths() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        std::fs::create_dir_all(root.join("src/utils")).unwrap();
+        std::fs::write(
+            root.join("src/main.rs"),
+            "mod utils;\nuse crate::utils::threads::par_map;\nuse serde::Serialize;\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("src/utils/mod.rs"), "pub mod threads;\n").unwrap();
+        std::fs::write(root.join("src/utils/threads.rs"), "pub fn par_map() {}\n").unwrap();
+
+        let files = vec![file("src/main.rs"), file("src/utils/mod.rs"), file("src/utils/threads.rs")];
+        let graph = DependencyGraph::build(&files, &root, 4096);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), vec![(0, 2)]);
+        assert!((0..3).all(|index| graph.is_parsed(index)));
+    }
+}
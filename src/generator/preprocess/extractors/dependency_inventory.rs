@@ -0,0 +1,545 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::generator::preprocess::extractors::language_processors::LanguageProcessorManager;
+use crate::types::dependency::{DependencyEntry, DependencyInventory, Ecosystem};
+use crate::types::{DirectoryInfo, FileInfo};
+use crate::utils::threads::par_map;
+
+/// `name[extras] <version specifiers>` of requirements.txt lines and PEP 508 strings
+static REQUIREMENT_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*([A-Za-z0-9][A-Za-z0-9._\-]*)\s*(?:\[[^\]]*\])?\s*([^;#]*)").unwrap());
+static MAVEN_DEPENDENCY_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<dependency>(.*?)</dependency>").unwrap());
+/// Sections of a pom that do not describe the artifact itself
+static MAVEN_FOREIGN_SECTIONS_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)<parent>.*?</parent>|<dependencyManagement>.*?</dependencyManagement>|<dependencies>.*?</dependencies>|<build>.*?</build>|<profiles>.*?</profiles>").unwrap()
+});
+static GRADLE_DEPENDENCY_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?m)^\s*(?:implementation|api|compileOnly|runtimeOnly|testImplementation|testRuntimeOnly|kapt|ksp|annotationProcessor)\s*\(?\s*["']([^:"'\s]+):([^:"'\s]+)(?::([^"'\s]+))?["']"#).unwrap()
+});
+static PACKAGE_REFERENCE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<PackageReference\s+([^>]*?)/?>").unwrap());
+static PROJECT_REFERENCE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"<ProjectReference\s+Include\s*=\s*"([^"]+)""#).unwrap());
+static XML_ATTRIBUTE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"(\w+)\s*=\s*"([^"]*)""#).unwrap());
+
+/// Standard library and platform imports, never listed as dependencies
+const STANDARD_LIBRARIES: [&str; 38] = [
+    "std", "core", "alloc", "proc_macro", "test", "java", "javax", "kotlin", "android", "System",
+    "Foundation", "UIKit", "SwiftUI", "Swift", "Combine", "AppKit", "Darwin", "XCTest", "os",
+    "sys", "re", "json", "typing", "collections", "datetime", "logging", "pathlib", "itertools",
+    "functools", "subprocess", "asyncio", "unittest", "dataclasses", "abc", "time", "math", "enum",
+    "io",
+];
+
+/// Dependency declared by a manifest
+struct Declared {
+    ecosystem: Ecosystem,
+    name: String,
+    version: Option<String>,
+    /// Path, workspace or project reference to a package of the project
+    local: bool,
+}
+
+/// Collect the dependencies declared by the manifests of the project directories (Cargo,
+/// npm, Python, Go, Maven/Gradle, NuGet, Composer) and count the source files importing
+/// them, as found by the language processors. Imports of ecosystems without a manifest
+/// are listed without a version.
+pub fn collect(files: &[FileInfo], directories: &[DirectoryInfo], root: &Path, max_bytes: usize) -> DependencyInventory {
+    let mut manifest_dirs = vec![root.to_path_buf()];
+    manifest_dirs.extend(directories.iter().map(|directory| root.join(&directory.path)));
+    manifest_dirs.dedup();
+
+    let mut own_packages: HashSet<(Ecosystem, String)> = HashSet::new();
+    let mut entries: BTreeMap<(Ecosystem, String), DependencyEntry> = BTreeMap::new();
+    let mut declaring_dirs: BTreeMap<(Ecosystem, String), Vec<PathBuf>> = BTreeMap::new();
+    for dir in &manifest_dirs {
+        for declared in read_manifests(dir, &mut own_packages) {
+            let key = (declared.ecosystem, declared.name.clone());
+            declaring_dirs.entry(key.clone()).or_default().push(dir.clone());
+            let entry = entries.entry(key).or_insert_with(|| DependencyEntry {
+                name: declared.name,
+                ecosystem: declared.ecosystem,
+                version: None,
+                is_external: true,
+                license: None,
+                files: 0,
+            });
+            entry.is_external &= !declared.local;
+            if let Some(version) = declared.version.filter(|version| !version.is_empty()) {
+                entry.version = match entry.version.take() {
+                    Some(known) if known.split(", ").any(|v| v == version) => Some(known),
+                    Some(known) => Some(format!("{}, {}", known, version)),
+                    None => Some(version),
+                };
+            }
+        }
+    }
+    for ((ecosystem, name), entry) in entries.iter_mut() {
+        if own_packages.contains(&(*ecosystem, name.clone())) {
+            entry.is_external = false;
+        }
+    }
+
+    // Source files importing each dependency
+    let declared_ecosystems: HashSet<Ecosystem> = entries.keys().map(|(ecosystem, _)| *ecosystem).collect();
+    let mut importers: BTreeMap<(Ecosystem, String), HashSet<usize>> = BTreeMap::new();
+    let processors = LanguageProcessorManager::new();
+    // Imports are extracted in parallel, then matched with the declared dependencies in file order
+    let imports = par_map(files, |file| {
+        let path = root.join(&file.path);
+        let ecosystem = ecosystem_of(&path)?;
+        processors.get_processor(&path)?;
+        let bytes = fs::read(&path).ok()?;
+        let content = String::from_utf8_lossy(&bytes[..bytes.len().min(max_bytes)]);
+        let dependencies = processors.extract_dependencies(&path, &content);
+        Some((path, ecosystem, dependencies))
+    });
+    for (index, (path, ecosystem, dependencies)) in imports
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, import)| Some((index, import?)))
+    {
+        let source = path.to_string_lossy();
+        for dependency in dependencies {
+            if !dependency.is_external {
+                continue;
+            }
+            // Processors keep the import either as the path or as the name of the dependency
+            let import = dependency
+                .path
+                .as_deref()
+                .filter(|import| *import != source)
+                .unwrap_or(&dependency.name);
+            let declared = entries
+                .keys()
+                .find(|(declared_ecosystem, name)| *declared_ecosystem == ecosystem && imports_package(ecosystem, import, name));
+            if let Some(key) = declared {
+                importers.entry(key.clone()).or_default().insert(index);
+            } else if !declared_ecosystems.contains(&ecosystem)
+                && let Some(package) = import_root(ecosystem, import)
+                && !STANDARD_LIBRARIES.contains(&package.split('.').next().unwrap_or_default())
+            {
+                importers.entry((ecosystem, package)).or_default().insert(index);
+            }
+        }
+    }
+    for ((ecosystem, name), files) in importers {
+        let entry = entries.entry((ecosystem, name.clone())).or_insert_with(|| DependencyEntry {
+            name,
+            ecosystem,
+            version: None,
+            is_external: true,
+            license: None,
+            files: 0,
+        });
+        entry.files = files.len();
+    }
+
+    let cargo_lock = read_cargo_lock(root);
+    let mut dependencies: Vec<DependencyEntry> = entries
+        .into_iter()
+        .map(|(key, mut entry)| {
+            if entry.is_external {
+                let mut dirs = declaring_dirs.remove(&key).unwrap_or_default();
+                dirs.push(root.to_path_buf());
+                entry.license = installed_license(&entry, &dirs, &cargo_lock);
+            }
+            entry
+        })
+        .collect();
+    dependencies.sort_by(|a, b| {
+        (a.ecosystem, !a.is_external, a.name.to_lowercase()).cmp(&(b.ecosystem, !b.is_external, b.name.to_lowercase()))
+    });
+    DependencyInventory { dependencies }
+}
+
+/// Ecosystem of the packages imported by a source file
+fn ecosystem_of(path: &Path) -> Option<Ecosystem> {
+    let ecosystem = match path.extension()?.to_str()? {
+        "rs" => Ecosystem::Cargo,
+        "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" | "vue" | "svelte" => Ecosystem::Npm,
+        "py" => Ecosystem::Pypi,
+        "go" => Ecosystem::Go,
+        "java" | "kt" | "kts" => Ecosystem::Maven,
+        "cs" => Ecosystem::Nuget,
+        "php" => Ecosystem::Composer,
+        "swift" => Ecosystem::Swift,
+        _ => return None,
+    };
+    Some(ecosystem)
+}
+
+/// Package an import refers to, for ecosystems without a manifest
+fn import_root(ecosystem: Ecosystem, import: &str) -> Option<String> {
+    let import = import.trim().trim_start_matches("::").trim_start_matches('\\');
+    let root = match ecosystem {
+        Ecosystem::Cargo => import.split("::").next()?.to_string(),
+        Ecosystem::Npm if import.starts_with("node:") => return None,
+        Ecosystem::Npm if import.starts_with('@') => import.splitn(3, '/').take(2).collect::<Vec<_>>().join("/"),
+        Ecosystem::Npm => import.split('/').next()?.to_string(),
+        Ecosystem::Pypi | Ecosystem::Swift => import.split('.').next()?.to_string(),
+        Ecosystem::Maven | Ecosystem::Nuget => import.split('.').take(2).collect::<Vec<_>>().join("."),
+        Ecosystem::Composer => import.split('\\').next()?.to_string(),
+        Ecosystem::Go => import.to_string(),
+    };
+    let valid = !root.is_empty()
+        && root
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '/' | '@'));
+    valid.then_some(root)
+}
+
+/// Whether an import refers to the declared package
+fn imports_package(ecosystem: Ecosystem, import: &str, package: &str) -> bool {
+    let normalize = |name: &str| name.to_lowercase().replace(['-', '.'], "_");
+    let root = import_root(ecosystem, import);
+    let import = import.trim().trim_start_matches('\\');
+    match ecosystem {
+        Ecosystem::Cargo => root.is_some_and(|root| root == package.replace('-', "_")),
+        Ecosystem::Npm => root.is_some_and(|root| root == package),
+        Ecosystem::Pypi | Ecosystem::Swift => root.is_some_and(|root| normalize(&root) == normalize(package)),
+        Ecosystem::Maven => {
+            let group = package.split(':').next().unwrap_or(package);
+            import == group || import.starts_with(&format!("{}.", group))
+        }
+        Ecosystem::Nuget => import == package || import.starts_with(&format!("{}.", package)),
+        Ecosystem::Go => import == package || import.starts_with(&format!("{}/", package)),
+        Ecosystem::Composer => {
+            let namespace = import.split('\\').take(2).collect::<Vec<_>>().join("/");
+            namespace.eq_ignore_ascii_case(package)
+        }
+    }
+}
+
+/// Dependencies declared by the manifests of a directory; the packages the manifests
+/// define are added to `own_packages`
+fn read_manifests(dir: &Path, own_packages: &mut HashSet<(Ecosystem, String)>) -> Vec<Declared> {
+    let mut declared = Vec::new();
+    let read = |name: &str| fs::read_to_string(dir.join(name)).ok();
+
+    if let Some(manifest) = read("Cargo.toml").and_then(|content| toml::from_str::<toml::Value>(&content).ok()) {
+        if let Some(name) = manifest.get("package").and_then(|package| package.get("name")).and_then(|name| name.as_str()) {
+            own_packages.insert((Ecosystem::Cargo, name.to_string()));
+        }
+        let mut tables: Vec<&toml::Value> = ["dependencies", "dev-dependencies", "build-dependencies"]
+            .iter()
+            .filter_map(|table| manifest.get(*table))
+            .collect();
+        tables.extend(manifest.get("workspace").and_then(|workspace| workspace.get("dependencies")));
+        for table in tables.into_iter().filter_map(|table| table.as_table()) {
+            for (name, spec) in table {
+                let (version, local) = match spec {
+                    toml::Value::String(version) => (Some(version.clone()), false),
+                    spec => (
+                        spec.get("version").and_then(|v| v.as_str()).map(str::to_string),
+                        spec.get("path").is_some(),
+                    ),
+                };
+                declared.push(Declared { ecosystem: Ecosystem::Cargo, name: name.clone(), version, local });
+            }
+        }
+    }
+
+    if let Some(manifest) = read("package.json").and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok()) {
+        if let Some(name) = manifest.get("name").and_then(|name| name.as_str()) {
+            own_packages.insert((Ecosystem::Npm, name.to_string()));
+        }
+        for table in ["dependencies", "devDependencies", "peerDependencies", "optionalDependencies"] {
+            for (name, version) in manifest.get(table).and_then(|table| table.as_object()).into_iter().flatten() {
+                let version = version.as_str().unwrap_or_default();
+                let local = ["workspace:", "file:", "link:", "portal:"].iter().any(|prefix| version.starts_with(prefix));
+                declared.push(Declared { ecosystem: Ecosystem::Npm, name: name.clone(), version: Some(version.to_string()), local });
+            }
+        }
+    }
+
+    if let Some(requirements) = read("requirements.txt") {
+        for line in requirements.lines().filter(|line| !line.trim_start().starts_with(['-', '#'])) {
+            declared.extend(python_requirement(line));
+        }
+    }
+    if let Some(manifest) = read("pyproject.toml").and_then(|content| toml::from_str::<toml::Value>(&content).ok()) {
+        let project = manifest.get("project");
+        let poetry = manifest.get("tool").and_then(|tool| tool.get("poetry"));
+        for name in [project, poetry].into_iter().flatten().filter_map(|table| table.get("name")?.as_str()) {
+            own_packages.insert((Ecosystem::Pypi, name.to_string()));
+        }
+        for requirement in project.and_then(|project| project.get("dependencies")?.as_array()).into_iter().flatten() {
+            declared.extend(requirement.as_str().and_then(python_requirement));
+        }
+        let poetry_tables = ["dependencies", "dev-dependencies"].iter().filter_map(|table| poetry?.get(*table));
+        for (name, spec) in poetry_tables.filter_map(|table| table.as_table()).flatten() {
+            if name == "python" {
+                continue;
+            }
+            let (version, local) = match spec {
+                toml::Value::String(version) => (Some(version.clone()), false),
+                spec => (spec.get("version").and_then(|v| v.as_str()).map(str::to_string), spec.get("path").is_some()),
+            };
+            declared.push(Declared { ecosystem: Ecosystem::Pypi, name: name.clone(), version, local });
+        }
+    }
+
+    if let Some(go_mod) = read("go.mod") {
+        let mut local_modules = Vec::new();
+        let mut block = "";
+        for line in go_mod.lines().map(|line| line.split("//").next().unwrap_or_default().trim()) {
+            let (directive, rest) = match line.split_once(char::is_whitespace) {
+                Some((directive @ ("module" | "require" | "replace"), rest)) => (directive, rest.trim()),
+                _ if line == ")" => {
+                    block = "";
+                    continue;
+                }
+                _ => (block, line),
+            };
+            if rest == "(" {
+                block = directive;
+                continue;
+            }
+            let mut parts = rest.split_whitespace();
+            match (directive, parts.next(), parts.next()) {
+                ("module", Some(module), _) => {
+                    own_packages.insert((Ecosystem::Go, module.to_string()));
+                }
+                ("require", Some(module), Some(version)) => declared.push(Declared {
+                    ecosystem: Ecosystem::Go,
+                    name: module.to_string(),
+                    version: Some(version.to_string()),
+                    local: false,
+                }),
+                ("replace", Some(module), _) if rest.contains("=> ./") || rest.contains("=> ../") => {
+                    local_modules.push(module.to_string())
+                }
+                _ => {}
+            }
+        }
+        for dependency in declared.iter_mut().filter(|d| d.ecosystem == Ecosystem::Go) {
+            dependency.local |= local_modules.contains(&dependency.name);
+        }
+    }
+
+    if let Some(pom) = read("pom.xml") {
+        let tag = |xml: &str, name: &str| {
+            Regex::new(&format!(r"<{0}>\s*([^<]+?)\s*</{0}>", name))
+                .ok()?
+                .captures(xml)
+                .map(|captures| captures[1].to_string())
+        };
+        let own = MAVEN_FOREIGN_SECTIONS_REGEX.replace_all(&pom, "");
+        let parent_group = Regex::new(r"(?s)<parent>.*?</parent>").ok().and_then(|r| r.find(&pom)).and_then(|parent| tag(parent.as_str(), "groupId"));
+        if let Some(artifact) = tag(&own, "artifactId") {
+            let group = tag(&own, "groupId").or(parent_group).unwrap_or_default();
+            own_packages.insert((Ecosystem::Maven, format!("{}:{}", group, artifact)));
+        }
+        for dependency in MAVEN_DEPENDENCY_REGEX.captures_iter(&pom) {
+            let (Some(group), Some(artifact)) = (tag(&dependency[1], "groupId"), tag(&dependency[1], "artifactId")) else {
+                continue;
+            };
+            declared.push(Declared {
+                ecosystem: Ecosystem::Maven,
+                name: format!("{}:{}", group, artifact),
+                version: tag(&dependency[1], "version"),
+                local: false,
+            });
+        }
+    }
+    for gradle in [read("build.gradle"), read("build.gradle.kts")].into_iter().flatten() {
+        for captures in GRADLE_DEPENDENCY_REGEX.captures_iter(&gradle) {
+            declared.push(Declared {
+                ecosystem: Ecosystem::Maven,
+                name: format!("{}:{}", &captures[1], &captures[2]),
+                version: captures.get(3).map(|version| version.as_str().to_string()),
+                local: false,
+            });
+        }
+    }
+
+    for project in fs::read_dir(dir).into_iter().flatten().flatten() {
+        let path = project.path();
+        if path.extension().is_none_or(|extension| extension != "csproj") {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        if let Some(stem) = path.file_stem() {
+            own_packages.insert((Ecosystem::Nuget, stem.to_string_lossy().to_string()));
+        }
+        for reference in PACKAGE_REFERENCE_REGEX.captures_iter(&content) {
+            let attributes: BTreeMap<String, String> = XML_ATTRIBUTE_REGEX
+                .captures_iter(&reference[1])
+                .map(|attribute| (attribute[1].to_string(), attribute[2].to_string()))
+                .collect();
+            if let Some(name) = attributes.get("Include") {
+                declared.push(Declared {
+                    ecosystem: Ecosystem::Nuget,
+                    name: name.clone(),
+                    version: attributes.get("Version").cloned(),
+                    local: false,
+                });
+            }
+        }
+        for reference in PROJECT_REFERENCE_REGEX.captures_iter(&content) {
+            let name = reference[1].rsplit(['/', '\\']).next().unwrap_or_default().trim_end_matches(".csproj");
+            declared.push(Declared { ecosystem: Ecosystem::Nuget, name: name.to_string(), version: None, local: true });
+        }
+    }
+
+    if let Some(manifest) = read("composer.json").and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok()) {
+        if let Some(name) = manifest.get("name").and_then(|name| name.as_str()) {
+            own_packages.insert((Ecosystem::Composer, name.to_string()));
+        }
+        for table in ["require", "require-dev"] {
+            for (name, version) in manifest.get(table).and_then(|table| table.as_object()).into_iter().flatten() {
+                if name == "php" || name.starts_with("ext-") || name.starts_with("lib-") {
+                    continue;
+                }
+                let version = version.as_str().map(str::to_string);
+                declared.push(Declared { ecosystem: Ecosystem::Composer, name: name.clone(), version, local: false });
+            }
+        }
+    }
+
+    declared
+}
+
+fn python_requirement(line: &str) -> Option<Declared> {
+    let captures = REQUIREMENT_REGEX.captures(line)?;
+    Some(Declared {
+        ecosystem: Ecosystem::Pypi,
+        name: captures[1].to_string(),
+        version: Some(captures[2].trim().to_string()),
+        local: false,
+    })
+}
+
+/// Locked versions of the crates in Cargo.lock
+fn read_cargo_lock(root: &Path) -> BTreeMap<String, String> {
+    let Some(lock) = fs::read_to_string(root.join("Cargo.lock"))
+        .ok()
+        .and_then(|content| toml::from_str::<toml::Value>(&content).ok())
+    else {
+        return BTreeMap::new();
+    };
+    let mut versions = BTreeMap::new();
+    for package in lock.get("package").and_then(|packages| packages.as_array()).into_iter().flatten() {
+        if let (Some(name), Some(version)) = (package.get("name").and_then(|v| v.as_str()), package.get("version").and_then(|v| v.as_str())) {
+            versions.entry(name.to_string()).or_insert_with(|| version.to_string());
+        }
+    }
+    versions
+}
+
+/// License declared by the installed package: `node_modules` for npm, `vendor` for Composer
+/// and the Cargo registry sources for the crate version locked in Cargo.lock
+fn installed_license(entry: &DependencyEntry, dirs: &[PathBuf], cargo_lock: &BTreeMap<String, String>) -> Option<String> {
+    let json_license = |path: PathBuf| {
+        let manifest: serde_json::Value = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+        match manifest.get("license")? {
+            serde_json::Value::String(license) => Some(license.clone()),
+            serde_json::Value::Array(licenses) => {
+                Some(licenses.iter().filter_map(|license| license.as_str()).collect::<Vec<_>>().join(" OR "))
+            }
+            license => license.get("type")?.as_str().map(str::to_string),
+        }
+    };
+    match entry.ecosystem {
+        Ecosystem::Npm => dirs
+            .iter()
+            .find_map(|dir| json_license(dir.join("node_modules").join(&entry.name).join("package.json"))),
+        Ecosystem::Composer => dirs
+            .iter()
+            .find_map(|dir| json_license(dir.join("vendor").join(&entry.name).join("composer.json"))),
+        Ecosystem::Cargo => {
+            let version = cargo_lock.get(&entry.name)?;
+            let cargo_home = std::env::var_os("CARGO_HOME")
+                .map(PathBuf::from)
+                .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cargo")))?;
+            fs::read_dir(cargo_home.join("registry").join("src")).ok()?.flatten().find_map(|registry| {
+                let manifest = fs::read_to_string(registry.path().join(format!("{}-{}", entry.name, version)).join("Cargo.toml")).ok()?;
+                let manifest: toml::Value = toml::from_str(&manifest).ok()?;
+                manifest.get("package")?.get("license")?.as_str().map(str::to_string)
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(path: &str) -> FileInfo {
+        FileInfo {
+            path: PathBuf::from(path),
+            name: path.rsplit('/').next().unwrap_or(path).to_string(),
+            size: 0,
+            extension: path.rsplit_once('.').map(|(_, extension)| extension.to_string()),
+            is_core: false,
+            importance_score: 0.0,
+            complexity_score: 0.0,
+            last_modified: None,
+        }
+    }
+
+    #[test]
+    fn test_collect_dependency_inventory() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        let write = |path: &str, content: &str| {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        write(
+            "Cargo.toml",
+            "[package]\nname = \"shop\"\n\n[dependencies]\nserde = { version = \"1.0\", features = [\"derive\"] }\nshop-core = { path = \"core\" }\nanyhow = \"1\"\n",
+        );
+        write("src/main.rs", "use serde::Deserialize;\nuse std::fs;\nuse crate::orders;\n");
+        write("web/package.json", "{\"name\": \"shop-web\", \"dependencies\": {\"lodash\": \"^4.17.21\"}}");
+        write("web/node_modules/lodash/package.json", "{\"name\": \"lodash\", \"license\": \"MIT\"}");
+        write("web/index.js", "import fp from \"lodash/fp\";\nimport api from \"./api\";\n");
+        write("tools/gen.py", "import requests\nimport os\n");
+
+        let directories: Vec<DirectoryInfo> = ["src", "web", "tools"]
+            .iter()
+            .map(|name| DirectoryInfo {
+                path: root.join(name),
+                name: name.to_string(),
+                file_count: 1,
+                subdirectory_count: 0,
+                total_size: 0,
+                importance_score: 0.0,
+            })
+            .collect();
+        let files = [file("src/main.rs"), file("web/index.js"), file("tools/gen.py")];
+        let inventory = collect(&files, &directories, &root, 64 * 1024);
+
+        let summary: Vec<_> = inventory
+            .dependencies
+            .iter()
+            .map(|d| (d.name.as_str(), d.version.as_deref(), d.is_external, d.license.as_deref(), d.files))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("anyhow", Some("1"), true, None, 0),
+                ("serde", Some("1.0"), true, None, 1),
+                ("shop-core", None, false, None, 0),
+                ("lodash", Some("^4.17.21"), true, Some("MIT"), 1),
+                ("requests", None, true, None, 1),
+            ]
+        );
+        assert_eq!(inventory.external_count(), 4);
+        assert_eq!(
+            inventory.by_ecosystem().iter().map(|(ecosystem, entries)| (ecosystem.label(), entries.len())).collect::<Vec<_>>(),
+            [("Cargo (crates.io)", 3), ("npm", 1), ("Python (PyPI)", 1)]
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::LazyLock;
+
+use regex::Regex;
+use walkdir::WalkDir;
+
+use crate::config::Config;
+use crate::types::diagram_asset::{DiagramAsset, DiagramFormat};
+use crate::utils::privacy::{PathAccess, PrivacyPolicy};
+use crate::utils::secret_redactor::{self, RedactionLog};
+
+/// Directories holding documentation; diagrams are only looked for below them
+const DOC_DIRECTORIES: [&str; 8] = ["docs", "doc", "documentation", "design", "architecture", "diagrams", "adr", "wiki"];
+/// Larger files are left out (bytes)
+const MAX_ASSET_SIZE: u64 = 5 * 1024 * 1024;
+const MAX_ASSETS: usize = 60;
+/// Characters of a text diagram source given to the agents
+const MAX_SOURCE_CHARS: usize = 1500;
+/// Markdown files larger than this are not searched for image references (bytes)
+const MAX_MARKDOWN_SIZE: u64 = 512 * 1024;
+
+/// `![alt](target)` image references of markdown documents
+static IMAGE_REFERENCE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"!\[([^\]]*)\]\(\s*<?([^)\s>]+)>?(?:\s+"[^"]*")?\s*\)"#).unwrap());
+
+/// Find the diagrams and images in the documentation folders of the project, titled by the
+/// alt text of the markdown images referencing them
+pub fn detect(config: &Config, redactions: &RedactionLog) -> Vec<DiagramAsset> {
+    let root = &config.project_path;
+    let privacy = PrivacyPolicy::new(&config.privacy);
+    let output = config.output_path.canonicalize().ok();
+
+    let mut candidates = Vec::new();
+    let mut markdown_files = Vec::new();
+    let walker = WalkDir::new(root).into_iter().filter_entry(|entry| {
+        let name = entry.file_name().to_string_lossy();
+        let skipped = entry.depth() > 0
+            && entry.file_type().is_dir()
+            && (name.starts_with('.')
+                || config.excluded_dirs.iter().any(|dir| *dir == name)
+                || output.as_ref().is_some_and(|output| entry.path().canonicalize().ok().as_ref() == Some(output)));
+        !skipped && !privacy.is_excluded(root, entry.path())
+    });
+    for entry in walker.filter_map(|entry| entry.ok()).filter(|entry| entry.file_type().is_file()) {
+        let Ok(relative) = entry.path().strip_prefix(root) else {
+            continue;
+        };
+        let extension = relative.extension().and_then(|e| e.to_str()).unwrap_or_default();
+        let size = entry.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+        if extension.eq_ignore_ascii_case("md") {
+            if size <= MAX_MARKDOWN_SIZE {
+                markdown_files.push(relative.to_path_buf());
+            }
+            continue;
+        }
+        let Some(format) = DiagramFormat::from_extension(extension) else {
+            continue;
+        };
+        let in_docs = relative.parent().is_some_and(|parent| {
+            parent.components().any(|component| {
+                let name = component.as_os_str().to_string_lossy().to_lowercase();
+                DOC_DIRECTORIES.contains(&name.as_str())
+            })
+        });
+        if in_docs && size > 0 && size <= MAX_ASSET_SIZE {
+            candidates.push((relative.to_path_buf(), format, size));
+        }
+    }
+    if candidates.is_empty() {
+        return Vec::new();
+    }
+
+    let titles = image_titles(root, &markdown_files);
+    candidates.sort_by_key(|(path, _, _)| (!titles.contains_key(path), path.clone()));
+    candidates
+        .into_iter()
+        .take(MAX_ASSETS)
+        .map(|(path, format, size)| {
+            let title = titles.get(&path).cloned().unwrap_or_else(|| humanize(&path));
+            // Metadata-only files are listed without their source
+            let readable = matches!(format, DiagramFormat::PlantUml | DiagramFormat::Mermaid)
+                && privacy.access(root, &path) == PathAccess::Full;
+            let source = readable
+                .then(|| std::fs::read_to_string(root.join(&path)).ok())
+                .flatten()
+                .map(|source| {
+                    let source: String = source.chars().take(MAX_SOURCE_CHARS).collect();
+                    secret_redactor::redact(&config.redaction, redactions, &path.to_string_lossy(), &source)
+                });
+            DiagramAsset {
+                path,
+                format,
+                title,
+                size,
+                source,
+            }
+        })
+        .collect()
+}
+
+/// Alt texts of the images referenced by the markdown files, by image path
+fn image_titles(root: &Path, markdown_files: &[PathBuf]) -> HashMap<PathBuf, String> {
+    let mut titles = HashMap::new();
+    for markdown in markdown_files {
+        let Ok(content) = std::fs::read_to_string(root.join(markdown)) else {
+            continue;
+        };
+        let directory = markdown.parent().unwrap_or(Path::new(""));
+        for captures in IMAGE_REFERENCE_REGEX.captures_iter(&content) {
+            let (alt, target) = (captures[1].trim(), &captures[2]);
+            if alt.is_empty() || target.contains("://") {
+                continue;
+            }
+            let target = target.split(['#', '?']).next().unwrap_or_default();
+            let path = match target.strip_prefix('/') {
+                Some(absolute) => PathBuf::from(absolute),
+                None => directory.join(target),
+            };
+            titles.entry(normalize(&path)).or_insert_with(|| alt.to_string());
+        }
+    }
+    titles
+}
+
+/// Resolve `.` and `..` without touching the file system
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// `docs/img/order_flow-v2.png` -> `order flow v2`
+fn humanize(path: &Path) -> String {
+    path.file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .replace(['-', '_', '.'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_diagram_assets() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        let write = |path: &str, content: &str| {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        };
+        write("README.md", "# Shop\n\n![Deployment overview](./docs/img/deploy.png)\n");
+        write("docs/img/deploy.png", "png");
+        write("docs/design/checkout_flow.puml", "@startuml\nBuyer -> Shop: checkout\n@enduml\n");
+        write("docs/design/notes.txt", "notes");
+        write("src/ui/logo.png", "png");
+        write("node_modules/pkg/docs/diagram.svg", "<svg/>");
+
+        let config = Config {
+            project_path: root.clone(),
+            output_path: root.join("litho.docs"),
+            ..Default::default()
+        };
+        let assets = detect(&config, &RedactionLog::default());
+
+        let summary: Vec<(String, DiagramFormat, &str)> = assets
+            .iter()
+            .map(|asset| (asset.output_path(), asset.format, asset.title.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("assets/docs/img/deploy.png".to_string(), DiagramFormat::Image, "Deployment overview"),
+                ("assets/docs/design/checkout_flow.puml".to_string(), DiagramFormat::PlantUml, "checkout flow"),
+            ]
+        );
+        assert_eq!(assets[0].source, None);
+        assert_eq!(assets[1].source.as_deref(), Some("@startuml\nBuyer -> Shop: checkout\n@enduml\n"));
+    }
+}
@@ -0,0 +1,183 @@
+use std::path::{Component, Path};
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::types::FileInfo;
+use crate::types::entry_point::EntryPoint;
+
+/// Entry points passed to the agents, most important first
+const MAX_ENTRY_POINTS: usize = 30;
+/// Main functions are rarely buried deeper than this
+const SCANNED_BYTES: usize = 64 * 1024;
+
+/// Files that are entry points by name
+const NAMED_ENTRY_POINTS: [(&str, &str); 4] = [
+    ("manage.py", "Django manage.py"),
+    ("Program.cs", ".NET Program.cs"),
+    ("__main__.py", "Python package __main__"),
+    ("wsgi.py", "WSGI application"),
+];
+
+/// Script entry files, only counted at the root of a project or package source directory
+/// (deeper `index` files are usually module barrels)
+const SCRIPT_ENTRY_POINTS: [&str; 10] = [
+    "index.ts", "index.js", "index.tsx", "index.mjs", "main.ts", "main.js", "main.tsx", "server.ts",
+    "server.js", "app.js",
+];
+const SCRIPT_ENTRY_DIRECTORIES: [&str; 4] = ["src", "bin", "server", "app"];
+
+struct EntryRule {
+    extensions: &'static [&'static str],
+    evidence: &'static str,
+    marker: &'static LazyLock<Regex>,
+}
+
+static RUST_MAIN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^\s*(pub\s+)?(async\s+)?fn\s+main\s*\(").unwrap());
+static GO_MAIN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)^\s*(//[^\n]*\n\s*)*package\s+main\b.*\bfunc\s+main\s*\(\s*\)").unwrap());
+static C_MAIN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^\s*int\s+main\s*\(").unwrap());
+static JAVA_MAIN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"public\s+static\s+void\s+main\s*\(\s*(final\s+)?String").unwrap());
+static SPRING_BOOT_APPLICATION: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"@SpringBootApplication\b").unwrap());
+static KOTLIN_MAIN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^\s*fun\s+main\s*\(").unwrap());
+static PYTHON_MAIN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"if\s+__name__\s*==\s*['"]__main__['"]"#).unwrap());
+static CSHARP_MAIN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"static\s+(async\s+)?(void|int|Task(<int>)?)\s+Main\s*\(").unwrap());
+static SWIFT_MAIN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^\s*@main\b").unwrap());
+
+const RULES: [EntryRule; 9] = [
+    EntryRule { extensions: &["rs"], evidence: "fn main", marker: &RUST_MAIN },
+    EntryRule { extensions: &["go"], evidence: "package main with func main", marker: &GO_MAIN },
+    EntryRule { extensions: &["c", "cc", "cpp", "cxx"], evidence: "int main", marker: &C_MAIN },
+    EntryRule { extensions: &["java", "kt"], evidence: "@SpringBootApplication", marker: &SPRING_BOOT_APPLICATION },
+    EntryRule { extensions: &["java"], evidence: "public static void main", marker: &JAVA_MAIN },
+    EntryRule { extensions: &["kt"], evidence: "fun main", marker: &KOTLIN_MAIN },
+    EntryRule { extensions: &["py"], evidence: "if __name__ == \"__main__\"", marker: &PYTHON_MAIN },
+    EntryRule { extensions: &["cs"], evidence: "static Main", marker: &CSHARP_MAIN },
+    EntryRule { extensions: &["swift"], evidence: "@main", marker: &SWIFT_MAIN },
+];
+
+/// Detect the files where the programs of the project start, from well-known file names
+/// and main-function markers; test files are ignored
+pub fn detect(files: &[FileInfo], root: &Path) -> Vec<EntryPoint> {
+    let mut candidates: Vec<&FileInfo> = files.iter().filter(|file| !is_test_path(&file.path)).collect();
+    candidates.sort_by(|a, b| b.importance_score.total_cmp(&a.importance_score).then_with(|| a.path.cmp(&b.path)));
+
+    let mut entry_points = Vec::new();
+    for file in candidates {
+        if entry_points.len() >= MAX_ENTRY_POINTS {
+            break;
+        }
+        if let Some(evidence) = named_entry_point(file) {
+            entry_points.push(EntryPoint {
+                path: file.path.clone(),
+                evidence: evidence.to_string(),
+            });
+            continue;
+        }
+
+        let extension = file.extension.as_deref().unwrap_or_default();
+        let rules: Vec<&EntryRule> = RULES.iter().filter(|rule| rule.extensions.contains(&extension)).collect();
+        if rules.is_empty() {
+            continue;
+        }
+        let Ok(bytes) = std::fs::read(root.join(&file.path)) else {
+            continue;
+        };
+        let head = String::from_utf8_lossy(&bytes[..bytes.len().min(SCANNED_BYTES)]);
+        if let Some(rule) = rules.into_iter().find(|rule| rule.marker.is_match(&head)) {
+            entry_points.push(EntryPoint {
+                path: file.path.clone(),
+                evidence: rule.evidence.to_string(),
+            });
+        }
+    }
+    entry_points
+}
+
+fn named_entry_point(file: &FileInfo) -> Option<&'static str> {
+    if let Some((_, evidence)) = NAMED_ENTRY_POINTS.iter().find(|(name, _)| file.name == *name) {
+        return Some(evidence);
+    }
+    if !SCRIPT_ENTRY_POINTS.contains(&file.name.as_str()) {
+        return None;
+    }
+    let parent = file.path.parent().and_then(Path::file_name).map(|name| name.to_string_lossy());
+    match parent {
+        None => Some("script entry file"),
+        Some(directory) if SCRIPT_ENTRY_DIRECTORIES.contains(&directory.as_ref()) => Some("script entry file"),
+        Some(_) => None,
+    }
+}
+
+/// Whether the path belongs to tests, by directory or file naming conventions
+pub fn is_test_path(path: &Path) -> bool {
+    path.components().any(|component| match component {
+        Component::Normal(name) => {
+            let name = name.to_string_lossy().to_lowercase();
+            matches!(name.as_str(), "test" | "tests" | "__tests__" | "spec" | "testdata")
+                || name.starts_with("test_")
+                || name.contains(".test.")
+                || name.contains(".spec.")
+                || name.contains("_test.")
+        }
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_detect_entry_points() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        let sources = [
+            ("src/main.rs", "#[tokio::main]\nasync fn main() {}\n"),
+            ("src/lib.rs", "pub fn main_loop() {}\n"),
+            ("cmd/api/main.go", "package main\n\nfunc main() {\n}\n"),
+            ("web/src/index.ts", "export {};\n"),
+            ("web/src/components/index.ts", "export * from './button';\n"),
+            ("backend/App.java", "@SpringBootApplication\npublic class App {}\n"),
+            ("manage.py", "import os\n"),
+            ("tests/main.rs", "fn main() {}\n"),
+        ];
+        let mut files = Vec::new();
+        for (path, content) in sources {
+            let path = PathBuf::from(path);
+            std::fs::create_dir_all(root.join(path.parent().unwrap())).unwrap();
+            std::fs::write(root.join(&path), content).unwrap();
+            files.push(FileInfo {
+                name: path.file_name().unwrap().to_string_lossy().to_string(),
+                extension: path.extension().map(|e| e.to_string_lossy().to_string()),
+                path,
+                size: 0,
+                is_core: false,
+                importance_score: 0.5,
+                complexity_score: 0.0,
+                last_modified: None,
+            });
+        }
+
+        let entry_points = detect(&files, &root);
+
+        let found: Vec<(String, &str)> = entry_points
+            .iter()
+            .map(|e| (e.path.display().to_string(), e.evidence.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("backend/App.java".to_string(), "@SpringBootApplication"),
+                ("cmd/api/main.go".to_string(), "package main with func main"),
+                ("manage.py".to_string(), "Django manage.py"),
+                ("src/main.rs".to_string(), "fn main"),
+                ("web/src/index.ts".to_string(), "script entry file"),
+            ]
+        );
+    }
+}
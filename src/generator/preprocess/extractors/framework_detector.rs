@@ -0,0 +1,179 @@
+use std::path::Path;
+
+use crate::types::FileInfo;
+use crate::types::framework::{DetectedFramework, Framework, FrameworkTags};
+
+/// Source files scanned for framework imports, most important first
+const MAX_SCANNED_SOURCES: usize = 200;
+/// Imports and annotations sit at the top of the files
+const SCANNED_BYTES: usize = 8 * 1024;
+/// Evidence files kept per framework
+const MAX_EVIDENCE: usize = 3;
+
+/// How a framework shows up in manifests and in source code
+struct FrameworkRule {
+    framework: Framework,
+    /// Manifest file names, or extensions starting with `.`
+    manifests: &'static [&'static str],
+    /// Lowercase markers searched in the lowercased manifests
+    manifest_markers: &'static [&'static str],
+    code_extensions: &'static [&'static str],
+    code_markers: &'static [&'static str],
+}
+
+const RULES: [FrameworkRule; 7] = [
+    FrameworkRule {
+        framework: Framework::SpringBoot,
+        manifests: &["pom.xml", "build.gradle", "build.gradle.kts"],
+        manifest_markers: &["spring-boot"],
+        code_extensions: &["java", "kt"],
+        code_markers: &["org.springframework.boot", "@SpringBootApplication"],
+    },
+    FrameworkRule {
+        framework: Framework::Django,
+        manifests: &["requirements.txt", "pyproject.toml", "Pipfile", "setup.py", "setup.cfg"],
+        manifest_markers: &["django"],
+        code_extensions: &["py"],
+        code_markers: &["from django", "import django"],
+    },
+    FrameworkRule {
+        framework: Framework::Rails,
+        manifests: &["Gemfile"],
+        manifest_markers: &["gem \"rails\"", "gem 'rails'"],
+        code_extensions: &["rb"],
+        code_markers: &["Rails.application", "< ApplicationController", "< ActiveRecord::"],
+    },
+    FrameworkRule {
+        framework: Framework::NextJs,
+        manifests: &["package.json"],
+        manifest_markers: &["\"next\":"],
+        code_extensions: &["js", "jsx", "ts", "tsx", "mjs"],
+        code_markers: &["from 'next/", "from \"next/"],
+    },
+    FrameworkRule {
+        framework: Framework::Actix,
+        manifests: &["Cargo.toml"],
+        manifest_markers: &["actix-web"],
+        code_extensions: &["rs"],
+        code_markers: &["actix_web::"],
+    },
+    FrameworkRule {
+        framework: Framework::NestJs,
+        manifests: &["package.json"],
+        manifest_markers: &["\"@nestjs/core\""],
+        code_extensions: &["ts", "js"],
+        code_markers: &["from '@nestjs/", "from \"@nestjs/"],
+    },
+    FrameworkRule {
+        framework: Framework::AspNetCore,
+        manifests: &[".csproj"],
+        manifest_markers: &["microsoft.net.sdk.web", "microsoft.aspnetcore"],
+        code_extensions: &["cs"],
+        code_markers: &["Microsoft.AspNetCore", "WebApplication.CreateBuilder"],
+    },
+];
+
+/// Detect the frameworks of the project from its manifests (at any depth, so monorepo
+/// members count) and from the imports of its most important source files
+pub fn detect(files: &[FileInfo], root: &Path) -> FrameworkTags {
+    let mut detected: Vec<DetectedFramework> = Vec::new();
+    let mut record = |framework: Framework, file: &FileInfo| {
+        match detected.iter_mut().find(|d| d.framework == framework) {
+            Some(existing) => {
+                if existing.evidence.len() < MAX_EVIDENCE && !existing.evidence.contains(&file.path) {
+                    existing.evidence.push(file.path.clone());
+                }
+            }
+            None => detected.push(DetectedFramework {
+                framework,
+                evidence: vec![file.path.clone()],
+            }),
+        }
+    };
+
+    for file in files {
+        let is_manifest = |rule: &FrameworkRule| {
+            rule.manifests.iter().any(|manifest| {
+                file.name == *manifest || (manifest.starts_with('.') && file.name.ends_with(manifest))
+            })
+        };
+        let manifest_rules: Vec<&FrameworkRule> = RULES.iter().filter(|rule| is_manifest(rule)).collect();
+        if manifest_rules.is_empty() {
+            continue;
+        }
+        let Ok(content) = std::fs::read_to_string(root.join(&file.path)) else {
+            continue;
+        };
+        let content = content.to_lowercase();
+        for rule in manifest_rules {
+            if rule.manifest_markers.iter().any(|marker| content.contains(marker)) {
+                record(rule.framework, file);
+            }
+        }
+    }
+
+    let mut sources: Vec<&FileInfo> = files
+        .iter()
+        .filter(|file| {
+            let extension = file.extension.as_deref().unwrap_or_default();
+            RULES.iter().any(|rule| rule.code_extensions.contains(&extension))
+        })
+        .collect();
+    sources.sort_by(|a, b| b.importance_score.total_cmp(&a.importance_score));
+    for file in sources.into_iter().take(MAX_SCANNED_SOURCES) {
+        let Ok(bytes) = std::fs::read(root.join(&file.path)) else {
+            continue;
+        };
+        let head = String::from_utf8_lossy(&bytes[..bytes.len().min(SCANNED_BYTES)]);
+        let extension = file.extension.as_deref().unwrap_or_default();
+        for rule in RULES.iter().filter(|rule| rule.code_extensions.contains(&extension)) {
+            if rule.code_markers.iter().any(|marker| head.contains(marker)) {
+                record(rule.framework, file);
+            }
+        }
+    }
+
+    // Order of the rules, independent of the file order
+    detected.sort_by_key(|d| RULES.iter().position(|rule| rule.framework == d.framework));
+    FrameworkTags { frameworks: detected }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_detect_frameworks() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        let sources = [
+            ("backend/pom.xml", "<artifactId>spring-boot-starter-web</artifactId>"),
+            ("web/package.json", r#"{"dependencies": {"next": "14.2.0", "react": "18"}}"#),
+            ("api/src/main.rs", "use actix_web::{web, App, HttpServer};\nfn main() {}"),
+            ("tools/requirements.txt", "requests==2.31\n"),
+            ("Api/Api.csproj", r#"<Project Sdk="Microsoft.NET.Sdk.Web"></Project>"#),
+        ];
+        let mut files = Vec::new();
+        for (path, content) in sources {
+            let path = PathBuf::from(path);
+            std::fs::create_dir_all(root.join(path.parent().unwrap())).unwrap();
+            std::fs::write(root.join(&path), content).unwrap();
+            files.push(FileInfo {
+                name: path.file_name().unwrap().to_string_lossy().to_string(),
+                extension: path.extension().map(|e| e.to_string_lossy().to_string()),
+                path,
+                size: 0,
+                is_core: false,
+                importance_score: 0.5,
+                complexity_score: 0.0,
+                last_modified: None,
+            });
+        }
+
+        let tags = detect(&files, &root);
+
+        assert_eq!(tags.names(), vec!["Spring Boot", "Next.js", "Actix Web", "ASP.NET Core"]);
+        assert_eq!(tags.frameworks[2].evidence, vec![PathBuf::from("api/src/main.rs")]);
+    }
+}
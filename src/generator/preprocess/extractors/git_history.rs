@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use tracing::warn;
+
+/// Marker prefixing the commit timestamp and author lines of the `git log` output
+const COMMIT_MARKER: &str = "@@commit ";
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+/// Commit activity of a file
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FileHistory {
+    pub commits: usize,
+    /// Unix timestamp of the latest commit touching the file
+    pub last_commit: i64,
+}
+
+/// Commit counts, recency and authors per file, read from the git history of the project
+#[derive(Debug, Default)]
+pub struct GitHistory {
+    /// Histories by path relative to the project root
+    files: HashMap<PathBuf, FileHistory>,
+    /// Commit count of each author, by path relative to the project root
+    authors: HashMap<PathBuf, HashMap<String, usize>>,
+    max_commits: usize,
+}
+
+impl GitHistory {
+    /// Read the commits of the last `days` days; `None` outside a git repository
+    pub fn load(project_path: &Path, days: u32) -> Option<Self> {
+        let output = Command::new("git")
+            .args([
+                "log",
+                &format!("--since={}.days", days),
+                &format!("--format={}%ct %an", COMMIT_MARKER),
+                "--name-only",
+                "--relative",
+                "--no-renames",
+            ])
+            .current_dir(project_path)
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            warn!("⚠️  Failed to read the git history, churn is not used for importance scoring");
+            return None;
+        }
+        Some(Self::parse(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    fn parse(log: &str) -> Self {
+        let mut files: HashMap<PathBuf, FileHistory> = HashMap::new();
+        let mut authors: HashMap<PathBuf, HashMap<String, usize>> = HashMap::new();
+        let mut timestamp = 0;
+        let mut author = "";
+        for line in log.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if let Some(commit) = line.strip_prefix(COMMIT_MARKER) {
+                let (commit_time, commit_author) = commit.split_once(' ').unwrap_or((commit, ""));
+                timestamp = commit_time.parse().unwrap_or(0);
+                author = commit_author.trim();
+                continue;
+            }
+            let path = PathBuf::from(line);
+            if !author.is_empty() {
+                *authors
+                    .entry(path.clone())
+                    .or_default()
+                    .entry(author.to_string())
+                    .or_default() += 1;
+            }
+            let history = files.entry(path).or_insert(FileHistory {
+                commits: 0,
+                last_commit: timestamp,
+            });
+            history.commits += 1;
+            history.last_commit = history.last_commit.max(timestamp);
+        }
+        let max_commits = files.values().map(|history| history.commits).max().unwrap_or(0);
+        Self {
+            files,
+            authors,
+            max_commits,
+        }
+    }
+
+    /// Author of most commits touching the file, ties broken by name
+    pub fn main_author(&self, path: &Path) -> Option<&str> {
+        self.authors
+            .get(path)?
+            .iter()
+            .max_by(|(a, a_commits), (b, b_commits)| a_commits.cmp(b_commits).then_with(|| b.cmp(a)))
+            .map(|(author, _)| author.as_str())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Activity of a file between 0 and 1: the average of its churn, relative to the most
+    /// changed file on a log scale, and the recency of its latest commit, halving every `half_life_days`
+    pub fn activity(&self, path: &Path, now: i64, half_life_days: u32) -> f64 {
+        let Some(history) = self.files.get(path) else {
+            return 0.0;
+        };
+        let churn = (1.0 + history.commits as f64).ln() / (1.0 + self.max_commits as f64).ln();
+        let age_days = (now - history.last_commit).max(0) as f64 / SECONDS_PER_DAY;
+        let recency = 0.5_f64.powf(age_days / half_life_days.max(1) as f64);
+        (churn + recency) / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_activity() {
+        let day = SECONDS_PER_DAY as i64;
+        let now = 1_000 * day;
+        let log = format!(
+            "{m}{} Ada Lovelace\n\nsrc/orders.rs\nsrc/lib.rs\n\n{m}{} Grace Hopper\n\nsrc/orders.rs\n\n{m}{} Grace Hopper\n\nsrc/orders.rs\nsrc/legacy.rs\n",
+            now - day,
+            now - 10 * day,
+            now - 360 * day,
+            m = COMMIT_MARKER
+        );
+
+        let history = GitHistory::parse(&log);
+
+        assert_eq!(
+            history.files[Path::new("src/orders.rs")],
+            FileHistory { commits: 3, last_commit: now - day }
+        );
+        let hotspot = history.activity(Path::new("src/orders.rs"), now, 90);
+        let recent = history.activity(Path::new("src/lib.rs"), now, 90);
+        let stale = history.activity(Path::new("src/legacy.rs"), now, 90);
+        assert!(hotspot > recent && recent > stale);
+        assert!(hotspot > 0.9 && hotspot <= 1.0);
+        assert_eq!(history.activity(Path::new("README.md"), now, 90), 0.0);
+        assert_eq!(history.main_author(Path::new("src/orders.rs")), Some("Grace Hopper"));
+        assert_eq!(history.main_author(Path::new("src/lib.rs")), Some("Ada Lovelace"));
+    }
+}
@@ -0,0 +1,763 @@
+use super::{Dependency, LanguageProcessor};
+use crate::types::code::{InterfaceInfo, ParameterInfo};
+use regex::Regex;
+use std::path::Path;
+
+/// Header extensions; a header is implemented by the source file of the same stem
+pub const HEADER_EXTENSIONS: [&str; 4] = ["h", "hh", "hpp", "hxx"];
+/// Implementation extensions
+pub const SOURCE_EXTENSIONS: [&str; 4] = ["c", "cc", "cpp", "cxx"];
+
+/// Words that precede a parenthesis without naming a function
+const CPP_KEYWORDS: [&str; 16] = [
+    "if", "while", "for", "switch", "return", "sizeof", "alignof", "decltype", "catch", "throw", "new",
+    "delete", "case", "static_assert", "defined", "__attribute__",
+];
+
+/// Declaration specifiers that say nothing about the return type
+const SPECIFIERS: [&str; 10] = [
+    "static", "inline", "virtual", "explicit", "constexpr", "consteval", "extern", "friend", "\"\"", "__forceinline",
+];
+
+#[derive(Debug)]
+pub struct CppProcessor {
+    include_regex: Regex,
+    access_regex: Regex,
+    namespace_regex: Regex,
+    class_regex: Regex,
+    enum_regex: Regex,
+    constructor_init_regex: Regex,
+}
+
+/// Enclosing declaration of a statement
+enum Scope {
+    /// Namespaces and `extern "C"` blocks, whose declarations stay free functions
+    Namespace,
+    /// Class, struct or union, with the visibility of the current access section
+    Class { name: String, visibility: &'static str },
+}
+
+/// What a statement opening a `{` block declares
+enum Opened {
+    Scope(Scope),
+    /// Function bodies, enums and initializers, whose content is not scanned
+    Body,
+}
+
+/// A function declaration or definition
+struct Function {
+    name: String,
+    return_type: Option<String>,
+    parameters: Vec<ParameterInfo>,
+    specifiers: Vec<String>,
+    /// Qualifiers after the parameters: `const`, `override`, `= 0`...
+    qualifiers: String,
+}
+
+impl CppProcessor {
+    pub fn new() -> Self {
+        Self {
+            include_regex: Regex::new(r#"^\s*#\s*(?:include|include_next|import)\s*([<"])([^>"]+)[>"]"#).unwrap(),
+            access_regex: Regex::new(r"^(public|protected|private)(?:\s+(?:slots|Q_SLOTS))?\s*:\s*").unwrap(),
+            namespace_regex: Regex::new(r"^(?:inline\s+)?namespace\b").unwrap(),
+            class_regex: Regex::new(r"^(?:typedef\s+)?(class|struct|union)\b\s*(.*)$").unwrap(),
+            enum_regex: Regex::new(r"^(?:typedef\s+)?enum\b(?:\s+(?:class|struct))?\s+(\w+)").unwrap(),
+            constructor_init_regex: Regex::new(r"\)\s*(?:noexcept\s*)?:[^:]").unwrap(),
+        }
+    }
+}
+
+impl Default for CppProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LanguageProcessor for CppProcessor {
+    fn supported_extensions(&self) -> Vec<&'static str> {
+        HEADER_EXTENSIONS.into_iter().chain(SOURCE_EXTENSIONS).collect()
+    }
+
+    fn extract_dependencies(&self, content: &str, file_path: &Path) -> Vec<Dependency> {
+        let source_file = file_path.to_string_lossy().to_string();
+
+        content
+            .lines()
+            .enumerate()
+            .filter_map(|(line_num, line)| {
+                let captures = self.include_regex.captures(line)?;
+                // `<...>` includes come from the system or library include paths, `"..."` ones
+                // from the project
+                let is_system = &captures[1] == "<";
+                Some(Dependency {
+                    name: source_file.clone(),
+                    path: Some(captures[2].trim().to_string()),
+                    is_external: is_system,
+                    line_number: Some(line_num + 1),
+                    dependency_type: if is_system { "system_include" } else { "include" }.to_string(),
+                    version: None,
+                })
+            })
+            .collect()
+    }
+
+    fn determine_component_type(&self, file_path: &Path, content: &str) -> String {
+        let file_name = file_path.file_name().and_then(|n| n.to_str()).unwrap_or("").to_lowercase();
+        let extension = file_path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        let is_cpp = extension != "c"
+            && (extension != "h" || ["class ", "namespace ", "template", "std::"].iter().any(|k| content.contains(k)));
+        let language = if is_cpp { "cpp" } else { "c" };
+
+        if HEADER_EXTENSIONS.contains(&extension) {
+            return format!("{}_header", language);
+        }
+        if file_name.contains("test") {
+            return format!("{}_test", language);
+        }
+        if content.contains("int main(") || content.contains("int wmain(") {
+            return format!("{}_main", language);
+        }
+        if content.contains("class ") {
+            format!("{}_class", language)
+        } else {
+            format!("{}_source", language)
+        }
+    }
+
+    fn is_important_line(&self, line: &str) -> bool {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("#include")
+            || trimmed.starts_with("#define")
+            || trimmed.starts_with("namespace ")
+            || trimmed.starts_with("template")
+            || trimmed.starts_with("class ")
+            || trimmed.starts_with("struct ")
+            || trimmed.starts_with("enum ")
+            || trimmed.starts_with("typedef ")
+            || trimmed.starts_with("using ")
+            || trimmed.starts_with("extern ")
+            || trimmed.starts_with("virtual ")
+        {
+            return true;
+        }
+
+        if trimmed.contains("TODO") || trimmed.contains("FIXME") || trimmed.contains("NOTE") || trimmed.contains("HACK") {
+            return true;
+        }
+
+        false
+    }
+
+    fn language_name(&self) -> &'static str {
+        "C/C++"
+    }
+
+    fn extract_interfaces(&self, content: &str, _file_path: &Path) -> Vec<InterfaceInfo> {
+        let lines: Vec<&str> = content.lines().collect();
+        let code = Self::strip_code(content);
+        let mut interfaces = Vec::new();
+        let mut scopes: Vec<Scope> = Vec::new();
+        // Nesting inside a function body or initializer, whose content is skipped
+        let mut body_depth = 0;
+        // Braces of a constructor member initializer, `: items{}`, which belong to the signature
+        let mut init_depth = 0;
+        let mut paren_depth = 0;
+        let mut statement = String::new();
+        let mut statement_line = 0;
+        let mut line = 0;
+
+        for c in code.chars() {
+            if c == '\n' {
+                line += 1;
+            }
+            if body_depth > 0 {
+                match c {
+                    '{' => body_depth += 1,
+                    '}' => body_depth -= 1,
+                    _ => {}
+                }
+                continue;
+            }
+            if statement.trim().is_empty() {
+                statement_line = line;
+            }
+            match c {
+                '(' => paren_depth += 1,
+                ')' => paren_depth -= 1,
+                '{' if paren_depth == 0 && init_depth > 0 => init_depth += 1,
+                '{' if paren_depth == 0 && self.opens_initializer(&statement) => init_depth = 1,
+                '{' if paren_depth == 0 => {
+                    match self.declare(&statement, '{', &scopes, &lines, statement_line, &mut interfaces) {
+                        Opened::Scope(scope) => scopes.push(scope),
+                        Opened::Body => body_depth = 1,
+                    }
+                    statement.clear();
+                    continue;
+                }
+                '}' if paren_depth == 0 && init_depth > 0 => init_depth -= 1,
+                '}' if paren_depth == 0 => {
+                    scopes.pop();
+                    statement.clear();
+                    continue;
+                }
+                ';' if paren_depth == 0 => {
+                    self.declare(&statement, ';', &scopes, &lines, statement_line, &mut interfaces);
+                    statement.clear();
+                    continue;
+                }
+                _ => {}
+            }
+            // Access labels end a section rather than a statement
+            statement.push(c);
+            if c == ':'
+                && let Some(captures) = self.access_regex.captures(statement.trim())
+                && captures.get(0).map(|m| m.end()) == Some(statement.trim().len())
+                && let Some(Scope::Class { visibility, .. }) = scopes.last_mut()
+            {
+                *visibility = match &captures[1] {
+                    "public" => "public",
+                    "protected" => "protected",
+                    _ => "private",
+                };
+                statement.clear();
+            }
+        }
+
+        // A definition replaces the prototype declared earlier in the same file
+        let mut unique: Vec<InterfaceInfo> = Vec::new();
+        for interface in interfaces {
+            let key = |i: &InterfaceInfo| (i.name.clone(), i.parameters.iter().map(|p| p.param_type.clone()).collect::<Vec<_>>());
+            match unique.iter_mut().find(|known| key(known) == key(&interface)) {
+                Some(known) => {
+                    if known.interface_type == "function_prototype" {
+                        known.interface_type = interface.interface_type;
+                    }
+                    if known.description.is_none() {
+                        known.description = interface.description;
+                    }
+                }
+                None => unique.push(interface),
+            }
+        }
+        unique
+    }
+}
+
+impl CppProcessor {
+    /// Code with comments, preprocessor lines and the content of string and character literals
+    /// blanked out, keeping the line breaks
+    fn strip_code(content: &str) -> String {
+        let mut code = String::with_capacity(content.len());
+        let mut chars = content.chars().peekable();
+        let mut at_line_start = true;
+        let mut previous = ' ';
+
+        while let Some(c) = chars.next() {
+            match c {
+                '/' if chars.peek() == Some(&'/') => {
+                    while chars.peek().is_some_and(|next| *next != '\n') {
+                        chars.next();
+                    }
+                }
+                '/' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    let mut last = ' ';
+                    for next in chars.by_ref() {
+                        if next == '\n' {
+                            code.push('\n');
+                        }
+                        if last == '*' && next == '/' {
+                            break;
+                        }
+                        last = next;
+                    }
+                }
+                // Preprocessor lines, with their `\` continuations
+                '#' if at_line_start => {
+                    let mut last = ' ';
+                    while let Some(&next) = chars.peek() {
+                        if next == '\n' && last != '\\' {
+                            break;
+                        }
+                        if next == '\n' {
+                            code.push('\n');
+                        }
+                        if !next.is_whitespace() {
+                            last = next;
+                        }
+                        chars.next();
+                    }
+                }
+                // A quote after a digit separates digits, as in `1'000`
+                '"' | '\'' if !(c == '\'' && previous.is_ascii_alphanumeric()) => {
+                    code.push(c);
+                    while let Some(next) = chars.next() {
+                        if next == '\\' {
+                            chars.next();
+                        } else if next == c || next == '\n' {
+                            code.push(next);
+                            break;
+                        }
+                    }
+                }
+                _ => code.push(c),
+            }
+            if c == '\n' {
+                at_line_start = true;
+            } else if !c.is_whitespace() {
+                at_line_start = false;
+            }
+            previous = c;
+        }
+        code
+    }
+
+    /// Whether a `{` continues the member initializer list of a constructor, as in
+    /// `Cache() : items{}, size(0) {`
+    fn opens_initializer(&self, statement: &str) -> bool {
+        self.constructor_init_regex.is_match(statement)
+            && statement
+                .trim_end()
+                .chars()
+                .last()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '>')
+    }
+
+    /// Record the declaration made by a statement ending with `;` or `{`, and return what a
+    /// `{` opens
+    fn declare(
+        &self,
+        statement: &str,
+        terminator: char,
+        scopes: &[Scope],
+        lines: &[&str],
+        line: usize,
+        interfaces: &mut Vec<InterfaceInfo>,
+    ) -> Opened {
+        let mut text = statement.split_whitespace().collect::<Vec<_>>().join(" ");
+        while let Some(end) = self.access_regex.find(&text).map(|m| m.end()) {
+            text = text[end..].to_string();
+        }
+        let mut details = Vec::new();
+        if let Some(rest) = text.strip_prefix("template") {
+            let rest = rest.trim_start();
+            if let Some(parameters) = Self::take_balanced(rest, '<', '>') {
+                details.push(format!("Template: {}", parameters));
+                text = rest[parameters.len()..].trim_start().to_string();
+            }
+        }
+        let is_template = !details.is_empty();
+        let class = scopes.iter().rev().find_map(|scope| match scope {
+            Scope::Class { name, visibility } => Some((name.as_str(), *visibility)),
+            Scope::Namespace => None,
+        });
+        let in_class = matches!(scopes.last(), Some(Scope::Class { .. }));
+
+        if terminator == '{' {
+            if self.namespace_regex.is_match(&text) || text == "extern \"\"" {
+                return Opened::Scope(Scope::Namespace);
+            }
+            if let Some(captures) = self.class_regex.captures(&text) {
+                let kind = captures[1].to_string();
+                let (head, bases) = Self::split_bases(&captures[2]);
+                let name = head
+                    .split_whitespace()
+                    .rfind(|word| *word != "final" && !word.starts_with("[[") && !word.starts_with("alignas"))
+                    .unwrap_or("")
+                    .to_string();
+                let qualified = match class {
+                    Some((outer, _)) if !name.is_empty() => format!("{}::{}", outer, name),
+                    _ => name.clone(),
+                };
+                if let Some(bases) = bases {
+                    details.push(format!("Bases: {}", bases));
+                }
+                if !name.is_empty() {
+                    interfaces.push(InterfaceInfo {
+                        name: qualified.clone(),
+                        interface_type: if is_template { format!("{}_template", kind) } else { kind.clone() },
+                        visibility: class.map_or("public", |(_, visibility)| visibility).to_string(),
+                        parameters: Vec::new(),
+                        return_type: None,
+                        description: Self::describe(lines, line, details),
+                    });
+                }
+                return Opened::Scope(Scope::Class {
+                    name: qualified,
+                    visibility: if kind == "class" { "private" } else { "public" },
+                });
+            }
+            if let Some(captures) = self.enum_regex.captures(&text) {
+                interfaces.push(InterfaceInfo {
+                    name: match class {
+                        Some((outer, _)) => format!("{}::{}", outer, &captures[1]),
+                        None => captures[1].to_string(),
+                    },
+                    interface_type: "enum".to_string(),
+                    visibility: class.map_or("public", |(_, visibility)| visibility).to_string(),
+                    parameters: Vec::new(),
+                    return_type: None,
+                    description: Self::describe(lines, line, Vec::new()),
+                });
+                return Opened::Body;
+            }
+        }
+
+        if ["typedef ", "using ", "friend ", "static_assert"].iter().any(|prefix| text.starts_with(prefix)) {
+            return Opened::Body;
+        }
+        let Some(function) = Self::parse_function(&text, class.filter(|_| in_class).map(|(name, _)| name)) else {
+            return Opened::Body;
+        };
+        if function.qualifiers.contains("= delete") {
+            return Opened::Body;
+        }
+
+        let last_segment = function.name.rsplit("::").next().unwrap_or("");
+        let owner = function.name.rsplit("::").nth(1);
+        let is_virtual = function.specifiers.iter().any(|s| s == "virtual")
+            || function.qualifiers.contains("override")
+            || function.qualifiers.contains("= 0");
+        if function.qualifiers.contains("= 0") {
+            details.push("Pure virtual".to_string());
+        }
+        let interface_type = if last_segment.starts_with('~') {
+            "destructor"
+        } else if owner.is_some_and(|owner| Self::base_name(owner) == last_segment) {
+            "constructor"
+        } else if in_class || owner.is_some() {
+            if function.specifiers.iter().any(|s| s == "static") && in_class {
+                "static_method"
+            } else if is_virtual {
+                "virtual_method"
+            } else {
+                "method"
+            }
+        } else if is_template {
+            "function_template"
+        } else if terminator == ';' {
+            "function_prototype"
+        } else {
+            "function"
+        };
+        // Free functions declared `static` are private to their translation unit
+        let visibility = match class.filter(|_| in_class) {
+            Some((_, visibility)) => visibility,
+            None if function.specifiers.iter().any(|s| s == "static") => "private",
+            None => "public",
+        };
+
+        interfaces.push(InterfaceInfo {
+            name: function.name,
+            interface_type: interface_type.to_string(),
+            visibility: visibility.to_string(),
+            parameters: function.parameters,
+            return_type: function.return_type,
+            description: Self::describe(lines, line, details),
+        });
+        Opened::Body
+    }
+
+    /// Parse a function declaration; members are qualified with the name of their class
+    fn parse_function(text: &str, class: Option<&str>) -> Option<Function> {
+        let mut open = text.find('(')?;
+        if text[..open].trim_end().ends_with("operator") && text[open..].starts_with("()") {
+            open += 2 + text[open + 2..].find('(')?;
+        }
+        let head = text[..open].trim_end();
+        let is_operator = head.contains("operator");
+        if head.contains('=') && !is_operator {
+            return None;
+        }
+
+        // The name is the trailing `Type::name`, or `Type::operator==`
+        let name_end = head.len();
+        let name_start = match head.rfind("operator").filter(|_| is_operator) {
+            Some(at) => head[..at].rfind(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':')).map_or(0, |i| i + 1),
+            None => head.rfind(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':' || c == '~')).map_or(0, |i| i + 1),
+        };
+        let name = head[name_start..name_end].trim().to_string();
+        let last_segment = name.rsplit("::").next().unwrap_or("");
+        if last_segment.is_empty() || CPP_KEYWORDS.contains(&last_segment) || last_segment.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let mut specifiers = Vec::new();
+        let return_words: Vec<&str> = head[..name_start]
+            .split_whitespace()
+            .filter(|word| {
+                let is_specifier = SPECIFIERS.contains(word) || word.starts_with("[[");
+                if is_specifier {
+                    specifiers.push(word.to_string());
+                }
+                !is_specifier
+            })
+            .collect();
+        let mut return_type = return_words.join(" ");
+        if return_type.contains(['.', '"', '!', '|', '+', '-']) {
+            return None;
+        }
+
+        let parameters_text = Self::take_balanced(&text[open..], '(', ')')?;
+        let qualifiers = text[open + parameters_text.len()..].trim().to_string();
+        if let Some(trailing) = qualifiers.split("->").nth(1) {
+            return_type = trailing
+                .split([' ', '{'])
+                .filter(|word| !["override", "final", "const", "noexcept"].contains(word))
+                .collect::<Vec<_>>()
+                .join(" ")
+                .trim()
+                .to_string();
+        }
+
+        // Without a return type only constructors and destructors are functions, not macro calls
+        let is_special = last_segment.starts_with('~')
+            || class.is_some_and(|class| Self::base_name(class) == last_segment)
+            || name.rsplit("::").nth(1).is_some_and(|owner| Self::base_name(owner) == last_segment);
+        if return_type.is_empty() && !is_special {
+            return None;
+        }
+        let parameters = Self::parse_parameters(&parameters_text[1..parameters_text.len() - 1])?;
+
+        let name = match class {
+            Some(class) if !name.contains("::") => format!("{}::{}", class, name),
+            _ => name,
+        };
+        Some(Function {
+            name,
+            return_type: if return_type.is_empty() || return_type == "void" { None } else { Some(return_type) },
+            parameters,
+            specifiers,
+            qualifiers,
+        })
+    }
+
+    /// Parse a parameter list; `None` when it holds values rather than declarations, as in the
+    /// variable `Widget widget(1, "name");`
+    fn parse_parameters(text: &str) -> Option<Vec<ParameterInfo>> {
+        let mut parameters = Vec::new();
+        for param in Self::split_top_level(text) {
+            let param = param.trim();
+            if param.is_empty() || param == "void" {
+                continue;
+            }
+            if param.starts_with(|c: char| c.is_ascii_digit() || c == '"' || c == '\'' || c == '-') || param.contains('.') && param != "..." {
+                return None;
+            }
+            let (declaration, is_optional) = match param.split_once('=') {
+                Some((declaration, _)) => (declaration.trim(), true),
+                None => (param, false),
+            };
+            // Function pointers name the parameter inside the parentheses: `void (*callback)(int)`
+            let (name, param_type) = if let Some(start) = declaration.find("(*") {
+                let name_end = declaration[start..].find(')').map_or(declaration.len(), |end| start + end);
+                (declaration[start + 2..name_end].trim().to_string(), declaration.to_string())
+            } else {
+                let (declaration, array) = match declaration.find('[') {
+                    Some(at) => (declaration[..at].trim_end(), &declaration[at..]),
+                    None => (declaration, ""),
+                };
+                let split = declaration.rfind(|c: char| !(c.is_alphanumeric() || c == '_')).map_or(0, |i| i + 1);
+                let (param_type, name) = declaration.split_at(split);
+                if param_type.trim().is_empty() || ["int", "char", "short", "long", "float", "double", "bool", "unsigned", "signed"].contains(&name) {
+                    (String::new(), format!("{}{}", declaration, array))
+                } else {
+                    (name.to_string(), format!("{}{}", param_type.trim(), array))
+                }
+            };
+            parameters.push(ParameterInfo {
+                name,
+                param_type,
+                is_optional,
+                description: None,
+            });
+        }
+        Some(parameters)
+    }
+
+    /// Class name and base clause of a class head such as `EXPORT Circle final : public Shape`
+    fn split_bases(head: &str) -> (&str, Option<String>) {
+        let bytes = head.as_bytes();
+        for (at, c) in head.char_indices() {
+            let after = bytes.get(at + 1).copied();
+            let before = at.checked_sub(1).map(|i| bytes[i]);
+            if c == ':' && after != Some(b':') && before != Some(b':') {
+                let bases = head[at + 1..].trim();
+                return (head[..at].trim(), (!bases.is_empty()).then(|| bases.to_string()));
+            }
+        }
+        (head.trim(), None)
+    }
+
+    /// Class name without its namespace and template arguments
+    fn base_name(name: &str) -> &str {
+        let name = name.rsplit("::").next().unwrap_or(name);
+        name.split('<').next().unwrap_or(name)
+    }
+
+    /// The text from its first character to the matching closing character, inclusive
+    fn take_balanced(text: &str, open: char, close: char) -> Option<String> {
+        if !text.starts_with(open) {
+            return None;
+        }
+        let mut depth = 0;
+        for (at, c) in text.char_indices() {
+            if c == open {
+                depth += 1;
+            } else if c == close {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(text[..=at].to_string());
+                }
+            }
+        }
+        None
+    }
+
+    /// Split on commas outside of brackets, so `std::map<K, V>` stays one parameter
+    fn split_top_level(text: &str) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let mut depth = 0;
+        let mut start = 0;
+        for (at, c) in text.char_indices() {
+            match c {
+                '<' | '(' | '[' | '{' => depth += 1,
+                '>' | ')' | ']' | '}' => depth -= 1,
+                ',' if depth == 0 => {
+                    parts.push(&text[start..at]);
+                    start = at + 1;
+                }
+                _ => {}
+            }
+        }
+        parts.push(&text[start..]);
+        parts
+    }
+
+    /// Doc comment above the declaration, followed by the extracted details
+    fn describe(lines: &[&str], current_line: usize, details: Vec<String>) -> Option<String> {
+        let mut doc_lines = Vec::new();
+        for line in lines[..current_line.min(lines.len())].iter().rev().map(|line| line.trim()) {
+            let text = if let Some(text) = line.strip_prefix("//") {
+                text.trim_start_matches(['/', '!'])
+            } else if line.starts_with("/*") || line.starts_with('*') {
+                line.trim_start_matches(['/', '*', '!']).trim_end_matches('/').trim_end_matches('*')
+            } else {
+                break;
+            };
+            let text = text.trim();
+            if !text.is_empty() && !text.starts_with('@') {
+                doc_lines.insert(0, text.to_string());
+            }
+        }
+        let parts: Vec<String> = (!doc_lines.is_empty())
+            .then(|| doc_lines.join(" "))
+            .into_iter()
+            .chain(details)
+            .collect();
+        if parts.is_empty() { None } else { Some(parts.join(" ")) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'a>(interfaces: &'a [InterfaceInfo], name: &str) -> &'a InterfaceInfo {
+        interfaces
+            .iter()
+            .find(|interface| interface.name == name)
+            .unwrap_or_else(|| panic!("{} not extracted from {:?}", name, interfaces.iter().map(|i| &i.name).collect::<Vec<_>>()))
+    }
+
+    #[test]
+    fn test_extract_includes_classes_templates_and_prototypes() {
+        let processor = CppProcessor::new();
+        let header = r#"#include <vector>
+#include "codec/buffer.h"
+#define CHECK(x) \
+    if (!(x)) { abort(); }
+
+namespace media {
+
+/// Decodes frames
+class Decoder : public Codec<Frame>, private NonCopyable {
+public:
+    explicit Decoder(const std::string& name, int threads = 1);
+    virtual ~Decoder();
+    /// Decode one packet
+    virtual Frame decode(const Packet& packet) override;
+    static Decoder* create(void (*log)(const char*));
+    Decoder(const Decoder&) = delete;
+protected:
+    virtual void flush() = 0;
+private:
+    std::vector<int> buffer_{};
+    struct State { int pts; };
+};
+
+template <typename T, size_t N>
+T checksum(const std::array<T, N>& data);
+
+}  // namespace media
+
+extern "C" {
+int codec_open(const char* path, unsigned flags);
+}
+"#;
+        let dependencies = processor.extract_dependencies(header, Path::new("include/decoder.h"));
+        assert_eq!(dependencies.len(), 2);
+        assert_eq!((dependencies[0].path.as_deref(), dependencies[0].is_external), (Some("vector"), true));
+        assert_eq!(dependencies[0].dependency_type, "system_include");
+        assert_eq!((dependencies[1].path.as_deref(), dependencies[1].is_external), (Some("codec/buffer.h"), false));
+
+        let interfaces = processor.extract_interfaces(header, Path::new("include/decoder.h"));
+        let decoder = find(&interfaces, "Decoder");
+        assert_eq!(decoder.interface_type, "class");
+        assert_eq!(decoder.description.as_deref(), Some("Decodes frames Bases: public Codec<Frame>, private NonCopyable"));
+        let constructor = find(&interfaces, "Decoder::Decoder");
+        assert_eq!(constructor.interface_type, "constructor");
+        assert_eq!((constructor.parameters[1].name.as_str(), constructor.parameters[1].is_optional), ("threads", true));
+        assert_eq!(find(&interfaces, "Decoder::~Decoder").interface_type, "destructor");
+        let decode = find(&interfaces, "Decoder::decode");
+        assert_eq!((decode.interface_type.as_str(), decode.visibility.as_str()), ("virtual_method", "public"));
+        assert_eq!(decode.return_type.as_deref(), Some("Frame"));
+        assert_eq!(decode.parameters[0].param_type, "const Packet&");
+        assert_eq!(decode.description.as_deref(), Some("Decode one packet"));
+        assert_eq!(find(&interfaces, "Decoder::create").parameters[0].name, "log");
+        let flush = find(&interfaces, "Decoder::flush");
+        assert_eq!((flush.visibility.as_str(), flush.description.as_deref()), ("protected", Some("Pure virtual")));
+        assert_eq!(find(&interfaces, "Decoder::State").visibility, "private");
+        assert_eq!(interfaces.iter().filter(|i| i.name == "Decoder::Decoder").count(), 1);
+        let checksum = find(&interfaces, "checksum");
+        assert_eq!(checksum.interface_type, "function_template");
+        assert_eq!(checksum.description.as_deref(), Some("Template: <typename T, size_t N>"));
+        let open = find(&interfaces, "codec_open");
+        assert_eq!((open.interface_type.as_str(), open.parameters.len()), ("function_prototype", 2));
+        assert!(interfaces.iter().all(|i| i.name != "CHECK" && i.name != "abort"));
+
+        let source = r#"#include "decoder.h"
+
+static int clamp(int value);
+
+Decoder::Decoder(const std::string& name, int threads) : name_{name}, threads_(threads) {
+    if (threads > 8) { clamp(threads); }
+}
+
+static int clamp(int value) { return value < 0 ? 0 : value; }
+
+int main(int argc, char** argv) {
+    Decoder decoder("main", 2);
+    return 0;
+}
+"#;
+        let interfaces = processor.extract_interfaces(source, Path::new("src/decoder.cpp"));
+        let names: Vec<&str> = interfaces.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, ["clamp", "Decoder::Decoder", "main"]);
+        assert_eq!((interfaces[0].interface_type.as_str(), interfaces[0].visibility.as_str()), ("function", "private"));
+        assert_eq!(interfaces[1].interface_type, "constructor");
+        assert_eq!(interfaces[2].parameters[1].param_type, "char**");
+        assert_eq!(processor.determine_component_type(Path::new("src/decoder.cpp"), source), "cpp_main");
+    }
+}
@@ -32,9 +32,15 @@ impl CSharpProcessor {
     }
 }
 
+impl Default for CSharpProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl LanguageProcessor for CSharpProcessor {
     fn supported_extensions(&self) -> Vec<&'static str> {
-        vec!["cs", "csproj", "sln", "sqlproj", "sql"]
+        vec!["cs", "csproj", "sln", "sqlproj"]
     }
     
     fn extract_dependencies(&self, content: &str, file_path: &Path) -> Vec<Dependency> {
@@ -56,11 +62,6 @@ impl LanguageProcessor for CSharpProcessor {
             return self.extract_sln_dependencies(content, &source_file);
         }
         
-        // Handle .sql files
-        if file_path.extension().and_then(|e| e.to_str()) == Some("sql") {
-            return self.extract_sql_dependencies(content, &source_file);
-        }
-        
         // Handle .cs files
         for (line_num, line) in content.lines().enumerate() {
             // Extract using statements
@@ -139,22 +140,6 @@ impl LanguageProcessor for CSharpProcessor {
             return "csharp_solution".to_string();
         }
         
-        // Check for SQL files
-        if file_name.ends_with(".sql") {
-            if content.to_uppercase().contains("CREATE TABLE") || content.to_uppercase().contains("ALTER TABLE") {
-                return "sql_table_definition".to_string();
-            } else if content.to_uppercase().contains("CREATE PROCEDURE") || content.to_uppercase().contains("ALTER PROCEDURE") {
-                return "sql_stored_procedure".to_string();
-            } else if content.to_uppercase().contains("CREATE VIEW") || content.to_uppercase().contains("ALTER VIEW") {
-                return "sql_view".to_string();
-            } else if content.to_uppercase().contains("CREATE FUNCTION") || content.to_uppercase().contains("ALTER FUNCTION") {
-                return "sql_function".to_string();
-            } else if content.to_uppercase().contains("CREATE TRIGGER") {
-                return "sql_trigger".to_string();
-            }
-            return "sql_script".to_string();
-        }
-        
         // Check for test files
         if file_name.ends_with("Test.cs") || file_name.ends_with("Tests.cs") ||
            content.contains("[Test]") || content.contains("[TestMethod]") {
@@ -222,10 +207,6 @@ impl LanguageProcessor for CSharpProcessor {
             .and_then(|n| n.to_str())
             .unwrap_or("");
         
-        if file_name.ends_with(".sql") {
-            return self.extract_sql_interfaces(content);
-        }
-        
         if file_name.ends_with(".sqlproj") {
             return self.extract_sqlproj_interfaces(content);
         }
@@ -634,161 +615,6 @@ impl CSharpProcessor {
         }
     }
     
-    /// Extract interfaces from SQL files (tables, views, stored procedures, functions, triggers)
-    fn extract_sql_interfaces(&self, content: &str) -> Vec<InterfaceInfo> {
-        let mut interfaces = Vec::new();
-        let lines: Vec<&str> = content.lines().collect();
-        
-        // Regex patterns for SQL objects
-        let create_table_re = regex::Regex::new(r"(?i)CREATE\s+TABLE\s+(?:\[?(\w+)\]?\.)?\[?(\w+)\]?").unwrap();
-        let alter_table_re = regex::Regex::new(r"(?i)ALTER\s+TABLE\s+(?:\[?(\w+)\]?\.)?\[?(\w+)\]?").unwrap();
-        let create_view_re = regex::Regex::new(r"(?i)CREATE\s+(?:OR\s+ALTER\s+)?VIEW\s+(?:\[?(\w+)\]?\.)?\[?(\w+)\]?").unwrap();
-        let create_proc_re = regex::Regex::new(r"(?i)CREATE\s+(?:OR\s+ALTER\s+)?PROC(?:EDURE)?\s+(?:\[?(\w+)\]?\.)?\[?(\w+)\]?").unwrap();
-        let create_func_re = regex::Regex::new(r"(?i)CREATE\s+(?:OR\s+ALTER\s+)?FUNCTION\s+(?:\[?(\w+)\]?\.)?\[?(\w+)\]?").unwrap();
-        let create_trigger_re = regex::Regex::new(r"(?i)CREATE\s+(?:OR\s+ALTER\s+)?TRIGGER\s+(?:\[?(\w+)\]?\.)?\[?(\w+)\]?").unwrap();
-        let create_index_re = regex::Regex::new(r"(?i)CREATE\s+(?:UNIQUE\s+)?(?:CLUSTERED\s+|NONCLUSTERED\s+)?INDEX\s+\[?(\w+)\]?\s+ON\s+(?:\[?(\w+)\]?\.)?\[?(\w+)\]?").unwrap();
-        let create_type_re = regex::Regex::new(r"(?i)CREATE\s+TYPE\s+(?:\[?(\w+)\]?\.)?\[?(\w+)\]?").unwrap();
-        
-        for (i, line) in lines.iter().enumerate() {
-            let line_content = *line;
-            
-            // Extract table definitions
-            if let Some(captures) = create_table_re.captures(line_content) {
-                let schema = captures.get(1).map(|m| m.as_str()).unwrap_or("dbo");
-                let name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
-                
-                // Extract columns from CREATE TABLE
-                let columns = self.extract_sql_columns(&lines, i);
-                
-                interfaces.push(InterfaceInfo {
-                    name: format!("{}.{}", schema, name),
-                    interface_type: "sql_table".to_string(),
-                    visibility: "public".to_string(),
-                    parameters: columns,
-                    return_type: None,
-                    description: self.extract_sql_comment(&lines, i),
-                });
-            }
-            
-            // Extract ALTER TABLE (for modifications)
-            if let Some(captures) = alter_table_re.captures(line_content) {
-                let schema = captures.get(1).map(|m| m.as_str()).unwrap_or("dbo");
-                let name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
-                
-                interfaces.push(InterfaceInfo {
-                    name: format!("{}.{}", schema, name),
-                    interface_type: "sql_table_alter".to_string(),
-                    visibility: "public".to_string(),
-                    parameters: Vec::new(),
-                    return_type: None,
-                    description: self.extract_sql_comment(&lines, i),
-                });
-            }
-            
-            // Extract view definitions
-            if let Some(captures) = create_view_re.captures(line_content) {
-                let schema = captures.get(1).map(|m| m.as_str()).unwrap_or("dbo");
-                let name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
-                
-                interfaces.push(InterfaceInfo {
-                    name: format!("{}.{}", schema, name),
-                    interface_type: "sql_view".to_string(),
-                    visibility: "public".to_string(),
-                    parameters: Vec::new(),
-                    return_type: None,
-                    description: self.extract_sql_comment(&lines, i),
-                });
-            }
-            
-            // Extract stored procedure definitions
-            if let Some(captures) = create_proc_re.captures(line_content) {
-                let schema = captures.get(1).map(|m| m.as_str()).unwrap_or("dbo");
-                let name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
-                
-                // Extract parameters
-                let params = self.extract_sql_proc_parameters(&lines, i);
-                
-                interfaces.push(InterfaceInfo {
-                    name: format!("{}.{}", schema, name),
-                    interface_type: "sql_stored_procedure".to_string(),
-                    visibility: "public".to_string(),
-                    parameters: params,
-                    return_type: None,
-                    description: self.extract_sql_comment(&lines, i),
-                });
-            }
-            
-            // Extract function definitions
-            if let Some(captures) = create_func_re.captures(line_content) {
-                let schema = captures.get(1).map(|m| m.as_str()).unwrap_or("dbo");
-                let name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
-                
-                // Extract parameters
-                let params = self.extract_sql_proc_parameters(&lines, i);
-                
-                // Try to extract return type
-                let return_type = self.extract_sql_function_return_type(&lines, i);
-                
-                interfaces.push(InterfaceInfo {
-                    name: format!("{}.{}", schema, name),
-                    interface_type: "sql_function".to_string(),
-                    visibility: "public".to_string(),
-                    parameters: params,
-                    return_type,
-                    description: self.extract_sql_comment(&lines, i),
-                });
-            }
-            
-            // Extract trigger definitions
-            if let Some(captures) = create_trigger_re.captures(line_content) {
-                let schema = captures.get(1).map(|m| m.as_str()).unwrap_or("dbo");
-                let name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
-                
-                interfaces.push(InterfaceInfo {
-                    name: format!("{}.{}", schema, name),
-                    interface_type: "sql_trigger".to_string(),
-                    visibility: "public".to_string(),
-                    parameters: Vec::new(),
-                    return_type: None,
-                    description: self.extract_sql_comment(&lines, i),
-                });
-            }
-            
-            // Extract index definitions
-            if let Some(captures) = create_index_re.captures(line_content) {
-                let index_name = captures.get(1).map(|m| m.as_str()).unwrap_or("").to_string();
-                let schema = captures.get(2).map(|m| m.as_str()).unwrap_or("dbo");
-                let table_name = captures.get(3).map(|m| m.as_str()).unwrap_or("");
-                
-                interfaces.push(InterfaceInfo {
-                    name: format!("{} ON {}.{}", index_name, schema, table_name),
-                    interface_type: "sql_index".to_string(),
-                    visibility: "public".to_string(),
-                    parameters: Vec::new(),
-                    return_type: None,
-                    description: self.extract_sql_comment(&lines, i),
-                });
-            }
-            
-            // Extract user-defined types
-            if let Some(captures) = create_type_re.captures(line_content) {
-                let schema = captures.get(1).map(|m| m.as_str()).unwrap_or("dbo");
-                let name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
-                
-                interfaces.push(InterfaceInfo {
-                    name: format!("{}.{}", schema, name),
-                    interface_type: "sql_type".to_string(),
-                    visibility: "public".to_string(),
-                    parameters: Vec::new(),
-                    return_type: None,
-                    description: self.extract_sql_comment(&lines, i),
-                });
-            }
-        }
-        
-        interfaces
-    }
-    
     /// Extract interfaces from .sqlproj files
     fn extract_sqlproj_interfaces(&self, content: &str) -> Vec<InterfaceInfo> {
         let mut interfaces = Vec::new();
@@ -849,138 +675,6 @@ impl CSharpProcessor {
         interfaces
     }
     
-    /// Extract column definitions from CREATE TABLE
-    fn extract_sql_columns(&self, lines: &[&str], start_line: usize) -> Vec<ParameterInfo> {
-        let mut columns = Vec::new();
-        let column_re = regex::Regex::new(r"(?i)^\s*\[?(\w+)\]?\s+([\w\(\),\s]+?)(?:\s+(?:NOT\s+)?NULL|\s+PRIMARY\s+KEY|\s+IDENTITY|\s+DEFAULT|\s*,|\s*\))").unwrap();
-        
-        // Look for columns in the following lines until we hit a closing paren or GO
-        for i in (start_line + 1)..lines.len().min(start_line + 50) {
-            let line = lines[i].trim();
-            
-            if line.starts_with(')') || line.to_uppercase().starts_with("GO") || line.to_uppercase().starts_with("CREATE") {
-                break;
-            }
-            
-            // Skip constraint definitions
-            if line.to_uppercase().starts_with("CONSTRAINT") || 
-               line.to_uppercase().starts_with("PRIMARY KEY") ||
-               line.to_uppercase().starts_with("FOREIGN KEY") ||
-               line.to_uppercase().starts_with("UNIQUE") ||
-               line.to_uppercase().starts_with("CHECK") {
-                continue;
-            }
-            
-            if let Some(captures) = column_re.captures(line) {
-                let name = captures.get(1).map(|m| m.as_str()).unwrap_or("").to_string();
-                let data_type = captures.get(2).map(|m| m.as_str().trim()).unwrap_or("").to_string();
-                
-                if !name.is_empty() && !data_type.is_empty() {
-                    columns.push(ParameterInfo {
-                        name,
-                        param_type: data_type,
-                        is_optional: false,
-                        description: None,
-                    });
-                }
-            }
-        }
-        
-        columns
-    }
-    
-    /// Extract parameters from stored procedure or function
-    fn extract_sql_proc_parameters(&self, lines: &[&str], start_line: usize) -> Vec<ParameterInfo> {
-        let mut params = Vec::new();
-        let param_re = regex::Regex::new(r"(?i)@(\w+)\s+([\w\(\),\s]+?)(?:\s*=\s*([^,\n]+))?(?:\s*,|\s*\)|\s*$|\s+AS|\s+WITH)").unwrap();
-        
-        // Collect lines until AS keyword
-        let mut param_section = String::new();
-        for i in start_line..lines.len().min(start_line + 30) {
-            let line = lines[i];
-            param_section.push_str(line);
-            param_section.push(' ');
-            
-            if line.to_uppercase().contains(" AS ") || line.to_uppercase().trim() == "AS" {
-                break;
-            }
-        }
-        
-        for captures in param_re.captures_iter(&param_section) {
-            let name = captures.get(1).map(|m| m.as_str()).unwrap_or("").to_string();
-            let data_type = captures.get(2).map(|m| m.as_str().trim()).unwrap_or("").to_string();
-            let default = captures.get(3).map(|m| m.as_str().trim().to_string());
-            
-            if !name.is_empty() {
-                params.push(ParameterInfo {
-                    name: format!("@{}", name),
-                    param_type: data_type,
-                    is_optional: default.is_some(),
-                    description: default,
-                });
-            }
-        }
-        
-        params
-    }
-    
-    /// Extract return type from SQL function
-    fn extract_sql_function_return_type(&self, lines: &[&str], start_line: usize) -> Option<String> {
-        let returns_re = regex::Regex::new(r"(?i)RETURNS\s+([\w\(\),\s]+?)(?:\s+AS|\s+WITH|\s+BEGIN)").unwrap();
-        
-        // Look for RETURNS keyword
-        for i in start_line..lines.len().min(start_line + 20) {
-            let line = lines[i];
-            if let Some(captures) = returns_re.captures(line) {
-                return captures.get(1).map(|m| m.as_str().trim().to_string());
-            }
-        }
-        
-        None
-    }
-    
-    /// Extract SQL comment (-- or /* */) preceding a statement
-    fn extract_sql_comment(&self, lines: &[&str], line_index: usize) -> Option<String> {
-        let mut comments = Vec::new();
-        
-        // Look backwards for comments
-        for i in (0..line_index).rev() {
-            let line = lines[i].trim();
-            
-            if line.starts_with("--") {
-                comments.insert(0, line[2..].trim().to_string());
-            } else if line.ends_with("*/") {
-                // Multi-line comment - find the start
-                let mut comment_text = String::new();
-                for j in (0..=i).rev() {
-                    let comment_line = lines[j].trim();
-                    if comment_line.starts_with("/*") {
-                        comment_text = comment_line[2..].trim_end_matches("*/").trim().to_string();
-                        break;
-                    } else if comment_line.ends_with("*/") {
-                        comment_text = comment_line.trim_end_matches("*/").trim().to_string();
-                    } else {
-                        comment_text = format!("{} {}", comment_line, comment_text);
-                    }
-                }
-                if !comment_text.is_empty() {
-                    return Some(comment_text.trim().to_string());
-                }
-                break;
-            } else if line.is_empty() || line.to_uppercase().starts_with("GO") {
-                continue;
-            } else {
-                break;
-            }
-        }
-        
-        if comments.is_empty() {
-            None
-        } else {
-            Some(comments.join(" "))
-        }
-    }
-    
     /// Extract dependencies from .sqlproj files (SQL project references and build items)
     fn extract_sqlproj_dependencies(&self, content: &str, source_file: &str) -> Vec<Dependency> {
         let mut dependencies = Vec::new();
@@ -1079,165 +773,4 @@ impl CSharpProcessor {
         
         dependencies
     }
-    
-    /// Extract dependencies from .sql files (table references, stored procedure calls, etc.)
-    fn extract_sql_dependencies(&self, content: &str, source_file: &str) -> Vec<Dependency> {
-        let mut dependencies = Vec::new();
-        
-        for (line_num, line) in content.lines().enumerate() {
-            let upper_line = line.to_uppercase();
-            let trimmed = line.trim();
-            
-            // Skip comments
-            if trimmed.starts_with("--") || trimmed.starts_with("/*") {
-                continue;
-            }
-            
-            // Extract table references from FROM clause
-            if upper_line.contains(" FROM ") {
-                if let Some(from_pos) = upper_line.find(" FROM ") {
-                    let after_from = &line[from_pos + 6..];
-                    let table_part = after_from
-                        .split_whitespace()
-                        .next()
-                        .unwrap_or("")
-                        .trim_matches(|c: char| !c.is_alphanumeric() && c != '.' && c != '_' && c != '[' && c != ']');
-                    
-                    if !table_part.is_empty() {
-                        dependencies.push(Dependency {
-                            name: table_part.to_string(),
-                            path: Some(source_file.to_string()),
-                            is_external: false,
-                            line_number: Some(line_num + 1),
-                            dependency_type: "table_reference".to_string(),
-                            version: None,
-                        });
-                    }
-                }
-            }
-            
-            // Extract table references from JOIN clause
-            if upper_line.contains(" JOIN ") {
-                if let Some(join_pos) = upper_line.find(" JOIN ") {
-                    let after_join = &line[join_pos + 6..];
-                    let table_part = after_join
-                        .split_whitespace()
-                        .next()
-                        .unwrap_or("")
-                        .trim_matches(|c: char| !c.is_alphanumeric() && c != '.' && c != '_' && c != '[' && c != ']');
-                    
-                    if !table_part.is_empty() {
-                        dependencies.push(Dependency {
-                            name: table_part.to_string(),
-                            path: Some(source_file.to_string()),
-                            is_external: false,
-                            line_number: Some(line_num + 1),
-                            dependency_type: "table_reference".to_string(),
-                            version: None,
-                        });
-                    }
-                }
-            }
-            
-            // Extract table references from INSERT INTO
-            if upper_line.contains("INSERT INTO ") {
-                if let Some(insert_pos) = upper_line.find("INSERT INTO ") {
-                    let after_insert = &line[insert_pos + 12..];
-                    let table_part = after_insert
-                        .split_whitespace()
-                        .next()
-                        .unwrap_or("")
-                        .trim_matches(|c: char| !c.is_alphanumeric() && c != '.' && c != '_' && c != '[' && c != ']');
-                    
-                    if !table_part.is_empty() {
-                        dependencies.push(Dependency {
-                            name: table_part.to_string(),
-                            path: Some(source_file.to_string()),
-                            is_external: false,
-                            line_number: Some(line_num + 1),
-                            dependency_type: "table_reference".to_string(),
-                            version: None,
-                        });
-                    }
-                }
-            }
-            
-            // Extract table references from UPDATE
-            if upper_line.contains("UPDATE ") && !upper_line.contains("UPDATE STATISTICS") {
-                if let Some(update_pos) = upper_line.find("UPDATE ") {
-                    let after_update = &line[update_pos + 7..];
-                    let table_part = after_update
-                        .split_whitespace()
-                        .next()
-                        .unwrap_or("")
-                        .trim_matches(|c: char| !c.is_alphanumeric() && c != '.' && c != '_' && c != '[' && c != ']');
-                    
-                    if !table_part.is_empty() {
-                        dependencies.push(Dependency {
-                            name: table_part.to_string(),
-                            path: Some(source_file.to_string()),
-                            is_external: false,
-                            line_number: Some(line_num + 1),
-                            dependency_type: "table_reference".to_string(),
-                            version: None,
-                        });
-                    }
-                }
-            }
-            
-            // Extract table references from DELETE FROM
-            if upper_line.contains("DELETE FROM ") {
-                if let Some(delete_pos) = upper_line.find("DELETE FROM ") {
-                    let after_delete = &line[delete_pos + 12..];
-                    let table_part = after_delete
-                        .split_whitespace()
-                        .next()
-                        .unwrap_or("")
-                        .trim_matches(|c: char| !c.is_alphanumeric() && c != '.' && c != '_' && c != '[' && c != ']');
-                    
-                    if !table_part.is_empty() {
-                        dependencies.push(Dependency {
-                            name: table_part.to_string(),
-                            path: Some(source_file.to_string()),
-                            is_external: false,
-                            line_number: Some(line_num + 1),
-                            dependency_type: "table_reference".to_string(),
-                            version: None,
-                        });
-                    }
-                }
-            }
-            
-            // Extract stored procedure calls: EXEC/EXECUTE ProcedureName
-            if upper_line.contains("EXEC ") || upper_line.contains("EXECUTE ") {
-                let exec_pos = if let Some(pos) = upper_line.find("EXECUTE ") {
-                    pos + 8
-                } else if let Some(pos) = upper_line.find("EXEC ") {
-                    pos + 5
-                } else {
-                    continue;
-                };
-                
-                let after_exec = &line[exec_pos..];
-                let proc_name = after_exec
-                    .split_whitespace()
-                    .next()
-                    .unwrap_or("")
-                    .trim_matches(|c: char| !c.is_alphanumeric() && c != '.' && c != '_' && c != '[' && c != ']');
-                
-                if !proc_name.is_empty() && !proc_name.starts_with('@') {
-                    dependencies.push(Dependency {
-                        name: proc_name.to_string(),
-                        path: Some(source_file.to_string()),
-                        is_external: false,
-                        line_number: Some(line_num + 1),
-                        dependency_type: "stored_procedure_call".to_string(),
-                        version: None,
-                    });
-                }
-            }
-        }
-        
-        dependencies
-    }
 }
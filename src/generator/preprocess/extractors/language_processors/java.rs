@@ -8,10 +8,11 @@ pub struct JavaProcessor {
     import_regex: Regex,
     package_regex: Regex,
     method_regex: Regex,
-    class_regex: Regex,
-    interface_regex: Regex,
-    enum_regex: Regex,
+    type_regex: Regex,
     constructor_regex: Regex,
+    field_regex: Regex,
+    request_method_regex: Regex,
+    important_annotation_regex: Regex,
 }
 
 impl JavaProcessor {
@@ -19,15 +20,22 @@ impl JavaProcessor {
         Self {
             import_regex: Regex::new(r"^\s*import\s+([^;]+);").unwrap(),
             package_regex: Regex::new(r"^\s*package\s+([^;]+);").unwrap(),
-            method_regex: Regex::new(r"^\s*(public|private|protected)?\s*(static)?\s*(final)?\s*(\w+)\s+(\w+)\s*\(([^)]*)\)").unwrap(),
-            class_regex: Regex::new(r"^\s*(public|private|protected)?\s*(abstract)?\s*(final)?\s*class\s+(\w+)").unwrap(),
-            interface_regex: Regex::new(r"^\s*(public|private|protected)?\s*interface\s+(\w+)").unwrap(),
-            enum_regex: Regex::new(r"^\s*(public|private|protected)?\s*enum\s+(\w+)").unwrap(),
-            constructor_regex: Regex::new(r"^\s*(public|private|protected)?\s*(\w+)\s*\(([^)]*)\)").unwrap(),
+            method_regex: Regex::new(r"^((?:(?:public|private|protected|static|final|abstract|synchronized|default|native)\s+)*)(?:<[^>]*>\s+)?([\w.$]+(?:<.*>)?(?:\[\])*)\s+(\w+)\s*\(").unwrap(),
+            type_regex: Regex::new(r"^((?:(?:public|private|protected|abstract|final|static|sealed|non-sealed|strictfp)\s+)*)(class|interface|enum|record|@interface)\s+(\w+)").unwrap(),
+            constructor_regex: Regex::new(r"^((?:(?:public|private|protected)\s+)*)(\w+)\s*\(").unwrap(),
+            field_regex: Regex::new(r"^((?:(?:public|private|protected|static|final|transient|volatile)\s+)*)([\w.$]+(?:<.*?>)?(?:\[\])*)\s+(\w+)\s*(?:=.*)?;").unwrap(),
+            request_method_regex: Regex::new(r"RequestMethod\.(\w+)").unwrap(),
+            important_annotation_regex: Regex::new(r"^@(?:\w+Mapping|RestController|Controller|Entity|Table|\w+Listener|FeignClient|Data|Value|Builder)\b").unwrap(),
         }
     }
 }
 
+impl Default for JavaProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl LanguageProcessor for JavaProcessor {
     fn supported_extensions(&self) -> Vec<&'static str> {
         vec!["java"]
@@ -111,6 +119,11 @@ impl LanguageProcessor for JavaProcessor {
             return true;
         }
         
+        // Spring mappings and listeners, JPA entities and Lombok types
+        if self.important_annotation_regex.is_match(trimmed) {
+            return true;
+        }
+        
         if trimmed.contains("TODO") || trimmed.contains("FIXME") || 
            trimmed.contains("NOTE") || trimmed.contains("HACK") {
             return true;
@@ -126,122 +139,592 @@ impl LanguageProcessor for JavaProcessor {
     fn extract_interfaces(&self, content: &str, _file_path: &Path) -> Vec<InterfaceInfo> {
         let mut interfaces = Vec::new();
         let lines: Vec<&str> = content.lines().collect();
-        
-        for (i, line) in lines.iter().enumerate() {
-            // Extract class definitions
-            if let Some(captures) = self.class_regex.captures(line) {
-                let visibility = captures.get(1).map(|m| m.as_str()).unwrap_or("package");
-                let is_abstract = captures.get(2).is_some();
-                let is_final = captures.get(3).is_some();
-                let name = captures.get(4).map(|m| m.as_str()).unwrap_or("").to_string();
-                
-                let mut interface_type = "class".to_string();
-                if is_abstract {
-                    interface_type = "abstract_class".to_string();
-                } else if is_final {
-                    interface_type = "final_class".to_string();
+        // Enclosing classes, interfaces, enums and records
+        let mut scopes: Vec<TypeScope> = Vec::new();
+        // Annotations read so far for the next declaration, and the line they start on
+        let mut pending: Vec<String> = Vec::new();
+        let mut pending_start = None;
+        let mut depth = 0;
+        let mut i = 0;
+
+        while i < lines.len() {
+            let start = i;
+            let mut text = lines[i].trim().to_string();
+            while text.starts_with('@') && !text.starts_with("@interface") {
+                let (annotation, rest, end) = Self::take_annotation(&lines, i, &text);
+                pending.push(annotation);
+                pending_start.get_or_insert(start);
+                for line in &lines[i..end] {
+                    depth += Self::brace_delta(line);
                 }
-                
-                interfaces.push(InterfaceInfo {
-                    name,
-                    interface_type,
-                    visibility: visibility.to_string(),
-                    parameters: Vec::new(),
-                    return_type: None,
-                    description: self.extract_javadoc(&lines, i),
-                });
+                i = end;
+                text = rest;
             }
-            
-            // Extract interface definitions
-            if let Some(captures) = self.interface_regex.captures(line) {
-                let visibility = captures.get(1).map(|m| m.as_str()).unwrap_or("package");
-                let name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
-                
-                interfaces.push(InterfaceInfo {
-                    name,
-                    interface_type: "interface".to_string(),
-                    visibility: visibility.to_string(),
-                    parameters: Vec::new(),
-                    return_type: None,
-                    description: self.extract_javadoc(&lines, i),
-                });
+            if text.is_empty() || text.starts_with("//") || text.starts_with('*') || text.starts_with("/*") {
+                depth += Self::brace_delta(lines[i]);
+                i += 1;
+                continue;
             }
-            
-            // Extract enum definitions
-            if let Some(captures) = self.enum_regex.captures(line) {
-                let visibility = captures.get(1).map(|m| m.as_str()).unwrap_or("package");
-                let name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
-                
+
+            let annotations = std::mem::take(&mut pending);
+            let doc_line = pending_start.take().unwrap_or(start);
+            let at_body = scopes
+                .last()
+                .is_some_and(|scope| scope.entered && depth == scope.open_depth + 1);
+            let in_body = at_body || scopes.is_empty();
+
+            if let Some(captures) = self.type_regex.captures(&text) {
+                let modifiers = captures.get(1).map_or("", |m| m.as_str());
+                let kind = captures.get(2).map_or("", |m| m.as_str());
+                let name = captures.get(3).map_or("", |m| m.as_str()).to_string();
+
+                let interface_type = if Self::has_annotation(&annotations, &["RestController", "Controller"]) {
+                    "controller"
+                } else if Self::has_annotation(&annotations, &["Entity", "Embeddable", "MappedSuperclass"]) {
+                    "entity"
+                } else {
+                    match kind {
+                        "class" if modifiers.contains("abstract") => "abstract_class",
+                        "class" if modifiers.contains("final") => "final_class",
+                        "@interface" => "annotation",
+                        kind => kind,
+                    }
+                };
+                let parameters = if kind == "record" {
+                    let signature = Self::collect_signature(&lines, i, &text);
+                    Self::take_balanced(&signature[signature.find('(').unwrap_or(0)..])
+                        .map(|params| self.parse_java_parameters(&params[1..params.len() - 1]))
+                        .unwrap_or_default()
+                } else {
+                    Vec::new()
+                };
+
+                let base_paths = annotations
+                    .iter()
+                    .find_map(|annotation| {
+                        let (name, args) = Self::split_annotation(annotation);
+                        (name == "RequestMapping").then(|| Self::mapping_paths(args))
+                    })
+                    .unwrap_or_else(|| vec![String::new()]);
                 interfaces.push(InterfaceInfo {
-                    name,
-                    interface_type: "enum".to_string(),
-                    visibility: visibility.to_string(),
-                    parameters: Vec::new(),
+                    name: name.clone(),
+                    interface_type: interface_type.to_string(),
+                    visibility: Self::visibility(modifiers).to_string(),
+                    parameters,
                     return_type: None,
-                    description: self.extract_javadoc(&lines, i),
+                    description: Self::describe(self.extract_javadoc(&lines, doc_line), &annotations),
                 });
-            }
-            
-            // Extract method definitions
-            if let Some(captures) = self.method_regex.captures(line) {
-                let visibility = captures.get(1).map(|m| m.as_str()).unwrap_or("package");
-                let is_static = captures.get(2).is_some();
-                let is_final = captures.get(3).is_some();
-                let return_type = captures.get(4).map(|m| m.as_str()).unwrap_or("").to_string();
-                let name = captures.get(5).map(|m| m.as_str()).unwrap_or("").to_string();
-                let params_str = captures.get(6).map(|m| m.as_str()).unwrap_or("");
-                
-                // Skip some Java keywords
-                if return_type == "if" || return_type == "for" || return_type == "while" || 
-                   return_type == "switch" || return_type == "try" {
-                    continue;
-                }
-                
-                let parameters = self.parse_java_parameters(params_str);
-                let mut interface_type = "method".to_string();
-                if is_static {
-                    interface_type = "static_method".to_string();
-                } else if is_final {
-                    interface_type = "final_method".to_string();
-                }
-                
-                interfaces.push(InterfaceInfo {
+                scopes.push(TypeScope {
                     name,
-                    interface_type,
-                    visibility: visibility.to_string(),
-                    parameters,
-                    return_type: Some(return_type),
-                    description: self.extract_javadoc(&lines, i),
+                    open_depth: depth,
+                    entered: false,
+                    index: interfaces.len() - 1,
+                    annotations,
+                    base_paths,
+                    fields: Vec::new(),
+                    methods: Vec::new(),
+                    has_constructor: false,
                 });
-            }
-            
-            // Extract constructors
-            if let Some(captures) = self.constructor_regex.captures(line) {
-                let visibility = captures.get(1).map(|m| m.as_str()).unwrap_or("package");
-                let name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
-                let params_str = captures.get(3).map(|m| m.as_str()).unwrap_or("");
-                
-                // Simple check if it's a constructor (name starts with uppercase)
-                if name.chars().next().map_or(false, |c| c.is_uppercase()) {
-                    let parameters = self.parse_java_parameters(params_str);
-                    
+            } else if at_body && let Some(scope) = scopes.last_mut() {
+                let owner = scope.name.clone();
+                if let Some(captures) = self.constructor_regex.captures(&text)
+                    && captures.get(2).is_some_and(|m| m.as_str() == owner)
+                {
+                    let modifiers = captures.get(1).map_or("", |m| m.as_str());
+                    let (params, _) = Self::split_signature(&Self::collect_signature(&lines, i, &text));
+                    scope.has_constructor = true;
                     interfaces.push(InterfaceInfo {
-                        name,
+                        name: owner,
                         interface_type: "constructor".to_string(),
-                        visibility: visibility.to_string(),
-                        parameters,
+                        visibility: Self::visibility(modifiers).to_string(),
+                        parameters: self.parse_java_parameters(&params),
                         return_type: None,
-                        description: self.extract_javadoc(&lines, i),
+                        description: Self::describe(self.extract_javadoc(&lines, doc_line), &annotations),
+                    });
+                } else if let Some(captures) = self.method_regex.captures(&text) {
+                    let modifiers = captures.get(1).map_or("", |m| m.as_str());
+                    let return_type = captures.get(2).map_or("", |m| m.as_str()).to_string();
+                    let name = captures.get(3).map_or("", |m| m.as_str()).to_string();
+                    if !JAVA_KEYWORDS.contains(&return_type.as_str()) && !JAVA_KEYWORDS.contains(&name.as_str()) {
+                        let (params, _) = Self::split_signature(&Self::collect_signature(&lines, i, &text));
+                        let parameters = self.parse_java_parameters(&params);
+                        let interface_type = if modifiers.contains("static") {
+                            "static_method"
+                        } else if modifiers.contains("final") {
+                            "final_method"
+                        } else {
+                            "method"
+                        };
+                        let qualified = format!("{}.{}", owner, name);
+
+                        // Outbound HTTP clients declare mappings too, but they are not entry points
+                        if !Self::has_annotation(&scope.annotations, &["FeignClient", "HttpExchange"]) {
+                            for route in self.extract_routes(&annotations, &scope.base_paths) {
+                                interfaces.push(InterfaceInfo {
+                                    name: route,
+                                    interface_type: "route".to_string(),
+                                    visibility: "public".to_string(),
+                                    parameters: parameters.clone(),
+                                    return_type: Some(return_type.clone()),
+                                    description: Some(format!("Handled by `{}`", qualified)),
+                                });
+                            }
+                        }
+                        for listener in self.extract_listeners(&annotations) {
+                            interfaces.push(InterfaceInfo {
+                                name: listener,
+                                interface_type: "listener".to_string(),
+                                visibility: "public".to_string(),
+                                parameters: parameters.clone(),
+                                return_type: None,
+                                description: Some(format!("Handled by `{}`", qualified)),
+                            });
+                        }
+
+                        scope.methods.push(name);
+                        interfaces.push(InterfaceInfo {
+                            name: qualified,
+                            interface_type: interface_type.to_string(),
+                            visibility: Self::visibility(modifiers).to_string(),
+                            parameters,
+                            return_type: Some(return_type),
+                            description: Self::describe(self.extract_javadoc(&lines, doc_line), &annotations),
+                        });
+                    }
+                } else if let Some(captures) = self.field_regex.captures(&text) {
+                    let modifiers = captures.get(1).map_or("", |m| m.as_str());
+                    scope.fields.push(Field {
+                        field_type: captures.get(2).map_or("", |m| m.as_str()).trim().to_string(),
+                        name: captures.get(3).map_or("", |m| m.as_str()).to_string(),
+                        is_static: modifiers.contains("static"),
+                        is_final: modifiers.contains("final"),
+                        annotations,
+                    });
+                }
+            } else if in_body && let Some(captures) = self.method_regex.captures(&text) {
+                // Methods outside of any type, e.g. in a snippet
+                let modifiers = captures.get(1).map_or("", |m| m.as_str());
+                let return_type = captures.get(2).map_or("", |m| m.as_str()).to_string();
+                let name = captures.get(3).map_or("", |m| m.as_str()).to_string();
+                if !JAVA_KEYWORDS.contains(&return_type.as_str()) && !JAVA_KEYWORDS.contains(&name.as_str()) {
+                    let (params, _) = Self::split_signature(&Self::collect_signature(&lines, i, &text));
+                    interfaces.push(InterfaceInfo {
+                        name,
+                        interface_type: "method".to_string(),
+                        visibility: Self::visibility(modifiers).to_string(),
+                        parameters: self.parse_java_parameters(&params),
+                        return_type: Some(return_type),
+                        description: Self::describe(self.extract_javadoc(&lines, doc_line), &annotations),
                     });
                 }
             }
+
+            depth += Self::brace_delta(lines[i]);
+            for scope in scopes.iter_mut() {
+                if depth > scope.open_depth {
+                    scope.entered = true;
+                }
+            }
+            while let Some(scope) = scopes.last() {
+                let closed = (scope.entered && depth <= scope.open_depth)
+                    || (!scope.entered && (text.ends_with(';') || text.ends_with('}')));
+                if !closed {
+                    break;
+                }
+                let scope = scopes.pop().unwrap();
+                self.close_scope(scope, &mut interfaces);
+            }
+            i += 1;
         }
-        
+        while let Some(scope) = scopes.pop() {
+            self.close_scope(scope, &mut interfaces);
+        }
+
         interfaces
     }
 }
 
+/// Words the method pattern can match as a return type or name in statements
+const JAVA_KEYWORDS: &[&str] = &[
+    "if", "for", "while", "switch", "try", "catch", "return", "new", "throw", "else", "synchronized", "public",
+    "private", "protected", "static", "final", "abstract", "class", "interface", "enum", "record",
+];
+
+/// Class, interface, enum or record being read
+#[derive(Debug)]
+struct TypeScope {
+    name: String,
+    open_depth: i32,
+    entered: bool,
+    /// Position of the type in the extracted interfaces
+    index: usize,
+    annotations: Vec<String>,
+    /// Paths of a class-level `@RequestMapping`, prefixing the method mappings
+    base_paths: Vec<String>,
+    fields: Vec<Field>,
+    methods: Vec<String>,
+    has_constructor: bool,
+}
+
+#[derive(Debug)]
+struct Field {
+    name: String,
+    field_type: String,
+    is_static: bool,
+    is_final: bool,
+    annotations: Vec<String>,
+}
+
 impl JavaProcessor {
+    fn visibility(modifiers: &str) -> &'static str {
+        ["public", "private", "protected"]
+            .into_iter()
+            .find(|visibility| modifiers.split_whitespace().any(|modifier| modifier == *visibility))
+            .unwrap_or("package")
+    }
+
+    /// Peel the annotation starting `text` (line `start`), joining lines until its arguments
+    /// close; returns the annotation, the rest of its last line and the index of that line
+    fn take_annotation(lines: &[&str], start: usize, text: &str) -> (String, String, usize) {
+        let name_end = text[1..]
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+            .map_or(text.len(), |at| at + 1);
+        let name = &text[..name_end];
+        let after = text[name_end..].trim_start();
+        if !after.starts_with('(') {
+            return (name.to_string(), after.to_string(), start);
+        }
+
+        let mut joined = after.to_string();
+        let mut end = start;
+        loop {
+            if let Some(args) = Self::take_balanced(&joined) {
+                let rest = joined[args.len()..].trim().to_string();
+                let args = args.split_whitespace().collect::<Vec<_>>().join(" ").replace("( ", "(");
+                return (format!("{}{}", name, args), rest, end);
+            }
+            if end + 1 >= lines.len() || end - start >= 20 {
+                return (format!("{}{}", name, joined), String::new(), end);
+            }
+            end += 1;
+            joined.push(' ');
+            joined.push_str(lines[end].trim());
+        }
+    }
+
+    /// Annotation name without `@` or package, and its arguments without parentheses
+    fn split_annotation(annotation: &str) -> (&str, &str) {
+        let (name, args) = match annotation.find('(') {
+            Some(at) => (&annotation[..at], annotation[at + 1..].trim_end_matches(')')),
+            None => (annotation, ""),
+        };
+        (name.trim_start_matches('@').rsplit('.').next().unwrap_or(name), args.trim())
+    }
+
+    fn has_annotation(annotations: &[String], names: &[&str]) -> bool {
+        annotations
+            .iter()
+            .any(|annotation| names.contains(&Self::split_annotation(annotation).0))
+    }
+
+    /// The balanced parenthesized group at the start of `text`, parentheses included
+    fn take_balanced(text: &str) -> Option<&str> {
+        let mut depth = 0;
+        let mut in_string = false;
+        for (at, c) in text.char_indices() {
+            match c {
+                '"' => in_string = !in_string,
+                '(' if !in_string => depth += 1,
+                ')' if !in_string => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(&text[..=at]);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Declaration from `text` (line `start`) joined with the next lines until its parameters close
+    fn collect_signature(lines: &[&str], start: usize, text: &str) -> String {
+        let mut signature = text.to_string();
+        for line in lines.iter().skip(start + 1).take(20) {
+            if signature.find('(').is_some_and(|at| Self::take_balanced(&signature[at..]).is_some()) {
+                break;
+            }
+            signature.push(' ');
+            signature.push_str(line.trim());
+        }
+        signature
+    }
+
+    /// Parameters of a declaration and what follows them
+    fn split_signature(signature: &str) -> (String, String) {
+        let Some(open) = signature.find('(') else {
+            return (String::new(), String::new());
+        };
+        match Self::take_balanced(&signature[open..]) {
+            Some(params) => (
+                params[1..params.len() - 1].to_string(),
+                signature[open + params.len()..].trim().to_string(),
+            ),
+            None => (signature[open + 1..].to_string(), String::new()),
+        }
+    }
+
+    /// String values of an annotation attribute: `"a"`, `{"a", "b"}`, or a constant name
+    fn annotation_values(args: &str, keys: &[&str]) -> Option<Vec<String>> {
+        let value = keys.iter().find_map(|key| {
+            Self::split_top_level(args).into_iter().find_map(|arg| {
+                let (name, value) = arg.split_once('=')?;
+                (name.trim() == *key).then(|| value.trim())
+            })
+        });
+        // The unnamed first argument is `value`
+        let value = value.or_else(|| {
+            keys.contains(&"value")
+                .then(|| Self::split_top_level(args).into_iter().next())
+                .flatten()
+                .map(str::trim)
+                .filter(|first| !first.is_empty() && !first.contains('='))
+        })?;
+
+        let values: Vec<String> = if value.starts_with('{') {
+            value
+                .trim_matches(['{', '}'])
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| item.trim_matches('"').to_string())
+                .collect()
+        } else {
+            vec![value.trim_matches('"').to_string()]
+        };
+        Some(values)
+    }
+
+    /// Split annotation arguments on commas outside of strings and braces
+    fn split_top_level(args: &str) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let mut depth = 0;
+        let mut in_string = false;
+        let mut start = 0;
+        for (at, c) in args.char_indices() {
+            match c {
+                '"' => in_string = !in_string,
+                '{' | '(' | '<' if !in_string => depth += 1,
+                '}' | ')' | '>' if !in_string => depth -= 1,
+                ',' if !in_string && depth == 0 => {
+                    parts.push(&args[start..at]);
+                    start = at + 1;
+                }
+                _ => {}
+            }
+        }
+        parts.push(&args[start..]);
+        parts
+    }
+
+    /// Paths of a `@...Mapping` annotation, the empty path when it has none
+    fn mapping_paths(args: &str) -> Vec<String> {
+        Self::annotation_values(args, &["value", "path"])
+            .filter(|paths| !paths.is_empty())
+            .unwrap_or_else(|| vec![String::new()])
+    }
+
+    /// Routes of Spring `@GetMapping("/{id}")`, `@RequestMapping(path = "/x", method = RequestMethod.POST)`...,
+    /// prefixed by the class-level mapping
+    fn extract_routes(&self, annotations: &[String], base_paths: &[String]) -> Vec<String> {
+        let mut routes = Vec::new();
+        for annotation in annotations {
+            let (name, args) = Self::split_annotation(annotation);
+            let verbs: Vec<String> = match name {
+                "GetMapping" | "PostMapping" | "PutMapping" | "DeleteMapping" | "PatchMapping" => {
+                    vec![name.trim_end_matches("Mapping").to_uppercase()]
+                }
+                "RequestMapping" => {
+                    let verbs: Vec<String> = self
+                        .request_method_regex
+                        .captures_iter(args)
+                        .filter_map(|verb| verb.get(1).map(|m| m.as_str().to_string()))
+                        .collect();
+                    if verbs.is_empty() { vec!["ANY".to_string()] } else { verbs }
+                }
+                _ => continue,
+            };
+            for base in base_paths {
+                for path in Self::mapping_paths(args) {
+                    let joined = format!("/{}/{}", base.trim_matches('/'), path.trim_matches('/'));
+                    let mut route = joined.split('/').filter(|segment| !segment.is_empty()).collect::<Vec<_>>().join("/");
+                    route.insert(0, '/');
+                    for verb in &verbs {
+                        routes.push(format!("{} {}", verb, route));
+                    }
+                }
+            }
+        }
+        routes
+    }
+
+    /// Message listeners like `@KafkaListener(topics = "orders")`, named `KAFKA orders`
+    fn extract_listeners(&self, annotations: &[String]) -> Vec<String> {
+        let mut listeners = Vec::new();
+        for annotation in annotations {
+            let (name, args) = Self::split_annotation(annotation);
+            let (broker, keys): (&str, &[&str]) = match name {
+                "KafkaListener" => ("KAFKA", &["topics", "topicPattern"]),
+                "RabbitListener" => ("RABBITMQ", &["queues"]),
+                "JmsListener" => ("JMS", &["destination"]),
+                "SqsListener" => ("SQS", &["value", "queueNames"]),
+                "StreamListener" => ("STREAM", &["value", "target"]),
+                _ => continue,
+            };
+            let destinations = Self::annotation_values(args, keys).unwrap_or_default();
+            if destinations.is_empty() {
+                listeners.push(broker.to_string());
+            }
+            listeners.extend(destinations.into_iter().map(|destination| format!("{} {}", broker, destination)));
+        }
+        listeners
+    }
+
+    /// Entity columns, and the accessors and constructors Lombok generates for a finished type
+    fn close_scope(&self, scope: TypeScope, interfaces: &mut Vec<InterfaceInfo>) {
+        let fields: Vec<&Field> = scope.fields.iter().filter(|field| !field.is_static).collect();
+        let parameter = |field: &Field| {
+            let required = Self::has_annotation(&field.annotations, &["Id", "NotNull", "NonNull", "NotBlank"])
+                || field.annotations.iter().any(|annotation| {
+                    let compact = annotation.replace(' ', "");
+                    compact.contains("nullable=false") || compact.contains("optional=false")
+                });
+            ParameterInfo {
+                name: field.name.clone(),
+                param_type: field.field_type.clone(),
+                is_optional: !required && !field.field_type.chars().next().is_some_and(char::is_lowercase),
+                description: (!field.annotations.is_empty()).then(|| field.annotations.join(" ")),
+            }
+        };
+        if interfaces[scope.index].interface_type == "entity" {
+            interfaces[scope.index].parameters = fields.iter().map(|field| parameter(field)).collect();
+        }
+
+        let class_lombok = |names: &[&str]| Self::has_annotation(&scope.annotations, names);
+        let is_value = class_lombok(&["Value"]);
+        let is_data = class_lombok(&["Data"]);
+        let generated = |name: String, interface_type: &str, parameters, return_type: Option<String>, by: &str| InterfaceInfo {
+            name,
+            interface_type: interface_type.to_string(),
+            visibility: "public".to_string(),
+            parameters,
+            return_type,
+            description: Some(format!("Generated by Lombok @{}", by)),
+        };
+
+        for field in &fields {
+            // A boolean `isActive` gets `isActive()` and `setActive()`
+            let is_boolean = field.field_type == "boolean";
+            let property = match field.name.strip_prefix("is") {
+                Some(rest) if is_boolean && rest.starts_with(char::is_uppercase) => rest,
+                _ => field.name.as_str(),
+            };
+            let capitalized = Self::capitalize(property);
+            let has_field_annotation = |name: &str| Self::has_annotation(&field.annotations, &[name]);
+
+            let getter_by = [("Getter", class_lombok(&["Getter"]) || has_field_annotation("Getter")), ("Data", is_data), ("Value", is_value)]
+                .into_iter()
+                .find_map(|(by, applies)| applies.then_some(by));
+            if let Some(by) = getter_by {
+                let name = format!("{}{}", if is_boolean { "is" } else { "get" }, capitalized);
+                if !scope.methods.contains(&name) {
+                    interfaces.push(generated(format!("{}.{}", scope.name, name), "method", Vec::new(), Some(field.field_type.clone()), by));
+                }
+            }
+
+            let setter_by = [("Setter", class_lombok(&["Setter"]) || has_field_annotation("Setter")), ("Data", is_data)]
+                .into_iter()
+                .find_map(|(by, applies)| applies.then_some(by));
+            if let Some(by) = setter_by
+                && !field.is_final
+                && !is_value
+            {
+                let name = format!("set{}", capitalized);
+                if !scope.methods.contains(&name) {
+                    interfaces.push(generated(format!("{}.{}", scope.name, name), "method", vec![parameter(field)], Some("void".to_string()), by));
+                }
+            }
+        }
+
+        let constructors = [
+            ("NoArgsConstructor", class_lombok(&["NoArgsConstructor"]), Vec::new()),
+            ("AllArgsConstructor", class_lombok(&["AllArgsConstructor"]) || is_value, fields.clone()),
+            (
+                if is_data { "Data" } else { "RequiredArgsConstructor" },
+                class_lombok(&["RequiredArgsConstructor"]) || (is_data && !scope.has_constructor),
+                fields
+                    .iter()
+                    .copied()
+                    .filter(|field| field.is_final || Self::has_annotation(&field.annotations, &["NonNull"]))
+                    .collect(),
+            ),
+        ];
+        for (by, applies, arguments) in constructors {
+            if applies {
+                let parameters = arguments.into_iter().map(parameter).collect();
+                interfaces.push(generated(scope.name.clone(), "constructor", parameters, None, by));
+            }
+        }
+        if class_lombok(&["Builder"]) {
+            interfaces.push(generated(
+                format!("{}.builder", scope.name),
+                "static_method",
+                Vec::new(),
+                Some(format!("{}Builder", scope.name)),
+                "Builder",
+            ));
+        }
+    }
+
+    fn capitalize(name: &str) -> String {
+        let mut chars = name.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default()
+    }
+
+    /// Javadoc followed by the annotations of the declaration
+    fn describe(javadoc: Option<String>, annotations: &[String]) -> Option<String> {
+        let mut parts: Vec<String> = javadoc.into_iter().collect();
+        if !annotations.is_empty() {
+            parts.push(format!("Annotations: {}", annotations.join(" ")));
+        }
+        if parts.is_empty() { None } else { Some(parts.join(" ")) }
+    }
+
+    /// Braces opened minus braces closed on a line, outside of strings, chars and comments
+    fn brace_delta(line: &str) -> i32 {
+        let code = line.split("//").next().unwrap_or(line).replace("'{'", "").replace("'}'", "");
+        let mut delta = 0;
+        let mut in_string = false;
+        let mut escaped = false;
+        for c in code.chars() {
+            match c {
+                '\\' if in_string => {
+                    escaped = !escaped;
+                    continue;
+                }
+                '"' if !escaped => in_string = !in_string,
+                '{' if !in_string => delta += 1,
+                '}' if !in_string => delta -= 1,
+                _ => {}
+            }
+            escaped = false;
+        }
+        delta
+    }
+
     /// Parse Java method parameters
     fn parse_java_parameters(&self, params_str: &str) -> Vec<ParameterInfo> {
         let mut parameters = Vec::new();
@@ -250,32 +733,26 @@ impl JavaProcessor {
             return parameters;
         }
         
-        // Simple parameter parsing, handling basic cases
-        for param in params_str.split(',') {
-            let param = param.trim();
+        // Split on top-level commas, so `Map<String, Object>` stays one parameter
+        for param in Self::split_top_level(params_str) {
+            // Drop parameter annotations like `@PathVariable` or `@RequestParam(defaultValue = "1")`
+            let mut param = param.trim().to_string();
+            while param.starts_with('@') {
+                let (_, rest, _) = Self::take_annotation(&[], 0, &param);
+                param = rest;
+            }
+            let param = param.trim().trim_start_matches("final ").trim();
             if param.is_empty() {
                 continue;
             }
             
-            // Parse parameter format: Type name or final Type name
-            let parts: Vec<&str> = param.split_whitespace().collect();
-            if parts.len() >= 2 {
-                let (param_type, name) = if parts[0] == "final" && parts.len() >= 3 {
-                    (parts[1].to_string(), parts[2].to_string())
-                } else {
-                    (parts[0].to_string(), parts[1].to_string())
-                };
-                
-                // Handle generic types
-                let clean_type = if param_type.contains('<') {
-                    param_type
-                } else {
-                    param_type
-                };
-                
+            // Parse parameter format: Type name, with generic types holding spaces
+            if let Some(split) = param.rfind(char::is_whitespace) {
+                let param_type = param[..split].trim().to_string();
+                let name = param[split..].trim().to_string();
                 parameters.push(ParameterInfo {
                     name,
-                    param_type: clean_type,
+                    param_type,
                     is_optional: false, // Java doesn't have optional parameters
                     description: None,
                 });
@@ -343,4 +820,108 @@ impl JavaProcessor {
             import_path.to_string()
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'a>(interfaces: &'a [InterfaceInfo], name: &str) -> &'a InterfaceInfo {
+        interfaces
+            .iter()
+            .find(|interface| interface.name == name)
+            .unwrap_or_else(|| panic!("{} not extracted", name))
+    }
+
+    #[test]
+    fn test_extract_spring_jpa_and_lombok() {
+        let processor = JavaProcessor::new();
+        let content = r#"
+package com.example.orders;
+
+/** Order endpoints */
+@RestController
+@RequestMapping("/api/orders")
+public class OrderController {
+    private final OrderService service;
+
+    public OrderController(OrderService service) {
+        this.service = service;
+    }
+
+    @GetMapping("/{id}")
+    public ResponseEntity<Order> get(@PathVariable Long id) {
+        return service.find(id).map(ResponseEntity::ok).orElseThrow();
+    }
+
+    @RequestMapping(
+        value = {"", "/bulk"},
+        method = {RequestMethod.POST, RequestMethod.PUT})
+    public List<Order> save(@RequestBody List<Order> orders, @RequestParam(defaultValue = "1") int version) {
+        return service.saveAll(orders);
+    }
+
+    @KafkaListener(topics = {"orders", "orders-retry"}, groupId = "billing")
+    public void onOrder(OrderEvent event) {}
+}
+
+@Entity
+@Data
+@Table(name = "orders")
+public class Order {
+    @Id @GeneratedValue
+    private Long id;
+
+    @Column(nullable = false)
+    private String customer;
+
+    private boolean isPaid;
+
+    private static final long serialVersionUID = 1L;
+}
+
+@FeignClient("billing")
+interface BillingClient {
+    @PostMapping("/invoices")
+    Invoice create(Invoice invoice);
+}
+"#;
+        let interfaces = processor.extract_interfaces(content, Path::new("OrderController.java"));
+
+        let controller = find(&interfaces, "OrderController");
+        assert_eq!(controller.interface_type, "controller");
+        assert_eq!(
+            controller.description.as_deref(),
+            Some(r#"Order endpoints Annotations: @RestController @RequestMapping("/api/orders")"#)
+        );
+        assert_eq!(find(&interfaces, "OrderController").visibility, "public");
+        assert!(interfaces.iter().any(|i| i.name == "OrderController" && i.interface_type == "constructor"));
+
+        let route = find(&interfaces, "GET /api/orders/{id}");
+        assert_eq!(route.interface_type, "route");
+        assert_eq!(route.description.as_deref(), Some("Handled by `OrderController.get`"));
+        assert_eq!(route.return_type.as_deref(), Some("ResponseEntity<Order>"));
+        let get = find(&interfaces, "OrderController.get");
+        assert_eq!((get.parameters[0].name.as_str(), get.parameters[0].param_type.as_str()), ("id", "Long"));
+        for route in ["POST /api/orders", "PUT /api/orders", "POST /api/orders/bulk", "PUT /api/orders/bulk"] {
+            assert_eq!(find(&interfaces, route).description.as_deref(), Some("Handled by `OrderController.save`"));
+        }
+        let save = find(&interfaces, "OrderController.save");
+        let params: Vec<(&str, &str)> = save.parameters.iter().map(|p| (p.name.as_str(), p.param_type.as_str())).collect();
+        assert_eq!(params, [("orders", "List<Order>"), ("version", "int")]);
+        assert_eq!(find(&interfaces, "KAFKA orders-retry").interface_type, "listener");
+        assert_eq!(find(&interfaces, "KAFKA orders").description.as_deref(), Some("Handled by `OrderController.onOrder`"));
+
+        let order = find(&interfaces, "Order");
+        assert_eq!(order.interface_type, "entity");
+        let columns: Vec<(&str, bool)> = order.parameters.iter().map(|p| (p.name.as_str(), p.is_optional)).collect();
+        assert_eq!(columns, [("id", false), ("customer", false), ("isPaid", false)]);
+        assert_eq!(find(&interfaces, "Order.getCustomer").return_type.as_deref(), Some("String"));
+        assert_eq!(find(&interfaces, "Order.isPaid").description.as_deref(), Some("Generated by Lombok @Data"));
+        assert_eq!(find(&interfaces, "Order.setPaid").parameters[0].param_type, "boolean");
+        assert!(!interfaces.iter().any(|i| i.name == "Order.getSerialVersionUID"));
+
+        // Feign clients call out; their mappings are not routes of this service
+        assert_eq!(find(&interfaces, "BillingClient.create").interface_type, "method");
+        assert!(!interfaces.iter().any(|i| i.name == "POST /invoices"));
+    }
+}
@@ -36,6 +36,12 @@ impl JavaScriptProcessor {
     }
 }
 
+impl Default for JavaScriptProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl LanguageProcessor for JavaScriptProcessor {
     fn supported_extensions(&self) -> Vec<&'static str> {
         vec!["js", "mjs", "cjs"]
@@ -193,7 +199,7 @@ impl LanguageProcessor for JavaScriptProcessor {
         "JavaScript"
     }
 
-    fn extract_interfaces(&self, content: &str, _file_path: &Path) -> Vec<InterfaceInfo> {
+    fn extract_interfaces(&self, content: &str, file_path: &Path) -> Vec<InterfaceInfo> {
         let mut interfaces = Vec::new();
         let lines: Vec<&str> = content.lines().collect();
 
@@ -326,6 +332,8 @@ impl LanguageProcessor for JavaScriptProcessor {
             }
         }
 
+        interfaces.extend(super::js_routes::extract_routes(content, file_path));
+
         interfaces
     }
 }
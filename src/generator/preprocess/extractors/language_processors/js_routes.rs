@@ -0,0 +1,399 @@
+//! HTTP routes of JavaScript/TypeScript servers, shared by the JavaScript, TypeScript and React
+//! processors: Express/Fastify/Koa style `app.get("/path", handler)` calls, NestJS controller
+//! decorators and Next.js file-based routes
+
+use regex::Regex;
+use std::path::Path;
+use std::sync::LazyLock;
+
+use crate::types::code::InterfaceInfo;
+
+/// `app.get("/users", list)`, `router.post('/users', auth, create)`...
+static ROUTE_CALL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"\b(\w+)\.(get|post|put|delete|del|patch|head|options|all)\(\s*['"`](/[^'"`]*)['"`]\s*,"#).unwrap()
+});
+
+/// Express `router.route("/users").get(list).post(create)`
+static ROUTE_CHAIN_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"\.route\(\s*['"`](/[^'"`]*)['"`]\s*\)"#).unwrap());
+static CHAINED_VERB_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\.(get|post|put|delete|patch|head|options|all)\(\s*([\w.]+)?").unwrap());
+
+/// Fastify `fastify.route({ method: ["GET", "HEAD"], url: "/users", handler: list })`
+static ROUTE_OPTIONS_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\.route\(\s*\{").unwrap());
+static OPTION_URL_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"\b(?:url|path)\s*:\s*['"`]([^'"`]+)['"`]"#).unwrap());
+static OPTION_METHOD_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\bmethod\s*:\s*(\[[^\]]*\]|'[^']*'|\x22[^\x22]*\x22)").unwrap());
+static OPTION_HANDLER_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\bhandler\s*:\s*([\w.]+)").unwrap());
+
+/// NestJS `@Controller("users")`, `@Get(":id")`, `@EventPattern("user.created")`
+static NEST_CONTROLLER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^@Controller\(\s*(?:['"`]([^'"`]*)['"`]|\{[^}]*path\s*:\s*['"`]([^'"`]*)['"`][^}]*\})?"#).unwrap()
+});
+static NEST_ROUTE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^@(Get|Post|Put|Delete|Patch|Head|Options|All)\(\s*(?:['"`]([^'"`]*)['"`])?"#).unwrap()
+});
+static NEST_PATTERN_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^@(EventPattern|MessagePattern)\(\s*['"`]([^'"`]*)['"`]"#).unwrap());
+static CLASS_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?:export\s+)?(?:default\s+)?(?:abstract\s+)?class\s+(\w+)").unwrap());
+static METHOD_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:(?:public|private|protected|static|async|override)\s+)*(\w+)\s*(?:<[^>]*>)?\s*\(").unwrap()
+});
+
+/// Next.js route handlers `export async function GET(request)` and `export const POST = ...`
+static NEXT_HANDLER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^export\s+(?:async\s+function\s+|function\s+|const\s+)(GET|POST|PUT|DELETE|PATCH|HEAD|OPTIONS)\b").unwrap()
+});
+static DEFAULT_EXPORT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^export\s+default\b(?:\s+(?:async\s+)?(?:function\s*(\w+)?|class\s+(\w+)|(\w+)\s*;?$))?").unwrap()
+});
+
+/// HTTP clients, whose `get("/path", config)` calls are requests rather than routes
+const CLIENTS: &[&str] = &["axios", "http", "https", "client", "httpClient", "request", "superagent", "ky", "got", "$http", "fetch", "instance"];
+
+/// Extensions of the files Next.js reads routes from
+const NEXT_EXTENSIONS: &[&str] = &["js", "jsx", "ts", "tsx", "mjs"];
+
+/// Routes declared in a JavaScript/TypeScript file: `route` interfaces named `GET /users/:id`,
+/// `listener` interfaces for NestJS message patterns, and `page_route` interfaces named by the
+/// page path for Next.js pages
+pub fn extract_routes(content: &str, file_path: &Path) -> Vec<InterfaceInfo> {
+    let mut routes = extract_route_calls(content);
+    routes.extend(extract_nest_routes(content));
+    routes.extend(extract_next_routes(content, file_path));
+    routes
+}
+
+fn route(name: String, interface_type: &str, handler: Option<&str>) -> InterfaceInfo {
+    InterfaceInfo {
+        name,
+        interface_type: interface_type.to_string(),
+        visibility: "public".to_string(),
+        parameters: Vec::new(),
+        return_type: None,
+        description: handler.map(|handler| format!("Handled by `{}`", handler)),
+    }
+}
+
+fn verb(method: &str) -> String {
+    match method {
+        "all" => "ANY".to_string(),
+        "del" => "DELETE".to_string(),
+        method => method.to_uppercase(),
+    }
+}
+
+/// Statement starting on line `start`, joined with the next lines until its parentheses close
+fn collect_statement(lines: &[&str], start: usize) -> String {
+    let mut statement = String::new();
+    let mut depth = 0;
+    for line in lines.iter().skip(start).take(15) {
+        for c in line.trim().chars() {
+            match c {
+                '(' | '{' | '[' => depth += 1,
+                ')' | '}' | ']' => depth -= 1,
+                _ => {}
+            }
+            statement.push(c);
+        }
+        if depth <= 0 {
+            break;
+        }
+        statement.push(' ');
+    }
+    statement
+}
+
+/// Last argument of the call `call` ends, when it names a handler (`controller.list`)
+fn last_argument(call: &str) -> Option<&str> {
+    let mut depth = 0;
+    let mut start = 0;
+    for (at, c) in call.char_indices() {
+        match c {
+            '(' | '{' | '[' => depth += 1,
+            ')' | '}' | ']' => {
+                depth -= 1;
+                if depth < 0 {
+                    let argument = call[start..at].trim();
+                    let is_name = !argument.is_empty()
+                        && argument.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.' || c == '$');
+                    return is_name.then_some(argument);
+                }
+            }
+            ',' if depth == 0 => start = at + 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn extract_route_calls(content: &str) -> Vec<InterfaceInfo> {
+    let mut routes = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("//") || trimmed.starts_with('*') {
+            continue;
+        }
+        if !(line.contains(".route(") || ROUTE_CALL_REGEX.is_match(line) || line.trim_end().ends_with('(')) {
+            continue;
+        }
+        let statement = collect_statement(&lines, i);
+
+        for captures in ROUTE_CALL_REGEX.captures_iter(&statement) {
+            let receiver = captures.get(1).map_or("", |m| m.as_str());
+            if CLIENTS.contains(&receiver) {
+                continue;
+            }
+            let method = captures.get(2).map_or("", |m| m.as_str());
+            let path = captures.get(3).map_or("", |m| m.as_str());
+            let handler = last_argument(&statement[captures.get(0).map_or(0, |m| m.end())..]);
+            routes.push(route(format!("{} {}", verb(method), path), "route", handler));
+        }
+
+        if let Some(captures) = ROUTE_CHAIN_REGEX.captures(&statement) {
+            let path = captures.get(1).map_or("", |m| m.as_str());
+            for chained in CHAINED_VERB_REGEX.captures_iter(&statement[captures.get(0).map_or(0, |m| m.end())..]) {
+                let method = chained.get(1).map_or("", |m| m.as_str());
+                routes.push(route(format!("{} {}", verb(method), path), "route", chained.get(2).map(|m| m.as_str())));
+            }
+        }
+
+        if ROUTE_OPTIONS_REGEX.is_match(&statement)
+            && let Some(url) = OPTION_URL_REGEX.captures(&statement).and_then(|captures| captures.get(1))
+        {
+            let methods: Vec<String> = OPTION_METHOD_REGEX
+                .captures(&statement)
+                .and_then(|captures| captures.get(1))
+                .map(|methods| {
+                    methods
+                        .as_str()
+                        .split(',')
+                        .map(|method| method.trim_matches(|c: char| !c.is_alphabetic()).to_lowercase())
+                        .filter(|method| !method.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default();
+            let handler = OPTION_HANDLER_REGEX
+                .captures(&statement)
+                .and_then(|captures| captures.get(1))
+                .map(|m| m.as_str());
+            for method in methods {
+                routes.push(route(format!("{} {}", verb(&method), url.as_str()), "route", handler));
+            }
+        }
+    }
+    // A call in a multi-line statement is found from each line the statement starts on
+    let mut seen = std::collections::HashSet::new();
+    routes.retain(|route| seen.insert((route.name.clone(), route.description.clone())));
+    routes
+}
+
+fn extract_nest_routes(content: &str) -> Vec<InterfaceInfo> {
+    let mut routes = Vec::new();
+    let mut prefix: Option<String> = None;
+    let mut controller = String::new();
+    // Route decorators read so far for the next method
+    let mut pending: Vec<(String, String, &str)> = Vec::new();
+
+    for line in content.lines().map(str::trim) {
+        if let Some(captures) = NEST_CONTROLLER_REGEX.captures(line) {
+            prefix = Some(captures.get(1).or(captures.get(2)).map_or("", |m| m.as_str()).to_string());
+        } else if let Some(captures) = NEST_ROUTE_REGEX.captures(line) {
+            let method = captures.get(1).map_or("", |m| m.as_str()).to_lowercase();
+            let path = captures.get(2).map_or("", |m| m.as_str());
+            pending.push((verb(&method), path.to_string(), "route"));
+        } else if let Some(captures) = NEST_PATTERN_REGEX.captures(line) {
+            let kind = if captures.get(1).is_some_and(|m| m.as_str() == "EventPattern") { "EVENT" } else { "MESSAGE" };
+            pending.push((kind.to_string(), captures.get(2).map_or("", |m| m.as_str()).to_string(), "listener"));
+        } else if let Some(captures) = CLASS_REGEX.captures(line) {
+            controller = captures.get(1).map_or("", |m| m.as_str()).to_string();
+            pending.clear();
+        } else if line.starts_with('@') || line.is_empty() || line.starts_with("//") {
+            continue;
+        } else if !pending.is_empty() {
+            let handler = METHOD_REGEX
+                .captures(line)
+                .and_then(|captures| captures.get(1))
+                .map(|method| format!("{}.{}", controller, method.as_str()));
+            for (kind, path, interface_type) in pending.drain(..) {
+                let name = if interface_type == "listener" {
+                    format!("{} {}", kind, path)
+                } else {
+                    let Some(prefix) = &prefix else {
+                        continue;
+                    };
+                    format!("{} {}", kind, join_paths(prefix, &path))
+                };
+                routes.push(route(name, interface_type, handler.as_deref()));
+            }
+        }
+    }
+    routes
+}
+
+/// `/users` joined with `:id`, as `/users/:id`
+fn join_paths(prefix: &str, path: &str) -> String {
+    let segments: Vec<&str> = prefix
+        .split('/')
+        .chain(path.split('/'))
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    format!("/{}", segments.join("/"))
+}
+
+/// Route of a file under a Next.js `pages/` or `app/` directory: API routes of `pages/api/` and
+/// `app/**/route.ts`, and the page routes of the other pages
+fn extract_next_routes(content: &str, file_path: &Path) -> Vec<InterfaceInfo> {
+    let extension = file_path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    if !NEXT_EXTENSIONS.contains(&extension) {
+        return Vec::new();
+    }
+    let segments: Vec<String> = file_path
+        .with_extension("")
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .collect();
+    // The routes directory directly under `src/`, or the last one in the path
+    let Some(root) = segments
+        .iter()
+        .enumerate()
+        .filter(|(_, segment)| *segment == "pages" || *segment == "app")
+        .max_by_key(|(index, _)| (*index > 0 && segments[index - 1] == "src", *index))
+        .map(|(index, _)| index)
+    else {
+        return Vec::new();
+    };
+    let is_app_router = segments[root] == "app";
+    let mut route_segments: Vec<&str> = segments[root + 1..]
+        .iter()
+        .map(String::as_str)
+        // Route groups `(marketing)` and parallel route slots `@modal` are not part of the URL
+        .filter(|segment| !(segment.starts_with('@') || segment.starts_with('(') && segment.ends_with(')')))
+        .collect();
+    let Some(file_name) = route_segments.pop() else {
+        return Vec::new();
+    };
+    let default_export = content.lines().find_map(|line| {
+        DEFAULT_EXPORT_REGEX
+            .captures(line.trim())
+            .map(|captures| captures.get(1).or(captures.get(2)).or(captures.get(3)).map(|m| m.as_str().to_string()))
+    });
+    let path = |segments: &[&str]| format!("/{}", segments.join("/"));
+
+    if is_app_router {
+        match file_name {
+            "route" => content
+                .lines()
+                .filter_map(|line| NEXT_HANDLER_REGEX.captures(line.trim()))
+                .filter_map(|captures| captures.get(1))
+                .map(|method| route(format!("{} {}", method.as_str(), path(&route_segments)), "route", Some(method.as_str())))
+                .collect(),
+            "page" if default_export.is_some() => {
+                vec![route(path(&route_segments), "page_route", default_export.flatten().as_deref())]
+            }
+            _ => Vec::new(),
+        }
+    } else {
+        if file_name.starts_with('_') || default_export.is_none() {
+            return Vec::new();
+        }
+        if file_name != "index" {
+            route_segments.push(file_name);
+        }
+        let handler = default_export.flatten();
+        if route_segments.first() == Some(&"api") {
+            vec![route(format!("ANY {}", path(&route_segments)), "route", handler.as_deref())]
+        } else {
+            vec![route(path(&route_segments), "page_route", handler.as_deref())]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(routes: &[InterfaceInfo]) -> Vec<(&str, &str, Option<&str>)> {
+        routes
+            .iter()
+            .map(|route| (route.name.as_str(), route.interface_type.as_str(), route.description.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn test_extract_routes() {
+        let express = r#"
+const app = express();
+app.get('/users/:id', auth, users.show);
+router.post("/users", async (req, res) => {
+  res.json(await create(req.body));
+});
+app.use('/static', express.static('public'));
+const response = await axios.get('/users/1', { params });
+router.route('/books').get(books.list).put(books.replace);
+fastify.route({
+  method: ['GET', 'HEAD'],
+  url: '/health',
+  handler: health,
+});
+"#;
+        assert_eq!(
+            names(&extract_routes(express, Path::new("src/server.js"))),
+            [
+                ("GET /users/:id", "route", Some("Handled by `users.show`")),
+                ("POST /users", "route", None),
+                ("GET /books", "route", Some("Handled by `books.list`")),
+                ("PUT /books", "route", Some("Handled by `books.replace`")),
+                ("GET /health", "route", Some("Handled by `health`")),
+                ("HEAD /health", "route", Some("Handled by `health`")),
+            ]
+        );
+
+        let nest = r#"
+@Controller('users')
+export class UsersController {
+  @Get(':id')
+  @UseGuards(AuthGuard)
+  async findOne(@Param('id') id: string): Promise<User> {}
+
+  @Post()
+  create(@Body() dto: CreateUserDto) {}
+
+  @EventPattern('user.created')
+  handleCreated(data: UserEvent) {}
+}
+"#;
+        assert_eq!(
+            names(&extract_routes(nest, Path::new("src/users/users.controller.ts"))),
+            [
+                ("GET /users/:id", "route", Some("Handled by `UsersController.findOne`")),
+                ("POST /users", "route", Some("Handled by `UsersController.create`")),
+                ("EVENT user.created", "listener", Some("Handled by `UsersController.handleCreated`")),
+            ]
+        );
+
+        let handler = "export async function GET(request: Request) {}\nexport const POST = withAuth(create);\n";
+        assert_eq!(
+            names(&extract_routes(handler, Path::new("/srv/app/web/src/app/(shop)/api/orders/[id]/route.ts"))),
+            [
+                ("GET /api/orders/[id]", "route", Some("Handled by `GET`")),
+                ("POST /api/orders/[id]", "route", Some("Handled by `POST`")),
+            ]
+        );
+        let page = "export default function BlogPost({ params }) {}\n";
+        assert_eq!(
+            names(&extract_routes(page, Path::new("web/pages/blog/[slug].tsx"))),
+            [("/blog/[slug]", "page_route", Some("Handled by `BlogPost`"))]
+        );
+        assert_eq!(
+            names(&extract_routes(page, Path::new("web/pages/api/index.ts"))),
+            [("ANY /api", "route", Some("Handled by `BlogPost`"))]
+        );
+        assert!(extract_routes(page, Path::new("web/pages/_app.tsx")).is_empty());
+        assert!(extract_routes("export const x = 1;\n", Path::new("web/pages/about.tsx")).is_empty());
+    }
+}
@@ -18,6 +18,12 @@ impl KotlinProcessor {
     }
 }
 
+impl Default for KotlinProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl LanguageProcessor for KotlinProcessor {
     fn supported_extensions(&self) -> Vec<&'static str> {
         vec!["kt"]
@@ -1,7 +1,32 @@
+use std::collections::HashMap;
 use std::path::Path;
+use std::sync::{Arc, LazyLock, RwLock};
+
+use regex::Regex;
 
 use crate::types::code::{CodeComplexity, Dependency, InterfaceInfo};
 
+/// An identifier followed by an opening parenthesis
+static CALL_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b([A-Za-z_]\w*)\s*\(").unwrap());
+
+/// Keywords followed by a parenthesis that are not calls
+const CONTROL_KEYWORDS: [&str; 12] = [
+    "if", "while", "for", "foreach", "match", "switch", "catch", "return", "sizeof", "typeof", "elif",
+    "using",
+];
+
+/// Keywords whose following identifier is the defined function, not a call
+const DEFINITION_KEYWORDS: [&str; 5] = ["fn", "def", "function", "func", "fun"];
+
+/// Branches, loops, handlers and short-circuit operators, each adding a path through the code
+static DECISION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b(?:if|elif|elsif|while|for|foreach|case|catch|except|when|guard)\b|&&|\|\|").unwrap()
+});
+static FUNCTION_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(?:fn|def|function|func|fun)\s+\w+").unwrap());
+static CLASS_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(?:class|struct|interface|trait|enum|protocol)\s+\w+").unwrap());
+
 /// Language processor trait
 pub trait LanguageProcessor: Send + Sync + std::fmt::Debug {
     /// Get supported file extensions
@@ -15,60 +40,137 @@ pub trait LanguageProcessor: Send + Sync + std::fmt::Debug {
     fn determine_component_type(&self, file_path: &Path, content: &str) -> String;
 
     /// Identify important code lines
-    #[allow(dead_code)]
     fn is_important_line(&self, line: &str) -> bool;
 
     /// Get language name
-    #[allow(dead_code)]
     fn language_name(&self) -> &'static str;
 
     /// Extract code interface definitions
     fn extract_interfaces(&self, content: &str, file_path: &Path) -> Vec<InterfaceInfo>;
-}
 
-/// Language processor manager
-#[derive(Debug)]
-pub struct LanguageProcessorManager {
-    processors: Vec<Box<dyn LanguageProcessor>>,
-}
+    /// Estimate cyclomatic complexity and nesting; the default nests on `{` blocks
+    fn calculate_complexity(&self, content: &str) -> CodeComplexity {
+        estimate_complexity(content, brace_nesting_depth(content))
+    }
 
-impl Clone for LanguageProcessorManager {
-    fn clone(&self) -> Self {
-        Self::new()
+    /// Extract the names of the functions and methods called in the code, in order of
+    /// appearance. The default matches `name(` outside of comments and definitions.
+    fn extract_function_calls(&self, content: &str) -> Vec<String> {
+        let mut calls = Vec::new();
+        for line in content.lines().map(str::trim) {
+            if is_comment_line(line) {
+                continue;
+            }
+            for captures in CALL_REGEX.captures_iter(line) {
+                let Some(name) = captures.get(1) else {
+                    continue;
+                };
+                let previous_word = line[..name.start()]
+                    .trim_end()
+                    .rsplit(|c: char| !c.is_alphanumeric() && c != '_')
+                    .next()
+                    .unwrap_or("");
+                if !CONTROL_KEYWORDS.contains(&name.as_str())
+                    && !DEFINITION_KEYWORDS.contains(&previous_word)
+                {
+                    calls.push(name.as_str().to_string());
+                }
+            }
+        }
+        calls
     }
 }
 
+/// Processors registered at runtime, added to every manager created afterwards
+static REGISTERED: LazyLock<RwLock<Vec<Arc<dyn LanguageProcessor>>>> = LazyLock::new(Default::default);
+
+/// Registry of language processors by file extension: the built-in processors, then the
+/// registered ones, which take over the extensions they support
+#[derive(Debug, Clone)]
+pub struct LanguageProcessorManager {
+    processors: Vec<Arc<dyn LanguageProcessor>>,
+    /// Index of the processor handling each extension
+    by_extension: HashMap<&'static str, usize>,
+}
+
 impl LanguageProcessorManager {
     pub fn new() -> Self {
+        let mut manager = Self::builtin();
+        for processor in REGISTERED.read().unwrap().iter() {
+            manager.add(Arc::clone(processor));
+        }
+        manager
+    }
+
+    /// Built-in processors only; the first one listing an extension handles it
+    pub fn builtin() -> Self {
+        let processors: Vec<Arc<dyn LanguageProcessor>> = vec![
+            Arc::new(rust::RustProcessor::new()),
+            Arc::new(javascript::JavaScriptProcessor::new()),
+            Arc::new(typescript::TypeScriptProcessor::new()),
+            Arc::new(php::PhpProcessor::new()),
+            Arc::new(react::ReactProcessor::new()),
+            Arc::new(vue::VueProcessor::new()),
+            Arc::new(svelte::SvelteProcessor::new()),
+            Arc::new(kotlin::KotlinProcessor::new()),
+            Arc::new(python::PythonProcessor::new()),
+            Arc::new(java::JavaProcessor::new()),
+            Arc::new(csharp::CSharpProcessor::new()),
+            Arc::new(sql::SqlProcessor::new()),
+            Arc::new(swift::SwiftProcessor::new()),
+            Arc::new(cpp::CppProcessor::new()),
+        ];
+        let mut by_extension = HashMap::new();
+        for (index, processor) in processors.iter().enumerate() {
+            for extension in processor.supported_extensions() {
+                by_extension.entry(extension).or_insert(index);
+            }
+        }
         Self {
-            processors: vec![
-                Box::new(rust::RustProcessor::new()),
-                Box::new(javascript::JavaScriptProcessor::new()),
-                Box::new(typescript::TypeScriptProcessor::new()),
-                Box::new(php::PhpProcessor::new()),
-                Box::new(react::ReactProcessor::new()),
-                Box::new(vue::VueProcessor::new()),
-                Box::new(svelte::SvelteProcessor::new()),
-                Box::new(kotlin::KotlinProcessor::new()),
-                Box::new(python::PythonProcessor::new()),
-                Box::new(java::JavaProcessor::new()),
-                Box::new(csharp::CSharpProcessor::new()),
-                Box::new(swift::SwiftProcessor::new()),
-            ],
+            processors,
+            by_extension,
         }
     }
 
-    /// Get processor by file extension
-    pub fn get_processor(&self, file_path: &Path) -> Option<&dyn LanguageProcessor> {
-        let extension = file_path.extension()?.to_str()?;
+    /// Register a processor for all managers created from now on, e.g. by an application
+    /// embedding the library to support another language
+    pub fn register(processor: Arc<dyn LanguageProcessor>) {
+        tracing::debug!("🧩 Registered language processor {}", processor.language_name());
+        REGISTERED.write().unwrap().push(processor);
+    }
 
-        for processor in &self.processors {
-            if processor.supported_extensions().contains(&extension) {
-                return Some(processor.as_ref());
-            }
+    /// Add a processor to this manager; it handles its extensions from now on
+    pub fn add(&mut self, processor: Arc<dyn LanguageProcessor>) {
+        let index = self.processors.len();
+        for extension in processor.supported_extensions() {
+            self.by_extension.insert(extension, index);
         }
+        self.processors.push(processor);
+    }
+
+    /// Supported languages and the file extensions they handle; extensions taken over by
+    /// another processor are listed under that one only
+    pub fn supported_languages(&self) -> Vec<(&'static str, Vec<&'static str>)> {
+        self.processors
+            .iter()
+            .enumerate()
+            .map(|(index, p)| {
+                let extensions = p
+                    .supported_extensions()
+                    .into_iter()
+                    .filter(|extension| self.by_extension.get(extension) == Some(&index))
+                    .collect::<Vec<_>>();
+                (p.language_name(), extensions)
+            })
+            .filter(|(_, extensions)| !extensions.is_empty())
+            .collect()
+    }
 
-        None
+    /// Get processor by file extension
+    pub fn get_processor(&self, file_path: &Path) -> Option<&dyn LanguageProcessor> {
+        let extension = file_path.extension()?.to_str()?;
+        let index = self.by_extension.get(extension)?;
+        Some(self.processors[*index].as_ref())
     }
 
     /// Extract file dependencies
@@ -91,7 +193,6 @@ impl LanguageProcessorManager {
     }
 
     /// Identify important code lines
-    #[allow(dead_code)]
     pub fn is_important_line(&self, file_path: &Path, line: &str) -> bool {
         if let Some(processor) = self.get_processor(file_path) {
             processor.is_important_line(line)
@@ -109,49 +210,175 @@ impl LanguageProcessorManager {
         }
     }
 
-    pub fn calculate_complexity_metrics(&self, content: &str) -> CodeComplexity {
-        let lines: Vec<&str> = content.lines().collect();
-        let lines_of_code = lines.len();
+    /// Extract the names of the functions called in the code
+    pub fn extract_function_calls(&self, file_path: &Path, content: &str) -> Vec<String> {
+        if let Some(processor) = self.get_processor(file_path) {
+            processor.extract_function_calls(content)
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Complexity metrics of a file, estimated by its language processor
+    pub fn calculate_complexity_metrics(&self, file_path: &Path, content: &str) -> CodeComplexity {
+        if let Some(processor) = self.get_processor(file_path) {
+            processor.calculate_complexity(content)
+        } else {
+            estimate_complexity(content, brace_nesting_depth(content))
+        }
+    }
+}
 
-        // Simplified complexity calculation
-        let number_of_functions = content.matches("fn ").count()
-            + content.matches("def ").count()
-            + content.matches("function ").count()
-            + content.matches("async ").count();  // C# async methods
+impl Default for LanguageProcessorManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-        let number_of_classes =
-            content.matches("class ").count() 
-            + content.matches("struct ").count()
-            + content.matches("interface ").count();  // C# interfaces
+/// Complexity from decision points and declarations, with the given nesting depth
+pub fn estimate_complexity(content: &str, max_nesting_depth: usize) -> CodeComplexity {
+    let code: Vec<&str> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !is_comment_line(line))
+        .collect();
+    let count = |regex: &Regex| code.iter().map(|line| regex.find_iter(line).count()).sum::<usize>();
 
-        // Simplified cyclomatic complexity calculation
-        let cyclomatic_complexity = 1.0
-            + content.matches("if ").count() as f64
-            + content.matches("while ").count() as f64
-            + content.matches("for ").count() as f64
-            + content.matches("foreach ").count() as f64  // C# foreach
-            + content.matches("match ").count() as f64
-            + content.matches("switch ").count() as f64  // C# switch
-            + content.matches("case ").count() as f64;
+    CodeComplexity {
+        cyclomatic_complexity: 1.0 + count(&DECISION_REGEX) as f64,
+        lines_of_code: code.len(),
+        number_of_functions: count(&FUNCTION_REGEX),
+        number_of_classes: count(&CLASS_REGEX),
+        max_nesting_depth,
+    }
+}
 
-        CodeComplexity {
-            cyclomatic_complexity,
-            lines_of_code,
-            number_of_functions,
-            number_of_classes,
+/// Deepest `{` block nesting outside of comments
+pub fn brace_nesting_depth(content: &str) -> usize {
+    let mut depth: usize = 0;
+    let mut max_depth = 0;
+    for line in content.lines().map(str::trim).filter(|line| !is_comment_line(line)) {
+        for c in line.chars() {
+            match c {
+                '{' => {
+                    depth += 1;
+                    max_depth = max_depth.max(depth);
+                }
+                '}' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+    }
+    max_depth
+}
+
+fn is_comment_line(line: &str) -> bool {
+    line.starts_with("//") || line.starts_with('#') || line.starts_with('*') || line.starts_with("/*")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complexity_metrics() {
+        let manager = LanguageProcessorManager::new();
+        let rust = "// if this were a loop\nfn handle(order: &Order) {\n    if order.paid && !order.shipped {\n        for item in &order.items {\n            ship(item);\n        }\n    }\n}\n";
+        let metrics = manager.calculate_complexity_metrics(Path::new("src/orders.rs"), rust);
+        assert_eq!(metrics.cyclomatic_complexity, 4.0);
+        assert_eq!(metrics.max_nesting_depth, 3);
+        assert_eq!(metrics.number_of_functions, 1);
+        assert_eq!(metrics.lines_of_code, 7);
+
+        let python = "def handle(order):\n    if order.paid:\n        for item in order.items:\n            ship(item)\n";
+        let metrics = manager.calculate_complexity_metrics(Path::new("orders.py"), python);
+        assert_eq!(metrics.cyclomatic_complexity, 3.0);
+        assert_eq!(metrics.max_nesting_depth, 3);
+        assert!(metrics.score() > 0.0 && metrics.score() < 1.0);
+    }
+
+    #[derive(Debug)]
+    struct LuaProcessor;
+
+    impl LanguageProcessor for LuaProcessor {
+        fn supported_extensions(&self) -> Vec<&'static str> {
+            vec!["lua", "tsx"]
+        }
+
+        fn extract_dependencies(&self, content: &str, _file_path: &Path) -> Vec<Dependency> {
+            content
+                .lines()
+                .filter_map(|line| line.trim().strip_prefix("require "))
+                .map(|name| Dependency {
+                    name: name.trim_matches('"').to_string(),
+                    path: None,
+                    is_external: true,
+                    line_number: None,
+                    dependency_type: "require".to_string(),
+                    version: None,
+                })
+                .collect()
+        }
+
+        fn determine_component_type(&self, _file_path: &Path, _content: &str) -> String {
+            "lua_module".to_string()
+        }
+
+        fn is_important_line(&self, line: &str) -> bool {
+            line.starts_with("function")
+        }
+
+        fn language_name(&self) -> &'static str {
+            "Lua"
+        }
+
+        fn extract_interfaces(&self, _content: &str, _file_path: &Path) -> Vec<InterfaceInfo> {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn test_processor_registry() {
+        let builtin = LanguageProcessorManager::builtin();
+        assert!(builtin.get_processor(Path::new("init.lua")).is_none());
+        assert_eq!(builtin.get_processor(Path::new("App.tsx")).unwrap().language_name(), "TypeScript");
+
+        let mut manager = builtin.clone();
+        manager.add(Arc::new(LuaProcessor));
+        assert_eq!(manager.get_processor(Path::new("App.tsx")).unwrap().language_name(), "Lua");
+        let dependencies = manager.extract_dependencies(Path::new("init.lua"), "require \"socket\"\nlocal x = 1\n");
+        assert_eq!(dependencies.len(), 1);
+        assert_eq!(dependencies[0].name, "socket");
+        assert!(builtin.get_processor(Path::new("init.lua")).is_none());
+    }
+
+    #[test]
+    fn test_supported_languages() {
+        let manager = LanguageProcessorManager::new();
+        let languages = manager.supported_languages();
+        assert!(languages.iter().any(|(name, extensions)| *name == "Rust" && extensions.contains(&"rs")));
+        // Every listed extension is handled by the language it is listed under
+        for (language, extensions) in languages {
+            for extension in extensions {
+                let processor = manager.get_processor(Path::new(&format!("file.{}", extension))).unwrap();
+                assert_eq!(processor.language_name(), language, ".{}", extension);
+            }
         }
     }
 }
 
 // Submodules
+pub mod cpp;
 pub mod csharp;
 pub mod java;
 pub mod javascript;
+pub mod js_routes;
 pub mod kotlin;
 pub mod php;
 pub mod python;
 pub mod react;
 pub mod rust;
+pub mod sql;
 pub mod svelte;
 pub mod swift;
 pub mod typescript;
@@ -51,6 +51,12 @@ impl PhpProcessor {
     }
 }
 
+impl Default for PhpProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl LanguageProcessor for PhpProcessor {
     fn supported_extensions(&self) -> Vec<&'static str> {
         vec!["php"]
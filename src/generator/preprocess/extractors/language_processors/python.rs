@@ -1,5 +1,5 @@
-use super::{Dependency, LanguageProcessor};
-use crate::types::code::{InterfaceInfo, ParameterInfo};
+use super::{Dependency, LanguageProcessor, estimate_complexity};
+use crate::types::code::{CodeComplexity, InterfaceInfo, ParameterInfo};
 use regex::Regex;
 use std::path::Path;
 
@@ -9,8 +9,12 @@ pub struct PythonProcessor {
     from_import_regex: Regex,
     function_regex: Regex,
     class_regex: Regex,
-    method_regex: Regex,
-    async_function_regex: Regex,
+    route_decorator_regex: Regex,
+    route_methods_regex: Regex,
+    django_path_regex: Regex,
+    model_base_regex: Regex,
+    field_regex: Regex,
+    django_field_regex: Regex,
 }
 
 impl PythonProcessor {
@@ -18,14 +22,24 @@ impl PythonProcessor {
         Self {
             import_regex: Regex::new(r"^\s*import\s+([^\s#]+)").unwrap(),
             from_import_regex: Regex::new(r"^\s*from\s+([^\s]+)\s+import").unwrap(),
-            function_regex: Regex::new(r"^\s*def\s+(\w+)\s*\(([^)]*)\)\s*(?:->\s*([^:]+))?:").unwrap(),
-            class_regex: Regex::new(r"^\s*class\s+(\w+)(?:\([^)]*\))?:").unwrap(),
-            method_regex: Regex::new(r"^\s+def\s+(\w+)\s*\(([^)]*)\)\s*(?:->\s*([^:]+))?:").unwrap(),
-            async_function_regex: Regex::new(r"^\s*async\s+def\s+(\w+)\s*\(([^)]*)\)\s*(?:->\s*([^:]+))?:").unwrap(),
+            function_regex: Regex::new(r"^(\s*)(async\s+)?def\s+(\w+)\s*\(").unwrap(),
+            class_regex: Regex::new(r"^(\s*)class\s+(\w+)\s*(?:\((.*)\))?\s*:").unwrap(),
+            route_decorator_regex: Regex::new(r#"^@\w+(?:\.\w+)*\.(get|post|put|delete|patch|head|options|trace|websocket|route|api_route)\(\s*(?:path\s*=\s*|rule\s*=\s*)?r?["']([^"']*)["'](.*)\)"#).unwrap(),
+            route_methods_regex: Regex::new(r"methods\s*=\s*[\[(]([^\])]*)[\])]").unwrap(),
+            django_path_regex: Regex::new(r#"^\s*(?:re_)?path\(\s*r?["']([^"']*)["']\s*,\s*([\w.]+)"#).unwrap(),
+            model_base_regex: Regex::new(r"\b(?:BaseModel|BaseSettings|SQLModel|TypedDict|NamedTuple|models\.Model|Schema)\b").unwrap(),
+            field_regex: Regex::new(r"^(\w+)\s*:\s*(.+)$").unwrap(),
+            django_field_regex: Regex::new(r"^(\w+)\s*=\s*(?:models\.)?(\w+Field|ForeignKey|OneToOneField|ManyToManyField)\((.*)").unwrap(),
         }
     }
 }
 
+impl Default for PythonProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl LanguageProcessor for PythonProcessor {
     fn supported_extensions(&self) -> Vec<&'static str> {
         vec!["py"]
@@ -101,6 +115,18 @@ impl LanguageProcessor for PythonProcessor {
         }
     }
     
+    /// Python nests by indentation: depth is the deepest indentation in units of the smallest one
+    fn calculate_complexity(&self, content: &str) -> CodeComplexity {
+        let indents: Vec<usize> = content
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(|line| line.len() - line.trim_start().len())
+            .collect();
+        let unit = indents.iter().copied().filter(|indent| *indent > 0).min().unwrap_or(1);
+        let max_nesting_depth = indents.iter().max().copied().unwrap_or(0) / unit;
+        estimate_complexity(content, max_nesting_depth)
+    }
+
     fn is_important_line(&self, line: &str) -> bool {
         let trimmed = line.trim();
         
@@ -109,6 +135,11 @@ impl LanguageProcessor for PythonProcessor {
            trimmed.starts_with("from ") {
             return true;
         }
+
+        // Route decorators and Django URL patterns
+        if self.route_decorator_regex.is_match(trimmed) || self.django_path_regex.is_match(trimmed) {
+            return true;
+        }
         
         if trimmed.contains("TODO") || trimmed.contains("FIXME") || 
            trimmed.contains("NOTE") || trimmed.contains("HACK") {
@@ -125,83 +156,123 @@ impl LanguageProcessor for PythonProcessor {
     fn extract_interfaces(&self, content: &str, _file_path: &Path) -> Vec<InterfaceInfo> {
         let mut interfaces = Vec::new();
         let lines: Vec<&str> = content.lines().collect();
-        
+        // Enclosing classes and functions with their indentation; `None` for a function
+        let mut scopes: Vec<(usize, Option<String>)> = Vec::new();
+
         for (i, line) in lines.iter().enumerate() {
-            // Extract async function definitions
-            if let Some(captures) = self.async_function_regex.captures(line) {
-                let name = captures.get(1).map(|m| m.as_str()).unwrap_or("").to_string();
-                let params_str = captures.get(2).map(|m| m.as_str()).unwrap_or("");
-                let return_type = captures.get(3).map(|m| m.as_str().trim().to_string());
-                
-                let parameters = self.parse_python_parameters(params_str);
-                
-                interfaces.push(InterfaceInfo {
-                    name,
-                    interface_type: "async_function".to_string(),
-                    visibility: "public".to_string(),
-                    parameters,
-                    return_type,
-                    description: self.extract_docstring(&lines, i),
-                });
-            }
-            // Extract regular function definitions
-            else if let Some(captures) = self.function_regex.captures(line) {
-                let name = captures.get(1).map(|m| m.as_str()).unwrap_or("").to_string();
-                let params_str = captures.get(2).map(|m| m.as_str()).unwrap_or("");
-                let return_type = captures.get(3).map(|m| m.as_str().trim().to_string());
-                
-                let parameters = self.parse_python_parameters(params_str);
-                
+            // Extract function and method definitions, async or not
+            if let Some(captures) = self.function_regex.captures(line) {
+                let indent = captures.get(1).map_or(0, |m| m.as_str().len());
+                let is_async = captures.get(2).is_some();
+                let name = captures.get(3).map(|m| m.as_str()).unwrap_or("").to_string();
+                scopes.retain(|(scope_indent, _)| *scope_indent < indent);
+                let class = scopes.last().and_then(|(_, class)| class.clone());
+                scopes.push((indent, None));
+
+                let (signature, body_line) = Self::collect_signature(&lines, i);
+                let (params_str, return_type) = Self::split_signature(&signature);
+                let decorators = Self::collect_decorators(&lines, i);
+
+                let mut kind = if class.is_some() { "method" } else { "function" };
+                for decorator in &decorators {
+                    match decorator.as_str() {
+                        "@staticmethod" => kind = "static_method",
+                        "@classmethod" => kind = "class_method",
+                        "@property" | "@cached_property" | "@functools.cached_property" => kind = "property",
+                        _ => {}
+                    }
+                }
+                let interface_type = if is_async { format!("async_{}", kind) } else { kind.to_string() };
+                let visibility = if name.starts_with("__") && name.ends_with("__") {
+                    "special"
+                } else if name.starts_with('_') {
+                    "private"
+                } else {
+                    "public"
+                };
+                let qualified = match &class {
+                    Some(class) => format!("{}.{}", class, name),
+                    None => name,
+                };
+
+                for route in self.extract_route_decorators(&decorators) {
+                    interfaces.push(InterfaceInfo {
+                        name: route,
+                        interface_type: "route".to_string(),
+                        visibility: "public".to_string(),
+                        parameters: self.parse_python_parameters(&params_str),
+                        return_type: return_type.clone(),
+                        description: Some(format!("Handled by `{}`", qualified)),
+                    });
+                }
+
                 interfaces.push(InterfaceInfo {
-                    name,
-                    interface_type: "function".to_string(),
-                    visibility: "public".to_string(),
-                    parameters,
+                    name: qualified,
+                    interface_type,
+                    visibility: visibility.to_string(),
+                    parameters: self.parse_python_parameters(&params_str),
                     return_type,
-                    description: self.extract_docstring(&lines, i),
+                    description: Self::describe(self.extract_docstring(&lines, body_line), &decorators),
                 });
             }
-            
-            // Extract class definitions
+
+            // Extract class definitions; dataclasses and Pydantic/Django models list their fields
             if let Some(captures) = self.class_regex.captures(line) {
-                let name = captures.get(1).map(|m| m.as_str()).unwrap_or("").to_string();
-                
-                interfaces.push(InterfaceInfo {
-                    name,
-                    interface_type: "class".to_string(),
-                    visibility: "public".to_string(),
-                    parameters: Vec::new(),
-                    return_type: None,
-                    description: self.extract_docstring(&lines, i),
+                let indent = captures.get(1).map_or(0, |m| m.as_str().len());
+                let name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
+                let bases = captures.get(3).map_or("", |m| m.as_str());
+                scopes.retain(|(scope_indent, _)| *scope_indent < indent);
+                scopes.push((indent, Some(name.clone())));
+
+                let decorators = Self::collect_decorators(&lines, i);
+                let is_dataclass = decorators.iter().any(|decorator| {
+                    let decorator = decorator.split('(').next().unwrap_or(decorator);
+                    decorator.ends_with("dataclass") || matches!(decorator, "@attr.s" | "@attrs.define" | "@define" | "@frozen")
                 });
-            }
-            
-            // Extract method definitions (inside classes)
-            if let Some(captures) = self.method_regex.captures(line) {
-                let name = captures.get(1).map(|m| m.as_str()).unwrap_or("").to_string();
-                let params_str = captures.get(2).map(|m| m.as_str()).unwrap_or("");
-                let return_type = captures.get(3).map(|m| m.as_str().trim().to_string());
-                
-                let parameters = self.parse_python_parameters(params_str);
-                let visibility = if name.starts_with('_') {
-                    if name.starts_with("__") && name.ends_with("__") {
-                        "special"
-                    } else {
-                        "private"
-                    }
+                let is_model = self.model_base_regex.is_match(bases);
+                let interface_type = if is_dataclass {
+                    "dataclass"
+                } else if is_model {
+                    "model"
                 } else {
-                    "public"
+                    "class"
                 };
-                
+                let parameters = if is_dataclass || is_model {
+                    self.extract_fields(&lines, i, indent)
+                } else {
+                    Vec::new()
+                };
+
+                let mut description = Self::describe(self.extract_docstring(&lines, i), &decorators);
+                if !bases.trim().is_empty() {
+                    let bases = format!("Bases: {}", bases.trim());
+                    description = Some(description.map_or(bases.clone(), |description| format!("{} {}", description, bases)));
+                }
                 interfaces.push(InterfaceInfo {
-                    name,
-                    interface_type: "method".to_string(),
-                    visibility: visibility.to_string(),
+                    name: name.clone(),
+                    interface_type: interface_type.to_string(),
+                    visibility: if name.starts_with('_') { "private" } else { "public" }.to_string(),
                     parameters,
-                    return_type,
-                    description: self.extract_docstring(&lines, i),
+                    return_type: None,
+                    description,
                 });
             }
+
+            // Extract Django URL patterns, like `path("users/<int:pk>/", views.user_detail)`
+            if let Some(captures) = self.django_path_regex.captures(line) {
+                let route = captures.get(1).map_or("", |m| m.as_str());
+                let handler = captures.get(2).map_or("", |m| m.as_str());
+                if handler != "include" {
+                    interfaces.push(InterfaceInfo {
+                        name: format!("ANY /{}", route.trim_start_matches(['^', '/'])),
+                        interface_type: "route".to_string(),
+                        visibility: "public".to_string(),
+                        parameters: Vec::new(),
+                        return_type: None,
+                        description: Some(format!("Handled by `{}`", handler)),
+                    });
+                }
+            }
         }
         
         interfaces
@@ -209,6 +280,180 @@ impl LanguageProcessor for PythonProcessor {
 }
 
 impl PythonProcessor {
+    /// Lines from the `def` joined up to the colon opening its body, and the line of that colon
+    fn collect_signature(lines: &[&str], start: usize) -> (String, usize) {
+        let mut signature = String::new();
+        let mut depth = 0;
+        for (index, line) in lines.iter().enumerate().skip(start).take(30) {
+            for c in line.trim().chars() {
+                match c {
+                    '(' | '[' | '{' => depth += 1,
+                    ')' | ']' | '}' => depth -= 1,
+                    ':' if depth == 0 => return (signature, index),
+                    _ => {}
+                }
+                signature.push(c);
+            }
+            signature.push(' ');
+        }
+        (signature, start)
+    }
+
+    /// Parameters and return type of a `def` signature
+    fn split_signature(signature: &str) -> (String, Option<String>) {
+        let Some(open) = signature.find('(') else {
+            return (String::new(), None);
+        };
+        let mut depth = 0;
+        for (at, c) in signature[open..].char_indices() {
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        let params = signature[open + 1..open + at].trim().trim_end_matches(',').to_string();
+                        let return_type = signature[open + at + 1..]
+                            .trim()
+                            .strip_prefix("->")
+                            .map(|return_type| return_type.trim().to_string())
+                            .filter(|return_type| !return_type.is_empty());
+                        return (params, return_type);
+                    }
+                }
+                _ => {}
+            }
+        }
+        (signature[open + 1..].to_string(), None)
+    }
+
+    /// Decorator lines directly above a definition, top to bottom
+    fn collect_decorators(lines: &[&str], current_line: usize) -> Vec<String> {
+        let mut decorators = Vec::new();
+        for line in lines[..current_line].iter().rev() {
+            let trimmed = line.trim();
+            if trimmed.starts_with('@') {
+                decorators.insert(0, trimmed.to_string());
+            } else if !(trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with(')')) {
+                break;
+            }
+        }
+        decorators
+    }
+
+    /// Routes declared by FastAPI/Flask decorators, like `@app.get("/users/{id}")` or
+    /// `@bp.route("/users", methods=["GET", "POST"])`
+    fn extract_route_decorators(&self, decorators: &[String]) -> Vec<String> {
+        let mut routes = Vec::new();
+        for decorator in decorators {
+            let Some(captures) = self.route_decorator_regex.captures(decorator) else {
+                continue;
+            };
+            let verb = captures.get(1).map_or("", |m| m.as_str());
+            let path = captures.get(2).map_or("", |m| m.as_str());
+            match verb {
+                "route" | "api_route" => {
+                    let options = captures.get(3).map_or("", |m| m.as_str());
+                    let methods: Vec<String> = self
+                        .route_methods_regex
+                        .captures(options)
+                        .and_then(|methods| methods.get(1))
+                        .map(|methods| {
+                            methods
+                                .as_str()
+                                .split(',')
+                                .map(|method| method.trim().trim_matches(['"', '\'']).to_uppercase())
+                                .filter(|method| !method.is_empty())
+                                .collect()
+                        })
+                        .unwrap_or_else(|| vec!["GET".to_string()]);
+                    routes.extend(methods.into_iter().map(|method| format!("{} {}", method, path)));
+                }
+                verb => routes.push(format!("{} {}", verb.to_uppercase(), path)),
+            }
+        }
+        routes
+    }
+
+    /// Annotated fields of a dataclass or model body (`name: str = ""`), and Django model fields
+    /// (`name = models.CharField(...)`)
+    fn extract_fields(&self, lines: &[&str], class_line: usize, class_indent: usize) -> Vec<ParameterInfo> {
+        let mut fields = Vec::new();
+        let mut body_indent = None;
+        for line in lines.iter().skip(class_line + 1) {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let indent = line.len() - line.trim_start().len();
+            if indent <= class_indent {
+                break;
+            }
+            if *body_indent.get_or_insert(indent) != indent {
+                continue;
+            }
+            let parts = Self::split_top_level(trimmed, '=');
+            let [annotated, default @ ..] = parts.as_slice() else {
+                continue;
+            };
+            if let Some(captures) = self.field_regex.captures(annotated.trim()) {
+                let name = captures.get(1).map_or("", |m| m.as_str()).to_string();
+                let field_type = captures.get(2).map_or("", |m| m.as_str()).trim().to_string();
+                let has_default = !default.is_empty();
+                if field_type.starts_with("ClassVar") || name == "model_config" {
+                    continue;
+                }
+                fields.push(ParameterInfo {
+                    name,
+                    is_optional: has_default || Self::is_optional_type(&field_type),
+                    param_type: field_type,
+                    description: None,
+                });
+            } else if let Some(captures) = self.django_field_regex.captures(trimmed) {
+                let options = captures.get(3).map_or("", |m| m.as_str());
+                fields.push(ParameterInfo {
+                    name: captures.get(1).map_or("", |m| m.as_str()).to_string(),
+                    param_type: captures.get(2).map_or("", |m| m.as_str()).to_string(),
+                    is_optional: options.contains("null=True") || options.contains("blank=True") || options.contains("default="),
+                    description: None,
+                });
+            }
+        }
+        fields
+    }
+
+    fn is_optional_type(param_type: &str) -> bool {
+        param_type.starts_with("Optional[") || param_type.contains("| None") || param_type.contains("None |")
+    }
+
+    /// Docstring followed by the decorators of the definition
+    fn describe(docstring: Option<String>, decorators: &[String]) -> Option<String> {
+        let mut parts: Vec<String> = docstring.into_iter().collect();
+        if !decorators.is_empty() {
+            parts.push(format!("Decorators: {}", decorators.join(" ")));
+        }
+        if parts.is_empty() { None } else { Some(parts.join(" ")) }
+    }
+
+    /// Split on `separator` outside of brackets, so `Dict[str, int]` stays one parameter
+    fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let mut depth = 0;
+        let mut start = 0;
+        for (at, c) in text.char_indices() {
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                _ if c == separator && depth == 0 => {
+                    parts.push(&text[start..at]);
+                    start = at + c.len_utf8();
+                }
+                _ => {}
+            }
+        }
+        parts.push(&text[start..]);
+        parts
+    }
+
     /// Parse Python function parameters
     fn parse_python_parameters(&self, params_str: &str) -> Vec<ParameterInfo> {
         let mut parameters = Vec::new();
@@ -217,41 +462,45 @@ impl PythonProcessor {
             return parameters;
         }
         
-        // Simple parameter parsing, handling basic cases
-        for param in params_str.split(',') {
+        for param in Self::split_top_level(params_str, ',') {
             let param = param.trim();
-            if param.is_empty() || param == "self" || param == "cls" {
+            // `*` and `/` only mark keyword-only and positional-only parameters
+            if param.is_empty() || param == "self" || param == "cls" || param == "*" || param == "/" {
                 continue;
             }
             
             // Parse parameter format: name, name: type, name = default, name: type = default
-            let is_optional = param.contains('=');
             let mut param_type = "Any".to_string();
             let mut name = param.to_string();
+            let mut is_optional = false;
             
-            // Handle type annotations
-            if let Some(colon_pos) = param.find(':') {
-                name = param[..colon_pos].trim().to_string();
-                let type_part = param[colon_pos + 1..].trim();
-                
-                if let Some(eq_pos) = type_part.find('=') {
-                    param_type = type_part[..eq_pos].trim().to_string();
-                } else {
-                    param_type = type_part.to_string();
-                }
+            // Handle type annotations; a default may hold `=` and `:` in brackets (`Field(default=1)`)
+            if let [annotated, default @ ..] = Self::split_top_level(param, '=').as_slice()
+                && let Some(colon_pos) = annotated.find(':')
+            {
+                name = annotated[..colon_pos].trim().to_string();
+                param_type = annotated[colon_pos + 1..].trim().to_string();
+                is_optional = !default.is_empty() || Self::is_optional_type(&param_type);
             } else if let Some(eq_pos) = param.find('=') {
                 name = param[..eq_pos].trim().to_string();
+                is_optional = true;
             }
             
             // Handle special parameters
             if name.starts_with('*') {
+                let annotated = param_type != "Any";
                 if name.starts_with("**") {
                     name = name.trim_start_matches("**").to_string();
-                    param_type = "dict".to_string();
+                    if !annotated {
+                        param_type = "dict".to_string();
+                    }
                 } else {
                     name = name.trim_start_matches('*').to_string();
-                    param_type = "tuple".to_string();
+                    if !annotated {
+                        param_type = "tuple".to_string();
+                    }
                 }
+                is_optional = true;
             }
             
             parameters.push(ParameterInfo {
@@ -315,4 +564,93 @@ impl PythonProcessor {
         
         None
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'a>(interfaces: &'a [InterfaceInfo], name: &str) -> &'a InterfaceInfo {
+        interfaces
+            .iter()
+            .find(|interface| interface.name == name)
+            .unwrap_or_else(|| panic!("{} not extracted", name))
+    }
+
+    #[test]
+    fn test_extract_typed_signatures_models_and_routes() {
+        let processor = PythonProcessor::new();
+        let content = r#"
+@dataclass(frozen=True)
+class Order:
+    id: int
+    items: list[str] = field(default_factory=list)
+    note: Optional[str] = None
+
+class UserIn(BaseModel):
+    name: str
+    age: Annotated[int, Field(ge=0, default=18)] = 18
+
+class Repository:
+    @staticmethod
+    def connect(url: str) -> "Repository":
+        pass
+
+    async def fetch(
+        self,
+        order_id: int,
+        filters: Dict[str, int] | None = None,
+        *,
+        limit: int = 10,
+    ) -> list[Order]:
+        """Fetch orders."""
+        def key(order): return order.id
+
+@app.get("/orders/{order_id}")
+async def get_order(order_id: int, repo: Repository = Depends(repo)) -> Order:
+    ...
+
+@bp.route("/users", methods=["GET", "POST"])
+def users():
+    ...
+
+urlpatterns = [
+    path("users/<int:pk>/", views.user_detail, name="user-detail"),
+    path("api/", include("api.urls")),
+]
+"#;
+        let interfaces = processor.extract_interfaces(content, Path::new("app/main.py"));
+
+        let order = find(&interfaces, "Order");
+        assert_eq!(order.interface_type, "dataclass");
+        let fields: Vec<(&str, &str, bool)> =
+            order.parameters.iter().map(|p| (p.name.as_str(), p.param_type.as_str(), p.is_optional)).collect();
+        assert_eq!(fields, [("id", "int", false), ("items", "list[str]", true), ("note", "Optional[str]", true)]);
+        let user = find(&interfaces, "UserIn");
+        assert_eq!(user.interface_type, "model");
+        assert_eq!(user.parameters[1].param_type, "Annotated[int, Field(ge=0, default=18)]");
+
+        assert_eq!(find(&interfaces, "Repository.connect").interface_type, "static_method");
+        let fetch = find(&interfaces, "Repository.fetch");
+        assert_eq!(fetch.interface_type, "async_method");
+        assert_eq!(fetch.return_type.as_deref(), Some("list[Order]"));
+        assert_eq!(fetch.description.as_deref(), Some("Fetch orders."));
+        let params: Vec<(&str, &str, bool)> =
+            fetch.parameters.iter().map(|p| (p.name.as_str(), p.param_type.as_str(), p.is_optional)).collect();
+        assert_eq!(
+            params,
+            [("order_id", "int", false), ("filters", "Dict[str, int] | None", true), ("limit", "int", true)]
+        );
+        // Functions nested in a method are not methods
+        assert_eq!(find(&interfaces, "key").interface_type, "function");
+
+        let route = find(&interfaces, "GET /orders/{order_id}");
+        assert_eq!(route.interface_type, "route");
+        assert_eq!(route.description.as_deref(), Some("Handled by `get_order`"));
+        assert_eq!(route.return_type.as_deref(), Some("Order"));
+        assert_eq!(find(&interfaces, "get_order").interface_type, "async_function");
+        assert_eq!(find(&interfaces, "POST /users").description.as_deref(), Some("Handled by `users`"));
+        assert!(interfaces.iter().any(|interface| interface.name == "GET /users"));
+        assert_eq!(find(&interfaces, "ANY /users/<int:pk>/").description.as_deref(), Some("Handled by `views.user_detail`"));
+        assert!(!interfaces.iter().any(|interface| interface.name == "ANY /api/"));
+    }
+}
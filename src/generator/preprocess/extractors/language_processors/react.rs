@@ -18,6 +18,12 @@ impl ReactProcessor {
     }
 }
 
+impl Default for ReactProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl LanguageProcessor for ReactProcessor {
     fn supported_extensions(&self) -> Vec<&'static str> {
         vec!["jsx", "tsx"]
@@ -153,7 +159,7 @@ impl LanguageProcessor for ReactProcessor {
         "React"
     }
 
-    fn extract_interfaces(&self, content: &str, _file_path: &Path) -> Vec<InterfaceInfo> {
+    fn extract_interfaces(&self, content: &str, file_path: &Path) -> Vec<InterfaceInfo> {
         let mut interfaces = Vec::new();
         let lines: Vec<&str> = content.lines().collect();
 
@@ -198,6 +204,8 @@ impl LanguageProcessor for ReactProcessor {
             }
         }
 
+        interfaces.extend(super::js_routes::extract_routes(content, file_path));
+
         interfaces
     }
 }
@@ -12,6 +12,10 @@ pub struct RustProcessor {
     trait_regex: Regex,
     impl_regex: Regex,
     enum_regex: Regex,
+    route_attribute_regex: Regex,
+    route_method_option_regex: Regex,
+    router_route_regex: Regex,
+    router_method_regex: Regex,
 }
 
 impl RustProcessor {
@@ -19,15 +23,25 @@ impl RustProcessor {
         Self {
             use_regex: Regex::new(r"^\s*use\s+([^;]+);").unwrap(),
             mod_regex: Regex::new(r"^\s*mod\s+([^;]+);").unwrap(),
-            fn_regex: Regex::new(r"^\s*(pub\s+)?(async\s+)?fn\s+(\w+)\s*\(([^)]*)\)\s*(?:->\s*([^{]+))?").unwrap(),
-            struct_regex: Regex::new(r"^\s*(pub\s+)?struct\s+(\w+)").unwrap(),
-            trait_regex: Regex::new(r"^\s*(pub\s+)?trait\s+(\w+)").unwrap(),
-            impl_regex: Regex::new(r"^\s*impl(?:\s*<[^>]*>)?\s+(?:(\w+)\s+for\s+)?(\w+)").unwrap(),
-            enum_regex: Regex::new(r"^\s*(pub\s+)?enum\s+(\w+)").unwrap(),
+            fn_regex: Regex::new(r#"^\s*(pub(?:\([^)]*\))?\s+)?((?:(?:const|async|unsafe|default)\s+|extern\s+(?:"[^"]*"\s+)?)*)fn\s+(\w+)"#).unwrap(),
+            struct_regex: Regex::new(r"^\s*(pub(?:\([^)]*\))?\s+)?struct\s+(\w+)").unwrap(),
+            trait_regex: Regex::new(r"^\s*(pub(?:\([^)]*\))?\s+)?(?:unsafe\s+)?(?:auto\s+)?trait\s+(\w+)").unwrap(),
+            impl_regex: Regex::new(r"^\s*(?:unsafe\s+)?impl\b").unwrap(),
+            enum_regex: Regex::new(r"^\s*(pub(?:\([^)]*\))?\s+)?enum\s+(\w+)").unwrap(),
+            route_attribute_regex: Regex::new(r#"^#\[(?:\w+::)*(get|post|put|delete|patch|head|options|trace|connect|route)\s*\(\s*"([^"]*)"(.*)\)\s*\]"#).unwrap(),
+            route_method_option_regex: Regex::new(r#"method\s*=\s*"(\w+)""#).unwrap(),
+            router_route_regex: Regex::new(r#"^\.route\(\s*"([^"]*)"\s*,"#).unwrap(),
+            router_method_regex: Regex::new(r"\b(get|post|put|delete|patch|head|options|trace|any)\((?:\s*([\w:]+)\s*)?\)(?:\s*\.to\(\s*([\w:]+)\s*\))?").unwrap(),
         }
     }
 }
 
+impl Default for RustProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl LanguageProcessor for RustProcessor {
     fn supported_extensions(&self) -> Vec<&'static str> {
         vec!["rs"]
@@ -51,7 +65,7 @@ impl LanguageProcessor for RustProcessor {
                     
                     dependencies.push(Dependency {
                         name: dependency_name,
-                        path: Some(source_file.clone()),
+                        path: Some(use_str.to_string()),
                         is_external,
                         line_number: Some(line_num + 1),
                         dependency_type: "use".to_string(),
@@ -126,8 +140,14 @@ impl LanguageProcessor for RustProcessor {
             return true;
         }
         
-        // impl blocks
-        if trimmed.starts_with("impl ") {
+        // Restricted visibility and qualified functions (`pub(crate) fn`, `const fn`...), generic
+        // and unsafe impl blocks
+        if self.fn_regex.is_match(line) || self.impl_regex.is_match(line) {
+            return true;
+        }
+
+        // Route attributes and router registrations
+        if self.route_attribute_regex.is_match(trimmed) || trimmed.starts_with(".route(") {
             return true;
         }
 
@@ -157,101 +177,490 @@ impl LanguageProcessor for RustProcessor {
     fn extract_interfaces(&self, content: &str, _file_path: &Path) -> Vec<InterfaceInfo> {
         let mut interfaces = Vec::new();
         let lines: Vec<&str> = content.lines().collect();
-        
+        // Enclosing impl and trait blocks, with the brace depth they were opened at
+        let mut scopes: Vec<(Scope, i32, bool)> = Vec::new();
+        let mut depth = 0;
+
         for (i, line) in lines.iter().enumerate() {
-            // Extract function definitions
+            let owner = scopes
+                .last()
+                .filter(|(_, open_depth, entered)| *entered && depth == open_depth + 1)
+                .map(|(scope, _, _)| scope.clone());
+
+            // Extract function definitions, as methods inside impl and trait blocks
             if let Some(captures) = self.fn_regex.captures(line) {
-                let visibility = if captures.get(1).is_some() { "public" } else { "private" };
-                let is_async = captures.get(2).is_some();
+                let mut visibility = Self::visibility(captures.get(1).map(|m| m.as_str()));
+                let is_async = captures.get(2).is_some_and(|m| m.as_str().contains("async"));
                 let name = captures.get(3).map(|m| m.as_str()).unwrap_or("").to_string();
-                let params_str = captures.get(4).map(|m| m.as_str()).unwrap_or("");
-                let return_type = captures.get(5).map(|m| m.as_str().trim().to_string());
-                
-                let parameters = self.parse_rust_parameters(params_str);
-                let interface_type = if is_async { "async_function" } else { "function" };
-                
+                let signature = Self::parse_signature(&Self::collect_signature(&lines, i));
+
+                let (name, kind) = match &owner {
+                    Some(Scope::Impl { self_type, trait_name }) => {
+                        if trait_name.is_some() {
+                            visibility = "public";
+                        }
+                        (format!("{}::{}", self_type, name), "method")
+                    }
+                    Some(Scope::Trait { name: trait_name, visibility: trait_visibility }) => {
+                        visibility = trait_visibility;
+                        (format!("{}::{}", trait_name, name), "trait_method")
+                    }
+                    None => (name, "function"),
+                };
+                let interface_type = if is_async { format!("async_{}", kind) } else { kind.to_string() };
+
+                let mut details = Vec::new();
+                if let Some(generics) = &signature.generics {
+                    details.push(format!("Generics: {}", generics));
+                }
+                if let Some(where_clause) = &signature.where_clause {
+                    details.push(format!("Where: {}", where_clause));
+                }
+                let routes = self.extract_route_attributes(&lines, i);
+                if !routes.is_empty() {
+                    details.push(format!("Routes: {}", routes.join(", ")));
+                }
+                for route in routes {
+                    interfaces.push(InterfaceInfo {
+                        name: route,
+                        interface_type: "route".to_string(),
+                        visibility: "public".to_string(),
+                        parameters: self.parse_rust_parameters(&signature.params),
+                        return_type: signature.return_type.clone(),
+                        description: Some(format!("Handled by `{}`", name)),
+                    });
+                }
+
                 interfaces.push(InterfaceInfo {
                     name,
-                    interface_type: interface_type.to_string(),
+                    interface_type,
                     visibility: visibility.to_string(),
-                    parameters,
-                    return_type,
-                    description: self.extract_doc_comment(&lines, i),
+                    parameters: self.parse_rust_parameters(&signature.params),
+                    return_type: signature.return_type,
+                    description: self.describe(&lines, i, details),
                 });
             }
 
-            // Extract struct definitions
-            if let Some(captures) = self.struct_regex.captures(line) {
-                let visibility = if captures.get(1).is_some() { "public" } else { "private" };
-                let name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
-                
-                interfaces.push(InterfaceInfo {
-                    name,
-                    interface_type: "struct".to_string(),
-                    visibility: visibility.to_string(),
-                    parameters: Vec::new(),
-                    return_type: None,
-                    description: self.extract_doc_comment(&lines, i),
-                });
+            // Extract struct and enum definitions
+            for (regex, interface_type) in [(&self.struct_regex, "struct"), (&self.enum_regex, "enum")] {
+                if let Some(captures) = regex.captures(line) {
+                    let visibility = Self::visibility(captures.get(1).map(|m| m.as_str()));
+                    let name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
+                    let rest = &line[captures.get(0).map_or(0, |m| m.end())..];
+                    let details = Self::take_balanced(rest.trim_start(), '<', '>')
+                        .map(|generics| vec![format!("Generics: {}", generics)])
+                        .unwrap_or_default();
+
+                    interfaces.push(InterfaceInfo {
+                        name,
+                        interface_type: interface_type.to_string(),
+                        visibility: visibility.to_string(),
+                        parameters: Vec::new(),
+                        return_type: None,
+                        description: self.describe(&lines, i, details),
+                    });
+                }
             }
 
-            // Extract trait definitions
+            // Extract trait definitions, with their generics and supertraits
             if let Some(captures) = self.trait_regex.captures(line) {
-                let visibility = if captures.get(1).is_some() { "public" } else { "private" };
+                let visibility = Self::visibility(captures.get(1).map(|m| m.as_str()));
                 let name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
-                
+                let header = Self::collect_header(&lines, i);
+                let mut rest = header[header.find(name.as_str()).map_or(0, |at| at + name.len())..].trim_start();
+
+                let mut details = Vec::new();
+                if let Some(generics) = Self::take_balanced(rest, '<', '>') {
+                    rest = rest[generics.len()..].trim_start();
+                    details.push(format!("Generics: {}", generics));
+                }
+                if let Some(bounds) = rest.strip_prefix(':') {
+                    let bounds = bounds.split(" where ").next().unwrap_or("").trim();
+                    if !bounds.is_empty() {
+                        details.push(format!("Supertraits: {}", bounds));
+                    }
+                }
+
+                scopes.push((Scope::Trait { name: name.clone(), visibility }, depth, false));
                 interfaces.push(InterfaceInfo {
                     name,
                     interface_type: "trait".to_string(),
                     visibility: visibility.to_string(),
                     parameters: Vec::new(),
                     return_type: None,
-                    description: self.extract_doc_comment(&lines, i),
+                    description: self.describe(&lines, i, details),
                 });
             }
 
-            // Extract enum definitions
-            if let Some(captures) = self.enum_regex.captures(line) {
-                let visibility = if captures.get(1).is_some() { "public" } else { "private" };
-                let name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
-                
-                interfaces.push(InterfaceInfo {
-                    name,
-                    interface_type: "enum".to_string(),
-                    visibility: visibility.to_string(),
-                    parameters: Vec::new(),
-                    return_type: None,
-                    description: self.extract_doc_comment(&lines, i),
-                });
+            // Extract impl blocks, `impl Trait for Type` apart from inherent impls
+            if self.impl_regex.is_match(line) {
+                let header = Self::collect_header(&lines, i);
+                if let Some(implementation) = Self::parse_impl(&header) {
+                    let name = match &implementation.trait_name {
+                        Some(trait_name) => format!("{} for {}", trait_name, implementation.self_type),
+                        None => implementation.self_type.clone(),
+                    };
+                    let interface_type = if implementation.trait_name.is_some() { "trait_implementation" } else { "implementation" };
+                    let mut details = Vec::new();
+                    if let Some(generics) = &implementation.generics {
+                        details.push(format!("Generics: {}", generics));
+                    }
+                    if let Some(where_clause) = &implementation.where_clause {
+                        details.push(format!("Where: {}", where_clause));
+                    }
+
+                    scopes.push((
+                        Scope::Impl {
+                            self_type: Self::base_name(&implementation.self_type),
+                            trait_name: implementation.trait_name,
+                        },
+                        depth,
+                        false,
+                    ));
+                    interfaces.push(InterfaceInfo {
+                        name,
+                        interface_type: interface_type.to_string(),
+                        visibility: "public".to_string(),
+                        parameters: Vec::new(),
+                        return_type: None,
+                        description: self.describe(&lines, i, details),
+                    });
+                }
             }
 
-            // Extract impl blocks
-            if let Some(captures) = self.impl_regex.captures(line) {
-                let trait_name = captures.get(1).map(|m| m.as_str());
-                let struct_name = captures.get(2).map(|m| m.as_str()).unwrap_or("").to_string();
-                
-                let name = if let Some(trait_name) = trait_name {
-                    format!("{} for {}", trait_name, struct_name)
-                } else {
-                    struct_name
-                };
-                
-                interfaces.push(InterfaceInfo {
-                    name,
-                    interface_type: "implementation".to_string(),
-                    visibility: "public".to_string(),
-                    parameters: Vec::new(),
-                    return_type: None,
-                    description: self.extract_doc_comment(&lines, i),
-                });
+            // Extract routes registered on a router, like axum `.route("/users", get(list).post(create))`
+            // and actix `.route("/users", web::get().to(list))`
+            if line.contains(".route(") {
+                let call = Self::collect_call(&lines, i, ".route(");
+                if let Some(captures) = self.router_route_regex.captures(&call) {
+                    let path = captures.get(1).map_or("", |m| m.as_str());
+                    for method in self.router_method_regex.captures_iter(&call[captures.get(0).map_or(0, |m| m.end())..]) {
+                        let verb = method.get(1).map_or("", |m| m.as_str()).to_uppercase();
+                        let handler = method.get(2).or(method.get(3)).map(|m| m.as_str());
+                        interfaces.push(InterfaceInfo {
+                            name: format!("{} {}", verb, path),
+                            interface_type: "route".to_string(),
+                            visibility: "public".to_string(),
+                            parameters: Vec::new(),
+                            return_type: None,
+                            description: handler.map(|handler| format!("Handled by `{}`", handler)),
+                        });
+                    }
+                }
+            }
+
+            depth += Self::brace_delta(line);
+            for (_, open_depth, entered) in scopes.iter_mut() {
+                if depth > *open_depth {
+                    *entered = true;
+                }
+            }
+            scopes.retain(|(_, open_depth, entered)| !*entered || depth > *open_depth);
+            // Bodiless headers (`trait Marker {}` closing on its line, or a declaration ending in `;`)
+            if line.trim_end().ends_with(';') || line.trim_end().ends_with('}') {
+                scopes.retain(|(_, open_depth, entered)| *entered || *open_depth != depth);
             }
         }
-        
+
         interfaces
     }
 }
 
+/// Impl or trait block enclosing a function
+#[derive(Debug, Clone)]
+enum Scope {
+    Impl { self_type: String, trait_name: Option<String> },
+    Trait { name: String, visibility: &'static str },
+}
+
+/// Function signature, possibly spanning several lines
+#[derive(Debug, Default)]
+struct Signature {
+    generics: Option<String>,
+    params: String,
+    return_type: Option<String>,
+    where_clause: Option<String>,
+}
+
+/// Header of an impl block
+#[derive(Debug)]
+struct Implementation {
+    generics: Option<String>,
+    trait_name: Option<String>,
+    self_type: String,
+    where_clause: Option<String>,
+}
+
 impl RustProcessor {
+    /// Map `pub`, `pub(crate)`, `pub(super)`... to a visibility
+    fn visibility(modifier: Option<&str>) -> &'static str {
+        match modifier.map(str::trim) {
+            Some("pub") => "public",
+            Some(_) => "internal",
+            None => "private",
+        }
+    }
+
+    /// Lines from `start` joined up to the body or the `;` ending the declaration
+    fn collect_signature(lines: &[&str], start: usize) -> String {
+        let mut signature = String::new();
+        let mut parens = 0;
+        for line in lines.iter().skip(start).take(30) {
+            for c in line.chars() {
+                match c {
+                    '(' => parens += 1,
+                    ')' => parens -= 1,
+                    '{' | ';' if parens == 0 => return signature,
+                    _ => {}
+                }
+                signature.push(c);
+            }
+            signature.push(' ');
+        }
+        signature
+    }
+
+    /// Header of a trait or impl block, up to its opening brace
+    fn collect_header(lines: &[&str], start: usize) -> String {
+        let header = Self::collect_signature(lines, start);
+        header.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    /// A call starting at `marker` on line `start`, joined up to its closing parenthesis
+    fn collect_call(lines: &[&str], start: usize, marker: &str) -> String {
+        let mut call = String::new();
+        let mut parens = 0;
+        let first = &lines[start][lines[start].find(marker).unwrap_or(0)..];
+        for line in std::iter::once(first).chain(lines.iter().skip(start + 1).take(10).copied()) {
+            for c in line.trim().chars() {
+                call.push(c);
+                match c {
+                    '(' => parens += 1,
+                    ')' => {
+                        parens -= 1;
+                        if parens == 0 {
+                            return call;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            call.push(' ');
+        }
+        call
+    }
+
+    /// Split a function signature into generics, parameters, return type and where clause
+    fn parse_signature(signature: &str) -> Signature {
+        let mut parsed = Signature::default();
+        let Some(fn_at) = signature.find("fn ") else {
+            return parsed;
+        };
+        let after_fn = signature[fn_at + 3..].trim_start();
+        let name_end = after_fn
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(after_fn.len());
+        let mut rest = after_fn[name_end..].trim_start();
+
+        if let Some(generics) = Self::take_balanced(rest, '<', '>') {
+            rest = rest[generics.len()..].trim_start();
+            parsed.generics = Some(Self::normalize(&generics));
+        }
+        if let Some(params) = Self::take_balanced(rest, '(', ')') {
+            rest = rest[params.len()..].trim_start();
+            parsed.params = Self::normalize(&params[1..params.len() - 1]);
+        }
+        let (before_where, where_clause) = match rest.find("where ") {
+            Some(at) => (&rest[..at], Some(&rest[at + 6..])),
+            None => (rest, None),
+        };
+        parsed.return_type = before_where
+            .trim()
+            .strip_prefix("->")
+            .map(Self::normalize)
+            .filter(|return_type| !return_type.is_empty());
+        parsed.where_clause = where_clause
+            .map(|clause| Self::normalize(clause).trim_end_matches(',').to_string())
+            .filter(|clause| !clause.is_empty());
+        parsed
+    }
+
+    /// Split an impl header into generics, trait, self type and where clause
+    fn parse_impl(header: &str) -> Option<Implementation> {
+        let at = header.find("impl")?;
+        let mut rest = header[at + 4..].trim_start();
+        let generics = Self::take_balanced(rest, '<', '>');
+        if let Some(generics) = &generics {
+            rest = rest[generics.len()..].trim_start();
+        }
+        let (target, where_clause) = match rest.find(" where ") {
+            Some(at) => (&rest[..at], Some(rest[at + 7..].trim().trim_end_matches(',').to_string())),
+            None => (rest, None),
+        };
+        let (trait_name, self_type) = match Self::find_top_level(target, " for ") {
+            Some(at) => (Some(target[..at].trim().to_string()), target[at + 5..].trim()),
+            None => (None, target.trim()),
+        };
+        if self_type.is_empty() {
+            return None;
+        }
+        Some(Implementation {
+            generics,
+            trait_name,
+            self_type: self_type.to_string(),
+            where_clause,
+        })
+    }
+
+    /// Type name without its path and generic arguments (`crate::store::Store<T>` is `Store`)
+    fn base_name(type_name: &str) -> String {
+        let without_generics = type_name.split('<').next().unwrap_or(type_name);
+        without_generics
+            .rsplit("::")
+            .next()
+            .unwrap_or(without_generics)
+            .trim_start_matches('&')
+            .trim()
+            .to_string()
+    }
+
+    /// The balanced `open`...`close` group at the start of `text`, delimiters included
+    fn take_balanced(text: &str, open: char, close: char) -> Option<String> {
+        if !text.starts_with(open) {
+            return None;
+        }
+        let mut depth = 0;
+        let mut previous = ' ';
+        for (at, c) in text.char_indices() {
+            // `->` inside generics, e.g. `<F: Fn() -> T>`
+            if c == open {
+                depth += 1;
+            } else if c == close && !(close == '>' && previous == '-') {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(text[..at + c.len_utf8()].to_string());
+                }
+            }
+            previous = c;
+        }
+        None
+    }
+
+    /// Position of `pattern` outside of generic arguments
+    fn find_top_level(text: &str, pattern: &str) -> Option<usize> {
+        let mut depth = 0;
+        for (at, c) in text.char_indices() {
+            match c {
+                '<' | '(' | '[' => depth += 1,
+                '>' | ')' | ']' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 && text[at..].starts_with(pattern) {
+                return Some(at);
+            }
+        }
+        None
+    }
+
+    /// Split on `separator` outside of generics, tuples and slices
+    fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let mut depth = 0;
+        let mut start = 0;
+        let mut previous = ' ';
+        for (at, c) in text.char_indices() {
+            match c {
+                '<' | '(' | '[' => depth += 1,
+                '>' if previous != '-' => depth -= 1,
+                ')' | ']' => depth -= 1,
+                _ if c == separator && depth == 0 => {
+                    parts.push(&text[start..at]);
+                    start = at + c.len_utf8();
+                }
+                _ => {}
+            }
+            previous = c;
+        }
+        parts.push(&text[start..]);
+        parts
+    }
+
+    /// Collapse the whitespace of a signature part joined from several lines
+    fn normalize(text: &str) -> String {
+        text.split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .replace("( ", "(")
+            .replace("< ", "<")
+            .replace(" >", ">")
+            .replace(" )", ")")
+    }
+
+    /// Braces opened minus braces closed on a line, outside of strings, chars and comments
+    fn brace_delta(line: &str) -> i32 {
+        let code = line.split("//").next().unwrap_or(line).replace("'{'", "").replace("'}'", "");
+        let mut delta = 0;
+        let mut in_string = false;
+        let mut escaped = false;
+        for c in code.chars() {
+            match c {
+                '\\' if in_string => {
+                    escaped = !escaped;
+                    continue;
+                }
+                '"' if !escaped => in_string = !in_string,
+                '{' if !in_string => delta += 1,
+                '}' if !in_string => delta -= 1,
+                _ => {}
+            }
+            escaped = false;
+        }
+        delta
+    }
+
+    /// Routes declared by attributes above a handler, like actix/rocket `#[get("/users/{id}")]`
+    fn extract_route_attributes(&self, lines: &[&str], current_line: usize) -> Vec<String> {
+        let mut routes = Vec::new();
+        for line in lines[..current_line].iter().rev() {
+            let trimmed = line.trim();
+            if !(trimmed.starts_with("#[") || trimmed.starts_with("///") || trimmed.is_empty()) {
+                break;
+            }
+            let Some(captures) = self.route_attribute_regex.captures(trimmed) else {
+                continue;
+            };
+            let verb = captures.get(1).map_or("", |m| m.as_str());
+            let path = captures.get(2).map_or("", |m| m.as_str());
+            if verb == "route" {
+                let options = captures.get(3).map_or("", |m| m.as_str());
+                let methods: Vec<String> = self
+                    .route_method_option_regex
+                    .captures_iter(options)
+                    .filter_map(|method| method.get(1).map(|m| m.as_str().to_uppercase()))
+                    .collect();
+                if methods.is_empty() {
+                    routes.insert(0, format!("ANY {}", path));
+                }
+                for method in methods.into_iter().rev() {
+                    routes.insert(0, format!("{} {}", method, path));
+                }
+            } else {
+                routes.insert(0, format!("{} {}", verb.to_uppercase(), path));
+            }
+        }
+        routes
+    }
+
+    /// Doc comment of the declaration, followed by the extracted details
+    fn describe(&self, lines: &[&str], current_line: usize, details: Vec<String>) -> Option<String> {
+        let parts: Vec<String> = self
+            .extract_doc_comment(lines, current_line)
+            .into_iter()
+            .chain(details)
+            .collect();
+        if parts.is_empty() { None } else { Some(parts.join(" ")) }
+    }
+
     /// Parse Rust function parameters
     fn parse_rust_parameters(&self, params_str: &str) -> Vec<ParameterInfo> {
         let mut parameters = Vec::new();
@@ -260,16 +669,16 @@ impl RustProcessor {
             return parameters;
         }
         
-        // Simple parameter parsing, handling basic cases
-        for param in params_str.split(',') {
+        // Split on top-level commas, so `HashMap<K, V>` stays one parameter
+        for param in Self::split_top_level(params_str, ',') {
             let param = param.trim();
-            if param.is_empty() || param == "&self" || param == "self" || param == "&mut self" {
+            if param.is_empty() || param.trim_start_matches("mut ").starts_with("self") || param.starts_with("&") && param.ends_with("self") {
                 continue;
             }
             
             // Parse parameter format: name: type or name: &type or name: Option<type>
-            if let Some(colon_pos) = param.find(':') {
-                let name = param[..colon_pos].trim().to_string();
+            if let Some(colon_pos) = Self::find_top_level(param, ":") {
+                let name = param[..colon_pos].trim().trim_start_matches("mut ").to_string();
                 let param_type = param[colon_pos + 1..].trim().to_string();
                 let is_optional = param_type.starts_with("Option<") || param_type.contains("?");
                 
@@ -341,4 +750,97 @@ impl RustProcessor {
             path.to_string()
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'a>(interfaces: &'a [InterfaceInfo], name: &str) -> &'a InterfaceInfo {
+        interfaces
+            .iter()
+            .find(|interface| interface.name == name)
+            .unwrap_or_else(|| panic!("{} not extracted", name))
+    }
+
+    #[test]
+    fn test_extract_traits_impls_generics_and_routes() {
+        let processor = RustProcessor::new();
+        let content = r#"
+/// Storage backend
+pub trait Store<K>: Send + Sync {
+    async fn get(&self, key: &K) -> Option<String>;
+    fn put(&mut self, key: K, value: String);
+}
+
+impl<K: Hash + Eq> Store<K> for MemoryStore<K>
+where
+    K: Clone,
+{
+    async fn get(&self, key: &K) -> Option<String> {
+        self.items.get(key).cloned()
+    }
+
+    fn put(&mut self, key: K, value: String) {}
+}
+
+impl Settings {
+    pub(crate) fn merge<T: Into<Settings>>(
+        &mut self,
+        other: T,
+        overrides: HashMap<String, String>,
+    ) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        fn helper() {}
+    }
+}
+
+#[get("/users/{id}")]
+async fn get_user(path: web::Path<u32>) -> impl Responder {}
+
+pub fn router() -> Router {
+    Router::new()
+        .route("/orders", get(list_orders).post(create_order))
+        .route(
+            "/health",
+            web::get().to(health),
+        )
+}
+"#;
+        let interfaces = processor.extract_interfaces(content, Path::new("src/store.rs"));
+
+        let store = find(&interfaces, "Store");
+        assert_eq!(store.interface_type, "trait");
+        assert_eq!(store.description.as_deref(), Some("Storage backend Generics: <K> Supertraits: Send + Sync"));
+        let trait_get = find(&interfaces, "Store::get");
+        assert_eq!(trait_get.interface_type, "async_trait_method");
+        assert_eq!(trait_get.visibility, "public");
+        assert_eq!(find(&interfaces, "Store::put").parameters.len(), 2);
+
+        let implementation = find(&interfaces, "Store<K> for MemoryStore<K>");
+        assert_eq!(implementation.interface_type, "trait_implementation");
+        assert_eq!(implementation.description.as_deref(), Some("Generics: <K: Hash + Eq> Where: K: Clone"));
+        assert_eq!(find(&interfaces, "MemoryStore::get").interface_type, "async_method");
+        assert_eq!(find(&interfaces, "Settings").interface_type, "implementation");
+
+        let merge = find(&interfaces, "Settings::merge");
+        assert_eq!(merge.visibility, "internal");
+        assert_eq!(merge.return_type.as_deref(), Some("Result<(), Error>"));
+        assert_eq!(merge.description.as_deref(), Some("Generics: <T: Into<Settings>> Where: T: Clone"));
+        let params: Vec<(&str, &str)> = merge.parameters.iter().map(|p| (p.name.as_str(), p.param_type.as_str())).collect();
+        assert_eq!(params, [("other", "T"), ("overrides", "HashMap<String, String>")]);
+        // Functions nested in a method body are not methods
+        assert_eq!(find(&interfaces, "helper").interface_type, "function");
+
+        let route = find(&interfaces, "GET /users/{id}");
+        assert_eq!(route.interface_type, "route");
+        assert_eq!(route.description.as_deref(), Some("Handled by `get_user`"));
+        assert_eq!(find(&interfaces, "get_user").interface_type, "async_function");
+        assert_eq!(find(&interfaces, "router").interface_type, "function");
+        assert_eq!(find(&interfaces, "POST /orders").description.as_deref(), Some("Handled by `create_order`"));
+        assert!(interfaces.iter().any(|interface| interface.name == "GET /orders"));
+        assert_eq!(find(&interfaces, "GET /health").description.as_deref(), Some("Handled by `health`"));
+    }
+}
@@ -0,0 +1,1069 @@
+use super::{Dependency, LanguageProcessor};
+use crate::generator::preprocess::extractors::migration_schema::{SchemaChange, TableRef};
+use crate::generator::research::types::{DatabaseView, TableColumn, TableRelationship};
+use crate::types::code::{InterfaceInfo, ParameterInfo};
+use regex::Regex;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// An identifier, bare or quoted the SQL Server (`[x]`), ANSI/Postgres (`"x"`) or MySQL (`` `x` ``) way
+const IDENT: &str = r#"(?:\[[^\]]+\]|"[^"]+"|`[^`]+`|[\w$#@]+)"#;
+
+/// Header of a `CREATE` statement: object kind and name
+static CREATE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)^CREATE\s+(?:OR\s+(?:REPLACE|ALTER)\s+)?(?:DEFINER\s*=\s*\S+\s+)?(?:(?:GLOBAL|LOCAL|TEMP|TEMPORARY|UNLOGGED|MATERIALIZED|UNIQUE|CLUSTERED|NONCLUSTERED|RECURSIVE)\s+)*(TABLE|VIEW|PROC|PROCEDURE|FUNCTION|TRIGGER|INDEX|TYPE)\s+(?:CONCURRENTLY\s+)?(?:IF\s+NOT\s+EXISTS\s+)?({IDENT}(?:\.{IDENT}){{0,2}})"
+    ))
+    .unwrap()
+});
+static ALTER_TABLE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"(?i)^ALTER\s+TABLE\s+(?:IF\s+EXISTS\s+)?(?:ONLY\s+)?({IDENT}(?:\.{IDENT}){{0,2}})\s*(.*)$")).unwrap()
+});
+static DROP_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^DROP\s+(?:MATERIALIZED\s+)?(TABLE|VIEW)\s+(?:IF\s+EXISTS\s+)?(.+?)(?:\s+(?:CASCADE|RESTRICT))?$").unwrap()
+});
+static RENAME_TABLE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)^RENAME\s+TABLES?\s+(.+)$").unwrap());
+static INDEX_TABLE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"(?i)\bON\s+(?:ONLY\s+)?({IDENT}(?:\.{IDENT}){{0,2}})")).unwrap());
+static RETURNS_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\bRETURNS\s+(.+?)(?:\s+(?:AS|LANGUAGE|BEGIN|WITH|DETERMINISTIC|NOT|READS|NO|CONTAINS|MODIFIES|SECURITY|IMMUTABLE|STABLE|VOLATILE|STRICT|PARALLEL|COST)\b|\s*\$|$)").unwrap()
+});
+static TABLE_REFERENCE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"(?i)\b(FROM|JOIN|INTO|UPDATE|REFERENCES)\s+({IDENT}(?:\.{IDENT}){{0,2}})")).unwrap()
+});
+static CALL_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"(?i)\b(?:EXEC|EXECUTE|CALL)\s+({IDENT}(?:\.{IDENT}){{0,2}})")).unwrap());
+static REFERENCES_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"(?i)\bREFERENCES\s+({IDENT}(?:\.{IDENT}){{0,2}})\s*(?:\(([^)]*)\))?")).unwrap()
+});
+static DOLLAR_QUOTE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\$(\w*)\$").unwrap());
+
+/// Words ending the data type of a column definition
+const COLUMN_KEYWORDS: [&str; 18] = [
+    "NOT", "NULL", "PRIMARY", "DEFAULT", "REFERENCES", "UNIQUE", "CHECK", "CONSTRAINT", "AUTO_INCREMENT",
+    "AUTOINCREMENT", "GENERATED", "COLLATE", "COMMENT", "ON", "CHARACTER", "SPARSE", "ROWGUIDCOL", "AS",
+];
+
+/// Words that are not table names after `FROM`, `INTO`...
+const NOT_TABLES: [&str; 8] = ["SELECT", "SET", "VALUES", "LATERAL", "UNNEST", "DUAL", "STATISTICS", "TABLE"];
+
+/// SQL dialect of a script, which decides the default schema of unqualified names
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlDialect {
+    TSql,
+    Postgres,
+    MySql,
+    Generic,
+}
+
+impl SqlDialect {
+    /// Dialect suggested by syntax only one of them uses
+    pub fn detect(content: &str) -> Self {
+        let upper = content.to_uppercase();
+        if upper.lines().any(|line| line.trim() == "GO") || upper.contains("[DBO].") || upper.contains("NVARCHAR") || upper.contains("IDENTITY(") {
+            SqlDialect::TSql
+        } else if content.contains('`') || upper.contains("AUTO_INCREMENT") || upper.contains("ENGINE=") || upper.contains("DELIMITER ") {
+            SqlDialect::MySql
+        } else if ["SERIAL", "$$", "PLPGSQL", "JSONB", "TIMESTAMPTZ", "CREATE EXTENSION", "::"].iter().any(|marker| upper.contains(marker)) {
+            SqlDialect::Postgres
+        } else {
+            SqlDialect::Generic
+        }
+    }
+
+    fn default_schema(&self) -> Option<&'static str> {
+        match self {
+            SqlDialect::TSql => Some("dbo"),
+            SqlDialect::Postgres => Some("public"),
+            SqlDialect::MySql | SqlDialect::Generic => None,
+        }
+    }
+}
+
+/// Processor for SQL scripts of SQL Server, PostgreSQL, MySQL and standard SQL
+#[derive(Debug)]
+pub struct SqlProcessor;
+
+impl SqlProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for SqlProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LanguageProcessor for SqlProcessor {
+    fn supported_extensions(&self) -> Vec<&'static str> {
+        vec!["sql"]
+    }
+
+    /// Tables read or written and the procedures called
+    fn extract_dependencies(&self, content: &str, file_path: &Path) -> Vec<Dependency> {
+        let source_file = file_path.to_string_lossy().to_string();
+        let mut dependencies = Vec::new();
+
+        for (line, statement) in split_statements(content) {
+            let text = normalize(&statement);
+            for captures in TABLE_REFERENCE_REGEX.captures_iter(&text) {
+                let name = unquote_name(&captures[2]);
+                if NOT_TABLES.contains(&name.to_uppercase().as_str()) || name.starts_with(['@', '#']) {
+                    continue;
+                }
+                dependencies.push(Dependency {
+                    name,
+                    path: Some(source_file.clone()),
+                    is_external: false,
+                    line_number: Some(line + 1),
+                    dependency_type: "table_reference".to_string(),
+                    version: None,
+                });
+            }
+            for captures in CALL_REGEX.captures_iter(&text) {
+                let name = unquote_name(&captures[1]);
+                if name.starts_with('@') {
+                    continue;
+                }
+                dependencies.push(Dependency {
+                    name,
+                    path: Some(source_file.clone()),
+                    is_external: false,
+                    line_number: Some(line + 1),
+                    dependency_type: "stored_procedure_call".to_string(),
+                    version: None,
+                });
+            }
+        }
+
+        dependencies
+    }
+
+    fn determine_component_type(&self, file_path: &Path, content: &str) -> String {
+        let path = file_path.to_string_lossy().to_lowercase();
+        if path.contains("migration") || path.contains("changelog") {
+            return "sql_migration".to_string();
+        }
+
+        let upper = content.to_uppercase();
+        if upper.contains("CREATE TABLE") || upper.contains("ALTER TABLE") {
+            "sql_table_definition".to_string()
+        } else if upper.contains("CREATE PROCEDURE") || upper.contains("ALTER PROCEDURE") {
+            "sql_stored_procedure".to_string()
+        } else if upper.contains("CREATE VIEW") || upper.contains("ALTER VIEW") {
+            "sql_view".to_string()
+        } else if upper.contains("CREATE FUNCTION") || upper.contains("ALTER FUNCTION") || upper.contains("CREATE OR REPLACE FUNCTION") {
+            "sql_function".to_string()
+        } else if upper.contains("CREATE TRIGGER") {
+            "sql_trigger".to_string()
+        } else {
+            "sql_script".to_string()
+        }
+    }
+
+    fn is_important_line(&self, line: &str) -> bool {
+        let upper = line.trim().to_uppercase();
+        ["CREATE ", "ALTER ", "DROP ", "RENAME ", "REFERENCES ", "FOREIGN KEY", "PRIMARY KEY"]
+            .iter()
+            .any(|keyword| upper.starts_with(keyword) || keyword.contains(' ') && upper.contains(keyword))
+            || upper.contains("TODO")
+            || upper.contains("FIXME")
+    }
+
+    fn language_name(&self) -> &'static str {
+        "SQL"
+    }
+
+    /// Tables, views, stored procedures, functions, triggers, indexes and types
+    fn extract_interfaces(&self, content: &str, _file_path: &Path) -> Vec<InterfaceInfo> {
+        let dialect = SqlDialect::detect(content);
+        let lines: Vec<&str> = content.lines().collect();
+        let qualified = |name: &str| {
+            let table = parse_name(name);
+            match table.schema.as_deref().or(dialect.default_schema()) {
+                Some(schema) => format!("{}.{}", schema, table.name),
+                None => table.name,
+            }
+        };
+        let mut interfaces = Vec::new();
+
+        for (line, statement) in split_statements(content) {
+            let text = normalize(&statement);
+            let description = extract_sql_comment(&lines, line);
+
+            if let Some(captures) = ALTER_TABLE_REGEX.captures(&text) {
+                interfaces.push(InterfaceInfo {
+                    name: qualified(&captures[1]),
+                    interface_type: "sql_table_alter".to_string(),
+                    visibility: "public".to_string(),
+                    parameters: Vec::new(),
+                    return_type: None,
+                    description,
+                });
+                continue;
+            }
+            let Some(captures) = CREATE_REGEX.captures(&text) else {
+                continue;
+            };
+            let name = captures.get(2).map_or("", |m| m.as_str());
+            if name.starts_with('#') {
+                // Temporary tables of a procedure body
+                continue;
+            }
+            let after_name = text[captures.get(0).map_or(0, |m| m.end())..].trim_start();
+            let mut interface = InterfaceInfo {
+                name: qualified(name),
+                interface_type: String::new(),
+                visibility: "public".to_string(),
+                parameters: Vec::new(),
+                return_type: None,
+                description,
+            };
+            match captures[1].to_uppercase().as_str() {
+                "TABLE" => {
+                    interface.interface_type = "sql_table".to_string();
+                    if let Some(definition) = parse_create_table(&text) {
+                        interface.parameters = definition
+                            .columns
+                            .iter()
+                            .map(|column| column_parameter(column, &definition))
+                            .collect();
+                    }
+                }
+                "VIEW" => interface.interface_type = "sql_view".to_string(),
+                "PROC" | "PROCEDURE" => {
+                    interface.interface_type = "sql_stored_procedure".to_string();
+                    interface.parameters = parse_routine_parameters(after_name);
+                }
+                "FUNCTION" => {
+                    interface.interface_type = "sql_function".to_string();
+                    interface.parameters = parse_routine_parameters(after_name);
+                    interface.return_type = RETURNS_REGEX.captures(after_name).map(|returns| returns[1].trim().to_string());
+                }
+                "TRIGGER" => interface.interface_type = "sql_trigger".to_string(),
+                "INDEX" => {
+                    interface.interface_type = "sql_index".to_string();
+                    if let Some(table) = INDEX_TABLE_REGEX.captures(after_name) {
+                        interface.name = format!("{} ON {}", parse_name(name).name, qualified(&table[1]));
+                    }
+                }
+                _ => interface.interface_type = "sql_type".to_string(),
+            }
+            interfaces.push(interface);
+        }
+
+        interfaces
+    }
+}
+
+/// Table definition of a `CREATE TABLE` statement
+pub struct TableDefinition {
+    pub table: TableRef,
+    pub columns: Vec<TableColumn>,
+    pub primary_key: Vec<String>,
+    pub foreign_keys: Vec<TableRelationship>,
+}
+
+/// Changes to the schema made by the DDL statements of a script, in order
+pub fn schema_changes(content: &str) -> Vec<SchemaChange> {
+    let mut changes = Vec::new();
+    for (_, statement) in split_statements(content) {
+        let text = normalize(&statement);
+        if let Some(definition) = parse_create_table(&text) {
+            if !definition.table.name.starts_with('#') {
+                changes.push(SchemaChange::CreateTable {
+                    table: definition.table,
+                    columns: definition.columns,
+                    primary_key: definition.primary_key,
+                    foreign_keys: definition.foreign_keys,
+                });
+            }
+        } else if let Some(captures) = CREATE_REGEX.captures(&text)
+            && captures[1].eq_ignore_ascii_case("VIEW")
+        {
+            let view = parse_name(&captures[2]);
+            changes.push(SchemaChange::CreateView(DatabaseView {
+                schema: view.schema.unwrap_or_default(),
+                name: view.name,
+                referenced_tables: referenced_tables(&text),
+                ..Default::default()
+            }));
+        } else if let Some(captures) = ALTER_TABLE_REGEX.captures(&text) {
+            let table = parse_name(&captures[1]);
+            for action in split_top_level(&captures[2], ',') {
+                changes.extend(alter_table_action(&table, action.trim()));
+            }
+        } else if let Some(captures) = DROP_REGEX.captures(&text) {
+            for name in split_top_level(&captures[2], ',') {
+                let name = parse_name(name.trim());
+                changes.push(if captures[1].eq_ignore_ascii_case("TABLE") {
+                    SchemaChange::DropTable(name)
+                } else {
+                    SchemaChange::DropView(name)
+                });
+            }
+        } else if let Some(captures) = RENAME_TABLE_REGEX.captures(&text) {
+            // MySQL `RENAME TABLE a TO b, c TO d`
+            for pair in split_top_level(&captures[1], ',') {
+                let words: Vec<&str> = pair.split_whitespace().collect();
+                if let [from, to_keyword, to] = words[..]
+                    && to_keyword.eq_ignore_ascii_case("TO")
+                {
+                    changes.push(SchemaChange::RenameTable { table: parse_name(from), to: parse_name(to).name });
+                }
+            }
+        }
+    }
+    changes
+}
+
+/// Tables a query reads
+pub fn referenced_tables(query: &str) -> Vec<String> {
+    let mut tables: Vec<String> = Vec::new();
+    for captures in TABLE_REFERENCE_REGEX.captures_iter(query) {
+        let keyword = captures[1].to_uppercase();
+        let name = unquote_name(&captures[2]);
+        if (keyword == "FROM" || keyword == "JOIN")
+            && !NOT_TABLES.contains(&name.to_uppercase().as_str())
+            && !tables.contains(&name)
+        {
+            tables.push(name);
+        }
+    }
+    tables
+}
+
+/// Parse a `CREATE TABLE` statement with a column list
+pub fn parse_create_table(text: &str) -> Option<TableDefinition> {
+    let captures = CREATE_REGEX.captures(text)?;
+    if !captures[1].eq_ignore_ascii_case("TABLE") {
+        return None;
+    }
+    let table = parse_name(&captures[2]);
+    let rest = text[captures.get(0)?.end()..].trim_start();
+    let body = take_balanced(rest, '(', ')')?;
+    let mut definition = TableDefinition {
+        table,
+        columns: Vec::new(),
+        primary_key: Vec::new(),
+        foreign_keys: Vec::new(),
+    };
+
+    for item in split_top_level(&body[1..body.len() - 1], ',') {
+        let item = item.trim();
+        let upper = item.to_ascii_uppercase();
+        let constraint = upper.strip_prefix("CONSTRAINT ").map(|rest| {
+            let name_end = rest.find(' ').unwrap_or(rest.len());
+            (item[11..11 + name_end].trim().to_string(), rest[name_end..].trim_start())
+        });
+        let (constraint_name, upper_body) = match &constraint {
+            Some((name, rest)) => (Some(unquote(name)), *rest),
+            None => (None, upper.as_str()),
+        };
+        let item_body = &item[item.len() - upper_body.len()..];
+
+        if upper_body.starts_with("PRIMARY KEY") {
+            definition.primary_key = column_list(item_body);
+        } else if upper_body.starts_with("FOREIGN KEY") {
+            if let Some(relationship) = foreign_key(&definition.table, column_list(item_body), item_body, constraint_name) {
+                definition.foreign_keys.push(relationship);
+            }
+        } else if ["UNIQUE", "CHECK", "INDEX", "KEY ", "FULLTEXT", "SPATIAL", "EXCLUDE", "LIKE ", "PERIOD "]
+            .iter()
+            .any(|keyword| upper_body.starts_with(keyword))
+            || constraint.is_some()
+        {
+            continue;
+        } else if let Some((column, is_primary_key, reference)) = parse_column(&definition.table, item) {
+            if is_primary_key {
+                definition.primary_key.push(column.name.clone());
+            }
+            definition.foreign_keys.extend(reference);
+            definition.columns.push(column);
+        }
+    }
+    for column in definition.columns.iter_mut() {
+        if definition.primary_key.iter().any(|key| key.eq_ignore_ascii_case(&column.name)) {
+            column.nullable = false;
+        }
+    }
+    Some(definition)
+}
+
+/// Changes made by one action of an `ALTER TABLE` statement
+fn alter_table_action(table: &TableRef, action: &str) -> Vec<SchemaChange> {
+    let words: Vec<&str> = action.split_whitespace().collect();
+    let upper: Vec<String> = words.iter().map(|word| word.to_uppercase()).collect();
+    let upper: Vec<&str> = upper.iter().map(String::as_str).collect();
+    let column_at = |index: usize| {
+        // Skip the optional `COLUMN` and `IF [NOT] EXISTS` words
+        let mut index = index;
+        while let Some(word) = upper.get(index)
+            && ["COLUMN", "IF", "NOT", "EXISTS"].contains(word)
+        {
+            index += 1;
+        }
+        index
+    };
+    let table_change = |change| vec![change];
+
+    match upper.first().copied() {
+        Some("ADD") => {
+            let rest_at = column_at(1);
+            let rest = words[rest_at..].join(" ");
+            let rest_upper = rest.to_uppercase();
+            let (constraint_name, rest, rest_upper) = match rest_upper.strip_prefix("CONSTRAINT ") {
+                Some(_) => {
+                    let name = words.get(rest_at + 1).map(|name| unquote(name));
+                    let rest = words[(rest_at + 2).min(words.len())..].join(" ");
+                    let rest_upper = rest.to_uppercase();
+                    (name, rest, rest_upper)
+                }
+                None => (None, rest, rest_upper),
+            };
+            if rest_upper.starts_with("PRIMARY KEY") {
+                table_change(SchemaChange::AddPrimaryKey { table: table.clone(), columns: column_list(&rest) })
+            } else if rest_upper.starts_with("FOREIGN KEY") {
+                foreign_key(table, column_list(&rest), &rest, constraint_name)
+                    .map(SchemaChange::AddForeignKey)
+                    .into_iter()
+                    .collect()
+            } else if constraint_name.is_some()
+                || ["UNIQUE", "CHECK", "INDEX", "KEY ", "FULLTEXT", "CONSTRAINT"].iter().any(|keyword| rest_upper.starts_with(keyword))
+            {
+                Vec::new()
+            } else if let Some((column, is_primary_key, reference)) = parse_column(table, &rest) {
+                let mut changes = Vec::new();
+                if is_primary_key {
+                    changes.push(SchemaChange::AddPrimaryKey { table: table.clone(), columns: vec![column.name.clone()] });
+                }
+                changes.insert(0, SchemaChange::AddColumn { table: table.clone(), column });
+                changes.extend(reference.map(SchemaChange::AddForeignKey));
+                changes
+            } else {
+                Vec::new()
+            }
+        }
+        Some("DROP") if !matches!(upper.get(1).copied(), Some("CONSTRAINT" | "INDEX" | "KEY" | "PRIMARY" | "FOREIGN")) => words
+            .get(column_at(1))
+            .map(|column| vec![SchemaChange::DropColumn { table: table.clone(), column: unquote(column) }])
+            .unwrap_or_default(),
+        Some("RENAME") => match upper[1..] {
+            ["TO", ..] | ["AS", ..] => words
+                .get(2)
+                .map(|to| table_change(SchemaChange::RenameTable { table: table.clone(), to: parse_name(to).name }))
+                .unwrap_or_default(),
+            _ => {
+                let from_at = column_at(1);
+                match (words.get(from_at), upper.get(from_at + 1), words.get(from_at + 2)) {
+                    (Some(from), Some(&"TO"), Some(to)) => table_change(SchemaChange::RenameColumn {
+                        table: table.clone(),
+                        from: unquote(from),
+                        to: unquote(to),
+                    }),
+                    _ => Vec::new(),
+                }
+            }
+        },
+        // MySQL `CHANGE old new type`, `MODIFY column type`
+        Some("CHANGE") => {
+            let from_at = column_at(1);
+            let (Some(from), Some(_)) = (words.get(from_at), words.get(from_at + 1)) else {
+                return Vec::new();
+            };
+            let Some((column, _, _)) = parse_column(table, &words[from_at + 1..].join(" ")) else {
+                return Vec::new();
+            };
+            vec![
+                SchemaChange::RenameColumn { table: table.clone(), from: unquote(from), to: column.name.clone() },
+                SchemaChange::AlterColumn {
+                    table: table.clone(),
+                    column: column.name,
+                    data_type: Some(column.data_type),
+                    nullable: Some(column.nullable),
+                    default_value: None,
+                },
+            ]
+        }
+        Some("MODIFY") | Some("ALTER") => {
+            let column_index = column_at(1);
+            let Some(column) = words.get(column_index).map(|column| unquote(column)) else {
+                return Vec::new();
+            };
+            let rest = &upper[column_index + 1..];
+            let rest_words = &words[column_index + 1..];
+            let alter = |data_type: Option<String>, nullable: Option<bool>, default_value: Option<Option<String>>| {
+                vec![SchemaChange::AlterColumn { table: table.clone(), column: column.clone(), data_type, nullable, default_value }]
+            };
+            match rest {
+                ["TYPE", ..] => alter(Some(data_type_of(&rest_words[1..])), None, None),
+                ["SET", "DATA", "TYPE", ..] => alter(Some(data_type_of(&rest_words[3..])), None, None),
+                ["SET", "NOT", "NULL", ..] => alter(None, Some(false), None),
+                ["DROP", "NOT", "NULL", ..] => alter(None, Some(true), None),
+                ["SET", "DEFAULT", ..] => alter(None, None, Some(Some(rest_words[2..].join(" ")))),
+                ["DROP", "DEFAULT", ..] => alter(None, None, Some(None)),
+                // SQL Server `ALTER COLUMN c type [NOT] NULL`, MySQL `MODIFY c type`
+                [first, ..] if !COLUMN_KEYWORDS.contains(first) => {
+                    let nullable = if rest.windows(2).any(|pair| pair == ["NOT", "NULL"]) {
+                        Some(false)
+                    } else if rest.contains(&"NULL") {
+                        Some(true)
+                    } else {
+                        None
+                    };
+                    alter(Some(data_type_of(rest_words)), nullable, None)
+                }
+                _ => Vec::new(),
+            }
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Parse a column definition: the column, whether it is the primary key, and the foreign key
+/// its `REFERENCES` clause declares
+fn parse_column(table: &TableRef, definition: &str) -> Option<(TableColumn, bool, Option<TableRelationship>)> {
+    let words = split_words(definition);
+    let name = unquote(words.first()?);
+    let upper: Vec<String> = words.iter().map(|word| word.to_uppercase()).collect();
+    let type_end = upper
+        .iter()
+        .skip(1)
+        .position(|word| COLUMN_KEYWORDS.contains(&word.as_str()) || word.starts_with("IDENTITY"))
+        .map_or(words.len(), |position| position + 1);
+    let data_type = words[1..type_end].join(" ");
+    if data_type.is_empty() {
+        return None;
+    }
+    let upper_type = data_type.to_uppercase();
+    let has = |phrase: &[&str]| upper.windows(phrase.len()).any(|window| window.iter().zip(phrase).all(|(a, b)| a == b));
+    let is_primary_key = has(&["PRIMARY", "KEY"]);
+    let default_value = upper.iter().position(|word| word == "DEFAULT").map(|at| {
+        let end = upper[at + 1..]
+            .iter()
+            .position(|word| COLUMN_KEYWORDS.contains(&word.as_str()))
+            .map_or(words.len(), |position| at + 1 + position);
+        words[at + 1..end].join(" ")
+    });
+    let column = TableColumn {
+        name,
+        nullable: !has(&["NOT", "NULL"]) && !is_primary_key,
+        is_identity: upper.iter().any(|word| word.starts_with("IDENTITY") || word == "AUTO_INCREMENT" || word == "AUTOINCREMENT")
+            || has(&["AS", "IDENTITY"])
+            || ["SERIAL", "BIGSERIAL", "SMALLSERIAL"].contains(&upper_type.as_str()),
+        data_type,
+        default_value: default_value.filter(|value| !value.is_empty()),
+    };
+    let reference = foreign_key(table, vec![column.name.clone()], definition, None);
+    Some((column, is_primary_key, reference))
+}
+
+/// Foreign key from columns of a table to the target of the `REFERENCES` clause in the text
+fn foreign_key(table: &TableRef, columns: Vec<String>, text: &str, constraint_name: Option<String>) -> Option<TableRelationship> {
+    let captures = REFERENCES_REGEX.captures(text)?;
+    let target = parse_name(&captures[1]);
+    Some(TableRelationship {
+        from_table: table.label(),
+        from_columns: columns,
+        to_table: target.label(),
+        to_columns: captures.get(2).map(|list| split_names(list.as_str())).unwrap_or_default(),
+        relationship_type: "ForeignKey".to_string(),
+        constraint_name,
+    })
+}
+
+/// Parameters of a procedure or function: `@name type = default OUTPUT` (SQL Server) or
+/// `[IN|OUT|INOUT] name type [DEFAULT value]` (PostgreSQL, MySQL)
+fn parse_routine_parameters(after_name: &str) -> Vec<ParameterInfo> {
+    let list = match take_balanced(after_name, '(', ')') {
+        Some(list) => list[1..list.len() - 1].to_string(),
+        // SQL Server parameters may go without parentheses, up to `AS`
+        None => {
+            let upper = after_name.to_ascii_uppercase();
+            let end = [" AS ", " AS", " WITH "]
+                .iter()
+                .filter_map(|keyword| upper.find(keyword))
+                .min()
+                .unwrap_or(after_name.len());
+            after_name[..end].to_string()
+        }
+    };
+
+    let mut parameters = Vec::new();
+    for parameter in split_top_level(&list, ',') {
+        let parameter = parameter.trim();
+        if parameter.is_empty() {
+            continue;
+        }
+        let mut direction = None;
+        // SQL Server modes follow the default value
+        let mut parameter = parameter;
+        if let Some((rest, last)) = parameter.rsplit_once(char::is_whitespace)
+            && ["OUTPUT", "OUT", "READONLY"].contains(&last.to_uppercase().as_str())
+            && rest.trim_start().starts_with('@')
+        {
+            if !last.eq_ignore_ascii_case("READONLY") {
+                direction = Some("OUT".to_string());
+            }
+            parameter = rest.trim_end();
+        }
+        let upper = parameter.to_ascii_uppercase();
+        let (declaration, default) = match upper.find(" DEFAULT ").or_else(|| parameter.find('=')) {
+            Some(at) => {
+                let skip = if parameter[at..].starts_with('=') { 1 } else { 9 };
+                (parameter[..at].trim(), Some(parameter[at + skip..].trim().to_string()))
+            }
+            None => (parameter, None),
+        };
+        let mut words: Vec<&str> = declaration.split_whitespace().collect();
+        if let Some(first) = words.first()
+            && ["IN", "OUT", "INOUT", "VARIADIC"].contains(&first.to_uppercase().as_str())
+        {
+            direction = Some(first.to_uppercase());
+            words.remove(0);
+        }
+        let (name, param_type) = match words.as_slice() {
+            [] => continue,
+            [param_type] => (String::new(), param_type.to_string()),
+            [name, param_type @ ..] => (unquote(name), param_type.join(" ")),
+        };
+        let details: Vec<String> = default
+            .iter()
+            .map(|value| format!("Default: {}", value))
+            .chain(direction.map(|direction| format!("Direction: {}", direction)))
+            .collect();
+        parameters.push(ParameterInfo {
+            name,
+            param_type,
+            is_optional: default.is_some(),
+            description: (!details.is_empty()).then(|| details.join(", ")),
+        });
+    }
+    parameters
+}
+
+/// Column of a table interface, its constraints in the description
+fn column_parameter(column: &TableColumn, definition: &TableDefinition) -> ParameterInfo {
+    let mut details = Vec::new();
+    if definition.primary_key.iter().any(|key| key.eq_ignore_ascii_case(&column.name)) {
+        details.push("primary key".to_string());
+    }
+    if column.is_identity {
+        details.push("identity".to_string());
+    }
+    if let Some(default_value) = &column.default_value {
+        details.push(format!("default {}", default_value));
+    }
+    for relationship in &definition.foreign_keys {
+        if relationship.from_columns.iter().any(|from| from.eq_ignore_ascii_case(&column.name)) {
+            details.push(format!("references {}({})", relationship.to_table, relationship.to_columns.join(", ")));
+        }
+    }
+    ParameterInfo {
+        name: column.name.clone(),
+        param_type: column.data_type.clone(),
+        is_optional: column.nullable,
+        description: (!details.is_empty()).then(|| details.join(", ")),
+    }
+}
+
+/// Statements of a script with the line they start on, without comments. `;`, SQL Server `GO`
+/// lines and MySQL `DELIMITER` separators end statements; dollar-quoted bodies stay whole.
+pub fn split_statements(content: &str) -> Vec<(usize, String)> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut start_line = 0;
+    let mut delimiter = ";".to_string();
+    let mut block_comment = false;
+    let mut quote: Option<char> = None;
+    let mut dollar_tag: Option<String> = None;
+    let mut flush = |current: &mut String, start_line: usize| {
+        if !current.trim().is_empty() {
+            statements.push((start_line, current.trim().to_string()));
+        }
+        current.clear();
+    };
+
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if !block_comment && quote.is_none() && dollar_tag.is_none() {
+            let upper = trimmed.to_uppercase();
+            if upper == "GO" || upper.strip_prefix("GO ").is_some_and(|count| count.trim().parse::<u32>().is_ok()) {
+                flush(&mut current, start_line);
+                continue;
+            }
+            if let Some(new_delimiter) = upper.strip_prefix("DELIMITER ") {
+                flush(&mut current, start_line);
+                delimiter = trimmed[trimmed.len() - new_delimiter.len()..].trim().to_string();
+                continue;
+            }
+        }
+
+        let mut at = 0;
+        while at < line.len() {
+            let rest = &line[at..];
+            let Some(c) = rest.chars().next() else {
+                break;
+            };
+            if block_comment {
+                if rest.starts_with("*/") {
+                    block_comment = false;
+                    at += 2;
+                } else {
+                    at += c.len_utf8();
+                }
+                continue;
+            }
+            if let Some(tag) = &dollar_tag {
+                if rest.starts_with(tag.as_str()) {
+                    current.push_str(tag);
+                    at += tag.len();
+                    dollar_tag = None;
+                } else {
+                    current.push(c);
+                    at += c.len_utf8();
+                }
+                continue;
+            }
+            if let Some(closing) = quote {
+                current.push(c);
+                at += c.len_utf8();
+                if c == closing {
+                    quote = None;
+                }
+                continue;
+            }
+            if rest.starts_with("--") {
+                break;
+            }
+            if rest.starts_with("/*") {
+                block_comment = true;
+                at += 2;
+                continue;
+            }
+            if rest.starts_with(delimiter.as_str()) {
+                flush(&mut current, start_line);
+                at += delimiter.len();
+                continue;
+            }
+            if current.trim().is_empty() && !c.is_whitespace() {
+                start_line = index;
+            }
+            match c {
+                '\'' | '"' | '`' => quote = Some(c),
+                '[' => quote = Some(']'),
+                '$' => {
+                    if let Some(captures) = DOLLAR_QUOTE_REGEX.captures(rest) {
+                        let tag = captures[0].to_string();
+                        current.push_str(&tag);
+                        at += tag.len();
+                        dollar_tag = Some(tag);
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            current.push(c);
+            at += c.len_utf8();
+        }
+        current.push('\n');
+    }
+    flush(&mut current, start_line);
+    statements
+}
+
+/// Statement text on one line, with single spaces
+fn normalize(statement: &str) -> String {
+    statement.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Table or object name, with its schema when qualified; database names of three-part names
+/// are dropped
+pub fn parse_name(name: &str) -> TableRef {
+    let parts = split_names_by(name, '.');
+    let mut parts = parts.into_iter().rev();
+    TableRef {
+        name: parts.next().unwrap_or_default(),
+        schema: parts.next(),
+    }
+}
+
+/// Name without its quotes, `schema.name` when qualified
+fn unquote_name(name: &str) -> String {
+    parse_name(name).label()
+}
+
+fn unquote(identifier: &str) -> String {
+    let identifier = identifier.trim();
+    for (open, close) in [('[', ']'), ('"', '"'), ('`', '`')] {
+        if let Some(inner) = identifier.strip_prefix(open).and_then(|rest| rest.strip_suffix(close)) {
+            return inner.to_string();
+        }
+    }
+    identifier.to_string()
+}
+
+/// Column names of the first parenthesized list, as in `PRIMARY KEY (a, b)`
+fn column_list(text: &str) -> Vec<String> {
+    let Some(start) = text.find('(') else {
+        return Vec::new();
+    };
+    take_balanced(&text[start..], '(', ')')
+        .map(|list| split_names(&list[1..list.len() - 1]))
+        .unwrap_or_default()
+}
+
+fn split_names(list: &str) -> Vec<String> {
+    split_names_by(list, ',')
+}
+
+/// Split on a separator outside of quotes, unquoting the parts
+fn split_names_by(text: &str, separator: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    for c in text.chars() {
+        match quote {
+            Some(closing) if c == closing => quote = None,
+            Some(_) => {}
+            None if c == '[' => quote = Some(']'),
+            None if c == '"' || c == '`' => quote = Some(c),
+            None if c == separator => {
+                parts.push(unquote(&current));
+                current.clear();
+                continue;
+            }
+            None => {}
+        }
+        current.push(c);
+    }
+    parts.push(unquote(&current));
+    parts.into_iter().filter(|part| !part.is_empty()).collect()
+}
+
+/// Data type from the words following a column name, up to its constraints
+fn data_type_of(words: &[&str]) -> String {
+    words
+        .iter()
+        .take_while(|word| {
+            let upper = word.to_uppercase();
+            !COLUMN_KEYWORDS.contains(&upper.as_str()) && upper != "USING"
+        })
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Split on whitespace outside of parentheses, so `DECIMAL(10, 2)` stays one word
+fn split_words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    for c in text.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c.is_whitespace() && depth == 0 => {
+                if !current.is_empty() {
+                    words.push(std::mem::take(&mut current));
+                }
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// Split on a separator outside of parentheses and quotes
+fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    for (at, c) in text.char_indices() {
+        match quote {
+            Some(closing) if c == closing => quote = None,
+            Some(_) => {}
+            None => match c {
+                '\'' | '"' | '`' => quote = Some(c),
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                c if c == separator && depth == 0 => {
+                    parts.push(&text[start..at]);
+                    start = at + c.len_utf8();
+                }
+                _ => {}
+            },
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// The text from its first character to the matching closing character, inclusive
+fn take_balanced(text: &str, open: char, close: char) -> Option<String> {
+    if !text.starts_with(open) {
+        return None;
+    }
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    for (at, c) in text.char_indices() {
+        match quote {
+            Some(closing) if c == closing => quote = None,
+            Some(_) => {}
+            None if c == '\'' => quote = Some(c),
+            None if c == open => depth += 1,
+            None if c == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(text[..=at].to_string());
+                }
+            }
+            None => {}
+        }
+    }
+    None
+}
+
+/// Extract SQL comment (-- or /* */) preceding a statement
+fn extract_sql_comment(lines: &[&str], line_index: usize) -> Option<String> {
+    let mut comments = Vec::new();
+
+    // Look backwards for comments
+    for i in (0..line_index.min(lines.len())).rev() {
+        let line = lines[i].trim();
+
+        if let Some(comment) = line.strip_prefix("--") {
+            comments.insert(0, comment.trim().to_string());
+        } else if line.ends_with("*/") {
+            // Multi-line comment - find the start
+            let mut comment_text = String::new();
+            for j in (0..=i).rev() {
+                let comment_line = lines[j].trim();
+                if let Some(comment) = comment_line.strip_prefix("/*") {
+                    comment_text = format!("{} {}", comment.trim_end_matches("*/").trim(), comment_text);
+                    break;
+                } else {
+                    comment_text = format!("{} {}", comment_line.trim_end_matches("*/").trim_start_matches('*').trim(), comment_text);
+                }
+            }
+            if !comment_text.trim().is_empty() {
+                return Some(comment_text.trim().to_string());
+            }
+            break;
+        } else if line.is_empty() || line.eq_ignore_ascii_case("GO") {
+            continue;
+        } else {
+            break;
+        }
+    }
+
+    if comments.is_empty() {
+        None
+    } else {
+        Some(comments.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dialects_and_schema_changes() {
+        let processor = SqlProcessor::new();
+        let postgres = r#"-- Registered users
+CREATE TABLE IF NOT EXISTS users (
+    id BIGSERIAL PRIMARY KEY,
+    email VARCHAR(255) NOT NULL UNIQUE,
+    balance NUMERIC(10, 2) DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TABLE orders (
+    id UUID,
+    user_id BIGINT REFERENCES users (id) ON DELETE CASCADE,
+    CONSTRAINT orders_pk PRIMARY KEY (id)
+);
+
+CREATE OR REPLACE FUNCTION order_total(p_order UUID, p_tax NUMERIC DEFAULT 0.2) RETURNS NUMERIC AS $$
+BEGIN
+    RETURN (SELECT sum(amount) FROM order_items WHERE order_id = p_order);
+END;
+$$ LANGUAGE plpgsql;
+
+ALTER TABLE orders ADD COLUMN status TEXT NOT NULL DEFAULT 'new', RENAME COLUMN user_id TO customer_id;
+"#;
+        assert_eq!(SqlDialect::detect(postgres), SqlDialect::Postgres);
+        let interfaces = processor.extract_interfaces(postgres, Path::new("schema.sql"));
+        let names: Vec<(&str, &str)> = interfaces.iter().map(|i| (i.name.as_str(), i.interface_type.as_str())).collect();
+        assert_eq!(
+            names,
+            [
+                ("public.users", "sql_table"),
+                ("public.orders", "sql_table"),
+                ("public.order_total", "sql_function"),
+                ("public.orders", "sql_table_alter")
+            ]
+        );
+        let users = &interfaces[0];
+        assert_eq!(users.description.as_deref(), Some("Registered users"));
+        assert_eq!(users.parameters[0].description.as_deref(), Some("primary key, identity"));
+        assert_eq!((users.parameters[2].param_type.as_str(), users.parameters[2].is_optional), ("NUMERIC(10, 2)", true));
+        assert_eq!(interfaces[1].parameters[1].description.as_deref(), Some("references users(id)"));
+        let total = &interfaces[2];
+        assert_eq!(total.return_type.as_deref(), Some("NUMERIC"));
+        assert_eq!((total.parameters[1].name.as_str(), total.parameters[1].is_optional), ("p_tax", true));
+
+        let changes = schema_changes(postgres);
+        assert_eq!(changes.len(), 4);
+        let SchemaChange::CreateTable { primary_key, foreign_keys, .. } = &changes[1] else {
+            panic!("expected orders to be created, got {:?}", changes[1]);
+        };
+        assert_eq!(primary_key, &["id"]);
+        assert_eq!((foreign_keys[0].to_table.as_str(), foreign_keys[0].to_columns.as_slice()), ("users", &["id".to_string()][..]));
+        assert!(matches!(&changes[2], SchemaChange::AddColumn { column, .. } if column.name == "status" && !column.nullable));
+        assert!(matches!(&changes[3], SchemaChange::RenameColumn { from, to, .. } if from == "user_id" && to == "customer_id"));
+
+        let mysql = "DELIMITER //\nCREATE PROCEDURE `shop`.`archive_orders`(IN days INT, OUT archived INT)\nBEGIN\n  DELETE FROM orders WHERE created_at < NOW() - INTERVAL days DAY;\nEND //\nDELIMITER ;\nCREATE TABLE `products` (`id` INT AUTO_INCREMENT PRIMARY KEY, `name` VARCHAR(100)) ENGINE=InnoDB;\nRENAME TABLE products TO items;\n";
+        assert_eq!(SqlDialect::detect(mysql), SqlDialect::MySql);
+        let interfaces = processor.extract_interfaces(mysql, Path::new("procs.sql"));
+        assert_eq!(interfaces[0].name, "shop.archive_orders");
+        assert_eq!(interfaces[0].parameters[1].description.as_deref(), Some("Direction: OUT"));
+        assert!(interfaces[1].parameters[0].description.as_deref().unwrap().contains("identity"));
+        assert!(matches!(&schema_changes(mysql)[1], SchemaChange::RenameTable { to, .. } if to == "items"));
+        let dependencies = processor.extract_dependencies(mysql, Path::new("procs.sql"));
+        assert_eq!(dependencies[0].name, "orders");
+
+        let tsql = "CREATE PROCEDURE [dbo].[GetUser]\n    @Id INT,\n    @Name NVARCHAR(50) = NULL OUTPUT\nAS\nBEGIN\n    SELECT * FROM [dbo].[Users] WHERE Id = @Id\nEND\nGO\n";
+        assert_eq!(SqlDialect::detect(tsql), SqlDialect::TSql);
+        let interfaces = processor.extract_interfaces(tsql, Path::new("GetUser.sql"));
+        assert_eq!(interfaces[0].name, "dbo.GetUser");
+        assert_eq!(interfaces[0].parameters[1].name, "@Name");
+        assert_eq!(interfaces[0].parameters[1].description.as_deref(), Some("Default: NULL, Direction: OUT"));
+    }
+
+    #[test]
+    fn test_non_ascii_identifiers_and_defaults() {
+        // Uppercasing changes the byte length of these characters
+        let table = parse_create_table(
+            "CREATE TABLE ﬁles (ıd INT, ŉame TEXT DEFAULT 'ŉŉŉŉŉŉŉŉŉŉŉŉ', CONSTRAINT ﬁles_pk PRIMARY KEY (ıd))",
+        )
+        .unwrap();
+        assert_eq!(table.columns[1].name, "ŉame");
+        assert_eq!(table.columns[1].default_value.as_deref(), Some("'ŉŉŉŉŉŉŉŉŉŉŉŉ'"));
+        assert_eq!(table.primary_key, ["ıd"]);
+
+        let parameters = parse_routine_parameters("(p_ſtate TEXT DEFAULT 'ﬁne', p_ı INT)");
+        assert_eq!(parameters[0].name, "p_ſtate");
+        assert_eq!(parameters[0].description.as_deref(), Some("Default: 'ﬁne'"));
+        assert_eq!(parameters[1].name, "p_ı");
+    }
+}
@@ -27,6 +27,12 @@ impl SvelteProcessor {
     }
 }
 
+impl Default for SvelteProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl LanguageProcessor for SvelteProcessor {
     fn supported_extensions(&self) -> Vec<&'static str> {
         vec!["svelte"]
@@ -30,6 +30,12 @@ impl TypeScriptProcessor {
     }
 }
 
+impl Default for TypeScriptProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl LanguageProcessor for TypeScriptProcessor {
     fn supported_extensions(&self) -> Vec<&'static str> {
         vec!["ts", "tsx"]
@@ -174,7 +180,7 @@ impl LanguageProcessor for TypeScriptProcessor {
         "TypeScript"
     }
 
-    fn extract_interfaces(&self, content: &str, _file_path: &Path) -> Vec<InterfaceInfo> {
+    fn extract_interfaces(&self, content: &str, file_path: &Path) -> Vec<InterfaceInfo> {
         let mut interfaces = Vec::new();
         let lines: Vec<&str> = content.lines().collect();
 
@@ -329,6 +335,8 @@ impl LanguageProcessor for TypeScriptProcessor {
             }
         }
 
+        interfaces.extend(super::js_routes::extract_routes(content, file_path));
+
         interfaces
     }
 }
@@ -27,6 +27,12 @@ impl VueProcessor {
     }
 }
 
+impl Default for VueProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl LanguageProcessor for VueProcessor {
     fn supported_extensions(&self) -> Vec<&'static str> {
         vec!["vue"]
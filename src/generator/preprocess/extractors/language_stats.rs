@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::types::FileInfo;
+use crate::types::project_structure::LanguageStats;
+
+/// Language written in files of the extension; data, documentation and config formats have none
+pub fn language_of(extension: &str) -> Option<&'static str> {
+    let language = match extension.to_lowercase().as_str() {
+        "rs" => "Rust",
+        "py" | "pyi" => "Python",
+        "js" | "mjs" | "cjs" | "jsx" => "JavaScript",
+        "ts" | "mts" | "cts" | "tsx" => "TypeScript",
+        "java" => "Java",
+        "kt" | "kts" => "Kotlin",
+        "scala" => "Scala",
+        "go" => "Go",
+        "rb" => "Ruby",
+        "php" => "PHP",
+        "cs" => "C#",
+        "fs" => "F#",
+        "swift" => "Swift",
+        "m" | "mm" => "Objective-C",
+        "c" | "h" => "C",
+        "cpp" | "cc" | "cxx" | "hpp" | "hh" => "C++",
+        "dart" => "Dart",
+        "lua" => "Lua",
+        "ex" | "exs" => "Elixir",
+        "erl" => "Erlang",
+        "hs" => "Haskell",
+        "clj" | "cljs" => "Clojure",
+        "r" => "R",
+        "sql" => "SQL",
+        "sh" | "bash" | "zsh" => "Shell",
+        "ps1" => "PowerShell",
+        "vue" => "Vue",
+        "svelte" => "Svelte",
+        "html" | "htm" => "HTML",
+        "css" | "scss" | "sass" | "less" => "CSS",
+        "wxml" | "wxss" | "ttml" | "ksml" => "Mini Program",
+        "proto" => "Protocol Buffers",
+        "tf" => "Terraform",
+        _ => return None,
+    };
+    Some(language)
+}
+
+/// Count the files and non-blank lines of each language, most lines first
+pub fn collect(files: &[FileInfo], root: &Path) -> Vec<LanguageStats> {
+    let mut by_language: HashMap<&'static str, (usize, usize)> = HashMap::new();
+    for file in files {
+        let Some(language) = file.extension.as_deref().and_then(language_of) else {
+            continue;
+        };
+        let Ok(bytes) = std::fs::read(root.join(&file.path)) else {
+            continue;
+        };
+        let lines = String::from_utf8_lossy(&bytes)
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .count();
+        let entry = by_language.entry(language).or_default();
+        entry.0 += 1;
+        entry.1 += lines;
+    }
+
+    let mut stats: Vec<LanguageStats> = by_language
+        .into_iter()
+        .map(|(language, (files, lines))| LanguageStats {
+            language: language.to_string(),
+            files,
+            lines,
+        })
+        .collect();
+    stats.sort_by(|a, b| b.lines.cmp(&a.lines).then_with(|| a.language.cmp(&b.language)));
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_collect_language_stats() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        std::fs::create_dir_all(&root).unwrap();
+        let sources = [
+            ("main.rs", "fn main() {\n\n    run();\n}\n"),
+            ("lib.rs", "pub fn run() {}\n"),
+            ("app.tsx", "export const App = () => null;\n"),
+            ("package.json", "{}\n"),
+        ];
+        let mut files = Vec::new();
+        for (name, content) in sources {
+            std::fs::write(root.join(name), content).unwrap();
+            let path = PathBuf::from(name);
+            files.push(FileInfo {
+                name: name.to_string(),
+                extension: path.extension().map(|e| e.to_string_lossy().to_string()),
+                path,
+                size: content.len() as u64,
+                is_core: false,
+                importance_score: 0.0,
+                complexity_score: 0.0,
+                last_modified: None,
+            });
+        }
+
+        let stats = collect(&files, &root);
+
+        assert_eq!(
+            stats,
+            vec![
+                LanguageStats { language: "Rust".to_string(), files: 2, lines: 4 },
+                LanguageStats { language: "TypeScript".to_string(), files: 1, lines: 1 },
+            ]
+        );
+    }
+}
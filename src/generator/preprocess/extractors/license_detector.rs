@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::types::FileInfo;
+use crate::types::license::{LicenseFile, LicenseInfo, SpdxHeader};
+
+static SPDX_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"SPDX-License-Identifier:\s*([A-Za-z0-9.+\-() ]+?)\s*(?:\*/|-->|$)").unwrap());
+
+/// SPDX headers sit in the first lines of the files
+const SCANNED_BYTES: usize = 2 * 1024;
+
+/// Name prefixes of license files, compared case-insensitively
+const LICENSE_FILE_PREFIXES: [&str; 4] = ["license", "licence", "copying", "unlicense"];
+
+/// Detect the project license from the license files at the project root
+/// and the `SPDX-License-Identifier` headers of the source files
+pub fn detect(files: &[FileInfo], root: &Path) -> LicenseInfo {
+    let mut license_files: Vec<LicenseFile> = fs::read_dir(root)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let lowercase = name.to_lowercase();
+            if !LICENSE_FILE_PREFIXES.iter().any(|prefix| lowercase.starts_with(prefix)) {
+                return None;
+            }
+            let text = fs::read_to_string(entry.path()).ok()?;
+            Some(LicenseFile {
+                path: PathBuf::from(name),
+                spdx_id: identify(&text).map(str::to_string),
+            })
+        })
+        .collect();
+    license_files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let mut headers: HashMap<String, usize> = HashMap::new();
+    for file in files {
+        let Ok(bytes) = fs::read(root.join(&file.path)) else {
+            continue;
+        };
+        let head = String::from_utf8_lossy(&bytes[..bytes.len().min(SCANNED_BYTES)]);
+        if let Some(captures) = head.lines().find_map(|line| SPDX_REGEX.captures(line)) {
+            *headers.entry(captures[1].trim().to_string()).or_default() += 1;
+        }
+    }
+    let mut spdx_headers: Vec<SpdxHeader> = headers
+        .into_iter()
+        .map(|(expression, files)| SpdxHeader { expression, files })
+        .collect();
+    spdx_headers.sort_by(|a, b| b.files.cmp(&a.files).then_with(|| a.expression.cmp(&b.expression)));
+
+    LicenseInfo {
+        files: license_files,
+        spdx_headers,
+    }
+}
+
+/// SPDX identifier of a well-known license text
+fn identify(text: &str) -> Option<&'static str> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+    let has = |phrase: &str| text.contains(phrase);
+    let id = if has("gnu affero general public license") {
+        "AGPL-3.0"
+    } else if has("gnu lesser general public license") {
+        if has("version 2.1") { "LGPL-2.1" } else { "LGPL-3.0" }
+    } else if has("gnu general public license") {
+        if has("version 2,") || has("version 2 ") { "GPL-2.0" } else { "GPL-3.0" }
+    } else if has("apache license") && has("version 2.0") {
+        "Apache-2.0"
+    } else if has("mozilla public license") && has("2.0") {
+        "MPL-2.0"
+    } else if has("free and unencumbered software released into the public domain") {
+        "Unlicense"
+    } else if has("permission to use, copy, modify, and/or distribute this software for any purpose") {
+        "ISC"
+    } else if has("permission is hereby granted, free of charge") {
+        "MIT"
+    } else if has("redistribution and use in source and binary forms") {
+        if has("neither the name") { "BSD-3-Clause" } else { "BSD-2-Clause" }
+    } else if has("creative commons") && has("cc0") {
+        "CC0-1.0"
+    } else {
+        return None;
+    };
+    Some(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_license() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(
+            root.join("LICENSE-MIT"),
+            "MIT License\n\nPermission is hereby granted, free of charge, to any person obtaining a copy",
+        )
+        .unwrap();
+        fs::write(
+            root.join("LICENSE-APACHE"),
+            "                 Apache License\n           Version 2.0, January 2004",
+        )
+        .unwrap();
+        fs::write(root.join("src/main.rs"), "// SPDX-License-Identifier: MIT OR Apache-2.0\nfn main() {}\n").unwrap();
+        fs::write(root.join("src/lib.c"), "/* SPDX-License-Identifier: MIT OR Apache-2.0 */\n").unwrap();
+        let files: Vec<FileInfo> = ["src/main.rs", "src/lib.c"]
+            .into_iter()
+            .map(|path| FileInfo {
+                path: PathBuf::from(path),
+                name: path.to_string(),
+                size: 0,
+                extension: None,
+                is_core: false,
+                importance_score: 0.0,
+                complexity_score: 0.0,
+                last_modified: None,
+            })
+            .collect();
+
+        let license = detect(&files, &root);
+
+        assert_eq!(license.files.len(), 2);
+        assert_eq!(
+            license.spdx_headers,
+            vec![SpdxHeader {
+                expression: "MIT OR Apache-2.0".to_string(),
+                files: 2
+            }]
+        );
+        assert_eq!(license.identifiers(), vec!["Apache-2.0", "MIT", "MIT OR Apache-2.0"]);
+    }
+}
@@ -0,0 +1,1182 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::generator::preprocess::extractors::language_processors::sql;
+use crate::generator::research::types::{DatabaseTable, DatabaseView, TableColumn, TableRelationship};
+use crate::types::FileInfo;
+use crate::utils::encoding;
+use crate::utils::paths::to_slash;
+use crate::utils::threads::par_map;
+
+/// `V1_2__name.sql` versioned and `R__name.sql` repeatable Flyway migrations
+static FLYWAY_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?:V(\d+(?:[._]\d+)*)|(R))__.+\.sql$").unwrap());
+/// Leading number of a migration file name, such as golang-migrate's `000001_` or dbmate's timestamps
+static LEADING_NUMBER_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\d+)").unwrap());
+/// Lines ending the up part of a migration holding both directions (dbmate, goose, sql-migrate)
+static DOWN_MARKER_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?im)^\s*--\s*(?:migrate:down|\+goose\s+down|\+migrate\s+down)\b").unwrap());
+static XML_TAG_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"<(/?)([\w:.-]+)((?:\s+[\w:.-]+\s*=\s*(?:"[^"]*"|'[^']*'))*)\s*(/?)>"#).unwrap()
+});
+static XML_ATTRIBUTE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"([\w:.-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap());
+/// Comments and rollback sections of a Liquibase changelog, which do not shape the schema
+static XML_IGNORED_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<!--.*?-->|<rollback\b[^>]*/>|<rollback\b.*?</rollback>").unwrap());
+static ALEMBIC_REVISION_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?m)^(down_)?revision\s*(?::[^=]+)?=\s*(.+)$"#).unwrap());
+static ALEMBIC_BATCH_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"op\.batch_alter_table\(\s*['\x22](\w+)['\x22][^)]*\)\s+as\s+(\w+)").unwrap());
+static PYTHON_CALL_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b(\w+)\.(\w+)\(").unwrap());
+static PYTHON_STRING_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"['"](\w+)['"]"#).unwrap());
+
+/// Directory names holding plain SQL migrations
+const MIGRATION_DIRS: [&str; 3] = ["migrations", "migration", "migrate"];
+
+/// Tool whose migrations shaped the schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MigrationTool {
+    Flyway,
+    Liquibase,
+    Diesel,
+    Alembic,
+    /// Numbered SQL files of golang-migrate, dbmate, goose and similar tools
+    Sql,
+}
+
+impl MigrationTool {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MigrationTool::Flyway => "Flyway",
+            MigrationTool::Liquibase => "Liquibase",
+            MigrationTool::Diesel => "Diesel",
+            MigrationTool::Alembic => "Alembic",
+            MigrationTool::Sql => "SQL migrations",
+        }
+    }
+}
+
+/// Table name, with its schema when qualified
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableRef {
+    pub schema: Option<String>,
+    pub name: String,
+}
+
+impl TableRef {
+    /// `schema.name`, or the name alone when unqualified
+    pub fn label(&self) -> String {
+        match &self.schema {
+            Some(schema) => format!("{}.{}", schema, self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    /// Whether both name the same table; an unqualified name matches any schema
+    pub fn matches(&self, schema: &str, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+            && (schema.is_empty() || self.schema.as_ref().is_none_or(|own| own.eq_ignore_ascii_case(schema)))
+    }
+
+    fn matches_label(&self, label: &str) -> bool {
+        let other = sql::parse_name(label);
+        self.matches(other.schema.as_deref().unwrap_or_default(), &other.name)
+    }
+}
+
+/// Change one migration statement makes to the schema
+#[derive(Debug, Clone)]
+pub enum SchemaChange {
+    CreateTable {
+        table: TableRef,
+        columns: Vec<TableColumn>,
+        primary_key: Vec<String>,
+        foreign_keys: Vec<TableRelationship>,
+    },
+    DropTable(TableRef),
+    RenameTable {
+        table: TableRef,
+        to: String,
+    },
+    AddColumn {
+        table: TableRef,
+        column: TableColumn,
+    },
+    DropColumn {
+        table: TableRef,
+        column: String,
+    },
+    RenameColumn {
+        table: TableRef,
+        from: String,
+        to: String,
+    },
+    /// Changed attributes of a column; `None` leaves one as it is
+    AlterColumn {
+        table: TableRef,
+        column: String,
+        data_type: Option<String>,
+        nullable: Option<bool>,
+        default_value: Option<Option<String>>,
+    },
+    AddPrimaryKey {
+        table: TableRef,
+        columns: Vec<String>,
+    },
+    AddForeignKey(TableRelationship),
+    CreateView(DatabaseView),
+    DropView(TableRef),
+}
+
+/// Migration applied to build the schema
+#[derive(Debug, Clone)]
+pub struct Migration {
+    pub tool: MigrationTool,
+    /// Path relative to the project root
+    pub path: PathBuf,
+    /// Version or revision, when the tool names one
+    pub version: Option<String>,
+}
+
+/// Schema left by applying the migrations of the project in order
+#[derive(Debug, Clone, Default)]
+pub struct MigratedSchema {
+    pub migrations: Vec<Migration>,
+    pub tables: Vec<DatabaseTable>,
+    pub views: Vec<DatabaseView>,
+    pub relationships: Vec<TableRelationship>,
+    /// Tables created or referenced by migrations and dropped by a later one
+    pub dropped_tables: Vec<String>,
+}
+
+impl MigratedSchema {
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty() && self.views.is_empty() && self.dropped_tables.is_empty()
+    }
+
+    /// Tools of the applied migrations
+    pub fn tools(&self) -> Vec<MigrationTool> {
+        let mut tools: Vec<MigrationTool> = self.migrations.iter().map(|migration| migration.tool).collect();
+        tools.sort();
+        tools.dedup();
+        tools
+    }
+
+    fn table_mut(&mut self, table: &TableRef) -> Option<&mut DatabaseTable> {
+        self.tables.iter_mut().find(|existing| table.matches(&existing.schema, &existing.name))
+    }
+
+    /// Apply a change made by the migration at `source`
+    pub fn apply(&mut self, change: SchemaChange, source: &str) {
+        match change {
+            SchemaChange::CreateTable { table, columns, primary_key, foreign_keys } => {
+                self.tables.retain(|existing| !table.matches(&existing.schema, &existing.name));
+                self.dropped_tables.retain(|dropped| !table.matches_label(dropped));
+                self.tables.push(DatabaseTable {
+                    schema: table.schema.clone().unwrap_or_default(),
+                    name: table.name,
+                    columns,
+                    primary_key,
+                    description: String::new(),
+                    source_path: source.to_string(),
+                });
+                for relationship in foreign_keys {
+                    self.add_relationship(relationship);
+                }
+            }
+            SchemaChange::DropTable(table) => {
+                let before = self.tables.len();
+                self.tables.retain(|existing| !table.matches(&existing.schema, &existing.name));
+                self.relationships
+                    .retain(|relationship| !table.matches_label(&relationship.from_table) && !table.matches_label(&relationship.to_table));
+                if self.tables.len() < before || !self.dropped_tables.contains(&table.label()) {
+                    self.dropped_tables.push(table.label());
+                }
+            }
+            SchemaChange::RenameTable { table, to } => {
+                let renamed = TableRef { schema: table.schema.clone(), name: to.clone() };
+                for relationship in self.relationships.iter_mut() {
+                    for label in [&mut relationship.from_table, &mut relationship.to_table] {
+                        if table.matches_label(label) {
+                            *label = renamed.label();
+                        }
+                    }
+                }
+                if let Some(existing) = self.table_mut(&table) {
+                    existing.name = to;
+                }
+            }
+            SchemaChange::AddColumn { table, column } => {
+                if let Some(existing) = self.table_mut(&table) {
+                    existing.columns.retain(|other| !other.name.eq_ignore_ascii_case(&column.name));
+                    existing.columns.push(column);
+                }
+            }
+            SchemaChange::DropColumn { table, column } => {
+                if let Some(existing) = self.table_mut(&table) {
+                    existing.columns.retain(|other| !other.name.eq_ignore_ascii_case(&column));
+                    existing.primary_key.retain(|key| !key.eq_ignore_ascii_case(&column));
+                }
+                self.relationships.retain(|relationship| {
+                    !(table.matches_label(&relationship.from_table)
+                        && relationship.from_columns.iter().any(|from| from.eq_ignore_ascii_case(&column)))
+                });
+            }
+            SchemaChange::RenameColumn { table, from, to } => {
+                let rename = |names: &mut Vec<String>| {
+                    for name in names.iter_mut().filter(|name| name.eq_ignore_ascii_case(&from)) {
+                        *name = to.clone();
+                    }
+                };
+                for relationship in self.relationships.iter_mut() {
+                    if table.matches_label(&relationship.from_table) {
+                        rename(&mut relationship.from_columns);
+                    }
+                    if table.matches_label(&relationship.to_table) {
+                        rename(&mut relationship.to_columns);
+                    }
+                }
+                if let Some(existing) = self.table_mut(&table) {
+                    rename(&mut existing.primary_key);
+                    for column in existing.columns.iter_mut().filter(|column| column.name.eq_ignore_ascii_case(&from)) {
+                        column.name = to.clone();
+                    }
+                }
+            }
+            SchemaChange::AlterColumn { table, column, data_type, nullable, default_value } => {
+                if let Some(existing) = self.table_mut(&table)
+                    && let Some(existing) = existing.columns.iter_mut().find(|other| other.name.eq_ignore_ascii_case(&column))
+                {
+                    if let Some(data_type) = data_type.filter(|data_type| !data_type.is_empty()) {
+                        existing.data_type = data_type;
+                    }
+                    if let Some(nullable) = nullable {
+                        existing.nullable = nullable;
+                    }
+                    if let Some(default_value) = default_value {
+                        existing.default_value = default_value;
+                    }
+                }
+            }
+            SchemaChange::AddPrimaryKey { table, columns } => {
+                if let Some(existing) = self.table_mut(&table) {
+                    for column in existing.columns.iter_mut() {
+                        if columns.iter().any(|key| key.eq_ignore_ascii_case(&column.name)) {
+                            column.nullable = false;
+                        }
+                    }
+                    existing.primary_key = columns;
+                }
+            }
+            SchemaChange::AddForeignKey(relationship) => self.add_relationship(relationship),
+            SchemaChange::CreateView(mut view) => {
+                let name = TableRef { schema: Some(view.schema.clone()).filter(|schema| !schema.is_empty()), name: view.name.clone() };
+                self.views.retain(|existing| !name.matches(&existing.schema, &existing.name));
+                view.source_path = source.to_string();
+                self.views.push(view);
+            }
+            SchemaChange::DropView(view) => self.views.retain(|existing| !view.matches(&existing.schema, &existing.name)),
+        }
+    }
+
+    fn add_relationship(&mut self, relationship: TableRelationship) {
+        let exists = self.relationships.iter().any(|existing| {
+            existing.from_table.eq_ignore_ascii_case(&relationship.from_table)
+                && existing.to_table.eq_ignore_ascii_case(&relationship.to_table)
+                && existing.from_columns == relationship.from_columns
+        });
+        if !exists {
+            self.relationships.push(relationship);
+        }
+    }
+}
+
+/// Migration file with the changes it makes, before ordering
+struct Parsed {
+    tool: MigrationTool,
+    path: PathBuf,
+    version: Option<String>,
+    /// Sort key within the migrations of its tool
+    order: (Vec<u64>, String),
+    changes: Vec<SchemaChange>,
+    /// Liquibase includes and Alembic parent revisions
+    links: Vec<String>,
+}
+
+/// Build the schema the migrations of the project leave, applying Flyway, Liquibase (XML and
+/// formatted SQL), Diesel, Alembic and numbered SQL migrations in the order of their tool
+pub fn collect(files: &[FileInfo], root: &Path) -> MigratedSchema {
+    let candidates: Vec<&FileInfo> = files.iter().filter(|file| candidate_tool(&file.path).is_some()).collect();
+    let parsed = par_map(&candidates, |file| {
+        let bytes = fs::read(root.join(&file.path)).ok()?;
+        let (content, _) = encoding::decode(&bytes);
+        parse_migration(&file.path, &content)
+    });
+
+    let mut by_tool: BTreeMap<MigrationTool, Vec<Parsed>> = BTreeMap::new();
+    for migration in parsed.into_iter().flatten() {
+        by_tool.entry(migration.tool).or_default().push(migration);
+    }
+
+    let mut schema = MigratedSchema::default();
+    for (tool, mut migrations) in by_tool {
+        match tool {
+            MigrationTool::Liquibase => order_liquibase(&mut migrations),
+            MigrationTool::Alembic => order_alembic(&mut migrations),
+            _ => migrations.sort_by(|a, b| a.order.cmp(&b.order)),
+        }
+        for migration in migrations {
+            let source = to_slash(&migration.path);
+            for change in migration.changes {
+                schema.apply(change, &source);
+            }
+            schema.migrations.push(Migration {
+                tool,
+                path: migration.path,
+                version: migration.version,
+            });
+        }
+    }
+    schema
+}
+
+/// Tool a file may be a migration of, judging by its path
+fn candidate_tool(path: &Path) -> Option<MigrationTool> {
+    let name = path.file_name()?.to_string_lossy();
+    let slash = to_slash(path).to_lowercase();
+    let extension = path.extension().map(|extension| extension.to_string_lossy().to_lowercase());
+    let in_dir = |names: &[&str]| path.parent().is_some_and(|parent| parent.iter().any(|part| names.contains(&part.to_string_lossy().to_lowercase().as_str())));
+
+    match extension.as_deref() {
+        Some("sql") if FLYWAY_REGEX.is_match(&name) => Some(MigrationTool::Flyway),
+        Some("sql") if name == "up.sql" && in_dir(&["migrations"]) => Some(MigrationTool::Diesel),
+        Some("sql") if in_dir(&MIGRATION_DIRS) || slash.contains("changelog") || slash.contains("liquibase") => Some(MigrationTool::Sql),
+        Some("xml") if slash.contains("changelog") || slash.contains("liquibase") || in_dir(&MIGRATION_DIRS) => {
+            Some(MigrationTool::Liquibase)
+        }
+        Some("py") if in_dir(&["versions"]) => Some(MigrationTool::Alembic),
+        _ => None,
+    }
+}
+
+fn parse_migration(path: &Path, content: &str) -> Option<Parsed> {
+    let name = path.file_name()?.to_string_lossy().to_string();
+    let mut parsed = Parsed {
+        tool: candidate_tool(path)?,
+        path: path.to_path_buf(),
+        version: None,
+        order: (Vec::new(), to_slash(path)),
+        changes: Vec::new(),
+        links: Vec::new(),
+    };
+
+    match parsed.tool {
+        MigrationTool::Flyway => {
+            let captures = FLYWAY_REGEX.captures(&name)?;
+            match captures.get(1) {
+                Some(version) => {
+                    parsed.order.0 = version.as_str().split(['.', '_']).filter_map(|part| part.parse().ok()).collect();
+                    parsed.version = Some(version.as_str().replace('_', "."));
+                }
+                // Repeatable migrations run after the versioned ones
+                None => parsed.order.0 = vec![u64::MAX],
+            }
+            parsed.changes = sql::schema_changes(content);
+        }
+        MigrationTool::Diesel => {
+            let directory = path.parent()?.file_name()?.to_string_lossy().to_string();
+            parsed.version = directory.split('_').next().map(str::to_string);
+            parsed.order.1 = directory;
+            parsed.changes = sql::schema_changes(content);
+        }
+        MigrationTool::Sql if content.trim_start().to_lowercase().starts_with("--liquibase formatted sql") => {
+            parsed.tool = MigrationTool::Liquibase;
+            parsed.changes = sql::schema_changes(content);
+        }
+        MigrationTool::Sql => {
+            let lower = name.to_lowercase();
+            if lower.contains(".down.") || lower.ends_with("_down.sql") || lower == "down.sql" || lower.starts_with("u") && FLYWAY_REGEX.is_match(&name[1..]) {
+                return None;
+            }
+            if let Some(number) = LEADING_NUMBER_REGEX.captures(&name) {
+                parsed.version = Some(number[1].to_string());
+                parsed.order.0 = vec![number[1].parse().unwrap_or(u64::MAX)];
+            }
+            let up = DOWN_MARKER_REGEX.find(content).map_or(content, |marker| &content[..marker.start()]);
+            parsed.changes = sql::schema_changes(up);
+        }
+        MigrationTool::Liquibase => {
+            if !content.contains("<databaseChangeLog") {
+                return None;
+            }
+            let (changes, includes) = parse_liquibase_xml(content);
+            parsed.changes = changes;
+            parsed.links = includes;
+        }
+        MigrationTool::Alembic => {
+            if !content.contains("def upgrade") {
+                return None;
+            }
+            for captures in ALEMBIC_REVISION_REGEX.captures_iter(content) {
+                let revisions: Vec<String> = PYTHON_STRING_REGEX.captures_iter(&captures[2]).map(|string| string[1].to_string()).collect();
+                if captures.get(1).is_some() {
+                    parsed.links = revisions;
+                } else {
+                    parsed.version = revisions.into_iter().next();
+                }
+            }
+            parsed.version.as_ref()?;
+            parsed.changes = parse_alembic(content);
+        }
+    }
+    Some(parsed)
+}
+
+/// Order Liquibase changelogs by the position of their `include` in the master changelogs;
+/// changelogs nobody includes are masters, taken in path order
+fn order_liquibase(migrations: &mut Vec<Parsed>) {
+    let paths: Vec<String> = migrations.iter().map(|migration| to_slash(&migration.path)).collect();
+    // Includes are relative to the including changelog or to a classpath root; `includeAll`
+    // names a directory, whose changelogs are taken in name order
+    let resolve = |including: usize, include: &str| -> Vec<usize> {
+        let include = include.trim_start_matches("classpath:").trim_start_matches("./").trim_start_matches('/');
+        let directory = paths[including].rsplit_once('/').map_or("", |(directory, _)| directory);
+        let relative = to_slash(Path::new(directory).join(include));
+        let suffix = format!("/{}", include);
+        if let Some(file) = paths.iter().position(|path| *path == relative || *path == include || path.ends_with(&suffix)) {
+            return vec![file];
+        }
+        let suffix = suffix.trim_end_matches('/');
+        let mut files: Vec<usize> = (0..paths.len())
+            .filter(|index| paths[*index].rsplit_once('/').is_some_and(|(parent, _)| parent == include.trim_end_matches('/') || parent.ends_with(suffix)))
+            .collect();
+        files.sort_by(|a, b| paths[*a].cmp(&paths[*b]));
+        files
+    };
+    let children: Vec<Vec<usize>> = (0..migrations.len())
+        .map(|index| {
+            let mut children: Vec<usize> = Vec::new();
+            for child in migrations[index].links.iter().flat_map(|include| resolve(index, include)) {
+                if child != index && !children.contains(&child) {
+                    children.push(child);
+                }
+            }
+            children
+        })
+        .collect();
+
+    let mut included = vec![false; migrations.len()];
+    for child in children.iter().flatten() {
+        included[*child] = true;
+    }
+    let mut roots: Vec<usize> = (0..migrations.len()).filter(|index| !included[*index]).collect();
+    roots.sort_by(|a, b| paths[*a].cmp(&paths[*b]));
+
+    fn visit(index: usize, children: &[Vec<usize>], visited: &mut Vec<bool>, order: &mut Vec<usize>) {
+        if visited[index] {
+            return;
+        }
+        visited[index] = true;
+        order.push(index);
+        for child in &children[index] {
+            visit(*child, children, visited, order);
+        }
+    }
+    let mut visited = vec![false; migrations.len()];
+    let mut order = Vec::new();
+    for root in roots.into_iter().chain(0..migrations.len()) {
+        visit(root, &children, &mut visited, &mut order);
+    }
+    let rank: HashMap<usize, usize> = order.into_iter().enumerate().map(|(rank, index)| (index, rank)).collect();
+    let mut ranked: Vec<(usize, Parsed)> = migrations.drain(..).enumerate().map(|(index, migration)| (rank[&index], migration)).collect();
+    ranked.sort_by_key(|(rank, _)| *rank);
+    migrations.extend(ranked.into_iter().map(|(_, migration)| migration));
+}
+
+
+/// Order Alembic revisions by their distance from the first revision; a merge revision
+/// comes after its longest parent branch
+fn order_alembic(migrations: &mut [Parsed]) {
+    let parents: HashMap<String, Vec<String>> = migrations
+        .iter()
+        .filter_map(|migration| Some((migration.version.clone()?, migration.links.clone())))
+        .collect();
+
+    fn depth(revision: &str, parents: &HashMap<String, Vec<String>>, depths: &mut HashMap<String, u64>, visiting: &mut Vec<String>) -> u64 {
+        if let Some(depth) = depths.get(revision) {
+            return *depth;
+        }
+        if visiting.iter().any(|other| other == revision) {
+            return 0;
+        }
+        visiting.push(revision.to_string());
+        let depth = parents
+            .get(revision)
+            .into_iter()
+            .flatten()
+            .filter(|parent| parents.contains_key(parent.as_str()))
+            .map(|parent| depth(parent, parents, depths, visiting) + 1)
+            .max()
+            .unwrap_or(0);
+        visiting.pop();
+        depths.insert(revision.to_string(), depth);
+        depth
+    }
+
+    let mut depths = HashMap::new();
+    for revision in parents.keys() {
+        depth(revision, &parents, &mut depths, &mut Vec::new());
+    }
+    migrations.sort_by(|a, b| {
+        let depth = |migration: &Parsed| migration.version.as_ref().and_then(|version| depths.get(version)).copied().unwrap_or(0);
+        depth(a).cmp(&depth(b)).then_with(|| a.order.cmp(&b.order))
+    });
+}
+
+/// Changes of a Liquibase XML changelog, and the changelogs it includes
+fn parse_liquibase_xml(content: &str) -> (Vec<SchemaChange>, Vec<String>) {
+    let content = XML_IGNORED_REGEX.replace_all(content, "");
+    let mut changes = Vec::new();
+    let mut includes = Vec::new();
+    // Change holding `column` elements: its tag and table, the columns, their keys
+    let mut open: Option<(String, TableRef)> = None;
+    let mut columns: Vec<TableColumn> = Vec::new();
+    let mut primary_key: Vec<String> = Vec::new();
+    let mut foreign_keys: Vec<TableRelationship> = Vec::new();
+
+    let mut at = 0;
+    while let Some(captures) = XML_TAG_REGEX.captures_at(&content, at) {
+        at = captures.get(0).map_or(content.len(), |tag| tag.end());
+        let full_name = &captures[2];
+        let tag = full_name.rsplit(':').next().unwrap_or(full_name);
+        let attributes: HashMap<&str, &str> = XML_ATTRIBUTE_REGEX
+            .captures_iter(&captures[3])
+            .filter_map(|attribute| Some((attribute.get(1)?.as_str(), attribute.get(2).or(attribute.get(3))?.as_str())))
+            .collect();
+        let attribute = |name: &str| attributes.get(name).map(|value| unescape_xml(value)).filter(|value| !value.is_empty());
+        let table_named = |name: &str| TableRef { schema: attribute("schemaName"), name: attribute(name).unwrap_or_default() };
+        let table = || table_named("tableName");
+
+        if !captures[1].is_empty() {
+            if let Some((_, table)) = open.take_if(|(open_tag, _)| open_tag == tag) {
+                let columns = std::mem::take(&mut columns);
+                let primary_key = std::mem::take(&mut primary_key);
+                let foreign_keys = std::mem::take(&mut foreign_keys);
+                match tag {
+                    "createTable" => changes.push(SchemaChange::CreateTable { table, columns, primary_key, foreign_keys }),
+                    "addColumn" => {
+                        changes.extend(columns.into_iter().map(|column| SchemaChange::AddColumn { table: table.clone(), column }));
+                        if !primary_key.is_empty() {
+                            changes.push(SchemaChange::AddPrimaryKey { table, columns: primary_key });
+                        }
+                        changes.extend(foreign_keys.into_iter().map(SchemaChange::AddForeignKey));
+                    }
+                    _ => changes.extend(columns.into_iter().map(|column| SchemaChange::DropColumn { table: table.clone(), column: column.name })),
+                }
+            }
+            continue;
+        }
+        let self_closing = !captures[4].is_empty();
+
+        match tag {
+            "include" => includes.extend(attribute("file")),
+            "includeAll" => includes.extend(attribute("path")),
+            "createTable" | "addColumn" | "dropColumn" if !self_closing => open = Some((tag.to_string(), table())),
+            "dropColumn" => {
+                if let Some(column) = attribute("columnName") {
+                    changes.push(SchemaChange::DropColumn { table: table(), column });
+                }
+            }
+            "column" if open.is_some() => {
+                let default_value = ["defaultValue", "defaultValueNumeric", "defaultValueBoolean", "defaultValueDate", "defaultValueComputed"]
+                    .iter()
+                    .find_map(|name| attribute(name));
+                columns.push(TableColumn {
+                    name: attribute("name").unwrap_or_default(),
+                    data_type: attribute("type").unwrap_or_default(),
+                    nullable: true,
+                    is_identity: attribute("autoIncrement").is_some_and(|value| value == "true"),
+                    default_value,
+                });
+            }
+            "constraints" => {
+                let (Some((_, table)), Some(column)) = (&open, columns.last_mut()) else {
+                    continue;
+                };
+                if attribute("nullable").is_some_and(|value| value == "false") {
+                    column.nullable = false;
+                }
+                if attribute("primaryKey").is_some_and(|value| value == "true") {
+                    column.nullable = false;
+                    primary_key.push(column.name.clone());
+                }
+                let target = match (attribute("references"), attribute("referencedTableName")) {
+                    (Some(references), _) => references
+                        .split_once('(')
+                        .map(|(target, columns)| (target.trim().to_string(), split_list(columns.trim_end_matches(')')))),
+                    (None, Some(target)) => Some((target, attribute("referencedColumnNames").map(|columns| split_list(&columns)).unwrap_or_default())),
+                    (None, None) => None,
+                };
+                if let Some((target, to_columns)) = target {
+                    foreign_keys.push(TableRelationship {
+                        from_table: table.label(),
+                        from_columns: vec![column.name.clone()],
+                        to_table: target,
+                        to_columns,
+                        relationship_type: "ForeignKey".to_string(),
+                        constraint_name: attribute("foreignKeyName"),
+                    });
+                }
+            }
+            "dropTable" => changes.push(SchemaChange::DropTable(table())),
+            "renameTable" => changes.push(SchemaChange::RenameTable {
+                table: table_named("oldTableName"),
+                to: attribute("newTableName").unwrap_or_default(),
+            }),
+            "renameColumn" => changes.push(SchemaChange::RenameColumn {
+                table: table(),
+                from: attribute("oldColumnName").unwrap_or_default(),
+                to: attribute("newColumnName").unwrap_or_default(),
+            }),
+            "modifyDataType" | "addNotNullConstraint" | "dropNotNullConstraint" | "addDefaultValue" | "dropDefaultValue" => {
+                let default_value = ["defaultValue", "defaultValueNumeric", "defaultValueBoolean", "defaultValueDate", "defaultValueComputed"]
+                    .iter()
+                    .find_map(|name| attribute(name));
+                changes.push(SchemaChange::AlterColumn {
+                    table: table(),
+                    column: attribute("columnName").unwrap_or_default(),
+                    data_type: attribute("newDataType"),
+                    nullable: match tag {
+                        "addNotNullConstraint" => Some(false),
+                        "dropNotNullConstraint" => Some(true),
+                        _ => None,
+                    },
+                    default_value: match tag {
+                        "addDefaultValue" => Some(default_value),
+                        "dropDefaultValue" => Some(None),
+                        _ => None,
+                    },
+                });
+            }
+            "addPrimaryKey" => changes.push(SchemaChange::AddPrimaryKey {
+                table: table(),
+                columns: attribute("columnNames").map(|columns| split_list(&columns)).unwrap_or_default(),
+            }),
+            "addForeignKeyConstraint" => changes.push(SchemaChange::AddForeignKey(TableRelationship {
+                from_table: TableRef { schema: attribute("baseTableSchemaName"), name: attribute("baseTableName").unwrap_or_default() }.label(),
+                from_columns: attribute("baseColumnNames").map(|columns| split_list(&columns)).unwrap_or_default(),
+                to_table: TableRef {
+                    schema: attribute("referencedTableSchemaName"),
+                    name: attribute("referencedTableName").unwrap_or_default(),
+                }
+                .label(),
+                to_columns: attribute("referencedColumnNames").map(|columns| split_list(&columns)).unwrap_or_default(),
+                relationship_type: "ForeignKey".to_string(),
+                constraint_name: attribute("constraintName"),
+            })),
+            "dropView" => changes.push(SchemaChange::DropView(table_named("viewName"))),
+            "createView" | "sql" if !self_closing => {
+                let end = content[at..].find(&format!("</{}", full_name)).map_or(content.len(), |end| at + end);
+                let text = unescape_xml(content[at..end].trim().trim_start_matches("<![CDATA[").trim_end_matches("]]>"));
+                at = end;
+                if tag == "sql" {
+                    changes.extend(sql::schema_changes(&text));
+                } else {
+                    changes.push(SchemaChange::CreateView(DatabaseView {
+                        schema: attribute("schemaName").unwrap_or_default(),
+                        name: attribute("viewName").unwrap_or_default(),
+                        referenced_tables: sql::referenced_tables(&text),
+                        ..Default::default()
+                    }));
+                }
+            }
+            _ => {}
+        }
+    }
+    (changes, includes)
+}
+
+fn unescape_xml(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn split_list(list: &str) -> Vec<String> {
+    list.split(',').map(str::trim).filter(|item| !item.is_empty()).map(str::to_string).collect()
+}
+
+/// Arguments of a Python call
+#[derive(Default)]
+pub(crate) struct PythonCall {
+    pub(crate) positional: Vec<String>,
+    pub(crate) keywords: HashMap<String, String>,
+}
+
+impl PythonCall {
+    /// Arguments of the call starting at the opening parenthesis of `text`
+    pub(crate) fn parse(text: &str) -> Option<Self> {
+        let mut call = PythonCall::default();
+        let inner = python_balanced(text)?;
+        for argument in python_split(&inner[1..inner.len() - 1]) {
+            let keyword = argument
+                .split_once('=')
+                .filter(|(name, value)| name.trim().chars().all(|c| c.is_alphanumeric() || c == '_') && !value.starts_with('='));
+            match keyword {
+                Some((name, value)) => {
+                    call.keywords.insert(name.trim().to_string(), value.trim().to_string());
+                }
+                None => call.positional.push(argument),
+            }
+        }
+        Some(call)
+    }
+
+    pub(crate) fn string(&self, index: usize) -> Option<String> {
+        self.positional.get(index).and_then(|argument| python_string(argument))
+    }
+
+    pub(crate) fn keyword_string(&self, name: &str) -> Option<String> {
+        self.keywords.get(name).and_then(|argument| python_string(argument))
+    }
+
+    pub(crate) fn flag(&self, name: &str) -> Option<bool> {
+        self.keywords.get(name).and_then(|value| match value.as_str() {
+            "True" => Some(true),
+            "False" => Some(false),
+            _ => None,
+        })
+    }
+}
+
+/// Changes made by the `upgrade()` function of an Alembic revision
+fn parse_alembic(content: &str) -> Vec<SchemaChange> {
+    let Some(start) = content.find("def upgrade") else {
+        return Vec::new();
+    };
+    let upgrade = &content[start..];
+    let end = upgrade.find("\ndef ").unwrap_or(upgrade.len());
+    let body = upgrade[..end]
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let batches: HashMap<String, String> = ALEMBIC_BATCH_REGEX
+        .captures_iter(&body)
+        .map(|captures| (captures[2].to_string(), captures[1].to_string()))
+        .collect();
+
+    let mut changes = Vec::new();
+    for captures in PYTHON_CALL_REGEX.captures_iter(&body) {
+        let batch_table = batches.get(&captures[1]);
+        if &captures[1] != "op" && batch_table.is_none() {
+            continue;
+        }
+        let operation = &captures[2];
+        let open = captures.get(0).map_or(0, |call| call.end() - 1);
+        let Some(mut call) = PythonCall::parse(&body[open..]) else {
+            continue;
+        };
+        if let Some(table) = batch_table {
+            // Batch operations leave out the table; the constraint name comes first
+            let at = if matches!(operation, "create_foreign_key" | "create_primary_key") { 1 } else { 0 };
+            call.positional.insert(at.min(call.positional.len()), format!("'{}'", table));
+        }
+        let table_at = |index: usize| TableRef { schema: call.keyword_string("schema"), name: call.string(index).unwrap_or_default() };
+
+        match operation {
+            "create_table" => {
+                let table = table_at(0);
+                let mut columns = Vec::new();
+                let mut primary_key = Vec::new();
+                let mut foreign_keys = Vec::new();
+                for argument in call.positional.iter().skip(1) {
+                    let Some(open) = argument.find('(') else {
+                        continue;
+                    };
+                    let Some(element) = PythonCall::parse(&argument[open..]) else {
+                        continue;
+                    };
+                    let constructor = argument[..open].rsplit('.').next().unwrap_or_default();
+                    match constructor {
+                        "Column" => {
+                            if let Some((column, is_primary_key, reference)) = alembic_column(&table, &element) {
+                                if is_primary_key {
+                                    primary_key.push(column.name.clone());
+                                }
+                                foreign_keys.extend(reference);
+                                columns.push(column);
+                            }
+                        }
+                        "PrimaryKeyConstraint" => primary_key = element.positional.iter().filter_map(|column| python_string(column)).collect(),
+                        "ForeignKeyConstraint" => {
+                            let remote = element.positional.get(1).map(|list| python_list(list)).unwrap_or_default();
+                            let Some((to_table, _)) = remote.first().and_then(|column| column.rsplit_once('.')) else {
+                                continue;
+                            };
+                            foreign_keys.push(TableRelationship {
+                                from_table: table.label(),
+                                from_columns: element.positional.first().map(|list| python_list(list)).unwrap_or_default(),
+                                to_table: to_table.to_string(),
+                                to_columns: remote.iter().filter_map(|column| column.rsplit_once('.')).map(|(_, column)| column.to_string()).collect(),
+                                relationship_type: "ForeignKey".to_string(),
+                                constraint_name: element.keyword_string("name"),
+                            });
+                        }
+                        _ => {}
+                    }
+                }
+                for column in columns.iter_mut() {
+                    if primary_key.contains(&column.name) {
+                        column.nullable = false;
+                    }
+                }
+                changes.push(SchemaChange::CreateTable { table, columns, primary_key, foreign_keys });
+            }
+            "drop_table" => changes.push(SchemaChange::DropTable(table_at(0))),
+            "rename_table" => changes.push(SchemaChange::RenameTable { table: table_at(0), to: call.string(1).unwrap_or_default() }),
+            "add_column" => {
+                let table = table_at(0);
+                let Some((column, is_primary_key, reference)) = call
+                    .positional
+                    .get(1)
+                    .and_then(|argument| PythonCall::parse(&argument[argument.find('(')?..]))
+                    .and_then(|element| alembic_column(&table, &element))
+                else {
+                    continue;
+                };
+                let name = column.name.clone();
+                changes.push(SchemaChange::AddColumn { table: table.clone(), column });
+                if is_primary_key {
+                    changes.push(SchemaChange::AddPrimaryKey { table, columns: vec![name] });
+                }
+                changes.extend(reference.map(SchemaChange::AddForeignKey));
+            }
+            "drop_column" => changes.push(SchemaChange::DropColumn { table: table_at(0), column: call.string(1).unwrap_or_default() }),
+            "alter_column" => {
+                let table = table_at(0);
+                let column = call.string(1).unwrap_or_default();
+                changes.push(SchemaChange::AlterColumn {
+                    table: table.clone(),
+                    column: column.clone(),
+                    data_type: call.keywords.get("type_").map(|data_type| python_type(data_type)),
+                    nullable: call.flag("nullable"),
+                    default_value: call
+                        .keywords
+                        .get("server_default")
+                        .map(|default| (default != "None").then(|| python_string(default).unwrap_or_else(|| default.clone()))),
+                });
+                if let Some(to) = call.keyword_string("new_column_name") {
+                    changes.push(SchemaChange::RenameColumn { table, from: column, to });
+                }
+            }
+            "create_primary_key" => changes.push(SchemaChange::AddPrimaryKey {
+                table: table_at(1),
+                columns: call.positional.get(2).map(|list| python_list(list)).unwrap_or_default(),
+            }),
+            "create_foreign_key" => changes.push(SchemaChange::AddForeignKey(TableRelationship {
+                from_table: TableRef { schema: call.keyword_string("source_schema"), name: call.string(1).unwrap_or_default() }.label(),
+                from_columns: call.positional.get(3).map(|list| python_list(list)).unwrap_or_default(),
+                to_table: TableRef { schema: call.keyword_string("referent_schema"), name: call.string(2).unwrap_or_default() }.label(),
+                to_columns: call.positional.get(4).map(|list| python_list(list)).unwrap_or_default(),
+                relationship_type: "ForeignKey".to_string(),
+                constraint_name: call.string(0),
+            })),
+            "execute" => {
+                if let Some(statement) = call.string(0) {
+                    changes.extend(sql::schema_changes(&statement));
+                }
+            }
+            _ => {}
+        }
+    }
+    changes
+}
+
+/// Column of an Alembic `sa.Column(...)`: the column, whether it is the primary key, and the
+/// foreign key of its `sa.ForeignKey(...)`
+fn alembic_column(table: &TableRef, column: &PythonCall) -> Option<(TableColumn, bool, Option<TableRelationship>)> {
+    let name = column.string(0)?;
+    let data_type = column
+        .positional
+        .get(1)
+        .filter(|argument| !argument.contains("ForeignKey("))
+        .map(|argument| python_type(argument))
+        .unwrap_or_default();
+    let is_primary_key = column.flag("primary_key") == Some(true);
+    let reference = column
+        .positional
+        .iter()
+        .filter_map(|argument| {
+            let open = argument.find("ForeignKey(")? + "ForeignKey".len();
+            let target = PythonCall::parse(&argument[open..])?.string(0)?;
+            let (to_table, to_column) = target.rsplit_once('.')?;
+            Some(TableRelationship {
+                from_table: table.label(),
+                from_columns: vec![name.clone()],
+                to_table: to_table.to_string(),
+                to_columns: vec![to_column.to_string()],
+                relationship_type: "ForeignKey".to_string(),
+                constraint_name: None,
+            })
+        })
+        .next();
+    let default_value = column
+        .keywords
+        .get("server_default")
+        .map(|default| python_string(default).unwrap_or_else(|| default.clone()));
+    let column = TableColumn {
+        nullable: column.flag("nullable").unwrap_or(!is_primary_key),
+        is_identity: column.flag("autoincrement").unwrap_or(is_primary_key && data_type.contains("Integer")),
+        name,
+        data_type,
+        default_value,
+    };
+    Some((column, is_primary_key, reference))
+}
+
+/// SQLAlchemy type of an expression such as `sa.String(length=255)`, without its module
+pub(crate) fn python_type(expression: &str) -> String {
+    let expression = expression.trim();
+    let name_end = expression.find('(').unwrap_or(expression.len());
+    let start = expression[..name_end].rfind('.').map_or(0, |dot| dot + 1);
+    expression[start..].trim_end_matches("()").to_string()
+}
+
+/// Value of a Python string literal, also when wrapped in `sa.text(...)`
+pub(crate) fn python_string(argument: &str) -> Option<String> {
+    let argument = argument.trim();
+    if let Some(open) = argument.find("text(")
+        && !argument.starts_with(['\'', '"'])
+    {
+        return python_string(python_balanced(&argument[open + 4..])?.trim_start_matches('(').trim_end_matches(')'));
+    }
+    let literal = argument.trim_start_matches(['r', 'b', 'u', 'f', 'R', 'B', 'U', 'F']);
+    for quote in ["\"\"\"", "'''", "\"", "'"] {
+        if let Some(inner) = literal.strip_prefix(quote).and_then(|rest| rest.strip_suffix(quote)) {
+            return Some(inner.to_string());
+        }
+    }
+    None
+}
+
+/// Strings of a Python list or tuple literal
+pub(crate) fn python_list(argument: &str) -> Vec<String> {
+    let inner = argument.trim().trim_start_matches(['[', '(']).trim_end_matches([']', ')']);
+    python_split(inner).iter().filter_map(|item| python_string(item)).collect()
+}
+
+/// The text from its opening bracket to the matching closing one, skipping Python strings
+pub(crate) fn python_balanced(text: &str) -> Option<&str> {
+    let mut depth = 0;
+    let mut quote: Option<&str> = None;
+    let mut at = 0;
+    while at < text.len() {
+        let rest = &text[at..];
+        if let Some(closing) = quote {
+            if rest.starts_with('\\') {
+                at += 2.min(rest.len());
+                continue;
+            }
+            if rest.starts_with(closing) {
+                quote = None;
+                at += closing.len();
+                continue;
+            }
+        } else if let Some(opening) = ["\"\"\"", "'''", "\"", "'"].into_iter().find(|quote| rest.starts_with(*quote)) {
+            quote = Some(opening);
+            at += opening.len();
+            continue;
+        } else if rest.starts_with(['(', '[', '{']) {
+            depth += 1;
+        } else if rest.starts_with([')', ']', '}']) {
+            depth -= 1;
+            if depth == 0 {
+                return Some(&text[..=at]);
+            }
+        }
+        at += rest.chars().next().map_or(1, char::len_utf8);
+    }
+    None
+}
+
+/// Arguments of a Python call, split on the commas outside of brackets and strings
+fn python_split(text: &str) -> Vec<String> {
+    let mut arguments = Vec::new();
+    let mut rest = text;
+    loop {
+        let mut at = 0;
+        let mut end = None;
+        while at < rest.len() {
+            let tail = &rest[at..];
+            if tail.starts_with(['\'', '"']) {
+                at += string_prefix(tail).len().max(1);
+                continue;
+            }
+            if tail.starts_with(['(', '[', '{']) {
+                at += python_balanced(tail).map_or(tail.len(), str::len);
+                continue;
+            }
+            if tail.starts_with(',') {
+                end = Some(at);
+                break;
+            }
+            at += tail.chars().next().map_or(1, char::len_utf8);
+        }
+        let argument = rest[..end.unwrap_or(rest.len())].trim();
+        if !argument.is_empty() {
+            arguments.push(argument.to_string());
+        }
+        match end {
+            Some(end) => rest = &rest[end + 1..],
+            None => break,
+        }
+    }
+    arguments
+}
+
+/// The Python string literal at the start of the text
+fn string_prefix(text: &str) -> &str {
+    let Some(quote) = ["\"\"\"", "'''", "\"", "'"].into_iter().find(|quote| text.starts_with(*quote)) else {
+        return "";
+    };
+    let mut at = quote.len();
+    while at < text.len() {
+        let rest = &text[at..];
+        if rest.starts_with('\\') {
+            at += 2.min(rest.len());
+        } else if rest.starts_with(quote) {
+            return &text[..at + quote.len()];
+        } else {
+            at += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(files: &[(&str, &str)]) -> MigratedSchema {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        let infos: Vec<FileInfo> = files
+            .iter()
+            .map(|(path, content)| {
+                let full = root.join(path);
+                fs::create_dir_all(full.parent().unwrap()).unwrap();
+                fs::write(&full, content).unwrap();
+                FileInfo {
+                    path: PathBuf::from(path),
+                    name: String::new(),
+                    size: 0,
+                    extension: None,
+                    is_core: false,
+                    importance_score: 0.0,
+                    complexity_score: 0.0,
+                    last_modified: None,
+                }
+            })
+            .collect();
+        collect(&infos, &root)
+    }
+
+    fn columns(schema: &MigratedSchema, table: &str) -> Vec<String> {
+        let table = schema.tables.iter().find(|candidate| candidate.name == table).unwrap();
+        table.columns.iter().map(|column| format!("{} {}", column.name, column.data_type)).collect()
+    }
+
+    #[test]
+    fn test_collect_migrations() {
+        // Flyway versions compare numerically, repeatable migrations come last
+        let flyway = project(&[
+            ("db/migration/V10__rename.sql", "ALTER TABLE users RENAME COLUMN name TO full_name;"),
+            ("db/migration/R__views.sql", "CREATE OR REPLACE VIEW active_users AS SELECT * FROM users;"),
+            ("db/migration/V2__widen.sql", "ALTER TABLE users ALTER COLUMN name TYPE VARCHAR(100);"),
+            ("db/migration/V1__init.sql", "CREATE TABLE users (id BIGSERIAL PRIMARY KEY, name TEXT);\nCREATE TABLE audit (id INT);\nDROP TABLE audit;"),
+        ]);
+        assert_eq!(flyway.tools(), [MigrationTool::Flyway]);
+        assert_eq!(columns(&flyway, "users"), ["id BIGSERIAL", "full_name VARCHAR(100)"]);
+        assert_eq!(flyway.views[0].referenced_tables, ["users"]);
+        assert_eq!(flyway.dropped_tables, ["audit"]);
+        assert_eq!(flyway.migrations[3].version, None);
+
+        // Liquibase changelogs apply in include order; rollbacks are ignored
+        let liquibase = project(&[
+            (
+                "src/main/resources/db/changelog/db.changelog-master.xml",
+                r#"<databaseChangeLog><include file="changes/b-create.xml" relativeToChangelogFile="true"/><include file="changes/a-alter.xml" relativeToChangelogFile="true"/></databaseChangeLog>"#,
+            ),
+            (
+                "src/main/resources/db/changelog/changes/b-create.xml",
+                r#"<databaseChangeLog>
+  <changeSet id="1" author="dev">
+    <createTable tableName="customers">
+      <column name="id" type="BIGINT" autoIncrement="true"><constraints primaryKey="true"/></column>
+      <column name="email" type="VARCHAR(255)"><constraints nullable="false"/></column>
+    </createTable>
+    <createTable tableName="orders">
+      <column name="id" type="BIGINT"><constraints primaryKey="true"/></column>
+      <column name="customer_id" type="BIGINT"><constraints references="customers(id)" foreignKeyName="fk_orders_customer"/></column>
+    </createTable>
+    <rollback><dropTable tableName="orders"/></rollback>
+  </changeSet>
+</databaseChangeLog>"#,
+            ),
+            (
+                "src/main/resources/db/changelog/changes/a-alter.xml",
+                r#"<databaseChangeLog>
+  <changeSet id="2" author="dev">
+    <addColumn tableName="orders"><column name="total" type="DECIMAL(10,2)" defaultValueNumeric="0"/></addColumn>
+    <renameTable oldTableName="customers" newTableName="clients"/>
+    <sql><![CDATA[CREATE INDEX idx_total ON orders (total); ALTER TABLE orders ADD status TEXT;]]></sql>
+  </changeSet>
+</databaseChangeLog>"#,
+            ),
+        ]);
+        assert_eq!(columns(&liquibase, "orders"), ["id BIGINT", "customer_id BIGINT", "total DECIMAL(10,2)", "status TEXT"]);
+        assert_eq!(columns(&liquibase, "clients"), ["id BIGINT", "email VARCHAR(255)"]);
+        let relationship = &liquibase.relationships[0];
+        assert_eq!((relationship.to_table.as_str(), relationship.constraint_name.as_deref()), ("clients", Some("fk_orders_customer")));
+
+        // Diesel up migrations in directory order; down migrations are skipped
+        let diesel = project(&[
+            ("migrations/2024-02-01-000000_add_body/up.sql", "ALTER TABLE posts ADD COLUMN body TEXT NOT NULL;"),
+            ("migrations/2024-02-01-000000_add_body/down.sql", "ALTER TABLE posts DROP COLUMN body;"),
+            ("migrations/2024-01-01-000000_create_posts/up.sql", "CREATE TABLE posts (id SERIAL PRIMARY KEY, title VARCHAR NOT NULL);"),
+            ("migrations/2024-01-01-000000_create_posts/down.sql", "DROP TABLE posts;"),
+        ]);
+        assert_eq!(columns(&diesel, "posts"), ["id SERIAL", "title VARCHAR", "body TEXT"]);
+        assert_eq!(diesel.migrations.len(), 2);
+
+        // Alembic revisions follow the down_revision chain, not the file names
+        let alembic = project(&[
+            (
+                "alembic/versions/a_second.py",
+                "revision = 'b2'\ndown_revision = 'a1'\n\ndef upgrade():\n    with op.batch_alter_table('account') as batch_op:\n        batch_op.add_column(sa.Column('email', sa.String(length=255), nullable=False))\n    op.alter_column('account', 'name', new_column_name='display_name')\n\ndef downgrade():\n    op.drop_table('account')\n",
+            ),
+            (
+                "alembic/versions/z_first.py",
+                "revision = 'a1'\ndown_revision = None\n\ndef upgrade():\n    op.create_table(\n        'account',\n        sa.Column('id', sa.Integer(), nullable=False),\n        sa.Column('name', sa.String(50)),  # shown name\n        sa.Column('owner_id', sa.Integer(), sa.ForeignKey('user.id')),\n        sa.PrimaryKeyConstraint('id'),\n    )\n    op.execute(\"CREATE VIEW named AS SELECT name FROM account\")\n",
+            ),
+        ]);
+        assert_eq!(columns(&alembic, "account"), ["id Integer", "display_name String(50)", "owner_id Integer", "email String(length=255)"]);
+        assert_eq!(alembic.tables[0].primary_key, ["id"]);
+        assert_eq!(alembic.relationships[0].to_table, "user");
+        assert_eq!(alembic.views.len(), 1);
+
+        // Numbered SQL migrations; the down part of dbmate files is left out
+        let numbered = project(&[
+            ("db/migrations/20240102_add_price.sql", "-- migrate:up\nALTER TABLE `items` ADD `price` DECIMAL(8, 2);\n-- migrate:down\nALTER TABLE `items` DROP `price`;\n"),
+            ("db/migrations/20240101_init.sql", "-- migrate:up\nCREATE TABLE `items` (`id` INT AUTO_INCREMENT PRIMARY KEY);\n-- migrate:down\nDROP TABLE `items`;\n"),
+        ]);
+        assert_eq!(columns(&numbered, "items"), ["id INT", "price DECIMAL(8, 2)"]);
+        assert!(numbered.tables[0].columns[0].is_identity);
+    }
+}
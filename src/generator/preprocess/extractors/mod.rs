@@ -1,3 +1,17 @@
+pub mod call_graph;
+pub mod dependency_graph;
+pub mod dependency_inventory;
+pub mod diagram_assets;
+pub mod entry_point_detector;
+pub mod framework_detector;
+pub mod git_history;
 pub mod language_processors;
+pub mod language_stats;
+pub mod license_detector;
+pub mod migration_schema;
+pub mod orm_models;
 pub mod structure_extractor;
+pub mod orphan_detector;
+pub mod ownership_extractor;
 pub mod original_document_extractor;
+pub mod workspace_extractor;
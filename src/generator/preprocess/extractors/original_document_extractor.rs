@@ -2,10 +2,17 @@ use anyhow::Result;
 use tokio::fs::read_to_string;
 use crate::generator::context::GeneratorContext;
 use crate::types::original_document::OriginalDocument;
+use crate::utils::privacy::{PathAccess, PrivacyPolicy};
+use crate::utils::secret_redactor;
 
 pub async fn extract(context: &GeneratorContext) -> Result<OriginalDocument> {
-    let readme = match read_to_string(context.config.project_path.join("README.md")).await {
+    let readme_path = context.config.project_path.join("README.md");
+    if PrivacyPolicy::new(&context.config.privacy).access(&context.config.project_path, &readme_path) != PathAccess::Full {
+        return Ok(OriginalDocument { readme: None });
+    }
+    let readme = match read_to_string(readme_path).await {
         Ok(content) => {
+            let content = secret_redactor::redact(&context.config.redaction, &context.redactions, "README.md", &content);
             let trimmed_content = trim_markdown(&content);
             Some(trimmed_content)
         },
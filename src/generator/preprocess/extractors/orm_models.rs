@@ -0,0 +1,874 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::generator::preprocess::extractors::language_processors::LanguageProcessorManager;
+use crate::generator::preprocess::extractors::migration_schema::{PythonCall, python_balanced, python_list, python_string, python_type};
+use crate::generator::research::types::{DatabaseTable, TableColumn, TableRelationship};
+use crate::types::FileInfo;
+use crate::types::code::InterfaceInfo;
+use crate::utils::encoding;
+use crate::utils::paths::to_slash;
+use crate::utils::threads::par_map;
+
+static PRISMA_BLOCK_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?ms)^\s*(model|view|enum|type)\s+(\w+)\s*\{(.*?)^\s*\}").unwrap());
+static PRISMA_MAP_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"@map\(\s*(?:name:\s*)?"([^"]+)"\s*\)"#).unwrap());
+static PRISMA_RELATION_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"@relation\([^)]*?fields:\s*\[([^\]]*)\][^)]*?references:\s*\[([^\]]*)\]").unwrap());
+static DIESEL_HEADER_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?:(\w+)\.)?(\w+)\s*(?:\(([^)]*)\))?\s*\{").unwrap());
+static DIESEL_COLUMN_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^\s*(\w+)\s*->\s*([^,\n]+?)\s*,?\s*$").unwrap());
+static DIESEL_JOINABLE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"joinable!\s*\(\s*(\w+)\s*->\s*(\w+)\s*\(\s*(\w+)\s*\)\s*\)").unwrap());
+/// `#[diesel(table_name = posts)]` or the older `#[table_name = "posts"]` on a struct
+static DIESEL_STRUCT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"#\[(?:diesel\(\s*table_name\s*=\s*(?:\w+::)*(\w+)[^\]]*\)|table_name\s*=\s*"(\w+)")\]\s*(?:#\[[^\]]*\]\s*)*(?:pub(?:\([^)]*\))?\s+)?struct\s+(\w+)"#).unwrap()
+});
+static PYTHON_CLASS_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^class\s+(\w+)\s*\(([^)]*)\)\s*:").unwrap());
+static SQLALCHEMY_TABLE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?m)^\s+__tablename__\s*=\s*['"](\w+)['"]"#).unwrap());
+static SQLALCHEMY_SCHEMA_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"['"]schema['"]\s*:\s*['"](\w+)['"]"#).unwrap());
+static SQLALCHEMY_COLUMN_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^[ \t]+(\w+)\s*(?::\s*([^=\n]+?))?\s*=\s*(?:\w+\.)?(?:Column|mapped_column)\(").unwrap());
+/// SQLAlchemy 2.0 columns declared by their annotation alone
+static SQLALCHEMY_ANNOTATION_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^[ \t]+(\w+)\s*:\s*(Mapped\[.+\])\s*$").unwrap());
+static QUOTED_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#""([^"]*)""#).unwrap());
+static CSHARP_CLASS_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*(?:(?:public|internal|protected|private|sealed|abstract|partial|static)\s+)*(?:class|record)\s+(\w+)").unwrap()
+});
+static CSHARP_PROPERTY_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*public\s+(?:(?:virtual|required|override|new)\s+)*([\w.<>\[\],?]+(?:\s*,\s*[\w.<>\[\]?]+)*)\s+(\w+)\s*\{\s*(?:get|init)").unwrap()
+});
+static CSHARP_DBSET_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"DbSet<(\w+)>\s+(\w+)").unwrap());
+static CSHARP_ENTITY_CONFIG_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:\bEntity|IEntityTypeConfiguration)<(\w+)>").unwrap());
+static CSHARP_TO_TABLE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"ToTable\(\s*"(\w+)"(?:\s*,\s*"(\w+)")?"#).unwrap());
+
+/// C# value types, never null unless declared with `?`
+const CSHARP_VALUE_TYPES: [&str; 17] = [
+    "int", "long", "short", "byte", "bool", "decimal", "double", "float", "char", "uint", "ulong", "Guid",
+    "DateTime", "DateTimeOffset", "TimeSpan", "DateOnly", "TimeOnly",
+];
+const CSHARP_COLLECTIONS: [&str; 7] = ["ICollection<", "IList<", "List<", "IEnumerable<", "HashSet<", "ISet<", "IReadOnlyCollection<"];
+
+/// ORM whose models map tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Orm {
+    EntityFramework,
+    Hibernate,
+    SqlAlchemy,
+    Diesel,
+    Prisma,
+}
+
+impl Orm {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Orm::EntityFramework => "Entity Framework",
+            Orm::Hibernate => "Hibernate",
+            Orm::SqlAlchemy => "SQLAlchemy",
+            Orm::Diesel => "Diesel",
+            Orm::Prisma => "Prisma",
+        }
+    }
+}
+
+/// Class, struct or model an ORM maps to a table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrmEntity {
+    pub orm: Orm,
+    /// Name of the class, struct or model
+    pub entity: String,
+    /// Relative to the project root
+    #[serde(serialize_with = "crate::utils::paths::serialize_slash")]
+    pub path: PathBuf,
+    pub table: DatabaseTable,
+}
+
+/// Tables and foreign keys the ORM models of the project declare
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrmModels {
+    pub entities: Vec<OrmEntity>,
+    pub relationships: Vec<TableRelationship>,
+}
+
+impl OrmModels {
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// ORMs with at least one entity
+    pub fn orms(&self) -> Vec<Orm> {
+        let mut orms: Vec<Orm> = self.entities.iter().map(|entity| entity.orm).collect();
+        orms.sort();
+        orms.dedup();
+        orms
+    }
+}
+
+/// Entity Framework class, chosen as an entity once the `DbSet`s of all files are known
+struct CSharpClass {
+    name: String,
+    path: PathBuf,
+    attributes: Vec<String>,
+    properties: Vec<CSharpProperty>,
+}
+
+struct CSharpProperty {
+    name: String,
+    property_type: String,
+    attributes: Vec<String>,
+}
+
+/// Models of one file; Entity Framework and Diesel declarations are joined across files
+#[derive(Default)]
+struct FileModels {
+    entities: Vec<OrmEntity>,
+    /// Targets name the entity until resolved to its table
+    relationships: Vec<TableRelationship>,
+    classes: Vec<CSharpClass>,
+    /// `DbSet<Entity> Property` declarations
+    db_sets: Vec<(String, String)>,
+    /// Fluent `ToTable` mappings: entity, table, schema
+    table_mappings: Vec<(String, String, Option<String>)>,
+    /// Diesel tables and the struct naming each of them
+    diesel_structs: Vec<(String, String)>,
+}
+
+/// Detect the entities of Entity Framework, Hibernate/JPA, SQLAlchemy, Diesel and Prisma
+/// models, with the tables they map and the foreign keys between them
+pub fn detect(files: &[FileInfo], root: &Path, max_bytes: usize) -> OrmModels {
+    let candidates: Vec<&FileInfo> = files
+        .iter()
+        .filter(|file| {
+            matches!(file.path.extension().and_then(|extension| extension.to_str()), Some("cs" | "java" | "py" | "rs" | "prisma"))
+                && file.size as usize <= max_bytes
+        })
+        .collect();
+    let processors = LanguageProcessorManager::new();
+    let parsed = par_map(&candidates, |file| {
+        let bytes = fs::read(root.join(&file.path)).ok()?;
+        let (content, _) = encoding::decode(&bytes);
+        Some(parse_file(&file.path, &content, &processors))
+    });
+    assemble(parsed.into_iter().flatten().collect())
+}
+
+fn parse_file(path: &Path, content: &str, processors: &LanguageProcessorManager) -> FileModels {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("prisma") => parse_prisma(path, content),
+        Some("rs") if content.contains("table!") || content.contains("table_name") => parse_diesel(path, content),
+        Some("py") if content.contains("__tablename__") || content.contains("db.Model") => parse_sqlalchemy(path, content),
+        Some("java") if content.contains("@Entity") => parse_jpa(path, &processors.extract_interfaces(path, content)),
+        Some("cs") if content.contains("get;") || content.contains("ToTable(") => parse_csharp(path, content),
+        _ => FileModels::default(),
+    }
+}
+
+fn table_label(table: &DatabaseTable) -> String {
+    if table.schema.is_empty() { table.name.clone() } else { format!("{}.{}", table.schema, table.name) }
+}
+
+fn foreign_key(from_table: String, from_columns: Vec<String>, to_table: String, to_columns: Vec<String>) -> TableRelationship {
+    TableRelationship {
+        from_table,
+        from_columns,
+        to_table,
+        to_columns,
+        relationship_type: "ForeignKey".to_string(),
+        constraint_name: None,
+    }
+}
+
+/// Join the declarations spread over files and point foreign keys at the tables of the
+/// entities they name
+fn assemble(parsed: Vec<FileModels>) -> OrmModels {
+    let mut models = OrmModels::default();
+    let mut classes = Vec::new();
+    let mut db_sets = HashMap::new();
+    let mut table_mappings = HashMap::new();
+    let mut diesel_structs = HashMap::new();
+    for file in parsed {
+        models.entities.extend(file.entities);
+        models.relationships.extend(file.relationships);
+        classes.extend(file.classes);
+        db_sets.extend(file.db_sets);
+        table_mappings.extend(file.table_mappings.into_iter().map(|(entity, table, schema)| (entity, (table, schema))));
+        diesel_structs.extend(file.diesel_structs);
+    }
+    entity_framework_entities(&classes, &db_sets, &table_mappings, &mut models);
+    for entity in models.entities.iter_mut().filter(|entity| entity.orm == Orm::Diesel) {
+        if let Some(name) = diesel_structs.get(&entity.table.name) {
+            entity.entity = name.clone();
+        }
+    }
+
+    let table_names: HashSet<String> = models.entities.iter().map(|entity| entity.table.name.clone()).collect();
+    for relationship in models.relationships.iter_mut() {
+        if !table_names.contains(&relationship.to_table)
+            && let Some(target) = models.entities.iter().find(|entity| entity.entity == relationship.to_table)
+        {
+            relationship.to_table = table_label(&target.table);
+        }
+        let Some(target) = models.entities.iter().find(|entity| table_label(&entity.table) == relationship.to_table) else {
+            continue;
+        };
+        if relationship.to_columns.is_empty() {
+            relationship.to_columns = target.table.primary_key.clone();
+        }
+        // Foreign key columns of navigation properties take the type of the key they reference
+        let key_type = relationship
+            .to_columns
+            .first()
+            .and_then(|key| target.table.columns.iter().find(|column| &column.name == key))
+            .map(|column| column.data_type.clone());
+        if let Some(key_type) = key_type
+            && let Some(source) = models.entities.iter_mut().find(|entity| table_label(&entity.table) == relationship.from_table)
+        {
+            for column in source.table.columns.iter_mut() {
+                if column.data_type.is_empty() && relationship.from_columns.contains(&column.name) {
+                    column.data_type = key_type.clone();
+                }
+            }
+        }
+    }
+    models
+}
+
+fn parse_prisma(path: &Path, content: &str) -> FileModels {
+    let mut models = FileModels::default();
+    let blocks: Vec<_> = PRISMA_BLOCK_REGEX.captures_iter(content).collect();
+    let model_names: HashSet<&str> = blocks
+        .iter()
+        .filter(|block| matches!(&block[1], "model" | "view"))
+        .filter_map(|block| block.get(2).map(|name| name.as_str()))
+        .collect();
+
+    for block in blocks.iter().filter(|block| matches!(&block[1], "model" | "view")) {
+        let body = &block[3];
+        let mut table = DatabaseTable {
+            name: block[2].to_string(),
+            source_path: to_slash(path),
+            ..Default::default()
+        };
+        for line in body.lines().map(|line| line.split("//").next().unwrap_or_default().trim()) {
+            if let Some(attribute) = line.strip_prefix("@@") {
+                let first_string = QUOTED_REGEX.captures(attribute).map(|string| string[1].to_string());
+                if attribute.starts_with("map(") {
+                    table.name = first_string.unwrap_or(table.name);
+                } else if attribute.starts_with("schema(") {
+                    table.schema = first_string.unwrap_or_default();
+                } else if attribute.starts_with("id(") {
+                    let list = attribute.find('[').and_then(|open| python_balanced(&attribute[open..]));
+                    table.primary_key = list.map(|list| split_names(&list[1..list.len() - 1])).unwrap_or_default();
+                }
+            }
+        }
+        let label = table_label(&table);
+
+        for line in body.lines().map(|line| line.split("//").next().unwrap_or_default().trim()) {
+            let mut words = line.split_whitespace();
+            let (Some(name), Some(field_type)) = (words.next(), words.next()) else {
+                continue;
+            };
+            if name.starts_with('@') || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                continue;
+            }
+            let attributes = &words.collect::<Vec<_>>().join(" ");
+            let base = field_type.trim_end_matches('?').trim_end_matches("[]");
+            if model_names.contains(base) {
+                if let Some(relation) = PRISMA_RELATION_REGEX.captures(attributes) {
+                    models.relationships.push(foreign_key(label.clone(), split_names(&relation[1]), base.to_string(), split_names(&relation[2])));
+                }
+                continue;
+            }
+            let column_name = PRISMA_MAP_REGEX.captures(attributes).map_or(name.to_string(), |map| map[1].to_string());
+            let default_value = attributes
+                .find("@default(")
+                .and_then(|at| python_balanced(&attributes[at + "@default".len()..]))
+                .map(|value| value[1..value.len() - 1].to_string());
+            if attributes.split_whitespace().any(|attribute| attribute == "@id" || attribute.starts_with("@id(")) {
+                table.primary_key.push(column_name.clone());
+            }
+            table.columns.push(TableColumn {
+                is_identity: default_value.as_deref().is_some_and(|value| value == "autoincrement()"),
+                nullable: field_type.ends_with('?') && !table.primary_key.contains(&column_name),
+                name: column_name,
+                data_type: field_type.trim_end_matches('?').to_string(),
+                default_value,
+            });
+        }
+        models.entities.push(OrmEntity {
+            orm: Orm::Prisma,
+            entity: block[2].to_string(),
+            path: path.to_path_buf(),
+            table,
+        });
+    }
+    models
+}
+
+fn parse_diesel(path: &Path, content: &str) -> FileModels {
+    let mut models = FileModels::default();
+    for captures in DIESEL_STRUCT_REGEX.captures_iter(content) {
+        if let Some(table) = captures.get(1).or(captures.get(2)) {
+            models.diesel_structs.push((table.as_str().to_string(), captures[3].to_string()));
+        }
+    }
+
+    let mut at = 0;
+    while let Some(found) = content[at..].find("table!") {
+        let start = at + found;
+        at = start + "table!".len();
+        let Some(open) = content[at..].find(['{', '(']).map(|open| at + open) else {
+            break;
+        };
+        let Some(invocation) = python_balanced(&content[open..]) else {
+            continue;
+        };
+        at = open + invocation.len();
+        let declaration: String = invocation[1..invocation.len() - 1]
+            .lines()
+            .map(str::trim)
+            .filter(|line| !(line.starts_with("use ") || line.starts_with("//") || line.starts_with("#[")))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let Some(header) = DIESEL_HEADER_REGEX.captures(declaration.trim_start()) else {
+            continue;
+        };
+        let body_start = declaration.len() - declaration.trim_start().len() + header.get(0).map_or(0, |header| header.end() - 1);
+        let body = python_balanced(&declaration[body_start..]).unwrap_or_default();
+        let mut table = DatabaseTable {
+            schema: header.get(1).map(|schema| schema.as_str().to_string()).unwrap_or_default(),
+            name: header[2].to_string(),
+            primary_key: header.get(3).map_or_else(|| vec!["id".to_string()], |keys| split_names(keys.as_str())),
+            source_path: to_slash(path),
+            ..Default::default()
+        };
+        for column in DIESEL_COLUMN_REGEX.captures_iter(body) {
+            let column_type = column[2].trim();
+            let (data_type, nullable) = match column_type.strip_prefix("Nullable<").and_then(|inner| inner.strip_suffix('>')) {
+                Some(inner) => (inner.to_string(), true),
+                None => (column_type.to_string(), false),
+            };
+            table.columns.push(TableColumn {
+                name: column[1].to_string(),
+                data_type,
+                nullable,
+                ..Default::default()
+            });
+        }
+        models.entities.push(OrmEntity {
+            orm: Orm::Diesel,
+            entity: table.name.clone(),
+            path: path.to_path_buf(),
+            table,
+        });
+    }
+
+    for joinable in DIESEL_JOINABLE_REGEX.captures_iter(content) {
+        models
+            .relationships
+            .push(foreign_key(joinable[1].to_string(), vec![joinable[3].to_string()], joinable[2].to_string(), Vec::new()));
+    }
+    models
+}
+
+fn parse_sqlalchemy(path: &Path, content: &str) -> FileModels {
+    let mut models = FileModels::default();
+    for class in PYTHON_CLASS_REGEX.captures_iter(content) {
+        let start = class.get(0).map_or(0, |class| class.end());
+        // The body runs to the next line starting at the first column
+        let end = content[start..]
+            .match_indices('\n')
+            .map(|(offset, _)| start + offset + 1)
+            .find(|line| content[*line..].starts_with(|c: char| !c.is_whitespace()))
+            .unwrap_or(content.len());
+        let body = &content[start..end];
+        let name = &class[1];
+        let table_name = match SQLALCHEMY_TABLE_REGEX.captures(body) {
+            Some(table) => table[1].to_string(),
+            // Flask-SQLAlchemy names the table of a `db.Model` after the class
+            None if class[2].contains("db.Model") => snake_case(name),
+            None => continue,
+        };
+        let mut table = DatabaseTable {
+            schema: SQLALCHEMY_SCHEMA_REGEX.captures(body).map(|schema| schema[1].to_string()).unwrap_or_default(),
+            name: table_name,
+            source_path: to_slash(path),
+            ..Default::default()
+        };
+        let label = table_label(&table);
+
+        let mut columns: Vec<(usize, TableColumn)> = Vec::new();
+        for captures in SQLALCHEMY_COLUMN_REGEX.captures_iter(body) {
+            let whole = captures.get(0).map_or(0..0, |column| column.range());
+            let Some(call) = PythonCall::parse(&body[whole.end - 1..]) else {
+                continue;
+            };
+            let mut arguments = call.positional.iter().peekable();
+            // `Column("user_name", String)` names the column apart from the attribute
+            let column_name = match arguments.peek().and_then(|argument| python_string(argument)) {
+                Some(column_name) => {
+                    arguments.next();
+                    column_name
+                }
+                None => captures[1].to_string(),
+            };
+            let arguments: Vec<&String> = arguments.collect();
+            let annotation = captures.get(2).map(|annotation| annotation.as_str().trim());
+            let data_type = arguments
+                .iter()
+                .find(|argument| !["ForeignKey(", "Identity(", "Sequence(", "Computed("].iter().any(|marker| argument.contains(marker)))
+                .map(|argument| python_type(argument))
+                .or_else(|| annotation.map(mapped_type))
+                .unwrap_or_default();
+            let is_primary_key = call.flag("primary_key") == Some(true);
+            if is_primary_key {
+                table.primary_key.push(column_name.clone());
+            }
+            for argument in arguments.iter().filter(|argument| argument.contains("ForeignKey(")) {
+                let open = argument.find("ForeignKey(").unwrap_or_default() + "ForeignKey".len();
+                if let Some(target) = PythonCall::parse(&argument[open..]).and_then(|foreign| foreign.string(0))
+                    && let Some((to_table, to_column)) = target.rsplit_once('.')
+                {
+                    models
+                        .relationships
+                        .push(foreign_key(label.clone(), vec![column_name.clone()], to_table.to_string(), vec![to_column.to_string()]));
+                }
+            }
+            let nullable = call.flag("nullable").unwrap_or_else(|| annotation.is_none_or(is_optional_annotation));
+            columns.push((
+                whole.start,
+                TableColumn {
+                    nullable: nullable && !is_primary_key,
+                    is_identity: call.flag("autoincrement").unwrap_or(is_primary_key && (data_type.contains("Integer") || data_type == "int")),
+                    default_value: call.keyword_string("server_default").or_else(|| call.keywords.get("default").cloned()),
+                    name: column_name,
+                    data_type,
+                },
+            ));
+        }
+        for captures in SQLALCHEMY_ANNOTATION_REGEX.captures_iter(body) {
+            columns.push((
+                captures.get(0).map_or(0, |column| column.start()),
+                TableColumn {
+                    name: captures[1].to_string(),
+                    data_type: mapped_type(&captures[2]),
+                    nullable: is_optional_annotation(&captures[2]),
+                    ..Default::default()
+                },
+            ));
+        }
+        columns.sort_by_key(|(position, _)| *position);
+        table.columns = columns.into_iter().map(|(_, column)| column).collect();
+        models.entities.push(OrmEntity {
+            orm: Orm::SqlAlchemy,
+            entity: name.to_string(),
+            path: path.to_path_buf(),
+            table,
+        });
+    }
+    models
+}
+
+/// Python type of a `Mapped[...]` annotation, without `Optional`
+fn mapped_type(annotation: &str) -> String {
+    let inner = annotation.trim().strip_prefix("Mapped[").and_then(|inner| inner.strip_suffix(']')).unwrap_or(annotation);
+    let inner = inner.strip_prefix("Optional[").and_then(|inner| inner.strip_suffix(']')).unwrap_or(inner);
+    inner.split('|').map(str::trim).find(|part| *part != "None").unwrap_or(inner).trim_matches(['"', '\'']).to_string()
+}
+
+fn is_optional_annotation(annotation: &str) -> bool {
+    annotation.contains("Optional[") || annotation.contains("None")
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (index, c) in name.chars().enumerate() {
+        if c.is_uppercase() && index > 0 {
+            snake.push('_');
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+/// Value of an argument of an annotation, as in `@Table(name = "orders")`
+fn annotation_argument(annotations: &str, annotation: &str, argument: &str) -> Option<String> {
+    let at = annotations.find(&format!("@{}(", annotation))? + annotation.len() + 1;
+    let arguments = python_balanced(&annotations[at..])?;
+    let pattern = Regex::new(&format!(r#"\b{}\s*=\s*"([^"]*)""#, argument)).ok()?;
+    pattern.captures(arguments).map(|value| value[1].to_string())
+}
+
+/// Hibernate/JPA `@Entity` classes, from the entities the Java processor extracts
+fn parse_jpa(path: &Path, interfaces: &[InterfaceInfo]) -> FileModels {
+    let mut models = FileModels::default();
+    for interface in interfaces.iter().filter(|interface| interface.interface_type == "entity") {
+        let annotations = interface.description.as_deref().unwrap_or_default();
+        if !annotations.contains("@Entity") {
+            continue;
+        }
+        let mut table = DatabaseTable {
+            schema: annotation_argument(annotations, "Table", "schema").unwrap_or_default(),
+            name: annotation_argument(annotations, "Table", "name").unwrap_or_else(|| interface.name.clone()),
+            source_path: to_slash(path),
+            ..Default::default()
+        };
+        let label = table_label(&table);
+
+        for field in &interface.parameters {
+            let annotations = field.description.as_deref().unwrap_or_default();
+            let has = |name: &str| annotations.split_whitespace().any(|annotation| annotation == format!("@{}", name) || annotation.starts_with(&format!("@{}(", name)));
+            if has("Transient") || has("OneToMany") || has("ManyToMany") || annotations.contains("mappedBy") {
+                continue;
+            }
+            let is_reference = has("ManyToOne") || has("OneToOne");
+            let name = annotation_argument(annotations, "Column", "name")
+                .or_else(|| annotation_argument(annotations, "JoinColumn", "name"))
+                .unwrap_or_else(|| if is_reference { format!("{}_id", field.name) } else { field.name.clone() });
+            if has("Id") {
+                table.primary_key.push(name.clone());
+            }
+            if is_reference {
+                let to_columns = annotation_argument(annotations, "JoinColumn", "referencedColumnName").into_iter().collect();
+                models.relationships.push(foreign_key(label.clone(), vec![name.clone()], field.param_type.clone(), to_columns));
+            }
+            table.columns.push(TableColumn {
+                nullable: field.is_optional && !has("Id"),
+                is_identity: has("GeneratedValue"),
+                // References take the type of the key they point to once resolved
+                data_type: if is_reference { String::new() } else { field.param_type.clone() },
+                default_value: None,
+                name,
+            });
+        }
+        models.entities.push(OrmEntity {
+            orm: Orm::Hibernate,
+            entity: interface.name.clone(),
+            path: path.to_path_buf(),
+            table,
+        });
+    }
+    models
+}
+
+/// Leading `[Attribute]` groups of a C# line, and the rest of it
+fn leading_attributes(line: &str) -> (Vec<String>, &str) {
+    let mut attributes = Vec::new();
+    let mut rest = line.trim_start();
+    while rest.starts_with('[') {
+        let Some(group) = python_balanced(rest) else {
+            break;
+        };
+        attributes.extend(split_top_level_commas(&group[1..group.len() - 1]));
+        rest = rest[group.len()..].trim_start();
+    }
+    (attributes, rest)
+}
+
+fn split_top_level_commas(text: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut current = String::new();
+    for c in text.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    parts.push(current.trim().to_string());
+    parts.into_iter().filter(|part| !part.is_empty()).collect()
+}
+
+fn parse_csharp(path: &Path, content: &str) -> FileModels {
+    let mut models = FileModels::default();
+    for captures in CSHARP_DBSET_REGEX.captures_iter(content) {
+        models.db_sets.push((captures[1].to_string(), captures[2].to_string()));
+    }
+    // Fluent configuration: `modelBuilder.Entity<Order>(...).ToTable("orders")` and
+    // `IEntityTypeConfiguration<Order>` classes
+    let configurations: Vec<_> = CSHARP_ENTITY_CONFIG_REGEX.captures_iter(content).collect();
+    for (index, configuration) in configurations.iter().enumerate() {
+        let start = configuration.get(0).map_or(0, |entity| entity.end());
+        let end = configurations.get(index + 1).and_then(|next| next.get(0)).map_or(content.len(), |next| next.start());
+        if let Some(table) = CSHARP_TO_TABLE_REGEX.captures(&content[start..end]) {
+            models.table_mappings.push((configuration[1].to_string(), table[1].to_string(), table.get(2).map(|schema| schema.as_str().to_string())));
+        }
+    }
+
+    let mut pending = Vec::new();
+    for line in content.lines() {
+        let (attributes, rest) = leading_attributes(line);
+        pending.extend(attributes);
+        if let Some(class) = CSHARP_CLASS_REGEX.captures(rest) {
+            models.classes.push(CSharpClass {
+                name: class[1].to_string(),
+                path: path.to_path_buf(),
+                attributes: std::mem::take(&mut pending),
+                properties: Vec::new(),
+            });
+        } else if let Some(property) = CSHARP_PROPERTY_REGEX.captures(rest)
+            && let Some(class) = models.classes.last_mut()
+        {
+            class.properties.push(CSharpProperty {
+                name: property[2].to_string(),
+                property_type: property[1].to_string(),
+                attributes: std::mem::take(&mut pending),
+            });
+        } else if !rest.is_empty() {
+            pending.clear();
+        }
+    }
+    models
+}
+
+/// String argument of a C# attribute, as in `[Table("orders", Schema = "sales")]`
+fn attribute_argument(attributes: &[String], attribute: &str, argument: Option<&str>) -> Option<String> {
+    let arguments = attributes.iter().find_map(|candidate| {
+        candidate
+            .strip_prefix(attribute)
+            .and_then(|rest| rest.strip_prefix("Attribute").or(Some(rest)))
+            .and_then(|rest| rest.strip_prefix('('))
+    })?;
+    match argument {
+        Some(argument) => Regex::new(&format!(r#"\b{}\s*=\s*"([^"]*)""#, argument)).ok()?.captures(arguments).map(|value| value[1].to_string()),
+        None => QUOTED_REGEX.captures(arguments.split(',').next()?).map(|value| value[1].to_string()),
+    }
+}
+
+fn has_attribute(attributes: &[String], attribute: &str) -> bool {
+    attributes.iter().any(|candidate| {
+        candidate == attribute || candidate.starts_with(&format!("{}(", attribute)) || candidate.starts_with(&format!("{}Attribute", attribute))
+    })
+}
+
+/// Entity Framework entities: the classes of a `DbSet` or with a `[Table]` attribute
+fn entity_framework_entities(
+    classes: &[CSharpClass],
+    db_sets: &HashMap<String, String>,
+    table_mappings: &HashMap<String, (String, Option<String>)>,
+    models: &mut OrmModels,
+) {
+    let entities: HashSet<&str> = classes
+        .iter()
+        .filter(|class| db_sets.contains_key(&class.name) || has_attribute(&class.attributes, "Table"))
+        .map(|class| class.name.as_str())
+        .collect();
+
+    for class in classes.iter().filter(|class| entities.contains(class.name.as_str())) {
+        let mapping = table_mappings.get(&class.name);
+        let mut table = DatabaseTable {
+            schema: attribute_argument(&class.attributes, "Table", Some("Schema"))
+                .or_else(|| mapping.and_then(|(_, schema)| schema.clone()))
+                .unwrap_or_default(),
+            name: attribute_argument(&class.attributes, "Table", None)
+                .or_else(|| mapping.map(|(table, _)| table.clone()))
+                .or_else(|| db_sets.get(&class.name).cloned())
+                .unwrap_or_else(|| class.name.clone()),
+            source_path: to_slash(&class.path),
+            ..Default::default()
+        };
+        let label = table_label(&table);
+        let property_named = |name: &str| class.properties.iter().find(|property| property.name.eq_ignore_ascii_case(name));
+
+        for property in class.properties.iter().filter(|property| !has_attribute(&property.attributes, "NotMapped")) {
+            let base = property.property_type.trim_end_matches('?');
+            if CSHARP_COLLECTIONS.iter().any(|collection| base.starts_with(collection)) {
+                continue;
+            }
+            if entities.contains(base) {
+                // Navigation property: its key is named by `[ForeignKey]` or by convention
+                let key = attribute_argument(&property.attributes, "ForeignKey", None)
+                    .or_else(|| {
+                        class
+                            .properties
+                            .iter()
+                            .find(|other| attribute_argument(&other.attributes, "ForeignKey", None).as_deref() == Some(property.name.as_str()))
+                            .map(|other| other.name.clone())
+                    })
+                    .or_else(|| property_named(&format!("{}Id", property.name)).map(|other| other.name.clone()))
+                    .unwrap_or_else(|| format!("{}Id", property.name));
+                models.relationships.push(foreign_key(label.clone(), vec![key], base.to_string(), Vec::new()));
+                continue;
+            }
+            let name = attribute_argument(&property.attributes, "Column", None).unwrap_or_else(|| property.name.clone());
+            if has_attribute(&property.attributes, "Key") {
+                table.primary_key.push(name.clone());
+            }
+            let is_value_type = CSHARP_VALUE_TYPES.contains(&base);
+            table.columns.push(TableColumn {
+                data_type: attribute_argument(&property.attributes, "Column", Some("TypeName")).unwrap_or_else(|| base.to_string()),
+                nullable: (property.property_type.ends_with('?') || !is_value_type) && !has_attribute(&property.attributes, "Required"),
+                is_identity: property
+                    .attributes
+                    .iter()
+                    .any(|attribute| attribute.contains("DatabaseGeneratedOption.Identity")),
+                default_value: None,
+                name,
+            });
+        }
+
+        if table.primary_key.is_empty()
+            && let Some(key) = property_named("Id").or_else(|| property_named(&format!("{}Id", class.name)))
+        {
+            let generated = !key.attributes.iter().any(|attribute| attribute.contains("DatabaseGeneratedOption.None"));
+            if let Some(column) = table.columns.iter_mut().find(|column| column.name == key.name) {
+                column.is_identity |= generated && matches!(column.data_type.as_str(), "int" | "long" | "short");
+            }
+            table.primary_key.push(key.name.clone());
+        }
+        for column in table.columns.iter_mut().filter(|column| table.primary_key.contains(&column.name)) {
+            column.nullable = false;
+        }
+        models.entities.push(OrmEntity {
+            orm: Orm::EntityFramework,
+            entity: class.name.clone(),
+            path: class.path.clone(),
+            table,
+        });
+    }
+}
+
+fn split_names(list: &str) -> Vec<String> {
+    if list.trim_start().starts_with(['\'', '"']) {
+        return python_list(list);
+    }
+    list.split(',').map(|name| name.trim().to_string()).filter(|name| !name.is_empty()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(files: &[(&str, &str)]) -> OrmModels {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        let infos: Vec<FileInfo> = files
+            .iter()
+            .map(|(path, content)| {
+                let full = root.join(path);
+                fs::create_dir_all(full.parent().unwrap()).unwrap();
+                fs::write(&full, content).unwrap();
+                FileInfo {
+                    path: PathBuf::from(path),
+                    name: String::new(),
+                    size: 0,
+                    extension: None,
+                    is_core: false,
+                    importance_score: 0.0,
+                    complexity_score: 0.0,
+                    last_modified: None,
+                }
+            })
+            .collect();
+        detect(&infos, &root, usize::MAX)
+    }
+
+    fn table<'a>(models: &'a OrmModels, name: &str) -> &'a DatabaseTable {
+        &models.entities.iter().find(|entity| entity.table.name == name).unwrap().table
+    }
+
+    fn columns(models: &OrmModels, name: &str) -> Vec<String> {
+        table(models, name).columns.iter().map(|column| format!("{} {}", column.name, column.data_type)).collect()
+    }
+
+    fn relationships(models: &OrmModels) -> Vec<String> {
+        models
+            .relationships
+            .iter()
+            .map(|relationship| {
+                format!(
+                    "{}({}) -> {}({})",
+                    relationship.from_table,
+                    relationship.from_columns.join(","),
+                    relationship.to_table,
+                    relationship.to_columns.join(",")
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_orm_models() {
+        let entity_framework = project(&[
+            (
+                "Data/ShopContext.cs",
+                "public class ShopContext : DbContext\n{\n    public DbSet<Customer> Customers { get; set; }\n    public DbSet<Order> Orders { get; set; }\n\n    protected override void OnModelCreating(ModelBuilder modelBuilder)\n    {\n        modelBuilder.Entity<Customer>().ToTable(\"clients\", \"sales\");\n    }\n}\n",
+            ),
+            (
+                "Models/Order.cs",
+                "[Table(\"orders\")]\npublic class Order\n{\n    public int Id { get; set; }\n    [Required]\n    public string Reference { get; set; }\n    public decimal? Total { get; set; }\n    public int CustomerId { get; set; }\n    public virtual Customer Customer { get; set; }\n    [NotMapped]\n    public string Display { get; set; }\n}\n\npublic class Customer\n{\n    [Key]\n    public Guid Code { get; set; }\n    [Column(\"full_name\")] public string Name { get; set; }\n    public ICollection<Order> Orders { get; set; }\n}\n",
+            ),
+        ]);
+        assert_eq!(entity_framework.orms(), [Orm::EntityFramework]);
+        assert_eq!(columns(&entity_framework, "orders"), ["Id int", "Reference string", "Total decimal", "CustomerId int"]);
+        let orders = table(&entity_framework, "orders");
+        assert_eq!(orders.primary_key, ["Id"]);
+        assert!(orders.columns[0].is_identity && !orders.columns[1].nullable && orders.columns[2].nullable);
+        assert_eq!(table(&entity_framework, "clients").schema, "sales");
+        assert_eq!(columns(&entity_framework, "clients"), ["Code Guid", "full_name string"]);
+        assert_eq!(relationships(&entity_framework), ["orders(CustomerId) -> sales.clients(Code)"]);
+
+        let hibernate = project(&[
+            (
+                "src/main/java/shop/Order.java",
+                "package shop;\n\n@Entity\n@Table(name = \"orders\")\npublic class Order {\n    @Id\n    @GeneratedValue(strategy = GenerationType.IDENTITY)\n    private Long id;\n\n    @Column(name = \"order_ref\", nullable = false)\n    private String reference;\n\n    @ManyToOne\n    @JoinColumn(name = \"customer_id\")\n    private Customer customer;\n\n    @OneToMany(mappedBy = \"order\")\n    private List<OrderLine> lines;\n\n    @Transient\n    private String display;\n}\n",
+            ),
+            (
+                "src/main/java/shop/Customer.java",
+                "package shop;\n\n@Entity\npublic class Customer {\n    @Id\n    private Long id;\n    private String name;\n}\n",
+            ),
+        ]);
+        assert_eq!(columns(&hibernate, "orders"), ["id Long", "order_ref String", "customer_id Long"]);
+        assert!(table(&hibernate, "orders").columns[0].is_identity);
+        assert_eq!(relationships(&hibernate), ["orders(customer_id) -> Customer(id)"]);
+
+        let sqlalchemy = project(&[(
+            "app/models.py",
+            "class Base(DeclarativeBase):\n    pass\n\n\nclass User(Base):\n    __tablename__ = \"users\"\n    __table_args__ = {\"schema\": \"auth\"}\n\n    id: Mapped[int] = mapped_column(primary_key=True)\n    email = Column(String(255), nullable=False)\n    nickname: Mapped[Optional[str]]\n\n\nclass BlogPost(db.Model):\n    id = db.Column(db.Integer, primary_key=True)\n    author_id = db.Column(db.Integer, db.ForeignKey(\"users.id\"))\n    author = relationship(\"User\")\n",
+        )]);
+        assert_eq!(columns(&sqlalchemy, "users"), ["id int", "email String(255)", "nickname str"]);
+        assert_eq!(table(&sqlalchemy, "users").schema, "auth");
+        assert!(table(&sqlalchemy, "users").columns[2].nullable);
+        assert_eq!(columns(&sqlalchemy, "blog_post"), ["id Integer", "author_id Integer"]);
+        assert!(table(&sqlalchemy, "blog_post").columns[0].is_identity);
+        assert_eq!(relationships(&sqlalchemy), ["blog_post(author_id) -> users(id)"]);
+
+        let diesel = project(&[
+            (
+                "src/schema.rs",
+                "diesel::table! {\n    users (id) {\n        id -> Int4,\n        name -> Varchar,\n    }\n}\n\ndiesel::table! {\n    posts {\n        id -> Int4,\n        user_id -> Int4,\n        body -> Nullable<Text>,\n    }\n}\n\ndiesel::joinable!(posts -> users (user_id));\n",
+            ),
+            ("src/models.rs", "#[derive(Queryable)]\n#[diesel(table_name = crate::schema::posts)]\npub struct Post {\n    pub id: i32,\n}\n"),
+        ]);
+        assert_eq!(columns(&diesel, "posts"), ["id Int4", "user_id Int4", "body Text"]);
+        assert!(table(&diesel, "posts").columns[2].nullable);
+        assert_eq!(diesel.entities[1].entity, "Post");
+        assert_eq!(relationships(&diesel), ["posts(user_id) -> users(id)"]);
+
+        let prisma = project(&[(
+            "prisma/schema.prisma",
+            "model User {\n  id    Int     @id @default(autoincrement())\n  email String  @unique @map(\"email_address\")\n  posts Post[]\n\n  @@map(\"users\")\n}\n\nmodel Post {\n  id       Int     @id @default(autoincrement())\n  title    String?\n  author   User    @relation(fields: [authorId], references: [id])\n  authorId Int\n}\n",
+        )]);
+        assert_eq!(columns(&prisma, "users"), ["id Int", "email_address String"]);
+        assert!(table(&prisma, "users").columns[0].is_identity);
+        assert_eq!(columns(&prisma, "Post"), ["id Int", "title String", "authorId Int"]);
+        assert!(table(&prisma, "Post").columns[1].nullable);
+        assert_eq!(relationships(&prisma), ["Post(authorId) -> users(id)"]);
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::generator::preprocess::extractors::dependency_graph::DependencyGraph;
+use crate::generator::preprocess::extractors::entry_point_detector::is_test_path;
+use crate::types::FileInfo;
+use crate::types::entry_point::EntryPoint;
+
+/// Orphan files passed to the agents
+const MAX_ORPHANS: usize = 50;
+/// Files loaded by tools or conventions rather than imported
+const CONVENTIONAL_ROOTS: [&str; 12] = [
+    "lib", "main", "mod", "build", "index", "__init__", "__main__", "setup", "conftest", "manage", "wsgi", "asgi",
+];
+
+/// Source files that no other project file imports: dead code candidates.
+/// Only languages whose imports resolve to project files at least once are considered, entry
+/// points, tests and conventionally loaded files (`lib.rs`, `__init__.py`, `*.config.js`, ...) are not.
+pub fn detect(files: &[FileInfo], root: &Path, entry_points: &[EntryPoint], max_bytes: usize) -> Vec<PathBuf> {
+    let graph = DependencyGraph::build(files, root, max_bytes);
+    if graph.edge_count() == 0 {
+        return Vec::new();
+    }
+    let in_degrees = graph.in_degrees();
+    let resolved_extensions: HashSet<&str> = files
+        .iter()
+        .zip(&in_degrees)
+        .filter(|(_, degree)| **degree > 0)
+        .filter_map(|(file, _)| file.extension.as_deref())
+        .collect();
+
+    let mut orphans: Vec<PathBuf> = files
+        .iter()
+        .enumerate()
+        .filter(|(index, file)| {
+            graph.is_parsed(*index)
+                && in_degrees[*index] == 0
+                && file
+                    .extension
+                    .as_deref()
+                    .is_some_and(|extension| resolved_extensions.contains(extension))
+                && !is_conventional_root(&file.path)
+                && !is_test_path(&file.path)
+                && !entry_points.iter().any(|entry| entry.path == file.path)
+        })
+        .map(|(_, file)| file.path.clone())
+        .collect();
+    orphans.sort();
+    orphans.truncate(MAX_ORPHANS);
+    orphans
+}
+
+fn is_conventional_root(path: &Path) -> bool {
+    let name = path.file_name().unwrap_or_default().to_string_lossy().to_lowercase();
+    let stem = name.split('.').next().unwrap_or_default();
+    CONVENTIONAL_ROOTS.contains(&stem)
+        || name.contains(".config.")
+        || name.ends_with(".d.ts")
+        || path.components().any(|c| c.as_os_str() == "bin" || c.as_os_str() == "examples")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_orphans() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        let sources = [
+            ("src/app.ts", "import { helper } from './helper';\n"),
+            ("src/helper.ts", "export const helper = 1;\n"),
+            ("src/legacy.ts", "export const unused = 1;\n"),
+            ("src/index.ts", "export {};\n"),
+            ("vite.config.ts", "export default {};\n"),
+            ("src/helper.test.ts", "import { helper } from './helper';\n"),
+        ];
+        let mut files = Vec::new();
+        for (path, content) in sources {
+            let path = PathBuf::from(path);
+            std::fs::create_dir_all(root.join(path.parent().unwrap())).unwrap();
+            std::fs::write(root.join(&path), content).unwrap();
+            files.push(FileInfo {
+                name: path.file_name().unwrap().to_string_lossy().to_string(),
+                extension: path.extension().map(|e| e.to_string_lossy().to_string()),
+                path,
+                size: 0,
+                is_core: false,
+                importance_score: 0.0,
+                complexity_score: 0.0,
+                last_modified: None,
+            });
+        }
+        let entry_points = vec![EntryPoint {
+            path: PathBuf::from("src/app.ts"),
+            evidence: "script entry file".to_string(),
+        }];
+
+        let orphans = detect(&files, &root, &entry_points, 4096);
+
+        assert_eq!(orphans, vec![PathBuf::from("src/legacy.ts")]);
+    }
+}
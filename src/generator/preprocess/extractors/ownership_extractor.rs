@@ -0,0 +1,141 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use glob::{MatchOptions, Pattern};
+
+use crate::generator::preprocess::extractors::git_history::GitHistory;
+use crate::types::FileInfo;
+use crate::types::ownership::{Ownership, OwnershipSource};
+
+/// Locations of CODEOWNERS honored by GitHub and GitLab, in lookup order
+const CODEOWNERS_LOCATIONS: [&str; 4] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS", ".gitlab/CODEOWNERS"];
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// CODEOWNERS rule: the file patterns of a line and its owners
+struct OwnerRule {
+    patterns: Vec<Pattern>,
+    owners: Vec<String>,
+}
+
+/// Assign owners to the project files from the CODEOWNERS file; without one, and when
+/// `git_fallback` is set, the author of most commits in the last `history_days` days owns each file
+pub fn extract(files: &[FileInfo], root: &Path, git_fallback: bool, history_days: u32) -> Ownership {
+    if let Some(location) = CODEOWNERS_LOCATIONS.iter().find(|location| root.join(location).is_file())
+        && let Ok(content) = fs::read_to_string(root.join(location))
+    {
+        let rules = parse(&content);
+        let files = files
+            .iter()
+            .filter_map(|file| {
+                // The last matching rule takes precedence; a rule without owners unassigns the file
+                let rule = rules.iter().rev().find(|rule| {
+                    rule.patterns
+                        .iter()
+                        .any(|pattern| pattern.matches_path_with(&file.path, MATCH_OPTIONS))
+                })?;
+                (!rule.owners.is_empty()).then(|| (file.path.clone(), rule.owners.clone()))
+            })
+            .collect();
+        return Ownership {
+            source: Some(OwnershipSource::CodeOwners(PathBuf::from(location))),
+            files,
+        };
+    }
+
+    if !git_fallback {
+        return Ownership::default();
+    }
+    let Some(history) = GitHistory::load(root, history_days) else {
+        return Ownership::default();
+    };
+    let files = files
+        .iter()
+        .filter_map(|file| Some((file.path.clone(), vec![history.main_author(&file.path)?.to_string()])))
+        .collect();
+    Ownership {
+        source: Some(OwnershipSource::GitHistory),
+        files,
+    }
+}
+
+fn parse(content: &str) -> Vec<OwnerRule> {
+    content
+        .lines()
+        .map(str::trim)
+        // Comments and GitLab section headers such as `[Backend] @backend-team`
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('[') && !line.starts_with("^["))
+        .filter_map(|line| {
+            let mut tokens = line.split_whitespace().take_while(|token| !token.starts_with('#'));
+            let pattern = tokens.next()?;
+            Some(OwnerRule {
+                patterns: glob_patterns(pattern).iter().filter_map(|p| Pattern::new(p).ok()).collect(),
+                owners: tokens.map(str::to_string).collect(),
+            })
+        })
+        .collect()
+}
+
+/// Globs over relative paths matching a gitignore-style CODEOWNERS pattern
+fn glob_patterns(pattern: &str) -> Vec<String> {
+    let directory_only = pattern.ends_with('/');
+    let trimmed = pattern.trim_start_matches('/').trim_end_matches('/');
+    // Patterns with an inner slash are relative to the root, others match at any depth
+    let base = if pattern.starts_with('/') || trimmed.contains('/') {
+        trimmed.to_string()
+    } else {
+        format!("**/{}", trimmed)
+    };
+    if directory_only {
+        vec![format!("{}/**", base)]
+    } else {
+        // A name may also be a directory, owning everything below it
+        vec![base.clone(), format!("{}/**", base)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codeowners_last_match_wins() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        fs::create_dir_all(root.join(".github")).unwrap();
+        fs::write(
+            root.join(".github/CODEOWNERS"),
+            "# Default owners\n* @org/platform\n\n/src/payments/ @org/payments @alice\n*.md @org/docs # docs team\nsrc/payments/legacy.rs\n",
+        )
+        .unwrap();
+        let files: Vec<FileInfo> = ["src/main.rs", "src/payments/api.rs", "src/payments/legacy.rs", "docs/guide.md"]
+            .into_iter()
+            .map(|path| FileInfo {
+                path: PathBuf::from(path),
+                name: String::new(),
+                size: 0,
+                extension: None,
+                is_core: false,
+                importance_score: 0.0,
+                complexity_score: 0.0,
+                last_modified: None,
+            })
+            .collect();
+
+        let ownership = extract(&files, &root, false, 30);
+
+        assert_eq!(
+            ownership.source,
+            Some(OwnershipSource::CodeOwners(PathBuf::from(".github/CODEOWNERS")))
+        );
+        assert_eq!(ownership.files[Path::new("src/main.rs")], vec!["@org/platform"]);
+        assert_eq!(ownership.files[Path::new("src/payments/api.rs")], vec!["@org/payments", "@alice"]);
+        assert!(!ownership.files.contains_key(Path::new("src/payments/legacy.rs")));
+        assert_eq!(ownership.files[Path::new("docs/guide.md")], vec!["@org/docs"]);
+        assert_eq!(ownership.owners_of(&["src/payments".to_string()]), vec!["@alice", "@org/payments"]);
+    }
+}
@@ -1,18 +1,29 @@
+use crate::config::SymlinkPolicy;
 use crate::generator::context::GeneratorContext;
 use crate::generator::preprocess::agents::directory_scoring::DirectoryScorer;
+use crate::generator::preprocess::extractors::dependency_graph::DependencyGraph;
+use crate::generator::preprocess::extractors::git_history::GitHistory;
+use crate::generator::preprocess::extractors::language_stats;
 use crate::types::project_structure::ProjectStructure;
 use crate::types::{DirectoryInfo, FileInfo};
-use crate::utils::file_utils::{is_binary_file_path, is_test_directory, is_test_file};
+use crate::utils::file_utils::{
+    IncludedDirs, VisitedEntries, is_binary_file, is_test_directory, is_test_file,
+};
+use crate::utils::gitignore::GitignoreMatcher;
+use crate::utils::privacy::PrivacyPolicy;
 use anyhow::Result;
 use futures::future::BoxFuture;
 use std::collections::HashMap;
 use std::fs::Metadata;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use tracing::{debug, warn};
 
 /// Project structure extractor
 pub struct StructureExtractor {
     directory_scorer: DirectoryScorer,
+    included_dirs: IncludedDirs,
+    privacy: PrivacyPolicy,
     context: GeneratorContext,
 }
 
@@ -20,6 +31,8 @@ impl StructureExtractor {
     pub fn new(context: GeneratorContext) -> Self {
         Self {
             directory_scorer: DirectoryScorer::new(),
+            included_dirs: IncludedDirs::new(&context.config.included_dirs),
+            privacy: PrivacyPolicy::new(&context.config.privacy),
             context,
         }
     }
@@ -54,8 +67,17 @@ impl StructureExtractor {
             HashMap::new()
         };
 
+        // Load .gitignore rules so ignored artifacts never reach the analysis
+        let gitignore = if self.context.config.respect_gitignore {
+            GitignoreMatcher::from_project(project_path)
+        } else {
+            GitignoreMatcher::default()
+        };
+
         // Collect all files during scan
         let mut files = Vec::new();
+        let mut visited = VisitedEntries::default();
+        visited.first_visit(project_path);
 
         // Scan directory, extract internal directory and file structure and basic file information
         self.scan_directory(
@@ -66,6 +88,8 @@ impl StructureExtractor {
             &mut file_types,
             &mut size_distribution,
             &tracked_files,
+            &gitignore,
+            &mut visited,
             0,
             self.context.config.max_depth.into(),
         )
@@ -74,24 +98,32 @@ impl StructureExtractor {
         // Sort directories lexicographically for deterministic ordering
         directories.sort_by(|a, b| a.path.cmp(&b.path));
 
-        // Sort files by importance score
-        files.sort_by(|a, b| {
-            b.importance_score.partial_cmp(&a.importance_score).unwrap_or(std::cmp::Ordering::Equal)
-        });
         // Apply LLM directory scoring boost to all directories
         match self.directory_scorer.score_directories(&self.context, &directories).await {
             Ok(dir_scores) => {
                 self.apply_directory_score_boost(&mut files, &dir_scores, project_path);
             }
             Err(e) => {
-                eprintln!("⚠️  Directory scoring failed: {}, skipping", e);
+                warn!("⚠️  Directory scoring failed: {}, skipping", e);
+                self.context
+                    .record_failure("preprocess", "directory scoring", &e.to_string())
+                    .await?;
             }
         }
 
         // Calculate importance scores (scores already calculated during scan, just refine)
         self.calculate_importance_scores(&mut files, &mut directories);
+        self.apply_centrality_boost(&mut files, project_path);
+        self.apply_git_activity_boost(&mut files, project_path);
+        for file in files.iter_mut() {
+            file.is_core = file.importance_score > 0.5;
+        }
+
+        // Sort files by importance score
+        files.sort_by(|a, b| b.importance_score.total_cmp(&a.importance_score));
 
         let project_name = self.context.config.get_project_name();
+        let language_stats = language_stats::collect(&files, project_path);
 
         Ok(ProjectStructure {
             project_name,
@@ -102,6 +134,7 @@ impl StructureExtractor {
             files,
             file_types,
             size_distribution,
+            language_stats,
         })
     }
 
@@ -122,15 +155,15 @@ impl StructureExtractor {
                     tracked.insert(path, ());
                 }
             } else {
-                eprintln!("⚠️  Warning: git ls-files failed, git_tracked_only will be ignored");
+                warn!("⚠️  Warning: git ls-files failed, git_tracked_only will be ignored");
             }
         } else {
-            eprintln!("⚠️  Warning: Failed to run git ls-files, git_tracked_only will be ignored");
+            warn!("⚠️  Warning: Failed to run git ls-files, git_tracked_only will be ignored");
         }
 
         // Warn if git_tracked_only is enabled but no files were found
         if self.context.config.git_tracked_only && tracked.is_empty() {
-            eprintln!("⚠️  Warning: git_tracked_only is enabled but no tracked files were found. Check that this is a git repository.");
+            warn!("⚠️  Warning: git_tracked_only is enabled but no tracked files were found. Check that this is a git repository.");
         }
 
         tracked
@@ -145,6 +178,8 @@ impl StructureExtractor {
         file_types: &'a mut HashMap<String, usize>,
         size_distribution: &'a mut HashMap<String, usize>,
         tracked_files: &'a HashMap<PathBuf, ()>,
+        gitignore: &'a GitignoreMatcher,
+        visited: &'a mut VisitedEntries,
         current_depth: usize,
         max_depth: usize,
     ) -> BoxFuture<'a, Result<()>> {
@@ -160,17 +195,36 @@ impl StructureExtractor {
 
             while let Some(entry) = entries.next_entry().await? {
                 let path = entry.path();
-                let file_type = entry.file_type().await?;
+                let mut file_type = entry.file_type().await?;
+
+                if file_type.is_symlink() {
+                    if self.context.config.symlink_policy == SymlinkPolicy::Skip {
+                        continue;
+                    }
+                    // Broken links are skipped
+                    let Ok(metadata) = tokio::fs::metadata(&path).await else {
+                        continue;
+                    };
+                    file_type = metadata.file_type();
+                }
+                if self.context.config.symlink_policy == SymlinkPolicy::Follow
+                    && !visited.first_visit(&path)
+                {
+                    debug!("   🔁 Skipping {}, already reached through another link", path.display());
+                    continue;
+                }
 
                 if file_type.is_file() {
-                    // Check size before any other processing to avoid OOM
+                    // Oversized files are kept: directory analysis reads them partially
                     if let Ok(metadata) = std::fs::metadata(&path) {
-                        if metadata.len() > self.context.config.max_file_size.into() {
-                            // Skip oversized files but continue scanning remaining entries
-                            continue;
-                        }
                         // Check if this file should be ignored
-                        if !self.should_ignore_file(&path, tracked_files) {
+                        if !self.should_ignore_file(&path, tracked_files)
+                            && !gitignore.is_ignored(&path, false)
+                            && !self.privacy.is_excluded(root_path, &path)
+                            && self.included_dirs.contains_file(
+                                path.strip_prefix(root_path).unwrap_or(&path),
+                            )
+                        {
                             let mut file_info = self.create_file_info(&path, root_path, &metadata)?;
 
                             // Calculate importance score during scan (lazy calculation)
@@ -198,7 +252,13 @@ impl StructureExtractor {
                         .to_string();
 
                     // Skip hidden directories and commonly ignored directories
-                    if !self.should_ignore_directory(&dir_name) {
+                    if !self.should_ignore_directory(&dir_name)
+                        && !gitignore.is_ignored(&path, true)
+                        && !self.privacy.is_excluded(root_path, &path)
+                        && self
+                            .included_dirs
+                            .may_contain(path.strip_prefix(root_path).unwrap_or(&path))
+                    {
                         dir_subdirectory_count += 1;
 
                         // Recursively scan subdirectories
@@ -210,6 +270,8 @@ impl StructureExtractor {
                             file_types,
                             size_distribution,
                             tracked_files,
+                            gitignore,
+                            visited,
                             current_depth + 1,
                             max_depth,
                         )
@@ -218,8 +280,12 @@ impl StructureExtractor {
                 }
             }
 
-            // Create directory information
-            if current_path != root_path {
+            // Create directory information (ancestors of included directories are only traversed)
+            if current_path != root_path
+                && self
+                    .included_dirs
+                    .contains(current_path.strip_prefix(root_path).unwrap_or(current_path))
+            {
                 let dir_info = DirectoryInfo {
                     path: current_path.clone(),
                     name: current_path
@@ -433,8 +499,8 @@ impl StructureExtractor {
             return true;
         }
 
-        // Check binary files
-        if is_binary_file_path(path) {
+        // Check binary files, by extension or content
+        if is_binary_file(path) {
             return true;
         }
 
@@ -476,6 +542,45 @@ impl StructureExtractor {
         }
     }
 
+    /// Add the dependency graph centrality of each file to its importance score, so that
+    /// heavily depended-on modules rank as core components
+    fn apply_centrality_boost(&self, files: &mut [FileInfo], root_path: &Path) {
+        let weight = self.context.config.importance.centrality_weight;
+        if weight <= 0.0 {
+            return;
+        }
+        let graph = DependencyGraph::build(files, root_path, self.context.config.max_file_size as usize);
+        if graph.edge_count() == 0 {
+            return;
+        }
+        debug!("🕸️  Dependency graph: {} internal imports between project files", graph.edge_count());
+
+        for (file, centrality) in files.iter_mut().zip(graph.centrality()) {
+            file.importance_score = (file.importance_score + centrality * weight).min(1.0);
+        }
+    }
+
+    /// Add the git activity of each file to its importance score, so that actively
+    /// maintained hotspots are prioritized
+    fn apply_git_activity_boost(&self, files: &mut [FileInfo], root_path: &Path) {
+        let importance = &self.context.config.importance;
+        if importance.churn_weight <= 0.0 {
+            return;
+        }
+        let Some(history) = GitHistory::load(root_path, importance.history_days) else {
+            return;
+        };
+        if history.is_empty() {
+            return;
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        for file in files.iter_mut() {
+            let activity = history.activity(&file.path, now, importance.recency_half_life_days);
+            file.importance_score = (file.importance_score + activity * importance.churn_weight).min(1.0);
+        }
+    }
+
     /// Apply directory-level LLM score as an additive boost to file importance scores
     fn apply_directory_score_boost(
         &self,
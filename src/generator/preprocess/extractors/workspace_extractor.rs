@@ -0,0 +1,276 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::types::workspace::{Workspace, WorkspaceKind, WorkspaceMember, WorkspaceModel};
+
+static MAVEN_MODULE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<module>\s*([^<]+?)\s*</module>").unwrap());
+static MAVEN_PARENT_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<parent>.*?</parent>").unwrap());
+static MAVEN_ARTIFACT_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<artifactId>\s*([^<]+?)\s*</artifactId>").unwrap());
+
+/// Detect the workspaces declared by the manifests at the project root:
+/// Cargo workspaces, npm/yarn/pnpm workspaces, Maven multi-module poms and Go workspaces
+pub fn extract(project_path: &Path) -> WorkspaceModel {
+    let workspaces = [
+        cargo_workspace(project_path),
+        node_workspace(project_path),
+        maven_workspace(project_path),
+        go_workspace(project_path),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|workspace| !workspace.members.is_empty())
+    .collect();
+    WorkspaceModel { workspaces }
+}
+
+fn cargo_workspace(root: &Path) -> Option<Workspace> {
+    let manifest: toml::Value = toml::from_str(&fs::read_to_string(root.join("Cargo.toml")).ok()?).ok()?;
+    let workspace = manifest.get("workspace")?;
+    let patterns = string_array(workspace.get("members"));
+    let excluded: Vec<PathBuf> = string_array(workspace.get("exclude"))
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+
+    let members = expand_members(root, &patterns, "Cargo.toml")
+        .into_iter()
+        .filter(|path| !excluded.contains(path))
+        .map(|path| {
+            let name = fs::read_to_string(root.join(&path).join("Cargo.toml"))
+                .ok()
+                .and_then(|content| toml::from_str::<toml::Value>(&content).ok())
+                .and_then(|member| {
+                    member
+                        .get("package")?
+                        .get("name")?
+                        .as_str()
+                        .map(str::to_string)
+                });
+            member(path, name)
+        })
+        .collect();
+    Some(Workspace {
+        kind: WorkspaceKind::Cargo,
+        manifest: PathBuf::from("Cargo.toml"),
+        members,
+    })
+}
+
+fn node_workspace(root: &Path) -> Option<Workspace> {
+    let (kind, manifest, patterns) = if let Ok(content) = fs::read_to_string(root.join("pnpm-workspace.yaml")) {
+        let config: serde_yaml::Value = serde_yaml::from_str(&content).ok()?;
+        let patterns: Vec<String> = config
+            .get("packages")?
+            .as_sequence()?
+            .iter()
+            .filter_map(|p| p.as_str().map(str::to_string))
+            .collect();
+        (WorkspaceKind::Pnpm, "pnpm-workspace.yaml", patterns)
+    } else {
+        let package: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(root.join("package.json")).ok()?).ok()?;
+        // "workspaces": [...] or "workspaces": { "packages": [...] }
+        let declared = package.get("workspaces")?;
+        let patterns: Vec<String> = declared
+            .as_array()
+            .or_else(|| declared.get("packages")?.as_array())?
+            .iter()
+            .filter_map(|p| p.as_str().map(str::to_string))
+            .collect();
+        let kind = if root.join("yarn.lock").exists() {
+            WorkspaceKind::Yarn
+        } else {
+            WorkspaceKind::Npm
+        };
+        (kind, "package.json", patterns)
+    };
+
+    let members = expand_members(root, &patterns, "package.json")
+        .into_iter()
+        .map(|path| {
+            let name = fs::read_to_string(root.join(&path).join("package.json"))
+                .ok()
+                .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+                .and_then(|package| package.get("name")?.as_str().map(str::to_string));
+            member(path, name)
+        })
+        .collect();
+    Some(Workspace {
+        kind,
+        manifest: PathBuf::from(manifest),
+        members,
+    })
+}
+
+fn maven_workspace(root: &Path) -> Option<Workspace> {
+    let pom = fs::read_to_string(root.join("pom.xml")).ok()?;
+    let members = MAVEN_MODULE_REGEX
+        .captures_iter(&pom)
+        .map(|captures| {
+            let path = PathBuf::from(&captures[1]);
+            // The artifactId of the module itself, not of its parent
+            let name = fs::read_to_string(root.join(&path).join("pom.xml"))
+                .ok()
+                .and_then(|module_pom| {
+                    let own = MAVEN_PARENT_REGEX.replace(&module_pom, "");
+                    MAVEN_ARTIFACT_REGEX
+                        .captures(&own)
+                        .map(|captures| captures[1].to_string())
+                });
+            member(path, name)
+        })
+        .collect();
+    Some(Workspace {
+        kind: WorkspaceKind::Maven,
+        manifest: PathBuf::from("pom.xml"),
+        members,
+    })
+}
+
+fn go_workspace(root: &Path) -> Option<Workspace> {
+    let go_work = fs::read_to_string(root.join("go.work")).ok()?;
+    // `use ./api` or a `use ( ... )` block
+    let mut paths = Vec::new();
+    let mut in_block = false;
+    for line in go_work.lines().map(|line| line.split("//").next().unwrap_or("").trim()) {
+        if in_block {
+            if line == ")" {
+                in_block = false;
+            } else if !line.is_empty() {
+                paths.push(line.to_string());
+            }
+        } else if let Some(rest) = line.strip_prefix("use") {
+            let rest = rest.trim();
+            if rest == "(" {
+                in_block = true;
+            } else if !rest.is_empty() {
+                paths.push(rest.to_string());
+            }
+        }
+    }
+
+    let members = paths
+        .into_iter()
+        .map(|raw| {
+            let path = normalize(Path::new(&raw));
+            let name = fs::read_to_string(root.join(&path).join("go.mod")).ok().and_then(|go_mod| {
+                go_mod
+                    .lines()
+                    .find_map(|line| line.trim().strip_prefix("module "))
+                    .map(|module| module.trim().to_string())
+            });
+            member(path, name)
+        })
+        .collect();
+    Some(Workspace {
+        kind: WorkspaceKind::Go,
+        manifest: PathBuf::from("go.work"),
+        members,
+    })
+}
+
+/// Member directories matching the glob patterns that contain the member manifest, sorted
+fn expand_members(root: &Path, patterns: &[String], manifest: &str) -> Vec<PathBuf> {
+    let mut members = Vec::new();
+    for pattern in patterns {
+        // Negated patterns (pnpm) only narrow globs, which are kept as declared
+        if pattern.starts_with('!') {
+            continue;
+        }
+        let full = root.join(pattern.trim_end_matches('/'));
+        let Ok(paths) = glob::glob(&full.to_string_lossy()) else {
+            continue;
+        };
+        for path in paths.flatten() {
+            if path.join(manifest).is_file()
+                && let Ok(relative) = path.strip_prefix(root)
+            {
+                let relative = normalize(relative);
+                if !members.contains(&relative) {
+                    members.push(relative);
+                }
+            }
+        }
+    }
+    members.sort();
+    members
+}
+
+fn member(path: PathBuf, name: Option<String>) -> WorkspaceMember {
+    let name = name.unwrap_or_else(|| path.file_name().unwrap_or_default().to_string_lossy().to_string());
+    WorkspaceMember { name, path }
+}
+
+/// Path without `./` components
+fn normalize(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| !matches!(component, std::path::Component::CurDir))
+        .collect()
+}
+
+fn string_array(value: Option<&toml::Value>) -> Vec<String> {
+    value
+        .and_then(|v| v.as_array())
+        .map(|items| items.iter().filter_map(|item| item.as_str().map(str::to_string)).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_detect_workspaces() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        write(&root, "Cargo.toml", "[workspace]\nmembers = [\"crates/*\", \"cli\"]\nexclude = [\"crates/scratch\"]\n");
+        write(&root, "crates/core/Cargo.toml", "[package]\nname = \"app-core\"\n");
+        write(&root, "crates/scratch/Cargo.toml", "[package]\nname = \"scratch\"\n");
+        write(&root, "crates/docs/README.md", "");
+        write(&root, "cli/Cargo.toml", "[package]\nname = \"app-cli\"\n");
+        write(&root, "package.json", r#"{"workspaces": {"packages": ["web/*"]}}"#);
+        write(&root, "yarn.lock", "");
+        write(&root, "web/admin/package.json", r#"{"name": "@app/admin"}"#);
+        write(&root, "pom.xml", "<modules>\n  <module>service</module>\n</modules>");
+        write(
+            &root,
+            "service/pom.xml",
+            "<parent><artifactId>app-parent</artifactId></parent>\n<artifactId>app-service</artifactId>",
+        );
+        write(&root, "go.work", "go 1.22\n\nuse (\n\t./tools // generators\n)\n");
+        write(&root, "tools/go.mod", "module example.com/tools\n");
+
+        let model = extract(&root);
+
+        let summary: Vec<_> = model
+            .workspaces
+            .iter()
+            .map(|w| {
+                let members: Vec<_> = w.members.iter().map(|m| format!("{}={}", m.name, m.path.display())).collect();
+                (w.kind, members)
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (WorkspaceKind::Cargo, vec!["app-cli=cli".to_string(), "app-core=crates/core".to_string()]),
+                (WorkspaceKind::Yarn, vec!["@app/admin=web/admin".to_string()]),
+                (WorkspaceKind::Maven, vec!["app-service=service".to_string()]),
+                (WorkspaceKind::Go, vec!["example.com/tools=tools".to_string()]),
+            ]
+        );
+        assert_eq!(model.member_count(), 5);
+    }
+}
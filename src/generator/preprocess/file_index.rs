@@ -0,0 +1,221 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use tracing::warn;
+
+use crate::config::Config;
+use crate::integrations::freshness::hash_bytes;
+use crate::types::DirectoryDossier;
+
+/// Version of the directory analysis; bump it when prompts or dossier fields change
+/// so dossiers of earlier versions are regenerated
+const ANALYSIS_VERSION: u32 = 2;
+
+/// Indexed state of one file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FileIndexEntry {
+    pub size: u64,
+    /// Modification time, seconds since the Unix epoch
+    pub modified: u64,
+    /// Hash of the content read for analysis
+    pub hash: String,
+    /// Analysis the file last contributed to, see `FileIndex::analysis_version`
+    pub analysis_version: String,
+}
+
+/// Dossier of a directory together with the fingerprint of the files it was generated from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexedDossier {
+    fingerprint: String,
+    dossier: DirectoryDossier,
+}
+
+/// Per-file index of the last preprocessing run, persisted in `.litho/index/`, so
+/// directories whose files are unchanged reuse their dossier without reading the files
+/// or calling the LLM, independently of the AI cache
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FileIndex {
+    /// Files by path relative to the project root
+    files: BTreeMap<PathBuf, FileIndexEntry>,
+    /// Dossiers by directory path relative to the project root
+    dossiers: BTreeMap<PathBuf, IndexedDossier>,
+    #[serde(skip)]
+    analysis_version: String,
+    #[serde(skip)]
+    root: PathBuf,
+}
+
+impl FileIndex {
+    /// Load the index of the previous run; a missing or unreadable index, or disabled
+    /// `incremental_preprocessing`, starts empty
+    pub fn load(config: &Config) -> Self {
+        let path = config.preprocess_index_path();
+        let mut index = fs::read_to_string(&path)
+            .ok()
+            .filter(|_| config.incremental_preprocessing)
+            .and_then(|content| match serde_json::from_str::<FileIndex>(&content) {
+                Ok(index) => Some(index),
+                Err(e) => {
+                    warn!("⚠️  Ignoring unreadable preprocessing index {}: {}", path.display(), e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+        // Dossiers are written by the efficient model in the target language
+        index.analysis_version = format!(
+            "{}:{}:{}",
+            ANALYSIS_VERSION,
+            config.llm.model_efficient,
+            config.target_language.code()
+        );
+        index.root = config.project_path.clone();
+        index
+    }
+
+    pub fn save(&self, config: &Config) -> Result<()> {
+        let path = config.preprocess_index_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Dossier of the previous run when the directory still holds exactly the same files,
+    /// of the same analysis version. Files are only read when their size or mtime changed.
+    pub fn unchanged_dossier(
+        &mut self,
+        dir: &Path,
+        files: &[PathBuf],
+        read: impl Fn(&Path) -> Option<String>,
+    ) -> Option<DirectoryDossier> {
+        let indexed = self.dossiers.get(&self.relative(dir))?;
+        let fingerprint = indexed.fingerprint.clone();
+
+        let mut hashes = Vec::new();
+        for path in files {
+            let relative = self.relative(path);
+            let (size, modified) = stat(path)?;
+            let entry = self.files.get(&relative)?;
+            if entry.analysis_version != self.analysis_version {
+                return None;
+            }
+            if entry.size == size && entry.modified == modified {
+                hashes.push((relative, entry.hash.clone()));
+                continue;
+            }
+            // Touched but possibly unchanged: compare the content hash, remember the new mtime
+            let hash = hash_bytes(read(path)?.as_bytes());
+            if hash != entry.hash {
+                return None;
+            }
+            let entry = self.files.get_mut(&relative)?;
+            entry.size = size;
+            entry.modified = modified;
+            hashes.push((relative, hash));
+        }
+
+        (fingerprint_of(&hashes) == fingerprint).then(|| self.dossiers[&self.relative(dir)].dossier.clone())
+    }
+
+    /// Record the files a dossier was generated from
+    pub fn record_dossier(&mut self, dir: &Path, files: &[(PathBuf, String)], dossier: &DirectoryDossier) {
+        let mut hashes = Vec::new();
+        for (path, content) in files {
+            let Some((size, modified)) = stat(path) else {
+                continue;
+            };
+            let relative = self.relative(path);
+            let hash = hash_bytes(content.as_bytes());
+            self.files.insert(
+                relative.clone(),
+                FileIndexEntry {
+                    size,
+                    modified,
+                    hash: hash.clone(),
+                    analysis_version: self.analysis_version.clone(),
+                },
+            );
+            hashes.push((relative, hash));
+        }
+        self.dossiers.insert(
+            self.relative(dir),
+            IndexedDossier {
+                fingerprint: fingerprint_of(&hashes),
+                dossier: dossier.clone(),
+            },
+        );
+    }
+
+    fn relative(&self, path: &Path) -> PathBuf {
+        path.strip_prefix(&self.root).unwrap_or(path).to_path_buf()
+    }
+}
+
+fn stat(path: &Path) -> Option<(u64, u64)> {
+    let metadata = fs::metadata(path).ok()?;
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+    Some((metadata.len(), modified))
+}
+
+/// Fingerprint of a set of files, independent of their order
+fn fingerprint_of(hashes: &[(PathBuf, String)]) -> String {
+    let mut hashes = hashes.to_vec();
+    hashes.sort();
+    let joined: String = hashes
+        .iter()
+        .map(|(path, hash)| format!("{}={}\n", path.display(), hash))
+        .collect();
+    hash_bytes(joined.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unchanged_dossier() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        let dir = root.join("src");
+        fs::create_dir_all(&dir).unwrap();
+        let main = dir.join("main.rs");
+        fs::write(&main, "fn main() {}").unwrap();
+
+        let mut index = FileIndex {
+            analysis_version: "1:model:en".to_string(),
+            root: root.clone(),
+            ..FileIndex::default()
+        };
+        let read = |path: &Path| fs::read_to_string(path).ok();
+        let dossier = DirectoryDossier {
+            summary: "Entry point".to_string(),
+            ..DirectoryDossier::default()
+        };
+        let files = vec![main.clone()];
+        assert!(index.unchanged_dossier(&dir, &files, read).is_none());
+
+        index.record_dossier(&dir, &[(main.clone(), "fn main() {}".to_string())], &dossier);
+        let reused = index.unchanged_dossier(&dir, &files, read).unwrap();
+        assert_eq!(reused.summary, "Entry point");
+        assert!(index.files.contains_key(Path::new("src/main.rs")));
+
+        // Changed content, a new file or another analysis version invalidate the dossier
+        fs::write(&main, "fn main() { run() }").unwrap();
+        assert!(index.unchanged_dossier(&dir, &files, read).is_none());
+        fs::write(&main, "fn main() {}").unwrap();
+        let lib = dir.join("lib.rs");
+        fs::write(&lib, "pub fn run() {}").unwrap();
+        assert!(index.unchanged_dossier(&dir, &[main.clone(), lib], read).is_none());
+        index.analysis_version = "2:model:en".to_string();
+        assert!(index.unchanged_dossier(&dir, &[main], read).is_none());
+    }
+}
@@ -13,4 +13,16 @@ impl ScopedKeys {
     pub const CODE_INSIGHTS: &'static str = "code_insights";
     pub const RELATIONSHIPS: &'static str = "relationships";
     pub const DIRECTORY_SELECTION: &'static str = "directory_selection";
+    pub const SAMPLING_REPORT: &'static str = "sampling_report";
+    pub const WORKSPACE: &'static str = "workspace";
+    pub const FRAMEWORKS: &'static str = "frameworks";
+    pub const LANGUAGE_STATS: &'static str = "language_stats";
+    pub const LICENSE: &'static str = "license";
+    pub const ENTRY_POINTS: &'static str = "entry_points";
+    pub const ORPHAN_FILES: &'static str = "orphan_files";
+    pub const OWNERSHIP: &'static str = "ownership";
+    pub const DEPENDENCIES: &'static str = "dependencies";
+    pub const ARCHITECTURE_META: &'static str = "architecture_meta";
+    pub const DIAGRAM_ASSETS: &'static str = "diagram_assets";
+    pub const ORM_MODELS: &'static str = "orm_models";
 }
\ No newline at end of file
@@ -2,8 +2,12 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use tokio::time::Instant;
 
-use crate::generator::preprocess::extractors::original_document_extractor;
+use crate::generator::preprocess::extractors::call_graph::CallGraph;
+use crate::generator::preprocess::extractors::{
+    dependency_inventory, diagram_assets, entry_point_detector, framework_detector, license_detector, orm_models, original_document_extractor, orphan_detector, ownership_extractor, workspace_extractor,
+};
 use crate::generator::preprocess::memory::{MemoryScope, ScopedKeys};
+use crate::generator::workflow::TimingKeys;
 use crate::types::original_document::OriginalDocument;
 use crate::{
     generator::{
@@ -12,17 +16,32 @@ use crate::{
         types::Generator,
     },
     types::{
-        project_structure::ProjectStructure, CodeAndDirectoryInsights, DirectoryDossier,
+        project_structure::ProjectStructure, CodeAndDirectoryInsights, DirectoryDossier, DirectoryInfo,
         DirectoryPurpose,
     },
 };
+use tracing::{debug, info, warn};
 
 pub mod agents;
 pub mod extractors;
+pub mod file_index;
 pub mod memory;
+pub mod sampling;
 
-use crate::generator::preprocess::agents::directory_summary::FileContent;
+use crate::generator::preprocess::agents::directory_summary::{DirectorySummarizer, FileContent};
 use crate::generator::preprocess::agents::relationships_analyze::RelationshipsAnalyze;
+use crate::generator::preprocess::file_index::FileIndex;
+use crate::generator::preprocess::sampling::{sample_files, SamplingReport};
+use crate::utils::privacy::{PathAccess, PrivacyPolicy, WITHHELD_CONTENT};
+use crate::utils::secret_redactor::RedactionLog;
+use crate::utils::progress::PhaseProgress;
+use crate::utils::threads::do_parallel_with_limit;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Statically detected function call dependencies given to the relationship analysis
+const MAX_STATIC_CALL_DEPENDENCIES: usize = 40;
 
 /// Preprocessing result — simplified to directory-only insights
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -41,6 +60,12 @@ impl PreProcessAgent {
     }
 }
 
+impl Default for PreProcessAgent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Generator<PreprocessingResult> for PreProcessAgent {
     async fn execute(&self, context: GeneratorContext) -> Result<PreprocessingResult> {
         let start_time = Instant::now();
@@ -48,44 +73,159 @@ impl Generator<PreprocessingResult> for PreProcessAgent {
         let structure_extractor = StructureExtractor::new(context.clone());
         let config = &context.config;
 
-        println!("🔍 Starting project preprocessing phase...");
+        info!("🔍 Starting project preprocessing phase...");
 
         // 1. Extract project original document materials
-        println!("📁 Extracting project original document materials...");
+        info!("📁 Extracting project original document materials...");
+        let step_start = Instant::now();
         let original_document = original_document_extractor::extract(&context).await?;
+        context
+            .record_timing(TimingKeys::PREPROCESS, "original documents", step_start.elapsed().as_secs_f64())
+            .await?;
 
         // 2. Extract project structure (includes all files and directories)
-        println!("📁 Extracting project structure...");
-        let project_structure = structure_extractor
+        info!("📁 Extracting project structure...");
+        let step_start = Instant::now();
+        let mut project_structure = structure_extractor
             .extract_structure(&config.project_path)
             .await?;
+        context
+            .record_timing(TimingKeys::PREPROCESS, "structure extraction", step_start.elapsed().as_secs_f64())
+            .await?;
 
-        println!(
+        info!(
             "   🔭 Discovered {} files, {} directories",
             project_structure.total_files, project_structure.total_directories
         );
 
-        // 3. Generate directory dossiers with LLM (reads files directly, no top-N filtering)
-        println!("📂 Generating directory dossiers with LLM...");
-        let directory_dossiers =
-            generate_directory_dossiers(&context, &project_structure).await?;
+        // Module layout declared by workspace manifests (Cargo, npm/yarn/pnpm, Maven, Go)
+        let workspace = workspace_extractor::extract(&config.project_path);
+        if !workspace.is_empty() {
+            let kinds: Vec<&str> = workspace.workspaces.iter().map(|w| w.kind.label()).collect();
+            info!(
+                "   📦 Workspace layout: {} with {} members",
+                kinds.join(", "),
+                workspace.member_count()
+            );
+        }
+
+        // Frameworks tag the project so agents look for framework-specific routes and wiring
+        let frameworks = framework_detector::detect(&project_structure.files, &config.project_path);
+        if !frameworks.is_empty() {
+            info!("   🧩 Detected frameworks: {}", frameworks.names().join(", "));
+        }
+
+        // The license is read from the files so the overview does not have to guess it
+        let license = license_detector::detect(&project_structure.files, &config.project_path);
+        if !license.is_empty() {
+            info!("   ⚖️ Detected license: {}", license.identifiers().join(", "));
+        }
 
-        // 4. Generate relationship analysis based on directory dossiers
-        println!("🔗 Generating relationship analysis...");
+        // Entry points anchor the main workflow and the startup boundaries on real files
+        let entry_points = entry_point_detector::detect(&project_structure.files, &config.project_path);
+        if !entry_points.is_empty() {
+            info!("   🚪 Detected {} entry points", entry_points.len());
+        }
+
+        // Files nothing imports are reported as dead code candidates
+        let orphan_files = orphan_detector::detect(
+            &project_structure.files,
+            &config.project_path,
+            &entry_points,
+            config.max_file_size as usize,
+        );
+        if !orphan_files.is_empty() {
+            info!("   🪦 {} source files are not imported by any other file", orphan_files.len());
+        }
+
+        let ownership = ownership_extractor::extract(
+            &project_structure.files,
+            &config.project_path,
+            config.ownership_from_git,
+            config.importance.history_days,
+        );
+        if let Some(source) = ownership.source.as_ref().filter(|_| !ownership.is_empty()) {
+            info!("   👥 Owners of {} files {}", ownership.files.len(), source.describe());
+        }
+
+        let dependencies = dependency_inventory::collect(
+            &project_structure.files,
+            &project_structure.directories,
+            &config.project_path,
+            config.max_file_size as usize,
+        );
+        if !dependencies.is_empty() {
+            info!(
+                "   📚 Found {} dependencies ({} external)",
+                dependencies.dependencies.len(),
+                dependencies.external_count()
+            );
+        }
+
+        let orm_models = orm_models::detect(&project_structure.files, &config.project_path, config.max_file_size as usize);
+        if !orm_models.is_empty() {
+            info!(
+                "   🧬 Found {} ORM entities ({})",
+                orm_models.entities.len(),
+                orm_models.orms().iter().map(|orm| orm.label()).collect::<Vec<_>>().join(", ")
+            );
+        }
+
+        // Existing diagrams are referenced by the documents instead of being redrawn
+        let diagrams = if config.output.reuse_diagrams {
+            diagram_assets::detect(config, &context.redactions)
+        } else {
+            Vec::new()
+        };
+        if !diagrams.is_empty() {
+            info!("   🖼️ Found {} existing diagrams in the documentation folders", diagrams.len());
+        }
+
+        // 3. Choose the files that get AI analysis (all files unless sampling is configured)
+        let sampling = sample_files(&project_structure.files, &config.sampling);
+        if !sampling.skipped_files.is_empty() {
+            info!(
+                "   🎯 Sampling ({:?}) selected {} of {} files for AI analysis, {} skipped",
+                sampling.strategy,
+                sampling.selected_files,
+                sampling.total_files,
+                sampling.skipped_files.len()
+            );
+        }
+
+        // 4. Generate directory dossiers with LLM (reads the selected files directly)
+        info!("📂 Generating directory dossiers with LLM...");
+        let directory_dossiers =
+            generate_directory_dossiers(&context, &project_structure, &sampling).await?;
+        apply_complexity_scores(&mut project_structure, &directory_dossiers, &config.project_path);
+
+        // 5. Generate relationship analysis based on directory dossiers
+        info!("🔗 Generating relationship analysis...");
+        let step_start = Instant::now();
+        let call_graph = CallGraph::build(
+            &project_structure.files,
+            &config.project_path,
+            config.max_file_size as usize,
+        );
+        info!("   📞 Found {} function call edges between files", call_graph.edge_count());
+        let static_calls = call_graph.dependencies(MAX_STATIC_CALL_DEPENDENCIES);
         let relationships_analyzer = RelationshipsAnalyze::new();
         let relationships = relationships_analyzer
-            .execute(&context, &directory_dossiers)
+            .execute(&context, &directory_dossiers, &static_calls)
+            .await?;
+        context
+            .record_timing(TimingKeys::PREPROCESS, "relationship analysis", step_start.elapsed().as_secs_f64())
             .await?;
 
         let processing_time = start_time.elapsed().as_secs_f64();
 
-        println!(
+        info!(
             "✅ Project preprocessing completed, {} directories analyzed, took {:.2}s",
             directory_dossiers.len(),
             processing_time
         );
 
-        // 4. Store results to Memory
+        // 6. Store results to Memory
         context
             .store_to_memory(
                 MemoryScope::PREPROCESS,
@@ -94,13 +234,13 @@ impl Generator<PreprocessingResult> for PreProcessAgent {
             )
             .await?;
         context
-            .store_to_memory(
+            .store_shared_to_memory(
                 MemoryScope::PREPROCESS,
                 ScopedKeys::CODE_INSIGHTS,
-                &CodeAndDirectoryInsights {
+                Arc::new(CodeAndDirectoryInsights {
                     file_insights: Vec::new(),
                     directory_insights: directory_dossiers.clone(),
-                },
+                }),
             )
             .await?;
         context
@@ -117,6 +257,43 @@ impl Generator<PreprocessingResult> for PreProcessAgent {
                 &relationships,
             )
             .await?;
+        context
+            .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::SAMPLING_REPORT, &sampling)
+            .await?;
+        context
+            .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::WORKSPACE, &workspace)
+            .await?;
+        context
+            .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::FRAMEWORKS, &frameworks)
+            .await?;
+        context
+            .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::LICENSE, &license)
+            .await?;
+        context
+            .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::ENTRY_POINTS, &entry_points)
+            .await?;
+        context
+            .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::ORPHAN_FILES, &orphan_files)
+            .await?;
+        context
+            .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::OWNERSHIP, &ownership)
+            .await?;
+        context
+            .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::DEPENDENCIES, &dependencies)
+            .await?;
+        context
+            .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::DIAGRAM_ASSETS, &diagrams)
+            .await?;
+        context
+            .store_to_memory(MemoryScope::PREPROCESS, ScopedKeys::ORM_MODELS, &orm_models)
+            .await?;
+        context
+            .store_to_memory(
+                MemoryScope::PREPROCESS,
+                ScopedKeys::LANGUAGE_STATS,
+                &project_structure.language_stats,
+            )
+            .await?;
 
         Ok(PreprocessingResult {
             original_document,
@@ -135,88 +312,174 @@ const MAX_BATCH_SIZE: usize = 256 * 1024;
 async fn generate_directory_dossiers(
     context: &GeneratorContext,
     project_structure: &ProjectStructure,
+    sampling: &SamplingReport,
 ) -> Result<Vec<DirectoryDossier>> {
-    use crate::generator::preprocess::agents::directory_summary::DirectorySummarizer;
-
-    let summarizer = DirectorySummarizer::new();
-    let config = &context.config;
-    let mut dossiers = Vec::new();
+    let summarizer = Arc::new(DirectorySummarizer::new());
+    let skipped = Arc::new(sampling.skipped_set());
+    // Core components may be read in full up to `limits.core_file_size`
+    let core_files: Arc<HashSet<PathBuf>> = Arc::new(
+        project_structure
+            .files
+            .iter()
+            .filter(|file| file.is_core)
+            .map(|file| file.path.clone())
+            .collect(),
+    );
+    let index = Arc::new(Mutex::new(FileIndex::load(&context.config)));
     let total_dirs = project_structure.directories.len();
+    let max_parallels = context.config.llm.max_parallels;
+    let progress = PhaseProgress::new("📂 Directory dossiers", total_dirs);
+    info!("🚀 Summarizing {} directories, max parallelism: {}", total_dirs, max_parallels);
+
+    // Directories are summarized concurrently; results keep the directory order of the project structure
+    let summary_futures: Vec<_> = project_structure
+        .directories
+        .iter()
+        .enumerate()
+        .map(|(idx, dir)| {
+            let dir = dir.clone();
+            let context = context.clone();
+            let summarizer = Arc::clone(&summarizer);
+            let skipped = Arc::clone(&skipped);
+            let core_files = Arc::clone(&core_files);
+            let index = Arc::clone(&index);
+            let progress = progress.clone();
+            Box::pin(async move {
+                let start = Instant::now();
+                let result = summarize_directory(
+                    &context,
+                    &summarizer,
+                    &dir,
+                    &skipped,
+                    &core_files,
+                    &index,
+                    (idx + 1, total_dirs),
+                )
+                .await;
+                let relative = dir.path.strip_prefix(&context.config.project_path).unwrap_or(&dir.path);
+                let item = format!("directory {}", relative.display());
+                context
+                    .record_timing(TimingKeys::PREPROCESS, &item, start.elapsed().as_secs_f64())
+                    .await?;
+                progress.set_message(&dir.name);
+                progress.inc();
+                result
+            })
+        })
+        .collect();
 
-    for (idx, dir) in project_structure.directories.iter().enumerate() {
-        // Read all files in this directory from disk
-        let mut files = read_directory_files(&dir.path, config)?;
+    let results = do_parallel_with_limit(summary_futures, max_parallels).await;
+    progress.finish();
 
-        if files.is_empty() {
-            continue;
-        }
+    let index = index.lock().unwrap_or_else(|e| e.into_inner());
+    if let Err(e) = index.save(&context.config) {
+        warn!("⚠️  Failed to save preprocessing index: {}", e);
+    }
 
-        // Sort lexicographically for cache-friendly batching
-        files.sort_by(|a, b| a.name.cmp(&b.name));
+    let mut dossiers = Vec::new();
+    for result in results {
+        dossiers.extend(result?);
+    }
+    Ok(dossiers)
+}
 
-        // Calculate total size
-        let total_size: usize = files.iter().map(|f| f.content.len()).sum();
+/// Summarize one directory, falling back to a placeholder dossier when the LLM fails.
+/// Directories whose files are unchanged since the previous run reuse their indexed dossier.
+/// Returns `None` for directories without readable files or whose files were all skipped by sampling.
+async fn summarize_directory(
+    context: &GeneratorContext,
+    summarizer: &DirectorySummarizer,
+    dir: &DirectoryInfo,
+    skipped: &HashSet<PathBuf>,
+    core_files: &HashSet<PathBuf>,
+    index: &Mutex<FileIndex>,
+    progress: (usize, usize),
+) -> Result<Option<DirectoryDossier>> {
+    let config = &context.config;
+    let privacy = PrivacyPolicy::new(&config.privacy);
+    let paths = list_directory_files(&dir.path, config, &privacy, skipped);
+    if paths.is_empty() {
+        return Ok(None);
+    }
 
-        if total_size <= MAX_BATCH_SIZE {
-            // Single batch
-            match summarizer
-                .summarize_directory(context, dir, &files, Some((idx + 1, total_dirs)))
-                .await
-            {
-                Ok(dossier) => dossiers.push(dossier),
-                Err(e) => {
-                    eprintln!(
-                        "⚠️  Failed to summarize directory {}: {}, using fallback",
-                        dir.name, e
-                    );
-                    dossiers.push(fallback_dossier(dir));
-                }
-            }
-        } else {
-            // Multiple batches: split by file boundaries, keep lexicographic order within each batch
-            let batches = split_into_batches(&files, MAX_BATCH_SIZE);
-            if batches.len() == 1 {
-                // Edge case: single file exceeds 256KB
-                match summarizer
-                    .summarize_directory(context, dir, &files, Some((idx + 1, total_dirs)))
-                    .await
-                {
-                    Ok(dossier) => dossiers.push(dossier),
-                    Err(e) => {
-                        eprintln!(
-                            "⚠️  Failed to summarize directory {}: {}, using fallback",
-                            dir.name, e
-                        );
-                        dossiers.push(fallback_dossier(dir));
-                    }
-                }
-            } else {
-                match summarizer
-                    .summarize_batch(context, dir, &batches, Some((idx + 1, total_dirs)))
-                    .await
-                {
-                    Ok(dossier) => dossiers.push(dossier),
-                    Err(e) => {
-                        eprintln!(
-                            "⚠️  Failed to summarize directory {} (batch mode): {}, using fallback",
-                            dir.name, e
-                        );
-                        dossiers.push(fallback_dossier(dir));
-                    }
-                }
-            }
-        }
+    let unchanged = index
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .unchanged_dossier(&dir.path, &paths, |path| read_file_content(path, config, &context.redactions, &privacy, core_files));
+    if let Some(dossier) = unchanged {
+        debug!("♻️  Directory {} is unchanged, reusing its dossier", dir.name);
+        return Ok(Some(dossier));
     }
 
-    Ok(dossiers)
+    // Read the files of this directory selected for AI analysis from disk
+    let mut files: Vec<FileContent> = paths
+        .into_iter()
+        .filter_map(|path| {
+            let content = read_file_content(&path, config, &context.redactions, &privacy, core_files)?;
+            let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            Some(FileContent { name, path, content })
+        })
+        .collect();
+    if files.is_empty() {
+        return Ok(None);
+    }
+
+    // Sort lexicographically for cache-friendly batching
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+
+    // Multiple batches when the content exceeds 256KB: split by file boundaries,
+    // keeping lexicographic order within each batch
+    let total_size: usize = files.iter().map(|f| f.content.len()).sum();
+    let batches = if total_size <= MAX_BATCH_SIZE {
+        Vec::new()
+    } else {
+        split_into_batches(&files, MAX_BATCH_SIZE)
+    };
+
+    let result = if batches.len() > 1 {
+        summarizer
+            .summarize_batch(context, dir, &batches, Some(progress))
+            .await
+    } else {
+        // Single batch, or a single file exceeding 256KB
+        summarizer
+            .summarize_directory(context, dir, &files, Some(progress))
+            .await
+    };
+
+    match result {
+        Ok(dossier) => {
+            let contents: Vec<(PathBuf, String)> =
+                files.into_iter().map(|file| (file.path, file.content)).collect();
+            index
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .record_dossier(&dir.path, &contents, &dossier);
+            Ok(Some(dossier))
+        }
+        Err(e) => {
+            let mode = if batches.len() > 1 { " (batch mode)" } else { "" };
+            warn!(
+                "⚠️  Failed to summarize directory {}{}: {}, using fallback",
+                dir.name, mode, e
+            );
+            context
+                .record_failure("preprocess", &dir.name, &e.to_string())
+                .await?;
+            Ok(Some(fallback_dossier(dir)))
+        }
+    }
 }
 
-/// Read all files in a directory, respecting config exclusions and max_file_size.
-fn read_directory_files(
+/// Files of a directory selected for AI analysis, respecting config exclusions and
+/// the files skipped by sampling (paths relative to the project root).
+fn list_directory_files(
     dir_path: &std::path::PathBuf,
     config: &crate::config::Config,
-) -> Result<Vec<FileContent>> {
-    use crate::utils::file_utils::{is_binary_file_path, is_test_file};
+    privacy: &PrivacyPolicy,
+    skipped: &HashSet<PathBuf>,
+) -> Vec<PathBuf> {
+    use crate::utils::file_utils::{is_binary_file, is_test_file};
 
     let mut files = Vec::new();
 
@@ -228,7 +491,20 @@ fn read_directory_files(
             }
 
             // Skip binary files
-            if is_binary_file_path(&path) {
+            if is_binary_file(&path) {
+                continue;
+            }
+
+            // Skip files excluded by the privacy policy
+            if privacy.is_excluded(&config.project_path, &path) {
+                continue;
+            }
+
+            // Skip files left out by sampling
+            if path
+                .strip_prefix(&config.project_path)
+                .is_ok_and(|relative| skipped.contains(relative))
+            {
                 continue;
             }
 
@@ -269,43 +545,64 @@ fn read_directory_files(
                 continue;
             }
 
-            if let Ok(metadata) = std::fs::metadata(&path) {
-                let file_size = metadata.len() as usize;
-                let name = path
-                    .file_name()
-                    .unwrap_or_default()
-                    .to_string_lossy()
-                    .to_string();
-
-                // Read up to max_file_size bytes (not the full file for oversized files)
-                let max_read_size = config.max_file_size as usize;
-                let read_size = file_size.min(max_read_size);
-
-                if let Ok(mut file) = std::fs::File::open(&path) {
-                    use std::io::Read;
-                    let mut buffer = vec![0u8; read_size];
-                    if let Ok(bytes_read) = file.read(&mut buffer) {
-                        buffer.truncate(bytes_read);
-                        // Decode to string, handling potential UTF-8 issues
-                        let content = String::from_utf8_lossy(&buffer).into_owned();
-                        // Truncate per-file at 256KB for prompt (but only if under max_file_size, otherwise we already truncated at max_file_size)
-                        let truncated = if content.chars().count() > 256 * 1024 {
-                            content.chars().take(256 * 1024).collect()
-                        } else {
-                            content
-                        };
-                        files.push(FileContent {
-                            name,
-                            path,
-                            content: truncated,
-                        });
-                    }
-                }
-            }
+            files.push(path);
         }
     }
 
-    Ok(files)
+    files
+}
+
+/// Read the content of a file for analysis. Files above their size limit (`max_file_size` or
+/// its `[limits]` overrides) are read partially: head, tail and the important lines in between,
+/// so large key files still contribute interfaces.
+/// Metadata-only files are represented by a placeholder, their content is never read.
+fn read_file_content(
+    path: &std::path::Path,
+    config: &crate::config::Config,
+    redactions: &RedactionLog,
+    privacy: &PrivacyPolicy,
+    core_files: &HashSet<PathBuf>,
+) -> Option<String> {
+    use crate::generator::preprocess::extractors::language_processors::LanguageProcessorManager;
+    use crate::utils::file_utils::read_partial_file;
+    use crate::utils::{encoding, secret_redactor};
+    use std::io::Read;
+
+    match privacy.access(&config.project_path, path) {
+        PathAccess::Full => {}
+        PathAccess::MetadataOnly => return Some(WITHHELD_CONTENT.to_string()),
+        PathAccess::Excluded => return None,
+    }
+
+    let file_size = std::fs::metadata(path).ok()?.len() as usize;
+    let is_core = path
+        .strip_prefix(&config.project_path)
+        .is_ok_and(|relative| core_files.contains(relative));
+    let max_file_size = config.max_file_size_for(path, is_core) as usize;
+    let source = path.strip_prefix(&config.project_path).unwrap_or(path).to_string_lossy();
+    let content = if file_size > max_file_size {
+        let processors = LanguageProcessorManager::new();
+        let (content, detected) =
+            read_partial_file(path, max_file_size, |line| processors.is_important_line(path, line)).ok()?;
+        encoding::record(&source, detected);
+        content
+    } else {
+        let mut file = std::fs::File::open(path).ok()?;
+        let mut buffer = vec![0u8; file_size];
+        let bytes_read = file.read(&mut buffer).ok()?;
+        buffer.truncate(bytes_read);
+        // Non-UTF-8 sources are transcoded from their detected encoding
+        encoding::decode_source(&source, &buffer)
+    };
+
+    let content = secret_redactor::redact(&config.redaction, redactions, &path.to_string_lossy(), &content);
+
+    // Truncate per-file at 256KB for prompt
+    Some(if content.chars().count() > 256 * 1024 {
+        content.chars().take(256 * 1024).collect()
+    } else {
+        content
+    })
 }
 
 /// Split files into batches, each batch's total content <= max_size.
@@ -331,6 +628,24 @@ fn split_into_batches(files: &[FileContent], max_size: usize) -> Vec<Vec<FileCon
     batches
 }
 
+/// Set the complexity scores of the files measured while their directory was summarized
+fn apply_complexity_scores(structure: &mut ProjectStructure, dossiers: &[DirectoryDossier], root: &Path) {
+    let scores: HashMap<PathBuf, f64> = dossiers
+        .iter()
+        .flat_map(|d| d.file_insights.iter())
+        .filter(|fi| fi.complexity.lines_of_code > 0)
+        .map(|fi| {
+            let path = fi.file_path.strip_prefix(root).unwrap_or(&fi.file_path);
+            (path.to_path_buf(), fi.complexity.score())
+        })
+        .collect();
+    for file in structure.files.iter_mut() {
+        if let Some(score) = scores.get(&file.path) {
+            file.complexity_score = *score;
+        }
+    }
+}
+
 fn fallback_dossier(dir: &crate::types::DirectoryInfo) -> DirectoryDossier {
     DirectoryDossier {
         path: dir.path.clone(),
@@ -0,0 +1,176 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{SamplingConfig, SamplingStrategy};
+use crate::types::FileInfo;
+
+/// Outcome of file sampling: which files were left out of AI analysis
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SamplingReport {
+    pub strategy: SamplingStrategy,
+    pub total_files: usize,
+    pub selected_files: usize,
+    /// Skipped file counts by directory
+    pub skipped_by_directory: BTreeMap<String, usize>,
+    /// Skipped file counts by file extension
+    pub skipped_by_language: BTreeMap<String, usize>,
+    /// Skipped files, relative to the project root
+    pub skipped_files: Vec<PathBuf>,
+}
+
+impl SamplingReport {
+    /// Skipped files as a set for lookups while reading directories
+    pub fn skipped_set(&self) -> HashSet<PathBuf> {
+        self.skipped_files.iter().cloned().collect()
+    }
+}
+
+/// Choose the files that get AI analysis. Files are ranked by importance score and
+/// kept according to the configured strategy; small projects are never sampled.
+pub fn sample_files(files: &[FileInfo], config: &SamplingConfig) -> SamplingReport {
+    let mut report = SamplingReport {
+        strategy: config.strategy,
+        total_files: files.len(),
+        selected_files: files.len(),
+        ..Default::default()
+    };
+    if config.strategy == SamplingStrategy::All || files.len() <= config.min_files {
+        return report;
+    }
+
+    let mut ranked: Vec<&FileInfo> = files.iter().collect();
+    ranked.sort_by(|a, b| {
+        b.importance_score
+            .total_cmp(&a.importance_score)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+
+    let cap = if config.max_files == 0 { usize::MAX } else { config.max_files };
+    let mut kept_per_group: HashMap<String, usize> = HashMap::new();
+    let mut selected = 0;
+    for file in ranked {
+        let quota = match config.strategy {
+            SamplingStrategy::Stratified => Some((directory_of(&file.path), config.per_directory)),
+            SamplingStrategy::LanguageQuota => {
+                let language = language_of(file);
+                let quota = config
+                    .language_quotas
+                    .get(&language)
+                    .copied()
+                    .unwrap_or(config.default_language_quota);
+                Some((language, quota))
+            }
+            SamplingStrategy::All | SamplingStrategy::TopN => None,
+        };
+        let within_quota = match &quota {
+            Some((group, quota)) => kept_per_group.get(group).copied().unwrap_or(0) < *quota,
+            None => true,
+        };
+
+        if within_quota && selected < cap {
+            selected += 1;
+            if let Some((group, _)) = quota {
+                *kept_per_group.entry(group).or_default() += 1;
+            }
+        } else {
+            *report.skipped_by_directory.entry(directory_of(&file.path)).or_default() += 1;
+            *report.skipped_by_language.entry(language_of(file)).or_default() += 1;
+            report.skipped_files.push(file.path.clone());
+        }
+    }
+
+    report.skipped_files.sort();
+    report.selected_files = selected;
+    report
+}
+
+fn directory_of(path: &Path) -> String {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_string_lossy().to_string(),
+        _ => ".".to_string(),
+    }
+}
+
+fn language_of(file: &FileInfo) -> String {
+    file.extension
+        .as_deref()
+        .map(str::to_lowercase)
+        .unwrap_or_else(|| "(none)".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(path: &str, importance_score: f64) -> FileInfo {
+        let path = PathBuf::from(path);
+        FileInfo {
+            name: path.file_name().unwrap().to_string_lossy().to_string(),
+            extension: path.extension().map(|e| e.to_string_lossy().to_string()),
+            path,
+            size: 100,
+            is_core: false,
+            importance_score,
+            complexity_score: 0.0,
+            last_modified: None,
+        }
+    }
+
+    #[test]
+    fn test_sampling_strategies() {
+        let files = vec![
+            file("src/main.rs", 0.9),
+            file("src/lib.rs", 0.8),
+            file("src/util.rs", 0.3),
+            file("web/app.ts", 0.7),
+            file("web/view.ts", 0.2),
+            file("README", 0.1),
+        ];
+        let config = |strategy| SamplingConfig {
+            strategy,
+            min_files: 0,
+            max_files: 3,
+            per_directory: 1,
+            default_language_quota: 1,
+            ..SamplingConfig::default()
+        };
+
+        let all = sample_files(&files, &config(SamplingStrategy::All));
+        assert!(all.skipped_files.is_empty());
+        let small_project = SamplingConfig {
+            min_files: 10,
+            ..config(SamplingStrategy::TopN)
+        };
+        assert!(sample_files(&files, &small_project).skipped_files.is_empty());
+
+        let top_n = sample_files(&files, &config(SamplingStrategy::TopN));
+        assert_eq!(top_n.selected_files, 3);
+        assert_eq!(
+            top_n.skipped_files,
+            vec![
+                PathBuf::from("README"),
+                PathBuf::from("src/util.rs"),
+                PathBuf::from("web/view.ts")
+            ]
+        );
+        assert_eq!(top_n.skipped_by_directory.get("src"), Some(&1));
+
+        // One file per directory: src/main.rs, web/app.ts and README
+        let stratified = sample_files(&files, &config(SamplingStrategy::Stratified));
+        assert_eq!(stratified.selected_files, 3);
+        assert!(!stratified.skipped_files.contains(&PathBuf::from("README")));
+        assert!(stratified.skipped_files.contains(&PathBuf::from("src/lib.rs")));
+
+        let quotas = SamplingConfig {
+            language_quotas: HashMap::from([("rs".to_string(), 2)]),
+            ..config(SamplingStrategy::LanguageQuota)
+        };
+        let by_language = sample_files(&files, &quotas);
+        assert_eq!(by_language.selected_files, 3);
+        assert_eq!(by_language.skipped_by_language.get("rs"), Some(&1));
+        assert_eq!(by_language.skipped_by_language.get("ts"), Some(&1));
+        assert_eq!(by_language.skipped_by_language.get("(none)"), Some(&1));
+    }
+}
@@ -30,8 +30,11 @@ impl StepForwardAgent for ArchitectureResearcher {
                 DataSource::ResearchResult(AgentType::DomainModulesDetector.to_string()),
             ],
             optional_sources: vec![
+                DataSource::ARCHITECTURE_META,
                 DataSource::PROJECT_STRUCTURE,
                 DataSource::DEPENDENCY_ANALYSIS,
+                DataSource::WORKSPACE,
+                DataSource::FRAMEWORKS,
                 // Use architecture, deployment, database and ADR docs for architecture analysis
                 DataSource::knowledge_categories(vec!["architecture", "deployment", "database", "adr"]),
             ],
@@ -1,15 +1,20 @@
-use crate::generator::preprocess::memory::{MemoryScope, ScopedKeys};
-use crate::generator::research::types::{AgentType, BoundaryAnalysisReport};
+use crate::generator::preprocess::extractors::language_processors::LanguageProcessorManager;
+use crate::generator::research::types::{
+    APIBoundary, AgentType, BoundaryAnalysisReport, RouterBoundary, RouterParam,
+};
 use crate::generator::{
     context::GeneratorContext,
     step_forward_agent::{
         AgentDataConfig, DataSource, FormatterConfig, LLMCallMode, PromptTemplate, StepForwardAgent,
     },
 };
-use crate::types::code::CodePurpose;
-use crate::types::{CodeAndDirectoryInsights, FileInsight};
+use crate::types::code::{CodePurpose, InterfaceInfo};
+use crate::types::FileInsight;
+use crate::utils::{encoding, paths, threads};
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
+use std::collections::{BTreeSet, HashSet};
+use tracing::info;
 
 /// Boundary Interface Analyzer - Responsible for analyzing the external call boundaries of the system, including CLI, API, configuration interfaces, etc.
 #[derive(Default, Clone)]
@@ -31,6 +36,10 @@ impl StepForwardAgent for BoundaryAnalyzer {
         crate::generator::research::memory::MemoryScope::STUDIES_RESEARCH.to_string()
     }
 
+    fn confidence(&self, result: &BoundaryAnalysisReport) -> Option<f64> {
+        Some(result.confidence_score)
+    }
+
     fn data_config(&self) -> AgentDataConfig {
         AgentDataConfig {
             required_sources: vec![
@@ -39,7 +48,11 @@ impl StepForwardAgent for BoundaryAnalyzer {
                 DataSource::ResearchResult(AgentType::SystemContextResearcher.to_string()),
             ],
             // Use API and deployment docs for boundary analysis
-            optional_sources: vec![DataSource::knowledge_categories(vec!["api", "deployment"])],
+            optional_sources: vec![
+                DataSource::FRAMEWORKS,
+                DataSource::ENTRY_POINTS,
+                DataSource::knowledge_categories(vec!["api", "deployment"]),
+            ],
         }
     }
 
@@ -106,7 +119,7 @@ Rules:
 
             closing_instruction: r#"
 ## Analysis Instructions:
-1. **Entry files**: Look for CLI arguments, environment variables, config loading - these ARE boundaries!
+1. **Entry files**: Start from the detected entry points when provided; look for CLI arguments, environment variables, config loading - these ARE boundaries!
 2. **Config files**: Document configuration parameters as CLI boundaries or integration suggestions
 3. **No API/Router code?** That's fine - focus on CLI/configuration interfaces
 4. **Minimum output**: If you have Entry/Config code, document at least the startup interface
@@ -125,19 +138,23 @@ DO NOT return all empty arrays if you have Entry or Config code to analyze!"#
         &self,
         context: &GeneratorContext,
     ) -> Result<Option<String>> {
-        // 1. Filter boundary-related code insights
+        // 1. Routes and listeners found by static analysis, as facts to start from
+        let detected = format_detected_boundaries(&self.detect_boundaries(context).await?);
+
+        // 2. Filter boundary-related code insights
         let boundary_insights = self.filter_boundary_code_insights(context).await?;
 
         if boundary_insights.is_empty() {
-            return Ok(Some(
-                "### Boundary-Related Code Insights\nNo obvious boundary interface-related code found.\n\n".to_string(),
-            ));
+            return Ok(Some(format!(
+                "{}### Boundary-Related Code Insights\nNo obvious boundary interface-related code found.\n\n",
+                detected
+            )));
         }
 
-        // 2. Format boundary code insights
+        // 3. Format boundary code insights
         let formatted_content = self.format_boundary_insights(&boundary_insights);
 
-        Ok(Some(formatted_content))
+        Ok(Some(format!("{}{}", detected, formatted_content)))
     }
 
     /// Post-processing - output analysis summary
@@ -146,15 +163,30 @@ DO NOT return all empty arrays if you have Entry or Config code to analyze!"#
         result: &BoundaryAnalysisReport,
         _context: &GeneratorContext,
     ) -> Result<()> {
-        println!("✅ Boundary interface analysis completed:");
-        println!("   - CLI commands: {} items", result.cli_boundaries.len());
-        println!("   - API interfaces: {} items", result.api_boundaries.len());
-        println!("   - Router routes: {} items", result.router_boundaries.len());
-        println!("   - Integration suggestions: {} items", result.integration_suggestions.len());
-        println!("   - Confidence: {:.1}/10", result.confidence_score);
+        info!("✅ Boundary interface analysis completed:");
+        info!("   - CLI commands: {} items", result.cli_boundaries.len());
+        info!("   - API interfaces: {} items", result.api_boundaries.len());
+        info!("   - Router routes: {} items", result.router_boundaries.len());
+        info!("   - Integration suggestions: {} items", result.integration_suggestions.len());
+        info!("   - Confidence: {:.1}/10", result.confidence_score);
 
         Ok(())
     }
+
+    /// Add the routes and message listeners the language processors found in the boundary code
+    /// and the LLM left out
+    async fn augment(
+        &self,
+        mut result: BoundaryAnalysisReport,
+        context: &GeneratorContext,
+    ) -> Result<BoundaryAnalysisReport> {
+        let detected = self.detect_boundaries(context).await?;
+        let added = merge_detected_boundaries(&mut result, detected);
+        if added > 0 {
+            info!("🔌 Added {} routes and listeners detected in the source code", added);
+        }
+        Ok(result)
+    }
 }
 
 impl BoundaryAnalyzer {
@@ -164,7 +196,7 @@ impl BoundaryAnalyzer {
         context: &GeneratorContext,
     ) -> Result<Vec<FileInsight>> {
         let all_insights = context
-            .get_from_memory::<CodeAndDirectoryInsights>(MemoryScope::PREPROCESS, ScopedKeys::CODE_INSIGHTS)
+            .code_insights()
             .await
             .ok_or_else(|| anyhow!("CODE_INSIGHTS not found in PREPROCESS memory"))?;
 
@@ -210,7 +242,7 @@ impl BoundaryAnalyzer {
             }
         }
 
-        println!(
+        info!(
             "📊 Boundary code distribution: Entry({}) API/Controller({}) Config({}) Router({})",
             entry_count, api_count, config_count, router_count
         );
@@ -218,6 +250,49 @@ impl BoundaryAnalyzer {
         Ok(boundary_insights)
     }
 
+    /// Routes, page routes and listeners declared in the files of all boundary-related code
+    /// insights, with the project-relative file declaring them
+    async fn detect_boundaries(&self, context: &GeneratorContext) -> Result<Vec<(String, InterfaceInfo)>> {
+        let Some(all_insights) = context.code_insights().await else {
+            return Ok(Vec::new());
+        };
+        let files: Vec<_> = all_insights
+            .directory_insights
+            .iter()
+            .flat_map(|d| d.file_insights.iter())
+            .filter(|fi| {
+                matches!(
+                    fi.code_purpose,
+                    CodePurpose::Entry
+                        | CodePurpose::Api
+                        | CodePurpose::Router
+                        | CodePurpose::Controller
+                        | CodePurpose::Page
+                ) && fi.file_path.is_file()
+            })
+            .map(|fi| fi.file_path.clone())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        let processors = LanguageProcessorManager::new();
+        let project_path = &context.config.project_path;
+        let detected = threads::par_map(&files, |path| {
+            let Ok(bytes) = std::fs::read(path) else {
+                return Vec::new();
+            };
+            let (content, _) = encoding::decode(&bytes);
+            let source = paths::to_slash(path.strip_prefix(project_path).unwrap_or(path));
+            processors
+                .extract_interfaces(path, &content)
+                .into_iter()
+                .filter(|interface| matches!(interface.interface_type.as_str(), "route" | "listener" | "page_route"))
+                .map(|interface| (source.clone(), interface))
+                .collect::<Vec<_>>()
+        });
+        Ok(detected.into_iter().flatten().collect())
+    }
+
     /// Format boundary code insights - specialized formatting logic
     fn format_boundary_insights(&self, insights: &[FileInsight]) -> String {
         let mut content = String::from("### Boundary-Related Code Insights\n");
@@ -279,7 +354,7 @@ impl BoundaryAnalyzer {
     fn add_boundary_insight_item(&self, content: &mut String, fi: &FileInsight) {
         content.push_str(&format!(
             "**File**: `{}` (Importance: {:.2}, Purpose: {:?})\n",
-            fi.file_path.to_string_lossy(),
+            paths::to_slash(&fi.file_path),
             fi.importance_score,
             fi.code_purpose
         ));
@@ -334,3 +409,181 @@ impl BoundaryAnalyzer {
         content.push_str("\n");
     }
 }
+
+/// Route and listener endpoints compare without case, trailing slashes and parameter names, so
+/// `/users/{id}`, `/users/:id`, `/users/[id]` and `/users/<int:pk>/` are the same
+fn endpoint_key(method: &str, endpoint: &str) -> (String, String) {
+    let segments: Vec<String> = endpoint
+        .trim()
+        .trim_end_matches('/')
+        .split('/')
+        .map(|segment| {
+            if segment.starts_with(['{', ':', '<', '[']) {
+                "{}".to_string()
+            } else {
+                segment.to_lowercase()
+            }
+        })
+        .collect();
+    (method.trim().to_uppercase(), segments.join("/"))
+}
+
+/// Detected routes and listeners listed for the prompt
+fn format_detected_boundaries(detected: &[(String, InterfaceInfo)]) -> String {
+    if detected.is_empty() {
+        return String::new();
+    }
+    let mut content = String::from(
+        "### Detected Routes and Listeners\nFound by static analysis of the source code; document each of them in `api_boundaries` (routes and listeners) or `router_boundaries` (page routes):\n\n",
+    );
+    for (source, interface) in detected {
+        content.push_str(&format!("- `{}` ({}) in `{}`", interface.name, interface.interface_type, source));
+        if let Some(description) = &interface.description {
+            content.push_str(&format!(": {}", description));
+        }
+        content.push('\n');
+    }
+    content.push('\n');
+    content
+}
+
+/// Parameters of the dynamic segments of a page route, like `[slug]` or `[...path]`
+fn page_params(path: &str) -> Vec<RouterParam> {
+    path.split('/')
+        .filter_map(|segment| segment.strip_prefix('[')?.strip_suffix(']'))
+        .map(|param| {
+            let catch_all = param.trim_start_matches('[').trim_end_matches(']');
+            match catch_all.strip_prefix("...") {
+                Some(key) => RouterParam {
+                    key: key.to_string(),
+                    value_type: "string[]".to_string(),
+                    description: "Catch-all route segments".to_string(),
+                },
+                None => RouterParam {
+                    key: param.to_string(),
+                    value_type: "string".to_string(),
+                    description: "Dynamic route segment".to_string(),
+                },
+            }
+        })
+        .collect()
+}
+
+/// Add the detected routes and listeners missing from the report's API boundaries, and the page
+/// routes missing from its router boundaries; returns how many were added
+fn merge_detected_boundaries(report: &mut BoundaryAnalysisReport, detected: Vec<(String, InterfaceInfo)>) -> usize {
+    let mut known_pages: HashSet<String> = report
+        .router_boundaries
+        .iter()
+        .map(|router| endpoint_key("", &router.path).1)
+        .collect();
+    let mut known: HashSet<(String, String)> = report
+        .api_boundaries
+        .iter()
+        .map(|api| endpoint_key(&api.method, &api.endpoint))
+        .collect();
+    // Endpoints the LLM documented without a method match every method
+    let documented: HashSet<String> = known
+        .iter()
+        .filter(|(method, _)| method.is_empty() || method == "ANY")
+        .map(|(_, endpoint)| endpoint.clone())
+        .collect();
+
+    let mut added = 0;
+    for (source, interface) in detected {
+        if interface.interface_type == "page_route" {
+            if known_pages.insert(endpoint_key("", &interface.name).1) {
+                report.router_boundaries.push(RouterBoundary {
+                    params: page_params(&interface.name),
+                    path: interface.name,
+                    description: interface.description.unwrap_or_default(),
+                    source_location: source,
+                });
+                added += 1;
+            }
+            continue;
+        }
+        let (method, endpoint) = interface.name.split_once(' ').unwrap_or((interface.name.as_str(), ""));
+        let key = endpoint_key(method, endpoint);
+        if documented.contains(&key.1) || !known.insert(key) {
+            continue;
+        }
+        let request_format = (!interface.parameters.is_empty()).then(|| {
+            interface
+                .parameters
+                .iter()
+                .map(|p| format!("{}: {}", p.name, p.param_type))
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+        report.api_boundaries.push(APIBoundary {
+            endpoint: endpoint.to_string(),
+            method: method.to_string(),
+            description: interface.description.clone().unwrap_or_default(),
+            request_format,
+            response_format: interface.return_type.clone(),
+            authentication: None,
+            source_location: source,
+        });
+        added += 1;
+    }
+    added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_detected_boundaries() {
+        let mut report = BoundaryAnalysisReport::default();
+        report.api_boundaries.push(APIBoundary {
+            endpoint: "/users/:id/".to_string(),
+            method: "get".to_string(),
+            ..Default::default()
+        });
+        let detected = |name: &str| {
+            (
+                "src/main/java/UserController.java".to_string(),
+                InterfaceInfo {
+                    name: name.to_string(),
+                    interface_type: "route".to_string(),
+                    description: Some("Handled by `UserController.get`".to_string()),
+                    return_type: Some("User".to_string()),
+                    ..Default::default()
+                },
+            )
+        };
+
+        let added = merge_detected_boundaries(
+            &mut report,
+            vec![
+                detected("GET /users/{id}"),
+                detected("DELETE /users/{id}"),
+                detected("KAFKA user-events"),
+                detected("KAFKA user-events"),
+                (
+                    "pages/docs/[...path].tsx".to_string(),
+                    InterfaceInfo {
+                        name: "/docs/[...path]".to_string(),
+                        interface_type: "page_route".to_string(),
+                        ..Default::default()
+                    },
+                ),
+            ],
+        );
+
+        assert_eq!(added, 3);
+        let page = &report.router_boundaries[0];
+        assert_eq!((page.path.as_str(), page.source_location.as_str()), ("/docs/[...path]", "pages/docs/[...path].tsx"));
+        assert_eq!((page.params[0].key.as_str(), page.params[0].value_type.as_str()), ("path", "string[]"));
+        let apis: Vec<(&str, &str)> = report
+            .api_boundaries
+            .iter()
+            .map(|api| (api.method.as_str(), api.endpoint.as_str()))
+            .collect();
+        assert_eq!(apis, [("get", "/users/:id/"), ("DELETE", "/users/{id}"), ("KAFKA", "user-events")]);
+        assert_eq!(report.api_boundaries[1].source_location, "src/main/java/UserController.java");
+        assert_eq!(report.api_boundaries[1].response_format.as_deref(), Some("User"));
+    }
+}
@@ -1,5 +1,7 @@
-use crate::generator::preprocess::memory::{MemoryScope, ScopedKeys};
-use crate::generator::research::types::{AgentType, DatabaseOverviewReport};
+use crate::generator::preprocess::extractors::migration_schema::{self, MigratedSchema};
+use crate::generator::preprocess::extractors::orm_models::OrmModels;
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
+use crate::generator::research::types::{AgentType, DatabaseOverviewReport, DatabaseTable, TableColumn, TableRelationship};
 use crate::generator::{
     context::GeneratorContext,
     step_forward_agent::{
@@ -7,9 +9,12 @@ use crate::generator::{
     },
 };
 use crate::types::code::CodePurpose;
-use crate::types::{CodeAndDirectoryInsights, FileInsight};
+use crate::types::FileInsight;
+use crate::types::project_structure::ProjectStructure;
+use crate::utils::paths;
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
+use tracing::info;
 
 /// Database Overview Analyzer - Analyzes SQL database projects, tables, views, stored procedures, and data relationships
 #[derive(Default, Clone)]
@@ -31,6 +36,10 @@ impl StepForwardAgent for DatabaseOverviewAnalyzer {
         crate::generator::research::memory::MemoryScope::STUDIES_RESEARCH.to_string()
     }
 
+    fn confidence(&self, result: &DatabaseOverviewReport) -> Option<f64> {
+        Some(result.confidence_score)
+    }
+
     fn data_config(&self) -> AgentDataConfig {
         AgentDataConfig {
             required_sources: vec![
@@ -45,11 +54,11 @@ impl StepForwardAgent for DatabaseOverviewAnalyzer {
     fn prompt_template(&self) -> PromptTemplate {
         PromptTemplate {
             system_prompt:
-                r#"You are a professional database architect and SQL analyst, focused on analyzing SQL Server database projects and their structures.
+                r#"You are a professional database architect and SQL analyst, focused on analyzing database projects, schemas and migrations in SQL Server, PostgreSQL, MySQL and other SQL dialects.
 
 Your task is to analyze the provided SQL code insights and produce a comprehensive database overview including:
 
-1. **Database Projects** - Identify .sqlproj files and their structure
+1. **Database Projects** - Identify .sqlproj files, migration directories (Flyway, Liquibase, Alembic, Diesel...) and their structure
 2. **Tables** - Extract table definitions, columns, data types, constraints
 3. **Views** - Identify views and their source tables
 4. **Stored Procedures** - Analyze stored procedures, their parameters, and the tables they interact with
@@ -68,6 +77,8 @@ Focus on:
 - Extract schema and object names accurately
 - Identify column data types and constraints
 - Detect relationships between tables (explicit FKs and implicit references via JOINs)
+- When a schema built from migrations is provided, it is the current schema: later migrations override earlier CREATE TABLE statements
+- When ORM models are provided (Entity Framework, Hibernate, SQLAlchemy, Diesel, Prisma), document the tables they map even without SQL scripts
 - Understand the purpose of stored procedures and functions
 - Map data flow patterns through the database
 
@@ -182,7 +193,7 @@ Rules:
 
             closing_instruction: r#"
 ## Analysis Requirements:
-- Focus on Database-purpose code (.sql, .sqlproj files)
+- Focus on Database-purpose code (.sql, .sqlproj files, schema migrations, ORM entities)
 - Extract table schemas, columns, and data types from CREATE TABLE statements
 - Identify stored procedure parameters and referenced tables
 - Detect foreign key relationships from constraint definitions
@@ -195,7 +206,7 @@ Rules:
             llm_call_mode: LLMCallMode::Extract,
             formatter_config: FormatterConfig {
                 include_source_code: true, // Database analysis requires viewing SQL source code
-                code_insights_limit: 50,   // Reduced limit to prevent token overflow
+                code_insights_share: 0.3,  // Source code makes each insight larger
                 only_directories_when_files_more_than: Some(300),
                 ..FormatterConfig::default()
             },
@@ -207,19 +218,27 @@ Rules:
         &self,
         context: &GeneratorContext,
     ) -> Result<Option<String>> {
-        // Filter database-related code insights
+        // 1. Schema the migrations leave, as facts to start from
+        let migrated = format!(
+            "{}{}",
+            format_migrated_schema(&self.migrated_schema(context).await),
+            format_orm_models(&self.orm_models(context).await)
+        );
+
+        // 2. Filter database-related code insights
         let database_insights = self.filter_database_code_insights(context).await?;
 
         if database_insights.is_empty() {
-            return Ok(Some(
-                "### Database-Related Code Insights\nNo SQL database-related code found in this project.\n\n".to_string(),
-            ));
+            return Ok(Some(format!(
+                "{}### Database-Related Code Insights\nNo SQL database-related code found in this project.\n\n",
+                migrated
+            )));
         }
 
-        // Format database code insights
+        // 3. Format database code insights
         let formatted_content = self.format_database_insights(&database_insights);
 
-        Ok(Some(formatted_content))
+        Ok(Some(format!("{}{}", migrated, formatted_content)))
     }
 
     /// Post-processing - output analysis summary
@@ -228,28 +247,67 @@ Rules:
         result: &DatabaseOverviewReport,
         _context: &GeneratorContext,
     ) -> Result<()> {
-        println!("✅ Database overview analysis completed:");
-        println!("   - Database projects: {} items", result.database_projects.len());
-        println!("   - Tables: {} items", result.tables.len());
-        println!("   - Views: {} items", result.views.len());
-        println!("   - Stored procedures: {} items", result.stored_procedures.len());
-        println!("   - Functions: {} items", result.database_functions.len());
-        println!("   - Table relationships: {} items", result.table_relationships.len());
-        println!("   - Data flows: {} items", result.data_flows.len());
-        println!("   - Confidence: {:.1}/10", result.confidence_score);
+        info!("✅ Database overview analysis completed:");
+        info!("   - Database projects: {} items", result.database_projects.len());
+        info!("   - Tables: {} items", result.tables.len());
+        info!("   - Views: {} items", result.views.len());
+        info!("   - Stored procedures: {} items", result.stored_procedures.len());
+        info!("   - Functions: {} items", result.database_functions.len());
+        info!("   - Table relationships: {} items", result.table_relationships.len());
+        info!("   - Data flows: {} items", result.data_flows.len());
+        info!("   - Confidence: {:.1}/10", result.confidence_score);
 
         Ok(())
     }
+
+    /// Bring the tables, views and foreign keys of the report in line with the schema the
+    /// migrations leave, then add the tables the ORM models map
+    async fn augment(
+        &self,
+        mut result: DatabaseOverviewReport,
+        context: &GeneratorContext,
+    ) -> Result<DatabaseOverviewReport> {
+        let schema = self.migrated_schema(context).await;
+        let changed = merge_migrated_schema(&mut result, &schema);
+        if changed > 0 {
+            info!("🗄️ Updated {} tables, views and relationships from {} migrations", changed, schema.migrations.len());
+        }
+        let models = self.orm_models(context).await;
+        let changed = merge_orm_models(&mut result, &models);
+        if changed > 0 {
+            info!("🧬 Added {} tables and relationships from {} ORM entities", changed, models.entities.len());
+        }
+        Ok(result)
+    }
 }
 
 impl DatabaseOverviewAnalyzer {
+    /// Schema built by applying the migrations of the project in order
+    async fn migrated_schema(&self, context: &GeneratorContext) -> MigratedSchema {
+        let Some(structure) = context
+            .get_from_memory::<ProjectStructure>(PreprocessScope::PREPROCESS, ScopedKeys::PROJECT_STRUCTURE)
+            .await
+        else {
+            return MigratedSchema::default();
+        };
+        migration_schema::collect(&structure.files, &context.config.project_path)
+    }
+
+    /// Entities the ORM models of the project map, detected during preprocessing
+    async fn orm_models(&self, context: &GeneratorContext) -> OrmModels {
+        context
+            .get_from_memory::<OrmModels>(PreprocessScope::PREPROCESS, ScopedKeys::ORM_MODELS)
+            .await
+            .unwrap_or_default()
+    }
+
     /// Filter database-related code insights
     async fn filter_database_code_insights(
         &self,
         context: &GeneratorContext,
     ) -> Result<Vec<FileInsight>> {
         let all_insights = context
-            .get_from_memory::<CodeAndDirectoryInsights>(MemoryScope::PREPROCESS, ScopedKeys::CODE_INSIGHTS)
+            .code_insights()
             .await
             .ok_or_else(|| anyhow!("CODE_INSIGHTS not found in PREPROCESS memory"))?;
 
@@ -305,7 +363,7 @@ impl DatabaseOverviewAnalyzer {
             }
         }
 
-        println!(
+        info!(
             "📊 Database code distribution: Projects({}) SQL Files({}) DAO({})",
             sqlproj_count, sql_count, dao_count
         );
@@ -446,7 +504,7 @@ impl DatabaseOverviewAnalyzer {
         content.push_str(&format!(
             "- **{}** (`{}`)\n",
             fi.name,
-            fi.file_path.display()
+            paths::to_slash(&fi.file_path)
         ));
 
         if !fi.summary.is_empty() {
@@ -471,3 +529,278 @@ impl DatabaseOverviewAnalyzer {
         content.push_str("\n");
     }
 }
+
+fn format_columns(columns: &[TableColumn], primary_key: &[String]) -> String {
+    columns
+        .iter()
+        .map(|column| {
+            let mut flags = Vec::new();
+            if primary_key.iter().any(|key| key.eq_ignore_ascii_case(&column.name)) {
+                flags.push("PK".to_string());
+            }
+            if column.is_identity {
+                flags.push("identity".to_string());
+            }
+            if !column.nullable {
+                flags.push("NOT NULL".to_string());
+            }
+            if let Some(default_value) = &column.default_value {
+                flags.push(format!("default {}", default_value));
+            }
+            match flags.is_empty() {
+                true => format!("{} {}", column.name, column.data_type),
+                false => format!("{} {} ({})", column.name, column.data_type, flags.join(", ")),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_migrated_schema(schema: &MigratedSchema) -> String {
+    if schema.is_empty() {
+        return String::new();
+    }
+    let tools: Vec<&str> = schema.tools().iter().map(|tool| tool.label()).collect();
+    let mut content = format!(
+        "### Schema from Migrations\nBuilt by applying {} {} migrations in order; this is the current schema, document each table and view in it:\n\n",
+        schema.migrations.len(),
+        tools.join(" and ")
+    );
+    for table in &schema.tables {
+        let name = if table.schema.is_empty() { table.name.clone() } else { format!("{}.{}", table.schema, table.name) };
+        content.push_str(&format!("- Table `{}` (`{}`): {}\n", name, table.source_path, format_columns(&table.columns, &table.primary_key)));
+    }
+    for view in &schema.views {
+        content.push_str(&format!("- View `{}` (`{}`) over {}\n", view.name, view.source_path, view.referenced_tables.join(", ")));
+    }
+    for relationship in &schema.relationships {
+        content.push_str(&format!(
+            "- Foreign key `{}`({}) -> `{}`({})\n",
+            relationship.from_table,
+            relationship.from_columns.join(", "),
+            relationship.to_table,
+            relationship.to_columns.join(", ")
+        ));
+    }
+    if !schema.dropped_tables.is_empty() {
+        content.push_str(&format!("- Dropped by later migrations, no longer in the schema: {}\n", schema.dropped_tables.join(", ")));
+    }
+    content.push('\n');
+    content
+}
+
+fn format_orm_models(models: &OrmModels) -> String {
+    if models.is_empty() {
+        return String::new();
+    }
+    let orms: Vec<&str> = models.orms().iter().map(|orm| orm.label()).collect();
+    let mut content = format!(
+        "### ORM Models\nTables mapped by the {} entities of the project; document each of them:\n\n",
+        orms.join(" and ")
+    );
+    for entity in &models.entities {
+        let table = &entity.table;
+        let name = if table.schema.is_empty() { table.name.clone() } else { format!("{}.{}", table.schema, table.name) };
+        content.push_str(&format!(
+            "- Table `{}` mapped by the {} entity `{}` (`{}`): {}\n",
+            name,
+            entity.orm.label(),
+            entity.entity,
+            table.source_path,
+            format_columns(&table.columns, &table.primary_key)
+        ));
+    }
+    for relationship in &models.relationships {
+        content.push_str(&format!(
+            "- Foreign key `{}`({}) -> `{}`({})\n",
+            relationship.from_table,
+            relationship.from_columns.join(", "),
+            relationship.to_table,
+            relationship.to_columns.join(", ")
+        ));
+    }
+    content.push('\n');
+    content
+}
+
+/// Whether a report table is the table of a migrated schema; unqualified names match any schema
+fn same_table(report: &DatabaseTable, migrated: &DatabaseTable) -> bool {
+    report.name.eq_ignore_ascii_case(&migrated.name)
+        && (report.schema.is_empty() || migrated.schema.is_empty() || report.schema.eq_ignore_ascii_case(&migrated.schema))
+}
+
+/// Add the migrated tables, views and foreign keys the LLM left out, take the columns of
+/// the migrated tables over the LLM's and remove the tables later migrations dropped
+fn merge_migrated_schema(report: &mut DatabaseOverviewReport, schema: &MigratedSchema) -> usize {
+    let mut changed = 0;
+    for dropped in &schema.dropped_tables {
+        let (dropped_schema, dropped_name) = dropped.rsplit_once('.').unwrap_or(("", dropped));
+        let dropped = DatabaseTable { schema: dropped_schema.to_string(), name: dropped_name.to_string(), ..Default::default() };
+        let before = report.tables.len();
+        report.tables.retain(|table| !same_table(table, &dropped) || schema.tables.iter().any(|migrated| same_table(table, migrated)));
+        changed += before - report.tables.len();
+    }
+
+    for migrated in &schema.tables {
+        match report.tables.iter_mut().find(|table| same_table(table, migrated)) {
+            Some(table) => {
+                if !migrated.columns.is_empty() {
+                    table.columns = migrated.columns.clone();
+                    table.primary_key = migrated.primary_key.clone();
+                    changed += 1;
+                }
+            }
+            None => {
+                let mut table = migrated.clone();
+                table.description = format!("Created by the `{}` migration", migrated.source_path);
+                report.tables.push(table);
+                changed += 1;
+            }
+        }
+    }
+
+    for view in &schema.views {
+        if !report.views.iter().any(|existing| existing.name.eq_ignore_ascii_case(&view.name)) {
+            report.views.push(view.clone());
+            changed += 1;
+        }
+    }
+
+    changed + add_relationships(report, &schema.relationships)
+}
+
+/// Add the tables of the ORM entities the report lacks and the columns of those it lists
+/// without any; the LLM's descriptions and migrated columns are kept
+fn merge_orm_models(report: &mut DatabaseOverviewReport, models: &OrmModels) -> usize {
+    let mut changed = 0;
+    for entity in &models.entities {
+        match report.tables.iter_mut().find(|table| same_table(table, &entity.table)) {
+            Some(table) => {
+                if table.columns.is_empty() && !entity.table.columns.is_empty() {
+                    table.columns = entity.table.columns.clone();
+                    table.primary_key = entity.table.primary_key.clone();
+                    changed += 1;
+                }
+            }
+            None => {
+                let mut table = entity.table.clone();
+                table.description = format!("Mapped by the `{}` {} entity", entity.entity, entity.orm.label());
+                report.tables.push(table);
+                changed += 1;
+            }
+        }
+    }
+    changed + add_relationships(report, &models.relationships)
+}
+
+/// Add the foreign keys the report lacks, comparing tables without their schema
+fn add_relationships(report: &mut DatabaseOverviewReport, relationships: &[TableRelationship]) -> usize {
+    let mut added = 0;
+    let table_name = |label: &str| label.rsplit('.').next().unwrap_or(label).to_lowercase();
+    for relationship in relationships {
+        let exists = report.table_relationships.iter().any(|existing| {
+            table_name(&existing.from_table) == table_name(&relationship.from_table)
+                && table_name(&existing.to_table) == table_name(&relationship.to_table)
+                && existing.from_columns.iter().map(|column| column.to_lowercase()).eq(relationship.from_columns.iter().map(|column| column.to_lowercase()))
+        });
+        if !exists {
+            report.table_relationships.push(relationship.clone());
+            added += 1;
+        }
+    }
+    added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::preprocess::extractors::migration_schema::SchemaChange;
+    use crate::generator::preprocess::extractors::language_processors::sql;
+    use crate::generator::preprocess::extractors::orm_models::{Orm, OrmEntity};
+
+    #[test]
+    fn test_merge_migrated_schema() {
+        let mut schema = MigratedSchema::default();
+        for (path, script) in [
+            ("db/migration/V1__init.sql", "CREATE TABLE users (id INT PRIMARY KEY, name TEXT); CREATE TABLE legacy (id INT);"),
+            ("db/migration/V2__orders.sql", "CREATE TABLE orders (id INT PRIMARY KEY, user_id INT REFERENCES users(id)); DROP TABLE legacy;"),
+            ("db/migration/V3__email.sql", "ALTER TABLE users ADD COLUMN email VARCHAR(255) NOT NULL;"),
+        ] {
+            for change in sql::schema_changes(script) {
+                schema.apply(change, path);
+            }
+        }
+        schema.apply(SchemaChange::RenameColumn {
+            table: sql::parse_name("users"),
+            from: "name".to_string(),
+            to: "full_name".to_string(),
+        }, "db/migration/V4__rename.sql");
+
+        let mut report = DatabaseOverviewReport::default();
+        report.tables.push(DatabaseTable {
+            schema: "public".to_string(),
+            name: "Users".to_string(),
+            description: "Registered users".to_string(),
+            columns: vec![TableColumn { name: "name".to_string(), data_type: "TEXT".to_string(), ..Default::default() }],
+            ..Default::default()
+        });
+        report.tables.push(DatabaseTable { name: "legacy".to_string(), ..Default::default() });
+
+        let changed = merge_migrated_schema(&mut report, &schema);
+
+        assert_eq!(changed, 4);
+        let names: Vec<&str> = report.tables.iter().map(|table| table.name.as_str()).collect();
+        assert_eq!(names, ["Users", "orders"]);
+        let users = &report.tables[0];
+        assert_eq!(users.description, "Registered users");
+        let columns: Vec<&str> = users.columns.iter().map(|column| column.name.as_str()).collect();
+        assert_eq!(columns, ["id", "full_name", "email"]);
+        assert_eq!(report.tables[1].description, "Created by the `db/migration/V2__orders.sql` migration");
+        assert_eq!(report.table_relationships[0].to_table, "users");
+
+        let prompt = format_migrated_schema(&schema);
+        assert!(prompt.contains("- Table `users` (`db/migration/V1__init.sql`): id INT (PK, NOT NULL), full_name TEXT, email VARCHAR(255) (NOT NULL)"));
+        assert!(prompt.contains("no longer in the schema: legacy"));
+    }
+
+    #[test]
+    fn test_merge_orm_models() {
+        let table = |name: &str, columns: &[&str]| DatabaseTable {
+            name: name.to_string(),
+            columns: columns.iter().map(|column| TableColumn { name: column.to_string(), data_type: "Long".to_string(), ..Default::default() }).collect(),
+            primary_key: vec!["id".to_string()],
+            source_path: "src/main/java/shop/Order.java".to_string(),
+            ..Default::default()
+        };
+        let entity = |name: &str, table: DatabaseTable| OrmEntity {
+            orm: Orm::Hibernate,
+            entity: name.to_string(),
+            path: std::path::PathBuf::from(&table.source_path),
+            table,
+        };
+        let models = OrmModels {
+            entities: vec![entity("Order", table("orders", &["id", "customer_id"])), entity("Customer", table("customers", &["id"]))],
+            relationships: vec![TableRelationship {
+                from_table: "orders".to_string(),
+                from_columns: vec!["customer_id".to_string()],
+                to_table: "customers".to_string(),
+                to_columns: vec!["id".to_string()],
+                relationship_type: "ForeignKey".to_string(),
+                constraint_name: None,
+            }],
+        };
+
+        let mut report = DatabaseOverviewReport::default();
+        report.tables.push(DatabaseTable { name: "Orders".to_string(), description: "Placed orders".to_string(), ..Default::default() });
+
+        assert_eq!(merge_orm_models(&mut report, &models), 3);
+        assert_eq!(report.tables[0].description, "Placed orders");
+        assert_eq!(report.tables[0].columns.len(), 2);
+        assert_eq!(report.tables[1].description, "Mapped by the `Customer` Hibernate entity");
+        assert_eq!(report.table_relationships.len(), 1);
+        assert_eq!(merge_orm_models(&mut report, &models), 0);
+
+        let prompt = format_orm_models(&models);
+        assert!(prompt.contains("- Table `orders` mapped by the Hibernate entity `Order` (`src/main/java/shop/Order.java`): id Long (PK, NOT NULL), customer_id Long (NOT NULL)"));
+    }
+}
@@ -8,6 +8,7 @@ use crate::generator::{
         AgentDataConfig, DataSource, FormatterConfig, LLMCallMode, PromptTemplate, StepForwardAgent,
     },
 };
+use tracing::info;
 
 /// Domain Division and Top-Level Abstract Module Researcher - Identifies high-level system domain architecture and abstract modules, as well as their internal relationships.
 #[derive(Default)]
@@ -28,6 +29,10 @@ impl StepForwardAgent for DomainModulesDetector {
         MemoryScope::STUDIES_RESEARCH.to_string()
     }
 
+    fn confidence(&self, result: &DomainModulesReport) -> Option<f64> {
+        Some(result.confidence_score)
+    }
+
     fn data_config(&self) -> AgentDataConfig {
         AgentDataConfig {
             required_sources: vec![
@@ -36,7 +41,9 @@ impl StepForwardAgent for DomainModulesDetector {
                 DataSource::CODE_INSIGHTS,
             ],
             optional_sources: vec![
+                DataSource::ARCHITECTURE_META,
                 DataSource::PROJECT_STRUCTURE,
+                DataSource::WORKSPACE,
                 // Use architecture and database docs for domain analysis
                 DataSource::knowledge_categories(vec!["architecture", "database"]),
             ],
@@ -142,8 +149,8 @@ Rules:
         _context: &GeneratorContext,
     ) -> Result<()> {
         // Simplified storage logic
-        println!("✅ Domain architecture analysis completed:");
-        println!(
+        info!("✅ Domain architecture analysis completed:");
+        info!(
             "   - Identified domain modules: {}",
             result.domain_modules.len()
         );
@@ -153,10 +160,10 @@ Rules:
             .iter()
             .map(|d| d.sub_modules.len())
             .sum();
-        println!("   - Total sub-modules: {}", total_sub_modules);
-        println!("   - Domain relations: {}", result.domain_relations.len());
-        println!("   - Business flows: {}", result.business_flows.len());
-        println!("   - Confidence score: {:.1}/10", result.confidence_score);
+        info!("   - Total sub-modules: {}", total_sub_modules);
+        info!("   - Domain relations: {}", result.domain_relations.len());
+        info!("   - Business flows: {}", result.business_flows.len());
+        info!("   - Confidence score: {:.1}/10", result.confidence_score);
 
         Ok(())
     }
@@ -1,4 +1,3 @@
-use crate::generator::preprocess::memory::{MemoryScope, ScopedKeys};
 use crate::generator::research::memory::MemoryRetriever;
 use crate::generator::research::types::{
     AgentType, DomainModule, DomainModulesReport, KeyModuleReport, SubModule,
@@ -10,11 +9,16 @@ use crate::generator::{
         AgentDataConfig, DataSource, FormatterConfig, LLMCallMode, PromptTemplate, StepForwardAgent,
     },
 };
-use crate::types::{CodeAndDirectoryInsights, FileInsight};
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
+use crate::types::FileInsight;
+use crate::types::ownership::Ownership;
+use crate::utils::progress::PhaseProgress;
 use crate::utils::threads::do_parallel_with_limit;
+use crate::utils::paths;
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
 use std::collections::HashSet;
+use tracing::{info, warn};
 
 // Research materials for domain modules
 #[derive(Default, Clone)]
@@ -85,7 +89,7 @@ impl KeyModulesInsight {
         &self,
         context: &GeneratorContext,
     ) -> Result<Vec<KeyModuleReport>> {
-        println!("🔍 Starting multi-domain module analysis...");
+        info!("🔍 Starting multi-domain module analysis...");
         let mut reports = vec![];
         let max_parallels = context.config.llm.max_parallels;
 
@@ -97,14 +101,16 @@ impl KeyModulesInsight {
         }
 
         let domain_names: Vec<String> = domain_modules.iter().map(|d| d.name.clone()).collect();
-        println!(
+        info!(
             "📋 Discovered {} domain modules: {}",
             domain_modules.len(),
             domain_names.join(", ")
         );
 
         // 2. Perform concurrent analysis for each domain module
-        println!("🚀 Starting concurrent analysis, max parallelism: {}", max_parallels);
+        info!("🚀 Starting concurrent analysis, max parallelism: {}", max_parallels);
+
+        let progress = PhaseProgress::new("🔍 Domain modules", domain_modules.len());
 
         // Create concurrent tasks
         let analysis_futures: Vec<_> = domain_modules
@@ -112,11 +118,14 @@ impl KeyModulesInsight {
             .map(|domain| {
                 let domain_clone = domain.clone();
                 let context_clone = context.clone();
+                let progress = progress.clone();
                 Box::pin(async move {
                     let key_modules_insight = KeyModulesInsight::default();
                     let result = key_modules_insight
                         .analyze_single_domain(&domain_clone, &context_clone)
                         .await;
+                    progress.set_message(&domain_clone.name);
+                    progress.inc();
                     (domain_clone.name.clone(), result)
                 })
             })
@@ -124,6 +133,7 @@ impl KeyModulesInsight {
 
         // Use do_parallel_with_limit for concurrency control
         let analysis_results = do_parallel_with_limit(analysis_futures, max_parallels).await;
+        progress.finish();
 
         // Process analysis results
         let mut successful_analyses = 0;
@@ -137,11 +147,17 @@ impl KeyModulesInsight {
                         .await?;
                     successful_analyses += 1;
                     reports.push(report);
-                    println!("✅ Domain module analysis: {} completed and stored", domain_name);
+                    info!("✅ Domain module analysis: {} completed and stored", domain_name);
                 }
                 Err(e) => {
-                    let msg = context.config.target_language.msg_domain_analysis_failed();
-                    println!("{}", msg.replace("{}", &domain_name).replace("{}", &e.to_string()));
+                    let msg = context
+                        .config
+                        .ui_language()
+                        .msg_domain_analysis_failed(&domain_name, &e.to_string());
+                    warn!("{}", msg);
+                    context
+                        .record_failure("research", &domain_name, &e.to_string())
+                        .await?;
                     // Continue processing other domains without interrupting the entire flow
                 }
             }
@@ -174,28 +190,16 @@ impl KeyModulesInsight {
         context: &GeneratorContext,
     ) -> Result<Vec<FileInsight>> {
         let all_insights = context
-            .get_from_memory::<CodeAndDirectoryInsights>(MemoryScope::PREPROCESS, ScopedKeys::CODE_INSIGHTS)
+            .code_insights()
             .await
             .expect("memory of CODE_INSIGHTS not found in PREPROCESS");
 
         // Collect all code paths associated with this domain
-        let mut domain_paths: HashSet<String> = HashSet::new();
-
-        // 1. Add the domain's own code paths
-        for path in &domain.code_paths {
-            domain_paths.insert(path.clone());
-        }
-
-        // 2. Add submodule code paths
-        for sub in &domain.sub_modules {
-            for path in &sub.code_paths {
-                domain_paths.insert(path.clone());
-            }
-        }
+        let domain_paths: HashSet<String> = domain_code_paths(domain).into_iter().collect();
 
         if domain_paths.is_empty() {
-            let msg = context.config.target_language.msg_no_code_path_for_domain();
-            println!("{}", msg.replace("{}", &domain.name));
+            let msg = context.config.ui_language().msg_no_code_path_for_domain(&domain.name);
+            info!("{}", msg);
             return Ok(Vec::new());
         }
 
@@ -216,7 +220,7 @@ impl KeyModulesInsight {
             .cloned()
             .collect();
 
-        println!(
+        info!(
             "📁 Filtered {} related code files for domain '{}'",
             all_files.len(),
             domain.name
@@ -252,16 +256,22 @@ impl KeyModulesInsight {
             progress: None,
         };
 
-        println!("🤖 Analyzing '{}' domain...", domain.name);
+        info!("🤖 Analyzing '{}' domain...", domain.name);
         let mut report: KeyModuleReport = extract(context, params).await?;
 
         // 4. Set domain context information
         report.domain_name = domain.name.clone();
+        if let Some(ownership) = context
+            .get_from_memory::<Ownership>(PreprocessScope::PREPROCESS, ScopedKeys::OWNERSHIP)
+            .await
+        {
+            report.owners = ownership.owners_of(&domain_code_paths(domain));
+        }
         if report.module_name.is_empty() {
             report.module_name = format!("{} Core Module", domain.name);
         }
 
-        println!("✅ '{}' domain analysis completed", domain.name);
+        info!("✅ '{}' domain analysis completed", domain.name);
         Ok(report)
     }
 
@@ -342,11 +352,12 @@ Rules:
             .enumerate()
             .map(|(i, fi)| {
                 format!(
-                    "{}. File `{}`, Purpose: {}\n   Description: {}\n   Source Code\n```code\n{}```\n---\n",
+                    "{}. File `{}`, Purpose: {}\n   Description: {}\n   Complexity: {}\n   Source Code\n```code\n{}```\n---\n",
                     i + 1,
-                    fi.file_path.to_string_lossy(),
+                    paths::to_slash(&fi.file_path),
                     fi.code_purpose,
                     fi.summary,
+                    fi.complexity.describe(),
                     fi.source_summary
                 )
             })
@@ -354,3 +365,13 @@ Rules:
             .join("\n")
     }
 }
+
+/// Code paths of the domain and of its submodules
+fn domain_code_paths(domain: &DomainModule) -> Vec<String> {
+    domain
+        .code_paths
+        .iter()
+        .chain(domain.sub_modules.iter().flat_map(|sub| sub.code_paths.iter()))
+        .cloned()
+        .collect()
+}
@@ -23,13 +23,18 @@ impl StepForwardAgent for SystemContextResearcher {
         MemoryScope::STUDIES_RESEARCH.to_string()
     }
 
+    fn confidence(&self, result: &SystemContextReport) -> Option<f64> {
+        Some(result.confidence_score)
+    }
+
     fn data_config(&self) -> AgentDataConfig {
         AgentDataConfig {
             required_sources: vec![DataSource::PROJECT_STRUCTURE, DataSource::CODE_INSIGHTS],
             optional_sources: vec![
                 DataSource::README_CONTENT,
+                DataSource::FRAMEWORKS,
                 // Use architecture and ADR docs for system context analysis
-                DataSource::knowledge_categories(vec!["architecture", "adr"]),
+                DataSource::knowledge_categories(vec!["architecture", "adr", "issues"]),
             ],
         }
     }
@@ -30,10 +30,10 @@ impl StepForwardAgent for WorkflowResearcher {
                 DataSource::CODE_INSIGHTS,
             ],
             // Use workflow docs for business process analysis
-            optional_sources: vec![DataSource::knowledge_categories(vec![
-                "workflow",
-                "architecture",
-            ])],
+            optional_sources: vec![
+                DataSource::ENTRY_POINTS,
+                DataSource::knowledge_categories(vec!["workflow", "architecture", "issues"]),
+            ],
         }
     }
 
@@ -66,6 +66,8 @@ Generate a Markdown document that includes:
 Focus on functional perspective rather than excessive technical details."#.to_string(),
             opening_instruction: "The following research reports are provided for analyzing the system's main workflows".to_string(),
             closing_instruction: r#"
+If detected entry points are provided, the main workflow MUST start from one of them; do not invent other starting points.
+
 ## Document Structure Requirements:
 Please generate a comprehensive workflow documentation in Markdown format:
 
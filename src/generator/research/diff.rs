@@ -0,0 +1,195 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+
+use crate::generator::research::memory::MemoryScope;
+use crate::generator::research::types::AgentType;
+
+/// Memory snapshot of a run, keyed by scope then key
+pub type MemorySnapshot = BTreeMap<String, BTreeMap<String, Value>>;
+
+/// Items added, removed or changed between two runs
+#[derive(Debug, Default, Serialize, PartialEq)]
+pub struct ItemChanges {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl ItemChanges {
+    fn between(old: &BTreeMap<String, &Value>, new: &BTreeMap<String, &Value>) -> Self {
+        Self {
+            added: new.keys().filter(|k| !old.contains_key(*k)).cloned().collect(),
+            removed: old.keys().filter(|k| !new.contains_key(*k)).cloned().collect(),
+            changed: new
+                .iter()
+                .filter(|(k, v)| old.get(*k).is_some_and(|old| old != *v))
+                .map(|(k, _)| k.clone())
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Differences between the research results of two runs
+#[derive(Debug, Serialize)]
+pub struct ResearchDiff {
+    pub from: String,
+    pub to: String,
+    /// Research reports, by agent report name
+    pub reports: ItemChanges,
+    /// Domain modules detected by the DomainModulesDetector, by name
+    pub domain_modules: ItemChanges,
+    /// CLI commands, API endpoints and routes found by the BoundaryAnalyzer
+    pub boundaries: ItemChanges,
+}
+
+impl ResearchDiff {
+    /// Compare the research scope of two memory snapshots
+    pub fn between(from: &str, old: &MemorySnapshot, to: &str, new: &MemorySnapshot) -> Self {
+        let old_research = research(old);
+        let new_research = research(new);
+        Self {
+            from: from.to_string(),
+            to: to.to_string(),
+            reports: ItemChanges::between(&reports(&old_research), &reports(&new_research)),
+            domain_modules: ItemChanges::between(
+                &domain_modules(&old_research),
+                &domain_modules(&new_research),
+            ),
+            boundaries: ItemChanges::between(&boundaries(&old_research), &boundaries(&new_research)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.reports.is_empty() && self.domain_modules.is_empty() && self.boundaries.is_empty()
+    }
+}
+
+impl Display for ResearchDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Research changes from run {} to run {}:", self.from, self.to)?;
+        if self.is_empty() {
+            return writeln!(f, "  no changes");
+        }
+        for (title, changes) in [
+            ("Domain modules", &self.domain_modules),
+            ("Boundaries", &self.boundaries),
+            ("Research reports", &self.reports),
+        ] {
+            if changes.is_empty() {
+                continue;
+            }
+            writeln!(f, "{}:", title)?;
+            for (marker, items) in [("+", &changes.added), ("-", &changes.removed), ("~", &changes.changed)] {
+                for item in items {
+                    writeln!(f, "  {} {}", marker, item)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn research(snapshot: &MemorySnapshot) -> BTreeMap<String, Value> {
+    snapshot
+        .get(MemoryScope::STUDIES_RESEARCH)
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn reports(research: &BTreeMap<String, Value>) -> BTreeMap<String, &Value> {
+    research.iter().map(|(k, v)| (k.clone(), v)).collect()
+}
+
+/// Items of an array field of a report, keyed by `id`
+fn items<'a>(
+    report: Option<&'a Value>,
+    field: &str,
+    id: impl Fn(&Value) -> Option<String>,
+) -> BTreeMap<String, &'a Value> {
+    report
+        .and_then(|report| report.get(field))
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|item| Some((id(item)?, item)))
+        .collect()
+}
+
+fn field(item: &Value, name: &str) -> Option<String> {
+    item.get(name).and_then(Value::as_str).map(str::to_string)
+}
+
+fn domain_modules(research: &BTreeMap<String, Value>) -> BTreeMap<String, &Value> {
+    let report = research.get(&AgentType::DomainModulesDetector.to_string());
+    items(report, "domain_modules", |module| field(module, "name"))
+}
+
+fn boundaries(research: &BTreeMap<String, Value>) -> BTreeMap<String, &Value> {
+    let report = research.get(&AgentType::BoundaryAnalyzer.to_string());
+    let mut boundaries = items(report, "cli_boundaries", |cli| {
+        Some(format!("CLI {}", field(cli, "command")?))
+    });
+    boundaries.extend(items(report, "api_boundaries", |api| {
+        Some(format!("API {} {}", field(api, "method")?, field(api, "endpoint")?))
+    }));
+    boundaries.extend(items(report, "router_boundaries", |route| {
+        Some(format!("Route {}", field(route, "path")?))
+    }));
+    boundaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn snapshot(modules: Value, apis: Value) -> MemorySnapshot {
+        let research = BTreeMap::from([
+            (
+                AgentType::DomainModulesDetector.to_string(),
+                json!({ "domain_modules": modules }),
+            ),
+            (
+                AgentType::BoundaryAnalyzer.to_string(),
+                json!({ "cli_boundaries": [], "api_boundaries": apis, "router_boundaries": [] }),
+            ),
+        ]);
+        BTreeMap::from([(MemoryScope::STUDIES_RESEARCH.to_string(), research)])
+    }
+
+    #[test]
+    fn test_research_diff() {
+        let old = snapshot(
+            json!([{ "name": "Orders", "importance": 8.0 }, { "name": "Billing" }]),
+            json!([{ "method": "GET", "endpoint": "/orders", "description": "List orders" }]),
+        );
+        let new = snapshot(
+            json!([{ "name": "Orders", "importance": 9.0 }, { "name": "Shipping" }]),
+            json!([
+                { "method": "GET", "endpoint": "/orders", "description": "List orders" },
+                { "method": "POST", "endpoint": "/orders", "description": "Create an order" }
+            ]),
+        );
+
+        let diff = ResearchDiff::between("1", &old, "2", &new);
+
+        assert_eq!(
+            diff.domain_modules,
+            ItemChanges {
+                added: vec!["Shipping".to_string()],
+                removed: vec!["Billing".to_string()],
+                changed: vec!["Orders".to_string()],
+            }
+        );
+        assert_eq!(diff.boundaries.added, vec!["API POST /orders".to_string()]);
+        assert!(diff.boundaries.changed.is_empty());
+        assert_eq!(diff.reports.changed.len(), 2);
+        assert!(ResearchDiff::between("2", &new, "2", &new).is_empty());
+    }
+}
@@ -9,6 +9,7 @@
 // F (Micro, C3, C4): BoundariesInsight: Categorize by focused Purpose, extract explanations of code that belongs to boundary types.
 
 pub mod agents;
+pub mod diff;
 pub mod orchestrator;
 pub mod types;
 pub mod memory;
@@ -1,6 +1,8 @@
 use anyhow::Result;
 
 use crate::generator::context::GeneratorContext;
+use crate::generator::preprocess::extractors::orm_models::OrmModels;
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
 use crate::generator::research::agents::architecture_researcher::ArchitectureResearcher;
 use crate::generator::research::agents::boundary_analyzer::BoundaryAnalyzer;
 use crate::generator::research::agents::database_overview_analyzer::DatabaseOverviewAnalyzer;
@@ -8,10 +10,14 @@ use crate::generator::research::agents::domain_modules_detector::DomainModulesDe
 use crate::generator::research::agents::key_modules_insight::KeyModulesInsight;
 use crate::generator::research::agents::system_context_researcher::SystemContextResearcher;
 use crate::generator::research::agents::workflow_researcher::WorkflowResearcher;
+use crate::generator::research::types::AgentType;
 use crate::generator::step_forward_agent::StepForwardAgent;
-use crate::generator::preprocess::memory::{MemoryScope, ScopedKeys};
+use crate::generator::workflow::TimingKeys;
 use crate::types::code::CodePurpose;
-use crate::types::{CodeAndDirectoryInsights, DirectoryPurpose};
+use crate::types::DirectoryPurpose;
+use crate::utils::progress::PhaseProgress;
+use std::time::Instant;
+use tracing::info;
 
 /// Multi-agent research orchestrator
 #[derive(Default)]
@@ -20,49 +26,91 @@ pub struct ResearchOrchestrator;
 impl ResearchOrchestrator {
     /// Execute all agent analysis pipelines
     pub async fn execute_research_pipeline(&self, context: &GeneratorContext) -> Result<()> {
-        println!("🚀 Starting Litho Studies Research investigation pipeline...");
+        info!("🚀 Starting Litho Studies Research investigation pipeline...");
 
-        // First layer: Macro analysis (C1)
-        self.execute_agent(&SystemContextResearcher, context)
-            .await?;
+        let has_database_files = self.has_database_files(context).await;
+        let total_agents = if has_database_files { 7 } else { 6 };
+        let progress = PhaseProgress::new("🔬 Research agents", total_agents);
 
-        // Second layer: Meso analysis (C2)
-        self.execute_agent(&DomainModulesDetector, context)
-            .await?;
-        self.execute_agent(&ArchitectureResearcher, context)
-            .await?;
-        self.execute_agent(&WorkflowResearcher, context)
-            .await?;
+        // Agents run as soon as the research results they depend on are available;
+        // concurrent LLM calls stay bounded by the client's `llm.max_parallels` limit.
+        let boundary_analyzer = BoundaryAnalyzer;
+        let database_overview_analyzer = DatabaseOverviewAnalyzer;
+        let research_layers = async {
+            // First layer: Macro analysis (C1)
+            self.execute_agent(&SystemContextResearcher, context, &progress)
+                .await?;
 
-        // Third layer: Micro analysis (C3-C4)
-        self.execute_agent(&KeyModulesInsight, context)
-            .await?;
+            // Second layer: Meso analysis (C2) and boundary interface analysis
+            let meso_and_micro = async {
+                self.execute_agent(&DomainModulesDetector, context, &progress)
+                    .await?;
 
-        // Boundary interface analysis
-        self.execute_agent(&BoundaryAnalyzer::default(), context)
-            .await?;
+                // Third layer: agents building on the domain modules, including micro analysis (C3-C4)
+                tokio::try_join!(
+                    self.execute_agent(&ArchitectureResearcher, context, &progress),
+                    self.execute_agent(&WorkflowResearcher, context, &progress),
+                    self.execute_agent(&KeyModulesInsight, context, &progress),
+                )?;
+                Ok::<_, anyhow::Error>(())
+            };
+            tokio::try_join!(
+                meso_and_micro,
+                self.execute_agent(&boundary_analyzer, context, &progress),
+            )?;
+            Ok::<_, anyhow::Error>(())
+        };
 
-        // Database overview analysis (only if database files exist)
-        if self.has_database_files(context).await {
-            self.execute_agent(&DatabaseOverviewAnalyzer::default(), context)
-                .await?;
-        }
+        // Database overview analysis only needs preprocessing results (and database files)
+        let database_overview = async {
+            if has_database_files {
+                self.execute_agent(&database_overview_analyzer, context, &progress)
+                    .await?;
+            }
+            Ok::<_, anyhow::Error>(())
+        };
+
+        tokio::try_join!(research_layers, database_overview)?;
+        progress.finish();
 
-        println!("✓ Litho Studies Research pipeline execution completed");
+        info!("✓ Litho Studies Research pipeline execution completed");
 
         Ok(())
     }
 
+    /// Execute one agent on the research results already in memory and return its output
+    pub async fn execute_single(&self, agent: &AgentType, context: &GeneratorContext) -> Result<serde_json::Value> {
+        let output = match agent {
+            AgentType::SystemContextResearcher => serde_json::to_value(SystemContextResearcher.execute(context).await?)?,
+            AgentType::DomainModulesDetector => serde_json::to_value(DomainModulesDetector.execute(context).await?)?,
+            AgentType::ArchitectureResearcher => serde_json::to_value(ArchitectureResearcher.execute(context).await?)?,
+            AgentType::WorkflowResearcher => serde_json::to_value(WorkflowResearcher.execute(context).await?)?,
+            AgentType::KeyModulesInsight => serde_json::to_value(KeyModulesInsight.execute(context).await?)?,
+            AgentType::BoundaryAnalyzer => serde_json::to_value(BoundaryAnalyzer.execute(context).await?)?,
+            AgentType::DatabaseOverviewAnalyzer => serde_json::to_value(DatabaseOverviewAnalyzer.execute(context).await?)?,
+        };
+        Ok(output)
+    }
+
     /// Check if the project has database-related files
     async fn has_database_files(&self, context: &GeneratorContext) -> bool {
+        // ORM entities map tables even when the project has no SQL scripts
+        if context
+            .get_from_memory::<OrmModels>(PreprocessScope::PREPROCESS, ScopedKeys::ORM_MODELS)
+            .await
+            .is_some_and(|models| !models.is_empty())
+        {
+            return true;
+        }
         if let Some(insights) = context
-            .get_from_memory::<CodeAndDirectoryInsights>(MemoryScope::PREPROCESS, ScopedKeys::CODE_INSIGHTS)
+            .code_insights()
             .await
         {
             insights.directory_insights.iter().any(|dossier| {
                 dossier.purpose == DirectoryPurpose::Database
                     || dossier.name.to_lowercase().contains("database")
                     || dossier.name.to_lowercase().contains("db")
+                    || dossier.name.to_lowercase().contains("migration")
             }) || insights.directory_insights.iter().flat_map(|d| d.file_insights.iter()).any(|fi| {
                 fi.code_purpose == CodePurpose::Database
                     || fi.file_path.to_string_lossy().ends_with(".sql")
@@ -78,21 +126,28 @@ impl ResearchOrchestrator {
         &self,
         agent: &T,
         context: &GeneratorContext,
+        progress: &PhaseProgress,
     ) -> Result<()>
     where
         T: StepForwardAgent + Send + Sync,
     {
         // Use localized agent name if available
         let agent_name = if let Some(agent_enum) = agent.agent_type_enum() {
-            agent_enum.display_name(&context.config.target_language)
+            agent_enum.display_name(context.config.ui_language())
         } else {
             agent.agent_type()
         };
         
-        println!("🤖 Executing {} agent analysis...", agent_name);
+        info!("🤖 Executing {} agent analysis...", agent_name);
+        progress.set_message(&agent_name);
 
+        let start = Instant::now();
         agent.execute(context).await?;
-        println!("✓ {} analysis completed", agent_name);
+        context
+            .record_timing(TimingKeys::RESEARCH, &agent.agent_type(), start.elapsed().as_secs_f64())
+            .await?;
+        progress.inc();
+        info!("✓ {} analysis completed", agent_name);
         Ok(())
     }
 }
@@ -885,6 +885,19 @@ pub enum AgentType {
 }
 
 impl AgentType {
+    /// All research agents in pipeline order
+    pub fn all() -> Vec<AgentType> {
+        vec![
+            AgentType::SystemContextResearcher,
+            AgentType::DomainModulesDetector,
+            AgentType::ArchitectureResearcher,
+            AgentType::WorkflowResearcher,
+            AgentType::KeyModulesInsight,
+            AgentType::BoundaryAnalyzer,
+            AgentType::DatabaseOverviewAnalyzer,
+        ]
+    }
+
     /// Get localized display name for the agent type
     pub fn display_name(&self, target_language: &TargetLanguage) -> String {
         match self {
@@ -897,6 +910,24 @@ impl AgentType {
             AgentType::DatabaseOverviewAnalyzer => target_language.msg_agent_type("database"),
         }
     }
+
+    /// Name of the agent in the configuration, e.g. `[formatter.architecture_researcher]`
+    pub fn config_key(&self) -> &'static str {
+        match self {
+            AgentType::SystemContextResearcher => "system_context_researcher",
+            AgentType::DomainModulesDetector => "domain_modules_detector",
+            AgentType::ArchitectureResearcher => "architecture_researcher",
+            AgentType::WorkflowResearcher => "workflow_researcher",
+            AgentType::KeyModulesInsight => "key_modules_insight",
+            AgentType::BoundaryAnalyzer => "boundary_analyzer",
+            AgentType::DatabaseOverviewAnalyzer => "database_overview_analyzer",
+        }
+    }
+
+    /// Agent named `key` in the configuration
+    pub fn from_config_key(key: &str) -> Option<AgentType> {
+        Self::all().into_iter().find(|agent| agent.config_key() == key)
+    }
 }
 
 impl Display for AgentType {
@@ -1153,6 +1184,10 @@ pub struct KeyModuleReport {
     pub flowchart_mermaid: String,
     #[serde(default, deserialize_with = "deserialize_string_lenient")]
     pub sequence_diagram_mermaid: String,
+    /// Owning teams or people of the module files, from CODEOWNERS or the git history
+    #[schemars(skip)]
+    #[serde(default)]
+    pub owners: Vec<String>,
 }
 
 /// Domain module analysis result from high-level architecture perspective
@@ -3,19 +3,37 @@ use async_trait::async_trait;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
-use crate::generator::agent_executor::{AgentExecuteParams, extract, prompt, prompt_with_tools};
+use crate::generator::agent_executor::{
+    AgentExecuteParams, extract, extract_with_model, prompt, prompt_with_model, prompt_with_tools,
+};
+use crate::generator::outlet::run_summary::{ConfidenceRetry, Escalation};
 use crate::generator::preprocess::memory::{MemoryScope, ScopedKeys};
 use crate::generator::research::memory::MemoryRetriever;
 use crate::{
+    config::{FormatterOverrides, LLMConfig},
     generator::context::GeneratorContext,
     types::{
+        architecture_meta::ArchitectureMeta,
         code::CodeInsight, code_releationship::RelationshipAnalysis,
-        project_structure::ProjectStructure, CodeAndDirectoryInsights,
+        diagram_asset::DiagramAsset,
+        entry_point::EntryPoint,
+        framework::FrameworkTags,
+        license::LicenseInfo,
+        ownership::Ownership,
+        project_structure::{LanguageStats, ProjectStructure},
+        workspace::WorkspaceModel,
+        CodeAndDirectoryInsights,
     },
     utils::project_structure_formatter::ProjectStructureFormatter,
     utils::prompt_compressor::{CompressionConfig, PromptCompressor},
+    utils::token_estimator::TokenEstimator,
 };
+use tracing::{debug, info, warn};
+
+/// Directories listed in the code ownership table
+const MAX_OWNERSHIP_ROWS: usize = 40;
 
 /// Replace time placeholders with actual time information
 /// This function replaces time placeholders in LLM responses with current actual time
@@ -61,6 +79,43 @@ impl DataSource {
         scope: MemoryScope::PREPROCESS,
         key: ScopedKeys::ORIGINAL_DOCUMENT,
     };
+    pub const WORKSPACE: DataSource = DataSource::MemoryData {
+        scope: MemoryScope::PREPROCESS,
+        key: ScopedKeys::WORKSPACE,
+    };
+    pub const FRAMEWORKS: DataSource = DataSource::MemoryData {
+        scope: MemoryScope::PREPROCESS,
+        key: ScopedKeys::FRAMEWORKS,
+    };
+    pub const TECHNOLOGY_COMPOSITION: DataSource = DataSource::MemoryData {
+        scope: MemoryScope::PREPROCESS,
+        key: ScopedKeys::LANGUAGE_STATS,
+    };
+    pub const LICENSE: DataSource = DataSource::MemoryData {
+        scope: MemoryScope::PREPROCESS,
+        key: ScopedKeys::LICENSE,
+    };
+    pub const ENTRY_POINTS: DataSource = DataSource::MemoryData {
+        scope: MemoryScope::PREPROCESS,
+        key: ScopedKeys::ENTRY_POINTS,
+    };
+    pub const ORPHAN_FILES: DataSource = DataSource::MemoryData {
+        scope: MemoryScope::PREPROCESS,
+        key: ScopedKeys::ORPHAN_FILES,
+    };
+    pub const OWNERSHIP: DataSource = DataSource::MemoryData {
+        scope: MemoryScope::PREPROCESS,
+        key: ScopedKeys::OWNERSHIP,
+    };
+    pub const DIAGRAM_ASSETS: DataSource = DataSource::MemoryData {
+        scope: MemoryScope::PREPROCESS,
+        key: ScopedKeys::DIAGRAM_ASSETS,
+    };
+    /// Architecture declared in `architecture_meta_path`, placed before all other materials
+    pub const ARCHITECTURE_META: DataSource = DataSource::MemoryData {
+        scope: MemoryScope::PREPROCESS,
+        key: ScopedKeys::ARCHITECTURE_META,
+    };
 
     /// Create a data source for specific knowledge categories
     pub fn knowledge_categories(categories: Vec<&str>) -> DataSource {
@@ -94,12 +149,12 @@ pub enum LLMCallMode {
 pub struct FormatterConfig {
     /// When file count exceeds the limit, only include folder information. If set to None, include all folders and files
     pub only_directories_when_files_more_than: Option<usize>,
-    /// Code insights display quantity limit
-    pub code_insights_limit: usize,
+    /// Share of the model context window code insights may fill
+    pub code_insights_share: f64,
     /// Whether to include source code content
     pub include_source_code: bool,
-    /// Dependency relationship display quantity limit
-    pub dependency_limit: usize,
+    /// Share of the model context window dependency relationships may fill
+    pub dependency_share: f64,
     /// README content truncation length
     pub readme_truncate_length: Option<usize>,
     /// Whether to enable smart compression
@@ -111,9 +166,9 @@ pub struct FormatterConfig {
 impl Default for FormatterConfig {
     fn default() -> Self {
         Self {
-            code_insights_limit: 25,  // Reduced from 50 to avoid 504 timeouts on large codebases
+            code_insights_share: 0.2,
             include_source_code: false,  // Disabled to reduce token usage
-            dependency_limit: 50,
+            dependency_share: 0.05,
             readme_truncate_length: Some(16384),
             enable_compression: true,
             compression_config: CompressionConfig::default(),
@@ -122,6 +177,79 @@ impl Default for FormatterConfig {
     }
 }
 
+impl FormatterConfig {
+    /// Settings of an agent with its `[formatter.<agent>]` overrides from the configuration
+    pub fn with_overrides(&self, overrides: &FormatterOverrides) -> Self {
+        let mut config = self.clone();
+        if let Some(share) = overrides.code_insights_share {
+            config.code_insights_share = share.clamp(0.0, 1.0);
+        }
+        if let Some(share) = overrides.dependency_share {
+            config.dependency_share = share.clamp(0.0, 1.0);
+        }
+        if let Some(include_source_code) = overrides.include_source_code {
+            config.include_source_code = include_source_code;
+        }
+        if let Some(enable_compression) = overrides.enable_compression {
+            config.enable_compression = enable_compression;
+        }
+        if let Some(length) = overrides.readme_truncate_length {
+            config.readme_truncate_length = (length > 0).then_some(length);
+        }
+        if let Some(files) = overrides.only_directories_when_files_more_than {
+            config.only_directories_when_files_more_than = (files > 0).then_some(files);
+        }
+        config
+    }
+
+    /// Twice the insights, dependencies and listed files, and the whole README, for re-runs
+    /// of agents that lacked context
+    pub fn expanded(&self) -> Self {
+        Self {
+            only_directories_when_files_more_than: self.only_directories_when_files_more_than.map(|limit| limit * 2),
+            code_insights_share: (self.code_insights_share * 2.0).min(MAX_SOURCE_SHARE),
+            dependency_share: (self.dependency_share * 2.0).min(MAX_SOURCE_SHARE),
+            readme_truncate_length: None,
+            ..self.clone()
+        }
+    }
+}
+
+/// Largest share of the context window a single data source may fill
+const MAX_SOURCE_SHARE: f64 = 0.5;
+
+/// Tokens a data source may fill in a prompt, counted with the tokenizer of the efficient model
+pub struct TokenBudget {
+    tokens: usize,
+    estimator: TokenEstimator,
+}
+
+impl TokenBudget {
+    /// `share` of the context window of the configured models
+    pub fn new(llm: &LLMConfig, share: f64) -> Self {
+        Self {
+            tokens: (llm.context_window() as f64 * share) as usize,
+            estimator: TokenEstimator::for_model(&llm.model_efficient),
+        }
+    }
+
+    /// Append entries to `content` while they fit, always keeping the first one; returns how
+    /// many entries were left out
+    fn fill(&self, content: &mut String, entries: impl ExactSizeIterator<Item = String>) -> usize {
+        let total = entries.len();
+        let mut used = 0;
+        for (index, entry) in entries.enumerate() {
+            let tokens = self.estimator.estimate_tokens(&entry).estimated_tokens;
+            if index > 0 && used + tokens > self.tokens {
+                return total - index;
+            }
+            used += tokens;
+            content.push_str(&entry);
+        }
+        0
+    }
+}
+
 /// Prompt template configuration
 #[derive(Debug, Clone)]
 pub struct PromptTemplate {
@@ -173,7 +301,7 @@ impl DataFormatter {
 
     /// Format code insights information (legacy — for Vec<CodeInsight>)
     #[allow(dead_code)]
-    pub fn format_code_insights(&self, insights: &[CodeInsight]) -> String {
+    pub fn format_code_insights(&self, insights: &[CodeInsight], llm: &LLMConfig) -> String {
         let config = &self.config;
 
         // First sort by importance score
@@ -185,28 +313,30 @@ impl DataFormatter {
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
 
-        let mut content = String::from("### Source Code Insights Summary\n");
-        for (i, insight) in sorted_insights
-            .iter()
-            .take(self.config.code_insights_limit)
-            .enumerate()
-        {
-            content.push_str(&format!(
+        let entries = sorted_insights.iter().enumerate().map(|(i, insight)| {
+            let mut entry = format!(
                 "{}. File `{}`, purpose type is `{}`, importance: {:.2}\n",
                 i + 1,
                 insight.code_dossier.file_path.to_string_lossy(),
                 insight.code_dossier.code_purpose,
                 insight.code_dossier.importance_score
-            ));
+            );
             if !insight.detailed_description.is_empty() {
-                content.push_str(&format!("   Detailed description: {}\n", &insight.detailed_description));
+                entry.push_str(&format!("   Detailed description: {}\n", &insight.detailed_description));
             }
             if config.include_source_code {
-                content.push_str(&format!(
+                entry.push_str(&format!(
                     "   Source code details: ```code\n{}\n```\n",
                     &insight.code_dossier.source_summary
                 ));
             }
+            entry
+        });
+
+        let mut content = String::from("### Source Code Insights Summary\n");
+        let omitted = TokenBudget::new(llm, config.code_insights_share).fill(&mut content, entries);
+        if omitted > 0 {
+            content.push_str(&format!("({} less important files omitted)\n", omitted));
         }
         content.push_str("\n");
         content
@@ -219,6 +349,7 @@ impl DataFormatter {
     pub fn format_code_and_directory_insights(
         &self,
         insights: &CodeAndDirectoryInsights,
+        llm: &LLMConfig,
     ) -> String {
         let config = &self.config;
 
@@ -236,28 +367,37 @@ impl DataFormatter {
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
 
-        let mut content = String::from("### Source Code Insights Summary\n");
-        for (i, fi) in all_files.iter().take(config.code_insights_limit).enumerate() {
-            content.push_str(&format!(
+        let entries = all_files.iter().enumerate().map(|(i, fi)| {
+            let mut entry = format!(
                 "{}. File `{}` (in `{}`), purpose type is `{:?}`, importance: {:.2}\n",
                 i + 1,
                 fi.name,
                 fi.file_path.parent().map(|p| p.to_string_lossy().to_string()).unwrap_or_default(),
                 fi.code_purpose,
                 fi.importance_score
-            ));
+            );
             if !fi.summary.is_empty() {
-                content.push_str(&format!("   Summary: {}\n", fi.summary));
+                entry.push_str(&format!("   Summary: {}\n", fi.summary));
             }
             if !fi.detailed_description.is_empty() {
-                content.push_str(&format!("   Detailed description: {}\n", fi.detailed_description));
+                entry.push_str(&format!("   Detailed description: {}\n", fi.detailed_description));
+            }
+            if fi.complexity.lines_of_code > 0 {
+                entry.push_str(&format!("   Complexity: {}\n", fi.complexity.describe()));
             }
             if config.include_source_code && !fi.source_summary.is_empty() {
-                content.push_str(&format!(
+                entry.push_str(&format!(
                     "   Source code details: ```code\n{}\n```\n",
                     fi.source_summary
                 ));
             }
+            entry
+        });
+
+        let mut content = String::from("### Source Code Insights Summary\n");
+        let omitted = TokenBudget::new(llm, config.code_insights_share).fill(&mut content, entries);
+        if omitted > 0 {
+            content.push_str(&format!("({} less important files omitted)\n", omitted));
         }
         content.push_str("\n");
         content
@@ -280,8 +420,200 @@ impl DataFormatter {
         )
     }
 
+    /// Format the workspace module layout declared by the build manifests
+    pub fn format_workspace(&self, workspace: &WorkspaceModel) -> String {
+        let mut content = String::from(
+            "### Workspace Module Layout (declared by build manifests, authoritative for module boundaries)\n",
+        );
+        for declared in &workspace.workspaces {
+            content.push_str(&format!(
+                "{} ({}):\n",
+                declared.kind.label(),
+                declared.manifest.display()
+            ));
+            for member in &declared.members {
+                content.push_str(&format!("- {} ({})\n", member.name, member.path.display()));
+            }
+        }
+        content.push('\n');
+        content
+    }
+
+    /// Format the detected frameworks with where each declares routes and wiring
+    pub fn format_frameworks(&self, frameworks: &FrameworkTags) -> String {
+        let mut content = String::from(
+            "### Detected Frameworks (from manifests and imports; look for the framework conventions below)\n",
+        );
+        for detected in &frameworks.frameworks {
+            content.push_str(&format!(
+                "- {}: {}\n",
+                detected.framework.name(),
+                detected.framework.prompt_hint()
+            ));
+        }
+        content.push('\n');
+        content
+    }
+
+    /// Format the detected entry points, where workflows and startup interfaces begin
+    pub fn format_entry_points(&self, entry_points: &[EntryPoint]) -> String {
+        let mut content =
+            String::from("### Detected Entry Points (where the programs start; begin the workflows from these files)\n");
+        for entry_point in entry_points {
+            content.push_str(&format!("- `{}`: {}\n", entry_point.path.display(), entry_point.evidence));
+        }
+        content.push('\n');
+        content
+    }
+
+    /// Format the owners of the top-level directories
+    pub fn format_ownership(&self, ownership: &Ownership) -> String {
+        let source = ownership.source.as_ref().map(|s| s.describe()).unwrap_or_default();
+        let mut content = format!("### Code Ownership ({})\n| Directory | Owners |\n|---|---|\n", source);
+        for (directory, owners) in ownership.by_directory(2).into_iter().take(MAX_OWNERSHIP_ROWS) {
+            let directory = directory.to_string_lossy();
+            content.push_str(&format!(
+                "| {} | {} |\n",
+                if directory.is_empty() { "(root)" } else { &directory },
+                owners.join(", ")
+            ));
+        }
+        content.push('\n');
+        content
+    }
+
+    /// Format the architecture declared by the project team, which overrides inferred structure
+    pub fn format_architecture_meta(&self, meta: &ArchitectureMeta) -> String {
+        let mut content = String::from(
+            "### Declared Architecture (maintained by the project team, authoritative: use these layer and module names, owners and boundaries; where the code diverges, describe the declared design and point out the divergence)\n",
+        );
+        if let Some(description) = &meta.description {
+            content.push_str(&format!("{}\n", description.trim()));
+        }
+        if !meta.layers.is_empty() {
+            content.push_str("Layers (outermost first):\n");
+            for layer in &meta.layers {
+                content.push_str(&format!("- {} [{}]", layer.name, layer.paths.join(", ")));
+                if let Some(description) = &layer.description {
+                    content.push_str(&format!(": {}", description));
+                }
+                match &layer.may_depend_on {
+                    Some(allowed) if allowed.is_empty() => content.push_str(" (depends on no other layer)"),
+                    Some(allowed) => content.push_str(&format!(" (may depend on: {})", allowed.join(", "))),
+                    None => {}
+                }
+                content.push('\n');
+            }
+        }
+        if !meta.modules.is_empty() {
+            content.push_str("Modules:\n");
+            for module in &meta.modules {
+                content.push_str(&format!("- {} [{}]", module.name, module.paths.join(", ")));
+                if let Some(layer) = &module.layer {
+                    content.push_str(&format!(", layer {}", layer));
+                }
+                if let Some(owner) = &module.owner {
+                    content.push_str(&format!(", owned by {}", owner));
+                }
+                if let Some(description) = &module.description {
+                    content.push_str(&format!(": {}", description));
+                }
+                content.push('\n');
+            }
+        }
+        if !meta.boundaries.is_empty() {
+            content.push_str("Forbidden dependencies:\n");
+            for boundary in &meta.boundaries {
+                content.push_str(&format!("- {} must not depend on {}", boundary.from, boundary.to));
+                if let Some(reason) = &boundary.reason {
+                    content.push_str(&format!(" ({})", reason));
+                }
+                content.push('\n');
+            }
+        }
+        content.push('\n');
+        content
+    }
+
+    /// Format the diagrams of the repository, copied next to the documents, so that the
+    /// writers reference them instead of redrawing them
+    pub fn format_diagram_assets(&self, diagrams: &[DiagramAsset]) -> String {
+        let mut content = String::from(
+            "### Existing Diagrams (maintained in the repository and copied to the documentation; when one shows what a section describes, reference it, e.g. \"see the existing deployment diagram\", instead of redrawing it. Embed images with `![title](path)` and link other formats with `[title](path)`, using exactly these paths)\n",
+        );
+        for diagram in diagrams {
+            content.push_str(&format!(
+                "- `{}` ({}): {}\n",
+                diagram.output_path(),
+                diagram.format.label(),
+                diagram.title
+            ));
+            if let Some(source) = &diagram.source {
+                content.push_str(&format!("```\n{}\n```\n", source.trim_end()));
+            }
+        }
+        content.push('\n');
+        content
+    }
+
+    /// Format the source files that no other file imports
+    pub fn format_orphan_files(&self, orphan_files: &[PathBuf]) -> String {
+        let mut content = String::from(
+            "### Unreferenced Source Files (not imported by any project file; dead code candidates, verify before removal)\n",
+        );
+        for path in orphan_files {
+            content.push_str(&format!("- `{}`\n", path.display()));
+        }
+        content.push('\n');
+        content
+    }
+
+    /// Format the detected license; an empty result tells the model not to name any license
+    pub fn format_license(&self, license: &LicenseInfo) -> String {
+        let mut content = String::from("### Project License (detected from the repository, state exactly this)\n");
+        if license.is_empty() {
+            content.push_str(
+                "No license file or SPDX header was found. Do not name a license; say that none is declared.\n",
+            );
+        }
+        for file in &license.files {
+            content.push_str(&format!(
+                "- License file `{}`: {}\n",
+                file.path.display(),
+                file.spdx_id.as_deref().unwrap_or("unrecognized license text")
+            ));
+        }
+        for header in &license.spdx_headers {
+            content.push_str(&format!(
+                "- SPDX-License-Identifier `{}` in {} source files\n",
+                header.expression, header.files
+            ));
+        }
+        content.push('\n');
+        content
+    }
+
+    /// Format the measured files and source lines per language as a table with shares
+    pub fn format_technology_composition(&self, stats: &[LanguageStats]) -> String {
+        let total_lines: usize = stats.iter().map(|s| s.lines).sum();
+        let mut content = String::from(
+            "### Technology Composition (measured, use these exact numbers)\n| Language | Files | Lines | Share |\n|---|---|---|---|\n",
+        );
+        for language in stats {
+            content.push_str(&format!(
+                "| {} | {} | {} | {:.1}% |\n",
+                language.language,
+                language.files,
+                language.lines,
+                language.lines as f64 * 100.0 / total_lines.max(1) as f64
+            ));
+        }
+        content.push('\n');
+        content
+    }
+
     /// Format dependency relationship analysis
-    pub fn format_dependency_analysis(&self, deps: &RelationshipAnalysis) -> String {
+    pub fn format_dependency_analysis(&self, deps: &RelationshipAnalysis, llm: &LLMConfig) -> String {
         let mut content = String::from("### Dependency Relationship Analysis\n");
 
         // Sort by dependency strength, prioritize important dependencies
@@ -293,13 +625,12 @@ impl DataFormatter {
             b_priority.cmp(&a_priority)
         });
 
-        for rel in sorted_deps.iter().take(self.config.dependency_limit) {
-            content.push_str(&format!(
-                "{} -> {} ({})\n",
-                rel.from,
-                rel.to,
-                rel.dependency_type.as_str()
-            ));
+        let entries = sorted_deps
+            .iter()
+            .map(|rel| format!("{} -> {} ({})\n", rel.from, rel.to, rel.dependency_type.as_str()));
+        let omitted = TokenBudget::new(llm, self.config.dependency_share).fill(&mut content, entries);
+        if omitted > 0 {
+            content.push_str(&format!("({} weaker dependencies omitted)\n", omitted));
         }
         content.push_str("\n");
         content
@@ -337,7 +668,7 @@ impl DataFormatter {
                     truncated)
         };
 
-        println!("   🚨 Emergency truncation for [{}]: reduced from {} to {} characters",
+        warn!("   🚨 Emergency truncation for [{}]: reduced from {} to {} characters",
                 content_type, content.len(), result.len());
 
         Ok(result)
@@ -386,14 +717,27 @@ impl DataFormatter {
             {
                 Ok(compression_result) => {
                     if compression_result.was_compressed {
-                        println!("   📊 {}", compression_result.compression_summary);
+                        debug!("   📊 {}", compression_result.compression_summary);
                     }
                     Ok(compression_result.compressed_content)
                 }
                 Err(e) => {
-                    // If compression fails, try to truncate content to a reasonable size
-                    println!("   ⚠️ Compression failed for [{}]: {}, attempting emergency truncation", content_type, e);
-                    self.emergency_truncate(content, content_type)
+                    // Content too large for one compression call is summarized in batches;
+                    // truncation is the last resort when that fails too
+                    warn!("   ⚠️ Compression failed for [{}]: {}, summarizing it in batches", content_type, e);
+                    match compressor.map_reduce(context, content, content_type).await {
+                        Ok(result) => {
+                            debug!("   📊 {}", result.compression_summary);
+                            Ok(result.compressed_content)
+                        }
+                        Err(e) => {
+                            warn!(
+                                "   ⚠️ Map-reduce summarization failed for [{}]: {}, attempting emergency truncation",
+                                content_type, e
+                            );
+                            self.emergency_truncate(content, content_type)
+                        }
+                    }
                 }
             }
         } else {
@@ -470,6 +814,18 @@ impl GeneratorPromptBuilder {
             prompt.push_str("\n");
         }
 
+        // The declared architecture comes first: it takes precedence over everything inferred below
+        let declares_architecture = data_sources.iter().any(|source| {
+            matches!(source, DataSource::MemoryData { key, .. } if *key == ScopedKeys::ARCHITECTURE_META)
+        });
+        if declares_architecture
+            && let Some(meta) = context
+                .get_from_memory::<ArchitectureMeta>(MemoryScope::PREPROCESS, ScopedKeys::ARCHITECTURE_META)
+                .await
+        {
+            prompt.push_str(&self.formatter.format_architecture_meta(&meta));
+        }
+
         // Collect and format various data sources
         let mut research_results = HashMap::new();
 
@@ -491,10 +847,10 @@ impl GeneratorPromptBuilder {
                     }
                     ScopedKeys::CODE_INSIGHTS => {
                         if let Some(insights) = context
-                            .get_from_memory::<CodeAndDirectoryInsights>(scope, key)
+                            .get_shared_from_memory::<CodeAndDirectoryInsights>(scope, key)
                             .await
                         {
-                            let formatted = self.formatter.format_code_and_directory_insights(&insights);
+                            let formatted = self.formatter.format_code_and_directory_insights(&insights, &context.config.llm);
                             let compressed = self
                                 .formatter
                                 .compress_content_if_needed(context, &formatted, "Code Insights")
@@ -512,12 +868,80 @@ impl GeneratorPromptBuilder {
                             prompt.push_str(&compressed);
                         }
                     }
+                    ScopedKeys::WORKSPACE => {
+                        if let Some(workspace) = context
+                            .get_from_memory::<WorkspaceModel>(scope, key)
+                            .await
+                            .filter(|workspace| !workspace.is_empty())
+                        {
+                            prompt.push_str(&self.formatter.format_workspace(&workspace));
+                        }
+                    }
+                    ScopedKeys::FRAMEWORKS => {
+                        if let Some(frameworks) = context
+                            .get_from_memory::<FrameworkTags>(scope, key)
+                            .await
+                            .filter(|frameworks| !frameworks.is_empty())
+                        {
+                            prompt.push_str(&self.formatter.format_frameworks(&frameworks));
+                        }
+                    }
+                    ScopedKeys::ENTRY_POINTS => {
+                        if let Some(entry_points) = context
+                            .get_from_memory::<Vec<EntryPoint>>(scope, key)
+                            .await
+                            .filter(|entry_points| !entry_points.is_empty())
+                        {
+                            prompt.push_str(&self.formatter.format_entry_points(&entry_points));
+                        }
+                    }
+                    ScopedKeys::OWNERSHIP => {
+                        if let Some(ownership) = context
+                            .get_from_memory::<Ownership>(scope, key)
+                            .await
+                            .filter(|ownership| !ownership.is_empty())
+                        {
+                            prompt.push_str(&self.formatter.format_ownership(&ownership));
+                        }
+                    }
+                    ScopedKeys::DIAGRAM_ASSETS => {
+                        if let Some(diagrams) = context
+                            .get_from_memory::<Vec<DiagramAsset>>(scope, key)
+                            .await
+                            .filter(|diagrams| !diagrams.is_empty())
+                        {
+                            prompt.push_str(&self.formatter.format_diagram_assets(&diagrams));
+                        }
+                    }
+                    ScopedKeys::ORPHAN_FILES => {
+                        if let Some(orphan_files) = context
+                            .get_from_memory::<Vec<PathBuf>>(scope, key)
+                            .await
+                            .filter(|orphan_files| !orphan_files.is_empty())
+                        {
+                            prompt.push_str(&self.formatter.format_orphan_files(&orphan_files));
+                        }
+                    }
+                    ScopedKeys::LICENSE => {
+                        if let Some(license) = context.get_from_memory::<LicenseInfo>(scope, key).await {
+                            prompt.push_str(&self.formatter.format_license(&license));
+                        }
+                    }
+                    ScopedKeys::LANGUAGE_STATS => {
+                        if let Some(stats) = context
+                            .get_from_memory::<Vec<LanguageStats>>(scope, key)
+                            .await
+                            .filter(|stats| !stats.is_empty())
+                        {
+                            prompt.push_str(&self.formatter.format_technology_composition(&stats));
+                        }
+                    }
                     ScopedKeys::RELATIONSHIPS => {
                         if let Some(deps) = context
                             .get_from_memory::<RelationshipAnalysis>(scope, key)
                             .await
                         {
-                            let formatted = self.formatter.format_dependency_analysis(&deps);
+                            let formatted = self.formatter.format_dependency_analysis(&deps, &context.config.llm);
                             let compressed = self
                                 .formatter
                                 .compress_content_if_needed(context, &formatted, "Dependencies")
@@ -536,7 +960,11 @@ impl GeneratorPromptBuilder {
                     // Load external knowledge from specific categories
                     let category_refs: Vec<&str> = categories.iter().map(|s| s.as_str()).collect();
                     if let Some(knowledge) = context
-                        .load_external_knowledge_by_categories(&category_refs, agent_filter)
+                        .load_external_knowledge_by_categories(
+                            &category_refs,
+                            agent_filter,
+                            &self.template.opening_instruction,
+                        )
                         .await
                     {
                         let cat_names = categories.join(", ");
@@ -571,6 +999,22 @@ impl GeneratorPromptBuilder {
     }
 }
 
+/// Prompts of an agent with twice the context, for re-runs with the powerful model
+async fn expanded_prompts(
+    context: &GeneratorContext,
+    template: &PromptTemplate,
+    data_sources: &[DataSource],
+    custom_content: Option<String>,
+    include_timestamp: bool,
+    agent: &str,
+) -> Result<(String, String)> {
+    let mut expanded = template.clone();
+    expanded.formatter_config = expanded.formatter_config.expanded();
+    GeneratorPromptBuilder::new(expanded)
+        .build_prompts(context, data_sources, custom_content, include_timestamp, Some(agent))
+        .await
+}
+
 /// Minimal Agent trait - Greatly simplifies agent implementation
 #[async_trait]
 pub trait StepForwardAgent: Send + Sync {
@@ -585,6 +1029,12 @@ pub trait StepForwardAgent: Send + Sync {
         None
     }
 
+    /// Name of the agent in the configuration, whose `[formatter.<name>]` section overrides
+    /// its data formatting
+    fn config_key(&self) -> Option<String> {
+        self.agent_type_enum().map(|agent_type| agent_type.config_key().to_string())
+    }
+
     fn memory_scope_key(&self) -> String;
 
     /// Data source configuration
@@ -598,6 +1048,18 @@ pub trait StepForwardAgent: Send + Sync {
         Ok(())
     }
 
+    /// Deterministic adjustments to an extracted result before it is stored, such as facts
+    /// found by static analysis that the LLM left out
+    async fn augment(&self, result: Self::Output, _context: &GeneratorContext) -> Result<Self::Output> {
+        Ok(result)
+    }
+
+    /// Confidence score (0-10) the agent reports for its result, checked against
+    /// `[confidence] min_score`
+    fn confidence(&self, _result: &Self::Output) -> Option<f64> {
+        None
+    }
+
     /// Optional custom prompt content provider hook
     /// Returns custom prompt content, will be inserted into the research materials reference section of standard prompt
     async fn provide_custom_prompt_content(&self, _context: &GeneratorContext) -> Result<Option<String>> {
@@ -616,6 +1078,16 @@ pub trait StepForwardAgent: Send + Sync {
         let config = self.data_config();
         let agent_type_value = self.agent_type();
 
+        // A resumed run reuses the results the interrupted run already stored
+        if context.config.resume
+            && let Some(result) = context
+                .get_from_memory::<Self::Output>(&self.memory_scope_key(), &agent_type_value)
+                .await
+        {
+            info!("♻️  Reusing {} from the memory snapshot", agent_type_value);
+            return Ok(result);
+        }
+
         // 2. Check if required data sources are available (automatic validation)
         for source in &config.required_sources {
             match source {
@@ -639,7 +1111,10 @@ pub trait StepForwardAgent: Send + Sync {
         let all_sources = [config.required_sources, config.optional_sources].concat();
 
         // 4. Build prompt using standard template and adjust according to target language
-        let template = self.prompt_template();
+        let mut template = self.prompt_template();
+        if let Some(overrides) = self.config_key().and_then(|key| context.config.formatter.get(&key)) {
+            template.formatter_config = template.formatter_config.with_overrides(overrides);
+        }
 
         // Add language instruction based on configured target language
         let language_instruction = context.config.target_language.prompt_instruction();
@@ -653,7 +1128,7 @@ pub trait StepForwardAgent: Send + Sync {
         let include_timestamp = self.should_include_timestamp();
 
         let (system_prompt, user_prompt) = prompt_builder
-            .build_prompts(context, &all_sources, custom_content, include_timestamp, Some(agent_type_value.as_str()))
+            .build_prompts(context, &all_sources, custom_content.clone(), include_timestamp, Some(agent_type_value.as_str()))
             .await?;
 
         let system_prompt = format!("{}\n\n{}", system_prompt, language_instruction);
@@ -662,32 +1137,182 @@ pub trait StepForwardAgent: Send + Sync {
         // 5. Select LLM invocation method based on configuration
         // Use localized agent name for log_tag if available
         let log_tag = if let Some(agent_enum) = self.agent_type_enum() {
-            agent_enum.display_name(&context.config.target_language)
+            agent_enum.display_name(context.config.ui_language())
         } else {
             agent_type_value.clone()
         };
 
+        let cache_scope = format!("{}/{}", self.memory_scope_key(), agent_type_value.as_str());
         let params = AgentExecuteParams {
             prompt_sys: system_prompt,
             prompt_user: user_prompt,
-            cache_scope: format!("{}/{}", self.memory_scope_key(), agent_type_value.as_str()),
-            log_tag,
+            cache_scope: cache_scope.clone(),
+            log_tag: log_tag.clone(),
             progress: None,
         };
 
+        let powerful_model = &context.config.llm.model_powerful;
         let result_value = match template.llm_call_mode {
             LLMCallMode::Extract => {
-                let result: Self::Output = extract(context, params).await?;
+                // Output failing schema validation re-runs once with the powerful model and twice the context
+                let mut result: Self::Output = match extract(context, params).await {
+                    Ok(result) => result,
+                    Err(e) if context.config.escalation.enabled => {
+                        warn!(
+                            "⚠️ [{}] output was not usable ({}), re-running with {} and expanded context",
+                            log_tag, e, powerful_model
+                        );
+                        let (system_prompt, user_prompt) = expanded_prompts(
+                            context,
+                            &template,
+                            &all_sources,
+                            custom_content.clone(),
+                            include_timestamp,
+                            &agent_type_value,
+                        )
+                        .await?;
+                        let params = AgentExecuteParams {
+                            prompt_sys: format!("{}\n\n{}", system_prompt, language_instruction),
+                            prompt_user: format!(
+                                "{}\n\n**Notice** A previous attempt at this analysis failed with: \"{}\". Return output matching the requested structure exactly.\n\n{}",
+                                user_prompt, e, language_instruction
+                            ),
+                            cache_scope: cache_scope.clone(),
+                            log_tag: format!("{} (escalated)", log_tag),
+                            progress: None,
+                        };
+                        let retried = extract_with_model::<Self::Output>(context, params, powerful_model).await;
+                        context
+                            .record_escalation(Escalation {
+                                agent: agent_type_value.clone(),
+                                reason: format!("invalid output: {}", e),
+                                succeeded: retried.is_ok(),
+                            })
+                            .await?;
+                        retried?
+                    }
+                    Err(e) => return Err(e),
+                };
+
+                // Low-confidence reports re-run with the powerful model and twice the context,
+                // keeping the best-scoring report
+                let settings = &context.config.confidence;
+                if let Some(min_score) = settings.min_score
+                    && let Some(initial_score) = self.confidence(&result)
+                    && initial_score < min_score
+                    && settings.max_retries > 0
+                {
+                    warn!(
+                        "⚠️ [{}] reported confidence {:.1}/10, below the minimum {:.1}, re-running with {} and expanded context",
+                        log_tag, initial_score, min_score, context.config.llm.model_powerful
+                    );
+                    let (system_prompt, user_prompt) = expanded_prompts(
+                        context,
+                        &template,
+                        &all_sources,
+                        custom_content,
+                        include_timestamp,
+                        &agent_type_value,
+                    )
+                    .await?;
+
+                    let mut best_score = initial_score;
+                    let mut attempts = 0;
+                    while attempts < settings.max_retries && best_score < min_score {
+                        attempts += 1;
+                        let params = AgentExecuteParams {
+                            prompt_sys: format!("{}\n\n{}", system_prompt, language_instruction),
+                            prompt_user: format!(
+                                "{}\n\n**Notice** Re-run {}: a previous analysis of this material reported a confidence of only {:.1}/10. Examine the expanded material thoroughly and base every conclusion on it.\n\n{}",
+                                user_prompt, attempts, best_score, language_instruction
+                            ),
+                            cache_scope: cache_scope.clone(),
+                            log_tag: format!("{} (re-run {})", log_tag, attempts),
+                            progress: None,
+                        };
+                        match extract_with_model::<Self::Output>(context, params, powerful_model).await {
+                            Ok(retried) => {
+                                let score = self.confidence(&retried).unwrap_or_default();
+                                info!("   🔁 [{}] re-run {} confidence: {:.1}/10", log_tag, attempts, score);
+                                if score > best_score {
+                                    best_score = score;
+                                    result = retried;
+                                }
+                            }
+                            Err(e) => {
+                                warn!("⚠️ [{}] re-run {} failed: {}", log_tag, attempts, e);
+                                break;
+                            }
+                        }
+                    }
+                    context
+                        .record_confidence_retry(ConfidenceRetry {
+                            agent: agent_type_value.clone(),
+                            initial_score,
+                            final_score: best_score,
+                            attempts,
+                            met_threshold: best_score >= min_score,
+                        })
+                        .await?;
+                }
+                let result = self.augment(result, context).await?;
                 serde_json::to_value(&result)?
             }
-            LLMCallMode::Prompt => {
-                let result_text: String = prompt(context, params).await?;
-                // Replace time placeholders
-                let processed_text = replace_time_placeholders(&result_text);
-                serde_json::to_value(&processed_text)?
-            }
-            LLMCallMode::PromptWithTools => {
-                let result_text: String = prompt_with_tools(context, params).await?;
+            LLMCallMode::Prompt | LLMCallMode::PromptWithTools => {
+                let mut result_text: String = if template.llm_call_mode == LLMCallMode::Prompt {
+                    prompt(context, params).await?
+                } else {
+                    prompt_with_tools(context, params).await?
+                };
+
+                // Suspiciously short reports and documents re-run once with the powerful model
+                // and twice the context, keeping the longer output
+                let escalation = &context.config.escalation;
+                let length = result_text.trim().chars().count();
+                if escalation.enabled && length < escalation.min_output_chars {
+                    warn!(
+                        "⚠️ [{}] output is suspiciously short ({} characters), re-running with {} and expanded context",
+                        log_tag, length, powerful_model
+                    );
+                    let (system_prompt, user_prompt) = expanded_prompts(
+                        context,
+                        &template,
+                        &all_sources,
+                        custom_content,
+                        include_timestamp,
+                        &agent_type_value,
+                    )
+                    .await?;
+                    let params = AgentExecuteParams {
+                        prompt_sys: format!("{}\n\n{}", system_prompt, language_instruction),
+                        prompt_user: format!(
+                            "{}\n\n**Notice** A previous answer to this request was only {} characters long. Cover all of the material above completely.\n\n{}",
+                            user_prompt, length, language_instruction
+                        ),
+                        cache_scope: cache_scope.clone(),
+                        log_tag: format!("{} (escalated)", log_tag),
+                        progress: None,
+                    };
+                    let succeeded = match prompt_with_model(context, params, powerful_model).await {
+                        Ok(retried) if retried.trim().chars().count() > length => {
+                            result_text = retried;
+                            true
+                        }
+                        Ok(_) => false,
+                        Err(e) => {
+                            warn!("⚠️ [{}] escalated re-run failed: {}", log_tag, e);
+                            false
+                        }
+                    };
+                    context
+                        .record_escalation(Escalation {
+                            agent: agent_type_value.clone(),
+                            reason: format!("output of {} characters", length),
+                            succeeded,
+                        })
+                        .await?;
+                }
+
                 // Replace time placeholders
                 let processed_text = replace_time_placeholders(&result_text);
                 serde_json::to_value(&processed_text)?
@@ -708,14 +1333,89 @@ pub trait StepForwardAgent: Send + Sync {
             self.post_process(&typed_result, context)?;
             // Use localized agent name if available
             let agent_name = if let Some(agent_enum) = self.agent_type_enum() {
-                agent_enum.display_name(&context.config.target_language)
+                agent_enum.display_name(context.config.ui_language())
             } else {
                 agent_type_value.clone()
             };
-            println!("✅ Sub-Agent [{}] execution completed", agent_name);
+            info!("✅ Sub-Agent [{}] execution completed", agent_name);
             Ok(typed_result)
         } else {
             Err(anyhow::format_err!(""))
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, LLMProvider};
+    use crate::generator::outlet::RunSummary;
+    use crate::generator::workflow::create_context;
+
+    struct ShortEditor;
+
+    #[async_trait]
+    impl StepForwardAgent for ShortEditor {
+        type Output = String;
+
+        fn agent_type(&self) -> String {
+            "Short Editor".to_string()
+        }
+
+        fn memory_scope_key(&self) -> String {
+            "documentation".to_string()
+        }
+
+        fn data_config(&self) -> AgentDataConfig {
+            AgentDataConfig {
+                required_sources: Vec::new(),
+                optional_sources: Vec::new(),
+            }
+        }
+
+        fn prompt_template(&self) -> PromptTemplate {
+            PromptTemplate {
+                system_prompt: "You write documentation.".to_string(),
+                opening_instruction: "Document the project.".to_string(),
+                closing_instruction: String::new(),
+                llm_call_mode: LLMCallMode::Prompt,
+                formatter_config: FormatterConfig::default(),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_short_output_is_escalated() {
+        let temp = tempfile::tempdir().unwrap();
+        let mut config = Config {
+            project_path: temp.path().join("project"),
+            output_path: temp.path().join("docs"),
+            internal_path: temp.path().join(".litho"),
+            ..Default::default()
+        };
+        config.llm.provider = LLMProvider::Mock;
+        config.llm.api_key = "mock".to_string();
+        config.cache.enabled = false;
+
+        // The mock document is long enough for the default minimum
+        let context = create_context(&config).unwrap();
+        let document = ShortEditor.execute(&context).await.unwrap();
+        assert!(RunSummary::collect(&context, None).await.escalations.is_empty());
+
+        // A shorter re-run does not replace the first output, and the escalation is reported
+        let length = document.trim().chars().count();
+        config.escalation.min_output_chars = length + 1;
+        let context = create_context(&config).unwrap();
+        assert_eq!(ShortEditor.execute(&context).await.unwrap(), document);
+        let escalations = RunSummary::collect(&context, None).await.escalations;
+        assert_eq!(escalations.len(), 1);
+        assert_eq!(escalations[0].agent, "Short Editor");
+        assert_eq!(escalations[0].reason, format!("output of {} characters", length));
+        assert!(!escalations[0].succeeded);
+
+        config.escalation.enabled = false;
+        let context = create_context(&config).unwrap();
+        ShortEditor.execute(&context).await.unwrap();
+        assert!(RunSummary::collect(&context, None).await.escalations.is_empty());
+    }
+}
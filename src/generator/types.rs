@@ -2,6 +2,7 @@ use anyhow::Result;
 
 use crate::generator::context::GeneratorContext;
 
+#[allow(async_fn_in_trait, reason = "generators are awaited by the crate's own workflow, which needs no `Send` bound")]
 pub trait Generator<T> {
     async fn execute(&self, context: GeneratorContext) -> Result<T>;
 }
@@ -2,7 +2,10 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use crate::generator::compose::DocumentationComposer;
-use crate::generator::outlet::{DiskOutlet, DocTree, Outlet, SummaryOutlet};
+use crate::generator::preprocess::memory::{MemoryScope as PreprocessScope, ScopedKeys};
+use crate::generator::outlet::{
+    DiskOutlet, DocTree, IndexOutlet, LinkCheckOutlet, Outlet, OutputVersion, RunProfile, RunStatus, RunSummary, SummaryOutlet, TokenUsageReport,
+};
 use crate::{
     cache::CacheManager,
     config::Config,
@@ -12,9 +15,12 @@ use crate::{
     },
     llm::client::LLMClient,
     memory::Memory,
+    types::architecture_meta::ArchitectureMeta,
+    utils::{archive, encoding, git_worktree, secret_redactor::RedactionLog},
 };
-use anyhow::Result;
+use anyhow::{Result, bail};
 use tokio::sync::RwLock;
+use tracing::{error, info, warn};
 
 /// Memory scope and key definitions for workflow timing statistics
 pub struct TimingScope;
@@ -40,97 +46,258 @@ impl TimingKeys {
     pub const DOCUMENT_GENERATION: &'static str = "document_generation";
     /// Total execution time
     pub const TOTAL_EXECUTION: &'static str = "total_execution";
+    /// Durations of the items of each stage (directories, agents, editors, outlets)
+    pub const PROFILE: &'static str = "profile";
 }
 
-pub async fn launch(c: &Config) -> Result<()> {
-    let overall_start = Instant::now();
-
-    let config = c.clone();
+/// Run the whole documentation workflow, write `run-summary.json` and notify the configured webhooks
+pub async fn launch(c: &Config) -> Result<RunStatus> {
+    // A versioned run writes to its own directory under the output path
+    let version = OutputVersion::for_run(c)?;
+    let config = match &version {
+        Some(version) => version.apply(c),
+        None => c.clone(),
+    };
+    let result = launch_run(&config).await;
+    if let Some(version) = &version {
+        let status = result.as_ref().copied().unwrap_or(RunStatus::Failure);
+        if let Err(e) = version.record(c, status) {
+            warn!("⚠️  Failed to record documentation version {}: {}", version.label, e);
+        }
+    }
+    crate::integrations::notifications::notify_run_finished(&config, &result).await;
+    result
+}
 
-    // Check mermaid-fixer availability at startup
-    if !crate::generator::outlet::MermaidFixer::is_available().await {
-        anyhow::bail!("mermaid-fixer is not installed. Run 'cargo install mermaid-fixer' to install it");
+async fn launch_run(c: &Config) -> Result<RunStatus> {
+    // An archive is documented from a temporary workspace, removed when the run ends
+    let extracted = if archive::is_archive(&c.project_path) {
+        Some(archive::extract(&c.project_path)?)
+    } else {
+        None
+    };
+    let config = match &extracted {
+        Some(extracted) => c.for_extracted_archive(extracted.project_root()),
+        None => c.clone(),
+    };
+    // A revision is documented from a temporary worktree, removed when the run ends
+    let worktree = match &config.git_ref {
+        Some(_) if extracted.is_some() => bail!("A git ref cannot be documented from an archive"),
+        Some(git_ref) => Some(git_worktree::checkout(&config.project_path, git_ref)?),
+        None => None,
+    };
+    let config = match &worktree {
+        Some(worktree) => config.for_git_ref(worktree.project_root()),
+        None => config,
+    };
+    if !config.projects.is_empty() {
+        return crate::generator::monorepo::launch_monorepo(&config).await;
     }
 
-    let llm_client = LLMClient::new(config.clone())?;
+    let context = create_context(&config)?;
+    run_project(&context).await
+}
+
+/// Create a fresh generator context (LLM client, cache and memory) for a configuration
+pub fn create_context(c: &Config) -> Result<GeneratorContext> {
+    let config = c.clone();
+
+    let redactions = Arc::new(RedactionLog::default());
+    let llm_client = LLMClient::new(config.clone(), redactions.clone())?;
     let cache_manager = Arc::new(RwLock::new(CacheManager::new(
         config.cache.clone(),
-        config.target_language.clone(),
+        config.ui_language().clone(),
     )));
-    let memory = Arc::new(RwLock::new(Memory::new()));
+    let memory_dir = config.memory_dir();
+    let memory = if config.resume {
+        match Memory::resume(&memory_dir) {
+            Ok(memory) => {
+                info!("♻️  Resuming from memory snapshot {}", memory_dir.display());
+                memory
+            }
+            Err(e) => {
+                warn!("⚠️  No usable memory snapshot to resume from ({}), starting fresh", e);
+                Memory::persistent(&memory_dir)
+            }
+        }
+    } else {
+        Memory::persistent(&memory_dir)
+    };
+    let memory = Arc::new(RwLock::new(memory.with_limits(&config.memory)));
 
-    let context = GeneratorContext {
+    Ok(GeneratorContext {
         llm_client,
         config,
         cache_manager,
         memory,
-    };
+        redactions,
+    })
+}
+
+/// Document a single project and write its run summary
+pub async fn run_project(context: &GeneratorContext) -> Result<RunStatus> {
+    let overall_start = Instant::now();
+    encoding::take_transcoded();
+    let result = run_stages(context, overall_start).await;
+
+    // Write the run summary even when the run was aborted
+    let summary = RunSummary::collect(context, result.as_ref().err().map(|e| format!("{:#}", e))).await;
+    if let Err(e) = summary.save(context) {
+        warn!("⚠️  Failed to save run summary: {}", e);
+    }
+    if let (Ok(_), Some(threshold_error)) = (&result, &summary.error) {
+        error!("❌ {}", threshold_error);
+    }
+    if let Err(e) = RunProfile::collect(context).await.save(context) {
+        warn!("⚠️  Failed to save run profile: {}", e);
+    }
+    if let Err(e) = TokenUsageReport::collect(context).save(context) {
+        warn!("⚠️  Failed to save token usage: {}", e);
+    }
+    match context.redactions.save_report(&context.config.output_path) {
+        Ok(Some(path)) => info!("💾 Saved redaction report: {}", path.display()),
+        Ok(None) => {}
+        Err(e) => warn!("⚠️  Failed to save redaction report: {}", e),
+    }
+    match encoding::save_report(&context.config.output_path) {
+        Ok(Some(path)) => info!("💾 Saved encoding report: {}", path.display()),
+        Ok(None) => {}
+        Err(e) => warn!("⚠️  Failed to save encoding report: {}", e),
+    }
+
+    // Keep this run's memory snapshot for `memory-diff`
+    let config = &context.config;
+    match Memory::archive_snapshot(&config.memory_dir(), &config.runs_dir(), config.memory.keep_runs) {
+        Ok(run_dir) => info!("🗃️  Archived memory snapshot to {}", run_dir.display()),
+        Err(e) => warn!("⚠️  Failed to archive memory snapshot: {}", e),
+    }
+
+    result.map(|_| summary.status)
+}
+
+/// Execute all workflow stages in order
+async fn run_stages(context: &GeneratorContext, overall_start: Instant) -> Result<()> {
+    // The declared architecture is validated before any work, so a broken file fails fast
+    if let Some(path) = &context.config.architecture_meta_path {
+        let meta = ArchitectureMeta::load(path, &context.config.project_path)?;
+        info!(
+            "🏛️  Declared architecture: {} layers, {} modules, {} boundaries",
+            meta.layers.len(),
+            meta.modules.len(),
+            meta.boundaries.len()
+        );
+        context
+            .store_to_memory(PreprocessScope::PREPROCESS, ScopedKeys::ARCHITECTURE_META, meta)
+            .await?;
+    }
 
     // Sync external knowledge if configured
     if let Ok(syncer) = crate::integrations::KnowledgeSyncer::new(context.config.clone()) {
         if syncer.should_sync().unwrap_or(false) {
-            println!("\n=== Syncing external knowledge sources ===");
+            info!("=== Syncing external knowledge sources ===");
             if let Err(e) = syncer.sync_all().await {
-                eprintln!("⚠️  Warning: Failed to sync external knowledge: {}", e);
+                warn!("⚠️  Warning: Failed to sync external knowledge: {}", e);
+                context
+                    .record_failure("knowledge_sync", "external knowledge", &e.to_string())
+                    .await?;
             }
         } else {
             let lang = context.config.target_language.display_name();
-            println!("ℹ️  External knowledge cache ({}) is up to date", lang);
+            info!("ℹ️  External knowledge cache ({}) is up to date", lang);
         }
     }
 
     // Preprocessing stage
+    context.llm_client.set_usage_phase(TimingKeys::PREPROCESS);
     let preprocess_start = Instant::now();
-    let preprocess_agent = PreProcessAgent::new();
-    preprocess_agent.execute(context.clone()).await?;
+    if context.config.resume
+        && context
+            .has_memory_data(PreprocessScope::PREPROCESS, ScopedKeys::RELATIONSHIPS)
+            .await
+    {
+        info!("♻️  Reusing preprocessing results from the memory snapshot");
+    } else {
+        let preprocess_agent = PreProcessAgent::new();
+        preprocess_agent.execute(context.clone()).await?;
+    }
     let preprocess_time = preprocess_start.elapsed().as_secs_f64();
     context
         .store_to_memory(TimingScope::TIMING, TimingKeys::PREPROCESS, preprocess_time)
         .await?;
-    println!(
+    info!(
         "=== Preprocessing completed, results stored to Memory (Duration: {:.2}s) ===",
         preprocess_time
     );
 
     // Execute multi-agent research stage
+    context.llm_client.set_usage_phase(TimingKeys::RESEARCH);
     let research_start = Instant::now();
     let research_orchestrator = ResearchOrchestrator::default();
     research_orchestrator
-        .execute_research_pipeline(&context)
+        .execute_research_pipeline(context)
         .await?;
     let research_time = research_start.elapsed().as_secs_f64();
     context
         .store_to_memory(TimingScope::TIMING, TimingKeys::RESEARCH, research_time)
         .await?;
-    println!("\n=== Project in-depth research completed (Duration: {:.2}s) ===", research_time);
+    info!("=== Project in-depth research completed (Duration: {:.2}s) ===", research_time);
 
     // Execute document generation process
+    context.llm_client.set_usage_phase(TimingKeys::COMPOSE);
     let compose_start = Instant::now();
-    let mut doc_tree = DocTree::new(&context.config.target_language);
+    let mut doc_tree = DocTree::new(&context.config);
     let documentation_orchestrator = DocumentationComposer::default();
     documentation_orchestrator
-        .execute(&context, &mut doc_tree)
+        .execute(context, &mut doc_tree)
         .await?;
     let compose_time = compose_start.elapsed().as_secs_f64();
     context
         .store_to_memory(TimingScope::TIMING, TimingKeys::COMPOSE, compose_time)
         .await?;
-    println!("\n=== Document generation completed (Duration: {:.2}s) ===", compose_time);
+    info!("=== Document generation completed (Duration: {:.2}s) ===", compose_time);
 
     // Execute document storage
+    context.llm_client.set_usage_phase(TimingKeys::OUTPUT);
     let output_start = Instant::now();
     let outlet = DiskOutlet::new(doc_tree);
-    outlet.save(&context).await?;
+    outlet.save(context).await?;
+    context
+        .record_timing(TimingKeys::OUTPUT, "documents", output_start.elapsed().as_secs_f64())
+        .await?;
 
     // Generate and save summary report
+    let summary_start = Instant::now();
     let summary_outlet = SummaryOutlet::new();
-    summary_outlet.save(&context).await?;
+    summary_outlet.save(context).await?;
+    context
+        .record_timing(TimingKeys::OUTPUT, "summary report", summary_start.elapsed().as_secs_f64())
+        .await?;
+
+    // Verify the links of the written documents, repairing them when configured
+    let link_check_start = Instant::now();
+    LinkCheckOutlet::new().save(context).await?;
+    context
+        .record_timing(TimingKeys::OUTPUT, "link check", link_check_start.elapsed().as_secs_f64())
+        .await?;
+
+    // Embed insights and documents for retrieval; the docs are already written, so only warn
+    let index_start = Instant::now();
+    let index_result = IndexOutlet::new().save(context).await;
+    context
+        .record_timing(TimingKeys::OUTPUT, "vector index", index_start.elapsed().as_secs_f64())
+        .await?;
+    if let Err(e) = index_result {
+        warn!("⚠️  Failed to update vector index: {:#}", e);
+        context
+            .record_failure("index", "vector index", &format!("{:#}", e))
+            .await?;
+    }
 
     let output_time = output_start.elapsed().as_secs_f64();
     context
         .store_to_memory(TimingScope::TIMING, TimingKeys::OUTPUT, output_time)
         .await?;
-    println!("\n=== Document storage completed (Duration: {:.2}s) ===", output_time);
+    info!("=== Document storage completed (Duration: {:.2}s) ===", output_time);
 
     // Record total execution time
     let total_time = overall_start.elapsed().as_secs_f64();
@@ -138,7 +305,7 @@ pub async fn launch(c: &Config) -> Result<()> {
         .store_to_memory(TimingScope::TIMING, TimingKeys::TOTAL_EXECUTION, total_time)
         .await?;
 
-    println!("\n🎉 All processes execution completed! Total duration: {:.2}s", total_time);
+    info!("🎉 All processes execution completed! Total duration: {:.2}s", total_time);
 
     Ok(())
 }
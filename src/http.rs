@@ -0,0 +1,200 @@
+//! Minimal HTTP/1.1 handling shared by the local servers (`serve`, `serve-api`): one
+//! request per connection, bodies delimited by `Content-Length`
+
+use anyhow::{Context, Result, anyhow};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt};
+
+/// Largest accepted request body
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+/// Longest accepted request line or header line
+const MAX_LINE_BYTES: u64 = 8 * 1024;
+/// Most headers accepted in a request
+const MAX_HEADERS: usize = 100;
+/// Time a client has to send the whole request
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub(crate) struct Request {
+    pub method: String,
+    /// Percent-encoded path, without the query string
+    pub path: String,
+    pub query: String,
+    /// Lowercase header names
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    /// Decoded value of a query string parameter
+    pub fn query_param(&self, name: &str) -> Option<String> {
+        self.query.split('&').find_map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (key == name).then(|| percent_decode(&value.replace('+', " ")))
+        })
+    }
+}
+
+/// Read an HTTP/1.1 request: request line, headers and a `Content-Length` body; slow or
+/// oversized requests are refused
+pub(crate) async fn read_request(reader: &mut (impl AsyncBufRead + Unpin)) -> Result<Request> {
+    tokio::time::timeout(READ_TIMEOUT, read_request_parts(reader))
+        .await
+        .map_err(|_| anyhow!("Request not received within {}s", READ_TIMEOUT.as_secs()))?
+}
+
+async fn read_request_parts(reader: &mut (impl AsyncBufRead + Unpin)) -> Result<Request> {
+    let mut line = String::new();
+    read_line(reader, &mut line).await?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(anyhow!("Malformed request line"));
+    };
+    let method = method.to_string();
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let (path, query) = (path.to_string(), query.to_string());
+
+    let mut headers = HashMap::new();
+    for count in 0.. {
+        line.clear();
+        if read_line(reader, &mut line).await? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if count == MAX_HEADERS {
+            return Err(anyhow!("More than {} request headers", MAX_HEADERS));
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length: usize = headers
+        .get("content-length")
+        .map(|length| length.parse())
+        .transpose()
+        .context("Invalid Content-Length")?
+        .unwrap_or(0);
+    if length > MAX_BODY_BYTES {
+        return Err(anyhow!("Request body larger than {} bytes", MAX_BODY_BYTES));
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).await?;
+    Ok(Request {
+        method,
+        path,
+        query,
+        headers,
+        body,
+    })
+}
+
+/// Read one line of at most `MAX_LINE_BYTES`
+async fn read_line(reader: &mut (impl AsyncBufRead + Unpin), line: &mut String) -> Result<usize> {
+    let read = reader.take(MAX_LINE_BYTES).read_line(line).await?;
+    if read as u64 == MAX_LINE_BYTES && !line.ends_with('\n') {
+        return Err(anyhow!("Request line longer than {} bytes", MAX_LINE_BYTES));
+    }
+    Ok(read)
+}
+
+/// Decode `%XX` escapes; invalid escapes are kept as they are
+pub(crate) fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = (bytes[index] == b'%')
+            .then(|| text.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+pub(crate) struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    pub fn json(status: u16, body: Value) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            headers: Vec::new(),
+            body: body.to_string().into_bytes(),
+        }
+    }
+
+    pub fn error(status: u16, message: &str) -> Self {
+        Self::json(status, json!({ "error": message }))
+    }
+
+    pub fn html(status: u16, body: String) -> Self {
+        Self {
+            status,
+            content_type: "text/html; charset=utf-8",
+            headers: Vec::new(),
+            body: body.into_bytes(),
+        }
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        let reason = match self.status {
+            200 => "OK",
+            202 => "Accepted",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            404 => "Not Found",
+            409 => "Conflict",
+            _ => "Internal Server Error",
+        };
+        let mut head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+            self.status,
+            reason,
+            self.content_type,
+            self.body.len()
+        );
+        for (name, value) in &self.headers {
+            head.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        head.push_str("\r\n");
+        let mut bytes = head.into_bytes();
+        bytes.extend(self.body);
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::BufReader;
+
+    #[tokio::test]
+    async fn test_request_limits() {
+        let long_line = format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_LINE_BYTES as usize));
+        let error = read_request(&mut BufReader::new(long_line.as_bytes())).await.err().unwrap();
+        assert!(error.to_string().contains("longer than"));
+
+        let many_headers = format!("GET / HTTP/1.1\r\n{}\r\n", "X-Filler: 1\r\n".repeat(MAX_HEADERS + 1));
+        let error = read_request(&mut BufReader::new(many_headers.as_bytes())).await.err().unwrap();
+        assert!(error.to_string().contains("request headers"));
+
+        let request = read_request(&mut BufReader::new(&b"GET /docs?q=a+b HTTP/1.1\r\nHost: localhost\r\n\r\n"[..])).await.unwrap();
+        assert_eq!((request.path.as_str(), request.query_param("q").as_deref()), ("/docs", Some("a b")));
+    }
+}
@@ -1,4 +1,30 @@
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Fluent resources embedded per language code; English is the fallback for missing messages
+const RESOURCES: [(&str, &str); 16] = [
+    ("zh", include_str!("../locales/zh.ftl")),
+    ("en", include_str!("../locales/en.ftl")),
+    ("ja", include_str!("../locales/ja.ftl")),
+    ("ko", include_str!("../locales/ko.ftl")),
+    ("de", include_str!("../locales/de.ftl")),
+    ("fr", include_str!("../locales/fr.ftl")),
+    ("ru", include_str!("../locales/ru.ftl")),
+    ("vi", include_str!("../locales/vi.ftl")),
+    ("es", include_str!("../locales/es.ftl")),
+    ("pt", include_str!("../locales/pt.ftl")),
+    ("it", include_str!("../locales/it.ftl")),
+    ("ar", include_str!("../locales/ar.ftl")),
+    ("he", include_str!("../locales/he.ftl")),
+    ("hi", include_str!("../locales/hi.ftl")),
+    ("tr", include_str!("../locales/tr.ftl")),
+    ("pl", include_str!("../locales/pl.ftl")),
+];
+
+/// Parsed messages per language code
+static CATALOGS: OnceLock<HashMap<&'static str, HashMap<String, String>>> = OnceLock::new();
 
 /// Target language type
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -19,6 +45,23 @@ pub enum TargetLanguage {
     Russian,
     #[serde(rename = "vi")]
     Vietnamese,
+    #[serde(rename = "es")]
+    Spanish,
+    /// Brazilian Portuguese
+    #[serde(rename = "pt", alias = "pt-BR", alias = "pt-br")]
+    Portuguese,
+    #[serde(rename = "it")]
+    Italian,
+    #[serde(rename = "ar")]
+    Arabic,
+    #[serde(rename = "he")]
+    Hebrew,
+    #[serde(rename = "hi")]
+    Hindi,
+    #[serde(rename = "tr")]
+    Turkish,
+    #[serde(rename = "pl")]
+    Polish,
 }
 
 impl Default for TargetLanguage {
@@ -29,16 +72,7 @@ impl Default for TargetLanguage {
 
 impl std::fmt::Display for TargetLanguage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            TargetLanguage::Chinese => write!(f, "zh"),
-            TargetLanguage::English => write!(f, "en"),
-            TargetLanguage::Japanese => write!(f, "ja"),
-            TargetLanguage::Korean => write!(f, "ko"),
-            TargetLanguage::German => write!(f, "de"),
-            TargetLanguage::French => write!(f, "fr"),
-            TargetLanguage::Russian => write!(f, "ru"),
-            TargetLanguage::Vietnamese => write!(f, "vi"),
-        }
+        write!(f, "{}", self.code())
     }
 }
 
@@ -55,467 +89,327 @@ impl std::str::FromStr for TargetLanguage {
             "fr" | "french" | "français" | "法文" => Ok(TargetLanguage::French),
             "ru" | "russian" | "русский" | "俄文" => Ok(TargetLanguage::Russian),
             "vi" | "vietnamese" | "vn" | "vietnam" => Ok(TargetLanguage::Vietnamese),
+            "es" | "spanish" | "español" | "espanol" | "西班牙文" => Ok(TargetLanguage::Spanish),
+            "pt" | "pt-br" | "portuguese" | "português" | "portugues" | "葡萄牙文" => {
+                Ok(TargetLanguage::Portuguese)
+            }
+            "it" | "italian" | "italiano" | "意大利文" => Ok(TargetLanguage::Italian),
+            "ar" | "arabic" | "العربية" | "阿拉伯文" => Ok(TargetLanguage::Arabic),
+            "he" | "iw" | "hebrew" | "עברית" | "希伯来文" => Ok(TargetLanguage::Hebrew),
+            "hi" | "hindi" | "हिन्दी" | "印地文" => Ok(TargetLanguage::Hindi),
+            "tr" | "turkish" | "türkçe" | "turkce" | "土耳其文" => Ok(TargetLanguage::Turkish),
+            "pl" | "polish" | "polski" | "波兰文" => Ok(TargetLanguage::Polish),
             _ => Err(format!("Unknown target language: {}", s)),
         }
     }
 }
 
 impl TargetLanguage {
-    /// Get the descriptive name of the language
-    pub fn display_name(&self) -> &'static str {
+    /// All supported target languages
+    pub fn all() -> Vec<TargetLanguage> {
+        vec![
+            TargetLanguage::Chinese,
+            TargetLanguage::English,
+            TargetLanguage::Japanese,
+            TargetLanguage::Korean,
+            TargetLanguage::German,
+            TargetLanguage::French,
+            TargetLanguage::Russian,
+            TargetLanguage::Vietnamese,
+            TargetLanguage::Spanish,
+            TargetLanguage::Portuguese,
+            TargetLanguage::Italian,
+            TargetLanguage::Arabic,
+            TargetLanguage::Hebrew,
+            TargetLanguage::Hindi,
+            TargetLanguage::Turkish,
+            TargetLanguage::Polish,
+        ]
+    }
+
+    /// Language code, also the name of its resource file under `locales/`
+    pub fn code(&self) -> &'static str {
         match self {
-            TargetLanguage::Chinese => "中文",
-            TargetLanguage::English => "English",
-            TargetLanguage::Japanese => "日本語",
-            TargetLanguage::Korean => "한국어",
-            TargetLanguage::German => "Deutsch",
-            TargetLanguage::French => "Français",
-            TargetLanguage::Russian => "Русский",
-            TargetLanguage::Vietnamese => "Tiếng Việt",
+            TargetLanguage::Chinese => "zh",
+            TargetLanguage::English => "en",
+            TargetLanguage::Japanese => "ja",
+            TargetLanguage::Korean => "ko",
+            TargetLanguage::German => "de",
+            TargetLanguage::French => "fr",
+            TargetLanguage::Russian => "ru",
+            TargetLanguage::Vietnamese => "vi",
+            TargetLanguage::Spanish => "es",
+            TargetLanguage::Portuguese => "pt",
+            TargetLanguage::Italian => "it",
+            TargetLanguage::Arabic => "ar",
+            TargetLanguage::Hebrew => "he",
+            TargetLanguage::Hindi => "hi",
+            TargetLanguage::Turkish => "tr",
+            TargetLanguage::Polish => "pl",
+        }
+    }
+
+    /// Raw message pattern for a key, falling back to English when the language lacks it
+    pub fn lookup(&self, key: &str) -> Option<&'static str> {
+        let catalogs = CATALOGS.get_or_init(|| {
+            RESOURCES
+                .iter()
+                .map(|(code, source)| (*code, parse_resource(source)))
+                .collect()
+        });
+        [self.code(), TargetLanguage::English.code()]
+            .iter()
+            .find_map(|code| catalogs.get(code)?.get(key))
+            .map(String::as_str)
+    }
+
+    /// Format a message, replacing `{ $name }` placeables with the given arguments
+    pub fn message(&self, key: &str, args: &[(&str, &str)]) -> String {
+        let Some(pattern) = self.lookup(key) else {
+            return key.to_string();
+        };
+        let mut message = pattern.to_string();
+        for (name, value) in args {
+            message = placeable_regex(name)
+                .replace_all(&message, regex::NoExpand(value))
+                .to_string();
         }
+        message
+    }
+
+    /// Get the descriptive name of the language
+    pub fn display_name(&self) -> &'static str {
+        self.lookup("language-name").unwrap_or_default()
     }
 
     /// Get the prompt instruction for the language
     pub fn prompt_instruction(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "请使用中文编写文档，确保语言表达准确、专业、易于理解。",
-            TargetLanguage::English => "Please write the documentation in English, ensuring accurate, professional, and easy-to-understand language.",
-            TargetLanguage::Japanese => "日本語でドキュメントを作成してください。正確で専門的で理解しやすい言語表現を心がけてください。",
-            TargetLanguage::Korean => "한국어로 문서를 작성해 주세요. 정확하고 전문적이며 이해하기 쉬운 언어 표현을 사용해 주세요.",
-            TargetLanguage::German => "Bitte schreiben Sie die Dokumentation auf Deutsch und stellen Sie sicher, dass die Sprache präzise, professionell und leicht verständlich ist.",
-            TargetLanguage::French => "Veuillez rédiger la documentation en français, en vous assurant que le langage soit précis, professionnel et facile à comprendre.",
-            TargetLanguage::Russian => "Пожалуйста, напишите документацию на русском языке, обеспечив точность, профессионализм и понятность изложения.",
-            TargetLanguage::Vietnamese => "Hãy viết toàn bộ tài liệu bằng tiếng Việt tự nhiên, chính xác và dễ hiểu, sử dụng đúng thuật ngữ kỹ thuật.",
-        }
+        self.lookup("prompt-instruction").unwrap_or_default()
     }
 
     /// Get directory name
     pub fn get_directory_name(&self, dir_type: &str) -> String {
-        match self {
-            TargetLanguage::Chinese => {
-                match dir_type {
-                    "deep_exploration" => "4、深入探索".to_string(),
-                    _ => dir_type.to_string(),
-                }
-            }
-            TargetLanguage::English => {
-                match dir_type {
-                    "deep_exploration" => "4.Deep-Exploration".to_string(),
-                    _ => dir_type.to_string(),
-                }
-            }
-            TargetLanguage::Japanese => {
-                match dir_type {
-                    "deep_exploration" => "4-詳細探索".to_string(),
-                    _ => dir_type.to_string(),
-                }
-            }
-            TargetLanguage::Korean => {
-                match dir_type {
-                    "deep_exploration" => "4-심층-탐색".to_string(),
-                    _ => dir_type.to_string(),
-                }
-            }
-            TargetLanguage::German => {
-                match dir_type {
-                    "deep_exploration" => "4-Tiefere-Erkundung".to_string(),
-                    _ => dir_type.to_string(),
-                }
-            }
-            TargetLanguage::French => {
-                match dir_type {
-                    "deep_exploration" => "4-Exploration-Approfondie".to_string(),
-                    _ => dir_type.to_string(),
-                }
-            }
-            TargetLanguage::Russian => {
-                match dir_type {
-                    "deep_exploration" => "4-Глубокое-Исследование".to_string(),
-                    _ => dir_type.to_string(),
-                }
-            }
-            TargetLanguage::Vietnamese => {
-                match dir_type {
-                    "deep_exploration" => "4-Khám-phá-chi-tiết".to_string(),
-                    _ => dir_type.to_string(),
-                }
-            }
-        }
+        self.lookup(&format!("dir-{}", dir_type))
+            .unwrap_or(dir_type)
+            .to_string()
     }
 
     /// Get document filename
     pub fn get_doc_filename(&self, doc_type: &str) -> String {
-        match self {
-            TargetLanguage::Chinese => {
-                match doc_type {
-                    "overview" => "1、项目概述.md".to_string(),
-                    "architecture" => "2、架构概览.md".to_string(),
-                    "workflow" => "3、工作流程.md".to_string(),
-                    "boundary" => "5、边界调用.md".to_string(),
-                    "database" => "6、数据库概览.md".to_string(),
-                    _ => format!("{}.md", doc_type),
-                }
-            }
-            TargetLanguage::English => {
-                match doc_type {
-                    "overview" => "1.Overview.md".to_string(),
-                    "architecture" => "2.Architecture.md".to_string(),
-                    "workflow" => "3.Workflow.md".to_string(),
-                    "boundary" => "5.Boundary-Interfaces.md".to_string(),
-                    "database" => "6.Database-Overview.md".to_string(),
-                    _ => format!("{}.md", doc_type),
-                }
-            }
-            TargetLanguage::Japanese => {
-                match doc_type {
-                    "overview" => "1-プロジェクト概要.md".to_string(),
-                    "architecture" => "2-アーキテクチャ概要.md".to_string(),
-                    "workflow" => "3-ワークフロー.md".to_string(),
-                    "boundary" => "5-境界インターフェース.md".to_string(),
-                    "database" => "6-データベース概要.md".to_string(),
-                    _ => format!("{}.md", doc_type),
-                }
-            }
-            TargetLanguage::Korean => {
-                match doc_type {
-                    "overview" => "1-프로젝트-개요.md".to_string(),
-                    "architecture" => "2-아키텍처-개요.md".to_string(),
-                    "workflow" => "3-워크플로우.md".to_string(),
-                    "boundary" => "5-경계-인터페이스.md".to_string(),
-                    "database" => "6-데이터베이스-개요.md".to_string(),
-                    _ => format!("{}.md", doc_type),
-                }
-            }
-            TargetLanguage::German => {
-                match doc_type {
-                    "overview" => "1-Projektübersicht.md".to_string(),
-                    "architecture" => "2-Architekturübersicht.md".to_string(),
-                    "workflow" => "3-Arbeitsablauf.md".to_string(),
-                    "boundary" => "5-Grenzschnittstellen.md".to_string(),
-                    "database" => "6-Datenbankübersicht.md".to_string(),
-                    _ => format!("{}.md", doc_type),
-                }
-            }
-            TargetLanguage::French => {
-                match doc_type {
-                    "overview" => "1-Aperçu-du-Projet.md".to_string(),
-                    "architecture" => "2-Aperçu-de-l'Architecture.md".to_string(),
-                    "workflow" => "3-Flux-de-Travail.md".to_string(),
-                    "boundary" => "5-Interfaces-de-Frontière.md".to_string(),
-                    "database" => "6-Aperçu-Base-de-Données.md".to_string(),
-                    _ => format!("{}.md", doc_type),
-                }
-            }
-            TargetLanguage::Russian => {
-                match doc_type {
-                    "overview" => "1-Обзор-Проекта.md".to_string(),
-                    "architecture" => "2-Обзор-Архитектуры.md".to_string(),
-                    "workflow" => "3-Рабочий-Процесс.md".to_string(),
-                    "boundary" => "5-Граничные-Интерфейсы.md".to_string(),
-                    "database" => "6-Обзор-Базы-Данных.md".to_string(),
-                    _ => format!("{}.md", doc_type),
-                }
-            }
-            TargetLanguage::Vietnamese => {
-                match doc_type {
-                    "overview" => "1-Tổng-quan-Dự-án.md".to_string(),
-                    "architecture" => "2-Kiến-trúc.md".to_string(),
-                    "workflow" => "3-Luồng-xử-lý.md".to_string(),
-                    "boundary" => "5-Lớp-giao-tiếp-biên.md".to_string(),
-                    "database" => "6-Tổng-quan-Cơ-sở-Dữ-liệu.md".to_string(),
-                    _ => format!("{}.md", doc_type),
-                }
-            }
-        }
+        format!(
+            "{}.md",
+            self.lookup(&format!("doc-{}", doc_type)).unwrap_or(doc_type)
+        )
+    }
+
+    /// Title of the table of contents of the documents
+    pub fn toc_title(&self) -> String {
+        self.message("toc-title", &[])
     }
 
     // ===== Console Messages Translation System =====
 
     /// Warning: Cannot read config file, using default config
-    pub fn msg_config_read_error(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "⚠️ 警告: 无法读取配置文件 {:?}，使用默认配置",
-            TargetLanguage::English => "⚠️ Warning: Cannot read config file {:?}, using default config",
-            TargetLanguage::Japanese => "⚠️ 警告: 設定ファイル {:?} を読み込めません、デフォルト設定を使用します",
-            TargetLanguage::Korean => "⚠️ 경고: 설정 파일 {:?}을(를) 읽을 수 없습니다. 기본 설정을 사용합니다",
-            TargetLanguage::German => "⚠️ Warnung: Konfigurationsdatei {:?} kann nicht gelesen werden, verwende Standardkonfiguration",
-            TargetLanguage::French => "⚠️ Avertissement: Impossible de lire le fichier de configuration {:?}, utilisation de la configuration par défaut",
-            TargetLanguage::Russian => "⚠️ Предупреждение: Не удается прочитать файл конфигурации {:?}, используется конфигурация по умолчанию",
-            TargetLanguage::Vietnamese => "⚠️ Cảnh báo: Không thể đọc tệp cấu hình {:?}, sử dụng cấu hình mặc định",
-        }
+    pub fn msg_config_read_error(&self, path: &str) -> String {
+        self.message("config-read-error", &[("path", path)])
     }
 
     /// Warning: Unknown provider, using default provider
-    pub fn msg_unknown_provider(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "⚠️ 警告: 未知的provider: {}，使用默认provider",
-            TargetLanguage::English => "⚠️ Warning: Unknown provider: {}, using default provider",
-            TargetLanguage::Japanese => "⚠️ 警告: 不明なプロバイダー: {}、デフォルトプロバイダーを使用します",
-            TargetLanguage::Korean => "⚠️ 경고: 알 수 없는 프로바이더: {}, 기본 프로바이더를 사용합니다",
-            TargetLanguage::German => "⚠️ Warnung: Unbekannter Provider: {}, verwende Standard-Provider",
-            TargetLanguage::French => "⚠️ Avertissement: Fournisseur inconnu: {}, utilisation du fournisseur par défaut",
-            TargetLanguage::Russian => "⚠️ Предупреждение: Неизвестный провайдер: {}, используется провайдер по умолчанию",
-            TargetLanguage::Vietnamese => "⚠️ Cảnh báo: Nhà cung cấp không xác định: {}, sử dụng nhà cung cấp mặc định",
-        }
+    pub fn msg_unknown_provider(&self, provider: &str) -> String {
+        self.message("unknown-provider", &[("provider", provider)])
     }
 
     /// Warning: Unknown target language, using default language (English)
-    pub fn msg_unknown_language(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "⚠️ 警告: 未知的目标语言: {}，使用默认语言 (English)",
-            TargetLanguage::English => "⚠️ Warning: Unknown target language: {}, using default language (English)",
-            TargetLanguage::Japanese => "⚠️ 警告: 不明な対象言語: {}、デフォルト言語 (English) を使用します",
-            TargetLanguage::Korean => "⚠️ 경고: 알 수 없는 대상 언어: {}, 기본 언어(English)를 사용합니다",
-            TargetLanguage::German => "⚠️ Warnung: Unbekannte Zielsprache: {}, verwende Standardsprache (English)",
-            TargetLanguage::French => "⚠️ Avertissement: Langue cible inconnue: {}, utilisation de la langue par défaut (English)",
-            TargetLanguage::Russian => "⚠️ Предупреждение: Неизвестный целевой язык: {}, используется язык по умолчанию (English)",
-            TargetLanguage::Vietnamese => "⚠️ Cảnh báo: Ngôn ngữ đích không xác định: {}, sử dụng ngôn ngữ mặc định (English)",
-        }
+    pub fn msg_unknown_language(&self, language: &str) -> String {
+        self.message("unknown-language", &[("language", language)])
     }
 
     /// Using cached AI analysis result
-    pub fn msg_cache_hit(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "   ✅ 使用缓存的AI分析结果: {}",
-            TargetLanguage::English => "   ✅ Using cached AI analysis result: {}",
-            TargetLanguage::Japanese => "   ✅ キャッシュされたAI分析結果を使用: {}",
-            TargetLanguage::Korean => "   ✅ 캐시된 AI 분석 결과 사용: {}",
-            TargetLanguage::German => "   ✅ Verwende gecachtes KI-Analyseergebnis: {}",
-            TargetLanguage::French => "   ✅ Utilisation du résultat d'analyse IA en cache: {}",
-            TargetLanguage::Russian => "   ✅ Использование кэшированного результата AI-анализа: {}",
-            TargetLanguage::Vietnamese => "   ✅ Sử dụng kết quả phân tích AI đã lưu: {}",
-        }
+    pub fn msg_cache_hit(&self, tag: &str) -> String {
+        self.message("cache-hit", &[("tag", tag)])
     }
 
-    /// Performing AI analysis — {} = current, {} = total, {} = path
-    pub fn msg_ai_analyzing(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "   🤖 正在进行AI分析 [{}/{}]: {}",
-            TargetLanguage::English => "   🤖 Performing AI analysis [{}/{}]: {}",
-            TargetLanguage::Japanese => "   🤖 AI分析を実行中 [{}/{}]: {}",
-            TargetLanguage::Korean => "   🤖 AI 분석 수행 중 [{}/{}]: {}",
-            TargetLanguage::German => "   🤖 Führe KI-Analyse durch [{}/{}]: {}",
-            TargetLanguage::French => "   🤖 Analyse IA en cours [{}/{}]: {}",
-            TargetLanguage::Russian => "   🤖 Выполнение AI-анализа [{}/{}]: {}",
-            TargetLanguage::Vietnamese => "   🤖 Đang thực hiện phân tích AI [{}/{}]: {}",
-        }
+    /// Performing AI analysis
+    pub fn msg_ai_analyzing(&self, current: usize, total: usize, tag: &str) -> String {
+        self.message(
+            "ai-analyzing",
+            &[
+                ("current", &current.to_string()),
+                ("total", &total.to_string()),
+                ("tag", tag),
+            ],
+        )
     }
 
     /// Cache miss - need AI inference
-    pub fn msg_cache_miss(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "   ⌛ 缓存未命中 [{}] - 需要进行AI推理",
-            TargetLanguage::English => "   ⌛ Cache miss [{}] - AI inference required",
-            TargetLanguage::Japanese => "   ⌛ キャッシュミス [{}] - AI推論が必要です",
-            TargetLanguage::Korean => "   ⌛ 캐시 미스 [{}] - AI 추론 필요",
-            TargetLanguage::German => "   ⌛ Cache-Miss [{}] - KI-Inferenz erforderlich",
-            TargetLanguage::French => "   ⌛ Cache manqué [{}] - Inférence IA requise",
-            TargetLanguage::Russian => "   ⌛ Промах кэша [{}] - требуется AI-вывод",
-            TargetLanguage::Vietnamese => "   ⌛ Bỏ lỡ bộ nhớ cache [{}] - Cần suy luận AI",
-        }
+    pub fn msg_cache_miss(&self, category: &str) -> String {
+        self.message("cache-miss", &[("category", category)])
     }
 
     /// Cache write - result cached
-    pub fn msg_cache_write(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "   💾 缓存写入 [{}] - 结果已缓存",
-            TargetLanguage::English => "   💾 Cache write [{}] - Result cached",
-            TargetLanguage::Japanese => "   💾 キャッシュ書き込み [{}] - 結果がキャッシュされました",
-            TargetLanguage::Korean => "   💾 캐시 쓰기 [{}] - 결과 캐시됨",
-            TargetLanguage::German => "   💾 Cache-Schreiben [{}] - Ergebnis gecacht",
-            TargetLanguage::French => "   💾 Écriture en cache [{}] - Résultat mis en cache",
-            TargetLanguage::Russian => "   💾 Запись в кэш [{}] - Результат кэширован",
-            TargetLanguage::Vietnamese => "   💾 Ghi bộ nhớ cache [{}] - Kết quả đã được lưu",
-        }
+    pub fn msg_cache_write(&self, category: &str) -> String {
+        self.message("cache-write", &[("category", category)])
     }
 
     /// Cache error
-    pub fn msg_cache_error(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "   ❌ 缓存错误 [{}]: {}",
-            TargetLanguage::English => "   ❌ Cache error [{}]: {}",
-            TargetLanguage::Japanese => "   ❌ キャッシュエラー [{}]: {}",
-            TargetLanguage::Korean => "   ❌ 캐시 오류 [{}]: {}",
-            TargetLanguage::German => "   ❌ Cache-Fehler [{}]: {}",
-            TargetLanguage::French => "   ❌ Erreur de cache [{}]: {}",
-            TargetLanguage::Russian => "   ❌ Ошибка кэша [{}]: {}",
-            TargetLanguage::Vietnamese => "   ❌ Lỗi bộ nhớ cache [{}]: {}",
-        }
+    pub fn msg_cache_error(&self, category: &str, error: &str) -> String {
+        self.message("cache-error", &[("category", category), ("error", error)])
     }
 
     /// Using cached compression result
-    pub fn msg_cache_compression_hit(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "   💾 使用缓存的压缩结果 [{}]",
-            TargetLanguage::English => "   💾 Using cached compression result [{}]",
-            TargetLanguage::Japanese => "   💾 キャッシュされた圧縮結果を使用 [{}]",
-            TargetLanguage::Korean => "   💾 캐시된 압축 결과 사용 [{}]",
-            TargetLanguage::German => "   💾 Verwende gecachtes Kompressionsergebnis [{}]",
-            TargetLanguage::French => "   💾 Utilisation du résultat de compression en cache [{}]",
-            TargetLanguage::Russian => "   💾 Использование кэшированного результата сжатия [{}]",
-            TargetLanguage::Vietnamese => "   💾 Sử dụng kết quả nén đã lưu [{}]",
-        }
+    pub fn msg_cache_compression_hit(&self, content_type: &str) -> String {
+        self.message("cache-compression-hit", &[("content_type", content_type)])
     }
 
     /// Cannot read file
     #[allow(dead_code)]
-    pub fn msg_cannot_read_file(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "无法读取文件: {}",
-            TargetLanguage::English => "Cannot read file: {}",
-            TargetLanguage::Japanese => "ファイルを読み込めません: {}",
-            TargetLanguage::Korean => "파일을 읽을 수 없습니다: {}",
-            TargetLanguage::German => "Datei kann nicht gelesen werden: {}",
-            TargetLanguage::French => "Impossible de lire le fichier: {}",
-            TargetLanguage::Russian => "Не удается прочитать файл: {}",
-            TargetLanguage::Vietnamese => "Không thể đọc tệp: {}",
-        }
+    pub fn msg_cannot_read_file(&self, path: &str) -> String {
+        self.message("cannot-read-file", &[("path", path)])
     }
 
     /// Agent type display names
     pub fn msg_agent_type(&self, agent_type: &str) -> String {
-        match agent_type {
-            "system_context" => match self {
-                TargetLanguage::Chinese => "项目概览调研报告",
-                TargetLanguage::English => "System Context Research Report",
-                TargetLanguage::Japanese => "システムコンテキスト調査レポート",
-                TargetLanguage::Korean => "시스템 컨텍스트 조사 보고서",
-                TargetLanguage::German => "Systemkontext-Forschungsbericht",
-                TargetLanguage::French => "Rapport de recherche sur le contexte système",
-                TargetLanguage::Russian => "Отчет об исследовании системного контекста",
-                TargetLanguage::Vietnamese => "Báo cáo nghiên cứu ngữ cảnh hệ thống",
-            }.to_string(),
-            "domain_modules" => match self {
-                TargetLanguage::Chinese => "领域模块调研报告",
-                TargetLanguage::English => "Domain Modules Research Report",
-                TargetLanguage::Japanese => "ドメインモジュール調査レポート",
-                TargetLanguage::Korean => "도메인 모듈 조사 보고서",
-                TargetLanguage::German => "Domain-Modul-Forschungsbericht",
-                TargetLanguage::French => "Rapport de recherche sur les modules de domaine",
-                TargetLanguage::Russian => "Отчет об исследовании доменных модулей",
-                TargetLanguage::Vietnamese => "Báo cáo nghiên cứu mô-đun miền",
-            }.to_string(),
-            "architecture" => match self {
-                TargetLanguage::Chinese => "系统架构调研报告",
-                TargetLanguage::English => "System Architecture Research Report",
-                TargetLanguage::Japanese => "システムアーキテクチャ調査レポート",
-                TargetLanguage::Korean => "시스템 아키텍처 조사 보고서",
-                TargetLanguage::German => "Systemarchitektur-Forschungsbericht",
-                TargetLanguage::French => "Rapport de recherche sur l'architecture système",
-                TargetLanguage::Russian => "Отчет об исследовании системной архитектуры",
-                TargetLanguage::Vietnamese => "Báo cáo nghiên cứu kiến trúc hệ thống",
-            }.to_string(),
-            "workflow" => match self {
-                TargetLanguage::Chinese => "工作流调研报告",
-                TargetLanguage::English => "Workflow Research Report",
-                TargetLanguage::Japanese => "ワークフロー調査レポート",
-                TargetLanguage::Korean => "워크플로우 조사 보고서",
-                TargetLanguage::German => "Workflow-Forschungsbericht",
-                TargetLanguage::French => "Rapport de recherche sur le flux de travail",
-                TargetLanguage::Russian => "Отчет об исследовании рабочего процесса",
-                TargetLanguage::Vietnamese => "Báo cáo nghiên cứu quy trình làm việc",
-            }.to_string(),
-            "key_modules" => match self {
-                TargetLanguage::Chinese => "核心模块与组件调研报告",
-                TargetLanguage::English => "Key Modules and Components Research Report",
-                TargetLanguage::Japanese => "主要モジュールとコンポーネント調査レポート",
-                TargetLanguage::Korean => "핵심 모듈 및 구성 요소 조사 보고서",
-                TargetLanguage::German => "Schlüsselmodul- und Komponenten-Forschungsbericht",
-                TargetLanguage::French => "Rapport de recherche sur les modules et composants clés",
-                TargetLanguage::Russian => "Отчет об исследовании ключевых модулей и компонентов",
-                TargetLanguage::Vietnamese => "Báo cáo nghiên cứu mô-đun và thành phần chính",
-            }.to_string(),
-            "boundary" => match self {
-                TargetLanguage::Chinese => "边界接口调研报告",
-                TargetLanguage::English => "Boundary Interface Research Report",
-                TargetLanguage::Japanese => "境界インターフェース調査レポート",
-                TargetLanguage::Korean => "경계 인터페이스 조사 보고서",
-                TargetLanguage::German => "Grenzschnittstellenforschungsbericht",
-                TargetLanguage::French => "Rapport de recherche sur les interfaces de frontière",
-                TargetLanguage::Russian => "Отчет об исследовании граничных интерфейсов",
-                TargetLanguage::Vietnamese => "Báo cáo nghiên cứu giao diện biên",
-            }.to_string(),
-            "database" => match self {
-                TargetLanguage::Chinese => "数据库概览调研报告",
-                TargetLanguage::English => "Database Overview Research Report",
-                TargetLanguage::Japanese => "データベース概要調査レポート",
-                TargetLanguage::Korean => "데이터베이스 개요 조사 보고서",
-                TargetLanguage::German => "Datenbankübersicht-Forschungsbericht",
-                TargetLanguage::French => "Rapport de recherche sur l'aperçu de la base de données",
-                TargetLanguage::Russian => "Отчет об исследовании обзора базы данных",
-                TargetLanguage::Vietnamese => "Báo cáo nghiên cứu tổng quan cơ sở dữ liệu",
-            }.to_string(),
-            _ => agent_type.to_string(),
-        }
+        self.lookup(&format!("agent-{}", agent_type))
+            .unwrap_or(agent_type)
+            .to_string()
     }
 
     /// Warning: Document content not found
-    pub fn msg_doc_not_found(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "⚠️ 警告: 未找到文档内容，键: {}",
-            TargetLanguage::English => "⚠️ Warning: Document content not found, key: {}",
-            TargetLanguage::Japanese => "⚠️ 警告: ドキュメントコンテンツが見つかりません、キー: {}",
-            TargetLanguage::Korean => "⚠️ 경고: 문서 내용을 찾을 수 없습니다, 키: {}",
-            TargetLanguage::German => "⚠️ Warnung: Dokumentinhalt nicht gefunden, Schlüssel: {}",
-            TargetLanguage::French => "⚠️ Avertissement: Contenu du document introuvable, clé: {}",
-            TargetLanguage::Russian => "⚠️ Предупреждение: Содержимое документа не найдено, ключ: {}",
-            TargetLanguage::Vietnamese => "⚠️ Cảnh báo: Không tìm thấy nội dung tài liệu, khóa: {}",
-        }
+    pub fn msg_doc_not_found(&self, key: &str) -> String {
+        self.message("doc-not-found", &[("key", key)])
     }
 
     /// Mermaid fixer error
-    pub fn msg_mermaid_error(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "⚠️ mermaid图表修复过程中出现错误: {}",
-            TargetLanguage::English => "⚠️ Error occurred during mermaid diagram repair: {}",
-            TargetLanguage::Japanese => "⚠️ mermaidダイアグラムの修復中にエラーが発生しました: {}",
-            TargetLanguage::Korean => "⚠️ mermaid 다이어그램 복구 중 오류 발생: {}",
-            TargetLanguage::German => "⚠️ Fehler während der Mermaid-Diagrammreparatur aufgetreten: {}",
-            TargetLanguage::French => "⚠️ Erreur survenue lors de la réparation du diagramme mermaid: {}",
-            TargetLanguage::Russian => "⚠️ Ошибка при восстановлении диаграммы mermaid: {}",
-            TargetLanguage::Vietnamese => "⚠️ Lỗi xảy ra trong quá trình sửa chữa sơ đồ mermaid: {}",
-        }
+    pub fn msg_mermaid_error(&self, error: &str) -> String {
+        self.message("mermaid-error", &[("error", error)])
     }
 
     /// Summary reasoning failed
-    pub fn msg_summary_reasoning_failed(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "⚠️  总结推理失败，返回原始部分结果...{}",
-            TargetLanguage::English => "⚠️  Summary reasoning failed, returning original partial result...{}",
-            TargetLanguage::Japanese => "⚠️  要約推論に失敗しました、元の部分的な結果を返します...{}",
-            TargetLanguage::Korean => "⚠️  요약 추론 실패, 원래 부분 결과 반환...{}",
-            TargetLanguage::German => "⚠️  Zusammenfassungs-Reasoning fehlgeschlagen, gebe ursprüngliches Teilergebnis zurück...{}",
-            TargetLanguage::French => "⚠️  Échec du raisonnement de résumé, retour du résultat partiel d'origine...{}",
-            TargetLanguage::Russian => "⚠️  Сбой суммирования, возврат исходного частичного результата...{}",
-            TargetLanguage::Vietnamese => "⚠️  Suy luận tóm tắt thất bại, trả về kết quả một phần ban đầu...{}",
-        }
+    pub fn msg_summary_reasoning_failed(&self, error: &str) -> String {
+        self.message("summary-reasoning-failed", &[("error", error)])
     }
 
     /// Domain analysis failed
-    pub fn msg_domain_analysis_failed(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "⚠️ 领域模块分析：{} 分析失败: {}",
-            TargetLanguage::English => "⚠️ Domain module analysis: {} analysis failed: {}",
-            TargetLanguage::Japanese => "⚠️ ドメインモジュール分析：{} の分析に失敗しました: {}",
-            TargetLanguage::Korean => "⚠️ 도메인 모듈 분석: {} 분석 실패: {}",
-            TargetLanguage::German => "⚠️ Domain-Modul-Analyse: {} Analyse fehlgeschlagen: {}",
-            TargetLanguage::French => "⚠️ Analyse du module de domaine: échec de l'analyse de {}: {}",
-            TargetLanguage::Russian => "⚠️ Анализ доменного модуля: анализ {} не удался: {}",
-            TargetLanguage::Vietnamese => "⚠️ Phân tích mô-đun miền: phân tích {} thất bại: {}",
-        }
+    pub fn msg_domain_analysis_failed(&self, domain: &str, error: &str) -> String {
+        self.message("domain-analysis-failed", &[("domain", domain), ("error", error)])
     }
 
     /// No code path for domain
-    pub fn msg_no_code_path_for_domain(&self) -> &'static str {
-        match self {
-            TargetLanguage::Chinese => "⚠️ 领域'{}'没有关联的代码路径",
-            TargetLanguage::English => "⚠️ Domain '{}' has no associated code paths",
-            TargetLanguage::Japanese => "⚠️ ドメイン'{}'に関連するコードパスがありません",
-            TargetLanguage::Korean => "⚠️ 도메인 '{}'에 연결된 코드 경로가 없습니다",
-            TargetLanguage::German => "⚠️ Domain '{}' hat keine zugeordneten Code-Pfade",
-            TargetLanguage::French => "⚠️ Le domaine '{}' n'a pas de chemins de code associés",
-            TargetLanguage::Russian => "⚠️ Домен '{}' не имеет связанных путей кода",
-            TargetLanguage::Vietnamese => "⚠️ Miền '{}' không có đường dẫn mã liên kết",
+    pub fn msg_no_code_path_for_domain(&self, domain: &str) -> String {
+        self.message("no-code-path-for-domain", &[("domain", domain)])
+    }
+}
+
+/// Regex matching the `{ $name }` placeable of a variable
+fn placeable_regex(name: &str) -> Regex {
+    Regex::new(&format!(r"\{{\s*\${}\s*\}}", regex::escape(name))).expect("valid placeable regex")
+}
+
+/// Parse the subset of Fluent syntax used by the resources: `key = value` messages,
+/// indented continuation lines, `#` comments and `{ "literal" }` placeables
+fn parse_resource(source: &str) -> HashMap<String, String> {
+    let literal = Regex::new(r#"\{\s*"([^"]*)"\s*\}"#).expect("valid literal regex");
+    let mut messages = HashMap::new();
+    let mut current: Option<(String, String)> = None;
+
+    for line in source.lines() {
+        if line.starts_with(' ') && !line.trim().is_empty() {
+            if let Some((_, value)) = current.as_mut() {
+                value.push('\n');
+                value.push_str(line.trim());
+            }
+            continue;
+        }
+        if let Some((key, value)) = current.take() {
+            messages.insert(key, literal.replace_all(&value, "$1").to_string());
+        }
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            current = Some((key.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    if let Some((key, value)) = current {
+        messages.insert(key, literal.replace_all(&value, "$1").to_string());
+    }
+
+    messages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_language_defines_every_message() {
+        let (_, english) = RESOURCES
+            .iter()
+            .find(|(code, _)| *code == TargetLanguage::English.code())
+            .unwrap();
+        let english = parse_resource(english);
+        for (code, source) in RESOURCES {
+            let messages = parse_resource(source);
+            for key in english.keys() {
+                assert!(messages.contains_key(key), "{} is missing `{}`", code, key);
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_language_has_a_resource() {
+        for language in TargetLanguage::all() {
+            assert!(RESOURCES.iter().any(|(code, _)| *code == language.code()));
+            assert_eq!(language.code().parse::<TargetLanguage>(), Ok(language.clone()));
         }
+        assert_eq!("pt-BR".parse::<TargetLanguage>(), Ok(TargetLanguage::Portuguese));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_rtl_languages_use_ascii_filenames() {
+        for language in [TargetLanguage::Arabic, TargetLanguage::Hebrew] {
+            for doc_type in crate::generator::compose::DOCUMENT_TYPES {
+                assert!(language.get_doc_filename(doc_type).is_ascii());
+            }
+            assert!(language.get_directory_name("deep_exploration").is_ascii());
+        }
+    }
+
+    #[test]
+    fn test_hindi_turkish_polish() {
+        assert_eq!("हिन्दी".parse::<TargetLanguage>(), Ok(TargetLanguage::Hindi));
+        assert_eq!("turkce".parse::<TargetLanguage>(), Ok(TargetLanguage::Turkish));
+        assert_eq!("Polski".parse::<TargetLanguage>(), Ok(TargetLanguage::Polish));
+        assert_eq!(TargetLanguage::Turkish.display_name(), "Türkçe");
+        assert_eq!(TargetLanguage::Polish.get_doc_filename("overview"), "1-Przegląd-Projektu.md");
+        assert_eq!(TargetLanguage::Hindi.get_doc_filename("overview"), "1-परियोजना-अवलोकन.md");
+
+        // Every document has its own translated file name
+        for language in [TargetLanguage::Hindi, TargetLanguage::Turkish, TargetLanguage::Polish] {
+            let mut names: Vec<String> = crate::generator::compose::DOCUMENT_TYPES
+                .iter()
+                .map(|doc_type| language.get_doc_filename(doc_type))
+                .collect();
+            names.push(language.get_directory_name("deep_exploration"));
+            assert!(names.iter().all(|name| !name.contains(['/', '\\'])));
+            names.sort();
+            names.dedup();
+            assert_eq!(names.len(), crate::generator::compose::DOCUMENT_TYPES.len() + 1);
+            assert_ne!(language.get_doc_filename("overview"), TargetLanguage::English.get_doc_filename("overview"));
+        }
+    }
+
+    #[test]
+    fn test_message_formatting() {
+        assert_eq!(
+            TargetLanguage::English.msg_ai_analyzing(2, 5, "overview"),
+            "   🤖 Performing AI analysis [2/5]: overview"
+        );
+        assert_eq!(TargetLanguage::Chinese.get_doc_filename("overview"), "1、项目概述.md");
+        assert_eq!(TargetLanguage::German.get_doc_filename("custom"), "custom.md");
+        assert_eq!(
+            TargetLanguage::English.get_directory_name("deep_exploration"),
+            "4.Deep-Exploration"
+        );
+    }
+}
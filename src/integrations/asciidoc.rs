@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+
+/// Admonition labels rendered as Markdown blockquotes
+const ADMONITIONS: [&str; 5] = ["NOTE", "TIP", "IMPORTANT", "WARNING", "CAUTION"];
+
+/// Convert AsciiDoc into Markdown-like text.
+/// Section titles become Markdown headings so documents can be chunked by section;
+/// lists, tables, listing blocks, admonitions and links are kept, attribute
+/// references are substituted and comments, anchors and includes are dropped.
+pub fn asciidoc_to_markdown(adoc: &str) -> String {
+    let mut text = String::new();
+    let mut attributes: HashMap<String, String> = HashMap::new();
+    let mut lines = adoc.lines().peekable();
+    let mut source_language = String::new();
+    let mut table_columns: Option<usize> = None;
+
+    while let Some(raw) = lines.next() {
+        let line = raw.trim_end();
+
+        // Block comments
+        if line.starts_with("////") {
+            for inner in lines.by_ref() {
+                if inner.trim_end().starts_with("////") {
+                    break;
+                }
+            }
+            continue;
+        }
+        if line.starts_with("//") {
+            continue;
+        }
+
+        // Listing and literal blocks are copied verbatim into a code fence
+        if line == "----" || line == "...." {
+            text.push_str(&format!("```{}\n", source_language));
+            for inner in lines.by_ref() {
+                if inner.trim_end() == line {
+                    break;
+                }
+                text.push_str(inner);
+                text.push('\n');
+            }
+            text.push_str("```\n\n");
+            source_language.clear();
+            continue;
+        }
+
+        if line.starts_with("|===") {
+            let mut body = Vec::new();
+            for inner in lines.by_ref() {
+                if inner.trim_end().starts_with("|===") {
+                    break;
+                }
+                body.push(substitute(inner.trim(), &attributes));
+            }
+            text.push_str(&table_to_markdown(&body, table_columns.take()));
+            text.push('\n');
+            continue;
+        }
+
+        // Attribute entries, e.g. `:product: Order Service`
+        if let Some(entry) = line.strip_prefix(':')
+            && let Some((name, value)) = entry.split_once(':')
+            && !name.is_empty()
+            && !name.contains(char::is_whitespace)
+        {
+            attributes.insert(name.trim_start_matches('!').to_string(), value.trim().to_string());
+            continue;
+        }
+
+        // Block attribute lines and anchors, e.g. `[source,rust]`, `[cols="1,3"]`, `[[id]]`
+        if line.starts_with('[') && line.ends_with(']') {
+            let inner = line.trim_matches(|c| c == '[' || c == ']');
+            let mut parts = inner.split(',').map(str::trim);
+            if parts.next() == Some("source") {
+                source_language = parts.next().unwrap_or_default().to_string();
+            }
+            if let Some(cols) = inner.split("cols=").nth(1) {
+                let cols = cols.trim_start_matches('"').split('"').next().unwrap_or_default();
+                table_columns = Some(column_count(cols));
+            }
+            continue;
+        }
+
+        // Delimiters of example, sidebar, quote, passthrough and open blocks carry no text
+        if matches!(line, "====" | "****" | "____" | "++++" | "--") {
+            continue;
+        }
+        if line.starts_with("include::") {
+            continue;
+        }
+
+        let line = substitute(line, &attributes);
+
+        let heading_level = line.chars().take_while(|&c| c == '=').count();
+        if (1..=6).contains(&heading_level) && line[heading_level..].starts_with(' ') {
+            text.push_str(&format!(
+                "{} {}\n\n",
+                "#".repeat(heading_level),
+                line[heading_level..].trim()
+            ));
+        } else if let Some(title) = line.strip_prefix('.')
+            && !title.is_empty()
+            && !title.starts_with([' ', '.'])
+        {
+            // Block title
+            text.push_str(&format!("**{}**\n\n", title));
+        } else if let Some((marker, item)) = line.split_once(' ')
+            && !marker.is_empty()
+            && marker.chars().all(|c| c == '*' || c == '-')
+        {
+            let depth = marker.len().saturating_sub(1);
+            text.push_str(&format!("{}- {}\n", "  ".repeat(depth), item.trim()));
+        } else if let Some((marker, item)) = line.split_once(' ')
+            && !marker.is_empty()
+            && marker.chars().all(|c| c == '.')
+        {
+            let depth = marker.len() - 1;
+            text.push_str(&format!("{}1. {}\n", "   ".repeat(depth), item.trim()));
+        } else if let Some((label, rest)) = line.split_once(": ")
+            && ADMONITIONS.contains(&label)
+        {
+            text.push_str(&format!("> **{}:** {}\n\n", label, rest.trim()));
+        } else if line.is_empty() {
+            text.push('\n');
+        } else {
+            text.push_str(&line);
+            text.push('\n');
+        }
+    }
+
+    collapse_blank_lines(&text)
+}
+
+/// Replace `{name}` attribute references and convert link, xref and image macros
+fn substitute(line: &str, attributes: &HashMap<String, String>) -> String {
+    let mut result = line.to_string();
+    for (name, value) in attributes {
+        result = result.replace(&format!("{{{}}}", name), value);
+    }
+
+    // Cross references: <<id,Text>> becomes Text, <<id>> becomes id
+    while let Some(start) = result.find("<<") {
+        let Some(len) = result[start..].find(">>") else {
+            break;
+        };
+        let reference = &result[start + 2..start + len];
+        let label = reference
+            .split_once(',')
+            .map(|(_, label)| label.trim())
+            .unwrap_or(reference)
+            .to_string();
+        result.replace_range(start..start + len + 2, &label);
+    }
+
+    convert_macros(&result)
+}
+
+/// Convert `image::`, `xref:`, `link:` and bare URL macros into Markdown
+fn convert_macros(line: &str) -> String {
+    let mut result = String::new();
+    let mut rest = line;
+
+    while let Some(open) = rest.find('[') {
+        let Some(close) = rest[open..].find(']') else {
+            break;
+        };
+        let target_start = rest[..open]
+            .rfind(char::is_whitespace)
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        let target = &rest[target_start..open];
+        let label = &rest[open + 1..open + close];
+
+        let converted = if let Some(path) = target
+            .strip_prefix("image::")
+            .or_else(|| target.strip_prefix("image:"))
+        {
+            Some(format!("![{}]({})", label, path))
+        } else if let Some(path) = target.strip_prefix("xref:") {
+            Some(if label.is_empty() { path.to_string() } else { label.to_string() })
+        } else if let Some(url) = target.strip_prefix("link:") {
+            Some(format!("[{}]({})", label, url))
+        } else if target.starts_with("http://") || target.starts_with("https://") {
+            Some(format!("[{}]({})", label, target))
+        } else {
+            None
+        };
+
+        match converted {
+            Some(markdown) => {
+                result.push_str(&rest[..target_start]);
+                result.push_str(&markdown);
+            }
+            None => result.push_str(&rest[..open + close + 1]),
+        }
+        rest = &rest[open + close + 1..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Number of columns in a `cols` specification such as `1,3,2` or `3*`
+fn column_count(cols: &str) -> usize {
+    match cols.split_once('*') {
+        Some((count, _)) if !cols.contains(',') => count.trim().parse().unwrap_or(1),
+        _ => cols.split(',').count(),
+    }
+}
+
+/// Render the body of a `|===` table as a Markdown table; the first row becomes the header
+fn table_to_markdown(body: &[String], columns: Option<usize>) -> String {
+    let cells: Vec<&str> = body
+        .iter()
+        .flat_map(|line| line.split('|').skip(1))
+        .map(str::trim)
+        .collect();
+    // Without a `cols` attribute the column count is that of the first row
+    let columns = columns
+        .or_else(|| {
+            body.iter()
+                .find(|line| !line.is_empty())
+                .map(|line| line.matches('|').count())
+        })
+        .unwrap_or(1)
+        .max(1);
+
+    let mut markdown = String::new();
+    for (i, row) in cells.chunks(columns).enumerate() {
+        markdown.push_str(&format!("| {} |\n", row.join(" | ")));
+        if i == 0 {
+            markdown.push_str(&format!("|{}\n", "---|".repeat(columns)));
+        }
+    }
+    markdown
+}
+
+/// Collapse runs of blank lines left behind by removed markup
+fn collapse_blank_lines(text: &str) -> String {
+    let mut result = String::new();
+    let mut blank_lines = 0;
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            blank_lines += 1;
+            if blank_lines > 1 {
+                continue;
+            }
+        } else {
+            blank_lines = 0;
+        }
+        result.push_str(line);
+        result.push('\n');
+    }
+
+    result.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asciidoc_to_markdown() {
+        let adoc = "= Order Service\n:product: Shop\n// internal note\n\n\
+            == Overview\n{product} handles orders, see <<api,the API>>.\n\n\
+            NOTE: Refunds are async.\n\n* Orders\n** Refunds\n\n\
+            [source,rust]\n----\nfn main() {}\n----\n\n\
+            [[api]]\n=== API\n[cols=\"1,2\"]\n|===\n|Endpoint |Purpose\n\n|/orders\n|Create orders\n|===\n\n\
+            link:https://example.com[Docs]\n";
+
+        let markdown = asciidoc_to_markdown(adoc);
+
+        assert_eq!(
+            markdown,
+            "# Order Service\n\n## Overview\n\nShop handles orders, see the API.\n\n\
+            > **NOTE:** Refunds are async.\n\n- Orders\n  - Refunds\n\n\
+            ```rust\nfn main() {}\n```\n\n\
+            ### API\n\n| Endpoint | Purpose |\n|---|---|\n| /orders | Create orders |\n\n\
+            [Docs](https://example.com)"
+        );
+    }
+}
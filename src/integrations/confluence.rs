@@ -0,0 +1,201 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::config::{ChunkingConfig, ConfluenceCategory, ConfluenceConfig};
+use crate::integrations::html::html_to_markdown;
+use crate::integrations::local_docs::{DocFileType, LocalDocMetadata, LocalDocsProcessor};
+
+/// Page size used when listing the pages of a space
+const PAGE_LIMIT: usize = 25;
+
+/// A Confluence page fetched through the REST API
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConfluencePage {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub body: Option<ConfluenceBody>,
+    #[serde(default)]
+    pub version: Option<ConfluenceVersion>,
+}
+
+impl ConfluencePage {
+    /// Version identifier used to detect page changes
+    pub fn version_id(&self) -> Option<String> {
+        let version = self.version.as_ref()?;
+        version
+            .number
+            .map(|n| n.to_string())
+            .or_else(|| version.when.clone())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConfluenceBody {
+    pub storage: ConfluenceStorage,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConfluenceStorage {
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConfluenceVersion {
+    #[serde(default)]
+    pub number: Option<u64>,
+    #[serde(default)]
+    pub when: Option<String>,
+}
+
+/// Result of a conditional page fetch
+pub enum PageFetch {
+    /// The page still matches the cached ETag
+    NotModified,
+    /// The page was fetched, with the ETag returned by the server
+    Fetched(Box<ConfluencePage>, Option<String>),
+}
+
+#[derive(Debug, Deserialize)]
+struct ConfluencePageList {
+    results: Vec<ConfluencePage>,
+    #[serde(default)]
+    size: usize,
+}
+
+/// Client for pulling pages from the Confluence REST API
+pub struct ConfluenceClient {
+    http: reqwest::Client,
+    base_url: String,
+    username: Option<String>,
+    api_token: Option<String>,
+    max_pages_per_space: usize,
+}
+
+impl ConfluenceClient {
+    /// Create a new client from the Confluence configuration
+    pub fn new(config: &ConfluenceConfig) -> Result<Self> {
+        if config.base_url.trim().is_empty() {
+            return Err(anyhow::anyhow!("Confluence base_url is not configured"));
+        }
+
+        Ok(Self {
+            http: reqwest::Client::new(),
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            username: config.username.clone(),
+            api_token: config.resolve_api_token(),
+            max_pages_per_space: config.max_pages_per_space,
+        })
+    }
+
+    /// Fetch a single page with its storage-format body, skipping the download if the ETag still matches
+    pub async fn fetch_page(&self, page_id: &str, etag: Option<&str>) -> Result<PageFetch> {
+        let url = format!(
+            "{}/rest/api/content/{}?expand=body.storage,version",
+            self.base_url, page_id
+        );
+        let mut request = self.request(&url);
+        if let Some(etag) = etag {
+            request = request.header("If-None-Match", etag);
+        }
+
+        let response = request.send().await?;
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            return Ok(PageFetch::NotModified);
+        }
+        let response = response.error_for_status()?;
+        let etag = response
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let page = response
+            .json::<ConfluencePage>()
+            .await
+            .context("Failed to parse Confluence page response")?;
+        Ok(PageFetch::Fetched(Box::new(page), etag))
+    }
+
+    /// Fetch the pages of a space, up to `max_pages_per_space`
+    pub async fn fetch_space_pages(&self, space_key: &str) -> Result<Vec<ConfluencePage>> {
+        let mut pages = Vec::new();
+        let mut start = 0;
+
+        while pages.len() < self.max_pages_per_space {
+            let limit = PAGE_LIMIT.min(self.max_pages_per_space - pages.len());
+            let url = format!("{}/rest/api/content", self.base_url);
+            let (start_param, limit_param) = (start.to_string(), limit.to_string());
+            let response = self
+                .request(&url)
+                .query(&[
+                    ("spaceKey", space_key),
+                    ("type", "page"),
+                    ("expand", "body.storage,version"),
+                    ("start", start_param.as_str()),
+                    ("limit", limit_param.as_str()),
+                ])
+                .send()
+                .await?
+                .error_for_status()?;
+            let list = response
+                .json::<ConfluencePageList>()
+                .await
+                .context("Failed to parse Confluence page list response")?;
+
+            let fetched = list.results.len();
+            pages.extend(list.results);
+            if fetched == 0 || list.size < limit {
+                break;
+            }
+            start += fetched;
+        }
+
+        Ok(pages)
+    }
+
+    /// Convert a page into knowledge documents for the given category
+    pub fn page_to_docs(
+        &self,
+        page: &ConfluencePage,
+        category: &ConfluenceCategory,
+        chunking_config: Option<&ChunkingConfig>,
+    ) -> Vec<LocalDocMetadata> {
+        let body = page
+            .body
+            .as_ref()
+            .map(|body| html_to_markdown(&body.storage.value))
+            .unwrap_or_default();
+        let content = format!("# {}\n\n{}", page.title, body);
+
+        let url = self.page_url(&page.id);
+        let last_modified = page
+            .version
+            .as_ref()
+            .and_then(|version| version.when.clone())
+            .unwrap_or_default();
+
+        LocalDocsProcessor::build_doc_metadata(
+            url,
+            DocFileType::Confluence,
+            last_modified,
+            content,
+            &category.name,
+            &category.target_agents,
+            chunking_config,
+        )
+    }
+
+    /// Stable URL of a page, also used as its knowledge document path
+    pub fn page_url(&self, page_id: &str) -> String {
+        format!("{}/pages/viewpage.action?pageId={}", self.base_url, page_id)
+    }
+
+    fn request(&self, url: &str) -> reqwest::RequestBuilder {
+        let request = self.http.get(url).header("Accept", "application/json");
+        match (&self.username, &self.api_token) {
+            (Some(username), token) => request.basic_auth(username, token.clone()),
+            (None, Some(token)) => request.bearer_auth(token),
+            (None, None) => request,
+        }
+    }
+}
@@ -0,0 +1,449 @@
+use anyhow::{Context, Result};
+use sqlx::{Connection, MySqlConnection, PgConnection, SqliteConnection};
+use sqlx::sqlite::SqliteConnectOptions;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::config::DatabaseSourceConfig;
+
+/// Supported database backends
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DatabaseBackend {
+    Postgres,
+    MySql,
+    Sqlite,
+}
+
+impl DatabaseBackend {
+    /// Detect the backend from the connection string scheme
+    pub fn from_connection_string(url: &str) -> Result<Self> {
+        let scheme = url.split(':').next().unwrap_or_default().to_lowercase();
+        match scheme.as_str() {
+            "postgres" | "postgresql" => Ok(Self::Postgres),
+            "mysql" | "mariadb" => Ok(Self::MySql),
+            "sqlite" => Ok(Self::Sqlite),
+            _ => Err(anyhow::anyhow!("Unsupported database scheme: {}", scheme)),
+        }
+    }
+
+    fn display_name(&self) -> &'static str {
+        match self {
+            Self::Postgres => "PostgreSQL",
+            Self::MySql => "MySQL",
+            Self::Sqlite => "SQLite",
+        }
+    }
+}
+
+/// A column of a table or view
+#[derive(Debug, Clone)]
+pub struct ColumnInfo {
+    pub name: String,
+    pub data_type: String,
+    pub nullable: bool,
+    pub default: Option<String>,
+    pub primary_key: bool,
+}
+
+/// A table or view with its columns and foreign keys
+#[derive(Debug, Clone, Default)]
+pub struct TableInfo {
+    pub schema: String,
+    pub name: String,
+    pub is_view: bool,
+    pub columns: Vec<ColumnInfo>,
+    /// `column → table.column` descriptions
+    pub foreign_keys: Vec<String>,
+}
+
+/// A stored procedure, function or trigger
+#[derive(Debug, Clone)]
+pub struct RoutineInfo {
+    pub schema: String,
+    pub name: String,
+    pub kind: String,
+    pub return_type: Option<String>,
+}
+
+/// Introspected database schema
+#[derive(Debug, Clone)]
+pub struct DatabaseSchema {
+    pub backend: DatabaseBackend,
+    pub tables: Vec<TableInfo>,
+    pub routines: Vec<RoutineInfo>,
+}
+
+/// Raw column row shared by all backends: (schema, table, table type, column, data type, nullable, default)
+type ColumnRow = (String, String, String, String, String, bool, Option<String>);
+
+/// Raw key row shared by all backends: (schema, table, column, is primary key, referenced table, referenced column)
+type KeyRow = (String, String, String, bool, Option<String>, Option<String>);
+
+/// MySQL has no native boolean, so flags come back as integers
+type MySqlColumnRow = (String, String, String, String, String, i64, Option<String>);
+type MySqlKeyRow = (String, String, String, i64, Option<String>, Option<String>);
+
+/// SQLite `pragma_table_info` row: (table, kind, column, data type, not null, default, pk position)
+type SqliteColumnRow = (String, String, String, String, i64, Option<String>, i64);
+
+/// Reads table, view and routine definitions over a read-only connection
+pub struct DatabaseSchemaIntrospector;
+
+impl DatabaseSchemaIntrospector {
+    /// Connect to the configured database and introspect its schema
+    pub async fn introspect(config: &DatabaseSourceConfig) -> Result<DatabaseSchema> {
+        let url = config.resolve_connection_string().ok_or_else(|| {
+            anyhow::anyhow!("No connection string configured for database '{}'", config.name)
+        })?;
+        let backend = DatabaseBackend::from_connection_string(&url)?;
+
+        let (columns, keys, mut routines) = match backend {
+            DatabaseBackend::Postgres => Self::introspect_postgres(&url).await?,
+            DatabaseBackend::MySql => Self::introspect_mysql(&url).await?,
+            DatabaseBackend::Sqlite => Self::introspect_sqlite(&url).await?,
+        };
+
+        if !config.include_routines {
+            routines.clear();
+        }
+
+        let mut schema = Self::assemble(backend, columns, keys, routines);
+        if !config.schemas.is_empty() {
+            schema.tables.retain(|t| config.schemas.contains(&t.schema));
+            schema.routines.retain(|r| config.schemas.contains(&r.schema));
+        }
+
+        Ok(schema)
+    }
+
+    async fn introspect_postgres(url: &str) -> Result<(Vec<ColumnRow>, Vec<KeyRow>, Vec<RoutineInfo>)> {
+        let mut conn = PgConnection::connect(url)
+            .await
+            .context("Failed to connect to PostgreSQL")?;
+        sqlx::query("SET SESSION CHARACTERISTICS AS TRANSACTION READ ONLY")
+            .execute(&mut conn)
+            .await?;
+
+        let columns: Vec<ColumnRow> = sqlx::query_as(
+            "SELECT c.table_schema::text, c.table_name::text, t.table_type::text, c.column_name::text,
+                    c.data_type::text, c.is_nullable = 'YES', c.column_default::text
+             FROM information_schema.columns c
+             JOIN information_schema.tables t
+               ON t.table_schema = c.table_schema AND t.table_name = c.table_name
+             WHERE c.table_schema NOT IN ('pg_catalog', 'information_schema')
+               AND c.table_schema NOT LIKE 'pg_toast%'
+             ORDER BY c.table_schema, c.table_name, c.ordinal_position",
+        )
+        .fetch_all(&mut conn)
+        .await?;
+
+        let keys: Vec<KeyRow> = sqlx::query_as(
+            "SELECT tc.table_schema::text, tc.table_name::text, kcu.column_name::text,
+                    tc.constraint_type = 'PRIMARY KEY', ccu.table_name::text, ccu.column_name::text
+             FROM information_schema.table_constraints tc
+             JOIN information_schema.key_column_usage kcu
+               ON kcu.constraint_name = tc.constraint_name AND kcu.constraint_schema = tc.constraint_schema
+             LEFT JOIN information_schema.constraint_column_usage ccu
+               ON tc.constraint_type = 'FOREIGN KEY'
+              AND ccu.constraint_name = tc.constraint_name AND ccu.constraint_schema = tc.constraint_schema
+             WHERE tc.constraint_type IN ('PRIMARY KEY', 'FOREIGN KEY')",
+        )
+        .fetch_all(&mut conn)
+        .await?;
+
+        let routines: Vec<(String, String, Option<String>, Option<String>)> = sqlx::query_as(
+            "SELECT routine_schema::text, routine_name::text, routine_type::text, data_type::text
+             FROM information_schema.routines
+             WHERE routine_schema NOT IN ('pg_catalog', 'information_schema')
+             ORDER BY routine_schema, routine_name",
+        )
+        .fetch_all(&mut conn)
+        .await?;
+
+        conn.close().await?;
+        Ok((columns, keys, Self::routine_infos(routines)))
+    }
+
+    async fn introspect_mysql(url: &str) -> Result<(Vec<ColumnRow>, Vec<KeyRow>, Vec<RoutineInfo>)> {
+        let mut conn = MySqlConnection::connect(url)
+            .await
+            .context("Failed to connect to MySQL")?;
+        sqlx::query("SET SESSION TRANSACTION READ ONLY")
+            .execute(&mut conn)
+            .await?;
+
+        let columns: Vec<MySqlColumnRow> = sqlx::query_as(
+            "SELECT CAST(c.TABLE_SCHEMA AS CHAR), CAST(c.TABLE_NAME AS CHAR), CAST(t.TABLE_TYPE AS CHAR),
+                    CAST(c.COLUMN_NAME AS CHAR), CAST(c.COLUMN_TYPE AS CHAR),
+                    CAST(c.IS_NULLABLE = 'YES' AS SIGNED), CAST(c.COLUMN_DEFAULT AS CHAR)
+             FROM information_schema.COLUMNS c
+             JOIN information_schema.TABLES t
+               ON t.TABLE_SCHEMA = c.TABLE_SCHEMA AND t.TABLE_NAME = c.TABLE_NAME
+             WHERE c.TABLE_SCHEMA NOT IN ('mysql', 'information_schema', 'performance_schema', 'sys')
+             ORDER BY c.TABLE_SCHEMA, c.TABLE_NAME, c.ORDINAL_POSITION",
+        )
+        .fetch_all(&mut conn)
+        .await?;
+
+        let keys: Vec<MySqlKeyRow> = sqlx::query_as(
+            "SELECT CAST(TABLE_SCHEMA AS CHAR), CAST(TABLE_NAME AS CHAR), CAST(COLUMN_NAME AS CHAR),
+                    CAST(CONSTRAINT_NAME = 'PRIMARY' AS SIGNED),
+                    CAST(REFERENCED_TABLE_NAME AS CHAR), CAST(REFERENCED_COLUMN_NAME AS CHAR)
+             FROM information_schema.KEY_COLUMN_USAGE
+             WHERE TABLE_SCHEMA NOT IN ('mysql', 'information_schema', 'performance_schema', 'sys')
+               AND (CONSTRAINT_NAME = 'PRIMARY' OR REFERENCED_TABLE_NAME IS NOT NULL)",
+        )
+        .fetch_all(&mut conn)
+        .await?;
+
+        let routines: Vec<(String, String, Option<String>, Option<String>)> = sqlx::query_as(
+            "SELECT CAST(ROUTINE_SCHEMA AS CHAR), CAST(ROUTINE_NAME AS CHAR),
+                    CAST(ROUTINE_TYPE AS CHAR), CAST(DATA_TYPE AS CHAR)
+             FROM information_schema.ROUTINES
+             WHERE ROUTINE_SCHEMA NOT IN ('mysql', 'information_schema', 'performance_schema', 'sys')
+             ORDER BY ROUTINE_SCHEMA, ROUTINE_NAME",
+        )
+        .fetch_all(&mut conn)
+        .await?;
+
+        conn.close().await?;
+
+        let columns = columns
+            .into_iter()
+            .map(|(s, t, tt, c, dt, nullable, d)| (s, t, tt, c, dt, nullable != 0, d))
+            .collect();
+        let keys = keys
+            .into_iter()
+            .map(|(s, t, c, pk, rt, rc)| (s, t, c, pk != 0, rt, rc))
+            .collect();
+        Ok((columns, keys, Self::routine_infos(routines)))
+    }
+
+    async fn introspect_sqlite(url: &str) -> Result<(Vec<ColumnRow>, Vec<KeyRow>, Vec<RoutineInfo>)> {
+        let options = SqliteConnectOptions::from_str(url)?.read_only(true);
+        let mut conn = SqliteConnection::connect_with(&options)
+            .await
+            .context("Failed to open SQLite database")?;
+
+        let columns: Vec<SqliteColumnRow> = sqlx::query_as(
+            "SELECT m.name, m.type, p.name, p.type, p.\"notnull\", p.dflt_value, p.pk
+             FROM sqlite_master m JOIN pragma_table_info(m.name) p
+             WHERE m.type IN ('table', 'view') AND m.name NOT LIKE 'sqlite_%'
+             ORDER BY m.name, p.cid",
+        )
+        .fetch_all(&mut conn)
+        .await?;
+
+        let foreign_keys: Vec<(String, String, String, Option<String>)> = sqlx::query_as(
+            "SELECT m.name, f.\"from\", f.\"table\", f.\"to\"
+             FROM sqlite_master m JOIN pragma_foreign_key_list(m.name) f
+             WHERE m.type = 'table'",
+        )
+        .fetch_all(&mut conn)
+        .await?;
+
+        let triggers: Vec<(String, String)> = sqlx::query_as(
+            "SELECT name, tbl_name FROM sqlite_master WHERE type = 'trigger' ORDER BY name",
+        )
+        .fetch_all(&mut conn)
+        .await?;
+
+        conn.close().await?;
+
+        let mut keys: Vec<KeyRow> = columns
+            .iter()
+            .filter(|(_, _, _, _, _, _, pk)| *pk > 0)
+            .map(|(table, _, column, ..)| {
+                ("main".to_string(), table.clone(), column.clone(), true, None, None)
+            })
+            .collect();
+        keys.extend(foreign_keys.into_iter().map(|(table, column, ref_table, ref_column)| {
+            ("main".to_string(), table, column, false, Some(ref_table), ref_column)
+        }));
+
+        let columns = columns
+            .into_iter()
+            .map(|(table, kind, column, data_type, not_null, default, _)| {
+                let table_type = if kind == "view" { "VIEW" } else { "BASE TABLE" };
+                ("main".to_string(), table, table_type.to_string(), column, data_type, not_null == 0, default)
+            })
+            .collect();
+
+        let routines = triggers
+            .into_iter()
+            .map(|(name, table)| RoutineInfo {
+                schema: "main".to_string(),
+                name,
+                kind: "TRIGGER".to_string(),
+                return_type: Some(format!("on {}", table)),
+            })
+            .collect();
+
+        Ok((columns, keys, routines))
+    }
+
+    fn routine_infos(rows: Vec<(String, String, Option<String>, Option<String>)>) -> Vec<RoutineInfo> {
+        rows.into_iter()
+            .map(|(schema, name, kind, return_type)| RoutineInfo {
+                schema,
+                name,
+                kind: kind.unwrap_or_else(|| "ROUTINE".to_string()),
+                return_type,
+            })
+            .collect()
+    }
+
+    /// Group raw rows into tables with their keys
+    fn assemble(
+        backend: DatabaseBackend,
+        columns: Vec<ColumnRow>,
+        keys: Vec<KeyRow>,
+        routines: Vec<RoutineInfo>,
+    ) -> DatabaseSchema {
+        let mut tables: BTreeMap<(String, String), TableInfo> = BTreeMap::new();
+
+        for (schema, table, table_type, column, data_type, nullable, default) in columns {
+            let entry = tables
+                .entry((schema.clone(), table.clone()))
+                .or_insert_with(|| TableInfo {
+                    schema,
+                    name: table,
+                    is_view: table_type.to_uppercase().contains("VIEW"),
+                    ..Default::default()
+                });
+            entry.columns.push(ColumnInfo {
+                name: column,
+                data_type,
+                nullable,
+                default,
+                primary_key: false,
+            });
+        }
+
+        for (schema, table, column, is_primary, ref_table, ref_column) in keys {
+            let Some(entry) = tables.get_mut(&(schema, table)) else {
+                continue;
+            };
+            if is_primary {
+                if let Some(col) = entry.columns.iter_mut().find(|c| c.name == column) {
+                    col.primary_key = true;
+                }
+            } else if let Some(ref_table) = ref_table {
+                let target = match ref_column {
+                    Some(ref_column) => format!("{}.{}", ref_table, ref_column),
+                    None => ref_table,
+                };
+                let fk = format!("{} → {}", column, target);
+                if !entry.foreign_keys.contains(&fk) {
+                    entry.foreign_keys.push(fk);
+                }
+            }
+        }
+
+        DatabaseSchema {
+            backend,
+            tables: tables.into_values().collect(),
+            routines,
+        }
+    }
+}
+
+impl DatabaseSchema {
+    /// Format the schema as Markdown for the knowledge pipeline
+    pub fn to_markdown(&self, source_name: &str) -> String {
+        let mut out = format!(
+            "# Live Database Schema: {} ({})\n\n{} tables, {} views, {} routines\n",
+            source_name,
+            self.backend.display_name(),
+            self.tables.iter().filter(|t| !t.is_view).count(),
+            self.tables.iter().filter(|t| t.is_view).count(),
+            self.routines.len()
+        );
+
+        for (title, views) in [("Tables", false), ("Views", true)] {
+            let tables: Vec<&TableInfo> = self.tables.iter().filter(|t| t.is_view == views).collect();
+            if tables.is_empty() {
+                continue;
+            }
+            out.push_str(&format!("\n## {}\n", title));
+            for table in tables {
+                out.push_str(&format!("\n### {}.{}\n\n", table.schema, table.name));
+                out.push_str("| Column | Type | Nullable | Default | Key |\n|---|---|---|---|---|\n");
+                for column in &table.columns {
+                    out.push_str(&format!(
+                        "| {} | {} | {} | {} | {} |\n",
+                        column.name,
+                        column.data_type,
+                        if column.nullable { "YES" } else { "NO" },
+                        column.default.as_deref().unwrap_or(""),
+                        if column.primary_key { "PK" } else { "" }
+                    ));
+                }
+                if !table.foreign_keys.is_empty() {
+                    out.push_str(&format!("\nForeign keys: {}\n", table.foreign_keys.join(", ")));
+                }
+            }
+        }
+
+        if !self.routines.is_empty() {
+            out.push_str("\n## Routines\n\n");
+            for routine in &self.routines {
+                match &routine.return_type {
+                    Some(return_type) => out.push_str(&format!(
+                        "- {} {}.{} ({})\n",
+                        routine.kind, routine.schema, routine.name, return_type
+                    )),
+                    None => out.push_str(&format!(
+                        "- {} {}.{}\n",
+                        routine.kind, routine.schema, routine.name
+                    )),
+                }
+            }
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_introspect_sqlite() {
+        let temp = tempfile::tempdir().unwrap();
+        let db_path = temp.path().join("schema.db");
+        let url = format!("sqlite://{}?mode=rwc", db_path.display());
+
+        let mut conn = SqliteConnection::connect(&url).await.unwrap();
+        sqlx::query(
+            "CREATE TABLE customers (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+             CREATE TABLE orders (id INTEGER PRIMARY KEY, customer_id INTEGER REFERENCES customers(id));
+             CREATE VIEW order_names AS SELECT o.id, c.name FROM orders o JOIN customers c ON c.id = o.customer_id;",
+        )
+        .execute(&mut conn)
+        .await
+        .unwrap();
+        conn.close().await.unwrap();
+
+        let config = DatabaseSourceConfig {
+            name: "shop".to_string(),
+            enabled: true,
+            connection_string: Some(format!("sqlite://{}", db_path.display())),
+            connection_string_env: None,
+            schemas: Vec::new(),
+            include_routines: true,
+            category: "database".to_string(),
+            target_agents: Vec::new(),
+            priority: 0,
+            max_age_hours: 24,
+        };
+        let schema = DatabaseSchemaIntrospector::introspect(&config).await.unwrap();
+        let markdown = schema.to_markdown("shop");
+
+        assert!(markdown.contains("2 tables, 1 views"));
+        assert!(markdown.contains("### main.orders"));
+        assert!(markdown.contains("| id | INTEGER | YES |  | PK |"));
+        assert!(markdown.contains("Foreign keys: customer_id → customers.id"));
+    }
+}
@@ -0,0 +1,191 @@
+use anyhow::{Context, Result};
+use md5::{Digest, Md5};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+use tracing::info;
+
+/// Fingerprint of a synced source, used to detect what changed between syncs
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SourceFingerprint {
+    /// Last modification time of a local file (unix seconds)
+    #[serde(default)]
+    pub modified: Option<i64>,
+    /// MD5 hash of the content
+    #[serde(default)]
+    pub hash: Option<String>,
+    /// HTTP ETag returned by a remote source
+    #[serde(default)]
+    pub etag: Option<String>,
+    /// Remote version identifier (e.g. Confluence page version)
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
+impl SourceFingerprint {
+    /// Fingerprint a local file, reusing the previous hash when the modification time is unchanged
+    pub fn for_file(path: &Path, previous: Option<&SourceFingerprint>) -> Result<Self> {
+        let modified = fs::metadata(path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs() as i64);
+
+        if let Some(previous) = previous
+            && modified.is_some()
+            && previous.modified == modified
+            && previous.hash.is_some()
+        {
+            return Ok(previous.clone());
+        }
+
+        let bytes = fs::read(path).with_context(|| format!("Failed to read {:?}", path))?;
+        Ok(Self {
+            modified,
+            hash: Some(hash_bytes(&bytes)),
+            ..Default::default()
+        })
+    }
+
+    /// Fingerprint generated content such as a schema dump or an issue summary
+    pub fn for_content(content: &str) -> Self {
+        Self {
+            hash: Some(hash_bytes(content.as_bytes())),
+            ..Default::default()
+        }
+    }
+
+    /// Whether two fingerprints describe the same content
+    pub fn same_content(&self, other: &SourceFingerprint) -> bool {
+        if let (Some(a), Some(b)) = (&self.hash, &other.hash) {
+            return a == b;
+        }
+        if let (Some(a), Some(b)) = (&self.version, &other.version) {
+            return a == b;
+        }
+        if let (Some(a), Some(b)) = (&self.etag, &other.etag) {
+            return a == b;
+        }
+        false
+    }
+}
+
+/// MD5 hex digest of the given bytes
+pub fn hash_bytes(bytes: &[u8]) -> String {
+    let mut hasher = Md5::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+/// What a sync changed for one knowledge source
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    pub added: Vec<String>,
+    pub updated: Vec<String>,
+    pub removed: Vec<String>,
+    pub unchanged: usize,
+}
+
+impl SyncReport {
+    /// Classify a source against its previous fingerprint; returns true if it changed
+    pub fn record(
+        &mut self,
+        key: &str,
+        previous: Option<&SourceFingerprint>,
+        current: &SourceFingerprint,
+    ) -> bool {
+        match previous {
+            None => {
+                self.added.push(key.to_string());
+                true
+            }
+            Some(previous) if previous.same_content(current) => {
+                self.unchanged += 1;
+                false
+            }
+            Some(_) => {
+                self.updated.push(key.to_string());
+                true
+            }
+        }
+    }
+
+    /// Record sources present in the previous sync but gone now
+    pub fn record_removed(
+        &mut self,
+        previous: &HashMap<String, SourceFingerprint>,
+        current: &HashMap<String, SourceFingerprint>,
+    ) {
+        let mut removed: Vec<String> = previous
+            .keys()
+            .filter(|key| !current.contains_key(*key))
+            .cloned()
+            .collect();
+        removed.sort();
+        self.removed.extend(removed);
+    }
+
+    /// Whether anything was added, updated or removed
+    pub fn has_changes(&self) -> bool {
+        !self.added.is_empty() || !self.updated.is_empty() || !self.removed.is_empty()
+    }
+
+    /// Log exactly what was refreshed
+    pub fn log(&self, source: &str) {
+        if !self.has_changes() {
+            info!("  ✅ {}: up to date ({} unchanged)", source, self.unchanged);
+            return;
+        }
+        for key in &self.added {
+            info!("    ➕ Added: {}", key);
+        }
+        for key in &self.updated {
+            info!("    🔄 Updated: {}", key);
+        }
+        for key in &self.removed {
+            info!("    ➖ Removed: {}", key);
+        }
+        info!(
+            "  📊 {}: {} added, {} updated, {} removed, {} unchanged",
+            source,
+            self.added.len(),
+            self.updated.len(),
+            self.removed.len(),
+            self.unchanged
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sync_report_classifies_changes() {
+        let old = SourceFingerprint::for_content("v1");
+        let previous = HashMap::from([
+            ("a.md".to_string(), old.clone()),
+            ("b.md".to_string(), old.clone()),
+            ("gone.md".to_string(), old.clone()),
+        ]);
+        let current = HashMap::from([
+            ("a.md".to_string(), old.clone()),
+            ("b.md".to_string(), SourceFingerprint::for_content("v2")),
+            ("new.md".to_string(), old.clone()),
+        ]);
+
+        let mut report = SyncReport::default();
+        for key in ["a.md", "b.md", "new.md"] {
+            report.record(key, previous.get(key), &current[key]);
+        }
+        report.record_removed(&previous, &current);
+
+        assert_eq!(report.unchanged, 1);
+        assert_eq!(report.updated, vec!["b.md"]);
+        assert_eq!(report.added, vec!["new.md"]);
+        assert_eq!(report.removed, vec!["gone.md"]);
+        assert!(report.has_changes());
+    }
+}
@@ -0,0 +1,177 @@
+/// Elements whose content is never rendered as text
+const SKIPPED_ELEMENTS: [&str; 6] = ["script", "style", "head", "noscript", "svg", "template"];
+/// Longest numeric character reference between `&#` and `;` (`x10FFFF`, `1114111`)
+const MAX_REFERENCE_LEN: usize = 8;
+
+/// Convert HTML (or Confluence storage XHTML) into Markdown-like text.
+/// Headings, list items, table rows and code blocks are kept; all other markup is stripped.
+pub fn html_to_markdown(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    let mut skip_depth: usize = 0;
+    let mut in_pre = false;
+
+    while let Some(open) = rest.find('<') {
+        if skip_depth == 0 {
+            text.push_str(&rest[..open]);
+        }
+
+        // Comments may contain '>' so they are matched on their own terminator
+        if rest[open..].starts_with("<!--") {
+            rest = match rest[open..].find("-->") {
+                Some(end) => &rest[open + end + 3..],
+                None => "",
+            };
+            continue;
+        }
+
+        let Some(close) = rest[open..].find('>') else {
+            rest = &rest[open..];
+            break;
+        };
+        let tag = rest[open + 1..open + close].trim().to_lowercase();
+        rest = &rest[open + close + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+
+        if SKIPPED_ELEMENTS.contains(&name.as_str()) {
+            if is_closing {
+                skip_depth = skip_depth.saturating_sub(1);
+            } else if !tag.ends_with('/') {
+                skip_depth += 1;
+            }
+            continue;
+        }
+        if skip_depth > 0 {
+            continue;
+        }
+
+        match name.as_str() {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" if !is_closing => {
+                let level = name[1..].parse::<usize>().unwrap_or(1);
+                text.push_str(&format!("\n\n{} ", "#".repeat(level)));
+            }
+            "pre" if is_closing => {
+                in_pre = false;
+                text.push_str("\n```\n\n");
+            }
+            "pre" => {
+                in_pre = true;
+                text.push_str("\n\n```\n");
+            }
+            "code" if !in_pre => text.push('`'),
+            "li" if !is_closing => text.push_str("\n- "),
+            "br" => text.push('\n'),
+            "p" | "div" | "section" | "article" | "tr" | "ul" | "ol" | "table" | "blockquote"
+            | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => text.push_str("\n\n"),
+            "td" | "th" if is_closing => text.push_str(" | "),
+            _ => {}
+        }
+    }
+    if skip_depth == 0 {
+        text.push_str(rest);
+    }
+
+    collapse_blank_lines(&decode_entities(&text))
+}
+
+/// Decode the named entities common in exported pages plus numeric character references
+fn decode_entities(text: &str) -> String {
+    let decoded = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'");
+
+    // Numeric references; text that only looks like one is kept as it is
+    let mut result = String::with_capacity(decoded.len());
+    let mut rest = decoded.as_str();
+    while let Some(start) = rest.find("&#") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let reference = after
+            .bytes()
+            .take(MAX_REFERENCE_LEN + 1)
+            .position(|byte| byte == b';')
+            .and_then(|len| {
+                let reference = &after[..len];
+                let code = match reference.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => reference.parse::<u32>().ok(),
+                };
+                code.and_then(char::from_u32).map(|c| (c, len))
+            });
+        match reference {
+            Some((c, len)) => {
+                result.push(c);
+                rest = &after[len + 1..];
+            }
+            None => {
+                result.push_str("&#");
+                rest = after;
+            }
+        }
+    }
+    result.push_str(rest);
+
+    // Decode &amp; last so escaped entities such as "&amp;lt;" stay literal
+    result.replace("&amp;", "&")
+}
+
+/// Collapse runs of blank lines left behind by nested block elements
+fn collapse_blank_lines(text: &str) -> String {
+    let mut result = String::new();
+    let mut blank_lines = 0;
+    for line in text.lines() {
+        let line = line.trim_end();
+        if line.trim().is_empty() {
+            blank_lines += 1;
+            if blank_lines > 1 {
+                continue;
+            }
+        } else {
+            blank_lines = 0;
+        }
+        result.push_str(line);
+        result.push('\n');
+    }
+
+    result.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_to_markdown() {
+        let html = "<html><head><title>x</title><style>p{}</style></head><body>\
+            <!-- nav > menu --><h2>Overview</h2><p>Orders &amp; payments&#33;</p>\
+            <ul><li>API</li><li>Worker</li></ul><script>alert(1)</script>\
+            <pre><code>cargo run</code></pre></body></html>";
+        let text = html_to_markdown(html);
+
+        assert!(text.starts_with("## Overview"));
+        assert!(text.contains("Orders & payments!"));
+        assert!(text.contains("- API\n- Worker"));
+        assert!(text.contains("```\ncargo run\n```"));
+        assert!(!text.contains("alert") && !text.contains("menu"));
+
+        assert_eq!(
+            decode_entities("Q&#A about caching; see &#x2192; below"),
+            "Q&#A about caching; see \u{2192} below"
+        );
+        assert_eq!(
+            decode_entities("&#12345678901234567890; &#x110000; &#65;"),
+            "&#12345678901234567890; &#x110000; A"
+        );
+    }
+}
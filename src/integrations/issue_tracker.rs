@@ -0,0 +1,338 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use crate::config::{IssueTrackerConfig, IssueTrackerProvider};
+
+const GITHUB_API_URL: &str = "https://api.github.com";
+
+/// Page size used when listing issues
+const PAGE_SIZE: usize = 50;
+
+/// An issue or epic pulled from the tracker (titles and labels only)
+#[derive(Debug, Clone)]
+pub struct TrackedIssue {
+    pub key: String,
+    pub title: String,
+    pub labels: Vec<String>,
+    pub state: String,
+    pub is_epic: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubIssue {
+    number: u64,
+    title: String,
+    state: String,
+    #[serde(default)]
+    labels: Vec<GithubLabel>,
+    #[serde(default)]
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubLabel {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraSearchResponse {
+    #[serde(default)]
+    issues: Vec<JiraIssue>,
+    #[serde(default)]
+    total: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraIssue {
+    key: String,
+    fields: JiraFields,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraFields {
+    summary: String,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    issuetype: Option<JiraNamed>,
+    #[serde(default)]
+    status: Option<JiraNamed>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraNamed {
+    name: String,
+}
+
+/// Result of a conditional issue fetch
+pub enum IssueFetch {
+    /// The most recently updated issues still match the cached ETag
+    NotModified,
+    /// Issues were fetched, with the ETag of the first page if the tracker returned one
+    Fetched(Vec<TrackedIssue>, Option<String>),
+}
+
+/// Client for pulling recent issues from GitHub Issues or Jira
+pub struct IssueTrackerClient {
+    http: reqwest::Client,
+    config: IssueTrackerConfig,
+    api_token: Option<String>,
+}
+
+impl IssueTrackerClient {
+    /// Create a new client from the issue tracker configuration
+    pub fn new(config: &IssueTrackerConfig) -> Result<Self> {
+        match config.provider {
+            IssueTrackerProvider::Github if config.repository.is_none() => {
+                return Err(anyhow::anyhow!("GitHub issue tracker requires `repository`"));
+            }
+            IssueTrackerProvider::Jira if config.base_url.is_none() => {
+                return Err(anyhow::anyhow!("Jira issue tracker requires `base_url`"));
+            }
+            _ => {}
+        }
+
+        Ok(Self {
+            http: reqwest::Client::new(),
+            config: config.clone(),
+            api_token: config.resolve_api_token(),
+        })
+    }
+
+    /// Fetch the most recently updated issues; GitHub answers 304 when the ETag still matches
+    pub async fn fetch_issues(&self, etag: Option<&str>) -> Result<IssueFetch> {
+        match self.config.provider {
+            IssueTrackerProvider::Github => self.fetch_github_issues(etag).await,
+            IssueTrackerProvider::Jira => Ok(IssueFetch::Fetched(self.fetch_jira_issues().await?, None)),
+        }
+    }
+
+    async fn fetch_github_issues(&self, etag: Option<&str>) -> Result<IssueFetch> {
+        let base_url = self
+            .config
+            .base_url
+            .as_deref()
+            .unwrap_or(GITHUB_API_URL)
+            .trim_end_matches('/');
+        let repository = self.config.repository.as_deref().unwrap_or_default();
+
+        let mut issues = Vec::new();
+        let mut first_page_etag = None;
+        let mut page = 1;
+        while issues.len() < self.config.max_issues {
+            let mut url = format!(
+                "{}/repos/{}/issues?state=all&sort=updated&direction=desc&per_page={}&page={}",
+                base_url, repository, PAGE_SIZE, page
+            );
+            if !self.config.labels.is_empty() {
+                url.push_str(&format!("&labels={}", self.config.labels.join(",")));
+            }
+
+            let mut request = self
+                .http
+                .get(&url)
+                .header("Accept", "application/vnd.github+json")
+                .header("User-Agent", "litho");
+            if let Some(token) = &self.api_token {
+                request = request.bearer_auth(token);
+            }
+            // Issues are sorted by update time, so an unchanged first page means nothing changed
+            if page == 1
+                && let Some(etag) = etag
+            {
+                request = request.header("If-None-Match", etag);
+            }
+
+            let response = request.send().await?;
+            if page == 1 && response.status() == reqwest::StatusCode::NOT_MODIFIED {
+                return Ok(IssueFetch::NotModified);
+            }
+            let response = response.error_for_status()?;
+            if page == 1 {
+                first_page_etag = response
+                    .headers()
+                    .get(reqwest::header::ETAG)
+                    .and_then(|v| v.to_str().ok())
+                    .map(str::to_string);
+            }
+
+            let batch: Vec<GithubIssue> = response
+                .json()
+                .await
+                .context("Failed to parse GitHub issues response")?;
+            let fetched = batch.len();
+
+            // The issues endpoint also lists pull requests
+            issues.extend(batch.into_iter().filter(|i| i.pull_request.is_none()).map(|issue| {
+                let labels: Vec<String> = issue.labels.into_iter().map(|l| l.name).collect();
+                TrackedIssue {
+                    key: format!("#{}", issue.number),
+                    title: issue.title,
+                    is_epic: labels.iter().any(|l| l.eq_ignore_ascii_case("epic")),
+                    labels,
+                    state: issue.state,
+                }
+            }));
+
+            if fetched < PAGE_SIZE {
+                break;
+            }
+            page += 1;
+        }
+
+        issues.truncate(self.config.max_issues);
+        Ok(IssueFetch::Fetched(issues, first_page_etag))
+    }
+
+    async fn fetch_jira_issues(&self) -> Result<Vec<TrackedIssue>> {
+        let base_url = self
+            .config
+            .base_url
+            .as_deref()
+            .unwrap_or_default()
+            .trim_end_matches('/');
+        let jql = self.jira_query();
+
+        let mut issues = Vec::new();
+        while issues.len() < self.config.max_issues {
+            let start_at = issues.len().to_string();
+            let max_results = PAGE_SIZE.min(self.config.max_issues - issues.len()).to_string();
+            let mut request = self
+                .http
+                .get(format!("{}/rest/api/2/search", base_url))
+                .query(&[
+                    ("jql", jql.as_str()),
+                    ("fields", "summary,labels,issuetype,status"),
+                    ("startAt", start_at.as_str()),
+                    ("maxResults", max_results.as_str()),
+                ])
+                .header("Accept", "application/json");
+            request = match (&self.config.username, &self.api_token) {
+                (Some(username), token) => request.basic_auth(username, token.clone()),
+                (None, Some(token)) => request.bearer_auth(token),
+                (None, None) => request,
+            };
+
+            let response: JiraSearchResponse = request
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await
+                .context("Failed to parse Jira search response")?;
+            let fetched = response.issues.len();
+
+            issues.extend(response.issues.into_iter().map(|issue| TrackedIssue {
+                key: issue.key,
+                title: issue.fields.summary,
+                labels: issue.fields.labels,
+                state: issue.fields.status.map(|s| s.name).unwrap_or_default(),
+                is_epic: issue
+                    .fields
+                    .issuetype
+                    .is_some_and(|t| t.name.eq_ignore_ascii_case("epic")),
+            }));
+
+            if fetched == 0 || issues.len() >= response.total {
+                break;
+            }
+        }
+
+        Ok(issues)
+    }
+
+    fn jira_query(&self) -> String {
+        if let Some(jql) = &self.config.jql {
+            return jql.clone();
+        }
+
+        let mut clauses = Vec::new();
+        if !self.config.project_keys.is_empty() {
+            clauses.push(format!("project in ({})", self.config.project_keys.join(", ")));
+        }
+        if !self.config.labels.is_empty() {
+            let labels: Vec<String> = self.config.labels.iter().map(|l| format!("\"{}\"", l)).collect();
+            clauses.push(format!("labels in ({})", labels.join(", ")));
+        }
+        format!("{} ORDER BY updated DESC", clauses.join(" AND "))
+            .trim()
+            .to_string()
+    }
+}
+
+/// Format issues as Markdown: epics first, then issues grouped by label
+pub fn issues_to_markdown(source: &str, issues: &[TrackedIssue]) -> String {
+    let mut out = format!(
+        "# Issue Tracker: {}\n\nRecently updated issues ({}), showing what the system is being built for.\n",
+        source,
+        issues.len()
+    );
+
+    let epics: Vec<&TrackedIssue> = issues.iter().filter(|i| i.is_epic).collect();
+    if !epics.is_empty() {
+        out.push_str("\n## Epics\n\n");
+        for epic in epics {
+            out.push_str(&format_issue(epic));
+        }
+    }
+
+    let mut by_label: BTreeMap<&str, Vec<&TrackedIssue>> = BTreeMap::new();
+    for issue in issues.iter().filter(|i| !i.is_epic) {
+        match issue.labels.first() {
+            Some(label) => by_label.entry(label.as_str()).or_default().push(issue),
+            None => by_label.entry("unlabeled").or_default().push(issue),
+        }
+    }
+
+    for (label, issues) in by_label {
+        out.push_str(&format!("\n## {}\n\n", label));
+        for issue in issues {
+            out.push_str(&format_issue(issue));
+        }
+    }
+
+    out
+}
+
+fn format_issue(issue: &TrackedIssue) -> String {
+    let mut line = format!("- {} {}", issue.key, issue.title);
+    if !issue.labels.is_empty() {
+        line.push_str(&format!(" [{}]", issue.labels.join(", ")));
+    }
+    if !issue.state.is_empty() {
+        line.push_str(&format!(" ({})", issue.state));
+    }
+    line.push('\n');
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issues_to_markdown_groups_epics_and_labels() {
+        let issues = vec![
+            TrackedIssue {
+                key: "SHOP-1".to_string(),
+                title: "Checkout redesign".to_string(),
+                labels: vec![],
+                state: "In Progress".to_string(),
+                is_epic: true,
+            },
+            TrackedIssue {
+                key: "SHOP-2".to_string(),
+                title: "Support refunds".to_string(),
+                labels: vec!["payments".to_string()],
+                state: "Open".to_string(),
+                is_epic: false,
+            },
+        ];
+
+        let markdown = issues_to_markdown("shop", &issues);
+
+        assert!(markdown.contains("## Epics\n\n- SHOP-1 Checkout redesign (In Progress)"));
+        assert!(markdown.contains("## payments\n\n- SHOP-2 Support refunds [payments] (Open)"));
+    }
+}
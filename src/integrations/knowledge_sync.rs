@@ -5,8 +5,19 @@ use std::fs;
 use std::collections::{HashMap, HashSet};
 use chrono::{DateTime, Utc};
 
-use crate::config::{Config, LocalDocsConfig};
-use crate::integrations::local_docs::{LocalDocsProcessor, LocalDocMetadata};
+use crate::config::{
+    Config, ConfluenceConfig, DatabaseSourceConfig, IssueTrackerConfig, LocalDocsConfig, RetrievalConfig,
+};
+use crate::integrations::confluence::{ConfluenceClient, PageFetch};
+#[cfg(feature = "database")]
+use crate::integrations::database_schema::DatabaseSchemaIntrospector;
+use crate::integrations::freshness::{SourceFingerprint, SyncReport};
+use crate::integrations::issue_tracker::{IssueFetch, IssueTrackerClient, issues_to_markdown};
+use crate::integrations::local_docs::{DocFileType, LocalDocsProcessor, LocalDocMetadata};
+use crate::integrations::retrieval::{KnowledgeRetriever, knowledge_id};
+use crate::integrations::vector_index::{IndexKind, VectorIndex};
+use crate::llm::client::utils::estimate_tokens;
+use tracing::{info, warn};
 
 /// Metadata about synced knowledge
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,23 +27,36 @@ pub struct KnowledgeMetadata {
     /// Documents organized by category
     #[serde(default)]
     pub categories: HashMap<String, Vec<LocalDocMetadata>>,
+    /// Fingerprints of the synced sources, keyed by file path, page URL or issue key
+    #[serde(default)]
+    pub fingerprints: HashMap<String, SourceFingerprint>,
+    /// ETag of the last remote listing, for sources that support conditional requests
+    #[serde(default)]
+    pub etag: Option<String>,
 }
 
 /// Syncs external knowledge sources to local cache
 pub struct KnowledgeSyncer {
     config: Config,
+    force: bool,
 }
 
 impl KnowledgeSyncer {
     /// Create a new knowledge syncer
     pub fn new(config: Config) -> Result<Self> {
-        Ok(Self { config })
+        Ok(Self { config, force: false })
+    }
+
+    /// Reprocess every source instead of reusing unchanged cached documents
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
     }
 
     /// Sync all configured knowledge sources
     pub async fn sync_all(&self) -> Result<()> {
         let target_lang = self.config.target_language.display_name();
-        println!("🔄 Syncing external knowledge sources (target language: {})...", target_lang);
+        info!("🔄 Syncing external knowledge sources (target language: {})...", target_lang);
 
         let mut synced_any = false;
 
@@ -41,36 +65,64 @@ impl KnowledgeSyncer {
                 self.sync_local_docs(local_docs_config).await?;
                 synced_any = true;
             } else {
-                println!("ℹ️  Local docs integration is disabled");
+                info!("ℹ️  Local docs integration is disabled");
+            }
+        }
+
+        if let Some(ref confluence_config) = self.config.knowledge.confluence {
+            if confluence_config.enabled {
+                self.sync_confluence(confluence_config).await?;
+                synced_any = true;
+            } else {
+                info!("ℹ️  Confluence integration is disabled");
+            }
+        }
+
+        for database_config in self.config.knowledge.databases.iter().filter(|d| d.enabled) {
+            // An unreachable database should not block the other knowledge sources
+            if let Err(e) = self.sync_database(database_config).await {
+                warn!("⚠️  {:#}", e);
+            }
+            synced_any = true;
+        }
+
+        if let Some(ref issues_config) = self.config.knowledge.issues {
+            if issues_config.enabled {
+                self.sync_issues(issues_config).await?;
+                synced_any = true;
+            } else {
+                info!("ℹ️  Issue tracker integration is disabled");
             }
         }
 
         if !synced_any {
-            println!("ℹ️  No knowledge sources are configured");
+            info!("ℹ️  No knowledge sources are configured");
         }
 
-        println!("✅ Knowledge sync completed");
+        if let Some(retrieval) = self.config.knowledge.retrieval.as_ref().filter(|r| r.enabled) {
+            self.prune_knowledge_index(&retrieval.model)?;
+        }
+
+        info!("✅ Knowledge sync completed");
         Ok(())
     }
 
-    /// Sync local documentation files
+    /// Sync local documentation files, reprocessing only files whose content changed
     async fn sync_local_docs(&self, config: &LocalDocsConfig) -> Result<()> {
-        println!("\n📄 Processing local documentation files...");
-
-        let cache_dir = config
-            .cache_dir
-            .clone()
-            .unwrap_or_else(|| {
-                self.config
-                    .internal_path
-                    .join("knowledge")
-                    .join("local_docs")
-            });
+        info!("📄 Processing local documentation files...");
 
+        let cache_dir = self.local_docs_cache_dir(config);
         fs::create_dir_all(&cache_dir).context("Failed to create local docs cache directory")?;
 
+        let previous = self.previous_metadata(&cache_dir);
+        let previous_fingerprints = previous
+            .as_ref()
+            .map(|m| m.fingerprints.clone())
+            .unwrap_or_default();
+
         let mut all_docs = Vec::new();
-        let mut categories_map: HashMap<String, Vec<LocalDocMetadata>> = HashMap::new();
+        let mut fingerprints: HashMap<String, SourceFingerprint> = HashMap::new();
+        let mut report = SyncReport::default();
         let mut processed_count = 0;
         let mut chunked_count = 0;
 
@@ -80,14 +132,48 @@ impl KnowledgeSyncer {
 
         // Process categorized documents
         for category in &config.categories {
-            println!("\n  📁 Processing category: {} ({})", category.name, category.description);
-            
+            info!("  📁 Processing category: {} ({})", category.name, category.description);
+
             let files = LocalDocsProcessor::expand_glob_patterns(&category.paths, Some(project_root));
-            
+
             // Determine chunking config for this category
             let chunking_config = category.chunking.as_ref().or(default_chunking.as_ref());
-            
+
             for file_path in files {
+                let key = file_path.to_string_lossy().to_string();
+                let previous_fingerprint = previous_fingerprints.get(&key);
+
+                // A file matched by several categories is fingerprinted once
+                let fingerprint = match fingerprints.get(&key) {
+                    Some(fingerprint) => fingerprint.clone(),
+                    None => match SourceFingerprint::for_file(&file_path, previous_fingerprint) {
+                        Ok(fingerprint) => {
+                            report.record(&key, previous_fingerprint, &fingerprint);
+                            fingerprints.insert(key.clone(), fingerprint.clone());
+                            fingerprint
+                        }
+                        Err(e) => {
+                            warn!("    ✗ Failed to read {}: {}", file_path.display(), e);
+                            continue;
+                        }
+                    },
+                };
+
+                // Reuse the cached documents of unchanged files
+                let cached = match (&previous, previous_fingerprint) {
+                    (Some(previous), Some(previous_fingerprint))
+                        if !self.force && previous_fingerprint.same_content(&fingerprint) =>
+                    {
+                        Self::cached_docs(previous, &key, &category.name, &category.target_agents)
+                    }
+                    _ => Vec::new(),
+                };
+                if !cached.is_empty() {
+                    all_docs.extend(cached);
+                    processed_count += 1;
+                    continue;
+                }
+
                 match LocalDocsProcessor::process_file_with_chunking(
                     &file_path,
                     &category.name,
@@ -97,119 +183,398 @@ impl KnowledgeSyncer {
                     Ok(doc_metas) => {
                         let is_chunked = doc_metas.len() > 1;
                         if is_chunked {
-                            println!("    ✓ [{}] {} (chunked into {} parts)", 
+                            info!("    ✓ [{}] {} (chunked into {} parts)",
                                 category.name, file_path.display(), doc_metas.len());
                             chunked_count += 1;
                         } else {
-                            println!("    ✓ [{}] {}", category.name, file_path.display());
-                        }
-                        
-                        for doc_meta in doc_metas {
-                            // Add to category-specific map
-                            categories_map
-                                .entry(category.name.clone())
-                                .or_default()
-                                .push(doc_meta.clone());
-                            
-                            // Also add to all_docs for combined access
-                            all_docs.push(doc_meta);
+                            info!("    ✓ [{}] {}", category.name, file_path.display());
                         }
+
+                        all_docs.extend(doc_metas);
                         processed_count += 1;
                     }
                     Err(e) => {
-                        eprintln!("    ✗ Failed to process {}: {}", file_path.display(), e);
+                        warn!("    ✗ Failed to process {}: {}", file_path.display(), e);
+                    }
+                }
+            }
+        }
+
+        report.record_removed(&previous_fingerprints, &fingerprints);
+        Self::save_metadata(&cache_dir, all_docs, fingerprints, None)?;
+        report.log("Local docs");
+
+        if chunked_count > 0 {
+            info!("✅ Processed {} files ({} chunked into multiple parts)", processed_count, chunked_count);
+        } else {
+            info!("✅ Processed {} local documentation files", processed_count);
+        }
+        Ok(())
+    }
+
+    /// Sync Confluence pages, skipping pages whose ETag or version is unchanged
+    async fn sync_confluence(&self, config: &ConfluenceConfig) -> Result<()> {
+        info!("🌐 Fetching Confluence pages from {}...", config.base_url);
+
+        let cache_dir = self.confluence_cache_dir(config);
+        fs::create_dir_all(&cache_dir).context("Failed to create Confluence cache directory")?;
+
+        let previous = self.previous_metadata(&cache_dir);
+        let previous_fingerprints = previous
+            .as_ref()
+            .map(|m| m.fingerprints.clone())
+            .unwrap_or_default();
+
+        let client = ConfluenceClient::new(config)?;
+        let mut all_docs = Vec::new();
+        let mut fingerprints: HashMap<String, SourceFingerprint> = HashMap::new();
+        let mut report = SyncReport::default();
+
+        for category in &config.categories {
+            info!("  📁 Processing category: {} ({})", category.name, category.description);
+
+            let chunking_config = category.chunking.as_ref().or(config.default_chunking.as_ref());
+            let mut seen_pages: HashSet<String> = HashSet::new();
+
+            for page_id in &category.page_ids {
+                seen_pages.insert(page_id.clone());
+                let key = client.page_url(page_id);
+                let previous_fingerprint = previous_fingerprints.get(&key);
+                let etag = previous_fingerprint
+                    .filter(|_| !self.force)
+                    .and_then(|fp| fp.etag.as_deref());
+
+                match client.fetch_page(page_id, etag).await {
+                    Ok(PageFetch::NotModified) => {
+                        let fingerprint = previous_fingerprint.cloned().unwrap_or_default();
+                        if !fingerprints.contains_key(&key) {
+                            report.record(&key, previous_fingerprint, &fingerprint);
+                        }
+                        if let Some(previous) = &previous {
+                            all_docs.extend(Self::cached_docs(
+                                previous,
+                                &key,
+                                &category.name,
+                                &category.target_agents,
+                            ));
+                        }
+                        fingerprints.insert(key, fingerprint);
+                    }
+                    Ok(PageFetch::Fetched(page, etag)) => {
+                        let fingerprint = SourceFingerprint {
+                            etag,
+                            version: page.version_id(),
+                            ..Default::default()
+                        };
+                        if !fingerprints.contains_key(&key) {
+                            report.record(&key, previous_fingerprint, &fingerprint);
+                        }
+                        all_docs.extend(client.page_to_docs(&page, category, chunking_config));
+                        fingerprints.insert(key, fingerprint);
+                    }
+                    Err(e) => warn!("    ✗ Failed to fetch Confluence page {}: {}", page_id, e),
+                }
+            }
+
+            for space_key in &category.space_keys {
+                let pages = match client.fetch_space_pages(space_key).await {
+                    Ok(pages) => pages,
+                    Err(e) => {
+                        warn!("    ✗ Failed to fetch Confluence space {}: {}", space_key, e);
+                        continue;
+                    }
+                };
+
+                for page in pages {
+                    if !seen_pages.insert(page.id.clone()) {
+                        continue;
+                    }
+                    let key = client.page_url(&page.id);
+                    let fingerprint = SourceFingerprint {
+                        version: page.version_id(),
+                        ..Default::default()
+                    };
+                    if !fingerprints.contains_key(&key) {
+                        report.record(&key, previous_fingerprints.get(&key), &fingerprint);
                     }
+                    all_docs.extend(client.page_to_docs(&page, category, chunking_config));
+                    fingerprints.insert(key, fingerprint);
                 }
             }
         }
 
-        // Save metadata
+        report.record_removed(&previous_fingerprints, &fingerprints);
+        let page_count = fingerprints.len();
+        Self::save_metadata(&cache_dir, all_docs, fingerprints, None)?;
+        report.log("Confluence");
+
+        info!("✅ Fetched {} Confluence pages", page_count);
+        Ok(())
+    }
+
+    /// Introspect a live database schema
+    #[cfg(feature = "database")]
+    async fn sync_database(&self, config: &DatabaseSourceConfig) -> Result<()> {
+        info!("🗄️  Introspecting database schema: {}...", config.name);
+
+        let cache_dir = self.database_cache_dir(config)?;
+        fs::create_dir_all(&cache_dir).context("Failed to create database schema cache directory")?;
+
+        let schema = DatabaseSchemaIntrospector::introspect(config)
+            .await
+            .with_context(|| format!("Failed to introspect database '{}'", config.name))?;
+
+        let key = format!("database://{}", config.name);
+        let markdown = schema.to_markdown(&config.name);
+        let fingerprint = SourceFingerprint::for_content(&markdown);
+        let previous_fingerprints = self
+            .previous_metadata(&cache_dir)
+            .map(|m| m.fingerprints)
+            .unwrap_or_default();
+
+        let mut report = SyncReport::default();
+        report.record(&key, previous_fingerprints.get(&key), &fingerprint);
+
+        let docs = LocalDocsProcessor::build_doc_metadata(
+            key.clone(),
+            DocFileType::DatabaseSchema,
+            Utc::now().to_rfc3339(),
+            markdown,
+            &config.category,
+            &config.target_agents,
+            None,
+        );
+        Self::save_metadata(&cache_dir, docs, HashMap::from([(key, fingerprint)]), None)?;
+        report.log(&config.name);
+
+        info!(
+            "✅ Introspected {} tables/views and {} routines from {}",
+            schema.tables.len(),
+            schema.routines.len(),
+            config.name
+        );
+        Ok(())
+    }
+
+    #[cfg(not(feature = "database"))]
+    async fn sync_database(&self, config: &DatabaseSourceConfig) -> Result<()> {
+        anyhow::bail!(
+            "Database '{}' skipped: live database schemas need litho built with the `database` feature",
+            config.name
+        )
+    }
+
+    /// Pull recent epics and issues from the issue tracker
+    async fn sync_issues(&self, config: &IssueTrackerConfig) -> Result<()> {
+        info!("🎫 Fetching recent issues from {:?}...", config.provider);
+
+        let cache_dir = self.issues_cache_dir();
+        fs::create_dir_all(&cache_dir).context("Failed to create issues cache directory")?;
+
+        let previous = self.previous_metadata(&cache_dir);
+        let etag = previous
+            .as_ref()
+            .filter(|_| !self.force)
+            .and_then(|m| m.etag.as_deref());
+
+        let client = IssueTrackerClient::new(config)?;
+        let (issues, etag) = match client.fetch_issues(etag).await? {
+            IssueFetch::Fetched(issues, etag) => (issues, etag),
+            IssueFetch::NotModified => {
+                // Keep the cached issues and only refresh the sync time
+                if let Some(previous) = previous {
+                    Self::save_metadata(&cache_dir, previous.local_docs, previous.fingerprints, previous.etag)?;
+                }
+                info!("✅ Issues are unchanged since the last sync");
+                return Ok(());
+            }
+        };
+
+        let previous_fingerprints = previous.map(|m| m.fingerprints).unwrap_or_default();
+        let mut report = SyncReport::default();
+        let fingerprints: HashMap<String, SourceFingerprint> = issues
+            .iter()
+            .map(|issue| {
+                let fingerprint = SourceFingerprint::for_content(&format!(
+                    "{}|{}|{}",
+                    issue.title,
+                    issue.state,
+                    issue.labels.join(",")
+                ));
+                report.record(&issue.key, previous_fingerprints.get(&issue.key), &fingerprint);
+                (issue.key.clone(), fingerprint)
+            })
+            .collect();
+        report.record_removed(&previous_fingerprints, &fingerprints);
+
+        let source = config
+            .repository
+            .clone()
+            .or_else(|| config.base_url.clone())
+            .unwrap_or_default();
+
+        let docs = LocalDocsProcessor::build_doc_metadata(
+            format!("issues://{}", source),
+            DocFileType::Issues,
+            Utc::now().to_rfc3339(),
+            issues_to_markdown(&source, &issues),
+            &config.category,
+            &config.target_agents,
+            None,
+        );
+        Self::save_metadata(&cache_dir, docs, fingerprints, etag)?;
+        report.log("Issues");
+
+        info!("✅ Fetched {} issues", issues.len());
+        Ok(())
+    }
+
+    /// Drop embeddings of knowledge chunks that no longer exist in any source
+    fn prune_knowledge_index(&self, model: &str) -> Result<()> {
+        let current: HashSet<String> = self
+            .enabled_metadata_files()
+            .iter()
+            .filter_map(|file| Self::read_metadata(file))
+            .flat_map(|metadata| metadata.local_docs)
+            .map(|doc| knowledge_id(&doc))
+            .collect();
+
+        let mut index = VectorIndex::open(&VectorIndex::default_dir(&self.config.internal_path), model);
+        let before = index.len_of(IndexKind::Knowledge);
+        index.retain_kind(IndexKind::Knowledge, |id| current.contains(id));
+        if index.len_of(IndexKind::Knowledge) < before {
+            index.save()?;
+        }
+        Ok(())
+    }
+
+    /// Write a source's metadata file, grouping documents by category
+    fn save_metadata(
+        cache_dir: &Path,
+        docs: Vec<LocalDocMetadata>,
+        fingerprints: HashMap<String, SourceFingerprint>,
+        etag: Option<String>,
+    ) -> Result<()> {
+        let mut categories_map: HashMap<String, Vec<LocalDocMetadata>> = HashMap::new();
+        for doc in &docs {
+            categories_map
+                .entry(doc.category.clone())
+                .or_default()
+                .push(doc.clone());
+        }
+
         let metadata = KnowledgeMetadata {
             last_synced: Utc::now(),
-            local_docs: all_docs,
+            local_docs: docs,
             categories: categories_map,
+            fingerprints,
+            etag,
         };
 
         let metadata_file = cache_dir.join("_metadata.json");
         let metadata_json =
             serde_json::to_string_pretty(&metadata).context("Failed to serialize metadata")?;
         fs::write(&metadata_file, metadata_json).context("Failed to write metadata")?;
-
-        if chunked_count > 0 {
-            println!("✅ Processed {} files ({} chunked into multiple parts)", processed_count, chunked_count);
-        } else {
-            println!("✅ Processed {} local documentation files", processed_count);
-        }
         Ok(())
     }
 
+    /// Metadata written by the previous sync into a cache directory
+    fn previous_metadata(&self, cache_dir: &Path) -> Option<KnowledgeMetadata> {
+        Self::read_metadata(&cache_dir.join("_metadata.json"))
+    }
+
+    /// Documents cached for a source in a category, re-targeted to the current agent list
+    fn cached_docs(
+        previous: &KnowledgeMetadata,
+        file_path: &str,
+        category: &str,
+        target_agents: &[String],
+    ) -> Vec<LocalDocMetadata> {
+        previous
+            .local_docs
+            .iter()
+            .filter(|doc| doc.file_path == file_path && doc.category == category)
+            .map(|doc| LocalDocMetadata {
+                target_agents: target_agents.to_vec(),
+                ..doc.clone()
+            })
+            .collect()
+    }
+
     /// Check if knowledge needs to be re-synced
     pub fn should_sync(&self) -> Result<bool> {
+        if let Some(ref issues_config) = self.config.knowledge.issues
+            && issues_config.enabled
+            && Self::is_older_than(
+                &self.issues_cache_dir().join("_metadata.json"),
+                issues_config.max_age_hours,
+            )
+        {
+            return Ok(true);
+        }
+
+        let database_stale = self
+            .config
+            .knowledge
+            .databases
+            .iter()
+            .filter(|d| d.enabled)
+            .any(|d| {
+                // Invalid names are reported by the sync
+                self.database_cache_dir(d)
+                    .map_or(true, |dir| Self::is_older_than(&dir.join("_metadata.json"), d.max_age_hours))
+            });
+        if database_stale {
+            return Ok(true);
+        }
+
+        if let Some(ref confluence_config) = self.config.knowledge.confluence
+            && confluence_config.enabled
+            && self.confluence_is_stale(confluence_config)
+        {
+            return Ok(true);
+        }
+
         // Check if local docs need syncing
         if let Some(ref local_docs_config) = self.config.knowledge.local_docs {
             if !local_docs_config.enabled {
                 return Ok(false);
             }
 
-            let cache_dir = local_docs_config
-                .cache_dir
-                .clone()
-                .unwrap_or_else(|| {
-                    self.config
-                        .internal_path
-                        .join("knowledge")
-                        .join("local_docs")
-                });
-
-            let metadata_file = cache_dir.join("_metadata.json");
-
-            // Always sync local docs if cache doesn't exist or if watch_for_changes is true
-            if !metadata_file.exists() {
+            // Always sync local docs if the cache doesn't exist yet
+            let Some(metadata) = self.previous_metadata(&self.local_docs_cache_dir(local_docs_config)) else {
                 return Ok(true);
-            }
+            };
 
             if local_docs_config.watch_for_changes {
-                // Check if any source file has been modified since last sync
-                let metadata_content = fs::read_to_string(&metadata_file)?;
-                let metadata: KnowledgeMetadata = serde_json::from_str(&metadata_content)?;
-
-                let mut cached_files: HashSet<PathBuf> = HashSet::new();
-                for doc in &metadata.local_docs {
-                    let cached_path = Path::new(&doc.file_path);
-                    cached_files.insert(Self::normalize_path(cached_path));
+                // Caches written before fingerprints were tracked cannot be compared
+                if metadata.fingerprints.is_empty() {
+                    return Ok(true);
                 }
 
-                let mut current_files: HashSet<PathBuf> = HashSet::new();
                 let project_root = self.config.project_path.as_path();
+                let mut current_files: HashSet<PathBuf> = HashSet::new();
                 for category in &local_docs_config.categories {
-                    let files = LocalDocsProcessor::expand_glob_patterns(&category.paths, Some(project_root));
-                    for file_path in files {
-                        current_files.insert(Self::normalize_path(&file_path));
-                    }
+                    current_files.extend(LocalDocsProcessor::expand_glob_patterns(
+                        &category.paths,
+                        Some(project_root),
+                    ));
                 }
 
                 // Detect new or removed files quickly
-                if current_files.symmetric_difference(&cached_files).next().is_some() {
+                if current_files.len() != metadata.fingerprints.len() {
                     return Ok(true);
                 }
-                
-                
-                
-                
-                // Check if any source file has been modified
-                for doc in &metadata.local_docs {
-                    let source_path = PathBuf::from(&doc.file_path);
-                    if source_path.exists() {
-                        if let Ok(file_metadata) = fs::metadata(&source_path) {
-                            if let Ok(modified) = file_metadata.modified() {
-                                // Convert SystemTime to DateTime<Utc>
-                                let modified_datetime: DateTime<Utc> = modified.into();
-                                // Compare with cached modification time
-                                if modified_datetime > metadata.last_synced {
-                                    return Ok(true);
-                                }
-                            }
-                        }
+
+                // Hash only the files whose modification time changed
+                for file_path in current_files {
+                    let key = file_path.to_string_lossy().to_string();
+                    let Some(previous) = metadata.fingerprints.get(&key) else {
+                        return Ok(true);
+                    };
+                    match SourceFingerprint::for_file(&file_path, Some(previous)) {
+                        Ok(current) if current.same_content(previous) => {}
+                        _ => return Ok(true),
                     }
                 }
                 return Ok(false);
@@ -218,74 +583,292 @@ impl KnowledgeSyncer {
 
         Ok(false)
     }
+
+    fn local_docs_cache_dir(&self, config: &LocalDocsConfig) -> PathBuf {
+        config.cache_dir.clone().unwrap_or_else(|| {
+            self.config
+                .internal_path
+                .join("knowledge")
+                .join("local_docs")
+        })
+    }
     
-    fn normalize_path(path: &Path) -> PathBuf {
-        fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+    /// Remote pages cannot be checked cheaply, so re-fetch once the cache exceeds its max age
+    fn confluence_is_stale(&self, config: &ConfluenceConfig) -> bool {
+        let metadata_file = self.confluence_cache_dir(config).join("_metadata.json");
+        Self::is_older_than(&metadata_file, config.max_age_hours)
+    }
+
+    /// Whether a cached metadata file is missing or older than the given number of hours
+    fn is_older_than(metadata_file: &Path, max_age_hours: u64) -> bool {
+        let Some(metadata) = Self::read_metadata(metadata_file) else {
+            return true;
+        };
+        let age = Utc::now() - metadata.last_synced;
+        age.num_hours() >= max_age_hours as i64
+    }
+
+    fn issues_cache_dir(&self) -> PathBuf {
+        self.config.internal_path.join("knowledge").join("issues")
+    }
+
+    fn database_cache_dir(&self, config: &DatabaseSourceConfig) -> Result<PathBuf> {
+        Ok(self
+            .config
+            .internal_path
+            .join("knowledge")
+            .join("databases")
+            .join(cache_name(&config.name)?))
     }
+
+    fn confluence_cache_dir(&self, config: &ConfluenceConfig) -> PathBuf {
+        config.cache_dir.clone().unwrap_or_else(|| {
+            self.config
+                .internal_path
+                .join("knowledge")
+                .join("confluence")
+        })
+    }
+
+    /// Metadata files of all enabled knowledge sources
+    fn enabled_metadata_files(&self) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+
+        if let Some(cfg) = &self.config.knowledge.local_docs
+            && cfg.enabled
+        {
+            files.push(self.local_docs_cache_dir(cfg).join("_metadata.json"));
+        }
+
+        if let Some(cfg) = &self.config.knowledge.confluence
+            && cfg.enabled
+        {
+            files.push(self.confluence_cache_dir(cfg).join("_metadata.json"));
+        }
+
+        for cfg in self.config.knowledge.databases.iter().filter(|d| d.enabled) {
+            if let Ok(dir) = self.database_cache_dir(cfg) {
+                files.push(dir.join("_metadata.json"));
+            }
+        }
+
+        if let Some(cfg) = &self.config.knowledge.issues
+            && cfg.enabled
+        {
+            files.push(self.issues_cache_dir().join("_metadata.json"));
+        }
+
+        files
+    }
+
+    fn read_metadata(metadata_file: &Path) -> Option<KnowledgeMetadata> {
+        let content = fs::read_to_string(metadata_file).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
     
-    /// Load cached knowledge for a specific category
-    pub fn load_cached_knowledge_by_category(
+    /// Load cached knowledge for several categories, highest priority first.
+    /// With `max_knowledge_tokens` set, lower-priority documents are dropped once the budget is used up.
+    pub fn load_cached_knowledge_by_categories(
         &self,
-        category: &str,
+        categories: &[&str],
         agent_filter: Option<&str>,
     ) -> Result<Option<String>> {
-        let local_docs_config = match &self.config.knowledge.local_docs {
-            Some(cfg) if cfg.enabled => cfg,
-            _ => return Ok(None),
-        };
-
-        let cache_dir = local_docs_config
-            .cache_dir
-            .clone()
-            .unwrap_or_else(|| {
-                self.config
-                    .internal_path
-                    .join("knowledge")
-                    .join("local_docs")
-            });
+        let mut loaded: Vec<(&str, i32, Vec<LocalDocMetadata>, DateTime<Utc>)> = categories
+            .iter()
+            .filter_map(|category| {
+                let (docs, last_synced) = self.collect_category_docs(category, agent_filter);
+                let last_synced = last_synced?;
+                (!docs.is_empty())
+                    .then(|| (*category, self.category_priority(category), docs, last_synced))
+            })
+            .collect();
 
-        let metadata_file = cache_dir.join("_metadata.json");
-        if !metadata_file.exists() {
+        if loaded.is_empty() {
             return Ok(None);
         }
 
-        let metadata_content = fs::read_to_string(&metadata_file)?;
-        let metadata: KnowledgeMetadata = serde_json::from_str(&metadata_content)?;
+        // Stable sort keeps the agent's requested order for equal priorities
+        loaded.sort_by_key(|(_, priority, _, _)| std::cmp::Reverse(*priority));
+
+        let mut remaining_budget = self.config.knowledge.max_knowledge_tokens;
+        let mut formatted = String::new();
+
+        for (category, _, docs, last_synced) in loaded {
+            let total_docs = docs.len();
+            let included: Vec<LocalDocMetadata> = docs
+                .into_iter()
+                .filter(|doc| Self::fits_budget(doc, &mut remaining_budget))
+                .collect();
+
+            let omitted = total_docs - included.len();
+            if omitted > 0 {
+                info!(
+                    "📚 Knowledge budget reached: omitted {} lower-priority document(s) from category '{}'",
+                    omitted, category
+                );
+            }
+            if included.is_empty() {
+                continue;
+            }
+
+            let target_lang = self.config.target_language.display_name();
+            let header = format!(
+                "# {} Documentation ({})\n\nCategory: {}\nLast processed: {}\nDocuments in category: {}\n\n",
+                Self::format_category_name(category),
+                target_lang,
+                category,
+                last_synced.format("%Y-%m-%d %H:%M:%S UTC"),
+                included.len()
+            );
+
+            formatted.push_str(&LocalDocsProcessor::format_for_llm_with_options(
+                &included,
+                Some(&header),
+                false,
+            ));
+            formatted.push_str("\n\n");
+        }
 
-        // Get documents for the specified category
-        let Some(docs) = metadata.categories.get(category) else {
+        if formatted.is_empty() {
             return Ok(None);
-        };
+        }
 
-        let filtered_docs: Vec<LocalDocMetadata> = docs
+        Ok(Some(formatted))
+    }
+
+    /// Retrieve the knowledge chunks most relevant to `query` from several categories.
+    /// Falls back to `None` when nothing is cached; the caller decides how to handle errors.
+    pub async fn retrieve_knowledge_by_categories(
+        &self,
+        retrieval: &RetrievalConfig,
+        categories: &[&str],
+        agent_filter: Option<&str>,
+        query: &str,
+    ) -> Result<Option<String>> {
+        let mut ordered: Vec<&str> = categories.to_vec();
+        ordered.sort_by_key(|category| std::cmp::Reverse(self.category_priority(category)));
+
+        let docs: Vec<LocalDocMetadata> = ordered
             .iter()
-            .cloned()
-            .filter(|doc| Self::doc_visible_to_agent(doc, agent_filter))
+            .flat_map(|category| self.collect_category_docs(category, agent_filter).0)
             .collect();
+        if docs.is_empty() {
+            return Ok(None);
+        }
 
-        if filtered_docs.is_empty() {
+        let total_docs = docs.len();
+        let retriever = KnowledgeRetriever::new(
+            retrieval,
+            &self.config.llm,
+            VectorIndex::default_dir(&self.config.internal_path),
+        );
+        let mut remaining_budget = self.config.knowledge.max_knowledge_tokens;
+        let relevant: Vec<LocalDocMetadata> = retriever
+            .retrieve(query, docs)
+            .await?
+            .into_iter()
+            .filter(|doc| Self::fits_budget(doc, &mut remaining_budget))
+            .collect();
+        if relevant.is_empty() {
             return Ok(None);
         }
 
-        let target_lang = self.config.target_language.display_name();
+        info!(
+            "🎯 Retrieved {} of {} knowledge chunks from categories: {:?}",
+            relevant.len(),
+            total_docs,
+            categories
+        );
         let header = format!(
-            "# {} Documentation ({})\n\nCategory: {}\nLast processed: {}\nDocuments in category: {}\n\n",
-            Self::format_category_name(category),
-            target_lang,
-            category,
-            metadata.last_synced.format("%Y-%m-%d %H:%M:%S UTC"),
-            filtered_docs.len()
+            "# Relevant External Knowledge ({})\n\nThe chunks below were retrieved from categories {} as the most relevant to this task.\n\n",
+            self.config.target_language.display_name(),
+            categories.join(", ")
         );
-
-        let formatted = LocalDocsProcessor::format_for_llm_with_options(
-            &filtered_docs,
+        Ok(Some(LocalDocsProcessor::format_for_llm_with_options(
+            &relevant,
             Some(&header),
-            false,
-        );
+            true,
+        )))
+    }
 
-        Ok(Some(formatted))
+    /// Whether a document fits the remaining token budget, consuming it if so
+    fn fits_budget(doc: &LocalDocMetadata, remaining_budget: &mut Option<usize>) -> bool {
+        let Some(budget) = remaining_budget.as_mut() else {
+            return true;
+        };
+        let tokens = estimate_tokens(&doc.processed_content);
+        let fits = tokens <= *budget;
+        if fits {
+            *budget -= tokens;
+        }
+        fits
     }
-    
+
+    /// Collect the documents of a category from all enabled sources, with the latest sync time
+    fn collect_category_docs(
+        &self,
+        category: &str,
+        agent_filter: Option<&str>,
+    ) -> (Vec<LocalDocMetadata>, Option<DateTime<Utc>>) {
+        let mut filtered_docs: Vec<LocalDocMetadata> = Vec::new();
+        let mut last_synced: Option<DateTime<Utc>> = None;
+
+        for metadata_file in self.enabled_metadata_files() {
+            let Some(metadata) = Self::read_metadata(&metadata_file) else {
+                continue;
+            };
+
+            // Get documents for the specified category
+            let Some(docs) = metadata.categories.get(category) else {
+                continue;
+            };
+
+            filtered_docs.extend(
+                docs.iter()
+                    .filter(|doc| Self::doc_visible_to_agent(doc, agent_filter))
+                    .cloned(),
+            );
+            last_synced = last_synced.max(Some(metadata.last_synced));
+        }
+
+        (filtered_docs, last_synced)
+    }
+
+    /// Configured priority of a category; the highest value wins when several sources share it
+    fn category_priority(&self, category: &str) -> i32 {
+        let knowledge = &self.config.knowledge;
+        let local_docs = knowledge
+            .local_docs
+            .iter()
+            .flat_map(|cfg| cfg.categories.iter())
+            .filter(|c| c.name == category)
+            .map(|c| c.priority);
+        let confluence = knowledge
+            .confluence
+            .iter()
+            .flat_map(|cfg| cfg.categories.iter())
+            .filter(|c| c.name == category)
+            .map(|c| c.priority);
+        let databases = knowledge
+            .databases
+            .iter()
+            .filter(|d| d.category == category)
+            .map(|d| d.priority);
+        let issues = knowledge
+            .issues
+            .iter()
+            .filter(|i| i.category == category)
+            .map(|i| i.priority);
+
+        local_docs
+            .chain(confluence)
+            .chain(databases)
+            .chain(issues)
+            .max()
+            .unwrap_or_default()
+    }
+
     /// Format category name for display
     fn format_category_name(category: &str) -> String {
         match category {
@@ -296,6 +879,7 @@ impl KnowledgeSyncer {
             "adr" => "Architecture Decision Records".to_string(),
             "workflow" => "Workflow & Business Process".to_string(),
             "general" => "General".to_string(),
+            "issues" => "Issue Tracker".to_string(),
             other => other.chars().next().map(|c| c.to_uppercase().to_string()).unwrap_or_default() 
                 + &other.chars().skip(1).collect::<String>(),
         }
@@ -314,3 +898,98 @@ impl KnowledgeSyncer {
         }
     }
 }
+
+/// Name of a knowledge source used as its cache directory: a single path component
+fn cache_name(name: &str) -> Result<&str> {
+    let mut components = Path::new(name).components();
+    match (components.next(), components.next()) {
+        (Some(std::path::Component::Normal(_)), None) if !name.contains(['/', '\\']) => Ok(name),
+        _ => anyhow::bail!(
+            "Invalid knowledge source name '{}': it names a cache directory and may not contain path separators or `..`",
+            name
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DocumentCategory;
+    use crate::integrations::local_docs::DocFileType;
+
+    fn doc(category: &str, content: &str) -> LocalDocMetadata {
+        LocalDocMetadata {
+            file_path: format!("docs/{}.md", category),
+            file_type: DocFileType::Markdown,
+            last_modified: String::new(),
+            processed_content: content.to_string(),
+            category: category.to_string(),
+            target_agents: Vec::new(),
+            chunk_info: None,
+        }
+    }
+
+    fn category(name: &str, priority: i32) -> DocumentCategory {
+        DocumentCategory {
+            name: name.to_string(),
+            description: String::new(),
+            paths: Vec::new(),
+            target_agents: Vec::new(),
+            chunking: None,
+            priority,
+        }
+    }
+
+    #[test]
+    fn test_high_priority_categories_fill_budget_first() {
+        let temp = tempfile::tempdir().unwrap();
+        let internal_path = temp.path().to_path_buf();
+        let cache_dir = internal_path.join("knowledge").join("local_docs");
+        fs::create_dir_all(&cache_dir).unwrap();
+
+        let general = doc("general", &"general notes ".repeat(80));
+        let architecture = doc("architecture", "official architecture spec");
+        let metadata = KnowledgeMetadata {
+            last_synced: Utc::now(),
+            local_docs: vec![general.clone(), architecture.clone()],
+            categories: HashMap::from([
+                ("general".to_string(), vec![general]),
+                ("architecture".to_string(), vec![architecture]),
+            ]),
+            fingerprints: HashMap::new(),
+            etag: None,
+        };
+        fs::write(cache_dir.join("_metadata.json"), serde_json::to_string(&metadata).unwrap()).unwrap();
+
+        let mut config = Config {
+            internal_path: internal_path.clone(),
+            ..Default::default()
+        };
+        config.knowledge.max_knowledge_tokens = Some(100);
+        config.knowledge.local_docs = Some(LocalDocsConfig {
+            enabled: true,
+            categories: vec![category("general", 0), category("architecture", 10)],
+            cache_dir: None,
+            watch_for_changes: false,
+            default_chunking: None,
+        });
+
+        let syncer = KnowledgeSyncer::new(config).unwrap();
+        let knowledge = syncer
+            .load_cached_knowledge_by_categories(&["general", "architecture"], None)
+            .unwrap()
+            .unwrap();
+
+        assert!(knowledge.starts_with("# Architecture Documentation"));
+        assert!(knowledge.contains("official architecture spec"));
+        assert!(!knowledge.contains("general notes"));
+    }
+
+    #[test]
+    fn test_cache_name() {
+        assert_eq!(cache_name("orders-db").unwrap(), "orders-db");
+        for name in ["", ".", "..", "../orders", "/srv/orders", "orders/db", "orders\\db"] {
+            assert!(cache_name(name).is_err(), "{}", name);
+        }
+    }
+}
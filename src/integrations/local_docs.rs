@@ -2,9 +2,17 @@ use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use std::fs;
+use std::io::Read;
 use glob::glob;
+use quick_xml::Reader;
+use quick_xml::events::Event;
 
 use crate::config::ChunkingConfig;
+use crate::integrations::asciidoc::asciidoc_to_markdown;
+use crate::integrations::html::html_to_markdown;
+use crate::integrations::openapi::OpenApiSummarizer;
+use crate::integrations::tabular::TabularFormatter;
+use tracing::warn;
 
 /// Metadata about processed local documentation
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,11 +47,25 @@ pub struct ChunkInfo {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DocFileType {
     Pdf,
+    Docx,
+    Html,
+    Csv,
+    /// Excel or OpenDocument workbook
+    Spreadsheet,
     Markdown,
+    /// AsciiDoc converted to Markdown-like text
+    AsciiDoc,
     Text,
     Sql,
     Yaml,
     Json,
+    Confluence,
+    /// OpenAPI/Swagger spec condensed into an endpoint and schema summary
+    OpenApi,
+    /// Schema introspected from a live database
+    DatabaseSchema,
+    /// Epics and issues pulled from an issue tracker
+    Issues,
 }
 
 /// Document chunker for splitting large documents
@@ -82,8 +104,18 @@ impl DocumentChunker {
     /// Semantic chunking - split by sections/headers (best for Markdown)
     fn chunk_semantic(&self, content: &str, file_type: &DocFileType) -> Vec<DocumentChunk> {
         match file_type {
-            DocFileType::Markdown => self.chunk_markdown_by_sections(content),
+            DocFileType::Markdown
+            | DocFileType::AsciiDoc
+            | DocFileType::Docx
+            | DocFileType::Html
+            | DocFileType::Confluence
+            | DocFileType::OpenApi
+            | DocFileType::DatabaseSchema
+            | DocFileType::Issues => {
+                self.chunk_markdown_by_sections(content)
+            }
             DocFileType::Sql => self.chunk_sql_by_statements(content),
+            DocFileType::Csv | DocFileType::Spreadsheet => self.chunk_table_rows(content),
             DocFileType::Yaml | DocFileType::Json => self.chunk_by_paragraph(content),
             _ => self.chunk_fixed_size(content),
         }
@@ -182,6 +214,60 @@ impl DocumentChunker {
         chunks
     }
     
+    /// Chunk Markdown tables at row boundaries, repeating the section heading and table header
+    fn chunk_table_rows(&self, content: &str) -> Vec<DocumentChunk> {
+        let mut chunks = Vec::new();
+        let mut current_chunk = String::new();
+        let mut section = String::new();
+        let mut header = String::new();
+        let mut previous_line = "";
+        let mut has_rows = false;
+
+        for line in content.lines() {
+            if let Some(title) = line.strip_prefix("## ") {
+                section = title.trim().to_string();
+                header.clear();
+            }
+
+            let is_row = line.starts_with('|') && !header.is_empty();
+            if is_row && has_rows && current_chunk.len() + line.len() >= self.config.max_chunk_size {
+                chunks.push(DocumentChunk {
+                    content: current_chunk.clone(),
+                    chunk_index: chunks.len(),
+                    total_chunks: 0,
+                    section_context: section.clone(),
+                });
+                current_chunk = format!("## {} (continued)\n\n{}", section, header);
+                has_rows = false;
+            }
+
+            current_chunk.push_str(line);
+            current_chunk.push('\n');
+
+            if line.starts_with("|---") {
+                header = format!("{}\n{}\n", previous_line, line);
+            }
+            has_rows |= is_row;
+            previous_line = line;
+        }
+
+        if !current_chunk.trim().is_empty() {
+            chunks.push(DocumentChunk {
+                content: current_chunk,
+                chunk_index: chunks.len(),
+                total_chunks: 0,
+                section_context: section,
+            });
+        }
+
+        let total = chunks.len();
+        for chunk in &mut chunks {
+            chunk.total_chunks = total;
+        }
+
+        chunks
+    }
+
     /// Chunk SQL by statement boundaries (CREATE, ALTER, etc.)
     fn chunk_sql_by_statements(&self, content: &str) -> Vec<DocumentChunk> {
         let mut chunks = Vec::new();
@@ -360,12 +446,110 @@ impl LocalDocsProcessor {
         Ok(text)
     }
 
+    /// Extract text content from a Word (.docx) file
+    pub fn extract_docx_text(docx_path: &Path) -> Result<String> {
+        let file = fs::File::open(docx_path)
+            .with_context(|| format!("Failed to open DOCX file: {:?}", docx_path))?;
+        let mut archive = zip::ZipArchive::new(file)
+            .with_context(|| format!("Failed to read DOCX archive: {:?}", docx_path))?;
+
+        let mut xml = String::new();
+        archive
+            .by_name("word/document.xml")
+            .with_context(|| format!("DOCX file has no word/document.xml: {:?}", docx_path))?
+            .read_to_string(&mut xml)?;
+
+        Self::docx_xml_to_text(&xml)
+            .with_context(|| format!("Failed to parse DOCX content: {:?}", docx_path))
+    }
+
+    /// Convert WordprocessingML into Markdown-like text (headings, list items and table rows)
+    fn docx_xml_to_text(xml: &str) -> Result<String> {
+        let mut reader = Reader::from_str(xml);
+        let mut text = String::new();
+        let mut paragraph = String::new();
+        let mut prefix = String::new();
+        let mut in_text = false;
+        let mut table_depth = 0;
+
+        loop {
+            match reader.read_event()? {
+                Event::Start(e) | Event::Empty(e) => match e.name().as_ref() {
+                    b"w:p" => {
+                        paragraph.clear();
+                        prefix.clear();
+                    }
+                    b"w:pStyle" => {
+                        let style = e
+                            .attributes()
+                            .flatten()
+                            .find(|a| a.key.as_ref() == b"w:val")
+                            .map(|a| String::from_utf8_lossy(&a.value).to_string())
+                            .unwrap_or_default();
+                        if style == "Title" {
+                            prefix = "# ".to_string();
+                        } else if let Some(level) = style
+                            .strip_prefix("Heading")
+                            .and_then(|l| l.parse::<usize>().ok())
+                        {
+                            prefix = format!("{} ", "#".repeat(level.clamp(1, 6)));
+                        }
+                    }
+                    b"w:numPr" if prefix.is_empty() => prefix = "- ".to_string(),
+                    b"w:t" => in_text = true,
+                    b"w:tab" => paragraph.push('\t'),
+                    b"w:br" => paragraph.push('\n'),
+                    b"w:tbl" => table_depth += 1,
+                    _ => {}
+                },
+                Event::Text(e) if in_text => paragraph.push_str(&e.unescape()?),
+                Event::End(e) => match e.name().as_ref() {
+                    b"w:t" => in_text = false,
+                    b"w:p" if table_depth > 0 => {
+                        text.push_str(paragraph.trim());
+                        text.push(' ');
+                    }
+                    b"w:p" if !paragraph.trim().is_empty() => {
+                        text.push_str(&prefix);
+                        text.push_str(paragraph.trim());
+                        text.push_str("\n\n");
+                    }
+                    b"w:tc" => text.push_str("| "),
+                    b"w:tr" => text.push('\n'),
+                    b"w:tbl" => {
+                        table_depth -= 1;
+                        text.push('\n');
+                    }
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        Ok(text.trim().to_string())
+    }
+
+    /// Read an HTML file and convert it to Markdown-like text
+    pub fn read_html(html_path: &Path) -> Result<String> {
+        let html = fs::read_to_string(html_path)
+            .with_context(|| format!("Failed to read HTML file: {:?}", html_path))?;
+        Ok(html_to_markdown(&html))
+    }
+
     /// Read markdown file content
     pub fn read_markdown(md_path: &Path) -> Result<String> {
         fs::read_to_string(md_path)
             .with_context(|| format!("Failed to read Markdown file: {:?}", md_path))
     }
 
+    /// Read an AsciiDoc file and convert it to Markdown-like text
+    pub fn read_asciidoc(adoc_path: &Path) -> Result<String> {
+        let adoc = fs::read_to_string(adoc_path)
+            .with_context(|| format!("Failed to read AsciiDoc file: {:?}", adoc_path))?;
+        Ok(asciidoc_to_markdown(&adoc))
+    }
+
     /// Read text file content
     pub fn read_text(txt_path: &Path) -> Result<String> {
         fs::read_to_string(txt_path)
@@ -404,28 +588,69 @@ impl LocalDocsProcessor {
         target_agents: &[String],
         chunking_config: Option<&ChunkingConfig>,
     ) -> Result<Vec<LocalDocMetadata>> {
-        let file_type = Self::detect_file_type(file_path)?;
+        let mut file_type = Self::detect_file_type(file_path)?;
         
-        let raw_content = match file_type {
+        let mut raw_content = match file_type {
             DocFileType::Pdf => Self::extract_pdf_text(file_path)?,
+            DocFileType::Docx => Self::extract_docx_text(file_path)?,
+            DocFileType::Html => Self::read_html(file_path)?,
+            DocFileType::Csv => TabularFormatter::csv_to_markdown(file_path)?,
+            DocFileType::Spreadsheet => TabularFormatter::spreadsheet_to_markdown(file_path)?,
             DocFileType::Markdown => Self::read_markdown(file_path)?,
+            DocFileType::AsciiDoc => Self::read_asciidoc(file_path)?,
             DocFileType::Text => Self::read_text(file_path)?,
             DocFileType::Sql => Self::read_sql(file_path)?,
             DocFileType::Yaml => Self::read_yaml(file_path)?,
             DocFileType::Json => Self::read_json(file_path)?,
+            DocFileType::Confluence
+            | DocFileType::OpenApi
+            | DocFileType::DatabaseSchema
+            | DocFileType::Issues => {
+                return Err(anyhow::anyhow!("Unsupported local file type: {:?}", file_type));
+            }
         };
 
+        // Condense OpenAPI/Swagger specs instead of passing the raw YAML/JSON through
+        if matches!(file_type, DocFileType::Yaml | DocFileType::Json)
+            && let Some(summary) =
+                OpenApiSummarizer::try_summarize(&raw_content, file_type == DocFileType::Yaml)
+        {
+            raw_content = summary;
+            file_type = DocFileType::OpenApi;
+        }
+
         let metadata = fs::metadata(file_path)?;
         let last_modified = format!("{:?}", metadata.modified()?);
         let file_path_str = file_path.to_string_lossy().to_string();
-        
+
+        Ok(Self::build_doc_metadata(
+            file_path_str,
+            file_type,
+            last_modified,
+            raw_content,
+            category,
+            target_agents,
+            chunking_config,
+        ))
+    }
+
+    /// Build document metadata from already loaded content, chunking it if needed
+    pub fn build_doc_metadata(
+        file_path_str: String,
+        file_type: DocFileType,
+        last_modified: String,
+        raw_content: String,
+        category: &str,
+        target_agents: &[String],
+        chunking_config: Option<&ChunkingConfig>,
+    ) -> Vec<LocalDocMetadata> {
         // Determine if we should chunk
         let config = chunking_config.cloned().unwrap_or_default();
         let chunker = DocumentChunker::new(config);
         
         if !chunker.needs_chunking(&raw_content) {
             // No chunking needed - return single document
-            return Ok(vec![LocalDocMetadata {
+            return vec![LocalDocMetadata {
                 file_path: file_path_str,
                 file_type,
                 last_modified,
@@ -433,14 +658,14 @@ impl LocalDocsProcessor {
                 category: category.to_string(),
                 target_agents: target_agents.to_vec(),
                 chunk_info: None,
-            }]);
+            }];
         }
         
         // Chunk the content
         let chunks = chunker.chunk_content(&raw_content, &file_type);
         
         // Create metadata for each chunk
-        let docs: Vec<LocalDocMetadata> = chunks
+        chunks
             .into_iter()
             .map(|chunk| LocalDocMetadata {
                 file_path: file_path_str.clone(),
@@ -455,9 +680,7 @@ impl LocalDocsProcessor {
                     section_context: chunk.section_context,
                 }),
             })
-            .collect();
-        
-        Ok(docs)
+            .collect()
     }
     
     /// Expand glob patterns to actual file paths
@@ -482,9 +705,11 @@ impl LocalDocsProcessor {
                             if let Some(ext) = entry.extension().and_then(|e| e.to_str()) {
                                 match ext.to_lowercase().as_str() {
                                     // Documentation files
-                                    "pdf" | "md" | "markdown" | "txt" | "text" |
+                                    "pdf" | "docx" | "html" | "htm" | "md" | "markdown" | "adoc" | "asciidoc" | "txt" | "text" |
                                     // Database schema files
                                     "sql" |
+                                    // Data dictionaries and interface matrices
+                                    "csv" | "xlsx" | "xlsm" | "xls" | "ods" |
                                     // API specs and config files
                                     "yaml" | "yml" | "json" => {
                                         files.push(entry);
@@ -496,7 +721,7 @@ impl LocalDocsProcessor {
                     }
                 }
                 Err(e) => {
-                    eprintln!("  ⚠️  Invalid glob pattern '{}': {}", pattern, e);
+                    warn!("  ⚠️  Invalid glob pattern '{}': {}", pattern, e);
                 }
             }
         }
@@ -513,7 +738,12 @@ impl LocalDocsProcessor {
 
         match extension.to_lowercase().as_str() {
             "pdf" => Ok(DocFileType::Pdf),
+            "docx" => Ok(DocFileType::Docx),
+            "html" | "htm" => Ok(DocFileType::Html),
+            "csv" => Ok(DocFileType::Csv),
+            "xlsx" | "xlsm" | "xls" | "ods" => Ok(DocFileType::Spreadsheet),
             "md" | "markdown" => Ok(DocFileType::Markdown),
+            "adoc" | "asciidoc" => Ok(DocFileType::AsciiDoc),
             "txt" | "text" => Ok(DocFileType::Text),
             "sql" => Ok(DocFileType::Sql),
             "yaml" | "yml" => Ok(DocFileType::Yaml),
@@ -602,5 +832,42 @@ mod tests {
             LocalDocsProcessor::detect_file_type(Path::new("notes.txt")).unwrap(),
             DocFileType::Text
         );
+        assert_eq!(
+            LocalDocsProcessor::detect_file_type(Path::new("design.docx")).unwrap(),
+            DocFileType::Docx
+        );
+        assert_eq!(
+            LocalDocsProcessor::detect_file_type(Path::new("architecture.adoc")).unwrap(),
+            DocFileType::AsciiDoc
+        );
+    }
+
+    #[test]
+    fn test_chunk_table_rows_repeats_header() {
+        let chunker = DocumentChunker::new(ChunkingConfig {
+            max_chunk_size: 80,
+            min_size_for_chunking: 0,
+            ..Default::default()
+        });
+        let content = "## fields\n\n| Field | Type |\n|---|---|\n| id | uuid |\n| name | text |\n| status | enum |\n";
+
+        let chunks = chunker.chunk_content(content, &DocFileType::Csv);
+
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[1].content.starts_with("## fields (continued)\n\n| Field | Type |\n|---|---|\n"));
+        assert!(chunks.iter().all(|c| c.section_context == "fields"));
+    }
+
+    #[test]
+    fn test_docx_xml_to_text() {
+        let xml = r#"<w:document><w:body>
+            <w:p><w:pPr><w:pStyle w:val="Heading1"/></w:pPr><w:r><w:t>Order Service</w:t></w:r></w:p>
+            <w:p><w:r><w:t xml:space="preserve">Handles orders &amp; </w:t></w:r><w:r><w:t>refunds.</w:t></w:r></w:p>
+            <w:p><w:pPr><w:numPr><w:ilvl w:val="0"/></w:numPr></w:pPr><w:r><w:t>REST API</w:t></w:r></w:p>
+        </w:body></w:document>"#;
+
+        let text = LocalDocsProcessor::docx_xml_to_text(xml).unwrap();
+
+        assert_eq!(text, "# Order Service\n\nHandles orders & refunds.\n\n- REST API");
     }
 }
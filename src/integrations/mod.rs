@@ -1,4 +1,16 @@
+pub mod asciidoc;
+pub mod confluence;
+#[cfg(feature = "database")]
+pub mod database_schema;
+pub mod freshness;
+pub mod html;
+pub mod issue_tracker;
 pub mod knowledge_sync;
 pub mod local_docs;
+pub mod notifications;
+pub mod openapi;
+pub mod retrieval;
+pub mod tabular;
+pub mod vector_index;
 
 pub use knowledge_sync::KnowledgeSyncer;
@@ -0,0 +1,167 @@
+use anyhow::Result;
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use std::fs;
+use tracing::{info, warn};
+
+use crate::config::{Config, WebhookKind};
+use crate::generator::outlet::run_summary::RunTokenUsage;
+use crate::generator::outlet::{RunStatus, RunSummary};
+
+/// Maximum number of failures listed in chat messages
+const MAX_LISTED_FAILURES: usize = 5;
+
+/// Notify the configured webhooks that a run finished; failures are only logged
+pub async fn notify_run_finished(config: &Config, result: &Result<RunStatus>) {
+    let webhooks = &config.notifications.webhooks;
+    if webhooks.is_empty() {
+        return;
+    }
+
+    let summary = load_summary(config, result);
+    let http = reqwest::Client::new();
+
+    for webhook in webhooks {
+        if !webhook.events.is_empty() && !webhook.events.contains(&summary.status) {
+            continue;
+        }
+        let Some(url) = webhook.resolve_url() else {
+            warn!("⚠️  Webhook has no URL configured, skipping");
+            continue;
+        };
+
+        let mut request = http.post(&url).json(&payload(webhook.kind, &summary));
+        for (name, value) in &webhook.headers {
+            request = request.header(name, value);
+        }
+        match request.send().await.and_then(|r| r.error_for_status()) {
+            Ok(_) => info!("📣 Sent {:?} run notification", webhook.kind),
+            Err(e) => warn!("⚠️  Failed to send {:?} run notification: {}", webhook.kind, e),
+        }
+    }
+}
+
+/// Read this run's `run-summary.json`, or describe the failure when the run aborted before writing it
+fn load_summary(config: &Config, result: &Result<RunStatus>) -> RunSummary {
+    let error = result.as_ref().err().map(|e| format!("{:#}", e));
+    let saved = fs::read_to_string(config.output_path.join("run-summary.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<RunSummary>(&content).ok())
+        // A summary without the error is left over from an earlier run
+        .filter(|summary| error.is_none() || summary.error.is_some());
+
+    saved.unwrap_or_else(|| RunSummary {
+        status: match result {
+            Ok(status) => *status,
+            Err(_) => RunStatus::Failure,
+        },
+        project_name: config.get_project_name(),
+        target_language: config.target_language.to_string(),
+        finished_at: chrono::Utc::now().to_rfc3339(),
+        documents: Vec::new(),
+        failures: Vec::new(),
+        consistency_issues: Vec::new(),
+        confidence_retries: Vec::new(),
+        escalations: Vec::new(),
+        fallbacks: 0,
+        token_usage: RunTokenUsage::default(),
+        durations: BTreeMap::new(),
+        error,
+    })
+}
+
+/// Build the request body for a webhook kind
+fn payload(kind: WebhookKind, summary: &RunSummary) -> Value {
+    match kind {
+        WebhookKind::Generic => serde_json::to_value(summary).unwrap_or(Value::Null),
+        WebhookKind::Slack => json!({ "text": format!("*{}*\n{}", title(summary), details(summary)) }),
+        WebhookKind::Teams => json!({
+            "@type": "MessageCard",
+            "@context": "https://schema.org/extensions",
+            "themeColor": match summary.status {
+                RunStatus::Success => "2EB67D",
+                RunStatus::PartialFailure => "ECB22E",
+                RunStatus::Failure => "E01E5A",
+            },
+            "summary": title(summary),
+            "title": title(summary),
+            "text": details(summary).replace('\n', "<br>"),
+        }),
+    }
+}
+
+fn title(summary: &RunSummary) -> String {
+    match summary.status {
+        RunStatus::Success => format!("✅ Litho documentation for {} generated", summary.project_name),
+        RunStatus::PartialFailure => format!(
+            "⚠️ Litho documentation for {} generated with {} failure(s)",
+            summary.project_name,
+            summary.failures.len()
+        ),
+        RunStatus::Failure => format!("❌ Litho documentation for {} failed", summary.project_name),
+    }
+}
+
+fn details(summary: &RunSummary) -> String {
+    let mut lines = Vec::new();
+    if let Some(error) = &summary.error {
+        lines.push(format!("Error: {}", error));
+    }
+    lines.push(format!("Documents: {}", summary.documents.len()));
+    if let Some(total) = summary.durations.get("total_execution") {
+        lines.push(format!("Duration: {:.0}s", total));
+    }
+    if summary.token_usage.total_tokens > 0 {
+        lines.push(format!(
+            "Tokens: {} ({} LLM calls)",
+            summary.token_usage.total_tokens, summary.token_usage.llm_calls
+        ));
+    }
+    for failure in summary.failures.iter().take(MAX_LISTED_FAILURES) {
+        lines.push(format!("- [{}] {}: {}", failure.stage, failure.item, failure.error));
+    }
+    if summary.failures.len() > MAX_LISTED_FAILURES {
+        lines.push(format!("… and {} more", summary.failures.len() - MAX_LISTED_FAILURES));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::outlet::RunFailure;
+
+    #[test]
+    fn test_payload_formats() {
+        let summary = RunSummary {
+            status: RunStatus::PartialFailure,
+            project_name: "shop".to_string(),
+            target_language: "en".to_string(),
+            finished_at: String::new(),
+            documents: vec!["overview.md".to_string()],
+            failures: vec![RunFailure {
+                stage: "output".to_string(),
+                item: "workflow".to_string(),
+                error: "document not found in memory".to_string(),
+            }],
+            consistency_issues: Vec::new(),
+            confidence_retries: Vec::new(),
+            escalations: Vec::new(),
+            fallbacks: 0,
+            token_usage: RunTokenUsage::default(),
+            durations: BTreeMap::new(),
+            error: None,
+        };
+
+        let slack = payload(WebhookKind::Slack, &summary);
+        assert!(slack["text"].as_str().unwrap().starts_with("*⚠️ Litho documentation for shop generated with 1 failure(s)*"));
+        assert!(slack["text"].as_str().unwrap().contains("- [output] workflow"));
+
+        let teams = payload(WebhookKind::Teams, &summary);
+        assert_eq!(teams["@type"], "MessageCard");
+        assert_eq!(teams["themeColor"], "ECB22E");
+
+        let generic = payload(WebhookKind::Generic, &summary);
+        assert_eq!(generic["status"], "partial_failure");
+    }
+}
@@ -0,0 +1,282 @@
+use serde_json::Value;
+
+/// HTTP methods recognised as operations in an OpenAPI path item
+const HTTP_METHODS: [&str; 8] = ["get", "put", "post", "delete", "options", "head", "patch", "trace"];
+
+/// Maximum number of properties listed per schema
+const MAX_SCHEMA_PROPERTIES: usize = 20;
+
+/// Condenses OpenAPI/Swagger specifications into an endpoint and schema summary
+pub struct OpenApiSummarizer;
+
+impl OpenApiSummarizer {
+    /// Parse the content and summarize it if it is an OpenAPI (3.x) or Swagger (2.0) spec
+    pub fn try_summarize(content: &str, is_yaml: bool) -> Option<String> {
+        let spec: Value = if is_yaml {
+            serde_yaml::from_str(content).ok()?
+        } else {
+            serde_json::from_str(content).ok()?
+        };
+
+        if !Self::is_openapi_spec(&spec) {
+            return None;
+        }
+
+        Some(Self::summarize(&spec))
+    }
+
+    /// Check whether a parsed document is an OpenAPI or Swagger spec
+    pub fn is_openapi_spec(spec: &Value) -> bool {
+        (spec.get("openapi").is_some() || spec.get("swagger").is_some())
+            && spec.get("paths").is_some_and(Value::is_object)
+    }
+
+    /// Build the condensed Markdown summary
+    pub fn summarize(spec: &Value) -> String {
+        let mut summary = String::new();
+
+        let info = spec.get("info");
+        let title = info
+            .and_then(|i| i.get("title"))
+            .and_then(Value::as_str)
+            .unwrap_or("API");
+        let version = info
+            .and_then(|i| i.get("version"))
+            .and_then(Value::as_str)
+            .unwrap_or("unknown");
+        let spec_version = spec
+            .get("openapi")
+            .or_else(|| spec.get("swagger"))
+            .map(Self::value_to_string)
+            .unwrap_or_default();
+
+        summary.push_str(&format!("# OpenAPI: {} (version {})\n\n", title, version));
+        summary.push_str(&format!("Specification: {}\n", spec_version));
+        if let Some(description) = info
+            .and_then(|i| i.get("description"))
+            .and_then(Value::as_str)
+        {
+            summary.push_str(&format!("Description: {}\n", Self::first_line(description)));
+        }
+
+        let servers = Self::servers(spec);
+        if !servers.is_empty() {
+            summary.push_str(&format!("Servers: {}\n", servers.join(", ")));
+        }
+
+        summary.push_str("\n## Endpoints\n\n");
+        if let Some(paths) = spec.get("paths").and_then(Value::as_object) {
+            for (path, item) in paths {
+                for method in HTTP_METHODS {
+                    if let Some(operation) = item.get(method) {
+                        summary.push_str(&Self::format_operation(method, path, operation));
+                    }
+                }
+            }
+        }
+
+        let schemas = spec
+            .get("components")
+            .and_then(|c| c.get("schemas"))
+            .or_else(|| spec.get("definitions"))
+            .and_then(Value::as_object);
+        if let Some(schemas) = schemas
+            && !schemas.is_empty()
+        {
+            summary.push_str("\n## Schemas\n\n");
+            for (name, schema) in schemas {
+                summary.push_str(&Self::format_schema(name, schema));
+            }
+        }
+
+        summary
+    }
+
+    fn servers(spec: &Value) -> Vec<String> {
+        if let Some(servers) = spec.get("servers").and_then(Value::as_array) {
+            return servers
+                .iter()
+                .filter_map(|s| s.get("url").and_then(Value::as_str))
+                .map(str::to_string)
+                .collect();
+        }
+
+        // Swagger 2.0 describes a single server through host + basePath
+        match spec.get("host").and_then(Value::as_str) {
+            Some(host) => {
+                let base_path = spec.get("basePath").and_then(Value::as_str).unwrap_or("");
+                vec![format!("{}{}", host, base_path)]
+            }
+            None => Vec::new(),
+        }
+    }
+
+    fn format_operation(method: &str, path: &str, operation: &Value) -> String {
+        let mut line = format!("- `{} {}`", method.to_uppercase(), path);
+
+        if let Some(summary) = operation
+            .get("summary")
+            .or_else(|| operation.get("description"))
+            .and_then(Value::as_str)
+        {
+            line.push_str(&format!(" — {}", Self::first_line(summary)));
+        }
+
+        if let Some(tags) = operation.get("tags").and_then(Value::as_array) {
+            let tags: Vec<String> = tags.iter().map(Self::value_to_string).collect();
+            if !tags.is_empty() {
+                line.push_str(&format!(" [{}]", tags.join(", ")));
+            }
+        }
+
+        if let Some(parameters) = operation.get("parameters").and_then(Value::as_array) {
+            let params: Vec<String> = parameters
+                .iter()
+                .filter_map(|p| {
+                    if let Some(reference) = p.get("$ref").and_then(Value::as_str) {
+                        return Some(Self::ref_name(reference));
+                    }
+                    let name = p.get("name").and_then(Value::as_str)?;
+                    let location = p.get("in").and_then(Value::as_str).unwrap_or("query");
+                    Some(format!("{}({})", name, location))
+                })
+                .collect();
+            if !params.is_empty() {
+                line.push_str(&format!("; params: {}", params.join(", ")));
+            }
+        }
+
+        if let Some(body) = operation
+            .get("requestBody")
+            .and_then(|b| b.get("content"))
+            .and_then(Value::as_object)
+            .and_then(|content| content.values().next())
+            .and_then(|media| media.get("schema"))
+        {
+            line.push_str(&format!("; body: {}", Self::schema_type(body)));
+        }
+
+        if let Some(responses) = operation.get("responses").and_then(Value::as_object) {
+            let codes: Vec<&str> = responses.keys().map(String::as_str).collect();
+            if !codes.is_empty() {
+                line.push_str(&format!(" → {}", codes.join(", ")));
+            }
+        }
+
+        if operation.get("deprecated").and_then(Value::as_bool) == Some(true) {
+            line.push_str(" (deprecated)");
+        }
+
+        line.push('\n');
+        line
+    }
+
+    fn format_schema(name: &str, schema: &Value) -> String {
+        let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+            return format!("- **{}**: {}\n", name, Self::schema_type(schema));
+        };
+
+        let required: Vec<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|r| r.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        let mut fields: Vec<String> = properties
+            .iter()
+            .take(MAX_SCHEMA_PROPERTIES)
+            .map(|(field, field_schema)| {
+                let marker = if required.contains(&field.as_str()) { "*" } else { "" };
+                format!("{}{}: {}", field, marker, Self::schema_type(field_schema))
+            })
+            .collect();
+        if properties.len() > MAX_SCHEMA_PROPERTIES {
+            fields.push(format!("… {} more", properties.len() - MAX_SCHEMA_PROPERTIES));
+        }
+
+        format!("- **{}**: {}\n", name, fields.join(", "))
+    }
+
+    /// Short type description of a schema (`$ref` names, arrays and enums are resolved)
+    fn schema_type(schema: &Value) -> String {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            return Self::ref_name(reference);
+        }
+        if let Some(items) = schema.get("items") {
+            return format!("{}[]", Self::schema_type(items));
+        }
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            let values: Vec<String> = values.iter().map(Self::value_to_string).collect();
+            return format!("enum({})", values.join("|"));
+        }
+        for combinator in ["allOf", "oneOf", "anyOf"] {
+            if let Some(variants) = schema.get(combinator).and_then(Value::as_array) {
+                let variants: Vec<String> = variants.iter().map(Self::schema_type).collect();
+                return format!("{}({})", combinator, variants.join(", "));
+            }
+        }
+
+        let base = schema.get("type").and_then(Value::as_str).unwrap_or("object");
+        match schema.get("format").and_then(Value::as_str) {
+            Some(format) => format!("{}<{}>", base, format),
+            None => base.to_string(),
+        }
+    }
+
+    fn ref_name(reference: &str) -> String {
+        reference.rsplit('/').next().unwrap_or(reference).to_string()
+    }
+
+    fn first_line(text: &str) -> &str {
+        text.lines().next().unwrap_or("").trim()
+    }
+
+    fn value_to_string(value: &Value) -> String {
+        match value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_openapi_yaml() {
+        let spec = r##"
+openapi: 3.0.1
+info:
+  title: Orders
+  version: "1.2"
+paths:
+  /orders/{id}:
+    get:
+      summary: Get an order
+      parameters:
+        - name: id
+          in: path
+      responses:
+        "200":
+          description: ok
+components:
+  schemas:
+    Order:
+      required: [id]
+      properties:
+        id:
+          type: integer
+        items:
+          type: array
+          items:
+            $ref: "#/components/schemas/Item"
+"##;
+        let summary = OpenApiSummarizer::try_summarize(spec, true).unwrap();
+
+        assert!(summary.contains("# OpenAPI: Orders (version 1.2)"));
+        assert!(summary.contains("- `GET /orders/{id}` — Get an order; params: id(path) → 200"));
+        assert!(summary.contains("- **Order**: id*: integer, items: Item[]"));
+        assert!(OpenApiSummarizer::try_summarize("name: not-a-spec", true).is_none());
+    }
+}
@@ -0,0 +1,198 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::path::PathBuf;
+use tracing::{debug, info};
+
+use crate::config::{LLMConfig, RetrievalConfig};
+use crate::integrations::freshness::hash_bytes;
+use crate::integrations::local_docs::LocalDocMetadata;
+use crate::integrations::vector_index::{IndexItem, IndexKind, VectorIndex};
+
+/// Client for an OpenAI-compatible `/embeddings` endpoint
+pub struct EmbeddingClient {
+    http: reqwest::Client,
+    url: String,
+    api_key: String,
+    model: String,
+    batch_size: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingResponse {
+    data: Vec<EmbeddingData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingData {
+    #[serde(default)]
+    index: usize,
+    embedding: Vec<f32>,
+}
+
+impl EmbeddingClient {
+    /// Create a new client from the retrieval configuration
+    pub fn new(config: &RetrievalConfig, llm: &LLMConfig) -> Self {
+        let (base_url, api_key) = config.resolve_endpoint(llm);
+        Self {
+            http: reqwest::Client::new(),
+            url: format!("{}/embeddings", base_url.trim_end_matches('/')),
+            api_key,
+            model: config.model.clone(),
+            batch_size: config.batch_size.max(1),
+        }
+    }
+
+    /// Embedding model name, used to invalidate indexed vectors when it changes
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Embed texts in batches, returning one vector per input in order
+    pub async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        let mut vectors = Vec::with_capacity(texts.len());
+
+        for batch in texts.chunks(self.batch_size) {
+            let mut request = self.http.post(&self.url).json(&serde_json::json!({
+                "model": self.model,
+                "input": batch,
+            }));
+            if !self.api_key.is_empty() {
+                request = request.bearer_auth(&self.api_key);
+            }
+
+            let mut response: EmbeddingResponse = request
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await
+                .context("Failed to parse embeddings response")?;
+            if response.data.len() != batch.len() {
+                return Err(anyhow::anyhow!(
+                    "Embeddings endpoint returned {} vectors for {} inputs",
+                    response.data.len(),
+                    batch.len()
+                ));
+            }
+
+            response.data.sort_by_key(|d| d.index);
+            vectors.extend(response.data.into_iter().map(|d| d.embedding));
+        }
+
+        Ok(vectors)
+    }
+}
+
+/// Retrieves the knowledge chunks most relevant to a task by embedding similarity
+pub struct KnowledgeRetriever {
+    client: EmbeddingClient,
+    index_dir: PathBuf,
+    top_k: usize,
+}
+
+impl KnowledgeRetriever {
+    /// Create a retriever that stores chunk embeddings in the vector index at `index_dir`
+    pub fn new(config: &RetrievalConfig, llm: &LLMConfig, index_dir: PathBuf) -> Self {
+        Self {
+            client: EmbeddingClient::new(config, llm),
+            index_dir,
+            top_k: config.top_k.max(1),
+        }
+    }
+
+    /// Return the `top_k` documents most similar to the query, most relevant first
+    pub async fn retrieve(
+        &self,
+        query: &str,
+        docs: Vec<LocalDocMetadata>,
+    ) -> Result<Vec<LocalDocMetadata>> {
+        if docs.len() <= self.top_k {
+            return Ok(docs);
+        }
+
+        let mut index = VectorIndex::open(&self.index_dir, self.client.model());
+        let items: Vec<IndexItem> = docs.iter().map(knowledge_index_item).collect();
+        let embedded = index.upsert(&self.client, IndexKind::Knowledge, items).await?;
+        if embedded > 0 {
+            info!("🧮 Embedded {} new knowledge chunks", embedded);
+            index.save()?;
+        }
+
+        let query_vector = self
+            .client
+            .embed(&[query.to_string()])
+            .await?
+            .pop()
+            .context("Embeddings endpoint returned no vector for the query")?;
+        let vectors: Vec<&[f32]> = docs
+            .iter()
+            .map(|doc| {
+                index
+                    .vector(IndexKind::Knowledge, &knowledge_id(doc), &doc.processed_content)
+                    .unwrap_or_default()
+            })
+            .collect();
+        let ranked = rank_by_similarity(&query_vector, &vectors, self.top_k);
+
+        debug!("🎯 Retrieved {} of {} knowledge chunks", ranked.len(), docs.len());
+        let mut docs: Vec<Option<LocalDocMetadata>> = docs.into_iter().map(Some).collect();
+        Ok(ranked.into_iter().filter_map(|i| docs[i].take()).collect())
+    }
+}
+
+/// Index identifier of a knowledge chunk; identical chunks share one embedding
+pub fn knowledge_id(doc: &LocalDocMetadata) -> String {
+    hash_bytes(doc.processed_content.as_bytes())
+}
+
+fn knowledge_index_item(doc: &LocalDocMetadata) -> IndexItem {
+    IndexItem {
+        id: knowledge_id(doc),
+        source: doc.file_path.clone(),
+        title: doc
+            .chunk_info
+            .as_ref()
+            .map(|chunk| chunk.section_context.clone())
+            .filter(|title| !title.is_empty())
+            .unwrap_or_else(|| doc.file_path.clone()),
+        text: doc.processed_content.clone(),
+    }
+}
+
+/// Cosine similarity of two vectors; 0 when either is empty or zero
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+/// Indices of the `k` vectors most similar to the query, most similar first
+pub fn rank_by_similarity(query: &[f32], vectors: &[&[f32]], k: usize) -> Vec<usize> {
+    let mut scored: Vec<(usize, f32)> = vectors
+        .iter()
+        .enumerate()
+        .map(|(i, vector)| (i, cosine_similarity(query, vector)))
+        .collect();
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+    scored.into_iter().take(k).map(|(i, _)| i).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_by_similarity() {
+        let vectors: [&[f32]; 3] = [&[0.0, 1.0], &[1.0, 0.1], &[0.7, 0.7]];
+
+        assert_eq!(rank_by_similarity(&[1.0, 0.0], &vectors, 2), vec![1, 2]);
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[]), 0.0);
+    }
+}
@@ -0,0 +1,112 @@
+use anyhow::{Context, Result};
+use calamine::{Reader, open_workbook_auto};
+use std::path::Path;
+
+/// Maximum number of data rows rendered per table; the remainder is summarized
+const MAX_TABLE_ROWS: usize = 500;
+
+/// Renders tabular knowledge files (CSV, Excel, ODS) as Markdown tables
+pub struct TabularFormatter;
+
+impl TabularFormatter {
+    /// Read a CSV file and render it as a Markdown table
+    pub fn csv_to_markdown(csv_path: &Path) -> Result<String> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_path(csv_path)
+            .with_context(|| format!("Failed to open CSV file: {:?}", csv_path))?;
+
+        let rows = reader
+            .records()
+            .map(|record| record.map(|r| r.iter().map(str::to_string).collect()))
+            .collect::<Result<Vec<Vec<String>>, _>>()
+            .with_context(|| format!("Failed to parse CSV file: {:?}", csv_path))?;
+
+        let title = csv_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Ok(Self::rows_to_markdown(&title, &rows))
+    }
+
+    /// Read every sheet of a workbook (xlsx, xls, ods) and render each as a Markdown table
+    pub fn spreadsheet_to_markdown(workbook_path: &Path) -> Result<String> {
+        let mut workbook = open_workbook_auto(workbook_path)
+            .with_context(|| format!("Failed to open spreadsheet: {:?}", workbook_path))?;
+
+        let mut sections = Vec::new();
+        for sheet_name in workbook.sheet_names() {
+            let range = workbook
+                .worksheet_range(&sheet_name)
+                .with_context(|| format!("Failed to read sheet '{}' of {:?}", sheet_name, workbook_path))?;
+            let rows: Vec<Vec<String>> = range
+                .rows()
+                .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+                .collect();
+            if rows.iter().any(|row| row.iter().any(|cell| !cell.trim().is_empty())) {
+                sections.push(Self::rows_to_markdown(&sheet_name, &rows));
+            }
+        }
+
+        Ok(sections.join("\n"))
+    }
+
+    /// Render rows as a Markdown table under a `##` heading; the first non-empty row is the header
+    pub fn rows_to_markdown(title: &str, rows: &[Vec<String>]) -> String {
+        let mut rows = rows
+            .iter()
+            .filter(|row| row.iter().any(|cell| !cell.trim().is_empty()));
+        let mut out = format!("## {}\n\n", title);
+
+        let Some(header) = rows.next() else {
+            return out;
+        };
+        let data: Vec<&Vec<String>> = rows.collect();
+        let columns = data.iter().map(|r| r.len()).chain([header.len()]).max().unwrap_or(0);
+
+        out.push_str(&Self::format_row(header, columns));
+        out.push_str(&format!("|{}\n", "---|".repeat(columns)));
+        for row in data.iter().take(MAX_TABLE_ROWS) {
+            out.push_str(&Self::format_row(row, columns));
+        }
+        if data.len() > MAX_TABLE_ROWS {
+            out.push_str(&format!("\n… {} more rows omitted\n", data.len() - MAX_TABLE_ROWS));
+        }
+
+        out
+    }
+
+    fn format_row(row: &[String], columns: usize) -> String {
+        let cells: Vec<String> = (0..columns)
+            .map(|i| {
+                row.get(i)
+                    .map(|cell| cell.trim().replace('|', "\\|").replace(['\r', '\n'], " "))
+                    .unwrap_or_default()
+            })
+            .collect();
+        format!("| {} |\n", cells.join(" | "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rows_to_markdown() {
+        let rows = vec![
+            vec!["Field".to_string(), "Description".to_string()],
+            vec![],
+            vec!["order_id".to_string(), "Primary key | UUID".to_string()],
+            vec!["status".to_string()],
+        ];
+
+        let markdown = TabularFormatter::rows_to_markdown("orders.csv", &rows);
+
+        assert_eq!(
+            markdown,
+            "## orders.csv\n\n| Field | Description |\n|---|---|\n| order_id | Primary key \\| UUID |\n| status |  |\n"
+        );
+    }
+}
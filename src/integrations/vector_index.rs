@@ -0,0 +1,280 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+use crate::integrations::freshness::hash_bytes;
+use crate::integrations::retrieval::{EmbeddingClient, cosine_similarity};
+
+/// File name of the index inside the index directory
+const INDEX_FILE: &str = "vectors.json";
+
+/// Kind of artifact an index entry was built from
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexKind {
+    /// Chunk of synced external knowledge
+    Knowledge,
+    /// Insight about a source file or directory
+    CodeInsight,
+    /// Public interface of a source file
+    Interface,
+    /// Section of a generated document
+    Document,
+}
+
+/// Text to be embedded and stored in the index
+#[derive(Debug, Clone)]
+pub struct IndexItem {
+    /// Unique identifier within its kind
+    pub id: String,
+    /// File path, URL or document the text was taken from
+    pub source: String,
+    pub title: String,
+    pub text: String,
+}
+
+/// Embedded item stored in the index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexEntry {
+    pub id: String,
+    pub kind: IndexKind,
+    pub source: String,
+    pub title: String,
+    pub text: String,
+    /// Content hash used to skip re-embedding unchanged text
+    pub hash: String,
+    pub vector: Vec<f32>,
+}
+
+/// Search result with its cosine similarity to the query
+#[derive(Debug, Clone)]
+pub struct SearchHit<'a> {
+    pub entry: &'a IndexEntry,
+    pub score: f32,
+}
+
+/// Lightweight on-disk vector index under `.litho/index`.
+/// Search is an exact cosine scan, which stays fast for the few thousand entries of a project.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct VectorIndex {
+    /// Embedding model the vectors were produced with
+    model: String,
+    entries: Vec<IndexEntry>,
+    #[serde(skip)]
+    dir: PathBuf,
+}
+
+impl VectorIndex {
+    /// Default index directory for a project's internal path
+    pub fn default_dir(internal_path: &Path) -> PathBuf {
+        internal_path.join("index")
+    }
+
+    /// Load the index in `dir` as stored, whatever model built it
+    pub fn load(dir: &Path) -> Option<Self> {
+        let content = fs::read_to_string(dir.join(INDEX_FILE)).ok()?;
+        let index = serde_json::from_str::<VectorIndex>(&content).ok()?;
+        Some(Self {
+            dir: dir.to_path_buf(),
+            ..index
+        })
+    }
+
+    /// Open the index in `dir`; vectors built with a different model are discarded
+    pub fn open(dir: &Path, model: &str) -> Self {
+        match Self::load(dir) {
+            Some(index) if index.model == model => index,
+            Some(_) => {
+                warn!("⚠️  Embedding model changed, rebuilding the vector index");
+                Self::empty(dir, model)
+            }
+            None => Self::empty(dir, model),
+        }
+    }
+
+    fn empty(dir: &Path, model: &str) -> Self {
+        Self {
+            model: model.to_string(),
+            entries: Vec::new(),
+            dir: dir.to_path_buf(),
+        }
+    }
+
+    /// Persist the index to disk
+    pub fn save(&self) -> Result<()> {
+        fs::create_dir_all(&self.dir).context("Failed to create vector index directory")?;
+        let json = serde_json::to_string(self).context("Failed to serialize vector index")?;
+        fs::write(self.dir.join(INDEX_FILE), json).context("Failed to write vector index")
+    }
+
+    /// Embedding model the vectors were produced with
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// All entries of the index
+    pub fn entries(&self) -> &[IndexEntry] {
+        &self.entries
+    }
+
+    /// Number of entries of the given kind
+    pub fn len_of(&self, kind: IndexKind) -> usize {
+        self.entries.iter().filter(|e| e.kind == kind).count()
+    }
+
+    /// Add or update items, embedding only text that is new or changed; returns the number embedded
+    pub async fn upsert(
+        &mut self,
+        client: &EmbeddingClient,
+        kind: IndexKind,
+        items: Vec<IndexItem>,
+    ) -> Result<usize> {
+        let existing: HashMap<(&str, &str), &[f32]> = self
+            .entries
+            .iter()
+            .filter(|e| e.kind == kind)
+            .map(|e| ((e.id.as_str(), e.hash.as_str()), e.vector.as_slice()))
+            .collect();
+
+        let mut seen: HashSet<String> = HashSet::new();
+        let mut reused: Vec<IndexEntry> = Vec::new();
+        let mut pending: Vec<(IndexItem, String)> = Vec::new();
+        for item in items {
+            if !seen.insert(item.id.clone()) {
+                continue;
+            }
+            let hash = hash_bytes(item.text.as_bytes());
+            let vector = existing.get(&(item.id.as_str(), hash.as_str())).map(|v| v.to_vec());
+            match vector {
+                Some(vector) => reused.push(Self::entry(item, kind, hash, vector)),
+                None => pending.push((item, hash)),
+            }
+        }
+
+        let texts: Vec<String> = pending.iter().map(|(item, _)| item.text.clone()).collect();
+        let vectors = if texts.is_empty() {
+            Vec::new()
+        } else {
+            client.embed(&texts).await?
+        };
+        let embedded = pending.len();
+
+        let updated: Vec<IndexEntry> = reused
+            .into_iter()
+            .chain(
+                pending
+                    .into_iter()
+                    .zip(vectors)
+                    .map(|((item, hash), vector)| Self::entry(item, kind, hash, vector)),
+            )
+            .collect();
+        let updated_ids: Vec<&str> = updated.iter().map(|e| e.id.as_str()).collect();
+        self.entries
+            .retain(|e| e.kind != kind || !updated_ids.contains(&e.id.as_str()));
+        self.entries.extend(updated);
+
+        Ok(embedded)
+    }
+
+    /// Replace all entries of a kind with `items`, dropping entries that no longer exist
+    pub async fn replace_kind(
+        &mut self,
+        client: &EmbeddingClient,
+        kind: IndexKind,
+        items: Vec<IndexItem>,
+    ) -> Result<usize> {
+        let ids: HashSet<String> = items.iter().map(|item| item.id.clone()).collect();
+        let embedded = self.upsert(client, kind, items).await?;
+        self.retain_kind(kind, |id| ids.contains(id));
+        Ok(embedded)
+    }
+
+    /// Keep only the entries of a kind whose identifier satisfies the predicate
+    pub fn retain_kind(&mut self, kind: IndexKind, mut keep: impl FnMut(&str) -> bool) {
+        self.entries.retain(|e| e.kind != kind || keep(&e.id));
+    }
+
+    /// Stored vector of an entry, if its text is unchanged
+    pub fn vector(&self, kind: IndexKind, id: &str, text: &str) -> Option<&[f32]> {
+        let hash = hash_bytes(text.as_bytes());
+        self.entries
+            .iter()
+            .find(|e| e.kind == kind && e.id == id && e.hash == hash)
+            .map(|e| e.vector.as_slice())
+    }
+
+    /// The `k` entries most similar to the query vector, optionally restricted to some kinds
+    pub fn search(&self, query: &[f32], k: usize, kinds: &[IndexKind]) -> Vec<SearchHit<'_>> {
+        let mut hits: Vec<SearchHit> = self
+            .entries
+            .iter()
+            .filter(|e| kinds.is_empty() || kinds.contains(&e.kind))
+            .map(|entry| SearchHit {
+                entry,
+                score: cosine_similarity(query, &entry.vector),
+            })
+            .collect();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+        hits.truncate(k);
+        hits
+    }
+
+    fn entry(item: IndexItem, kind: IndexKind, hash: String, vector: Vec<f32>) -> IndexEntry {
+        IndexEntry {
+            id: item.id,
+            kind,
+            source: item.source,
+            title: item.title,
+            text: item.text,
+            hash,
+            vector,
+        }
+    }
+
+    /// Log the size of the index per kind
+    pub fn log_stats(&self) {
+        info!(
+            "🗂️  Vector index: {} knowledge, {} code insight, {} interface, {} document entries",
+            self.len_of(IndexKind::Knowledge),
+            self.len_of(IndexKind::CodeInsight),
+            self.len_of(IndexKind::Interface),
+            self.len_of(IndexKind::Document)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_filters_by_kind_and_ranks_by_similarity() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().to_path_buf();
+        let mut index = VectorIndex::open(&dir, "test-model");
+        for (id, kind, vector) in [
+            ("a", IndexKind::Document, vec![1.0, 0.0]),
+            ("b", IndexKind::Interface, vec![1.0, 0.0]),
+            ("c", IndexKind::Document, vec![0.6, 0.8]),
+        ] {
+            let item = IndexItem {
+                id: id.to_string(),
+                source: String::new(),
+                title: id.to_string(),
+                text: id.to_string(),
+            };
+            index.entries.push(VectorIndex::entry(item, kind, hash_bytes(id.as_bytes()), vector));
+        }
+        index.save().unwrap();
+
+        let reopened = VectorIndex::open(&dir, "test-model");
+        let hits = reopened.search(&[1.0, 0.0], 5, &[IndexKind::Document]);
+        let ids: Vec<&str> = hits.iter().map(|h| h.entry.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "c"]);
+        assert!(reopened.vector(IndexKind::Interface, "b", "b").is_some());
+        assert_eq!(VectorIndex::open(&dir, "other-model").len_of(IndexKind::Document), 0);
+    }
+}
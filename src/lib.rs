@@ -0,0 +1,49 @@
+//! Litho (deepwiki-rs) as a library: embed documentation generation in other Rust tools
+//! (IDE extensions, CI bots) instead of shelling out to the `deepwiki-rs` binary.
+//!
+//! [`launch`] runs the whole workflow for a [`Config`], exactly like the binary does:
+//!
+//! ```no_run
+//! # async fn run() -> anyhow::Result<()> {
+//! let mut config = deepwiki_rs::Config::default();
+//! config.project_path = "path/to/project".into();
+//! config.output_path = "path/to/project/litho.docs".into();
+//! let status = deepwiki_rs::launch(&config).await?;
+//! println!("{:?}", status);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! To drive the pipeline stage by stage or read its intermediate results, create a
+//! [`GeneratorContext`] with [`create_context`], run [`run_project`] on it and read the
+//! memory with [`GeneratorContext::get_from_memory`] and [`GeneratorContext::list_memory_keys`],
+//! using the scopes and keys of [`generator::preprocess::memory`],
+//! [`generator::research::memory`] and [`generator::compose::memory`].
+//!
+//! Support for further languages is added with
+//! [`LanguageProcessorManager::register`](generator::preprocess::extractors::language_processors::LanguageProcessorManager::register).
+
+pub mod api_server;
+pub mod ask;
+pub mod cache;
+pub mod cli;
+pub mod config;
+pub mod docs_server;
+pub mod generator;
+mod http;
+pub mod i18n;
+pub mod integrations;
+pub mod llm;
+pub mod mcp;
+pub mod memory;
+pub mod metrics;
+pub mod types;
+pub mod utils;
+
+pub use config::Config;
+pub use generator::context::GeneratorContext;
+pub use generator::outlet::{RunStatus, RunSummary};
+pub use generator::workflow::{create_context, launch, run_project};
+pub use i18n::TargetLanguage;
+pub use llm::client::LLMClient;
+pub use memory::Memory;
@@ -1,21 +1,29 @@
 //! Agent builder - Responsible for building and configuring LLM Agent
 
+use std::sync::Arc;
+
 use crate::{
     config::Config,
     llm::client::providers::{ProviderAgent, ProviderClient},
     llm::tools::{file_explorer::AgentToolFileExplorer, file_reader::AgentToolFileReader},
+    utils::secret_redactor::RedactionLog,
 };
 
 /// Agent builder
 pub struct AgentBuilder<'a> {
     client: &'a ProviderClient,
     config: &'a Config,
+    redactions: &'a Arc<RedactionLog>,
 }
 
 impl<'a> AgentBuilder<'a> {
     /// Create a new Agent builder
-    pub fn new(client: &'a ProviderClient, config: &'a Config) -> Self {
-        Self { client, config }
+    pub fn new(client: &'a ProviderClient, config: &'a Config, redactions: &'a Arc<RedactionLog>) -> Self {
+        Self {
+            client,
+            config,
+            redactions,
+        }
     }
 
     /// Build Agent with built-in preset tools
@@ -24,7 +32,7 @@ impl<'a> AgentBuilder<'a> {
 
         if !llm_config.disable_preset_tools {
             let file_explorer = AgentToolFileExplorer::new(self.config.clone());
-            let file_reader = AgentToolFileReader::new(self.config.clone());
+            let file_reader = AgentToolFileReader::new(self.config.clone(), self.redactions.clone());
 
             let system_prompt_with_tools = format!(
                 "{}\nDo not fabricate non-existent code. If you need to learn more about the project structure and source code content, actively call tools to obtain more contextual information",
@@ -46,8 +54,11 @@ impl<'a> AgentBuilder<'a> {
 
     /// Build Agent without tools
     pub fn build_agent_without_tools(&self, system_prompt: &str) -> ProviderAgent {
-        let llm_config = &self.config.llm;
-        self.client
-            .create_agent(&llm_config.model_efficient, system_prompt, llm_config)
+        self.build_agent_without_tools_for_model(system_prompt, &self.config.llm.model_efficient)
+    }
+
+    /// Build Agent without tools using the given model
+    pub fn build_agent_without_tools_for_model(&self, system_prompt: &str, model: &str) -> ProviderAgent {
+        self.client.create_agent(model, system_prompt, &self.config.llm)
     }
 }
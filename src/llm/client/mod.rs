@@ -4,15 +4,25 @@ use anyhow::Result;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+use tokio::sync::{Semaphore, SemaphorePermit};
 
-use crate::{config::Config, llm::client::utils::evaluate_befitting_model};
+use crate::{
+    config::Config, llm::client::utils::evaluate_befitting_model, metrics::metrics,
+    utils::secret_redactor::RedactionLog,
+};
+use tracing::{debug, warn};
 
 mod agent_builder;
+mod mock;
 mod ollama_extractor;
 mod openai_compatible_extractor;
 mod providers;
 mod react;
 mod react_executor;
+mod retry;
 mod summary_reasoner;
 pub mod types;
 pub mod utils;
@@ -23,27 +33,79 @@ use agent_builder::AgentBuilder;
 use providers::ProviderClient;
 use react_executor::ReActExecutor;
 use summary_reasoner::SummaryReasoner;
+use types::{TokenUsage, TokenUsageRecord, TokenUsageTracker};
 
 /// LLM client - Provides unified LLM service interface
 #[derive(Clone)]
 pub struct LLMClient {
     config: Config,
     client: ProviderClient,
+    usage_tracker: Arc<TokenUsageTracker>,
+    /// Calls answered by a fallback (other model or summary reasoning) instead of the primary agent
+    fallbacks: Arc<AtomicUsize>,
+    /// Bounds concurrent LLM requests across all agents to `llm.max_parallels`
+    request_limit: Arc<Semaphore>,
+    /// Secrets redacted from the files read by agent tools
+    redactions: Arc<RedactionLog>,
 }
 
 impl LLMClient {
-    /// Create a new LLM client
-    pub fn new(config: Config) -> Result<Self> {
+    /// Create a new LLM client; redactions in files read by its agents go to `redactions`
+    pub fn new(config: Config, redactions: Arc<RedactionLog>) -> Result<Self> {
         let client = ProviderClient::new(&config.llm)?;
-        Ok(Self { client, config })
+        Ok(Self {
+            client,
+            usage_tracker: Arc::new(TokenUsageTracker::default()),
+            fallbacks: Arc::new(AtomicUsize::new(0)),
+            request_limit: Arc::new(Semaphore::new(config.llm.max_parallels.max(1))),
+            redactions,
+            config,
+        })
+    }
+
+    /// Record the token usage of a completed (non-cached) LLM call of the agent
+    pub fn record_token_usage(&self, agent: &str, model: &str, usage: &TokenUsage) {
+        self.usage_tracker.record(agent, model, usage);
+        metrics().record_tokens(usage);
+    }
+
+    /// Attribute the token usage of the following calls to the workflow phase
+    pub fn set_usage_phase(&self, phase: &str) {
+        self.usage_tracker.set_phase(phase);
+    }
+
+    /// Number of LLM calls and their accumulated token usage
+    pub fn token_usage(&self) -> (usize, TokenUsage) {
+        (self.usage_tracker.calls(), self.usage_tracker.total())
+    }
+
+    /// Token usage per agent, model and phase
+    pub fn token_usage_records(&self) -> Vec<TokenUsageRecord> {
+        self.usage_tracker.records()
+    }
+
+    /// Number of calls that fell back to another model or to summary reasoning
+    pub fn fallback_count(&self) -> usize {
+        self.fallbacks.load(Ordering::Relaxed)
+    }
+
+    fn record_fallback(&self) {
+        self.fallbacks.fetch_add(1, Ordering::Relaxed);
+        metrics().record_llm_fallback();
+    }
+
+    /// Wait for a free request slot; held for the whole call including retries and fallbacks
+    async fn acquire_request_slot(&self) -> Result<SemaphorePermit<'_>> {
+        Ok(self.request_limit.acquire().await?)
     }
 
     /// Get Agent builder
     fn get_agent_builder(&self) -> AgentBuilder<'_> {
-        AgentBuilder::new(&self.client, &self.config)
+        AgentBuilder::new(&self.client, &self.config, &self.redactions)
     }
 
-    /// Generic retry logic for handling async operation retry mechanism
+    /// Retry failed calls with exponential backoff and jitter, waiting as long as rate-limited
+    /// providers ask; authentication and quota errors are returned at once
     async fn retry_with_backoff<T, F, Fut>(&self, operation: F) -> Result<T>
     where
         F: Fn() -> Fut,
@@ -51,22 +113,37 @@ impl LLMClient {
     {
         let llm_config = &self.config.llm;
         let max_retries = llm_config.retry_attempts;
-        let retry_delay_ms = llm_config.retry_delay_ms;
         let mut retries = 0;
 
         loop {
-            match operation().await {
+            let started = Instant::now();
+            let result = operation().await;
+            metrics().record_llm_call(started.elapsed(), result.is_ok());
+            match result {
                 Ok(result) => return Ok(result),
                 Err(err) => {
+                    let failure = retry::classify(&err);
+                    if failure == retry::Failure::Fatal {
+                        warn!("❌ Model service call failed, not retrying: {}", err);
+                        return Err(err);
+                    }
                     retries += 1;
-                    eprintln!(
+                    warn!(
                         "❌ Model service call error, retrying (attempt {} / {}): {}",
                         retries, max_retries, err
                     );
                     if retries >= max_retries {
                         return Err(err);
                     }
-                    tokio::time::sleep(std::time::Duration::from_millis(retry_delay_ms)).await;
+                    let delay = retry::delay(
+                        failure,
+                        retries,
+                        llm_config.retry_delay_ms,
+                        llm_config.retry_max_delay_ms,
+                        rand::random::<f64>(),
+                    );
+                    debug!("   ⏳ Waiting {}ms before retrying", delay.as_millis());
+                    tokio::time::sleep(delay).await;
                 }
             }
         }
@@ -77,13 +154,30 @@ impl LLMClient {
     where
         T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
     {
+        let _slot = self.acquire_request_slot().await?;
         let (befitting_model, fallover_model) =
             evaluate_befitting_model(&self.config.llm, system_prompt, user_prompt);
+        debug!(
+            "   🧭 Selected model {} (fallback: {})",
+            befitting_model,
+            fallover_model.as_deref().unwrap_or("none")
+        );
 
         self.extract_inner(system_prompt, user_prompt, befitting_model, fallover_model)
             .await
     }
 
+    /// Data extraction with the given model, without fallback
+    pub async fn extract_with_model<T>(&self, system_prompt: &str, user_prompt: &str, model: &str) -> Result<T>
+    where
+        T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
+    {
+        let _slot = self.acquire_request_slot().await?;
+        debug!("   🧭 Selected model {} (fallback: none)", model);
+        self.extract_inner(system_prompt, user_prompt, model.to_string(), None)
+            .await
+    }
+
     async fn extract_inner<T>(
         &self,
         system_prompt: &str,
@@ -105,7 +199,8 @@ impl LLMClient {
                 Ok(r) => Ok(r),
                 Err(e) => match fallover_model {
                     Some(ref model) => {
-                        eprintln!("⚠️ Main model failed, switching to fallback model {}...", model);
+                        warn!("⚠️ Main model failed, switching to fallback model {}...", model);
+                        self.record_fallback();
                         let user_prompt_with_fixer = format!("{}\n\n**Notice** There was an error during my previous LLM call, error message: \"{}\". Please ensure you avoid this error this time", user_prompt, e);
                         Box::pin(self.extract_inner(
                             system_prompt,
@@ -139,6 +234,7 @@ impl LLMClient {
         user_prompt: &str,
         react_config: ReActConfig,
     ) -> Result<ReActResponse> {
+        let _slot = self.acquire_request_slot().await?;
         let agent_builder = self.get_agent_builder();
         let agent = agent_builder.build_agent_with_tools(system_prompt);
         let model_name = self.config.llm.model_efficient.clone();
@@ -157,8 +253,9 @@ impl LLMClient {
             && response.chat_history.is_some()
         {
             if react_config.verbose {
-                println!("🔄 Activating ReAct Agent summary to direct reasoning mode...");
+                debug!("🔄 Activating ReAct Agent summary to direct reasoning mode...");
             }
+            self.record_fallback();
 
             match self
                 .try_summary_reasoning(system_prompt, user_prompt, &response)
@@ -166,14 +263,14 @@ impl LLMClient {
             {
                 Ok(summary_response) => {
                     if react_config.verbose {
-                        println!("✅ Summary reasoning completed");
+                        debug!("✅ Summary reasoning completed");
                     }
                     return Ok(summary_response);
                 }
                 Err(e) => {
                     if react_config.verbose {
-                        let msg = self.config.target_language.msg_summary_reasoning_failed();
-                        println!("{}", msg.replace("{}", &e.to_string()));
+                        let msg = self.config.ui_language().msg_summary_reasoning_failed(&e.to_string());
+                        debug!("{}", msg);
                     }
                     // When summary reasoning fails, return the original partial result
                 }
@@ -226,10 +323,54 @@ impl LLMClient {
         system_prompt: &str,
         user_prompt: &str,
     ) -> Result<String> {
+        self.prompt_without_react_with_model(system_prompt, user_prompt, &self.config.llm.model_efficient)
+            .await
+    }
+
+    /// Single-turn dialogue without tools using the given model
+    pub async fn prompt_without_react_with_model(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        model: &str,
+    ) -> Result<String> {
+        let _slot = self.acquire_request_slot().await?;
         let agent_builder = self.get_agent_builder();
-        let agent = agent_builder.build_agent_without_tools(system_prompt);
+        let agent = agent_builder.build_agent_without_tools_for_model(system_prompt, model);
 
         self.retry_with_backoff(|| async { agent.prompt(user_prompt, 1).await.map_err(|e| e.into()) })
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LLMProvider;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_request_slots_bound_concurrent_calls() {
+        let mut config = Config::default();
+        config.llm.provider = LLMProvider::Mock;
+        config.llm.max_parallels = 2;
+        let client = LLMClient::new(config, Arc::new(RedactionLog::default())).unwrap();
+        // Agents running concurrently hold clones of the same client
+        let clone = client.clone();
+
+        let active = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let call = |client: &LLMClient| {
+            let (active, peak) = (&active, &peak);
+            let client = client.clone();
+            async move {
+                let _slot = client.acquire_request_slot().await.unwrap();
+                peak.fetch_max(active.fetch_add(1, Ordering::SeqCst) + 1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                active.fetch_sub(1, Ordering::SeqCst);
+            }
+        };
+        futures::future::join_all((0..6).map(|i| call(if i % 2 == 0 { &client } else { &clone }))).await;
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+}
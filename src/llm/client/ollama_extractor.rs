@@ -11,13 +11,16 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::LazyLock;
 
+use super::providers::HttpClient;
+use super::retry;
+
 /// JSON code block regex pattern
 static JSON_CODE_BLOCK_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"```(?:json)?\s*(\{[\s\S]*?\})\s*```").unwrap());
 
 /// Ollama structured output extractor
 pub struct OllamaExtractorWrapper<T> {
-    agent: Agent<rig::providers::ollama::CompletionModel>,
+    agent: Agent<rig::providers::ollama::CompletionModel<HttpClient>>,
     max_retries: u32,
     base_url: String,
     model: String,
@@ -30,7 +33,7 @@ where
 {
     /// Create a new Ollama extractor with explicit configuration
     pub fn with_config(
-        agent: Agent<rig::providers::ollama::CompletionModel>,
+        agent: Agent<rig::providers::ollama::CompletionModel<HttpClient>>,
         max_retries: u32,
         base_url: String,
         model: String,
@@ -56,20 +59,33 @@ where
                 Ok(result) => return Ok(result),
                 Err(e) => {
                     let error_msg = format!("{:#}", e);
+                    let mut failure = retry::classify(&e);
                     // Check if it's an API response parsing error
                     if error_msg.contains("ApiResponse") || error_msg.contains("untagged enum") {
                         // Try direct HTTP call as fallback
                         match self.try_extract_via_http(&enhanced_prompt, attempt as usize).await {
                             Ok(result) => return Ok(result),
                             Err(http_err) => {
-                                last_error = Some(format!("rig: {}, http: {}", error_msg, http_err));
+                                failure = retry::classify(&http_err);
+                                last_error = Some(format!("rig: {}, http: {:#}", error_msg, http_err));
                             }
                         }
                     } else {
                         last_error = Some(error_msg);
                     }
+                    let error = last_error.as_deref().unwrap_or_default();
+                    if failure == retry::Failure::Fatal {
+                        return Err(anyhow::anyhow!("Failed, not retrying: {}", error));
+                    }
                     if attempt < self.max_retries {
-                        tokio::time::sleep(tokio::time::Duration::from_millis(3000)).await;
+                        let delay = retry::delay(
+                            failure,
+                            attempt,
+                            retry::EXTRACTOR_RETRY_DELAY_MS,
+                            retry::EXTRACTOR_MAX_RETRY_DELAY_MS,
+                            rand::random::<f64>(),
+                        );
+                        tokio::time::sleep(delay).await;
                     }
                 }
             }
@@ -112,7 +128,8 @@ where
             .context("Failed to send HTTP request to Ollama")?;
 
         if !response.status().is_success() {
-            anyhow::bail!("Ollama HTTP error: {}", response.status());
+            return Err(anyhow::Error::new(retry::HttpStatusError::from_response(response).await)
+                .context("Ollama request failed"));
         }
 
         let json: Value = response
@@ -11,13 +11,16 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::LazyLock;
 
+use super::providers::HttpClient;
+use super::retry;
+
 /// JSON code block regex pattern
 static JSON_CODE_BLOCK_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"```(?:json)?\s*(\{[\s\S]*?\})\s*```").unwrap());
 
 /// OpenAI-compatible structured output extractor with HTTP fallback
 pub struct OpenAICompatibleExtractorWrapper<T> {
-    agent: Agent<rig::providers::openai::completion::CompletionModel>,
+    agent: Agent<rig::providers::openai::completion::CompletionModel<HttpClient>>,
     max_retries: u32,
     base_url: String,
     model: String,
@@ -31,7 +34,7 @@ where
 {
     /// Create a new OpenAI-compatible extractor with configuration
     pub fn new(
-        agent: Agent<rig::providers::openai::completion::CompletionModel>,
+        agent: Agent<rig::providers::openai::completion::CompletionModel<HttpClient>>,
         max_retries: u32,
         base_url: String,
         model: String,
@@ -59,6 +62,7 @@ where
                 Ok(result) => return Ok(result),
                 Err(e) => {
                     let error_msg = format!("{:#}", e);
+                    let mut failure = retry::classify(&e);
                     // Check if it's an API response parsing error
                     if error_msg.contains("ApiResponse") 
                         || error_msg.contains("untagged enum")
@@ -67,14 +71,26 @@ where
                         match self.try_extract_via_http(&enhanced_prompt, attempt as usize).await {
                             Ok(result) => return Ok(result),
                             Err(http_err) => {
-                                last_error = Some(format!("rig: {}, http: {}", error_msg, http_err));
+                                failure = retry::classify(&http_err);
+                                last_error = Some(format!("rig: {}, http: {:#}", error_msg, http_err));
                             }
                         }
                     } else {
                         last_error = Some(error_msg);
                     }
+                    let error = last_error.as_deref().unwrap_or_default();
+                    if failure == retry::Failure::Fatal {
+                        return Err(anyhow::anyhow!("Failed, not retrying: {}", error));
+                    }
                     if attempt < self.max_retries {
-                        tokio::time::sleep(tokio::time::Duration::from_millis(3000)).await;
+                        let delay = retry::delay(
+                            failure,
+                            attempt,
+                            retry::EXTRACTOR_RETRY_DELAY_MS,
+                            retry::EXTRACTOR_MAX_RETRY_DELAY_MS,
+                            rand::random::<f64>(),
+                        );
+                        tokio::time::sleep(delay).await;
                     }
                 }
             }
@@ -126,9 +142,9 @@ where
             .context("Failed to send HTTP request to OpenAI-compatible API")?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            anyhow::bail!("OpenAI-compatible API HTTP error {}: {}", status, body);
+            // Rate-limited callers wait as long as the provider asks
+            return Err(anyhow::Error::new(retry::HttpStatusError::from_response(response).await)
+                .context("OpenAI-compatible API request failed"));
         }
 
         let json: Value = response
@@ -16,20 +16,60 @@ use crate::{
     llm::tools::time::AgentToolTime,
 };
 
+use super::mock::{MOCK_DOCUMENT, MockExtractor};
+use super::retry::HttpStatusError;
 use super::ollama_extractor::OllamaExtractorWrapper;
 use super::openai_compatible_extractor::OpenAICompatibleExtractorWrapper;
 
+/// HTTP stack of the rig provider clients
+pub(super) type HttpClient = reqwest_middleware::ClientWithMiddleware;
+
+fn http_client() -> HttpClient {
+    reqwest_middleware::ClientBuilder::new(reqwest_middleware::reqwest::Client::new())
+        .with(ResponseStatus)
+        .build()
+}
+
+/// Turns unsuccessful responses into [`HttpStatusError`]s so that their status and
+/// Retry-After header reach the retry policy, rig's own errors keep neither
+struct ResponseStatus;
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for ResponseStatus {
+    async fn handle(
+        &self,
+        request: reqwest_middleware::reqwest::Request,
+        extensions: &mut http::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest_middleware::reqwest::Response> {
+        let response = next.run(request, extensions).await?;
+        if response.status().is_success() {
+            return Ok(response);
+        }
+        let status = response.status().as_u16();
+        let retry_after = response
+            .headers()
+            .get(http::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body = response.text().await.unwrap_or_default();
+        Err(reqwest_middleware::Error::middleware(HttpStatusError::new(status, retry_after.as_deref(), body)))
+    }
+}
+
 /// Unified Provider client enum
 #[derive(Clone)]
 pub enum ProviderClient {
-    OpenAI(rig::providers::openai::CompletionsClient),
-    Moonshot(rig::providers::moonshot::Client),
-    DeepSeek(rig::providers::deepseek::Client),
-    Mistral(rig::providers::mistral::Client),
-    OpenRouter(rig::providers::openrouter::Client),
-    Anthropic(rig::providers::anthropic::Client),
+    OpenAI(rig::providers::openai::CompletionsClient<HttpClient>),
+    Moonshot(rig::providers::moonshot::Client<HttpClient>),
+    DeepSeek(rig::providers::deepseek::Client<HttpClient>),
+    Mistral(rig::providers::mistral::Client<HttpClient>),
+    OpenRouter(rig::providers::openrouter::Client<HttpClient>),
+    Anthropic(rig::providers::anthropic::Client<HttpClient>),
+    // rig's Gemini completions only run on its default HTTP client
     Gemini(rig::providers::gemini::Client),
-    Ollama(rig::providers::ollama::Client),
+    Ollama(rig::providers::ollama::Client<HttpClient>),
+    Mock,
 }
 
 impl ProviderClient {
@@ -37,20 +77,19 @@ impl ProviderClient {
     pub fn new(config: &LLMConfig) -> Result<Self> {
         match config.provider {
             LLMProvider::OpenAI => {
+                let builder = rig::providers::openai::Client::builder()
+                    .http_client(http_client())
+                    .api_key(&config.api_key);
                 let client = if config.api_base_url != "https://api.openai.com/v1" {
-                    rig::providers::openai::Client::builder()
-                        .api_key(&config.api_key)
-                        .base_url(&config.api_base_url)
-                        .build()?
-                        .completions_api()
+                    builder.base_url(&config.api_base_url).build()?.completions_api()
                 } else {
-                    rig::providers::openai::Client::new(&config.api_key)?
-                        .completions_api()
+                    builder.build()?.completions_api()
                 };
                 Ok(ProviderClient::OpenAI(client))
             }
             LLMProvider::Moonshot => {
                 let client = rig::providers::moonshot::Client::builder()
+                    .http_client(http_client())
                     .api_key(&config.api_key)
                     .base_url(&config.api_base_url)
                     .build()?;
@@ -58,17 +97,24 @@ impl ProviderClient {
             }
             LLMProvider::DeepSeek => {
                 let client = rig::providers::deepseek::Client::builder()
+                    .http_client(http_client())
                     .api_key(&config.api_key)
                     .base_url(&config.api_base_url)
                     .build()?;
                 Ok(ProviderClient::DeepSeek(client))
             }
             LLMProvider::Mistral => {
-                let client = rig::providers::mistral::Client::new(&config.api_key)?;
+                let client = rig::providers::mistral::Client::builder()
+                    .http_client(http_client())
+                    .api_key(&config.api_key)
+                    .build()?;
                 Ok(ProviderClient::Mistral(client))
             }
             LLMProvider::OpenRouter => {
-                let client = rig::providers::openrouter::Client::new(&config.api_key)?;
+                let client = rig::providers::openrouter::Client::builder()
+                    .http_client(http_client())
+                    .api_key(&config.api_key)
+                    .build()?;
                 Ok(ProviderClient::OpenRouter(client))
             }
             LLMProvider::Anthropic => {
@@ -78,13 +124,13 @@ impl ProviderClient {
                 let normalized_url = config.api_base_url.to_lowercase().trim_end_matches('/').to_string();
                 let use_custom_url = normalized_url != "https://api.anthropic.com"
                     && normalized_url.contains("anthropic");
+                let builder = rig::providers::anthropic::Client::builder()
+                    .http_client(http_client())
+                    .api_key(&config.api_key);
                 let client = if use_custom_url {
-                    rig::providers::anthropic::Client::builder()
-                        .api_key(&config.api_key)
-                        .base_url(&config.api_base_url)
-                        .build()?
+                    builder.base_url(&config.api_base_url).build()?
                 } else {
-                    rig::providers::anthropic::Client::new(&config.api_key)?
+                    builder.build()?
                 };
                 Ok(ProviderClient::Anthropic(client))
             }
@@ -94,11 +140,13 @@ impl ProviderClient {
             }
             LLMProvider::Ollama => {
                 let client = rig::providers::ollama::Client::builder()
+                    .http_client(http_client())
                     .api_key(rig::client::Nothing)
                     .base_url(&config.api_base_url)
                     .build()?;
                 Ok(ProviderClient::Ollama(client))
             }
+            LLMProvider::Mock => Ok(ProviderClient::Mock),
         }
     }
 
@@ -220,6 +268,7 @@ impl ProviderClient {
                 let agent = builder.build();
                 ProviderAgent::Ollama(agent)
             }
+            ProviderClient::Mock => ProviderAgent::Mock,
         }
     }
 
@@ -386,6 +435,7 @@ impl ProviderClient {
                     .build();
                 ProviderAgent::Ollama(agent)
             }
+            ProviderClient::Mock => ProviderAgent::Mock,
         }
     }
 
@@ -500,6 +550,7 @@ impl ProviderClient {
 
                 ProviderExtractor::Ollama(wrapper)
             }
+            ProviderClient::Mock => ProviderExtractor::Mock(MockExtractor::new()),
         }
     }
 }
@@ -507,18 +558,19 @@ impl ProviderClient {
 /// Unified Agent enum
 pub enum ProviderAgent {
     OpenAI {
-        agent: Agent<rig::providers::openai::completion::CompletionModel>,
+        agent: Agent<rig::providers::openai::completion::CompletionModel<HttpClient>>,
         base_url: String,
         model: String,
         api_key: String,
     },
-    Mistral(Agent<rig::providers::mistral::CompletionModel>),
-    OpenRouter(Agent<rig::providers::openrouter::CompletionModel>),
-    Anthropic(Agent<rig::providers::anthropic::completion::CompletionModel>),
+    Mistral(Agent<rig::providers::mistral::CompletionModel<HttpClient>>),
+    OpenRouter(Agent<rig::providers::openrouter::CompletionModel<HttpClient>>),
+    Anthropic(Agent<rig::providers::anthropic::completion::CompletionModel<HttpClient>>),
     Gemini(Agent<rig::providers::gemini::completion::CompletionModel>),
-    Moonshot(Agent<rig::providers::moonshot::CompletionModel>),
-    DeepSeek(Agent<rig::providers::deepseek::CompletionModel>),
-    Ollama(Agent<rig::providers::ollama::CompletionModel>),
+    Moonshot(Agent<rig::providers::moonshot::CompletionModel<HttpClient>>),
+    DeepSeek(Agent<rig::providers::deepseek::CompletionModel<HttpClient>>),
+    Ollama(Agent<rig::providers::ollama::CompletionModel<HttpClient>>),
+    Mock,
 }
 
 impl ProviderAgent {
@@ -566,6 +618,7 @@ impl ProviderAgent {
             ProviderAgent::Ollama(agent) => {
                 agent.prompt(prompt).with_tool_concurrency(concurrency).await.map_err(|e| e.into())
             }
+            ProviderAgent::Mock => Ok(MOCK_DOCUMENT.to_string()),
         }
     }
 
@@ -596,9 +649,8 @@ impl ProviderAgent {
             .map_err(|e| anyhow::anyhow!("HTTP request failed: {}", e))?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            anyhow::bail!("OpenAI-compatible API HTTP error {}: {}", status, body);
+            return Err(anyhow::Error::new(HttpStatusError::from_response(response).await)
+                .context("OpenAI-compatible API request failed"));
         }
 
         let json: serde_json::Value = response
@@ -624,13 +676,14 @@ where
     T: JsonSchema + for<'a> Deserialize<'a> + Serialize + Send + Sync + 'static,
 {
     OpenAI(OpenAICompatibleExtractorWrapper<T>),
-    Mistral(Extractor<rig::providers::mistral::CompletionModel, T>),
-    OpenRouter(Extractor<rig::providers::openrouter::CompletionModel, T>),
-    Anthropic(Extractor<rig::providers::anthropic::completion::CompletionModel, T>),
+    Mistral(Extractor<rig::providers::mistral::CompletionModel<HttpClient>, T>),
+    OpenRouter(Extractor<rig::providers::openrouter::CompletionModel<HttpClient>, T>),
+    Anthropic(Extractor<rig::providers::anthropic::completion::CompletionModel<HttpClient>, T>),
     Gemini(Extractor<rig::providers::gemini::completion::CompletionModel, T>),
-    Moonshot(Extractor<rig::providers::moonshot::CompletionModel, T>),
-    DeepSeek(Extractor<rig::providers::deepseek::CompletionModel, T>),
+    Moonshot(Extractor<rig::providers::moonshot::CompletionModel<HttpClient>, T>),
+    DeepSeek(Extractor<rig::providers::deepseek::CompletionModel<HttpClient>, T>),
     Ollama(OllamaExtractorWrapper<T>),
+    Mock(MockExtractor<T>),
 }
 
 impl<T> ProviderExtractor<T>
@@ -664,6 +717,7 @@ where
             ProviderExtractor::Ollama(extractor) => {
                 extractor.extract(prompt).await.map_err(|e| e.into())
             }
+            ProviderExtractor::Mock(extractor) => extractor.extract(prompt),
         }
     }
 }
@@ -4,6 +4,7 @@ use anyhow::Result;
 
 use super::react::{ReActConfig, ReActResponse};
 use super::providers::ProviderAgent;
+use tracing::debug;
 
 /// ReAct executor
 pub struct ReActExecutor;
@@ -19,7 +20,7 @@ impl ReActExecutor {
         model_name: &str,
     ) -> Result<ReActResponse> {
         if config.verbose {
-            println!(
+            debug!(
                 "   ♻️ Activating Agent mode (model: {})",
                 model_name
             );
@@ -28,14 +29,14 @@ impl ReActExecutor {
         match agent.prompt(user_prompt, config.concurrency).await {
             Ok(response) => {
                 if config.verbose {
-                    println!("   ✅ Agent task completed");
+                    debug!("   ✅ Agent task completed");
                 }
 
                 Ok(ReActResponse::success(response, config.max_iterations))
             }
             Err(e) => {
                 if config.verbose {
-                    println!("   ❌ Agent error: {:?}", e);
+                    debug!("   ❌ Agent error: {:?}", e);
                 }
                 Err(anyhow::anyhow!("Agent task execution failed (model: {}): {}", model_name, e))
             }
@@ -0,0 +1,186 @@
+//! Retry policy of model service calls: exponential backoff with jitter, the delay asked
+//! for by rate-limited providers, and no retries for errors that cannot recover
+//!
+//! Failed HTTP responses are classified by their status and Retry-After header; the error
+//! message is only used for failures without a response (network errors, SDK errors).
+
+use regex::Regex;
+use std::fmt;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+static RATE_LIMIT_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b429\b|too many requests|rate[ _-]?limit").unwrap());
+/// Authentication, authorization and billing failures: retrying gives the same answer
+static FATAL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(401|403)\b|unauthori[sz]ed|forbidden|invalid[ _-]?api[ _-]?key|incorrect api key|authentication|permission denied|insufficient[ _-]?quota").unwrap()
+});
+/// `Retry-After: 12` header echoed in the error, or "try again in 1.5s" / "in 500ms" in the message
+static RETRY_AFTER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)retry[- ]after\W{0,3}(\d+(?:\.\d+)?)|try again in (\d+(?:\.\d+)?)\s*(ms|s)").unwrap()
+});
+
+/// Unsuccessful HTTP response of a model service
+#[derive(Debug)]
+pub struct HttpStatusError {
+    pub status: u16,
+    /// Delay asked for by the Retry-After header
+    pub retry_after: Option<Duration>,
+    pub body: String,
+}
+
+impl HttpStatusError {
+    /// Error of a response; `retry_after` is the header value, in seconds or an HTTP date
+    pub fn new(status: u16, retry_after: Option<&str>, body: String) -> Self {
+        let retry_after = retry_after.and_then(|value| {
+            let value = value.trim();
+            match value.parse::<f64>() {
+                Ok(seconds) if seconds.is_finite() && seconds >= 0.0 => Some(Duration::from_secs_f64(seconds)),
+                Ok(_) => None,
+                Err(_) => chrono::DateTime::parse_from_rfc2822(value)
+                    .ok()
+                    .and_then(|at| (at.with_timezone(&chrono::Utc) - chrono::Utc::now()).to_std().ok()),
+            }
+        });
+        Self { status, retry_after, body }
+    }
+
+    /// Error of an unsuccessful response of the direct HTTP fallbacks
+    pub async fn from_response(response: reqwest::Response) -> Self {
+        let status = response.status().as_u16();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        Self::new(status, retry_after.as_deref(), response.text().await.unwrap_or_default())
+    }
+}
+
+impl fmt::Display for HttpStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "HTTP error {}: {}", self.status, self.body)
+    }
+}
+
+impl std::error::Error for HttpStatusError {}
+
+/// How a failed call should be retried
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Failure {
+    /// Retrying cannot succeed (bad credentials, no quota)
+    Fatal,
+    /// The provider throttled the call, possibly saying how long to wait
+    RateLimited(Option<Duration>),
+    /// Network errors, timeouts, overloaded servers, malformed replies
+    Transient,
+}
+
+/// Classify an error by the HTTP response in its chain, by its message chain without one
+pub fn classify(error: &anyhow::Error) -> Failure {
+    for cause in error.chain() {
+        if let Some(response) = cause.downcast_ref::<HttpStatusError>() {
+            return classify_status(response.status, response.retry_after, &response.body);
+        }
+        if let Some(reqwest_middleware::Error::Middleware(inner)) = cause.downcast_ref::<reqwest_middleware::Error>()
+            && let Some(response) = inner.downcast_ref::<HttpStatusError>()
+        {
+            return classify_status(response.status, response.retry_after, &response.body);
+        }
+        match cause.downcast_ref::<rig::http_client::Error>() {
+            Some(rig::http_client::Error::InvalidStatusCode(status)) => return classify_status(status.as_u16(), None, ""),
+            Some(rig::http_client::Error::InvalidStatusCodeWithMessage(status, body)) => {
+                return classify_status(status.as_u16(), None, body);
+            }
+            _ => {}
+        }
+    }
+    classify_message(&format!("{:#}", error))
+}
+
+/// Classify a failed HTTP response; the body only refines the status, it never decides
+/// whether a throttled or failing server is retried
+fn classify_status(status: u16, retry_after: Option<Duration>, body: &str) -> Failure {
+    match status {
+        401..=403 => Failure::Fatal,
+        // OpenAI answers an exhausted quota with 429 as well
+        429 if body.contains("insufficient_quota") => Failure::Fatal,
+        429 => Failure::RateLimited(retry_after.or_else(|| retry_after_hint(body))),
+        _ => Failure::Transient,
+    }
+}
+
+/// Classify an error without a response from its message
+fn classify_message(message: &str) -> Failure {
+    if FATAL_REGEX.is_match(message) {
+        Failure::Fatal
+    } else if RATE_LIMIT_REGEX.is_match(message) {
+        Failure::RateLimited(retry_after_hint(message))
+    } else {
+        Failure::Transient
+    }
+}
+
+/// Delay named in an error message or response body
+fn retry_after_hint(message: &str) -> Option<Duration> {
+    let captures = RETRY_AFTER_REGEX.captures(message)?;
+    let (value, unit) = match (captures.get(1), captures.get(2)) {
+        (Some(seconds), _) => (seconds.as_str(), "s"),
+        (None, Some(value)) => (value.as_str(), captures.get(3).map_or("s", |unit| unit.as_str())),
+        _ => return None,
+    };
+    let value: f64 = value.parse().ok()?;
+    let seconds = if unit.eq_ignore_ascii_case("ms") { value / 1000.0 } else { value };
+    Some(Duration::from_secs_f64(seconds))
+}
+
+/// Delay between the attempts of the provider extractors
+pub const EXTRACTOR_RETRY_DELAY_MS: u64 = 3000;
+/// Upper bound of the delay between the attempts of the provider extractors
+pub const EXTRACTOR_MAX_RETRY_DELAY_MS: u64 = 60_000;
+
+/// Delay before the given retry (1-based): the provider's Retry-After when known, otherwise
+/// `base_ms` doubled per retry, of which a random half is added as jitter so that concurrent
+/// agents do not retry in lockstep; `jitter` is in `0.0..1.0`. Both are capped at `max_ms`.
+pub fn delay(failure: Failure, retry: u32, base_ms: u64, max_ms: u64, jitter: f64) -> Duration {
+    let max_ms = max_ms.max(base_ms);
+    if let Failure::RateLimited(Some(retry_after)) = failure {
+        return retry_after.min(Duration::from_millis(max_ms));
+    }
+    let exponential = base_ms.saturating_mul(1u64 << retry.saturating_sub(1).min(16));
+    let capped = exponential.min(max_ms) as f64;
+    Duration::from_millis((capped / 2.0 + capped / 2.0 * jitter) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_and_delay() {
+        let rate_limited = anyhow::Error::new(HttpStatusError::new(429, Some("12"), "slow down".to_string()))
+            .context("OpenAI-compatible API request failed");
+        assert_eq!(classify(&rate_limited), Failure::RateLimited(Some(Duration::from_secs(12))));
+        // The body does not make a throttled or failing server fatal
+        let throttled = HttpStatusError::new(429, None, "authentication service busy, try again in 2s".to_string());
+        assert_eq!(classify(&throttled.into()), Failure::RateLimited(Some(Duration::from_secs(2))));
+        let unavailable = HttpStatusError::new(503, None, "permission denied by upstream".to_string());
+        assert_eq!(classify(&unavailable.into()), Failure::Transient);
+        let middleware = reqwest_middleware::Error::Middleware(HttpStatusError::new(401, None, String::new()).into());
+        assert_eq!(classify(&anyhow::Error::new(middleware).context("prompt failed")), Failure::Fatal);
+        let quota = HttpStatusError::new(429, None, r#"{"error":{"code":"insufficient_quota"}}"#.to_string());
+        assert_eq!(classify(&quota.into()), Failure::Fatal);
+
+        let throttled = anyhow::anyhow!("ProviderError: Rate limit reached for gpt-4o. Please try again in 450ms.");
+        assert_eq!(classify(&throttled), Failure::RateLimited(Some(Duration::from_millis(450))));
+        let auth = anyhow::anyhow!("HTTP error 401 Unauthorized").context("AI analysis failed");
+        assert_eq!(classify(&auth), Failure::Fatal);
+        assert_eq!(classify(&anyhow::anyhow!("error sending request: connection reset")), Failure::Transient);
+
+        assert_eq!(delay(Failure::Transient, 1, 1000, 60_000, 0.0), Duration::from_millis(500));
+        assert_eq!(delay(Failure::Transient, 3, 1000, 60_000, 1.0), Duration::from_millis(4000));
+        assert_eq!(delay(Failure::RateLimited(None), 10, 1000, 60_000, 1.0), Duration::from_millis(60_000));
+        assert_eq!(delay(Failure::RateLimited(Some(Duration::from_secs(12))), 1, 1000, 60_000, 0.5), Duration::from_secs(12));
+        assert_eq!(delay(Failure::RateLimited(Some(Duration::from_secs(3600))), 1, 1000, 60_000, 0.5), Duration::from_secs(60));
+    }
+}
@@ -1,4 +1,7 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 /// Token usage information
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,3 +31,84 @@ impl TokenUsage {
             + (self.output_tokens as f64 / 1000.0) * output_cost_per_1k
     }
 }
+
+/// Token usage of the calls one agent made with one model during one workflow phase
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TokenUsageRecord {
+    pub agent: String,
+    pub model: String,
+    pub phase: String,
+    pub calls: usize,
+    pub input_tokens: usize,
+    pub output_tokens: usize,
+    pub total_tokens: usize,
+}
+
+/// Calls and usage per (agent, model, phase)
+type UsageBreakdown = BTreeMap<(String, String, String), (usize, TokenUsage)>;
+
+/// Accumulated token usage of all LLM calls made during a run
+#[derive(Debug, Default)]
+pub struct TokenUsageTracker {
+    calls: AtomicUsize,
+    input_tokens: AtomicUsize,
+    output_tokens: AtomicUsize,
+    /// Workflow phase the calls are attributed to
+    phase: Mutex<Option<String>>,
+    breakdown: Mutex<UsageBreakdown>,
+}
+
+impl TokenUsageTracker {
+    /// Record the usage of a single LLM call made by the agent with the model
+    pub fn record(&self, agent: &str, model: &str, usage: &TokenUsage) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.input_tokens
+            .fetch_add(usage.input_tokens, Ordering::Relaxed);
+        self.output_tokens
+            .fetch_add(usage.output_tokens, Ordering::Relaxed);
+
+        let phase = self.phase.lock().unwrap().clone().unwrap_or_else(|| "other".to_string());
+        let mut breakdown = self.breakdown.lock().unwrap();
+        let (calls, total) = breakdown
+            .entry((agent.to_string(), model.to_string(), phase))
+            .or_insert_with(|| (0, TokenUsage::new(0, 0)));
+        *calls += 1;
+        *total = TokenUsage::new(total.input_tokens + usage.input_tokens, total.output_tokens + usage.output_tokens);
+    }
+
+    /// Attribute the following calls to the workflow phase
+    pub fn set_phase(&self, phase: &str) {
+        *self.phase.lock().unwrap() = Some(phase.to_string());
+    }
+
+    /// Usage per agent, model and phase
+    pub fn records(&self) -> Vec<TokenUsageRecord> {
+        self.breakdown
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|((agent, model, phase), (calls, usage))| TokenUsageRecord {
+                agent: agent.clone(),
+                model: model.clone(),
+                phase: phase.clone(),
+                calls: *calls,
+                input_tokens: usage.input_tokens,
+                output_tokens: usage.output_tokens,
+                total_tokens: usage.total_tokens,
+            })
+            .collect()
+    }
+
+    /// Number of recorded LLM calls
+    pub fn calls(&self) -> usize {
+        self.calls.load(Ordering::Relaxed)
+    }
+
+    /// Total usage recorded so far
+    pub fn total(&self) -> TokenUsage {
+        TokenUsage::new(
+            self.input_tokens.load(Ordering::Relaxed),
+            self.output_tokens.load(Ordering::Relaxed),
+        )
+    }
+}
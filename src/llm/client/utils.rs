@@ -4,29 +4,38 @@ use crate::{
 
 use std::sync::LazyLock;
 
-static TOKEN_ESTIMATOR: LazyLock<TokenEstimator> = LazyLock::new(|| TokenEstimator::new());
+static TOKEN_ESTIMATOR: LazyLock<TokenEstimator> = LazyLock::new(TokenEstimator::new);
+
+/// Prompts up to this many tokens go to the efficient model first
+const EFFICIENT_MODEL_MAX_PROMPT_TOKENS: usize = 8 * 1024;
 
 pub fn evaluate_befitting_model(
     llm_config: &LLMConfig,
     system_prompt: &str,
     user_prompt: &str,
 ) -> (String, Option<String>) {
-    if system_prompt.len() + user_prompt.len() <= 32 * 1024 {
-        return (
+    let prompt_tokens =
+        TokenEstimator::for_model(&llm_config.model_efficient).estimate_request_tokens(system_prompt, user_prompt);
+    if prompt_tokens <= EFFICIENT_MODEL_MAX_PROMPT_TOKENS {
+        (
             llm_config.model_efficient.clone(),
             Some(llm_config.model_powerful.clone()),
-        );
+        )
+    } else {
+        (llm_config.model_powerful.clone(), None)
     }
-    return (llm_config.model_powerful.clone(), None);
 }
 
-/// Estimate token usage (based on text length)
-pub fn estimate_token_usage(input_text: &str, output_text: &str) -> TokenUsage {
-    // Rough estimate: 1 token ≈ 4 characters (English) or ~1.5 characters (Chinese)
-    let input_estimate = TOKEN_ESTIMATOR.estimate_tokens(input_text);
-    let output_estimate = TOKEN_ESTIMATOR.estimate_tokens(output_text);
+/// Estimate the token count of a single text
+pub fn estimate_tokens(text: &str) -> usize {
+    TOKEN_ESTIMATOR.estimate_tokens(text).estimated_tokens
+}
+
+/// Estimate the token usage of a call to `model`
+pub fn estimate_token_usage(model: &str, input_text: &str, output_text: &str) -> TokenUsage {
+    let estimator = TokenEstimator::for_model(model);
     TokenUsage::new(
-        input_estimate.estimated_tokens,
-        output_estimate.estimated_tokens,
+        estimator.estimate_request_tokens("", input_text),
+        estimator.estimate_tokens(output_text).estimated_tokens,
     )
 }
@@ -5,17 +5,24 @@ use rig::tool::Tool;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
 use std::time::Duration;
 use walkdir::WalkDir;
 
-use crate::config::Config;
+use crate::config::{Config, SymlinkPolicy};
 use crate::types::FileInfo;
-use crate::utils::file_utils::is_test_file;
+use crate::utils::file_utils::{IncludedDirs, VisitedEntries, is_test_file};
+use crate::utils::gitignore::GitignoreMatcher;
+use crate::utils::privacy::PrivacyPolicy;
+use tracing::debug;
 
 /// File exploration tool
 #[derive(Debug, Clone)]
 pub struct AgentToolFileExplorer {
     config: Config,
+    gitignore: Arc<GitignoreMatcher>,
+    included_dirs: IncludedDirs,
+    privacy: PrivacyPolicy,
 }
 
 /// File exploration parameters
@@ -40,7 +47,17 @@ pub struct FileExplorerResult {
 
 impl AgentToolFileExplorer {
     pub fn new(config: Config) -> Self {
-        Self { config }
+        let gitignore = if config.respect_gitignore {
+            GitignoreMatcher::from_project(&config.project_path)
+        } else {
+            GitignoreMatcher::default()
+        };
+        Self {
+            included_dirs: IncludedDirs::new(&config.included_dirs),
+            privacy: PrivacyPolicy::new(&config.privacy),
+            config,
+            gitignore: Arc::new(gitignore),
+        }
     }
 
     async fn list_directory(&self, args: &FileExplorerArgs) -> Result<FileExplorerResult> {
@@ -65,7 +82,8 @@ impl AgentToolFileExplorer {
 
         if recursive {
             // Recursive traversal, limit depth to 3
-            for entry in WalkDir::new(&target_path).max_depth(3) {
+            let mut visited = VisitedEntries::default();
+            for entry in self.walk(&target_path, 3) {
                 if files.len() >= max_files {
                     break;
                 }
@@ -73,7 +91,7 @@ impl AgentToolFileExplorer {
                 let entry = entry?;
                 let path = entry.path();
 
-                if self.is_ignored(path) {
+                if self.is_ignored(path) || !self.first_visit(&mut visited, path) {
                     continue;
                 }
 
@@ -106,13 +124,25 @@ impl AgentToolFileExplorer {
                     continue;
                 }
 
-                if entry.file_type()?.is_file() {
+                let mut file_type = entry.file_type()?;
+                if file_type.is_symlink() {
+                    if self.config.symlink_policy == SymlinkPolicy::Skip {
+                        continue;
+                    }
+                    // Broken links are skipped
+                    let Ok(metadata) = std::fs::metadata(&path) else {
+                        continue;
+                    };
+                    file_type = metadata.file_type();
+                }
+
+                if file_type.is_file() {
                     let file_info = self.create_file_info(&path)?;
                     if let Some(ext) = &file_info.extension {
                         *file_types.entry(ext.clone()).or_insert(0) += 1;
                     }
                     files.push(file_info);
-                } else if entry.file_type()?.is_dir() {
+                } else if file_type.is_dir() {
                     let relative_path = path
                         .strip_prefix(&self.config.project_path)
                         .unwrap_or(&path)
@@ -158,7 +188,8 @@ impl AgentToolFileExplorer {
         let mut file_types = HashMap::new();
 
         // Use walkdir for recursive search, limit depth to 5
-        for entry in WalkDir::new(&search_path).max_depth(5) {
+        let mut visited = VisitedEntries::default();
+        for entry in self.walk(&search_path, 5) {
             if files.len() >= max_files {
                 break;
             }
@@ -166,7 +197,10 @@ impl AgentToolFileExplorer {
             let entry = entry?;
             let path = entry.path();
 
-            if !entry.file_type().is_file() || self.is_ignored(path) {
+            if !entry.file_type().is_file()
+                || self.is_ignored(path)
+                || !self.first_visit(&mut visited, path)
+            {
                 continue;
             }
 
@@ -255,6 +289,27 @@ impl AgentToolFileExplorer {
         })
     }
 
+    /// Recursive traversal following symbolic links according to the symlink policy;
+    /// link cycles are reported by walkdir and skipped
+    fn walk(&self, root: &Path, max_depth: usize) -> impl Iterator<Item = walkdir::Result<walkdir::DirEntry>> {
+        WalkDir::new(root)
+            .max_depth(max_depth)
+            .follow_links(self.config.symlink_policy == SymlinkPolicy::Follow)
+            .into_iter()
+            .filter(|entry| match entry {
+                Err(e) if e.loop_ancestor().is_some() => {
+                    debug!("   🔁 Skipping symbolic link cycle: {}", e);
+                    false
+                }
+                _ => true,
+            })
+    }
+
+    /// Whether an entry is reached for the first time; only links lead to an entry twice
+    fn first_visit(&self, visited: &mut VisitedEntries, path: &Path) -> bool {
+        self.config.symlink_policy == SymlinkPolicy::Skip || visited.first_visit(path)
+    }
+
     fn is_ignored(&self, path: &Path) -> bool {
         let path_str = path.to_string_lossy().to_lowercase();
         let file_name = path
@@ -319,9 +374,32 @@ impl AgentToolFileExplorer {
             return true;
         }
 
+        // Check .gitignore rules
+        if self.gitignore.is_ignored(path, path.is_dir()) {
+            return true;
+        }
+
+        // Check paths excluded by the privacy policy
+        if self.privacy.is_excluded(&self.config.project_path, path) {
+            return true;
+        }
+
+        // Check included directories (if specified)
+        if !self.included_dirs.is_empty() {
+            let relative_path = path.strip_prefix(&self.config.project_path).unwrap_or(path);
+            let included = if path.is_dir() {
+                self.included_dirs.may_contain(relative_path)
+            } else {
+                self.included_dirs.contains_file(relative_path)
+            };
+            if !included {
+                return true;
+            }
+        }
+
         // Check file size
         if let Ok(metadata) = std::fs::metadata(path) {
-            if metadata.len() > self.config.max_file_size {
+            if metadata.len() > self.config.max_file_size_for(path, false) {
                 return true;
             }
         }
@@ -523,7 +601,7 @@ impl Tool for AgentToolFileExplorer {
     }
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        println!("   🔧 tool called...file_reader@{:?}", args);
+        debug!("   🔧 tool called...file_reader@{:?}", args);
 
         tokio::time::sleep(Duration::from_secs(1)).await;
 
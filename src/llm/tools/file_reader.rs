@@ -3,13 +3,18 @@
 use anyhow::Result;
 use rig::tool::Tool;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
-use crate::{config::Config, utils::file_utils::is_binary_file_path};
+use crate::{config::Config, utils::encoding, utils::file_utils::is_binary_file, utils::secret_redactor::{self, RedactionLog}};
+use crate::utils::privacy::{PathAccess, PrivacyPolicy, WITHHELD_CONTENT};
+use tracing::debug;
 
 /// File reading tool
 #[derive(Debug, Clone)]
 pub struct AgentToolFileReader {
     config: Config,
+    privacy: PrivacyPolicy,
+    redactions: Arc<RedactionLog>,
 }
 
 /// File reading parameters
@@ -33,22 +38,27 @@ pub struct FileReaderResult {
 }
 
 impl AgentToolFileReader {
-    pub fn new(config: Config) -> Self {
-        Self { config }
+    pub fn new(config: Config, redactions: Arc<RedactionLog>) -> Self {
+        Self {
+            privacy: PrivacyPolicy::new(&config.privacy),
+            config,
+            redactions,
+        }
     }
 
     async fn read_file_content(&self, args: &FileReaderArgs) -> Result<FileReaderResult> {
         let project_root = &self.config.project_path;
         let file_path = project_root.join(&args.file_path);
 
-        if !file_path.exists() {
+        let access = self.privacy.access(project_root, &file_path);
+        if !file_path.exists() || access == PathAccess::Excluded {
             return Ok(FileReaderResult {
                 file_path: args.file_path.clone(),
                 ..Default::default()
             });
         }
 
-        if is_binary_file_path(&file_path) {
+        if is_binary_file(&file_path) {
             return Ok(FileReaderResult {
                 file_path: args.file_path.clone(),
                 ..Default::default()
@@ -56,7 +66,17 @@ impl AgentToolFileReader {
         }
 
         let metadata = tokio::fs::metadata(&file_path).await?;
-        let full_content = tokio::fs::read_to_string(&file_path).await?;
+        if access == PathAccess::MetadataOnly {
+            return Ok(FileReaderResult {
+                content: WITHHELD_CONTENT.to_string(),
+                file_path: args.file_path.clone(),
+                file_size: metadata.len(),
+                ..Default::default()
+            });
+        }
+        let (full_content, detected) = encoding::decode(&tokio::fs::read(&file_path).await?);
+        encoding::record(&args.file_path, detected);
+        let full_content = secret_redactor::redact(&self.config.redaction, &self.redactions, &args.file_path, &full_content);
         let lines: Vec<&str> = full_content.lines().collect();
         let total_lines = lines.len();
 
@@ -100,7 +120,7 @@ impl AgentToolFileReader {
             total_lines,
             read_lines,
             file_size: metadata.len(),
-            encoding: "UTF-8".to_string(),
+            encoding: detected.name().to_string(),
         })
     }
 }
@@ -147,7 +167,7 @@ impl Tool for AgentToolFileReader {
     }
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        println!("   🔧 tool called...file_reader@{:?}", args);
+        debug!("   🔧 tool called...file_reader@{:?}", args);
 
         self.read_file_content(&args)
             .await
@@ -5,6 +5,7 @@ use rig::tool::Tool;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::debug;
 
 /// Time tool
 #[derive(Debug, Clone)]
@@ -66,6 +67,12 @@ impl AgentToolTime {
     }
 }
 
+impl Default for AgentToolTime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Tool for AgentToolTime {
     const NAME: &'static str = "time";
 
@@ -91,7 +98,7 @@ impl Tool for AgentToolTime {
     }
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        println!("   🔧 tool called...time@{:?}", args);
+        debug!("   🔧 tool called...time@{:?}", args);
 
         tokio::time::sleep(Duration::from_secs(1)).await;
 
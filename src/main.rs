@@ -1,39 +1,252 @@
-use crate::generator::workflow::launch;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Parser;
+use std::process::ExitCode;
+use tracing::{info, warn};
 
-mod cache;
-mod cli;
-mod config;
-mod generator;
-mod i18n;
-mod integrations;
-mod llm;
-mod memory;
-mod types;
-mod utils;
+use deepwiki_rs::{api_server, ask, cli, config, docs_server, generator, i18n, integrations, launch, mcp, memory, utils};
 
 #[tokio::main]
-async fn main() -> Result<()> {
-    let args = cli::Args::parse();
+async fn main() -> Result<ExitCode> {
+    let mut args = cli::Args::parse();
+    let verbosity = utils::logging::Verbosity::from_flags(args.quiet, args.verbose);
+    if matches!(args.command, Some(cli::Commands::McpServe)) {
+        // stdout carries the MCP protocol
+        utils::logging::init_stderr_logging(verbosity);
+    } else {
+        utils::logging::init_logging(args.log_format, verbosity, args.ci);
+    }
 
     // Handle subcommands
-    if let Some(command) = args.command {
-        return handle_subcommand(command, args.config).await;
+    if let Some(command) = args.command.take() {
+        handle_subcommand(command, args).await?;
+        return Ok(ExitCode::SUCCESS);
     }
 
     // Default: run documentation generation
+    // Exit codes: 0 success, 1 total failure, 2 partial failure (see run-summary.json)
     let config = args.to_config();
-    launch(&config).await
+    let status = launch(&config).await?;
+    Ok(status.exit_code(config.ci.enabled))
 }
 
 /// Handle CLI subcommands
-async fn handle_subcommand(command: cli::Commands, config_path: Option<std::path::PathBuf>) -> Result<()> {
+async fn handle_subcommand(command: cli::Commands, args: cli::Args) -> Result<()> {
     match command {
         cli::Commands::SyncKnowledge { config, force } => {
-            sync_knowledge(config.or(config_path), force).await
+            sync_knowledge(config.or(args.config), force).await
+        }
+        cli::Commands::McpServe => mcp::McpServer::new(args.to_config()).serve_stdio().await,
+        cli::Commands::Ask { question, limit } => {
+            ask::AskSession::new(args.to_config(), limit)?.run(question).await
+        }
+        cli::Commands::Serve { bind } => docs_server::DocsServer::new(args.to_config()).serve(&bind).await,
+        cli::Commands::ServeApi { bind } => api_server::ApiServer::new(args.to_config()).serve(bind).await,
+        cli::Commands::Memory { entry, export } => inspect_memory(&args.to_config(), entry, export),
+        cli::Commands::MemoryDiff { from, to, json } => diff_runs(&args.to_config(), from, to, json),
+        cli::Commands::Eval { agents, config_b, model_b, judge, output } => {
+            evaluate(args.to_config(), agents, config_b, model_b, judge, output).await
+        }
+        cli::Commands::Bench { models, agents, output } => benchmark_models(args.to_config(), models, agents, output).await,
+        cli::Commands::Golden { fixtures, dir, update } => run_golden_suite(&dir, &fixtures, update).await,
+        cli::Commands::List { kind } => {
+            list_capabilities(kind);
+            Ok(())
+        }
+    }
+}
+
+/// Print supported capabilities to stdout
+fn list_capabilities(kind: Option<cli::ListKind>) {
+    use cli::ListKind;
+    use generator::preprocess::extractors::language_processors::LanguageProcessorManager;
+
+    let show = |k: ListKind| kind.is_none() || kind == Some(k);
+
+    if show(ListKind::Languages) {
+        println!("Languages (dedicated dependency and interface extraction):");
+        for (language, extensions) in LanguageProcessorManager::new().supported_languages() {
+            let extensions: Vec<String> = extensions.iter().map(|e| format!(".{}", e)).collect();
+            println!("  {:<12} {}", language, extensions.join(", "));
+        }
+        println!("  Other text files are still analyzed without language-specific extraction.");
+        println!();
+    }
+
+    if show(ListKind::Agents) {
+        println!("Research agents:");
+        for agent in generator::research::types::AgentType::all() {
+            println!("  {}", agent);
+        }
+        println!();
+    }
+
+    if show(ListKind::Documents) {
+        let language = i18n::TargetLanguage::default();
+        println!("Documents (file names depend on --target-language, shown for '{}'):", language);
+        for doc_type in generator::compose::DOCUMENT_TYPES {
+            println!("  {}", language.get_doc_filename(doc_type));
+        }
+        println!(
+            "  {}/<domain>.md (one per key module)",
+            language.get_directory_name("deep_exploration")
+        );
+        println!();
+    }
+
+    if show(ListKind::Providers) {
+        println!("LLM providers (--llm-provider):");
+        for provider in config::LLMProvider::all() {
+            println!("  {}", provider);
+        }
+        println!();
+    }
+
+    if show(ListKind::TargetLanguages) {
+        println!("Target languages (--target-language):");
+        for language in i18n::TargetLanguage::all() {
+            println!("  {:<4} {}", language.to_string(), language.display_name());
+        }
+    }
+}
+
+/// Print or export the memory snapshot written by the last run
+fn inspect_memory(
+    config: &config::Config,
+    entry: Option<String>,
+    export: Option<std::path::PathBuf>,
+) -> Result<()> {
+    let dir = config.memory_dir();
+    let scopes = memory::Memory::read_snapshot(&dir)
+        .with_context(|| format!("No memory snapshot found in {}", dir.display()))?;
+
+    if let Some(path) = export {
+        std::fs::write(&path, serde_json::to_string_pretty(&scopes)?)?;
+        info!("💾 Exported {} memory scopes to {}", scopes.len(), path.display());
+        return Ok(());
+    }
+
+    if let Some(spec) = entry {
+        let (scope, key, value) = memory::Memory::find_entry(&scopes, &spec)?;
+        println!("{}:{}", scope, key);
+        println!("{}", serde_json::to_string_pretty(value)?);
+        return Ok(());
+    }
+
+    println!("Memory snapshot in {}:", dir.display());
+    for (scope, entries) in &scopes {
+        println!("{} ({} entries)", scope, entries.len());
+        for (key, value) in entries {
+            println!("  {:<40} {:>10} bytes", key, value.to_string().len());
         }
     }
+    Ok(())
+}
+
+/// Print the research differences between two archived runs
+fn diff_runs(config: &config::Config, from: Option<String>, to: Option<String>, json: bool) -> Result<()> {
+    use generator::research::diff::ResearchDiff;
+
+    let runs_dir = config.runs_dir();
+    let runs = memory::Memory::list_runs(&runs_dir);
+    let to = match to {
+        Some(to) => to,
+        None => runs.last().cloned().context("No archived runs to compare")?,
+    };
+    let from = match from {
+        Some(from) => from,
+        None => runs
+            .iter()
+            .take_while(|run| **run != to)
+            .last()
+            .cloned()
+            .with_context(|| format!("No archived run before {}", to))?,
+    };
+
+    let read = |run: &str| {
+        memory::Memory::read_snapshot(&runs_dir.join(run))
+            .with_context(|| format!("No archived run {} in {}", run, runs_dir.display()))
+    };
+    let diff = ResearchDiff::between(&from, &read(&from)?, &to, &read(&to)?);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&diff)?);
+    } else {
+        print!("{}", diff);
+    }
+    Ok(())
+}
+
+/// Compare research agents under two configurations and save the report
+async fn evaluate(
+    config: config::Config,
+    agents: Vec<String>,
+    config_b: Option<std::path::PathBuf>,
+    model_b: Option<String>,
+    judge: bool,
+    output: Option<std::path::PathBuf>,
+) -> Result<()> {
+    use generator::eval::{self, Variant};
+
+    let agents = eval::select_agents(&agents)?;
+    let b = Variant {
+        label: match (&config_b, &model_b) {
+            (Some(path), Some(model)) => format!("{} with model {}", path.display(), model),
+            (Some(path), None) => path.display().to_string(),
+            (None, model) => format!("model {}", model.as_deref().unwrap_or_default()),
+        },
+        config: eval::variant_b_config(&config, config_b.as_ref(), model_b.as_deref())?,
+    };
+    let a = Variant {
+        label: format!("models {} / {}", config.llm.model_efficient, config.llm.model_powerful),
+        config,
+    };
+    let dir = output.unwrap_or_else(|| a.config.eval_dir());
+
+    let report = eval::evaluate(a, b, &agents, judge).await?;
+    let path = report.save(&dir)?;
+    info!("💾 Saved evaluation report to {}", path.display());
+    Ok(())
+}
+
+/// Benchmark models on the same agents and save the report
+async fn benchmark_models(
+    config: config::Config,
+    models: Vec<String>,
+    agents: Vec<String>,
+    output: Option<std::path::PathBuf>,
+) -> Result<()> {
+    let agents = generator::bench::select_agents(&agents)?;
+    let report = generator::bench::benchmark(&config, &models, &agents).await?;
+    let path = report.save(&output.unwrap_or_else(|| config.eval_dir()))?;
+    print!("{}", report.summary_table());
+    info!("💾 Saved benchmark report to {}", path.display());
+    Ok(())
+}
+
+/// Run the golden-output regression suite, failing when a fixture differs from its golden output
+async fn run_golden_suite(dir: &std::path::Path, fixtures: &[String], update: bool) -> Result<()> {
+    let results = generator::golden::run_suite(dir, fixtures, update).await?;
+    let mut failed = 0;
+    for result in &results {
+        if result.updated {
+            println!("updated  {}", result.name);
+        } else if result.differences.is_empty() {
+            println!("ok       {}", result.name);
+        } else {
+            failed += 1;
+            println!("FAILED   {}", result.name);
+            for difference in &result.differences {
+                println!("         {}", difference);
+            }
+        }
+    }
+    if failed > 0 {
+        anyhow::bail!(
+            "{} of {} fixtures differ from their golden output; rerun with --update if the change is intended",
+            failed,
+            results.len()
+        );
+    }
+    Ok(())
 }
 
 /// Sync external knowledge sources
@@ -49,17 +262,17 @@ async fn sync_knowledge(config_path: Option<std::path::PathBuf>, force: bool) ->
         if default_path.exists() {
             config::Config::from_file(&default_path)?
         } else {
-            println!("⚠️  No configuration file found. Using defaults.");
+            warn!("⚠️  No configuration file found. Using defaults.");
             config::Config::default()
         }
     };
 
     // Create syncer
-    let syncer = KnowledgeSyncer::new(config)?;
+    let syncer = KnowledgeSyncer::new(config)?.with_force(force);
 
     // Check if sync is needed
     if !force && !syncer.should_sync()? {
-        println!("✅ Knowledge cache is up to date. Use --force to sync anyway.");
+        info!("✅ Knowledge cache is up to date. Use --force to sync anyway.");
         return Ok(());
     }
 
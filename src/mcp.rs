@@ -0,0 +1,300 @@
+//! Model Context Protocol server exposing the generated wiki to coding assistants
+//!
+//! Speaks newline-delimited JSON-RPC 2.0 over stdio, as MCP clients expect for local servers.
+
+use anyhow::Result;
+use serde_json::{Value, json};
+use std::fs;
+use std::path::{Component, Path};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tracing::{debug, info};
+use walkdir::WalkDir;
+
+use crate::ask::{kind_label, retrieve};
+use crate::config::Config;
+use crate::integrations::vector_index::{IndexEntry, IndexKind, VectorIndex};
+
+/// MCP protocol revision implemented by this server
+const PROTOCOL_VERSION: &str = "2024-11-05";
+
+/// Default number of search results
+const DEFAULT_SEARCH_LIMIT: usize = 5;
+
+/// Maximum characters of an entry shown in search results
+const SNIPPET_CHARS: usize = 800;
+
+/// Serves the generated documentation and analysis artifacts as MCP tools
+pub struct McpServer {
+    config: Config,
+}
+
+impl McpServer {
+    pub fn new(config: Config) -> Self {
+        Self { config }
+    }
+
+    /// Serve requests from stdin until the client closes the stream
+    pub async fn serve_stdio(&self) -> Result<()> {
+        info!(
+            "🔌 Litho MCP server ready (docs: {}, index: {})",
+            self.config.output_path.display(),
+            VectorIndex::default_dir(&self.config.internal_path).display()
+        );
+
+        let mut lines = BufReader::new(tokio::io::stdin()).lines();
+        let mut stdout = tokio::io::stdout();
+
+        while let Some(line) = lines.next_line().await? {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let response = match serde_json::from_str::<Value>(&line) {
+                Ok(request) => self.handle(request).await,
+                Err(e) => Some(error_response(Value::Null, -32700, &format!("Parse error: {}", e))),
+            };
+            if let Some(response) = response {
+                stdout.write_all(format!("{}\n", response).as_bytes()).await?;
+                stdout.flush().await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handle one JSON-RPC message; notifications get no response
+    pub async fn handle(&self, request: Value) -> Option<Value> {
+        let method = request["method"].as_str().unwrap_or_default().to_string();
+        let id = request.get("id").cloned()?;
+        debug!("MCP request: {}", method);
+
+        let result = match method.as_str() {
+            "initialize" => Ok(json!({
+                "protocolVersion": PROTOCOL_VERSION,
+                "capabilities": { "tools": {} },
+                "serverInfo": { "name": "litho", "version": env!("CARGO_PKG_VERSION") },
+            })),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": tool_definitions() })),
+            "tools/call" => Ok(self.call_tool(&request["params"]).await),
+            _ => Err((-32601, format!("Method not found: {}", method))),
+        };
+
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err((code, message)) => error_response(id, code, &message),
+        })
+    }
+
+    async fn call_tool(&self, params: &Value) -> Value {
+        let arguments = &params["arguments"];
+        let result = match params["name"].as_str().unwrap_or_default() {
+            "search" => self.search(arguments).await,
+            "get_document" => self.get_document(arguments),
+            "get_module_insight" => self.get_module_insight(arguments),
+            other => Err(anyhow::anyhow!("Unknown tool: {}", other)),
+        };
+
+        match result {
+            Ok(text) => json!({ "content": [{ "type": "text", "text": text }], "isError": false }),
+            Err(e) => json!({ "content": [{ "type": "text", "text": format!("{:#}", e) }], "isError": true }),
+        }
+    }
+
+    /// Semantic search over the vector index, falling back to keyword search over the docs
+    async fn search(&self, arguments: &Value) -> Result<String> {
+        let query = required_str(arguments, "query")?;
+        let limit = arguments["limit"]
+            .as_u64()
+            .map(|l| l as usize)
+            .unwrap_or(DEFAULT_SEARCH_LIMIT);
+
+        let passages = retrieve(&self.config, query, limit).await;
+        if passages.is_empty() {
+            return Ok(format!("No documentation matches \"{}\"", query));
+        }
+        Ok(passages
+            .iter()
+            .map(|passage| {
+                format!(
+                    "## {} ({}, {}, {})\n\n{}",
+                    passage.title,
+                    passage.kind,
+                    passage.source,
+                    passage.relevance,
+                    snippet(&passage.text)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n"))
+    }
+
+    /// Return a generated document, or the list of documents when no path is given
+    fn get_document(&self, arguments: &Value) -> Result<String> {
+        let output_dir = &self.config.output_path;
+        let Some(path) = arguments["path"].as_str().filter(|p| !p.is_empty()) else {
+            let mut documents: Vec<String> = WalkDir::new(output_dir)
+                .into_iter()
+                .filter_map(|e| e.ok())
+                .filter(|e| e.path().extension().is_some_and(|ext| ext == "md"))
+                .filter_map(|e| e.path().strip_prefix(output_dir).ok().map(|p| p.to_string_lossy().to_string()))
+                .collect();
+            documents.sort();
+            if documents.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "No generated documentation found in {}",
+                    output_dir.display()
+                ));
+            }
+            return Ok(format!("Available documents:\n{}", documents.join("\n")));
+        };
+
+        // Only serve files inside the output directory
+        let relative = Path::new(path);
+        if relative
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+        {
+            return Err(anyhow::anyhow!("Invalid document path: {}", path));
+        }
+        fs::read_to_string(output_dir.join(relative))
+            .map_err(|e| anyhow::anyhow!("Failed to read document {}: {}", path, e))
+    }
+
+    /// Collect the insights, interfaces and deep-dive document of a module
+    fn get_module_insight(&self, arguments: &Value) -> Result<String> {
+        let module = required_str(arguments, "module")?;
+        let needle = module.to_lowercase();
+        let mut sections = Vec::new();
+
+        let deep_dir = self
+            .config
+            .output_path
+            .join(self.config.directory_name("deep_exploration"));
+        for entry in WalkDir::new(&deep_dir).into_iter().filter_map(|e| e.ok()) {
+            let stem = entry
+                .path()
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_lowercase())
+                .unwrap_or_default();
+            if entry.file_type().is_file() && stem.contains(&needle) {
+                sections.push(fs::read_to_string(entry.path())?);
+            }
+        }
+
+        if let Some(index) = VectorIndex::load(&VectorIndex::default_dir(&self.config.internal_path)) {
+            let matches = |entry: &&IndexEntry| {
+                matches!(entry.kind, IndexKind::CodeInsight | IndexKind::Interface)
+                    && (entry.source.to_lowercase().contains(&needle)
+                        || entry.title.to_lowercase().contains(&needle))
+            };
+            let insights: Vec<String> = index
+                .entries()
+                .iter()
+                .filter(matches)
+                .map(|entry| format!("### [{}] {}\n\n{}", kind_label(entry.kind), entry.title, entry.text))
+                .collect();
+            if !insights.is_empty() {
+                sections.push(format!("## Code insights\n\n{}", insights.join("\n\n")));
+            }
+        }
+
+        if sections.is_empty() {
+            return Err(anyhow::anyhow!("No insight found for module \"{}\"", module));
+        }
+        Ok(sections.join("\n\n"))
+    }
+}
+
+fn tool_definitions() -> Value {
+    json!([
+        {
+            "name": "search",
+            "description": "Search the generated architecture documentation and code insights of this project",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string", "description": "What to look for" },
+                    "limit": { "type": "integer", "description": "Maximum number of results (default 5)" }
+                },
+                "required": ["query"]
+            }
+        },
+        {
+            "name": "get_document",
+            "description": "Read a generated documentation file; omit the path to list all documents",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string", "description": "Path relative to the documentation directory" }
+                }
+            }
+        },
+        {
+            "name": "get_module_insight",
+            "description": "Get the analysis of a module: its deep-dive document, file insights and interfaces",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "module": { "type": "string", "description": "Module name or source path fragment" }
+                },
+                "required": ["module"]
+            }
+        }
+    ])
+}
+
+fn required_str<'a>(arguments: &'a Value, name: &str) -> Result<&'a str> {
+    arguments[name]
+        .as_str()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or_else(|| anyhow::anyhow!("Missing required argument: {}", name))
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+fn snippet(text: &str) -> String {
+    match text.char_indices().nth(SNIPPET_CHARS) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_tools_list_and_get_document() {
+        let temp = tempfile::tempdir().unwrap();
+        let output_path = temp.path().to_path_buf();
+        fs::create_dir_all(&output_path).unwrap();
+        fs::write(output_path.join("overview.md"), "# Overview\n\n## Payments\nHandles refunds").unwrap();
+        let server = McpServer::new(Config {
+            output_path: output_path.clone(),
+            ..Default::default()
+        });
+
+        let list = server
+            .handle(json!({ "jsonrpc": "2.0", "id": 1, "method": "tools/list" }))
+            .await
+            .unwrap();
+        assert_eq!(list["result"]["tools"].as_array().unwrap().len(), 3);
+
+        let call = |name: &str, arguments: Value| {
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/call",
+                    "params": { "name": name, "arguments": arguments } })
+        };
+        let doc = server.handle(call("get_document", json!({ "path": "overview.md" }))).await.unwrap();
+        assert!(doc["result"]["content"][0]["text"].as_str().unwrap().contains("Handles refunds"));
+
+        let escape = server.handle(call("get_document", json!({ "path": "../secret" }))).await.unwrap();
+        assert_eq!(escape["result"]["isError"], true);
+
+        let search = server.handle(call("search", json!({ "query": "refunds" }))).await.unwrap();
+        assert!(search["result"]["content"][0]["text"].as_str().unwrap().contains("Payments"));
+
+        assert!(server.handle(json!({ "jsonrpc": "2.0", "method": "notifications/initialized" })).await.is_none());
+    }
+}
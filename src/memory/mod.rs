@@ -1,8 +1,21 @@
-use anyhow::Result;
-use chrono::{DateTime, Utc};
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Local, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::any::Any;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tracing::{debug, warn};
+
+use crate::config::MemoryConfig;
+use crate::integrations::freshness::hash_bytes;
+
+/// Format of the identifiers of archived runs
+const RUN_ID_FORMAT: &str = "%Y%m%d-%H%M%S";
+const RUN_ID_FORMAT_LEN: usize = "20250101-120000".len();
 
 /// Memory metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,11 +39,93 @@ impl MemoryMetadata {
     }
 }
 
+impl Default for MemoryMetadata {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// On-disk snapshot of one memory scope
+#[derive(Debug, Serialize, Deserialize)]
+struct ScopeSnapshot {
+    scope: String,
+    entries: BTreeMap<String, Value>,
+    /// Spilled entries, as the path of their spill file relative to the snapshot directory
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    spilled: BTreeMap<String, String>,
+}
+
+/// `ScopeSnapshot` borrowing the values of the memory instead of copying them
+#[derive(Serialize)]
+struct ScopeSnapshotRef<'a> {
+    scope: &'a str,
+    entries: BTreeMap<&'a str, &'a Value>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    spilled: BTreeMap<&'a str, String>,
+}
+
+/// Snapshot of a scope serialized under the memory lock, written to disk once it is released
+#[derive(Debug)]
+pub struct SnapshotWrite {
+    path: PathBuf,
+    contents: String,
+    generation: u64,
+    /// Generation of the last snapshot written per file, shared by all writes of a memory
+    written: Arc<Mutex<HashMap<PathBuf, u64>>>,
+}
+
+impl SnapshotWrite {
+    /// Write the snapshot, unless a newer snapshot of the scope was written first
+    pub fn write(self) -> Result<()> {
+        let mut written = self.written.lock().unwrap_or_else(|e| e.into_inner());
+        if written.get(&self.path).is_some_and(|generation| *generation >= self.generation) {
+            return Ok(());
+        }
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // Write to a temporary file first so a crash never leaves a truncated snapshot
+        let tmp_path = self.path.with_extension("json.tmp");
+        fs::write(&tmp_path, &self.contents)?;
+        fs::rename(&tmp_path, &self.path)?;
+        written.insert(self.path, self.generation);
+        Ok(())
+    }
+}
+
+/// Typed values handed out by `Memory::get_shared`, keyed like the memory data
+#[derive(Default)]
+struct SharedValues(HashMap<String, Arc<dyn Any + Send + Sync>>);
+
+impl fmt::Debug for SharedValues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
 /// Unified memory manager
 #[derive(Debug)]
 pub struct Memory {
     data: HashMap<String, Value>,
     metadata: MemoryMetadata,
+    /// Directory receiving a snapshot file per scope after every store
+    snapshot_dir: Option<PathBuf>,
+    /// Scopes stored since their last snapshot
+    dirty_scopes: BTreeSet<String>,
+    snapshot_generation: u64,
+    written_snapshots: Arc<Mutex<HashMap<PathBuf, u64>>>,
+    /// Size limits, applied once configured with `with_limits`
+    limits: Option<MemoryConfig>,
+    /// Keys whose values were spilled to disk
+    spilled: HashSet<String>,
+    /// Bytes of the values held in `data`
+    resident_size: usize,
+    /// Logical time of the last access of each key, used to find cold entries
+    last_access: HashMap<String, u64>,
+    access_clock: u64,
+    size_warning_logged: bool,
+    /// Deserialized large artifacts shared by all readers instead of copied per read
+    shared: SharedValues,
 }
 
 impl Memory {
@@ -38,6 +133,137 @@ impl Memory {
         Self {
             data: HashMap::new(),
             metadata: MemoryMetadata::new(),
+            snapshot_dir: None,
+            dirty_scopes: BTreeSet::new(),
+            snapshot_generation: 0,
+            written_snapshots: Arc::default(),
+            limits: None,
+            spilled: HashSet::new(),
+            resident_size: 0,
+            last_access: HashMap::new(),
+            access_clock: 0,
+            size_warning_logged: false,
+            shared: SharedValues::default(),
+        }
+    }
+
+    /// Empty memory that snapshots each scope to `dir`, replacing the snapshot of a previous run
+    pub fn persistent(dir: &Path) -> Self {
+        if dir.exists()
+            && let Err(e) = fs::remove_dir_all(dir)
+        {
+            warn!("⚠️  Failed to clear memory snapshot {}: {}", dir.display(), e);
+        }
+        Self {
+            snapshot_dir: Some(dir.to_path_buf()),
+            ..Self::new()
+        }
+    }
+
+    /// Reload the snapshot in `dir` and keep snapshotting to it
+    pub fn resume(dir: &Path) -> Result<Self> {
+        let mut memory = Self::load(dir)?;
+        memory.snapshot_dir = Some(dir.to_path_buf());
+        Ok(memory)
+    }
+
+    /// Reload the snapshot in `dir` into memory only, leaving the snapshot untouched
+    pub fn load(dir: &Path) -> Result<Self> {
+        let mut memory = Self::new();
+        for (scope, entries) in Self::read_snapshot(dir)? {
+            for (key, value) in entries {
+                memory.insert(&scope, &key, value);
+            }
+        }
+        Ok(memory)
+    }
+
+    /// Apply size limits; spilling needs a snapshot directory to write to
+    pub fn with_limits(mut self, limits: &MemoryConfig) -> Self {
+        self.limits = Some(limits.clone());
+        self.enforce_limits(None);
+        self
+    }
+
+    /// Read the scopes of a snapshot directory, keyed by scope then key
+    pub fn read_snapshot(dir: &Path) -> Result<BTreeMap<String, BTreeMap<String, Value>>> {
+        let mut scopes = BTreeMap::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != "json") {
+                continue;
+            }
+            let mut snapshot: ScopeSnapshot = serde_json::from_str(&fs::read_to_string(&path)?)?;
+            for (key, spill_file) in snapshot.spilled {
+                let value = serde_json::from_str(&fs::read_to_string(dir.join(&spill_file))?)?;
+                snapshot.entries.insert(key, value);
+            }
+            scopes.insert(snapshot.scope, snapshot.entries);
+        }
+        Ok(scopes)
+    }
+
+    /// Copy the snapshot in `dir` to a new run directory in `runs_dir`, keeping the latest `keep` runs
+    pub fn archive_snapshot(dir: &Path, runs_dir: &Path, keep: usize) -> Result<PathBuf> {
+        let run_dir = runs_dir.join(Local::now().format(RUN_ID_FORMAT).to_string());
+        fs::create_dir_all(&run_dir)?;
+        // Spilled entries are written inline, the run keeps no spill files
+        for (scope, entries) in Self::read_snapshot(dir)? {
+            let snapshot = ScopeSnapshot { scope, entries, spilled: BTreeMap::new() };
+            fs::write(run_dir.join(format!("{}.json", Self::snapshot_file_name(&snapshot.scope))), serde_json::to_string(&snapshot)?)?;
+        }
+
+        let runs = Self::list_runs(runs_dir);
+        for run in runs.iter().take(runs.len().saturating_sub(keep)) {
+            fs::remove_dir_all(runs_dir.join(run))?;
+        }
+        Ok(run_dir)
+    }
+
+    /// Identifiers of the archived runs in `runs_dir`, oldest first.
+    /// Sub-project directories of a monorepo share the parent and are skipped.
+    pub fn list_runs(runs_dir: &Path) -> Vec<String> {
+        let mut runs: Vec<String> = fs::read_dir(runs_dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|name| {
+                name.len() == RUN_ID_FORMAT_LEN && name.chars().all(|c| c.is_ascii_digit() || c == '-')
+            })
+            .collect();
+        runs.sort();
+        runs
+    }
+
+    /// Find an entry of a snapshot by `scope:key`, or by a key that exists in only one scope
+    pub fn find_entry<'a>(
+        scopes: &'a BTreeMap<String, BTreeMap<String, Value>>,
+        spec: &str,
+    ) -> Result<(&'a str, &'a str, &'a Value)> {
+        if let Some((scope, key)) = spec.split_once(':')
+            && let Some((scope, entries)) = scopes.get_key_value(scope)
+            && let Some((key, value)) = entries.get_key_value(key)
+        {
+            return Ok((scope, key, value));
+        }
+
+        let matches: Vec<(&str, &str, &Value)> = scopes
+            .iter()
+            .filter_map(|(scope, entries)| {
+                let (key, value) = entries.get_key_value(spec)?;
+                Some((scope.as_str(), key.as_str(), value))
+            })
+            .collect();
+        match matches.as_slice() {
+            [entry] => Ok(*entry),
+            [] => Err(anyhow!("No memory entry named '{}'", spec)),
+            _ => Err(anyhow!(
+                "'{}' exists in several scopes ({}), use <scope>:<key>",
+                spec,
+                matches.iter().map(|(scope, _, _)| *scope).collect::<Vec<_>>().join(", ")
+            )),
         }
     }
 
@@ -46,8 +272,28 @@ impl Memory {
     where
         T: Serialize,
     {
-        let full_key = format!("{}:{}", scope, key);
         let serialized = serde_json::to_value(data)?;
+        self.insert(scope, key, serialized);
+        self.enforce_limits(Some(&format!("{}:{}", scope, key)));
+        if self.snapshot_dir.is_some() {
+            self.dirty_scopes.insert(scope.to_string());
+        }
+        Ok(())
+    }
+
+    /// Store a large artifact and keep the typed value, handed out by `get_shared` without copying
+    pub fn store_shared<T>(&mut self, scope: &str, key: &str, data: Arc<T>) -> Result<()>
+    where
+        T: Serialize + Send + Sync + 'static,
+    {
+        self.store(scope, key, data.as_ref())?;
+        self.shared.0.insert(format!("{}:{}", scope, key), data);
+        Ok(())
+    }
+
+    fn insert(&mut self, scope: &str, key: &str, serialized: Value) {
+        let full_key = format!("{}:{}", scope, key);
+        self.shared.0.remove(&full_key);
 
         // Calculate data size
         let data_size = serialized.to_string().len();
@@ -55,15 +301,165 @@ impl Memory {
         // Update metadata
         if let Some(old_size) = self.metadata.data_sizes.get(&full_key) {
             self.metadata.total_size -= old_size;
+            if !self.spilled.contains(&full_key) {
+                self.resident_size -= old_size;
+            }
         }
         self.metadata.data_sizes.insert(full_key.clone(), data_size);
         self.metadata.total_size += data_size;
         self.metadata.last_updated = Utc::now();
 
+        // The spill file is kept: the snapshot on disk may reference it until the scope is written again
+        self.spilled.remove(&full_key);
+        self.resident_size += data_size;
+        self.touch(&full_key);
         self.data.insert(full_key, serialized);
+    }
+
+    fn touch(&mut self, full_key: &str) {
+        self.access_clock += 1;
+        self.last_access.insert(full_key.to_string(), self.access_clock);
+    }
+
+    /// Warn about the memory size and spill cold entries, never the entry in use (`keep`)
+    fn enforce_limits(&mut self, keep: Option<&str>) {
+        let Some(limits) = &self.limits else {
+            return;
+        };
+        let warning_bytes = limits.warning_threshold_mb * 1024 * 1024;
+        let spill_bytes = limits.spill_threshold_mb * 1024 * 1024;
+
+        if !self.size_warning_logged && self.metadata.total_size > warning_bytes {
+            self.size_warning_logged = true;
+            let mut scopes: Vec<(String, usize)> = self.get_usage_stats().into_iter().collect();
+            scopes.sort_by_key(|(_, size)| std::cmp::Reverse(*size));
+            let largest: Vec<String> = scopes
+                .iter()
+                .take(3)
+                .map(|(scope, size)| format!("{} {:.1} MB", scope, *size as f64 / 1048576.0))
+                .collect();
+            warn!(
+                "⚠️  Generator memory holds {:.1} MB, above the {} MB warning threshold (largest scopes: {})",
+                self.metadata.total_size as f64 / 1048576.0,
+                limits.warning_threshold_mb,
+                largest.join(", ")
+            );
+        }
+
+        if spill_bytes == 0 || self.resident_size <= spill_bytes || self.snapshot_dir.is_none() {
+            return;
+        }
+        let mut cold: Vec<(u64, String)> = self
+            .data
+            .keys()
+            .filter(|key| Some(key.as_str()) != keep)
+            .map(|key| (self.last_access.get(key).copied().unwrap_or(0), key.clone()))
+            .collect();
+        cold.sort();
+        for (_, key) in cold {
+            if self.resident_size <= spill_bytes {
+                break;
+            }
+            if let Err(e) = self.spill(&key) {
+                warn!("⚠️  Failed to spill memory entry '{}' to disk: {}", key, e);
+                break;
+            }
+        }
+    }
+
+    /// Move the value of a key from `data` to its spill file
+    fn spill(&mut self, full_key: &str) -> Result<()> {
+        let path = self.spill_path(full_key).ok_or_else(|| anyhow!("no snapshot directory"))?;
+        let Some(value) = self.data.get(full_key) else {
+            return Ok(());
+        };
+        fs::create_dir_all(path.parent().unwrap_or(Path::new(".")))?;
+        fs::write(&path, serde_json::to_string(value)?)?;
+
+        self.data.remove(full_key);
+        self.shared.0.remove(full_key);
+        self.spilled.insert(full_key.to_string());
+        self.resident_size -= self.metadata.data_sizes.get(full_key).copied().unwrap_or(0);
+        debug!("💤 Spilled memory entry '{}' to {}", full_key, path.display());
         Ok(())
     }
 
+    /// Read the value of a spilled key back from disk
+    fn read_spilled(&self, full_key: &str) -> Option<Value> {
+        let path = self.spill_path(full_key)?;
+        let content = fs::read_to_string(path).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    fn spill_path(&self, full_key: &str) -> Option<PathBuf> {
+        let dir = self.snapshot_dir.as_ref()?;
+        Some(dir.join("spill").join(format!("{}.json", hash_bytes(full_key.as_bytes()))))
+    }
+
+    /// Serialize the scopes stored since their last snapshot; the caller writes them to
+    /// `<dir>/<scope>.json` after releasing the memory
+    pub fn take_snapshot_writes(&mut self) -> Vec<SnapshotWrite> {
+        let Some(dir) = self.snapshot_dir.clone() else {
+            return Vec::new();
+        };
+        let mut writes = Vec::new();
+        for scope in std::mem::take(&mut self.dirty_scopes) {
+            match self.snapshot_scope(&scope) {
+                Ok(contents) => {
+                    self.snapshot_generation += 1;
+                    writes.push(SnapshotWrite {
+                        path: dir.join(format!("{}.json", Self::snapshot_file_name(&scope))),
+                        contents,
+                        generation: self.snapshot_generation,
+                        written: self.written_snapshots.clone(),
+                    });
+                }
+                Err(e) => warn!("⚠️  Failed to snapshot memory scope '{}': {}", scope, e),
+            }
+        }
+        writes
+    }
+
+    /// Write the pending snapshots right away
+    pub fn flush_snapshots(&mut self) {
+        for write in self.take_snapshot_writes() {
+            if let Err(e) = write.write() {
+                warn!("⚠️  Failed to write memory snapshot: {}", e);
+            }
+        }
+    }
+
+    /// Serialize the entries of a scope, spilled entries as references to their spill files
+    fn snapshot_scope(&self, scope: &str) -> Result<String> {
+        let prefix = format!("{}:", scope);
+        let spill_dir = self.snapshot_dir.as_deref().unwrap_or(Path::new("."));
+        let snapshot = ScopeSnapshotRef {
+            scope,
+            entries: self
+                .data
+                .iter()
+                .filter_map(|(key, value)| Some((key.strip_prefix(&prefix)?, value)))
+                .collect(),
+            spilled: self
+                .spilled
+                .iter()
+                .filter_map(|key| {
+                    let path = self.spill_path(key)?;
+                    let relative = path.strip_prefix(spill_dir).ok()?;
+                    Some((key.strip_prefix(&prefix)?, relative.to_string_lossy().replace('\\', "/")))
+                })
+                .collect(),
+        };
+        Ok(serde_json::to_string(&snapshot)?)
+    }
+
+    fn snapshot_file_name(scope: &str) -> String {
+        scope
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '_' || c == '-' { c } else { '_' })
+            .collect()
+    }
+
     /// Get data from specified scope and key
     pub fn get<T>(&mut self, scope: &str, key: &str) -> Option<T>
     where
@@ -78,9 +474,41 @@ impl Memory {
             .entry(full_key.clone())
             .or_insert(0) += 1;
 
+        if self.spilled.contains(&full_key) {
+            let value = self.read_spilled(&full_key)?;
+            let (scope, key) = full_key.split_once(':')?;
+            self.insert(scope, key, value);
+            self.enforce_limits(Some(&full_key));
+        } else if self.data.contains_key(&full_key) {
+            self.touch(&full_key);
+        }
+
         self.data
             .get(&full_key)
-            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .and_then(|value| T::deserialize(value).ok())
+    }
+
+    /// Get a typed value shared between readers: deserialized on the first read and reused
+    /// until the key is stored again or spilled to disk
+    pub fn get_shared<T>(&mut self, scope: &str, key: &str) -> Option<Arc<T>>
+    where
+        T: for<'a> Deserialize<'a> + Send + Sync + 'static,
+    {
+        let full_key = format!("{}:{}", scope, key);
+        if let Some(value) = self
+            .shared
+            .0
+            .get(&full_key)
+            .and_then(|value| Arc::clone(value).downcast::<T>().ok())
+        {
+            *self.metadata.access_counts.entry(full_key.clone()).or_insert(0) += 1;
+            self.touch(&full_key);
+            return Some(value);
+        }
+
+        let value = Arc::new(self.get::<T>(scope, key)?);
+        self.shared.0.insert(full_key, value.clone());
+        Some(value)
     }
 
     /// List all keys in the specified scope
@@ -88,6 +516,7 @@ impl Memory {
         let prefix = format!("{}:", scope);
         self.data
             .keys()
+            .chain(&self.spilled)
             .filter(|key| key.starts_with(&prefix))
             .map(|key| key[prefix.len()..].to_string())
             .collect()
@@ -96,7 +525,7 @@ impl Memory {
     /// Check if specified data exists
     pub fn has_data(&self, scope: &str, key: &str) -> bool {
         let full_key = format!("{}:{}", scope, key);
-        self.data.contains_key(&full_key)
+        self.data.contains_key(&full_key) || self.spilled.contains(&full_key)
     }
 
     /// Get memory usage statistics
@@ -111,3 +540,113 @@ impl Memory {
         stats
     }
 }
+
+impl Default for Memory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_and_resume() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().join("memory");
+        let mut memory = Memory::persistent(&dir);
+        memory.store("studies_research", "SystemContextResearcher", "context").unwrap();
+        memory.store("documentation", "Project Overview", "# Overview").unwrap();
+        assert!(Memory::read_snapshot(&dir).is_err());
+        memory.flush_snapshots();
+
+        let runs_dir = temp.path().join("runs");
+        let run_dir = Memory::archive_snapshot(&dir, &runs_dir, 1).unwrap();
+        assert_eq!(Memory::list_runs(&runs_dir), vec![run_dir.file_name().unwrap().to_string_lossy()]);
+        assert!(Memory::read_snapshot(&run_dir).unwrap().contains_key("documentation"));
+
+        let mut resumed = Memory::resume(&dir).unwrap();
+        assert_eq!(
+            resumed.get::<String>("studies_research", "SystemContextResearcher"),
+            Some("context".to_string())
+        );
+        assert!(resumed.has_data("documentation", "Project Overview"));
+
+        // A snapshot taken earlier but written last does not overwrite a newer one
+        resumed.store("documentation", "Project Overview", "# Old").unwrap();
+        let older = resumed.take_snapshot_writes();
+        resumed.store("documentation", "Project Overview", "# New").unwrap();
+        resumed.take_snapshot_writes().into_iter().chain(older).for_each(|write| write.write().unwrap());
+        assert_eq!(Memory::read_snapshot(&dir).unwrap()["documentation"]["Project Overview"], Value::from("# New"));
+
+        // A new run starts from an empty snapshot
+        Memory::persistent(&dir);
+        assert!(Memory::resume(&dir).is_err());
+    }
+
+    #[test]
+    fn test_spill_cold_entries() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().to_path_buf();
+        let limits = MemoryConfig {
+            warning_threshold_mb: 1,
+            spill_threshold_mb: 1,
+            ..MemoryConfig::default()
+        };
+        let mut memory = Memory::persistent(&dir).with_limits(&limits);
+        let large = "x".repeat(700 * 1024);
+        memory.store("preprocess", "code_insights", &large).unwrap();
+        memory.store("studies_research", "Workflow Research Report", &large).unwrap();
+        memory.flush_snapshots();
+
+        // The older entry went to disk but is still listed, readable and snapshotted
+        assert!(memory.spilled.contains("preprocess:code_insights"));
+        assert_eq!(memory.list_keys("preprocess"), vec!["code_insights".to_string()]);
+        assert_eq!(memory.get::<String>("preprocess", "code_insights"), Some(large.clone()));
+        assert!(memory.spilled.contains("studies_research:Workflow Research Report"));
+        // The snapshot references spill files instead of inlining their values
+        let file = fs::read_to_string(dir.join("preprocess.json")).unwrap();
+        assert!(file.len() < 1024 && file.contains("spill/"));
+        let snapshot = Memory::read_snapshot(&dir).unwrap();
+        assert_eq!(snapshot["studies_research"]["Workflow Research Report"], Value::from(large.clone()));
+        assert_eq!(snapshot["preprocess"]["code_insights"], Value::from(large));
+        assert_eq!(memory.get_usage_stats()["studies_research"], 700 * 1024 + 2);
+    }
+
+    #[test]
+    fn test_shared_values() {
+        let mut memory = Memory::new();
+        memory.store_shared("preprocess", "code_insights", Arc::new(vec![1, 2])).unwrap();
+
+        let first = memory.get_shared::<Vec<i32>>("preprocess", "code_insights").unwrap();
+        let second = memory.get_shared::<Vec<i32>>("preprocess", "code_insights").unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(memory.get::<Vec<i32>>("preprocess", "code_insights"), Some(vec![1, 2]));
+
+        // A plain store replaces the shared value, readers holding the old one keep it
+        memory.store("preprocess", "code_insights", vec![3]).unwrap();
+        assert_eq!(*memory.get_shared::<Vec<i32>>("preprocess", "code_insights").unwrap(), vec![3]);
+        assert_eq!(*first, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_find_entry() {
+        let mut scopes = BTreeMap::new();
+        for scope in ["studies_research", "timing"] {
+            let entries = BTreeMap::from([("Domain Modules Research Report".to_string(), Value::from(scope))]);
+            scopes.insert(scope.to_string(), entries);
+        }
+        scopes
+            .get_mut("timing")
+            .unwrap()
+            .insert("total_execution".to_string(), Value::from(12.5));
+
+        let (scope, key, value) = Memory::find_entry(&scopes, "total_execution").unwrap();
+        assert_eq!((scope, key, value), ("timing", "total_execution", &Value::from(12.5)));
+        let (scope, _, _) = Memory::find_entry(&scopes, "studies_research:Domain Modules Research Report").unwrap();
+        assert_eq!(scope, "studies_research");
+        assert!(Memory::find_entry(&scopes, "Domain Modules Research Report").is_err());
+        assert!(Memory::find_entry(&scopes, "missing").is_err());
+    }
+}
@@ -0,0 +1,186 @@
+//! Process-wide operational metrics in the Prometheus text format, exposed on `/metrics`
+//! by `serve-api`: LLM calls and their latency, tokens, cache hits and failed steps
+
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::llm::client::types::TokenUsage;
+
+/// Upper bounds (seconds) of the LLM call latency histogram buckets
+const LATENCY_BUCKETS: [f64; 9] = [0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0];
+
+/// Counters of every generation run in the process
+#[derive(Debug, Default)]
+pub struct Metrics {
+    llm_calls: AtomicU64,
+    llm_errors: AtomicU64,
+    llm_fallbacks: AtomicU64,
+    /// Calls per latency bucket (not cumulative), the last one above all bounds
+    latency_buckets: [AtomicU64; LATENCY_BUCKETS.len() + 1],
+    latency_sum_ms: AtomicU64,
+    input_tokens: AtomicU64,
+    output_tokens: AtomicU64,
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+    step_failures: AtomicU64,
+}
+
+static METRICS: std::sync::LazyLock<Metrics> = std::sync::LazyLock::new(Metrics::default);
+
+/// The metrics of this process
+pub fn metrics() -> &'static Metrics {
+    &METRICS
+}
+
+impl Metrics {
+    /// One request to the model service (each retry counts), successful or not
+    pub fn record_llm_call(&self, duration: Duration, success: bool) {
+        self.llm_calls.fetch_add(1, Ordering::Relaxed);
+        if !success {
+            self.llm_errors.fetch_add(1, Ordering::Relaxed);
+        }
+        let seconds = duration.as_secs_f64();
+        let bucket = LATENCY_BUCKETS
+            .iter()
+            .position(|bound| seconds <= *bound)
+            .unwrap_or(LATENCY_BUCKETS.len());
+        self.latency_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        self.latency_sum_ms.fetch_add(duration.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    pub fn record_llm_fallback(&self) {
+        self.llm_fallbacks.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_tokens(&self, usage: &TokenUsage) {
+        self.input_tokens.fetch_add(usage.input_tokens as u64, Ordering::Relaxed);
+        self.output_tokens.fetch_add(usage.output_tokens as u64, Ordering::Relaxed);
+    }
+
+    pub fn record_cache_lookup(&self, hit: bool) {
+        let counter = if hit { &self.cache_hits } else { &self.cache_misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// A step that failed and was skipped or replaced by a fallback (see `run-summary.json`)
+    pub fn record_step_failure(&self) {
+        self.step_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Prometheus text exposition of the metrics
+    pub fn render(&self) -> String {
+        let load = |counter: &AtomicU64| counter.load(Ordering::Relaxed);
+        let mut text = String::new();
+        let mut metric = |name: &str, kind: &str, help: &str, samples: &[(&str, String)]| {
+            let _ = writeln!(text, "# HELP {} {}\n# TYPE {} {}", name, help, name, kind);
+            for (labels, value) in samples {
+                let _ = writeln!(text, "{}{} {}", name, labels, value);
+            }
+        };
+
+        let calls = load(&self.llm_calls);
+        let errors = load(&self.llm_errors);
+        metric(
+            "litho_llm_requests_total",
+            "counter",
+            "Requests sent to the model service, by outcome",
+            &[
+                ("{outcome=\"success\"}", (calls - errors).to_string()),
+                ("{outcome=\"error\"}", errors.to_string()),
+            ],
+        );
+        metric(
+            "litho_llm_fallbacks_total",
+            "counter",
+            "Calls answered by a fallback model or by summary reasoning",
+            &[("", load(&self.llm_fallbacks).to_string())],
+        );
+
+        let mut cumulative = 0;
+        let mut buckets: Vec<(String, String)> = LATENCY_BUCKETS
+            .iter()
+            .zip(&self.latency_buckets)
+            .map(|(bound, count)| {
+                cumulative += load(count);
+                (format!("_bucket{{le=\"{}\"}}", bound), cumulative.to_string())
+            })
+            .collect();
+        buckets.push(("_bucket{le=\"+Inf\"}".to_string(), calls.to_string()));
+        buckets.push(("_sum".to_string(), (load(&self.latency_sum_ms) as f64 / 1000.0).to_string()));
+        buckets.push(("_count".to_string(), calls.to_string()));
+        let buckets: Vec<(&str, String)> = buckets.iter().map(|(labels, value)| (labels.as_str(), value.clone())).collect();
+        metric(
+            "litho_llm_request_duration_seconds",
+            "histogram",
+            "Latency of the requests sent to the model service",
+            &buckets,
+        );
+
+        metric(
+            "litho_llm_tokens_total",
+            "counter",
+            "Tokens of the requests sent to the model service",
+            &[
+                ("{kind=\"input\"}", load(&self.input_tokens).to_string()),
+                ("{kind=\"output\"}", load(&self.output_tokens).to_string()),
+            ],
+        );
+
+        let (hits, misses) = (load(&self.cache_hits), load(&self.cache_misses));
+        metric(
+            "litho_cache_lookups_total",
+            "counter",
+            "LLM response cache lookups, by result",
+            &[("{result=\"hit\"}", hits.to_string()), ("{result=\"miss\"}", misses.to_string())],
+        );
+        let hit_rate = if hits + misses == 0 { 0.0 } else { hits as f64 / (hits + misses) as f64 };
+        metric(
+            "litho_cache_hit_ratio",
+            "gauge",
+            "Share of the LLM response cache lookups that were hits",
+            &[("", format!("{:.4}", hit_rate))],
+        );
+
+        metric(
+            "litho_step_failures_total",
+            "counter",
+            "Failed steps skipped or replaced by a fallback",
+            &[("", load(&self.step_failures).to_string())],
+        );
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_metrics() {
+        let metrics = Metrics::default();
+        metrics.record_llm_call(Duration::from_millis(800), true);
+        metrics.record_llm_call(Duration::from_secs(7), true);
+        metrics.record_llm_call(Duration::from_secs(400), false);
+        metrics.record_tokens(&TokenUsage::new(1200, 300));
+        metrics.record_cache_lookup(true);
+        metrics.record_cache_lookup(false);
+        metrics.record_cache_lookup(false);
+        metrics.record_cache_lookup(false);
+        metrics.record_step_failure();
+
+        let text = metrics.render();
+        assert!(text.contains("# TYPE litho_llm_requests_total counter\n"));
+        assert!(text.contains("litho_llm_requests_total{outcome=\"success\"} 2\n"));
+        assert!(text.contains("litho_llm_requests_total{outcome=\"error\"} 1\n"));
+        assert!(text.contains("litho_llm_request_duration_seconds_bucket{le=\"0.5\"} 0\n"));
+        assert!(text.contains("litho_llm_request_duration_seconds_bucket{le=\"1\"} 1\n"));
+        assert!(text.contains("litho_llm_request_duration_seconds_bucket{le=\"10\"} 2\n"));
+        assert!(text.contains("litho_llm_request_duration_seconds_bucket{le=\"300\"} 2\n"));
+        assert!(text.contains("litho_llm_request_duration_seconds_bucket{le=\"+Inf\"} 3\n"));
+        assert!(text.contains("litho_llm_request_duration_seconds_sum 407.8\n"));
+        assert!(text.contains("litho_llm_tokens_total{kind=\"input\"} 1200\n"));
+        assert!(text.contains("litho_cache_hit_ratio 0.2500\n"));
+        assert!(text.contains("litho_step_failures_total 1\n"));
+    }
+}
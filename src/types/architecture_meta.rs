@@ -0,0 +1,247 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use glob::Pattern;
+use serde::{Deserialize, Serialize};
+
+/// Intended architecture declared by the project team in `architecture_meta_path`
+/// (YAML, JSON or TOML). It takes precedence over what the agents infer from the code.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ArchitectureMeta {
+    /// Architecture style and main design decisions in a few sentences
+    pub description: Option<String>,
+    /// Layers from the outermost to the innermost
+    pub layers: Vec<DeclaredLayer>,
+    pub modules: Vec<DeclaredModule>,
+    /// Dependencies that must not exist
+    pub boundaries: Vec<DeclaredBoundary>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DeclaredLayer {
+    pub name: String,
+    pub description: Option<String>,
+    /// Glob patterns or directories, relative to the project root
+    pub paths: Vec<String>,
+    /// Layers this layer may depend on; any dependency is allowed when not set
+    pub may_depend_on: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DeclaredModule {
+    pub name: String,
+    pub description: Option<String>,
+    /// Glob patterns or directories, relative to the project root
+    pub paths: Vec<String>,
+    /// Team or people owning the module
+    pub owner: Option<String>,
+    /// Layer the module belongs to
+    pub layer: Option<String>,
+}
+
+/// Forbidden dependency between two layers or modules
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DeclaredBoundary {
+    pub from: String,
+    pub to: String,
+    pub reason: Option<String>,
+}
+
+impl ArchitectureMeta {
+    /// Read and validate the file; relative paths that do not exist from the working
+    /// directory are resolved against the project root
+    pub fn load(path: &Path, project_root: &Path) -> Result<Self> {
+        let path = if path.is_relative() && !path.exists() {
+            project_root.join(path)
+        } else {
+            path.to_path_buf()
+        };
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read architecture meta file {}", path.display()))?;
+        let meta: Self = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => toml::from_str(&content).map_err(anyhow::Error::from),
+            Some("json") => serde_json::from_str(&content).map_err(anyhow::Error::from),
+            _ => serde_yaml::from_str(&content).map_err(anyhow::Error::from),
+        }
+        .with_context(|| format!("Invalid architecture meta file {}", path.display()))?;
+        meta.validate()
+            .with_context(|| format!("Invalid architecture meta file {}", path.display()))?;
+        Ok(meta)
+    }
+
+    /// Check names, references and path patterns, reporting every problem at once
+    pub fn validate(&self) -> Result<()> {
+        let mut errors = Vec::new();
+        let mut names = HashSet::new();
+        let layers: Vec<&str> = self.layers.iter().map(|layer| layer.name.as_str()).collect();
+
+        let entries = self
+            .layers
+            .iter()
+            .map(|layer| ("layer", &layer.name, &layer.paths))
+            .chain(self.modules.iter().map(|module| ("module", &module.name, &module.paths)));
+        for (kind, name, paths) in entries {
+            if name.trim().is_empty() {
+                errors.push(format!("a {} has no name", kind));
+            } else if !names.insert(name.as_str()) {
+                errors.push(format!("{} `{}` is declared twice", kind, name));
+            }
+            if paths.is_empty() {
+                errors.push(format!("{} `{}` has no paths", kind, name));
+            }
+            for path in paths {
+                if let Err(e) = Pattern::new(&normalize(path)) {
+                    errors.push(format!("{} `{}` has an invalid path pattern `{}`: {}", kind, name, path, e));
+                }
+            }
+        }
+        for layer in &self.layers {
+            for target in layer.may_depend_on.iter().flatten() {
+                if !layers.contains(&target.as_str()) {
+                    errors.push(format!("layer `{}` may depend on unknown layer `{}`", layer.name, target));
+                }
+            }
+        }
+        for module in &self.modules {
+            if let Some(layer) = module.layer.as_ref().filter(|layer| !layers.contains(&layer.as_str())) {
+                errors.push(format!("module `{}` belongs to unknown layer `{}`", module.name, layer));
+            }
+        }
+        for boundary in &self.boundaries {
+            for name in [&boundary.from, &boundary.to] {
+                if !names.contains(name.as_str()) {
+                    errors.push(format!("boundary `{} -> {}` names unknown layer or module `{}`", boundary.from, boundary.to, name));
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            bail!("{}", errors.join("; "));
+        }
+        Ok(())
+    }
+
+    /// Layer a file belongs to, either through its own paths or through its module
+    pub fn layer_of(&self, path: &Path) -> Option<&str> {
+        self.layers
+            .iter()
+            .find(|layer| matches(&layer.paths, path))
+            .map(|layer| layer.name.as_str())
+            .or_else(|| self.module_of(path).and_then(|module| module.layer.as_deref()))
+    }
+
+    pub fn module_of(&self, path: &Path) -> Option<&DeclaredModule> {
+        self.modules.iter().find(|module| matches(&module.paths, path))
+    }
+
+    /// Declared rules broken by a dependency of `from` on `to` (paths relative to the project root)
+    pub fn violations(&self, from: &Path, to: &Path) -> Vec<String> {
+        let mut violations = Vec::new();
+        let (from_layer, to_layer) = (self.layer_of(from), self.layer_of(to));
+        if let (Some(from_layer), Some(to_layer)) = (from_layer, to_layer)
+            && from_layer != to_layer
+            && let Some(allowed) = self
+                .layers
+                .iter()
+                .find(|layer| layer.name == from_layer)
+                .and_then(|layer| layer.may_depend_on.as_ref())
+            && !allowed.iter().any(|layer| layer == to_layer)
+        {
+            violations.push(format!("layer `{}` may not depend on layer `{}`", from_layer, to_layer));
+        }
+
+        let from_module = self.module_of(from).map(|module| module.name.as_str());
+        let to_module = self.module_of(to).map(|module| module.name.as_str());
+        for boundary in &self.boundaries {
+            let crosses = |names: [Option<&str>; 2], target: &str| names.contains(&Some(target));
+            if crosses([from_layer, from_module], &boundary.from)
+                && crosses([to_layer, to_module], &boundary.to)
+                && boundary.from != boundary.to
+            {
+                violations.push(match &boundary.reason {
+                    Some(reason) => format!("crosses the boundary `{} -> {}` ({})", boundary.from, boundary.to, reason),
+                    None => format!("crosses the boundary `{} -> {}`", boundary.from, boundary.to),
+                });
+            }
+        }
+        violations
+    }
+}
+
+fn normalize(pattern: &str) -> String {
+    pattern.replace('\\', "/").trim_start_matches("./").trim_matches('/').to_string()
+}
+
+/// Whether a pattern matches the path or one of its directories
+fn matches(patterns: &[String], path: &Path) -> bool {
+    path.ancestors()
+        .filter(|path| !path.as_os_str().is_empty())
+        .any(|path| {
+            let path = path.to_string_lossy().replace('\\', "/");
+            patterns
+                .iter()
+                .filter_map(|pattern| Pattern::new(&normalize(pattern)).ok())
+                .any(|pattern| pattern.matches(&path))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const META: &str = r#"
+description: Hexagonal architecture, the domain knows nothing about the adapters
+layers:
+  - name: adapters
+    paths: ["src/adapters"]
+    may_depend_on: [domain]
+  - name: domain
+    paths: ["src/domain/**"]
+    may_depend_on: []
+modules:
+  - name: Billing
+    paths: ["src/domain/billing"]
+    owner: "@payments"
+    layer: domain
+  - name: Ledger
+    paths: ["src/domain/ledger"]
+    layer: domain
+boundaries:
+  - from: Billing
+    to: Ledger
+    reason: billing goes through ledger events
+"#;
+
+    #[test]
+    fn test_architecture_meta_rules() {
+        let meta: ArchitectureMeta = serde_yaml::from_str(META).unwrap();
+        meta.validate().unwrap();
+
+        assert_eq!(meta.layer_of(Path::new("src/adapters/http/routes.rs")), Some("adapters"));
+        assert_eq!(meta.module_of(Path::new("src/domain/billing/invoice.rs")).unwrap().name, "Billing");
+        assert!(meta.violations(Path::new("src/adapters/db.rs"), Path::new("src/domain/order.rs")).is_empty());
+        assert_eq!(
+            meta.violations(Path::new("src/domain/order.rs"), Path::new("src/adapters/db.rs")),
+            ["layer `domain` may not depend on layer `adapters`"]
+        );
+        assert_eq!(
+            meta.violations(Path::new("src/domain/billing/invoice.rs"), Path::new("src/domain/ledger/entry.rs")),
+            ["crosses the boundary `Billing -> Ledger` (billing goes through ledger events)"]
+        );
+
+        let invalid: ArchitectureMeta = serde_yaml::from_str(
+            "layers:\n  - name: core\n    paths: [\"src/[core\"]\n    may_depend_on: [infra]\nmodules:\n  - name: core\n    paths: []\n",
+        )
+        .unwrap();
+        let error = invalid.validate().unwrap_err().to_string();
+        assert!(error.contains("layer `core` has an invalid path pattern `src/[core`"));
+        assert!(error.contains("module `core` is declared twice"));
+        assert!(error.contains("module `core` has no paths"));
+        assert!(error.contains("layer `core` may depend on unknown layer `infra`"));
+    }
+}
@@ -148,12 +148,19 @@ where
         Some(serde_json::Value::String(s)) => s.parse::<usize>().unwrap_or(0),
         _ => 0,
     };
+
+    let max_nesting_depth = match json_value.get("max_nesting_depth") {
+        Some(serde_json::Value::Number(n)) => n.as_u64().unwrap_or(0) as usize,
+        Some(serde_json::Value::String(s)) => s.parse::<usize>().unwrap_or(0),
+        _ => 0,
+    };
     
     Ok(CodeComplexity {
         cyclomatic_complexity,
         lines_of_code,
         number_of_functions,
         number_of_classes,
+        max_nesting_depth,
     })
 }
 
@@ -252,6 +259,7 @@ pub struct CodeDossier {
     #[serde(default, deserialize_with = "deserialize_string_lenient")]
     pub name: String,
     /// File path
+    #[serde(serialize_with = "crate::utils::paths::serialize_slash")]
     pub file_path: PathBuf,
     /// Source code summary
     #[schemars(skip)]
@@ -389,6 +397,25 @@ pub struct CodeComplexity {
     pub number_of_functions: usize,
     #[serde(deserialize_with = "deserialize_usize_lenient")]
     pub number_of_classes: usize,
+    /// Deepest block nesting
+    #[serde(deserialize_with = "deserialize_usize_lenient")]
+    pub max_nesting_depth: usize,
+}
+
+impl CodeComplexity {
+    /// Complexity between 0 and 1: cyclomatic complexity saturating at 50 paths, weighted 0.7,
+    /// and nesting saturating at 6 levels, weighted 0.3
+    pub fn score(&self) -> f64 {
+        0.7 * (self.cyclomatic_complexity / 50.0).min(1.0) + 0.3 * (self.max_nesting_depth as f64 / 6.0).min(1.0)
+    }
+
+    /// One-line summary cited in prompts
+    pub fn describe(&self) -> String {
+        format!(
+            "cyclomatic complexity {:.0}, max nesting {}, {} lines, {} functions",
+            self.cyclomatic_complexity, self.max_nesting_depth, self.lines_of_code, self.number_of_functions
+        )
+    }
 }
 
 /// Code functionality classification enum
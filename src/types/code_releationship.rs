@@ -113,6 +113,22 @@ pub struct RelationshipAnalysis {
     pub key_insights: Vec<String>,
 }
 
+impl RelationshipAnalysis {
+    /// Add dependencies not yet present with the same endpoints and type
+    pub fn merge_dependencies(&mut self, dependencies: &[CoreDependency]) {
+        for dependency in dependencies {
+            let exists = self.core_dependencies.iter().any(|existing| {
+                existing.from == dependency.from
+                    && existing.to == dependency.to
+                    && existing.dependency_type.as_str() == dependency.dependency_type.as_str()
+            });
+            if !exists {
+                self.core_dependencies.push(dependency.clone());
+            }
+        }
+    }
+}
+
 fn deserialize_vec_core_dependencies_lenient<'de, D>(
     deserializer: D,
 ) -> Result<Vec<CoreDependency>, D::Error>
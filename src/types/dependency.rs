@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+/// Package ecosystem of a dependency
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Ecosystem {
+    Cargo,
+    Npm,
+    Pypi,
+    Go,
+    Maven,
+    Nuget,
+    Composer,
+    Swift,
+}
+
+impl Ecosystem {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Ecosystem::Cargo => "Cargo (crates.io)",
+            Ecosystem::Npm => "npm",
+            Ecosystem::Pypi => "Python (PyPI)",
+            Ecosystem::Go => "Go modules",
+            Ecosystem::Maven => "Maven / Gradle",
+            Ecosystem::Nuget => "NuGet",
+            Ecosystem::Composer => "Composer",
+            Ecosystem::Swift => "Swift",
+        }
+    }
+}
+
+/// Dependency declared by a manifest or imported by the source files
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DependencyEntry {
+    /// Package name; `group:artifact` for Maven
+    pub name: String,
+    pub ecosystem: Ecosystem,
+    /// Version requirements declared by the manifests, `None` for dependencies only seen in imports
+    pub version: Option<String>,
+    /// `false` for packages of the project itself (workspace members, path dependencies)
+    pub is_external: bool,
+    /// License of the installed package, when its metadata is available locally
+    pub license: Option<String>,
+    /// Number of source files importing it
+    pub files: usize,
+}
+
+/// Dependencies of the project across ecosystems
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct DependencyInventory {
+    /// By ecosystem, external dependencies first, then by name
+    pub dependencies: Vec<DependencyEntry>,
+}
+
+impl DependencyInventory {
+    pub fn is_empty(&self) -> bool {
+        self.dependencies.is_empty()
+    }
+
+    pub fn external_count(&self) -> usize {
+        self.dependencies.iter().filter(|dependency| dependency.is_external).count()
+    }
+
+    /// Dependencies grouped by ecosystem
+    pub fn by_ecosystem(&self) -> Vec<(Ecosystem, Vec<&DependencyEntry>)> {
+        let mut groups: Vec<(Ecosystem, Vec<&DependencyEntry>)> = Vec::new();
+        for dependency in &self.dependencies {
+            match groups.iter_mut().find(|(ecosystem, _)| *ecosystem == dependency.ecosystem) {
+                Some((_, entries)) => entries.push(dependency),
+                None => groups.push((dependency.ecosystem, vec![dependency])),
+            }
+        }
+        groups
+    }
+}
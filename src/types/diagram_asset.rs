@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Output directory the existing diagrams are copied to, relative to the documentation root
+pub const ASSETS_DIR: &str = "assets";
+
+/// File format of an existing diagram
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagramFormat {
+    Image,
+    Svg,
+    Drawio,
+    PlantUml,
+    Mermaid,
+}
+
+impl DiagramFormat {
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        let format = match extension.to_lowercase().as_str() {
+            "png" | "jpg" | "jpeg" | "gif" | "webp" => DiagramFormat::Image,
+            "svg" => DiagramFormat::Svg,
+            "drawio" | "dio" => DiagramFormat::Drawio,
+            "puml" | "plantuml" | "pu" | "iuml" => DiagramFormat::PlantUml,
+            "mmd" | "mermaid" => DiagramFormat::Mermaid,
+            _ => return None,
+        };
+        Some(format)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DiagramFormat::Image => "image",
+            DiagramFormat::Svg => "SVG",
+            DiagramFormat::Drawio => "draw.io",
+            DiagramFormat::PlantUml => "PlantUML",
+            DiagramFormat::Mermaid => "Mermaid",
+        }
+    }
+
+    /// Whether markdown viewers render the file as an image
+    pub fn is_embeddable(&self) -> bool {
+        matches!(self, DiagramFormat::Image | DiagramFormat::Svg)
+    }
+}
+
+/// Diagram or image kept in the documentation folders of the repository
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DiagramAsset {
+    /// Relative to the project root
+    #[serde(serialize_with = "crate::utils::paths::serialize_slash")]
+    pub path: PathBuf,
+    pub format: DiagramFormat,
+    /// Alt text of the markdown image referencing it, or the humanized file name
+    pub title: String,
+    pub size: u64,
+    /// Beginning of the source of text diagrams (PlantUML, Mermaid)
+    pub source: Option<String>,
+}
+
+impl DiagramAsset {
+    /// Path of the copy in the documentation output, relative to its root
+    pub fn output_path(&self) -> String {
+        format!("{}/{}", ASSETS_DIR, crate::utils::paths::to_slash(&self.path))
+    }
+}
@@ -0,0 +1,13 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// File where the program starts, found by name or by a main-function marker
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct EntryPoint {
+    /// Relative to the project root
+    #[serde(serialize_with = "crate::utils::paths::serialize_slash")]
+    pub path: PathBuf,
+    /// What marks the file as an entry point, e.g. `fn main` or `@SpringBootApplication`
+    pub evidence: String,
+}
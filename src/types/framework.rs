@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Application framework recognized from manifests and imports
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Framework {
+    SpringBoot,
+    Django,
+    Rails,
+    NextJs,
+    Actix,
+    NestJs,
+    AspNetCore,
+}
+
+impl Framework {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Framework::SpringBoot => "Spring Boot",
+            Framework::Django => "Django",
+            Framework::Rails => "Ruby on Rails",
+            Framework::NextJs => "Next.js",
+            Framework::Actix => "Actix Web",
+            Framework::NestJs => "NestJS",
+            Framework::AspNetCore => "ASP.NET Core",
+        }
+    }
+
+    /// Where the framework declares entry points, routes and configuration, to steer the agents
+    pub fn prompt_hint(&self) -> &'static str {
+        match self {
+            Framework::SpringBoot => {
+                "HTTP routes are declared with @RequestMapping/@GetMapping/@PostMapping on @RestController or @Controller classes; beans come from @Service/@Repository/@Component and @Configuration classes; settings live in application.yml/application.properties"
+            }
+            Framework::Django => {
+                "Routes are declared in urls.py (path/re_path) mapping to views; models live in models.py, admin registrations in admin.py, settings in settings.py; management commands are CLI entry points"
+            }
+            Framework::Rails => {
+                "Routes are declared in config/routes.rb (resources, get/post); controllers inherit ApplicationController, models inherit ApplicationRecord; background jobs live in app/jobs"
+            }
+            Framework::NextJs => {
+                "Routes follow the file system: app/**/page.tsx and route.ts (App Router) or pages/** and pages/api/** (Pages Router); middleware.ts intercepts requests; server actions are marked 'use server'"
+            }
+            Framework::Actix => {
+                "HTTP routes are declared with #[get]/#[post]/#[route] attribute macros or web::resource/web::scope in App::new(); shared state is registered with app_data; HttpServer::new starts the server"
+            }
+            Framework::NestJs => {
+                "HTTP routes are declared with @Controller plus @Get/@Post/@Put/@Delete decorators; providers are @Injectable services wired by @Module; microservice handlers use @MessagePattern/@EventPattern"
+            }
+            Framework::AspNetCore => {
+                "HTTP routes are declared with [Route]/[HttpGet]/[HttpPost] attributes on ControllerBase classes or minimal APIs (app.MapGet/MapPost) in Program.cs; services are registered on builder.Services; settings live in appsettings.json"
+            }
+        }
+    }
+}
+
+/// Framework detected in the project with the files revealing it
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DetectedFramework {
+    pub framework: Framework,
+    /// Manifests or sources mentioning the framework, relative to the project root
+    pub evidence: Vec<PathBuf>,
+}
+
+/// Frameworks of the project, used as tags biasing the agent prompts
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct FrameworkTags {
+    pub frameworks: Vec<DetectedFramework>,
+}
+
+impl FrameworkTags {
+    pub fn is_empty(&self) -> bool {
+        self.frameworks.is_empty()
+    }
+
+    pub fn names(&self) -> Vec<&'static str> {
+        self.frameworks.iter().map(|f| f.framework.name()).collect()
+    }
+}
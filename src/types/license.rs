@@ -0,0 +1,50 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// License file at the project root
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LicenseFile {
+    /// Relative to the project root
+    #[serde(serialize_with = "crate::utils::paths::serialize_slash")]
+    pub path: PathBuf,
+    /// SPDX identifier recognized from the license text, `None` when the text is unknown
+    pub spdx_id: Option<String>,
+}
+
+/// `SPDX-License-Identifier` expression and the number of source files declaring it
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SpdxHeader {
+    pub expression: String,
+    pub files: usize,
+}
+
+/// License of the project, as declared by its license files and SPDX headers
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct LicenseInfo {
+    pub files: Vec<LicenseFile>,
+    /// Most declared first
+    pub spdx_headers: Vec<SpdxHeader>,
+}
+
+impl LicenseInfo {
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty() && self.spdx_headers.is_empty()
+    }
+
+    /// Distinct license identifiers, license files first
+    pub fn identifiers(&self) -> Vec<String> {
+        let mut identifiers: Vec<String> = Vec::new();
+        let declared = self
+            .files
+            .iter()
+            .filter_map(|file| file.spdx_id.clone())
+            .chain(self.spdx_headers.iter().map(|header| header.expression.clone()));
+        for identifier in declared {
+            if !identifiers.contains(&identifier) {
+                identifiers.push(identifier);
+            }
+        }
+        identifiers
+    }
+}
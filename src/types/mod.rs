@@ -1,7 +1,15 @@
+pub mod architecture_meta;
 pub mod code;
 pub mod code_releationship;
+pub mod dependency;
+pub mod diagram_asset;
+pub mod entry_point;
+pub mod framework;
+pub mod license;
 pub mod original_document;
+pub mod ownership;
 pub mod project_structure;
+pub mod workspace;
 
 use std::path::PathBuf;
 
@@ -10,6 +18,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct FileInfo {
+    #[serde(serialize_with = "crate::utils::paths::serialize_slash")]
     pub path: PathBuf,
     pub name: String,
     pub size: u64,
@@ -23,6 +32,7 @@ pub struct FileInfo {
 /// Directory information
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct DirectoryInfo {
+    #[serde(serialize_with = "crate::utils::paths::serialize_slash")]
     pub path: PathBuf,
     pub name: String,
     pub file_count: usize,
@@ -56,6 +66,7 @@ impl Default for DirectoryPurpose {
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct DirectoryDossier {
+    #[serde(serialize_with = "crate::utils::paths::serialize_slash")]
     pub path: PathBuf,
     pub name: String,
     pub purpose: DirectoryPurpose,
@@ -73,6 +84,7 @@ pub struct DirectoryDossier {
 #[serde(default)]
 pub struct FileInsight {
     pub name: String,
+    #[serde(serialize_with = "crate::utils::paths::serialize_slash")]
     pub file_path: PathBuf,
     pub summary: String,
     /// Code purpose classification (based on file extension and content)
@@ -96,6 +108,9 @@ pub struct FileInsight {
     /// Importance score
     #[serde(default)]
     pub importance_score: f64,
+    /// Complexity measured by the language processor
+    #[serde(default)]
+    pub complexity: crate::types::code::CodeComplexity,
 }
 
 /// Merged insights stored under CODE_INSIGHTS key
@@ -0,0 +1,80 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Owners listed per domain module or directory
+const MAX_OWNERS: usize = 3;
+
+/// Where the owners of the files come from
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OwnershipSource {
+    /// A CODEOWNERS file, relative to the project root
+    CodeOwners(PathBuf),
+    /// The author of most commits touching each file
+    GitHistory,
+}
+
+impl OwnershipSource {
+    pub fn describe(&self) -> String {
+        match self {
+            OwnershipSource::CodeOwners(path) => format!("declared in `{}`", path.display()),
+            OwnershipSource::GitHistory => "main commit authors from the git history".to_string(),
+        }
+    }
+}
+
+/// Owners of the project files
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Ownership {
+    pub source: Option<OwnershipSource>,
+    /// Owners by file path relative to the project root
+    pub files: BTreeMap<PathBuf, Vec<String>>,
+}
+
+impl Ownership {
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Owners of most files under the paths (files or directories), most files first
+    pub fn owners_of(&self, paths: &[String]) -> Vec<String> {
+        let prefixes: Vec<String> = paths
+            .iter()
+            .map(|path| path.replace('\\', "/").trim_start_matches("./").trim_end_matches('/').to_string())
+            .filter(|path| !path.is_empty())
+            .collect();
+        rank_owners(self.files.iter().filter(|(file, _)| {
+            let file = file.to_string_lossy().replace('\\', "/");
+            prefixes
+                .iter()
+                .any(|prefix| file == *prefix || file.starts_with(&format!("{}/", prefix)))
+        }))
+    }
+
+    /// Owners of the directories `depth` levels below the project root, for a compact overview
+    pub fn by_directory(&self, depth: usize) -> Vec<(PathBuf, Vec<String>)> {
+        let mut directories: BTreeMap<PathBuf, Vec<(&PathBuf, &Vec<String>)>> = BTreeMap::new();
+        for (file, owners) in &self.files {
+            let directory: PathBuf = file.parent().unwrap_or(Path::new("")).iter().take(depth).collect();
+            directories.entry(directory).or_default().push((file, owners));
+        }
+        directories
+            .into_iter()
+            .map(|(directory, files)| (directory, rank_owners(files.into_iter())))
+            .collect()
+    }
+}
+
+fn rank_owners<'a>(files: impl Iterator<Item = (&'a PathBuf, &'a Vec<String>)>) -> Vec<String> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for (_, owners) in files {
+        for owner in owners {
+            *counts.entry(owner).or_default() += 1;
+        }
+    }
+    let mut ranked: Vec<(&str, usize)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    ranked.into_iter().take(MAX_OWNERS).map(|(owner, _)| owner.to_string()).collect()
+}
@@ -15,4 +15,16 @@ pub struct ProjectStructure {
     pub total_directories: usize,
     pub file_types: HashMap<String, usize>,
     pub size_distribution: HashMap<String, usize>,
+    /// Files and source lines per language, most lines first
+    #[serde(default)]
+    pub language_stats: Vec<LanguageStats>,
+}
+
+/// Size of the code written in one language
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LanguageStats {
+    pub language: String,
+    pub files: usize,
+    /// Non-blank lines
+    pub lines: usize,
 }
@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Build tool declaring a multi-module workspace
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkspaceKind {
+    Cargo,
+    Npm,
+    Yarn,
+    Pnpm,
+    Maven,
+    Go,
+}
+
+impl WorkspaceKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            WorkspaceKind::Cargo => "Cargo workspace",
+            WorkspaceKind::Npm => "npm workspaces",
+            WorkspaceKind::Yarn => "Yarn workspaces",
+            WorkspaceKind::Pnpm => "pnpm workspace",
+            WorkspaceKind::Maven => "Maven multi-module project",
+            WorkspaceKind::Go => "Go workspace",
+        }
+    }
+}
+
+/// Module of a workspace
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WorkspaceMember {
+    /// Package, crate, artifact or module name declared in the member manifest
+    pub name: String,
+    /// Member directory, relative to the project root
+    #[serde(serialize_with = "crate::utils::paths::serialize_slash")]
+    pub path: PathBuf,
+}
+
+/// Workspace declared by a root manifest
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Workspace {
+    pub kind: WorkspaceKind,
+    /// Manifest declaring the workspace, relative to the project root
+    pub manifest: PathBuf,
+    pub members: Vec<WorkspaceMember>,
+}
+
+/// Module layout of a monorepo, as declared by its build manifests
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct WorkspaceModel {
+    pub workspaces: Vec<Workspace>,
+}
+
+impl WorkspaceModel {
+    pub fn is_empty(&self) -> bool {
+        self.workspaces.is_empty()
+    }
+
+    pub fn member_count(&self) -> usize {
+        self.workspaces.iter().map(|w| w.members.len()).sum()
+    }
+}
@@ -0,0 +1,221 @@
+//! Archive inputs: a `.zip`, `.tar`, `.tar.gz` or `.tgz` project path is unpacked to a
+//! temporary workspace that is removed when the run ends
+
+use anyhow::{Context, Result, bail};
+use flate2::read::GzDecoder;
+use std::fs::{self, File};
+use std::io::{BufReader, Read};
+use std::path::{Component, Path, PathBuf};
+use tracing::{info, warn};
+
+/// Archives unpacking to more than this are refused (bytes)
+const MAX_EXTRACTED_BYTES: u64 = 4 * 1024 * 1024 * 1024;
+
+/// Archive formats accepted as a project path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArchiveKind {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+fn archive_kind(path: &Path) -> Option<ArchiveKind> {
+    let name = path.file_name()?.to_string_lossy().to_lowercase();
+    if name.ends_with(".zip") {
+        Some(ArchiveKind::Zip)
+    } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+        Some(ArchiveKind::TarGz)
+    } else if name.ends_with(".tar") {
+        Some(ArchiveKind::Tar)
+    } else {
+        None
+    }
+}
+
+/// Whether the project path is an archive file to unpack
+pub fn is_archive(path: &Path) -> bool {
+    path.is_file() && archive_kind(path).is_some()
+}
+
+/// Archive name without its extension, e.g. `shop-1.2.0` for `shop-1.2.0.tar.gz`
+pub fn archive_stem(path: &Path) -> String {
+    let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+    let lowercase = name.to_lowercase();
+    [".tar.gz", ".tgz", ".tar", ".zip"]
+        .iter()
+        .find(|extension| lowercase.ends_with(*extension))
+        .map(|extension| name[..name.len() - extension.len()].to_string())
+        .unwrap_or(name)
+}
+
+/// Temporary workspace holding an unpacked archive, deleted on drop
+pub struct ExtractedArchive {
+    workspace: PathBuf,
+    project_root: PathBuf,
+}
+
+impl ExtractedArchive {
+    /// Directory to document: the single top-level directory of the archive, if it has one
+    pub fn project_root(&self) -> &Path {
+        &self.project_root
+    }
+}
+
+impl Drop for ExtractedArchive {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_dir_all(&self.workspace) {
+            warn!("⚠️  Failed to remove archive workspace {}: {}", self.workspace.display(), e);
+        }
+    }
+}
+
+/// Unpack an archive to a new temporary workspace
+pub fn extract(archive: &Path) -> Result<ExtractedArchive> {
+    let Some(kind) = archive_kind(archive) else {
+        bail!("Unsupported archive format: {}", archive.display());
+    };
+    let workspace = std::env::temp_dir().join(format!("litho-archive-{}", uuid::Uuid::new_v4()));
+    fs::create_dir_all(&workspace)?;
+    // The workspace is removed on drop, also when unpacking fails
+    let mut extracted = ExtractedArchive {
+        project_root: workspace.clone(),
+        workspace,
+    };
+    info!("📦 Unpacking {} to {}", archive.display(), extracted.workspace.display());
+
+    let file = BufReader::new(File::open(archive).with_context(|| format!("Failed to open {}", archive.display()))?);
+    let files = match kind {
+        ArchiveKind::Zip => extract_zip(file, &extracted.workspace),
+        ArchiveKind::Tar => extract_tar(file, &extracted.workspace),
+        ArchiveKind::TarGz => extract_tar(GzDecoder::new(file), &extracted.workspace),
+    }
+    .with_context(|| format!("Failed to unpack {}", archive.display()))?;
+
+    let entries: Vec<PathBuf> = fs::read_dir(&extracted.workspace)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .collect();
+    if let [single] = entries.as_slice()
+        && single.is_dir()
+    {
+        extracted.project_root = single.clone();
+    }
+    info!("📦 Unpacked {} files", files);
+    Ok(extracted)
+}
+
+/// Relative path of an archive entry, `None` for absolute paths or paths leaving the workspace
+fn safe_path(name: &str) -> Option<PathBuf> {
+    let mut path = PathBuf::new();
+    for component in Path::new(name).components() {
+        match component {
+            Component::Normal(part) => path.push(part),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    (!path.as_os_str().is_empty()).then_some(path)
+}
+
+/// Write one file of the archive, counting the unpacked bytes against the limit
+fn write_entry(workspace: &Path, path: &Path, reader: &mut impl Read, total: &mut u64) -> Result<()> {
+    let target = workspace.join(path);
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut limited = reader.take(MAX_EXTRACTED_BYTES - *total + 1);
+    *total += std::io::copy(&mut limited, &mut File::create(&target)?)?;
+    if *total > MAX_EXTRACTED_BYTES {
+        bail!("the archive unpacks to more than {} bytes", MAX_EXTRACTED_BYTES);
+    }
+    Ok(())
+}
+
+fn extract_zip(reader: impl Read + std::io::Seek, workspace: &Path) -> Result<usize> {
+    let mut archive = zip::ZipArchive::new(reader)?;
+    let (mut files, mut total) = (0, 0);
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index)?;
+        let Some(path) = entry.enclosed_name().and_then(|name| safe_path(&name.to_string_lossy())) else {
+            continue;
+        };
+        if entry.is_dir() {
+            fs::create_dir_all(workspace.join(path))?;
+        } else if entry.is_file() {
+            write_entry(workspace, &path, &mut entry, &mut total)?;
+            files += 1;
+        }
+    }
+    Ok(files)
+}
+
+/// Unpack the regular files and directories of a tar stream; links and special files are
+/// skipped
+fn extract_tar(reader: impl Read, workspace: &Path) -> Result<usize> {
+    let mut archive = tar::Archive::new(reader);
+    let (mut files, mut total) = (0, 0);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let Some(path) = safe_path(&String::from_utf8_lossy(&entry.path_bytes())) else {
+            continue;
+        };
+        let kind = entry.header().entry_type();
+        if kind.is_dir() {
+            fs::create_dir_all(workspace.join(path))?;
+        } else if kind.is_file() || kind.is_contiguous() {
+            write_entry(workspace, &path, &mut entry, &mut total)?;
+            files += 1;
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::{Compression, write::GzEncoder};
+    use std::io::Write;
+
+    /// ustar entry with its data blocks; the name is written as is, `tar::Builder` refuses `..`
+    fn tar_entry(name: &str, kind: tar::EntryType, data: &[u8]) -> Vec<u8> {
+        let mut header = tar::Header::new_ustar();
+        header.as_old_mut().name[..name.len()].copy_from_slice(name.as_bytes());
+        header.set_mode(0o644);
+        header.set_size(data.len() as u64);
+        header.set_entry_type(kind);
+        header.set_cksum();
+        let mut entry = header.as_bytes().to_vec();
+        entry.extend_from_slice(data);
+        entry.resize(entry.len().div_ceil(512) * 512, 0);
+        entry
+    }
+
+    #[test]
+    fn test_extract_tar_gz() {
+        let mut tar = Vec::new();
+        tar.extend(tar_entry("shop-1.2.0/", tar::EntryType::Directory, b""));
+        tar.extend(tar_entry("shop-1.2.0/Cargo.toml", tar::EntryType::Regular, b"[package]\nname = \"shop\"\n"));
+        tar.extend(tar_entry("shop-1.2.0/src/main.rs", tar::EntryType::Regular, b"fn main() {}\n"));
+        tar.extend(tar_entry("../escape.txt", tar::EntryType::Regular, b"outside"));
+        tar.extend([0u8; 1024]);
+
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().to_path_buf();
+        fs::create_dir_all(&dir).unwrap();
+        let archive = dir.join("shop-1.2.0.tar.gz");
+        let mut encoder = GzEncoder::new(File::create(&archive).unwrap(), Compression::default());
+        encoder.write_all(&tar).unwrap();
+        encoder.finish().unwrap();
+
+        assert!(is_archive(&archive));
+        assert_eq!(archive_stem(&archive), "shop-1.2.0");
+        let extracted = extract(&archive).unwrap();
+        let root = extracted.project_root().to_path_buf();
+        assert!(root.ends_with("shop-1.2.0"));
+        assert_eq!(fs::read_to_string(root.join("src/main.rs")).unwrap(), "fn main() {}\n");
+        assert!(!root.parent().unwrap().join("escape.txt").exists());
+        assert!(!root.parent().unwrap().parent().unwrap().join("escape.txt").exists());
+
+        drop(extracted);
+        assert!(!root.exists());
+    }
+}
@@ -0,0 +1,171 @@
+//! Charset detection for source files that are not UTF-8
+//!
+//! Files saved as GBK, Shift-JIS, Windows-1252 or UTF-16 are transcoded before their content
+//! reaches a prompt instead of turning into mojibake. The transcoded files (source and detected
+//! encoding) are collected for `encoding-report.json`.
+
+use anyhow::Result;
+use encoding_rs::{Encoding, GBK, SHIFT_JIS, UTF_8, WINDOWS_1252};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+
+/// Legacy encodings tried, in increasing order of preference on equal scores
+const CANDIDATES: [&Encoding; 3] = [GBK, SHIFT_JIS, WINDOWS_1252];
+
+/// Files of the current run that were transcoded, by source
+static TRANSCODED: LazyLock<Mutex<BTreeMap<String, &'static str>>> = LazyLock::new(Default::default);
+
+/// Encoding of the bytes: a byte order mark, else UTF-8 when it is mostly valid, else the
+/// legacy encoding whose decoded text looks most like natural text
+pub fn detect(bytes: &[u8]) -> &'static Encoding {
+    if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+        return encoding;
+    }
+    // A stray invalid byte in a UTF-8 file, or a character cut at the end of a sample, keeps UTF-8
+    let (mut valid, mut invalid) = (0, 0);
+    let mut chunks = bytes.utf8_chunks().peekable();
+    while let Some(chunk) = chunks.next() {
+        valid += chunk.valid().chars().filter(|c| !c.is_ascii()).count();
+        let cut = chunks.peek().is_none() && chunk.invalid().first().is_some_and(|byte| *byte >= 0xc0);
+        invalid += usize::from(!chunk.invalid().is_empty() && !cut);
+    }
+    if invalid == 0 || valid > invalid {
+        return UTF_8;
+    }
+
+    let mut best = (WINDOWS_1252, i64::MIN);
+    for encoding in CANDIDATES {
+        let decoded = encoding
+            .decode_without_bom_handling_and_without_replacement(bytes)
+            // The sample may end in the middle of a double-byte character
+            .or_else(|| encoding.decode_without_bom_handling_and_without_replacement(&bytes[..bytes.len() - 1]));
+        if let Some(text) = decoded {
+            let score = score(encoding, &text);
+            if score >= best.1 {
+                best = (encoding, score);
+            }
+        }
+    }
+    best.0
+}
+
+/// Plausibility of the decoded text: kana and common ideographs for the CJK encodings,
+/// accented letters within words for Windows-1252; control and private-use characters count
+/// against it
+fn score(encoding: &'static Encoding, text: &str) -> i64 {
+    let chars: Vec<char> = text.chars().collect();
+    let near_letter = |index: Option<usize>| index.and_then(|index| chars.get(index)).is_some_and(char::is_ascii_alphabetic);
+    chars
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| !c.is_ascii())
+        .map(|(index, &c)| match c {
+            '\u{3040}'..='\u{30ff}' => 2,
+            '\u{4e00}'..='\u{9fff}' if encoding == GBK && is_common_hanzi(c) => 2,
+            '\u{4e00}'..='\u{9fff}' | '\u{3000}'..='\u{303f}' | '\u{ff01}'..='\u{ff5e}' => 1,
+            '\u{ff61}'..='\u{ff9f}' => -1,
+            '\u{80}'..='\u{9f}' | '\u{e000}'..='\u{f8ff}' => -3,
+            '\u{c0}'..='\u{ff}' if c != '×' && c != '÷' => {
+                if near_letter(index.checked_sub(1)) || near_letter(Some(index + 1)) { 1 } else { -1 }
+            }
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Level 1 hanzi of GB 2312: the most frequent 3755 characters
+fn is_common_hanzi(c: char) -> bool {
+    let mut buffer = [0; 4];
+    let (bytes, _, _) = GBK.encode(c.encode_utf8(&mut buffer));
+    matches!(bytes.first(), Some(0xb0..=0xd7))
+}
+
+/// Decode the bytes in their detected encoding, without a byte order mark
+pub fn decode(bytes: &[u8]) -> (String, &'static Encoding) {
+    let encoding = detect(bytes);
+    let (text, encoding, _) = encoding.decode(bytes);
+    (text.into_owned(), encoding)
+}
+
+/// Decode the content of a source file; `source` names the file in the report when it was
+/// transcoded
+pub fn decode_source(source: &str, bytes: &[u8]) -> String {
+    let (text, encoding) = decode(bytes);
+    record(source, encoding);
+    text
+}
+
+/// Remember that the source was transcoded from `encoding`
+pub fn record(source: &str, encoding: &'static Encoding) {
+    if encoding != UTF_8 {
+        TRANSCODED.lock().unwrap().insert(source.to_string(), encoding.name());
+    }
+}
+
+/// A source file read in another encoding than UTF-8
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Transcoded {
+    pub source: String,
+    pub encoding: String,
+}
+
+/// Take the transcoded files collected since the last call
+pub fn take_transcoded() -> Vec<Transcoded> {
+    std::mem::take(&mut *TRANSCODED.lock().unwrap())
+        .into_iter()
+        .map(|(source, encoding)| Transcoded { source, encoding: encoding.to_string() })
+        .collect()
+}
+
+/// Write the transcoded files of the run as `encoding-report.json`; nothing is written when
+/// every file was UTF-8
+pub fn save_report(output_dir: &Path) -> Result<Option<PathBuf>> {
+    let files = take_transcoded();
+    if files.is_empty() {
+        return Ok(None);
+    }
+    std::fs::create_dir_all(output_dir)?;
+    let path = output_dir.join("encoding-report.json");
+    let report = serde_json::json!({ "total": files.len(), "files": files });
+    std::fs::write(&path, serde_json::to_string_pretty(&report)?)?;
+    Ok(Some(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use encoding_rs::UTF_16LE;
+
+    #[test]
+    fn test_detect_and_decode() {
+        let cases = [
+            (GBK, "// 获取用户信息，并返回订单列表\nfn load() {}\n"),
+            (SHIFT_JIS, "// ユーザー情報を取得します。\nこんにちは、世界\n"),
+            (WINDOWS_1252, "// Crée la connexion à la base de données\nlet naïve = true;\n"),
+            (UTF_8, "// 获取用户信息 — café\n"),
+        ];
+        for (encoding, text) in cases {
+            let (bytes, _, _) = encoding.encode(text);
+            assert_eq!(detect(&bytes), encoding, "{}", text);
+            assert_eq!(decode(&bytes), (text.to_string(), encoding));
+        }
+
+        // A UTF-8 sample cut in the middle of a character, and a stray Latin-1 byte
+        let cut = "注释 comment".as_bytes();
+        assert_eq!(detect(&cut[..4]), UTF_8);
+        assert_eq!(detect(b"// \xe5\x90\x8d\xe5\x89\x8d caf\xe9\n"), UTF_8);
+
+        let mut utf16 = vec![0xff, 0xfe];
+        utf16.extend("fn main() {}".encode_utf16().flat_map(u16::to_le_bytes));
+        assert_eq!(decode(&utf16), ("fn main() {}".to_string(), UTF_16LE));
+
+        let (gbk, _, _) = GBK.encode("// 用户");
+        assert_eq!(decode_source("legacy/user.c", &gbk), "// 用户");
+        assert!(take_transcoded().contains(&Transcoded {
+            source: "legacy/user.c".to_string(),
+            encoding: "GBK".to_string(),
+        }));
+    }
+}
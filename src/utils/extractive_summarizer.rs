@@ -0,0 +1,237 @@
+use std::collections::HashSet;
+
+use crate::utils::token_estimator::TokenEstimator;
+
+/// Share of the content the importance-ranked truncation may drop; beyond that the
+/// remaining content is left to LLM compression so no large part is lost silently
+const MAX_DROPPED_SHARE: f64 = 0.3;
+
+/// Keywords marking sections that carry structural information
+const IMPORTANT_KEYWORDS: [&str; 12] = [
+    "interface", "api", "struct", "class", "trait", "type", "config", "error", "entry", "main",
+    "module", "depend",
+];
+
+/// Prefixes of code lines kept when code blocks are rendered as signatures only
+const SIGNATURE_PREFIXES: [&str; 24] = [
+    "pub ", "fn ", "async fn", "struct ", "enum ", "trait ", "impl ", "type ", "mod ", "use ",
+    "class ", "interface ", "def ", "async def", "function ", "export ", "import ", "from ",
+    "func ", "package ", "public ", "protected ", "private ", "const ",
+];
+
+/// Lossless-leaning reduction step
+type Stage = fn(&str) -> String;
+
+/// Result of the local extractive pre-summarization
+#[derive(Debug, Clone)]
+pub struct ExtractiveSummary {
+    pub content: String,
+    pub tokens: usize,
+    /// Steps that changed the content, for logging
+    pub steps: Vec<String>,
+}
+
+/// Shrink content without an LLM call: remove duplicate lines and blocks, render code
+/// blocks as signatures only, then drop the least important sections. Each step only
+/// runs while the content is still over `budget` tokens.
+pub fn summarize(content: &str, budget: usize, estimator: &TokenEstimator) -> ExtractiveSummary {
+    let count = |text: &str| estimator.estimate_tokens(text).estimated_tokens;
+    let original_tokens = count(content);
+    let mut summary = ExtractiveSummary {
+        content: content.to_string(),
+        tokens: original_tokens,
+        steps: Vec::new(),
+    };
+
+    let stages: [(&str, Stage); 2] = [
+        ("duplicate removal", remove_duplicates),
+        ("signature-only code", render_code_signatures),
+    ];
+    for (step, stage) in stages {
+        if summary.tokens <= budget {
+            return summary;
+        }
+        let reduced = stage(&summary.content);
+        let tokens = count(&reduced);
+        if tokens < summary.tokens {
+            summary.steps.push(format!("{} ({} -> {} tokens)", step, summary.tokens, tokens));
+            summary.content = reduced;
+            summary.tokens = tokens;
+        }
+    }
+
+    if summary.tokens > budget {
+        let floor = ((original_tokens as f64) * (1.0 - MAX_DROPPED_SHARE)) as usize;
+        let (reduced, dropped) = drop_unimportant_sections(&summary.content, budget.max(floor), &count);
+        if dropped > 0 {
+            let tokens = count(&reduced);
+            summary.steps.push(format!(
+                "importance truncation, {} sections dropped ({} -> {} tokens)",
+                dropped, summary.tokens, tokens
+            ));
+            summary.content = reduced;
+            summary.tokens = tokens;
+        }
+    }
+
+    summary
+}
+
+/// Remove repeated lines and repeated blank-line separated blocks, keeping the first occurrence.
+/// Short lines (closing braces, list markers) are kept since they repeat legitimately.
+fn remove_duplicates(content: &str) -> String {
+    let mut seen_blocks = HashSet::new();
+    let mut seen_lines = HashSet::new();
+    let mut blocks = Vec::new();
+
+    for block in content.split("\n\n") {
+        let key = block.trim();
+        if !key.is_empty() && !seen_blocks.insert(key.to_string()) {
+            continue;
+        }
+        let lines: Vec<&str> = block
+            .lines()
+            .filter(|line| {
+                let line = line.trim();
+                line.len() < 40 || seen_lines.insert(line.to_string())
+            })
+            .collect();
+        blocks.push(lines.join("\n"));
+    }
+
+    blocks.join("\n\n")
+}
+
+/// Keep only declaration lines inside fenced code blocks
+fn render_code_signatures(content: &str) -> String {
+    let mut result = Vec::new();
+    let mut in_code = false;
+    let mut elided = false;
+
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            elided = false;
+            result.push(line.to_string());
+            continue;
+        }
+        if !in_code {
+            result.push(line.to_string());
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        let is_signature = SIGNATURE_PREFIXES.iter().any(|prefix| trimmed.starts_with(prefix))
+            || trimmed.starts_with('@')
+            || trimmed.starts_with("#[");
+        let closes_block = matches!(trimmed, "}" | "};" | "})" | ")" | "]" | "end");
+        if is_signature || closes_block {
+            result.push(line.to_string());
+            elided = false;
+        } else if !elided {
+            let indent = &line[..line.len() - trimmed.len()];
+            result.push(format!("{}…", indent));
+            elided = true;
+        }
+    }
+
+    result.join("\n")
+}
+
+/// Drop whole sections, least important first, until the content fits `budget` tokens.
+/// Sections start at Markdown headings; the kept sections stay in their original order.
+fn drop_unimportant_sections(
+    content: &str,
+    budget: usize,
+    count: &dyn Fn(&str) -> usize,
+) -> (String, usize) {
+    let mut sections: Vec<String> = Vec::new();
+    let mut in_code = false;
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+        }
+        if (!in_code && line.starts_with('#')) || sections.is_empty() {
+            sections.push(String::new());
+        }
+        if let Some(section) = sections.last_mut() {
+            section.push_str(line);
+            section.push('\n');
+        }
+    }
+    if sections.len() < 2 {
+        return (content.to_string(), 0);
+    }
+
+    let tokens: Vec<usize> = sections.iter().map(|s| count(s)).collect();
+    let mut ranked: Vec<usize> = (0..sections.len()).collect();
+    ranked.sort_by(|a, b| importance(&sections[*a], *a).total_cmp(&importance(&sections[*b], *b)));
+
+    let mut total: usize = tokens.iter().sum();
+    let mut dropped = vec![false; sections.len()];
+    // The first section usually holds the title and introduction, it is always kept
+    for index in ranked.into_iter().filter(|i| *i > 0) {
+        if total <= budget {
+            break;
+        }
+        total -= tokens[index];
+        dropped[index] = true;
+    }
+
+    let dropped_count = dropped.iter().filter(|d| **d).count();
+    let mut result = String::new();
+    for (index, section) in sections.iter().enumerate() {
+        if !dropped[index] {
+            result.push_str(section);
+        }
+    }
+    if dropped_count > 0 {
+        result.push_str(&format!("\n[{} less relevant sections omitted]\n", dropped_count));
+    }
+    (result, dropped_count)
+}
+
+/// Importance of a section: higher-level headings, structural keywords and earlier positions rank higher
+fn importance(section: &str, position: usize) -> f64 {
+    let heading = section.lines().next().unwrap_or_default();
+    let level = heading.chars().take_while(|c| *c == '#').count();
+    let heading_score = match level {
+        0 => 1.0,
+        level => 4.0 - level.min(4) as f64,
+    };
+    let lower = section.to_lowercase();
+    let keyword_score = IMPORTANT_KEYWORDS
+        .iter()
+        .filter(|keyword| lower.contains(*keyword))
+        .count() as f64
+        * 0.5;
+    heading_score + keyword_score - position as f64 * 0.01
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_stages_run_only_while_over_budget() {
+        let estimator = TokenEstimator::new();
+        let filler = "The order service keeps every order in a local cache for quick lookups. ".repeat(5);
+        let content = format!(
+            "# Orders\n{filler}\n\n{filler}\n\n## API\nEntry points:\n```rust\npub fn create_order(req: Request) -> Order {{\n    let order = Order::new(req);\n    order.validate();\n    order\n}}\n```\n\n### Notes\n{filler}"
+        );
+
+        // Already within budget: nothing changes
+        let untouched = summarize(&content, 10_000, &estimator);
+        assert_eq!(untouched.content, content);
+        assert!(untouched.steps.is_empty());
+
+        let summary = summarize(&content, 60, &estimator);
+        assert!(summary.steps[0].starts_with("duplicate removal"));
+        assert!(summary.content.contains("pub fn create_order(req: Request) -> Order {\n    …\n}"));
+        assert!(!summary.content.contains("order.validate()"));
+        // Truncation never drops more than its share, the rest is left to LLM compression
+        assert!(summary.content.contains("## API"));
+        assert!(summary.tokens > 60);
+        assert!(summary.tokens < untouched.tokens);
+    }
+}
@@ -1,5 +1,42 @@
+use encoding_rs::Encoding;
+use glob::Pattern;
+use std::collections::{HashSet, VecDeque};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
 use std::path::Path;
 
+use crate::utils::encoding;
+
+/// Bytes read from the start of a file to sniff whether it is binary
+const SNIFF_BYTES: usize = 8192;
+/// Share of control characters above which content is considered binary
+const MAX_CONTROL_RATIO: f64 = 0.3;
+/// Signatures of binary formats that may come without a known extension
+const BINARY_SIGNATURES: [&[u8]; 14] = [
+    b"\x7fELF",
+    b"\xca\xfe\xba\xbe",
+    b"\xcf\xfa\xed\xfe",
+    b"\xfe\xed\xfa",
+    b"\0asm",
+    b"\x89PNG",
+    b"GIF8",
+    b"\xff\xd8\xff",
+    b"%PDF-",
+    b"PK\x03\x04",
+    b"\x1f\x8b",
+    b"BZh",
+    b"7z\xbc\xaf\x27\x1c",
+    b"SQLite format 3\0",
+];
+
+/// Bytes read from the start of an oversized file to detect its encoding
+const ENCODING_SAMPLE_BYTES: u64 = 64 * 1024;
+
+/// Share of the byte budget of an oversized file spent on its first lines
+const PARTIAL_HEAD_SHARE: f64 = 0.4;
+/// Share spent on its last lines; the rest goes to the important lines in between
+const PARTIAL_TAIL_SHARE: f64 = 0.2;
+
 /// Check if a file is a test file
 pub fn is_test_file(path: &Path) -> bool {
     let file_name = path
@@ -161,3 +198,307 @@ pub fn is_binary_file_path(path: &Path) -> bool {
         false
     }
 }
+
+/// Check if a file is binary, by its extension or else by sniffing its first bytes
+pub fn is_binary_file(path: &Path) -> bool {
+    if is_binary_file_path(path) {
+        return true;
+    }
+    let Ok(file) = File::open(path) else {
+        return false;
+    };
+    let mut sample = Vec::with_capacity(SNIFF_BYTES);
+    if file.take(SNIFF_BYTES as u64).read_to_end(&mut sample).is_err() {
+        return false;
+    }
+    is_binary_content(&sample)
+}
+
+/// Whether the start of a file looks binary: a known magic number, a NUL byte or mostly
+/// control characters. UTF-16 text, recognized by its byte order mark, is not binary.
+pub fn is_binary_content(sample: &[u8]) -> bool {
+    if sample.starts_with(b"\xff\xfe") || sample.starts_with(b"\xfe\xff") {
+        return false;
+    }
+    if BINARY_SIGNATURES.iter().any(|signature| sample.starts_with(signature)) || sample.contains(&0) {
+        return true;
+    }
+    let control = sample
+        .iter()
+        .filter(|byte| byte.is_ascii_control() && !matches!(byte, b'\t' | b'\n' | b'\r' | 0x0c | 0x1b))
+        .count();
+    !sample.is_empty() && control as f64 / sample.len() as f64 > MAX_CONTROL_RATIO
+}
+
+/// Read a file larger than `max_bytes` partially: its first and last lines and, in between,
+/// the lines `is_important` selects (declarations, signatures) prefixed with their line number.
+/// The file is streamed, so memory stays bounded by `max_bytes` whatever the file size.
+/// Lines are decoded in the encoding detected from the start of the file, which is returned.
+pub fn read_partial_file(
+    path: &Path,
+    max_bytes: usize,
+    is_important: impl Fn(&str) -> bool,
+) -> std::io::Result<(String, &'static Encoding)> {
+    let head_budget = (max_bytes as f64 * PARTIAL_HEAD_SHARE) as usize;
+    let tail_budget = (max_bytes as f64 * PARTIAL_TAIL_SHARE) as usize;
+    let important_budget = max_bytes.saturating_sub(head_budget + tail_budget);
+
+    let mut head = Vec::new();
+    let mut head_size = 0;
+    let mut head_done = false;
+    let mut tail: VecDeque<(usize, String)> = VecDeque::new();
+    let mut tail_size = 0;
+    let mut important = Vec::new();
+    let mut important_size = 0;
+    let mut total_lines = 0;
+
+    let mut sample = Vec::new();
+    File::open(path)?.take(ENCODING_SAMPLE_BYTES).read_to_end(&mut sample)?;
+    let encoding = encoding::detect(&sample);
+    let lines: Box<dyn Iterator<Item = std::io::Result<String>>> = if encoding.is_ascii_compatible() {
+        Box::new(BufReader::new(File::open(path)?).split(b'\n').map(move |line| {
+            line.map(|line| encoding.decode_without_bom_handling(&line).0.into_owned())
+        }))
+    } else {
+        // UTF-16 cannot be split on newline bytes
+        let (text, _) = encoding::decode(&std::fs::read(path)?);
+        Box::new(text.lines().map(|line| Ok(line.to_string())).collect::<Vec<_>>().into_iter())
+    };
+
+    for (index, line) in lines.enumerate() {
+        let line = line?.trim_end_matches('\r').to_string();
+        total_lines = index + 1;
+        if !head_done {
+            if head_size + line.len() < head_budget {
+                head_size += line.len() + 1;
+                head.push(line);
+                continue;
+            }
+            head_done = true;
+        }
+
+        // Lines leaving the tail window are kept when they are important and fit the budget
+        tail_size += line.len() + 1;
+        tail.push_back((index + 1, line));
+        while tail_size > tail_budget {
+            let Some((number, line)) = tail.pop_front() else {
+                break;
+            };
+            tail_size -= line.len() + 1;
+            let entry = format!("{}: {}", number, line.trim());
+            if important_size + entry.len() < important_budget && is_important(&line) {
+                important_size += entry.len() + 1;
+                important.push(entry);
+            }
+        }
+    }
+
+    let first_tail_line = tail.front().map(|(number, _)| *number).unwrap_or(total_lines + 1);
+    let mut content = head.join("\n");
+    if first_tail_line > head.len() + 1 {
+        content.push_str(&format!(
+            "\n… [lines {}-{} of {} omitted, the file exceeds max_file_size; important lines follow] …\n",
+            head.len() + 1,
+            first_tail_line - 1,
+            total_lines
+        ));
+        content.push_str(&important.join("\n"));
+        content.push_str("\n… [end of important lines] …");
+    }
+    for (_, line) in tail {
+        content.push('\n');
+        content.push_str(&line);
+    }
+    Ok((content, encoding))
+}
+
+/// Directory allowlist built from glob patterns relative to the project root
+#[derive(Debug, Clone, Default)]
+pub struct IncludedDirs {
+    patterns: Vec<Pattern>,
+    /// Literal leading part of each pattern, used to decide which directories to descend into
+    literal_prefixes: Vec<String>,
+}
+
+impl IncludedDirs {
+    pub fn new(patterns: &[String]) -> Self {
+        let mut included = Self::default();
+        for raw in patterns {
+            let normalized = raw.replace('\\', "/").trim_matches('/').to_string();
+            match Pattern::new(&normalized) {
+                Ok(pattern) => {
+                    let literal_end = normalized
+                        .find(['*', '?', '[', '{'])
+                        .unwrap_or(normalized.len());
+                    let literal = normalized[..literal_end].trim_end_matches('/').to_string();
+                    included.patterns.push(pattern);
+                    included.literal_prefixes.push(literal);
+                }
+                Err(e) => {
+                    tracing::warn!("⚠️  Invalid included_dirs pattern '{}': {}", raw, e);
+                }
+            }
+        }
+        included
+    }
+
+    /// No allowlist configured, everything is included
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Whether the directory (relative to the project root) or one of its ancestors is included
+    pub fn contains(&self, relative_dir: &Path) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        relative_dir
+            .ancestors()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .any(|dir| {
+                let dir = dir.to_string_lossy().replace('\\', "/");
+                self.patterns.iter().any(|p| p.matches(&dir))
+            })
+    }
+
+    /// Whether traversal has to descend into the directory to reach included directories
+    pub fn may_contain(&self, relative_dir: &Path) -> bool {
+        if relative_dir.as_os_str().is_empty() || self.contains(relative_dir) {
+            return true;
+        }
+        let dir = relative_dir.to_string_lossy().replace('\\', "/");
+        self.literal_prefixes.iter().any(|literal| {
+            literal.is_empty() || literal == &dir || literal.starts_with(&format!("{}/", dir))
+        })
+    }
+
+    /// Whether a file (relative to the project root) lives in an included directory
+    pub fn contains_file(&self, relative_file: &Path) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        relative_file
+            .parent()
+            .is_some_and(|parent| self.contains(parent))
+    }
+}
+
+/// Identity of a file or directory, shared by every path and link leading to it
+#[cfg(unix)]
+type EntryId = (u64, u64);
+#[cfg(not(unix))]
+type EntryId = std::path::PathBuf;
+
+/// Files and directories reached during a traversal, so that entries reachable through
+/// several symbolic links are visited once and link cycles end
+#[derive(Debug, Default)]
+pub struct VisitedEntries {
+    seen: HashSet<EntryId>,
+}
+
+impl VisitedEntries {
+    /// Whether the entry is reached for the first time; entries that cannot be identified count as new
+    pub fn first_visit(&mut self, path: &Path) -> bool {
+        match entry_id(path) {
+            Some(id) => self.seen.insert(id),
+            None => true,
+        }
+    }
+}
+
+#[cfg(unix)]
+fn entry_id(path: &Path) -> Option<EntryId> {
+    use std::os::unix::fs::MetadataExt;
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn entry_id(path: &Path) -> Option<EntryId> {
+    std::fs::canonicalize(path).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_visited_entries_through_symlinks() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        let src = root.join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(src.join("lib.rs"), "").unwrap();
+        std::os::unix::fs::symlink(src.join("lib.rs"), root.join("lib_link.rs")).unwrap();
+        std::os::unix::fs::symlink(&root, src.join("loop")).unwrap();
+
+        let mut visited = VisitedEntries::default();
+        assert!(visited.first_visit(&root));
+        assert!(visited.first_visit(&src.join("lib.rs")));
+        assert!(!visited.first_visit(&root.join("lib_link.rs")));
+        assert!(!visited.first_visit(&src.join("loop")));
+        assert!(visited.first_visit(&root.join("missing.rs")));
+    }
+
+    #[test]
+    fn test_included_dirs_matching() {
+        let included = IncludedDirs::new(&["services/payment".to_string(), "libs/*-core".to_string()]);
+
+        assert!(included.contains(Path::new("services/payment")));
+        assert!(included.contains(Path::new("services/payment/src/api")));
+        assert!(!included.contains(Path::new("services/billing")));
+        assert!(included.may_contain(Path::new("services")));
+        assert!(!included.may_contain(Path::new("apps")));
+        assert!(included.may_contain(Path::new("")));
+        assert!(included.contains(Path::new("libs/auth-core")));
+
+        assert!(included.contains_file(Path::new("services/payment/main.go")));
+        assert!(!included.contains_file(Path::new("main.go")));
+        assert!(IncludedDirs::new(&[]).contains_file(Path::new("main.go")));
+    }
+
+    #[test]
+    fn test_read_partial_file() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("partial.rs");
+        let content: String = (1..=1000)
+            .map(|i| {
+                if i % 100 == 0 {
+                    format!("pub fn handler_{}() {{\n", i)
+                } else {
+                    format!("    let value_{} = {};\n", i, i)
+                }
+            })
+            .collect();
+        std::fs::write(&path, &content).unwrap();
+
+        let (partial, _) = read_partial_file(&path, 2000, |line| line.starts_with("pub fn")).unwrap();
+
+        assert!(partial.starts_with("    let value_1 = 1;\n"));
+        assert!(partial.contains("omitted, the file exceeds max_file_size"));
+        assert!(partial.contains("\n500: pub fn handler_500() {\n"));
+        assert!(!partial.contains("value_500 "));
+        assert!(partial.ends_with("pub fn handler_1000() {"));
+        assert!(partial.len() < 2200);
+    }
+
+    #[test]
+    fn test_binary_content_sniffing() {
+        assert!(is_binary_content(b"\x7fELF\x02\x01\x01"));
+        assert!(is_binary_content(b"PK\x03\x04rest of the archive"));
+        assert!(is_binary_content(b"header\0\0\0payload"));
+        assert!(is_binary_content(&[0x01, 0x02, 0x03, b'a', 0x04, 0x05]));
+        assert!(!is_binary_content(b"#!/bin/sh\necho \"\x1b[1mbuild\x1b[0m\"\n"));
+        assert!(!is_binary_content("Maven wrapper: 日本語\n".as_bytes()));
+        assert!(!is_binary_content(b"\xff\xfeh\0i\0"));
+        assert!(!is_binary_content(b""));
+
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("sniff");
+        std::fs::write(&path, b"\x7fELF\x02\x01\x01\0\0\0").unwrap();
+        assert!(is_binary_file(&path));
+        std::fs::write(&path, "[settings]\nkey = value\n").unwrap();
+        assert!(!is_binary_file(&path));
+    }
+}
@@ -0,0 +1,112 @@
+//! Revision inputs: `--ref` checks a branch, tag or commit out to a temporary detached
+//! worktree, so the working tree of the repository is left untouched
+
+use anyhow::{Context, Result, bail};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tracing::{info, warn};
+
+/// Detached worktree of a revision, removed from the repository on drop
+pub struct GitWorktree {
+    repository: PathBuf,
+    worktree: PathBuf,
+    project_root: PathBuf,
+}
+
+impl GitWorktree {
+    /// The project directory inside the worktree
+    pub fn project_root(&self) -> &Path {
+        &self.project_root
+    }
+}
+
+impl Drop for GitWorktree {
+    fn drop(&mut self) {
+        let worktree = self.worktree.to_string_lossy().to_string();
+        if let Err(e) = git(&self.repository, &["worktree", "remove", "--force", &worktree]) {
+            warn!("⚠️  Failed to remove worktree {}: {:#}", self.worktree.display(), e);
+            let _ = std::fs::remove_dir_all(&self.worktree);
+            let _ = git(&self.repository, &["worktree", "prune"]);
+        }
+    }
+}
+
+/// Check `git_ref` out to a new temporary worktree of the repository containing `project_path`
+pub fn checkout(project_path: &Path, git_ref: &str) -> Result<GitWorktree> {
+    let repository = PathBuf::from(git(project_path, &["rev-parse", "--show-toplevel"])?);
+    // The project may be a subdirectory of the repository
+    let prefix = git(project_path, &["rev-parse", "--show-prefix"])?;
+    let commit = git(project_path, &["rev-parse", "--verify", "--end-of-options", &format!("{}^{{commit}}", git_ref)])
+        .with_context(|| format!("Unknown git ref `{}`", git_ref))?;
+
+    let worktree = std::env::temp_dir().join(format!("litho-ref-{}", uuid::Uuid::new_v4()));
+    let path = worktree.to_string_lossy().to_string();
+    git(&repository, &["worktree", "add", "--detach", &path, &commit])?;
+    info!("🔖 Checked out {} ({}) to {}", git_ref, &commit[..commit.len().min(12)], worktree.display());
+    Ok(GitWorktree {
+        project_root: worktree.join(prefix),
+        repository,
+        worktree,
+    })
+}
+
+/// Short hash of a revision of the repository containing `project_path`, `None` outside a
+/// git repository
+pub fn short_commit(project_path: &Path, revision: &str) -> Option<String> {
+    if !project_path.is_dir() {
+        return None;
+    }
+    git(project_path, &["rev-parse", "--short", "--verify", "--end-of-options", &format!("{}^{{commit}}", revision)]).ok()
+}
+
+/// Run git in a directory and return its trimmed standard output
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkout_ref() {
+        let temp = tempfile::tempdir().unwrap();
+        let repository = temp.path().to_path_buf();
+        std::fs::create_dir_all(repository.join("app")).unwrap();
+        let commit = |message: &str| {
+            git(&repository, &["add", "-A"]).unwrap();
+            git(&repository, &["-c", "user.name=Test", "-c", "user.email=test@example.com", "commit", "-qm", message]).unwrap();
+        };
+        if git(&repository, &["init", "-q"]).is_err() {
+            return;
+        }
+        std::fs::write(repository.join("app/main.rs"), "// v1\n").unwrap();
+        commit("v1");
+        git(&repository, &["tag", "v1.0"]).unwrap();
+        std::fs::write(repository.join("app/main.rs"), "// v2\n").unwrap();
+        commit("v2");
+
+        let worktree = checkout(&repository.join("app"), "v1.0").unwrap();
+        let root = worktree.project_root().to_path_buf();
+        assert_eq!(std::fs::read_to_string(root.join("main.rs")).unwrap(), "// v1\n");
+        assert_eq!(std::fs::read_to_string(repository.join("app/main.rs")).unwrap(), "// v2\n");
+        assert!(checkout(&repository, "no-such-ref").is_err());
+
+        drop(worktree);
+        assert!(!root.exists());
+        assert_eq!(git(&repository, &["worktree", "list", "--porcelain"]).unwrap().matches("worktree ").count(), 1);
+    }
+}
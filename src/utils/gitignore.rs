@@ -0,0 +1,95 @@
+use ignore::Match;
+use ignore::WalkBuilder;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use std::path::Path;
+
+/// Matcher for the `.gitignore` files of a project (root, nested and `.git/info/exclude`)
+#[derive(Debug, Clone, Default)]
+pub struct GitignoreMatcher {
+    /// Gitignore rules ordered from the deepest directory to the project root
+    matchers: Vec<Gitignore>,
+}
+
+impl GitignoreMatcher {
+    /// Collect all `.gitignore` files below the project root
+    pub fn from_project(project_path: &Path) -> Self {
+        let mut matchers = Vec::new();
+
+        // Root rules also include the repository-local exclude file
+        let mut root_builder = GitignoreBuilder::new(project_path);
+        root_builder.add(project_path.join(".gitignore"));
+        root_builder.add(project_path.join(".git").join("info").join("exclude"));
+        if let Ok(gitignore) = root_builder.build()
+            && !gitignore.is_empty()
+        {
+            matchers.push(gitignore);
+        }
+
+        // Nested .gitignore files, the walker itself skips already ignored directories
+        let walker = WalkBuilder::new(project_path)
+            .hidden(false)
+            .require_git(false)
+            .filter_entry(|entry| entry.file_name() != ".git")
+            .build();
+        for entry in walker.flatten() {
+            let path = entry.path();
+            let Some(parent) = path.parent() else {
+                continue;
+            };
+            if entry.file_name() != ".gitignore" || parent == project_path {
+                continue;
+            }
+            let mut builder = GitignoreBuilder::new(parent);
+            builder.add(path);
+            if let Ok(gitignore) = builder.build()
+                && !gitignore.is_empty()
+            {
+                matchers.push(gitignore);
+            }
+        }
+
+        // Deeper rules take precedence over the rules of their parents
+        matchers.sort_by_key(|g| std::cmp::Reverse(g.path().components().count()));
+
+        Self { matchers }
+    }
+
+    /// Check whether the path (or one of its parent directories) is ignored by git
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        for gitignore in &self.matchers {
+            if !path.starts_with(gitignore.path()) {
+                continue;
+            }
+            match gitignore.matched_path_or_any_parents(path, is_dir) {
+                Match::Ignore(_) => return true,
+                Match::Whitelist(_) => return false,
+                Match::None => {}
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_root_and_nested_gitignore() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().to_path_buf();
+        std::fs::create_dir_all(root.join("web/generated")).unwrap();
+        std::fs::create_dir_all(root.join("vendor_cache")).unwrap();
+        std::fs::write(root.join(".gitignore"), "vendor_cache/\n*.log\n").unwrap();
+        std::fs::write(root.join("web/.gitignore"), "generated/\n!keep.log\n").unwrap();
+
+        let matcher = GitignoreMatcher::from_project(&root);
+
+        assert!(matcher.is_ignored(&root.join("vendor_cache"), true));
+        assert!(matcher.is_ignored(&root.join("vendor_cache/lib.rs"), false));
+        assert!(matcher.is_ignored(&root.join("debug.log"), false));
+        assert!(matcher.is_ignored(&root.join("web/generated/api.ts"), false));
+        assert!(!matcher.is_ignored(&root.join("web/keep.log"), false));
+        assert!(!matcher.is_ignored(&root.join("web/main.ts"), false));
+    }
+}
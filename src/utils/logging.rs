@@ -0,0 +1,219 @@
+use clap::ValueEnum;
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::{Level, Metadata};
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+
+use crate::utils::progress::{multi_progress, progress_bars_enabled, set_progress_bars_enabled};
+
+/// Output format of log events
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    /// Human readable progress lines
+    #[default]
+    Text,
+    /// One JSON object per event, for CI systems and log aggregators
+    Json,
+}
+
+/// Console lines without emoji, for CI logs
+static PLAIN_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Writes one log event at a time, hiding active progress bars while printing
+struct ConsoleWriter {
+    to_stderr: bool,
+    buffer: Vec<u8>,
+}
+
+impl Write for ConsoleWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for ConsoleWriter {
+    fn drop(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let to_stderr = self.to_stderr;
+        let mut buffer = std::mem::take(&mut self.buffer);
+        if PLAIN_OUTPUT.load(Ordering::Relaxed) {
+            buffer = strip_emoji(&String::from_utf8_lossy(&buffer)).into_bytes();
+        }
+        let print = move || {
+            let _ = if to_stderr {
+                std::io::stderr().write_all(&buffer)
+            } else {
+                std::io::stdout().write_all(&buffer)
+            };
+        };
+        if progress_bars_enabled() {
+            multi_progress().suspend(print);
+        } else {
+            print();
+        }
+    }
+}
+
+/// Warnings and errors go to stderr, progress to stdout unless stdout is reserved
+struct ConsoleMakeWriter {
+    stderr_only: bool,
+}
+
+impl<'a> MakeWriter<'a> for ConsoleMakeWriter {
+    type Writer = ConsoleWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        ConsoleWriter {
+            to_stderr: self.stderr_only,
+            buffer: Vec::new(),
+        }
+    }
+
+    fn make_writer_for(&'a self, meta: &Metadata<'_>) -> Self::Writer {
+        ConsoleWriter {
+            to_stderr: self.stderr_only || *meta.level() <= Level::WARN,
+            buffer: Vec::new(),
+        }
+    }
+}
+
+/// Console verbosity selected on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verbosity {
+    /// Errors only (-q)
+    Quiet,
+    /// Progress, warnings and errors
+    Normal,
+    /// Prompt sizes, cache decisions and retry details (-v)
+    Verbose,
+    /// Everything, including dependency logs (-vv)
+    Trace,
+}
+
+impl Verbosity {
+    pub fn from_flags(quiet: bool, verbose: u8) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Verbosity::Quiet,
+            (false, 0) => Verbosity::Normal,
+            (false, 1) => Verbosity::Verbose,
+            (false, _) => Verbosity::Trace,
+        }
+    }
+
+    /// Default filter directive, `RUST_LOG` takes precedence
+    fn directive(&self) -> &'static str {
+        match self {
+            Verbosity::Quiet => "error",
+            Verbosity::Normal => "warn,deepwiki_rs=info",
+            Verbosity::Verbose => "warn,deepwiki_rs=debug",
+            Verbosity::Trace => "info,deepwiki_rs=trace",
+        }
+    }
+}
+
+/// Initialize the global tracing subscriber; `plain` output (CI) has no progress bars or emoji
+/// and goes to stderr, leaving stdout to the run summary
+pub fn init_logging(format: LogFormat, verbosity: Verbosity, plain: bool) {
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(verbosity.directive()));
+
+    set_progress_bars_enabled(show_progress_bars(format, verbosity, plain, std::io::stderr().is_terminal()));
+    PLAIN_OUTPUT.store(plain, Ordering::Relaxed);
+
+    let result = match format {
+        LogFormat::Text => tracing_subscriber::fmt()
+            .with_env_filter(filter)
+            .with_target(false)
+            .with_level(false)
+            .without_time()
+            .with_writer(ConsoleMakeWriter { stderr_only: plain })
+            .try_init(),
+        LogFormat::Json => tracing_subscriber::fmt()
+            .json()
+            .with_env_filter(filter)
+            .with_current_span(false)
+            .with_writer(if plain { BoxMakeWriter::new(std::io::stderr) } else { BoxMakeWriter::new(std::io::stdout) })
+            .try_init(),
+    };
+
+    if let Err(e) = result {
+        eprintln!("⚠️  Failed to initialize logging: {}", e);
+    }
+}
+
+/// Progress bars only make sense for humans watching an interactive terminal
+fn show_progress_bars(format: LogFormat, verbosity: Verbosity, plain: bool, terminal: bool) -> bool {
+    format == LogFormat::Text && verbosity != Verbosity::Quiet && !plain && terminal
+}
+
+/// Initialize logging for stdio servers, where stdout carries the protocol and every event goes to stderr
+pub fn init_stderr_logging(verbosity: Verbosity) {
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(verbosity.directive()));
+    set_progress_bars_enabled(false);
+
+    let result = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_target(false)
+        .with_level(false)
+        .without_time()
+        .with_writer(ConsoleMakeWriter { stderr_only: true })
+        .try_init();
+
+    if let Err(e) = result {
+        eprintln!("⚠️  Failed to initialize logging: {}", e);
+    }
+}
+
+/// Remove emoji and the spaces following them
+fn strip_emoji(text: &str) -> String {
+    let is_emoji = |c: char| {
+        matches!(c as u32, 0x1F000..=0x1FAFF | 0x2300..=0x23FF | 0x2600..=0x27BF | 0x2B00..=0x2BFF | 0xFE0F | 0x200D)
+    };
+    let mut plain = String::with_capacity(text.len());
+    let mut after_emoji = false;
+    for c in text.chars() {
+        if is_emoji(c) {
+            after_emoji = true;
+        } else if !(after_emoji && c == ' ') {
+            after_emoji = false;
+            plain.push(c);
+        }
+    }
+    plain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity_from_flags() {
+        assert_eq!(Verbosity::from_flags(true, 2), Verbosity::Quiet);
+        assert_eq!(Verbosity::from_flags(false, 0), Verbosity::Normal);
+        assert_eq!(Verbosity::from_flags(false, 1), Verbosity::Verbose);
+        assert_eq!(Verbosity::from_flags(false, 3), Verbosity::Trace);
+        assert_eq!(Verbosity::Quiet.directive(), "error");
+        assert_eq!(Verbosity::Verbose.directive(), "warn,deepwiki_rs=debug");
+        assert_eq!(strip_emoji("⚠️  Failed to save run profile\n   🧭 Selected model"), "Failed to save run profile\n   Selected model");
+    }
+
+    #[test]
+    fn test_progress_bars_only_on_terminals() {
+        assert!(show_progress_bars(LogFormat::Text, Verbosity::Normal, false, true));
+        assert!(show_progress_bars(LogFormat::Text, Verbosity::Verbose, false, true));
+        assert!(!show_progress_bars(LogFormat::Text, Verbosity::Normal, false, false));
+        assert!(!show_progress_bars(LogFormat::Json, Verbosity::Normal, false, true));
+        assert!(!show_progress_bars(LogFormat::Text, Verbosity::Normal, true, true));
+        assert!(!show_progress_bars(LogFormat::Text, Verbosity::Quiet, false, true));
+    }
+}
@@ -1,5 +1,15 @@
+pub mod archive;
+pub mod encoding;
+pub mod extractive_summarizer;
 pub mod file_utils;
+pub mod git_worktree;
+pub mod gitignore;
+pub mod logging;
+pub mod paths;
+pub mod progress;
 pub mod project_structure_formatter;
 pub mod threads;
 pub mod token_estimator;
 pub mod prompt_compressor;
+pub mod privacy;
+pub mod secret_redactor;
@@ -0,0 +1,56 @@
+//! Platform-independent paths: everything emitted into prompts, memory, documents and links
+//! uses forward slashes, and Windows verbatim (`\\?\`) prefixes are removed from the paths
+//! the project is scanned from
+
+use serde::Serializer;
+use std::path::{Path, PathBuf};
+
+/// The path with forward slashes and without a verbatim prefix
+pub fn to_slash(path: impl AsRef<Path>) -> String {
+    strip_verbatim(path.as_ref()).to_string_lossy().replace('\\', "/")
+}
+
+/// The path without its verbatim prefix: `\\?\C:\repo` becomes `C:\repo` and
+/// `\\?\UNC\server\share` becomes `\\server\share`. Verbatim paths, as returned by
+/// `canonicalize` on Windows, disable the handling of `/` in joined relative paths; long
+/// paths are still opened since the standard library adds the prefix where needed.
+pub fn strip_verbatim(path: &Path) -> PathBuf {
+    let text = path.to_string_lossy();
+    if let Some(unc) = text.strip_prefix(r"\\?\UNC\") {
+        PathBuf::from(format!(r"\\{}", unc))
+    } else if let Some(local) = text.strip_prefix(r"\\?\")
+        && local.chars().nth(1) == Some(':')
+    {
+        PathBuf::from(local)
+    } else {
+        path.to_path_buf()
+    }
+}
+
+/// Serialize a path with forward slashes, so memory and prompts read the same on every platform
+pub fn serialize_slash<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&to_slash(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_platform_independent_paths() {
+        assert_eq!(to_slash(r"src\generator\mod.rs"), "src/generator/mod.rs");
+        assert_eq!(to_slash(r"\\?\C:\repo\src\main.rs"), "C:/repo/src/main.rs");
+        assert_eq!(to_slash(r"\\?\UNC\server\share\repo"), "//server/share/repo");
+        assert_eq!(strip_verbatim(Path::new(r"\\?\UNC\server\share")), PathBuf::from(r"\\server\share"));
+        assert_eq!(strip_verbatim(Path::new(r"\\?\Volume{1234}\repo")), PathBuf::from(r"\\?\Volume{1234}\repo"));
+        assert_eq!(strip_verbatim(Path::new("/home/dev/repo")), PathBuf::from("/home/dev/repo"));
+
+        #[derive(serde::Serialize)]
+        struct File {
+            #[serde(serialize_with = "serialize_slash")]
+            path: PathBuf,
+        }
+        let file = File { path: PathBuf::from(r"src\lib.rs") };
+        assert_eq!(serde_json::to_string(&file).unwrap(), r#"{"path":"src/lib.rs"}"#);
+    }
+}
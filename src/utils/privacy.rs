@@ -0,0 +1,97 @@
+//! Privacy policies of sensitive paths
+//!
+//! `[privacy]` marks paths as metadata-only (names, locations and sizes may be used, the
+//! content is never sent to a model) or excluded (ignored as if the files did not exist).
+
+use glob::Pattern;
+use std::path::Path;
+
+use crate::config::PrivacyConfig;
+
+/// Placeholder standing for the content of metadata-only files in prompts and tool results
+pub const WITHHELD_CONTENT: &str =
+    "[Content withheld by the privacy policy: only the name and location of this file may be used]";
+
+/// What may be used of a path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathAccess {
+    Full,
+    MetadataOnly,
+    Excluded,
+}
+
+/// Glob patterns of `[privacy]`, matched against paths relative to the project root
+#[derive(Debug, Clone, Default)]
+pub struct PrivacyPolicy {
+    metadata_only: Vec<Pattern>,
+    excluded: Vec<Pattern>,
+}
+
+impl PrivacyPolicy {
+    pub fn new(config: &PrivacyConfig) -> Self {
+        Self {
+            metadata_only: compile(&config.metadata_only),
+            excluded: compile(&config.excluded),
+        }
+    }
+
+    /// Access to a file or directory; a path inherits the policy of its directories and
+    /// exclusion wins over metadata-only
+    pub fn access(&self, project_root: &Path, path: &Path) -> PathAccess {
+        let relative = path.strip_prefix(project_root).unwrap_or(path);
+        let matches = |patterns: &[Pattern]| {
+            relative
+                .ancestors()
+                .filter(|path| !path.as_os_str().is_empty())
+                .any(|path| {
+                    let path = path.to_string_lossy().replace('\\', "/");
+                    patterns.iter().any(|pattern| pattern.matches(&path))
+                })
+        };
+        if matches(&self.excluded) {
+            PathAccess::Excluded
+        } else if matches(&self.metadata_only) {
+            PathAccess::MetadataOnly
+        } else {
+            PathAccess::Full
+        }
+    }
+
+    pub fn is_excluded(&self, project_root: &Path, path: &Path) -> bool {
+        self.access(project_root, path) == PathAccess::Excluded
+    }
+}
+
+fn compile(patterns: &[String]) -> Vec<Pattern> {
+    patterns
+        .iter()
+        .filter_map(|raw| {
+            let normalized = raw.replace('\\', "/").trim_matches('/').to_string();
+            Pattern::new(&normalized)
+                .inspect_err(|e| tracing::warn!("⚠️  Invalid [privacy] pattern '{}': {}", raw, e))
+                .ok()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_access() {
+        let policy = PrivacyPolicy::new(&PrivacyConfig {
+            metadata_only: vec!["src/billing".to_string(), "**/*.sql".to_string()],
+            excluded: vec!["secrets/".to_string(), "src/billing/keys.rs".to_string()],
+        });
+        let root = Path::new("/work/shop");
+
+        assert_eq!(policy.access(root, Path::new("/work/shop/src/main.rs")), PathAccess::Full);
+        assert_eq!(policy.access(root, Path::new("/work/shop/src/billing/invoice.rs")), PathAccess::MetadataOnly);
+        assert_eq!(policy.access(root, Path::new("src/billing")), PathAccess::MetadataOnly);
+        assert_eq!(policy.access(root, Path::new("db/schema.sql")), PathAccess::MetadataOnly);
+        assert_eq!(policy.access(root, Path::new("/work/shop/src/billing/keys.rs")), PathAccess::Excluded);
+        assert!(policy.is_excluded(root, Path::new("/work/shop/secrets/prod/db.env")));
+        assert_eq!(policy.access(root, Path::new("/work/shop/docs/secrets.md")), PathAccess::Full);
+    }
+}
@@ -0,0 +1,98 @@
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static MULTI_PROGRESS: OnceLock<MultiProgress> = OnceLock::new();
+static PROGRESS_BARS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable progress bars (only enabled for interactive terminals)
+pub fn set_progress_bars_enabled(enabled: bool) {
+    PROGRESS_BARS_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether progress bars are currently drawn
+pub fn progress_bars_enabled() -> bool {
+    PROGRESS_BARS_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Shared container for all progress bars, log lines are printed above it
+pub fn multi_progress() -> &'static MultiProgress {
+    MULTI_PROGRESS.get_or_init(MultiProgress::new)
+}
+
+/// N-of-M progress of a long running phase, a no-op when progress bars are disabled
+#[derive(Clone)]
+pub struct PhaseProgress {
+    bar: Option<ProgressBar>,
+}
+
+impl PhaseProgress {
+    pub fn new(label: &str, total: usize) -> Self {
+        if !progress_bars_enabled() || total == 0 {
+            return Self { bar: None };
+        }
+
+        let bar = multi_progress().add(ProgressBar::new(total as u64));
+        bar.set_style(
+            ProgressStyle::with_template("{prefix} [{bar:30.cyan/blue}] {pos}/{len} ({elapsed}) {wide_msg}")
+                .unwrap_or_else(|_| ProgressStyle::default_bar())
+                .progress_chars("=> "),
+        );
+        bar.set_prefix(label.to_string());
+        Self { bar: Some(bar) }
+    }
+
+    /// Show the item currently being processed
+    pub fn set_message(&self, message: &str) {
+        if let Some(bar) = &self.bar {
+            bar.set_message(message.to_string());
+        }
+    }
+
+    /// Mark one item as done
+    pub fn inc(&self) {
+        if let Some(bar) = &self.bar {
+            bar.inc(1);
+        }
+    }
+
+    /// Remove the bar once the phase is completed
+    pub fn finish(&self) {
+        if let Some(bar) = &self.bar {
+            bar.finish_and_clear();
+            multi_progress().remove(bar);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phase_progress() {
+        // Without a terminal every call is a no-op
+        let disabled = PhaseProgress::new("📄 Files", 3);
+        assert!(disabled.bar.is_none());
+        disabled.set_message("src/main.rs");
+        disabled.inc();
+        disabled.finish();
+
+        set_progress_bars_enabled(true);
+        let empty = PhaseProgress::new("📄 Files", 0);
+        let progress = PhaseProgress::new("📄 Files", 3);
+        set_progress_bars_enabled(false);
+        assert!(empty.bar.is_none());
+
+        // Clones share the bar, as the tasks of a phase do
+        progress.set_message("src/main.rs");
+        progress.inc();
+        progress.clone().inc();
+        let bar = progress.bar.clone().unwrap();
+        assert_eq!((bar.position(), bar.length()), (2, Some(3)));
+        assert_eq!(bar.prefix(), "📄 Files");
+        assert_eq!(bar.message(), "src/main.rs");
+        progress.finish();
+        assert!(bar.is_finished());
+    }
+}
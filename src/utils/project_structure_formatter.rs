@@ -307,6 +307,7 @@ mod tests {
             total_directories: 4,
             file_types: std::collections::HashMap::new(),
             size_distribution: std::collections::HashMap::new(),
+            language_stats: Vec::new(),
         };
 
         let result = ProjectStructureFormatter::format_as_directory_tree(&structure);
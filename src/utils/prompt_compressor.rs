@@ -3,11 +3,19 @@ use serde::{Deserialize, Serialize};
 
 use crate::generator::agent_executor::{AgentExecuteParams, prompt};
 use crate::generator::context::GeneratorContext;
+use crate::utils::extractive_summarizer;
+use crate::utils::threads::do_parallel_with_limit;
 use crate::utils::token_estimator::{TokenEstimation, TokenEstimator};
+use tracing::{debug, info, warn};
+
+const COMPRESSION_SYSTEM_PROMPT: &str = "You are a professional content simplification expert, skilled at extracting and preserving key information while significantly reducing content length. Focus on preserving only the most critical information and eliminate all redundancies.";
+/// Levels of map-reduce before the merged summaries are kept as they are
+const MAX_MAP_REDUCE_LEVELS: usize = 3;
+/// Smallest summary requested for one batch, however many batches there are (tokens)
+const MIN_BATCH_SUMMARY_TOKENS: usize = 1024;
 
 /// Prompt compressor for compressing overly long prompt content
 pub struct PromptCompressor {
-    token_estimator: TokenEstimator,
     compression_config: CompressionConfig,
 }
 
@@ -20,6 +28,9 @@ pub struct CompressionConfig {
     pub target_compression_ratio: f64,
     /// Whether compression is enabled
     pub enabled: bool,
+    /// Shrink content locally (duplicate removal, signature-only code, importance-ranked
+    /// truncation) before falling back to LLM compression
+    pub local_pre_summarization: bool,
     /// Types of key information to preserve during compression
     pub preserve_patterns: Vec<PreservePattern>,
 }
@@ -47,6 +58,7 @@ impl Default for CompressionConfig {
             compression_threshold: 65536, // Reduced to 64K to prevent token overflow
             target_compression_ratio: 0.5, // More aggressive compression to 50%
             enabled: true,
+            local_pre_summarization: true,
             preserve_patterns: vec![
                 PreservePattern::FunctionSignatures,
                 PreservePattern::TypeDefinitions,
@@ -78,7 +90,6 @@ pub struct CompressionResult {
 impl PromptCompressor {
     pub fn new(config: CompressionConfig) -> Self {
         Self {
-            token_estimator: TokenEstimator::new(),
             compression_config: config,
         }
     }
@@ -90,25 +101,61 @@ impl PromptCompressor {
         content: &str,
         content_type: &str,
     ) -> Result<CompressionResult> {
+        // Count tokens with the tokenizer of the model receiving the prompt
+        let token_estimator = TokenEstimator::for_model(&context.config.llm.model_efficient);
         if !self.compression_config.enabled {
-            return Ok(self.create_no_compression_result(content));
+            return Ok(self.create_no_compression_result(&token_estimator, content));
         }
 
-        let estimation = self.token_estimator.estimate_tokens(content);
+        let estimation = token_estimator.estimate_tokens(content);
 
         if estimation.estimated_tokens <= self.compression_config.compression_threshold {
-            return Ok(self.create_no_compression_result(content));
+            return Ok(self.create_no_compression_result(&token_estimator, content));
         }
 
+        // Zero-cost local stage first, it often avoids the compression call entirely
+        let original_tokens = estimation.estimated_tokens;
+        let local_summary;
+        let (content, estimation) = if self.compression_config.local_pre_summarization {
+            local_summary = extractive_summarizer::summarize(
+                content,
+                self.compression_config.compression_threshold,
+                &token_estimator,
+            );
+            for step in &local_summary.steps {
+                debug!("   ✂️  Local pre-summarization [{}]: {}", content_type, step);
+            }
+            if local_summary.tokens <= self.compression_config.compression_threshold {
+                let ratio = local_summary.tokens as f64 / original_tokens as f64;
+                return Ok(CompressionResult {
+                    compressed_content: local_summary.content.clone(),
+                    original_tokens,
+                    compressed_tokens: local_summary.tokens,
+                    compression_ratio: ratio,
+                    was_compressed: true,
+                    compression_summary: format!(
+                        "Local pre-summarization: {}tokens -> {}tokens, compression ratio {:.1}%",
+                        original_tokens,
+                        local_summary.tokens,
+                        (1.0 - ratio) * 100.0
+                    ),
+                });
+            }
+            let estimation = token_estimator.estimate_tokens(&local_summary.content);
+            (local_summary.content.as_str(), estimation)
+        } else {
+            (content, estimation)
+        };
+
         // Check cache
         let cache_manager = context.cache_manager.read().await;
         if let Ok(Some(cached_result)) = cache_manager
             .get_compression_cache(content, content_type)
             .await
         {
-            let msg = context.config.target_language.msg_cache_compression_hit().replace("{}", content_type);
-            println!("{}", msg);
-            let compressed_estimation = self.token_estimator.estimate_tokens(&cached_result);
+            let msg = context.config.ui_language().msg_cache_compression_hit(content_type);
+            debug!("{}", msg);
+            let compressed_estimation = token_estimator.estimate_tokens(&cached_result);
             let actual_ratio =
                 compressed_estimation.estimated_tokens as f64 / estimation.estimated_tokens as f64;
 
@@ -128,13 +175,13 @@ impl PromptCompressor {
         }
         drop(cache_manager);
 
-        println!(
+        info!(
             "   🗜️  Detected oversized content [{}]: {} tokens, starting intelligent compression...",
             content_type, estimation.estimated_tokens
         );
 
         let result = self
-            .perform_compression(context, content, content_type, estimation)
+            .perform_compression(context, &token_estimator, content, content_type, estimation)
             .await?;
 
         // Cache compression result
@@ -152,6 +199,7 @@ impl PromptCompressor {
     async fn perform_compression(
         &self,
         context: &GeneratorContext,
+        token_estimator: &TokenEstimator,
         content: &str,
         content_type: &str,
         original_estimation: TokenEstimation,
@@ -165,9 +213,7 @@ impl PromptCompressor {
             self.build_compression_prompt(content, content_type, target_tokens);
 
         let params = AgentExecuteParams {
-            prompt_sys:
-                "You are a professional content simplification expert, skilled at extracting and preserving key information while significantly reducing content length. Focus on preserving only the most critical information and eliminate all redundancies."
-                    .to_string(),
+            prompt_sys: COMPRESSION_SYSTEM_PROMPT.to_string(),
             prompt_user: compression_prompt,
             cache_scope: format!("prompt_compression_{}", content_type),
             log_tag: format!("Context-Compression-{}", content_type),
@@ -183,12 +229,12 @@ impl PromptCompressor {
         }
 
         let compressed_content = prompt(context, params).await?;
-        let compressed_estimation = self.token_estimator.estimate_tokens(&compressed_content);
+        let compressed_estimation = token_estimator.estimate_tokens(&compressed_content);
 
         let actual_ratio = compressed_estimation.estimated_tokens as f64
             / original_estimation.estimated_tokens as f64;
 
-        println!(
+        info!(
             "   ✅ Compression complete: {} tokens -> {} tokens (compression ratio: {:.1}%)",
             original_estimation.estimated_tokens,
             compressed_estimation.estimated_tokens,
@@ -210,6 +256,105 @@ impl PromptCompressor {
         })
     }
 
+    /// Summarize content too large for a single compression call: split it into batches under
+    /// the compression threshold, summarize each with the efficient model and merge the
+    /// summaries, level by level until the merged summaries fit under the threshold
+    pub async fn map_reduce(
+        &self,
+        context: &GeneratorContext,
+        content: &str,
+        content_type: &str,
+    ) -> Result<CompressionResult> {
+        let token_estimator = TokenEstimator::for_model(&context.config.llm.model_efficient);
+        let threshold = self.compression_config.compression_threshold;
+        let original_tokens = token_estimator.estimate_tokens(content).estimated_tokens;
+        let cache_type = format!("{}_map_reduce", content_type);
+
+        let cached = context
+            .cache_manager
+            .read()
+            .await
+            .get_compression_cache(content, &cache_type)
+            .await;
+        let (merged, levels) = if let Ok(Some(cached)) = cached {
+            debug!("{}", context.config.ui_language().msg_cache_compression_hit(content_type));
+            (cached, 0)
+        } else {
+            let mut merged = content.to_string();
+            let mut tokens = original_tokens;
+            let mut levels = 0;
+            while tokens > threshold && levels < MAX_MAP_REDUCE_LEVELS {
+                levels += 1;
+                let batches = split_into_batches(&merged, threshold, &token_estimator);
+                // Each batch gets an equal share of the threshold so the merged summaries fit
+                let target_tokens = (threshold / batches.len()).max(MIN_BATCH_SUMMARY_TOKENS);
+                info!(
+                    "   🧩 Map-reduce [{}] level {}: summarizing {} tokens in {} batches",
+                    content_type,
+                    levels,
+                    tokens,
+                    batches.len()
+                );
+
+                let total = batches.len();
+                let futures: Vec<_> = batches
+                    .iter()
+                    .enumerate()
+                    .map(|(index, batch)| {
+                        let context = context.clone();
+                        let part = format!("{} (part {} of {})", content_type, index + 1, total);
+                        let params = AgentExecuteParams {
+                            prompt_sys: COMPRESSION_SYSTEM_PROMPT.to_string(),
+                            prompt_user: self.build_compression_prompt(batch, &part, target_tokens),
+                            cache_scope: format!("prompt_compression_{}", content_type),
+                            log_tag: format!("Context-MapReduce-{}", content_type),
+                            progress: Some((index + 1, total)),
+                        };
+                        Box::pin(async move { prompt(&context, params).await })
+                    })
+                    .collect();
+                let summaries = do_parallel_with_limit(futures, context.config.llm.max_parallels)
+                    .await
+                    .into_iter()
+                    .collect::<Result<Vec<_>>>()?;
+
+                let reduced = summaries.join("\n\n");
+                let reduced_tokens = token_estimator.estimate_tokens(&reduced).estimated_tokens;
+                let shrank = reduced_tokens < tokens;
+                merged = reduced;
+                tokens = reduced_tokens;
+                if !shrank {
+                    warn!("   ⚠️ Map-reduce [{}] stopped shrinking at {} tokens", content_type, tokens);
+                    break;
+                }
+            }
+            let _ = context
+                .cache_manager
+                .write()
+                .await
+                .set_compression_cache(content, &cache_type, merged.clone())
+                .await;
+            (merged, levels)
+        };
+
+        let compressed_tokens = token_estimator.estimate_tokens(&merged).estimated_tokens;
+        let ratio = compressed_tokens as f64 / original_tokens.max(1) as f64;
+        Ok(CompressionResult {
+            compressed_content: merged,
+            original_tokens,
+            compressed_tokens,
+            compression_ratio: ratio,
+            was_compressed: true,
+            compression_summary: format!(
+                "Map-reduce summarization in {} levels: {}tokens -> {}tokens, compression ratio {:.1}%",
+                levels,
+                original_tokens,
+                compressed_tokens,
+                (1.0 - ratio) * 100.0
+            ),
+        })
+    }
+
     /// Build compression prompt
     fn build_compression_prompt(
         &self,
@@ -258,8 +403,8 @@ Output only the condensed information, with zero additional comments or explanat
     }
 
     /// Create uncompressed result
-    fn create_no_compression_result(&self, content: &str) -> CompressionResult {
-        let estimation = self.token_estimator.estimate_tokens(content);
+    fn create_no_compression_result(&self, token_estimator: &TokenEstimator, content: &str) -> CompressionResult {
+        let estimation = token_estimator.estimate_tokens(content);
 
         CompressionResult {
             compressed_content: content.to_string(),
@@ -271,3 +416,59 @@ Output only the condensed information, with zero additional comments or explanat
         }
     }
 }
+
+/// Split content at line boundaries into batches of at most `max_tokens`; lines longer than
+/// that are cut into pieces
+fn split_into_batches(content: &str, max_tokens: usize, token_estimator: &TokenEstimator) -> Vec<String> {
+    let count = |text: &str| token_estimator.estimate_tokens(text).estimated_tokens;
+    let max_tokens = max_tokens.max(1);
+    let mut batches = Vec::new();
+    let mut batch = String::new();
+    let mut batch_tokens = 0;
+    for line in content.split_inclusive('\n') {
+        let line_tokens = count(line);
+        let pieces: Vec<String> = if line_tokens > max_tokens {
+            let chars: Vec<char> = line.chars().collect();
+            let piece_chars = chars.len().div_ceil(line_tokens.div_ceil(max_tokens)).max(1);
+            chars.chunks(piece_chars).map(|piece| piece.iter().collect()).collect()
+        } else {
+            vec![line.to_string()]
+        };
+        for piece in pieces {
+            let piece_tokens = count(&piece);
+            if batch_tokens + piece_tokens > max_tokens && !batch.is_empty() {
+                batches.push(std::mem::take(&mut batch));
+                batch_tokens = 0;
+            }
+            batch.push_str(&piece);
+            batch_tokens += piece_tokens;
+        }
+    }
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+    batches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_into_batches() {
+        let estimator = TokenEstimator::new();
+        let count = |text: &str| estimator.estimate_tokens(text).estimated_tokens;
+        let content: String = (0..400)
+            .map(|i| format!("- module_{} handles the orders of region {}\n", i, i % 7))
+            .chain(std::iter::once("x".repeat(5000)))
+            .collect();
+
+        let batches = split_into_batches(&content, 500, &estimator);
+
+        assert!(batches.len() > 1);
+        assert_eq!(batches.concat(), content);
+        assert!(batches.iter().all(|batch| count(batch) <= 500 + 10));
+        assert!(batches[0].ends_with('\n'));
+        assert_eq!(split_into_batches("", 500, &estimator), Vec::<String>::new());
+    }
+}
@@ -1,31 +1,143 @@
 use serde::{Deserialize, Serialize};
+use tiktoken_rs::CoreBPE;
 
-/// Token estimator for estimating the number of tokens in text
+/// Tokenizer family of a model. The OpenAI vocabularies are bundled and count exactly,
+/// the others are estimated from their vocabulary statistics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenizerFamily {
+    /// GPT-4 / GPT-3.5 BPE (cl100k_base), also a close match for DeepSeek and Moonshot models
+    #[default]
+    Cl100k,
+    /// GPT-4o, GPT-4.1, GPT-5 and o-series BPE (o200k_base)
+    O200k,
+    /// Anthropic Claude models, whose tokenizer is not published
+    Claude,
+    /// SentencePiece-style vocabularies (Llama, Mistral, Gemini, Qwen and other local models)
+    SentencePiece,
+}
+
+impl TokenizerFamily {
+    /// Tokenizer family of a model name, e.g. `gpt-4o-mini` or `openai/gpt-5`
+    pub fn for_model(model: &str) -> Self {
+        let model = model.rsplit('/').next().unwrap_or(model).to_lowercase();
+        let is_o_series = ["o1", "o3", "o4"].iter().any(|prefix| model.starts_with(prefix));
+
+        if is_o_series || ["gpt-4o", "gpt-4.1", "gpt-4.5", "gpt-5", "gpt-oss"].iter().any(|m| model.starts_with(m)) {
+            TokenizerFamily::O200k
+        } else if model.contains("claude") {
+            TokenizerFamily::Claude
+        } else if ["llama", "mistral", "mixtral", "codestral", "gemini", "gemma", "qwen", "phi"]
+            .iter()
+            .any(|m| model.contains(m))
+        {
+            TokenizerFamily::SentencePiece
+        } else {
+            TokenizerFamily::Cl100k
+        }
+    }
+
+    /// BPE vocabulary of the family, `None` when it is not bundled
+    fn bpe(&self) -> Option<&'static CoreBPE> {
+        match self {
+            TokenizerFamily::Cl100k => Some(tiktoken_rs::cl100k_base_singleton()),
+            TokenizerFamily::O200k => Some(tiktoken_rs::o200k_base_singleton()),
+            TokenizerFamily::Claude | TokenizerFamily::SentencePiece => None,
+        }
+    }
+
+    fn rules(&self) -> TokenCalculationRules {
+        match self {
+            TokenizerFamily::Cl100k => TokenCalculationRules {
+                whole_word_chars: 6,
+                subword_chars: 4.0,
+                other_script_chars_per_token: 2.0,
+                cjk_tokens_per_char: 1.2,
+                base_token_overhead: 50,
+            },
+            TokenizerFamily::O200k => TokenCalculationRules {
+                whole_word_chars: 7,
+                subword_chars: 4.5,
+                other_script_chars_per_token: 3.0,
+                cjk_tokens_per_char: 0.8,
+                base_token_overhead: 50,
+            },
+            TokenizerFamily::Claude => TokenCalculationRules {
+                whole_word_chars: 6,
+                subword_chars: 3.5,
+                other_script_chars_per_token: 2.0,
+                cjk_tokens_per_char: 1.0,
+                base_token_overhead: 50,
+            },
+            TokenizerFamily::SentencePiece => TokenCalculationRules {
+                whole_word_chars: 5,
+                subword_chars: 3.5,
+                other_script_chars_per_token: 2.0,
+                cjk_tokens_per_char: 1.0,
+                base_token_overhead: 50,
+            },
+        }
+    }
+}
+
+/// Context window of well-known models (tokens), `None` for unknown models
+pub fn context_window_for_model(model: &str) -> Option<usize> {
+    let model = model.rsplit('/').next().unwrap_or(model).to_lowercase();
+    // Checked in order, so more specific prefixes come first
+    let windows: [(&str, usize); 22] = [
+        ("gpt-4.1", 1_047_576),
+        ("gpt-5", 400_000),
+        ("gpt-4o", 128_000),
+        ("gpt-4-turbo", 128_000),
+        ("gpt-4", 8_192),
+        ("gpt-3.5", 16_385),
+        ("gpt-oss", 131_072),
+        ("o1", 200_000),
+        ("o3", 200_000),
+        ("o4", 200_000),
+        ("claude", 200_000),
+        ("gemini", 1_048_576),
+        ("deepseek", 65_536),
+        ("kimi", 131_072),
+        ("moonshot-v1-8k", 8_192),
+        ("moonshot-v1-32k", 32_768),
+        ("moonshot", 131_072),
+        ("qwen3", 131_072),
+        ("qwen", 32_768),
+        ("llama", 8_192),
+        ("codestral", 256_000),
+        ("mistral", 32_768),
+    ];
+    windows
+        .iter()
+        .find(|(prefix, _)| model.starts_with(prefix))
+        .map(|(_, window)| *window)
+}
+
+/// Token counter for the tokenizer of a model. Text is encoded with the BPE vocabulary of
+/// the family when it is bundled; otherwise it is split with the GPT pre-tokenizer rules
+/// (words with their leading space, digit groups of three, punctuation runs, whitespace
+/// runs) and each piece is costed with the vocabulary statistics of the family.
 pub struct TokenEstimator {
-    /// Token calculation rules for different models
+    /// BPE vocabulary of the tokenizer family, when bundled
+    bpe: Option<&'static CoreBPE>,
+    /// Token calculation rules of the tokenizer family
     model_rules: TokenCalculationRules,
 }
 
-/// Token calculation rules
+/// Vocabulary statistics of a tokenizer family
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenCalculationRules {
-    /// Average token ratio for English characters (characters/token)
-    pub english_char_per_token: f64,
-    /// Average token ratio for Chinese characters
-    pub chinese_char_per_token: f64,
-    /// Base token overhead (system prompt, etc.)
+    /// Tokens a request adds around its messages (roles, separators, reply priming)
     pub base_token_overhead: usize,
-}
-
-impl Default for TokenCalculationRules {
-    fn default() -> Self {
-        Self {
-            // Based on empirical values from GPT series models
-            english_char_per_token: 4.0,
-            chinese_char_per_token: 1.5,
-            base_token_overhead: 50,
-        }
-    }
+    /// Latin-script words up to this length are a single token
+    pub whole_word_chars: usize,
+    /// Average characters per sub-word token of longer words
+    pub subword_chars: f64,
+    /// Average characters per token of other alphabetic scripts (Cyrillic, Arabic, Devanagari, ...)
+    pub other_script_chars_per_token: f64,
+    /// Average tokens per CJK character
+    pub cjk_tokens_per_char: f64,
 }
 
 /// Token estimation result
@@ -44,62 +156,132 @@ pub struct TokenEstimation {
     pub english_char_count: usize,
 }
 
+/// Character classes of the pre-tokenizer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharClass {
+    Letter,
+    Cjk,
+    Digit,
+    Whitespace,
+    Punctuation,
+}
+
 impl TokenEstimator {
     pub fn new() -> Self {
+        Self::for_family(TokenizerFamily::default())
+    }
+
+    /// Estimator for the tokenizer of a model
+    pub fn for_model(model: &str) -> Self {
+        Self::for_family(TokenizerFamily::for_model(model))
+    }
+
+    pub fn for_family(family: TokenizerFamily) -> Self {
         Self {
-            model_rules: TokenCalculationRules::default(),
+            bpe: family.bpe(),
+            model_rules: family.rules(),
         }
     }
 
+    /// Tokens of a request with a system and a user prompt, including the request overhead
+    pub fn estimate_request_tokens(&self, system_prompt: &str, user_prompt: &str) -> usize {
+        self.estimate_tokens(system_prompt).estimated_tokens
+            + self.estimate_tokens(user_prompt).estimated_tokens
+            + self.model_rules.base_token_overhead
+    }
+
     /// Estimate the number of tokens in text
     pub fn estimate_tokens(&self, text: &str) -> TokenEstimation {
-        let character_count = text.chars().count();
-        let chinese_char_count = self.count_chinese_chars(text);
-        let english_char_count = self.count_english_chars(text);
-        let other_char_count = character_count - chinese_char_count - english_char_count;
-
-        // Calculate token count for each part
-        let chinese_tokens =
-            (chinese_char_count as f64 / self.model_rules.chinese_char_per_token).ceil() as usize;
-        let english_tokens =
-            (english_char_count as f64 / self.model_rules.english_char_per_token).ceil() as usize;
-        // Calculate other characters using English rules
-        let other_tokens = if other_char_count > 0 {
-            (other_char_count as f64 / self.model_rules.english_char_per_token).ceil() as usize
-        } else {
-            0
+        let chars: Vec<char> = text.chars().collect();
+        let estimated_tokens = match self.bpe {
+            Some(bpe) => bpe.encode_ordinary(text).len(),
+            None => self.pre_tokenized_tokens(&chars),
         };
 
-        let estimated_tokens =
-            chinese_tokens + english_tokens + other_tokens + self.model_rules.base_token_overhead;
-
         TokenEstimation {
             estimated_tokens,
-            character_count,
-            chinese_char_count,
-            english_char_count,
+            character_count: chars.len(),
+            chinese_char_count: chars.iter().filter(|c| Self::is_cjk_char(**c)).count(),
+            english_char_count: chars.iter().filter(|c| c.is_ascii()).count(),
+        }
+    }
+
+    /// Tokens of the pre-tokenized pieces of a text, for families without a bundled vocabulary
+    fn pre_tokenized_tokens(&self, chars: &[char]) -> usize {
+        let mut estimated_tokens = 0;
+        let mut i = 0;
+
+        while i < chars.len() {
+            let class = Self::classify(chars[i]);
+            let mut end = i + 1;
+            // A single space is merged into the following word or punctuation run
+            let run_class = if chars[i] == ' ' && end < chars.len() {
+                match Self::classify(chars[end]) {
+                    CharClass::Whitespace | CharClass::Digit => class,
+                    next => {
+                        end += 1;
+                        next
+                    }
+                }
+            } else {
+                class
+            };
+            while end < chars.len() && Self::classify(chars[end]) == run_class {
+                // Whitespace runs stop before the space that starts the next word
+                if run_class == CharClass::Whitespace
+                    && chars[end] == ' '
+                    && end + 1 < chars.len()
+                    && Self::classify(chars[end + 1]) != CharClass::Whitespace
+                {
+                    break;
+                }
+                end += 1;
+            }
+
+            estimated_tokens += self.piece_tokens(run_class, &chars[i..end]);
+            i = end;
         }
+        estimated_tokens
     }
 
-    /// Count number of Chinese characters
-    fn count_chinese_chars(&self, text: &str) -> usize {
-        text.chars().filter(|c| self.is_chinese_char(*c)).count()
+    /// Tokens of one pre-tokenized piece
+    fn piece_tokens(&self, class: CharClass, piece: &[char]) -> usize {
+        let rules = &self.model_rules;
+        let content: Vec<char> = piece.iter().copied().filter(|c| *c != ' ').collect();
+        let len = content.len();
+
+        match class {
+            CharClass::Whitespace => 1,
+            CharClass::Digit => len.div_ceil(3),
+            CharClass::Punctuation => len.div_ceil(2).max(1),
+            CharClass::Cjk => (len as f64 * rules.cjk_tokens_per_char).ceil() as usize,
+            CharClass::Letter if content.iter().all(char::is_ascii) => {
+                if len <= rules.whole_word_chars {
+                    1
+                } else {
+                    1 + ((len - rules.whole_word_chars) as f64 / rules.subword_chars).ceil() as usize
+                }
+            }
+            CharClass::Letter => (len as f64 / rules.other_script_chars_per_token).ceil() as usize,
+        }
     }
 
-    /// Count number of English characters
-    fn count_english_chars(&self, text: &str) -> usize {
-        text.chars()
-            .filter(|c| {
-                c.is_ascii_alphabetic()
-                    || c.is_ascii_whitespace()
-                    || c.is_ascii_digit()
-                    || c.is_ascii_punctuation()
-            })
-            .count()
+    fn classify(c: char) -> CharClass {
+        if Self::is_cjk_char(c) {
+            CharClass::Cjk
+        } else if c.is_alphabetic() || c == '_' {
+            CharClass::Letter
+        } else if c.is_numeric() {
+            CharClass::Digit
+        } else if c.is_whitespace() {
+            CharClass::Whitespace
+        } else {
+            CharClass::Punctuation
+        }
     }
 
-    /// Check if a character is Chinese
-    fn is_chinese_char(&self, c: char) -> bool {
+    /// Check if a character is Chinese, Japanese or Korean
+    fn is_cjk_char(c: char) -> bool {
         matches!(c as u32,
             0x4E00..=0x9FFF |  // CJK Unified Ideographs
             0x3400..=0x4DBF |  // CJK Extension A
@@ -108,7 +290,60 @@ impl TokenEstimator {
             0x2B740..=0x2B81F | // CJK Extension D
             0x2B820..=0x2CEAF | // CJK Extension E
             0x2CEB0..=0x2EBEF | // CJK Extension F
-            0x30000..=0x3134F   // CJK Extension G
+            0x30000..=0x3134F | // CJK Extension G
+            0x3040..=0x30FF |  // Hiragana and Katakana
+            0xAC00..=0xD7AF    // Hangul Syllables
         )
     }
 }
+
+impl Default for TokenEstimator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenizer_family_and_counts() {
+        assert_eq!(TokenizerFamily::for_model("gpt-4o-mini"), TokenizerFamily::O200k);
+        assert_eq!(TokenizerFamily::for_model("openai/o3-mini"), TokenizerFamily::O200k);
+        assert_eq!(TokenizerFamily::for_model("gpt-4-turbo"), TokenizerFamily::Cl100k);
+        assert_eq!(TokenizerFamily::for_model("claude-sonnet-4"), TokenizerFamily::Claude);
+        assert_eq!(TokenizerFamily::for_model("qwen2.5-coder:7b"), TokenizerFamily::SentencePiece);
+
+        let cl100k = TokenEstimator::for_family(TokenizerFamily::Cl100k);
+        let count = |text: &str| cl100k.estimate_tokens(text).estimated_tokens;
+        // "Hello", " world", "!"
+        assert_eq!(count("Hello world!"), 3);
+        // "fn", " main", "()", " {}"
+        assert_eq!(count("fn main() {}"), 4);
+        // "123", "456", "7"
+        assert_eq!(count("1234567"), 3);
+        // "international", "ization"
+        assert_eq!(count("internationalization"), 2);
+        assert_eq!(cl100k.estimate_request_tokens("Hello world!", "fn main() {}"), 3 + 4 + 50);
+
+        let o200k = TokenEstimator::for_family(TokenizerFamily::O200k);
+        let chinese = "代码仓库的架构文档";
+        assert!(o200k.estimate_tokens(chinese).estimated_tokens < count(chinese));
+
+        // Claude has no bundled vocabulary and is estimated from the pre-tokenized pieces
+        let claude = TokenEstimator::for_family(TokenizerFamily::Claude);
+        assert_eq!(claude.estimate_tokens("Hello world!").estimated_tokens, 3);
+        assert_eq!(claude.estimate_tokens("internationalization").estimated_tokens, 5);
+    }
+
+    #[test]
+    fn test_context_window_for_model() {
+        assert_eq!(context_window_for_model("gpt-4o-mini"), Some(128_000));
+        assert_eq!(context_window_for_model("gpt-4"), Some(8_192));
+        assert_eq!(context_window_for_model("anthropic/claude-sonnet-4"), Some(200_000));
+        assert_eq!(context_window_for_model("Qwen/Qwen3-Next-80B-A3B-Instruct"), Some(131_072));
+        assert_eq!(context_window_for_model("llama3.2:3b"), Some(8_192));
+        assert_eq!(context_window_for_model("my-finetune"), None);
+    }
+}
@@ -0,0 +1,40 @@
+import sqlite3
+from pathlib import Path
+
+from app.models import Book, BookCreate
+
+DATABASE = "bookshelf.db"
+
+SCHEMA = Path(__file__).with_name("schema.sql")
+
+
+def connect() -> sqlite3.Connection:
+    connection = sqlite3.connect(DATABASE)
+    connection.row_factory = sqlite3.Row
+    return connection
+
+
+def init() -> None:
+    with connect() as connection:
+        connection.executescript(SCHEMA.read_text())
+
+
+def list_books() -> list[Book]:
+    with connect() as connection:
+        rows = connection.execute("SELECT * FROM books ORDER BY id").fetchall()
+    return [Book(**dict(row)) for row in rows]
+
+
+def insert_book(book: BookCreate) -> Book:
+    with connect() as connection:
+        cursor = connection.execute(
+            "INSERT INTO books (title, author, year) VALUES (?, ?, ?)",
+            (book.title, book.author, book.year),
+        )
+    return Book(id=cursor.lastrowid, **book.model_dump())
+
+
+def get_book(book_id: int) -> Book | None:
+    with connect() as connection:
+        row = connection.execute("SELECT * FROM books WHERE id = ?", (book_id,)).fetchone()
+    return Book(**dict(row)) if row else None
@@ -0,0 +1,29 @@
+from fastapi import FastAPI, HTTPException
+
+from app import db
+from app.models import Book, BookCreate
+
+app = FastAPI(title="bookshelf")
+
+
+@app.on_event("startup")
+def startup() -> None:
+    db.init()
+
+
+@app.get("/books", response_model=list[Book])
+def list_books() -> list[Book]:
+    return db.list_books()
+
+
+@app.post("/books", response_model=Book, status_code=201)
+def create_book(book: BookCreate) -> Book:
+    return db.insert_book(book)
+
+
+@app.get("/books/{book_id}", response_model=Book)
+def get_book(book_id: int) -> Book:
+    book = db.get_book(book_id)
+    if book is None:
+        raise HTTPException(status_code=404, detail="book not found")
+    return book
@@ -0,0 +1,11 @@
+from pydantic import BaseModel
+
+
+class BookCreate(BaseModel):
+    title: str
+    author: str
+    year: int | None = None
+
+
+class Book(BookCreate):
+    id: int
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS books (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    title TEXT NOT NULL,
+    author TEXT NOT NULL,
+    year INTEGER
+);
+
+CREATE INDEX IF NOT EXISTS books_author ON books (author);
@@ -0,0 +1,34 @@
+use crate::store::{Store, Task};
+
+/// Execute a command line against the store and return the text to print
+pub fn run(args: &[String], store: &mut Store) -> Result<String, String> {
+    match args.first().map(String::as_str) {
+        Some("add") => {
+            let title = args.get(1).ok_or("missing task title")?;
+            let id = store.add(title);
+            store.save().map_err(|e| e.to_string())?;
+            Ok(format!("added task {}", id))
+        }
+        Some("list") => Ok(store
+            .tasks()
+            .iter()
+            .map(format_task)
+            .collect::<Vec<_>>()
+            .join("\n")),
+        Some("done") => {
+            let id: u32 = args
+                .get(1)
+                .and_then(|id| id.parse().ok())
+                .ok_or("missing task id")?;
+            store.complete(id).ok_or(format!("no task {}", id))?;
+            store.save().map_err(|e| e.to_string())?;
+            Ok(format!("completed task {}", id))
+        }
+        _ => Err("usage: todo <add|list|done>".to_string()),
+    }
+}
+
+fn format_task(task: &Task) -> String {
+    let mark = if task.done { "x" } else { " " };
+    format!("[{}] {} {}", mark, task.id, task.title)
+}
@@ -0,0 +1,16 @@
+mod commands;
+mod store;
+
+use std::env;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut store = store::Store::open(store::default_path());
+    match commands::run(&args, &mut store) {
+        Ok(output) => println!("{}", output),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
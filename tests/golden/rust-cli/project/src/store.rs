@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Task {
+    pub id: u32,
+    pub title: String,
+    pub done: bool,
+}
+
+/// Tasks persisted as JSON
+pub struct Store {
+    path: PathBuf,
+    tasks: Vec<Task>,
+}
+
+pub fn default_path() -> PathBuf {
+    let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
+    PathBuf::from(home).join(".todo.json")
+}
+
+impl Store {
+    pub fn open(path: PathBuf) -> Self {
+        let tasks = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self { path, tasks }
+    }
+
+    pub fn tasks(&self) -> &[Task] {
+        &self.tasks
+    }
+
+    pub fn add(&mut self, title: &str) -> u32 {
+        let id = self.tasks.iter().map(|task| task.id).max().unwrap_or(0) + 1;
+        self.tasks.push(Task { id, title: title.to_string(), done: false });
+        id
+    }
+
+    pub fn complete(&mut self, id: u32) -> Option<()> {
+        self.tasks.iter_mut().find(|task| task.id == id).map(|task| task.done = true)
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        fs::write(&self.path, serde_json::to_string_pretty(&self.tasks)?)
+    }
+}